@@ -0,0 +1,17 @@
+//! Compiles `proto/process.proto` when the `grpc` feature is enabled
+//!
+//! Uses protox, a pure-Rust protobuf compiler, so building does not need a
+//! system `protoc`.
+
+#[cfg(feature = "grpc")]
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("cargo:rerun-if-changed=proto/process.proto");
+    let descriptors = protox::compile(["proto/process.proto"], ["proto"])?;
+    tonic_prost_build::configure().compile_fds(descriptors)?;
+    Ok(())
+}
+
+#[cfg(not(feature = "grpc"))]
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+}
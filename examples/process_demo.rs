@@ -2,26 +2,10 @@ mod common;
 
 use anyhow::Context;
 use kodegen_mcp_schema::process::*;
+use kodegen_tools_process::client_types::{self, ProcessListOutput};
 use serde_json::json;
 use tracing::{error, info};
 
-// Response structures for process_list tool
-#[derive(Debug, serde::Deserialize)]
-struct ProcessInfo {
-    pid: u32,
-    name: String,
-    cpu_percent: f32,
-    memory_mb: f64,
-}
-
-#[derive(Debug, serde::Deserialize)]
-struct ProcessListResult {
-    processes: Vec<ProcessInfo>,
-    total_count: usize,
-    filter: Option<String>,
-    limited: bool,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     // Initialize logging
@@ -47,19 +31,13 @@ async fn main() -> anyhow::Result<()> {
     info!("1. Testing process_list");
     match client.call_tool(PROCESS_LIST, json!({})).await {
         Ok(result) => {
-            // Extract text content from CallToolResult
-            if let Some(text_content) = result.content.first().and_then(|c| c.as_text()) {
-                // Deserialize the JSON result into typed structure
-                match serde_json::from_str::<ProcessListResult>(&text_content.text) {
+            // The output struct is the JSON content item after the summary
+            match client_types::output::<ProcessListOutput>(&result) {
                 Ok(list) => {
-                    info!("✅ Found {} processes", list.total_count);
+                    info!("✅ Found {} processes", list.count);
 
-                    if list.limited {
-                        info!("   (Results limited - showing top processes by CPU usage)");
-                    }
-
-                    if let Some(ref filter) = list.filter {
-                        info!("   (Filtered by: {})", filter);
+                    if let Some(cap) = &list.capped {
+                        info!("   (Results capped by the server: {})", cap.by);
                     }
 
                     info!("   Top {} processes by CPU usage:", list.processes.len().min(10));
@@ -75,20 +53,17 @@ async fn main() -> anyhow::Result<()> {
                         };
 
                         info!(
-                            "   {:2}. PID {:6} | {:20} | CPU: {:5.1}% | Mem: {:7.1} MB",
+                            "   {:2}. PID {:6} | {:20} | CPU: {:5.1}% | Mem: {:>10}",
                             i + 1,
                             proc.pid,
                             name_display,
                             proc.cpu_percent,
-                            proc.memory_mb
+                            proc.rss_human
                         );
                     }
                 }
                 Err(e) => error!("Failed to parse process list: {}", e),
             }
-            } else {
-                error!("No text content in response from process_list tool");
-            }
         }
         Err(e) => error!("Failed to list processes: {}", e),
     }
@@ -0,0 +1,97 @@
+//! Bearer tokens for the side listeners (REST, gRPC)
+//!
+//! Requests carry `Authorization: Bearer <token>` (gRPC: the `authorization`
+//! metadata entry), checked against the tokens configured for the listener.
+//! Tokens are named and the name is the caller's identity: it keys the kill
+//! budget and owns the children it registers. A listener without tokens only
+//! binds loopback, where every caller is already a local user.
+
+use std::collections::BTreeMap;
+use std::net::{IpAddr, SocketAddr};
+
+/// Who sent a side-listener request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Caller {
+    /// Presented the token configured under this name
+    Token(String),
+    /// No tokens are configured, so the caller is anyone on this host
+    Local,
+}
+
+impl Caller {
+    /// Kill budget key: the token name, or the peer address without tokens
+    #[must_use]
+    pub(crate) fn client(&self, listener: &str, peer: Option<IpAddr>) -> Option<String> {
+        match self {
+            Self::Token(name) => Some(format!("{listener}:{name}")),
+            Self::Local => peer.map(|peer| format!("{listener}:{peer}")),
+        }
+    }
+}
+
+/// Configured tokens, ignoring empty ones that anything would match
+fn active(tokens: &BTreeMap<String, String>) -> impl Iterator<Item = (&String, &String)> {
+    tokens.iter().filter(|(_, token)| !token.is_empty())
+}
+
+/// Refuse to serve `addr` beyond loopback when `tokens` holds none
+pub(crate) fn check_bind(listener: &str, addr: SocketAddr, tokens: &BTreeMap<String, String>) -> anyhow::Result<()> {
+    if addr.ip().is_loopback() || active(tokens).next().is_some() {
+        return Ok(());
+    }
+    anyhow::bail!(
+        "{listener}.bind {addr} is reachable from other hosts but {listener}.tokens is empty; \
+         set a token or bind a loopback address"
+    )
+}
+
+/// Whether a tokenless listener bound to `bind` should answer a request for `host`
+///
+/// Without tokens anyone who reaches the listener is trusted, and a web page
+/// can reach loopback by rebinding its own DNS name to 127.0.0.1; such a
+/// request still names that page's host, so only loopback names and `bind`
+/// itself are answered. The port is not compared for loopback names.
+pub(crate) fn local_host(host: &str, bind: &str) -> bool {
+    host.eq_ignore_ascii_case(bind) || loopback_name(host)
+}
+
+/// Whether a browser page from `origin` may open the event stream
+///
+/// Browsers let any page open a WebSocket to loopback and only tell the
+/// server where the page came from, so origins other than loopback ones must
+/// be listed in `allowed` (compared whole, e.g. `https://dash.example.com`).
+pub(crate) fn trusted_origin(origin: &str, allowed: &[String]) -> bool {
+    allowed.iter().any(|entry| entry.trim_end_matches('/').eq_ignore_ascii_case(origin))
+        || origin
+            .split_once("://")
+            .is_some_and(|(_, authority)| loopback_name(authority))
+}
+
+/// Whether the host of the authority `host[:port]` is `localhost` or a loopback address
+fn loopback_name(authority: &str) -> bool {
+    let name = match authority.strip_prefix('[') {
+        Some(rest) => rest.split_once(']').map_or(rest, |(name, _)| name),
+        None => authority.split_once(':').map_or(authority, |(name, _)| name),
+    };
+    name.eq_ignore_ascii_case("localhost") || name.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback())
+}
+
+/// The caller presenting `authorization`, or why it is refused
+pub(crate) fn authenticate(tokens: &BTreeMap<String, String>, authorization: Option<&str>) -> Result<Caller, String> {
+    if active(tokens).next().is_none() {
+        return Ok(Caller::Local);
+    }
+    let presented = authorization
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim)
+        .ok_or_else(|| "missing bearer token (Authorization: Bearer <token>)".to_string())?;
+    active(tokens)
+        .find(|(_, token)| same(token.as_bytes(), presented.as_bytes()))
+        .map(|(name, _)| Caller::Token(name.clone()))
+        .ok_or_else(|| "invalid bearer token".to_string())
+}
+
+/// Compare without stopping at the first differing byte
+fn same(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
@@ -0,0 +1,114 @@
+//! Processes other kodegen tool servers started
+//!
+//! Sibling servers (terminal, command runners) register the PIDs they spawn,
+//! either through `POST /children` on the REST listener, authenticated by a
+//! `rest.tokens` entry whose name becomes the owner, or, when embedded in the
+//! same binary, by calling [`ChildRegistry::register`] directly. Registered
+//! processes count as started by kodegen: `process_list` names their owner in
+//! `spawned_by`, and kill confirmation treats them like this server's own
+//! launches. Entries remember the start time so a recycled PID never inherits
+//! the registration, and exited processes are dropped lazily.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// One process registered by a sibling server
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RegisteredChild {
+    pub pid: u32,
+    /// Start time in seconds since the Unix epoch (pairs with `pid` to detect reuse)
+    pub start_time: u64,
+    /// Server or tool that started it, e.g. `kodegen-tools-terminal`
+    pub owner: String,
+    /// Free-form description, e.g. the command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// When it was registered (RFC 3339)
+    pub registered_at: String,
+}
+
+/// `spawned_by` of processes this server launched itself
+pub const THIS_SERVER: &str = "kodegen-tools-process";
+
+#[derive(Default)]
+pub struct ChildRegistry {
+    entries: Mutex<HashMap<u32, RegisteredChild>>,
+}
+
+impl ChildRegistry {
+    /// Record that `owner` started the process `pid` that began at `start_time`
+    ///
+    /// Registering a PID again replaces the earlier entry, unless that entry
+    /// names the same process under another owner: `Err` then carries it,
+    /// left in place.
+    pub fn register(
+        &self,
+        pid: u32,
+        start_time: u64,
+        owner: &str,
+        note: Option<String>,
+    ) -> Result<RegisteredChild, RegisteredChild> {
+        let mut entries = self.lock();
+        if let Some(existing) = entries.get(&pid)
+            && existing.start_time == start_time
+            && existing.owner != owner
+        {
+            return Err(existing.clone());
+        }
+        let child = RegisteredChild {
+            pid,
+            start_time,
+            owner: owner.to_string(),
+            note,
+            registered_at: chrono::Utc::now().to_rfc3339(),
+        };
+        entries.insert(pid, child.clone());
+        Ok(child)
+    }
+
+    /// Forget `pid`; returns the entry it had
+    pub fn unregister(&self, pid: u32) -> Option<RegisteredChild> {
+        self.lock().remove(&pid)
+    }
+
+    /// Forget `pid` if `owner` registered it
+    ///
+    /// `Err` carries the entry left in place because another owner holds it.
+    pub fn unregister_owned(&self, pid: u32, owner: &str) -> Option<Result<RegisteredChild, RegisteredChild>> {
+        let mut entries = self.lock();
+        let child = entries.get(&pid)?;
+        if child.owner != owner {
+            return Some(Err(child.clone()));
+        }
+        entries.remove(&pid).map(Ok)
+    }
+
+    /// Owner of `pid` if it is the registered process started at `start_time`
+    #[must_use]
+    pub fn owner(&self, pid: u32, start_time: u64) -> Option<String> {
+        self.lock()
+            .get(&pid)
+            .filter(|child| child.start_time == start_time)
+            .map(|child| child.owner.clone())
+    }
+
+    /// Drop entries whose process exited or whose PID was reused
+    pub fn prune_with(&self, alive: impl Fn(u32, u64) -> bool) {
+        self.lock().retain(|pid, child| alive(*pid, child.start_time));
+    }
+
+    /// All entries, sorted by PID
+    #[must_use]
+    pub fn snapshot(&self) -> Vec<RegisteredChild> {
+        let mut children: Vec<RegisteredChild> = self.lock().values().cloned().collect();
+        children.sort_by_key(|child| child.pid);
+        children
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<u32, RegisteredChild>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
@@ -0,0 +1,112 @@
+//! Typed responses for clients of the process tools
+//!
+//! Every tool answers with its summary text followed by its output struct as
+//! JSON (the last content item). The types here are those output structs and
+//! everything they contain, all `Deserialize`, so a client parses a response
+//! with [`output`] instead of hand-writing mirror structs that drift from the
+//! server. The REST routes and the `/events` stream return the same JSON.
+
+use rmcp::model::CallToolResult;
+use serde::de::{DeserializeOwned, Error as _};
+
+pub use crate::children::RegisteredChild;
+pub use crate::events::ProcessEvent;
+pub use crate::schema::{
+    // port_lookup
+    PortLookupOutput, PortOwner, PortProtocol,
+    // process_affinity
+    ProcessAffinityOutput,
+    // process_audit
+    AuditCheck, AuditFinding, AuditFlag, ProcessAuditOutput,
+    // process_can_kill, process_capabilities, process_code_signature
+    CapabilitySet, CodeSignature, ProcessCanKillOutput, ProcessCapabilitiesOutput, ProcessCodeSignatureOutput,
+    SignatureState,
+    // process_compare, process_env_diff
+    ComparedField, ProcessCompareOutput, ProcessEnvDiffOutput, ValueDifference,
+    // process_connections (entries are SocketInfo, listed under process_forensics)
+    ProcessConnectionsOutput,
+    // process_contain, process_uncontain
+    ContainmentCaps, ContainmentInfo, ProcessContainOutput, ProcessUncontainOutput,
+    // process_cpu_limit, process_cpu_limits, process_cpu_unlimit
+    CpuLimitInfo, CpuLimitMethod, ProcessCpuLimitOutput, ProcessCpuLimitsOutput, ProcessCpuUnlimitOutput,
+    // process_env
+    ProcessEnvOutput,
+    // process_forensics and the dump file it writes
+    ForensicRecord, MemoryMap, NamespaceInfo, OpenFile, ProcessForensicsOutput, ResourceLimit, SocketInfo,
+    ThreadInfo,
+    // process_freeze, process_thaw
+    FreezeInfo, FreezeMethod, ProcessFreezeOutput, ProcessThawOutput,
+    // process_group_define, process_group_kill, process_groups
+    ProcessGroupDefineOutput, ProcessGroupInfo, ProcessGroupKillOutput, ProcessGroupsOutput, SkippedProcess,
+    // process_info
+    ProcessInfoOutput, ProcessMemory,
+    // process_ionice, process_renice
+    IoClass, IoPriority, ProcessIoniceOutput, ProcessReniceOutput, ReniceResult,
+    // process_kill, process_terminate, process_kill_if_idle, process_recently_killed
+    ContainerDelivery, EscalationOutcome, EscalationStep, IdleVerdict, KillBudget, KilledProcess, ProcessKillIfIdleOutput,
+    ProcessKillOutput, ProcessRecentlyKilledOutput, ProcessSignal, ProcessTerminateOutput,
+    // process_kill_by_name, process_kill_tree
+    ProcessKillByNameOutput, ProcessKillTreeOutput, SignalResult, TreeKillOrder,
+    // process_kill_schedule, process_kill_schedules, process_kill_unschedule
+    ProcessKillScheduleOutput, ProcessKillSchedulesOutput, ProcessKillUnscheduleOutput, ScheduleStatus,
+    ScheduledKill,
+    // process_leaks, process_stats, process_sampling
+    LeakSuspect, MetricStats, ProcessLeaksOutput, ProcessSamplingOutput, ProcessStatsOutput,
+    // process_list
+    ListDelta, Packaging, PodInfo, ProcessInfo, ProcessKind, ProcessListOutput, ResultCap, SeccompMode,
+    // process_lock_check, process_pidfile, process_pidfile_cleanup
+    LockHolder, PidfileStatus, ProcessLockCheckOutput, ProcessPidfileCleanupOutput, ProcessPidfileOutput,
+    // process_logs, process_logs_follow
+    FollowEnd, LogSegment, LogStream, ProcessLogsFollowOutput, ProcessLogsOutput,
+    // process_mark, process_mark_compare
+    MarkerChange, MarkerComparison, MarkerInfo, MarkerProcess, MarkerTotals, ProcessMarkCompareOutput,
+    ProcessMarkOutput,
+    // process_oom_guard
+    OomGuardAction, OomGuardRule, OomIntervention, ProcessOomGuardOutput,
+    // process_open_files (entries are OpenFile, listed under process_forensics)
+    ProcessOpenFilesOutput,
+    // process_pgrep
+    PgrepMatch, ProcessPgrepOutput,
+    // process_pid_translate
+    ProcessPidTranslateOutput,
+    // process_priority
+    ProcessPriorityOutput,
+    // process_profile_define, process_profile_start, process_profiles
+    ProcessProfileDefineOutput, ProcessProfileStartOutput, ProcessProfilesOutput, ProfileInfo, ProfileLaunch,
+    ProfileParam, ProfileSource, RestartPolicy, SpawnProfile,
+    // process_provenance, process_ancestors
+    LaunchOrigin, ProcessAncestor, ProcessAncestorsOutput, ProcessProvenanceOutput,
+    // process_respawn, process_run, process_spawn, process_status
+    DaemonInfo, ExitInfo, ProcessRespawnOutput, ProcessRunOutput, ProcessSpawnOutput, ProcessStatusOutput,
+    ReapedOrphan, ReaperStats, ResourceUsage, SupervisedProcess,
+    // process_signal, process_signal_check
+    ProcessSignalCheckOutput, ProcessSignalOutput, SignalVerdict,
+    // process_suspend, process_resume
+    ProcessResumeOutput, ProcessSuspendOutput,
+    // process_tag, process_tags
+    ProcessTagOutput, ProcessTagsOutput, TaggedProcess,
+    // process_tree
+    ProcessTreeNode, ProcessTreeOutput,
+    // process_wait
+    ProcessWaitOutput,
+    // process_watch, process_unwatch, process_watch_events
+    ProcessUnwatchOutput, ProcessWatchEventsOutput, ProcessWatchOutput, WatchEvent, WatchInfo,
+    // `ProcessEvent` payloads not returned by any tool
+    CpuSpike, TracedProcess,
+};
+
+/// Typed output of a tool call, parsed from its JSON content item
+///
+/// # Errors
+///
+/// When the result carries no text content or the JSON does not match `T`,
+/// e.g. because the call failed or `T` belongs to another tool.
+pub fn output<T: DeserializeOwned>(result: &CallToolResult) -> Result<T, serde_json::Error> {
+    let json = result
+        .content
+        .iter()
+        .rev()
+        .find_map(|content| content.as_text())
+        .ok_or_else(|| serde_json::Error::custom("tool result has no text content"))?;
+    serde_json::from_str(&json.text)
+}
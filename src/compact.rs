@@ -0,0 +1,36 @@
+//! Table form of listings for token-efficient output
+//!
+//! Large listings repeat every field name once per row. Compact mode sends
+//! the names once as a header and each row as a plain array instead.
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// Header row and value rows for `items`
+///
+/// The header is the union of serialized field names in first-seen order, so
+/// optional fields skipped on some rows still get a column (`null` there).
+pub(crate) fn table<T: Serialize>(items: &[T]) -> (Vec<String>, Vec<Vec<Value>>) {
+    let objects: Vec<serde_json::Map<String, Value>> = items
+        .iter()
+        .filter_map(|item| match serde_json::to_value(item) {
+            Ok(Value::Object(object)) => Some(object),
+            _ => None,
+        })
+        .collect();
+
+    let mut header: Vec<String> = Vec::new();
+    for object in &objects {
+        for key in object.keys() {
+            if !header.contains(key) {
+                header.push(key.clone());
+            }
+        }
+    }
+
+    let rows = objects
+        .into_iter()
+        .map(|mut object| header.iter().map(|key| object.remove(key).unwrap_or(Value::Null)).collect())
+        .collect();
+    (header, rows)
+}
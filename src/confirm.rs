@@ -0,0 +1,53 @@
+//! Confirmation policy for kills
+//!
+//! Decides from [`ConfirmationConfig`] whether a kill must wait for an
+//! explicit `confirm: true`. Sensitive name patterns apply to every process;
+//! the `unowned` check exempts processes started by this server.
+
+use globset::{GlobBuilder, GlobMatcher};
+
+use crate::config::ConfirmationConfig;
+
+#[derive(Default)]
+pub struct ConfirmPolicy {
+    unowned: bool,
+    sensitive: Vec<(String, GlobMatcher)>,
+}
+
+impl ConfirmPolicy {
+    /// Compile the policy; invalid patterns are logged and skipped
+    #[must_use]
+    pub fn new(config: &ConfirmationConfig) -> Self {
+        let sensitive = config
+            .sensitive
+            .iter()
+            .filter_map(|pattern| match GlobBuilder::new(pattern).case_insensitive(true).build() {
+                Ok(glob) => Some((pattern.clone(), glob.compile_matcher())),
+                Err(e) => {
+                    log::warn!("Ignoring invalid sensitive pattern {pattern:?}: {e}");
+                    None
+                }
+            })
+            .collect();
+        Self {
+            unowned: config.unowned,
+            sensitive,
+        }
+    }
+
+    /// Why killing `name` needs confirmation, if it does
+    ///
+    /// `owned` tells whether this server started the process.
+    #[must_use]
+    pub fn reason(&self, name: &str, owned: bool) -> Option<String> {
+        if let Some((pattern, _)) = self.sensitive.iter().find(|(_, matcher)| matcher.is_match(name)) {
+            return Some(format!("name matches sensitive pattern {pattern:?}"));
+        }
+        (self.unowned && !owned).then(|| "not started by this server".to_string())
+    }
+}
+
+/// Refusal text for a kill that needs confirmation
+pub(crate) fn ask(reason: &str) -> String {
+    format!("needs confirmation ({reason}); ask the user, then call again with confirm=true")
+}
@@ -0,0 +1,159 @@
+//! Processes addressed by container and in-container PID
+//!
+//! Container logs name processes by their PID in the container's namespace,
+//! which means nothing on the host. [`locate`] finds the host process behind
+//! such a reference, and [`signal`] delivers a signal the way the container's
+//! own tooling would: through the runtime (`docker`, `podman` or `nerdctl`,
+//! which talk to the Docker or containerd API) or, without one, with
+//! `nsenter` from inside the container's PID and user namespaces. That works
+//! where the host signal is refused, e.g. for rootless containers or under an
+//! LSM profile that only admits signals from the container's own processes.
+
+use kodegen_mcp_schema::McpError;
+use std::process::Command;
+use std::time::Duration;
+
+use crate::platform;
+use crate::schema::ProcessSignal;
+use crate::state::ProcessState;
+
+/// Runtime CLIs tried in order
+const RUNTIMES: &[&str] = &["docker", "podman", "nerdctl"];
+
+/// Longest one delivery attempt may take
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Container ID characters shown in summaries, as `docker ps` does
+pub(crate) const SHORT_ID: usize = 12;
+
+/// `<container>:<pid>` as passed to the kill tools
+#[derive(Debug, Clone)]
+pub(crate) struct ContainerTarget {
+    /// Container ID or a unique prefix of it, lowercased
+    pub container: String,
+    /// PID inside the container
+    pub pid: u32,
+}
+
+/// Host process behind a container reference
+#[derive(Debug, Clone)]
+pub(crate) struct Located {
+    pub host_pid: u32,
+    /// Full container ID
+    pub container_id: String,
+    pub name: String,
+}
+
+/// Parse `<container>:<pid>`
+pub(crate) fn parse(target: &str) -> Result<ContainerTarget, McpError> {
+    target
+        .rsplit_once(':')
+        .and_then(|(container, pid)| {
+            let container = container.trim().to_lowercase();
+            let pid = pid.trim().parse().ok().filter(|pid| *pid != 0)?;
+            (!container.is_empty()).then_some(ContainerTarget { container, pid })
+        })
+        .ok_or_else(|| McpError::InvalidArguments(format!("Invalid container target {target:?}; expected <container_id>:<pid>")))
+}
+
+/// Host process that is `container_pid` inside the container whose ID starts with `container`
+pub(crate) fn locate(state: &ProcessState, container: &str, container_pid: u32) -> Result<Located, McpError> {
+    if !cfg!(target_os = "linux") {
+        return Err(McpError::Other(anyhow::anyhow!("PID namespaces are only available on Linux")));
+    }
+    let mut matches: Vec<Located> = state
+        .provider
+        .enumerate()
+        .into_iter()
+        .filter(|process| {
+            platform::namespace_pids(process.pid)
+                .is_some_and(|pids| pids.len() > 1 && pids.last() == Some(&container_pid))
+        })
+        .filter_map(|process| {
+            let container_id = platform::container_id(process.pid).filter(|id| id.to_lowercase().starts_with(container))?;
+            Some(Located {
+                host_pid: process.pid,
+                container_id,
+                name: process.name,
+            })
+        })
+        .collect();
+    matches.sort_by(|a, b| a.container_id.cmp(&b.container_id));
+    matches.dedup_by(|a, b| a.container_id == b.container_id);
+    match matches.len() {
+        0 => Err(McpError::ResourceNotFound(format!(
+            "No process with PID {container_pid} in a container matching {container:?} is visible to the server"
+        ))),
+        1 => Ok(matches.remove(0)),
+        _ => {
+            let ids: Vec<&str> = matches.iter().map(|located| short(&located.container_id)).collect();
+            Err(McpError::InvalidArguments(format!(
+                "{container:?} matches several containers ({}); pass more of the ID",
+                ids.join(", ")
+            )))
+        }
+    }
+}
+
+/// Send `signal` to `container_pid` inside `container_id`; returns what delivered it
+///
+/// `host_pid` is the same process on the host, whose namespaces `nsenter`
+/// joins. The container's init (PID 1) is signalled with the runtime's own
+/// `kill`, which needs nothing inside the image; other PIDs go through
+/// `kill` of the container's shell.
+pub(crate) fn signal(container_id: &str, container_pid: u32, host_pid: u32, signal: ProcessSignal) -> Result<&'static str, String> {
+    let name = signal.name().trim_start_matches("SIG");
+    let script = format!("kill -s {name} {container_pid}");
+    let mut failures = Vec::new();
+    for runtime in RUNTIMES {
+        let mut command = Command::new(runtime);
+        if container_pid == 1 {
+            command.args(["kill", "--signal", name, container_id]);
+        } else {
+            command.args(["exec", container_id, "sh", "-c", &script]);
+        }
+        match attempt(&mut command) {
+            // Not installed
+            None => continue,
+            Some(Ok(())) => return Ok(runtime),
+            Some(Err(reason)) => failures.push(format!("{runtime}: {reason}")),
+        }
+    }
+
+    let mut command = Command::new("nsenter");
+    command.args(["--target", &host_pid.to_string(), "--pid"]);
+    // Signal permission is then checked against the container's own user mapping
+    let user_ns = |pid: &str| std::fs::read_link(format!("/proc/{pid}/ns/user")).ok();
+    if user_ns(&host_pid.to_string()) != user_ns("self") {
+        command.args(["--user", "--preserve-credentials"]);
+    }
+    command.args(["--", "kill", "-s", name, &container_pid.to_string()]);
+    match attempt(&mut command) {
+        Some(Ok(())) => Ok("nsenter"),
+        Some(Err(reason)) => {
+            failures.push(format!("nsenter: {reason}"));
+            Err(failures.join("; "))
+        }
+        None if failures.is_empty() => Err("no container runtime CLI (docker, podman, nerdctl) or nsenter found".to_string()),
+        None => Err(failures.join("; ")),
+    }
+}
+
+/// Run one delivery command: `None` when it cannot start, else why it failed
+fn attempt(command: &mut Command) -> Option<Result<(), String>> {
+    let output = platform::run_captured(command, DELIVERY_TIMEOUT)?;
+    if output.status.success() {
+        return Some(Ok(()));
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    Some(Err(stderr
+        .lines()
+        .rev()
+        .find(|line| !line.trim().is_empty())
+        .map_or_else(|| format!("exited with {}", output.status), |line| line.trim().to_string())))
+}
+
+/// First [`SHORT_ID`] characters of a hex container ID; LXC names stay whole
+pub(crate) fn short(id: &str) -> &str {
+    if id.len() == 64 { &id[..SHORT_ID] } else { id }
+}
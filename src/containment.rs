@@ -0,0 +1,137 @@
+//! cgroup v2 containment of running processes
+//!
+//! A contained PID moves into a cgroup of its own capped through
+//! `memory.max`, `cpu.max` and `pids.max`; children it forks afterwards stay
+//! inside and share the caps. Containment lasts until released, and every
+//! contained process is released when the server shuts down.
+
+use std::collections::HashMap;
+use std::io;
+use std::sync::Mutex;
+
+use crate::cpu_limit::cpu_max;
+use crate::platform::{self, CgroupLeaf};
+use crate::schema::{ContainmentCaps, ContainmentInfo};
+
+/// Controllers every managed cgroup enables, so any cap can be added later
+const CONTROLLERS: [&str; 3] = ["cpu", "memory", "pids"];
+
+struct Contained {
+    info: ContainmentInfo,
+    start_time: u64,
+    leaf: CgroupLeaf,
+}
+
+impl Contained {
+    /// Refresh the usage figures from the cgroup
+    fn sample(&mut self) -> ContainmentInfo {
+        let read = |file| platform::cgroup_get(&self.leaf, file).ok().and_then(|value| value.parse().ok());
+        self.info.memory_current_bytes = read("memory.current");
+        self.info.pids_current = read("pids.current");
+        self.info.clone()
+    }
+}
+
+#[derive(Default)]
+pub struct Containments {
+    contained: Mutex<HashMap<u32, Contained>>,
+}
+
+impl Containments {
+    /// Contain `pid` with `caps`, or change the given caps of its existing containment
+    ///
+    /// Returns the containment and whether it already existed.
+    pub fn contain(&self, pid: u32, name: &str, start_time: u64, caps: ContainmentCaps) -> io::Result<(ContainmentInfo, bool)> {
+        let mut contained = self.lock();
+        // Left behind by an earlier process with the same PID
+        if contained.get(&pid).is_some_and(|entry| entry.start_time != start_time)
+            && let Some(stale) = contained.remove(&pid)
+        {
+            let _ = platform::cgroup_release(&stale.leaf);
+        }
+
+        if let Some(entry) = contained.get_mut(&pid) {
+            apply(&entry.leaf, caps)?;
+            let current = &mut entry.info.caps;
+            current.memory_max_bytes = caps.memory_max_bytes.or(current.memory_max_bytes);
+            current.cpu_percent = caps.cpu_percent.or(current.cpu_percent);
+            current.pids_max = caps.pids_max.or(current.pids_max);
+            return Ok((entry.sample(), true));
+        }
+
+        if caps.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "pass at least one of memory_max_bytes, cpu_percent or pids_max",
+            ));
+        }
+        let leaf = platform::cgroup_confine(pid, &CONTROLLERS)?;
+        if let Err(e) = apply(&leaf, caps) {
+            let _ = platform::cgroup_release(&leaf);
+            return Err(e);
+        }
+        let mut entry = Contained {
+            info: ContainmentInfo {
+                pid,
+                name: name.to_string(),
+                cgroup: leaf.path.display().to_string(),
+                caps,
+                memory_current_bytes: None,
+                pids_current: None,
+                since: chrono::Utc::now().to_rfc3339(),
+            },
+            start_time,
+            leaf,
+        };
+        let info = entry.sample();
+        contained.insert(pid, entry);
+        Ok((info, false))
+    }
+
+    /// Release `pid`; `Ok(None)` if it was not contained
+    pub fn release(&self, pid: u32) -> io::Result<Option<ContainmentInfo>> {
+        let Some(mut entry) = self.lock().remove(&pid) else {
+            return Ok(None);
+        };
+        let info = entry.sample();
+        platform::cgroup_release(&entry.leaf)?;
+        Ok(Some(info))
+    }
+
+    /// Containment of this exact process with current usage, if any
+    #[must_use]
+    pub fn get(&self, pid: u32, start_time: u64) -> Option<ContainmentInfo> {
+        self.lock()
+            .get_mut(&pid)
+            .filter(|entry| entry.start_time == start_time)
+            .map(Contained::sample)
+    }
+
+    /// Release every contained process, e.g. at shutdown
+    pub fn release_all(&self) {
+        for (_, entry) in self.lock().drain() {
+            if let Err(e) = platform::cgroup_release(&entry.leaf) {
+                log::warn!("Failed to release cgroup {}: {e}", entry.leaf.path.display());
+            }
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<u32, Contained>> {
+        self.contained.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Write every given cap to the cgroup's control files
+fn apply(leaf: &CgroupLeaf, caps: ContainmentCaps) -> io::Result<()> {
+    let writes = [
+        ("memory.max", caps.memory_max_bytes.map(|bytes| bytes.to_string())),
+        ("cpu.max", caps.cpu_percent.map(cpu_max)),
+        ("pids.max", caps.pids_max.map(|pids| pids.to_string())),
+    ];
+    for (file, value) in writes {
+        if let Some(value) = value {
+            platform::cgroup_set(leaf, file, &value).map_err(|e| io::Error::new(e.kind(), format!("writing {file}: {e}")))?;
+        }
+    }
+    Ok(())
+}
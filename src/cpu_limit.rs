@@ -0,0 +1,335 @@
+//! CPU limits for running processes
+//!
+//! A limit caps one PID at a share of one core. Where cgroup v2 offers the
+//! cpu controller the process moves into a cgroup of its own capped through
+//! `cpu.max`; elsewhere a thread duty-cycles it with SIGSTOP/SIGCONT, letting
+//! it run for `percent` of every 100 ms as cpulimit does. A limit ends when
+//! lifted, when the process exits or when the server shuts down, and a
+//! duty-cycled process is always left running.
+
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use sysinfo::{Pid, ProcessesToUpdate, System};
+
+use crate::platform::{self, CgroupLeaf};
+use crate::schema::{CpuLimitInfo, CpuLimitMethod};
+
+/// Duty cycle length of the signals method
+const DUTY_PERIOD: Duration = Duration::from_millis(100);
+
+/// `cpu.max` period in microseconds
+const CPU_MAX_PERIOD_US: u64 = 100_000;
+
+/// How often a duty-cycled PID is checked for exit or reuse
+const IDENTITY_CHECK: Duration = Duration::from_secs(1);
+
+enum Enforcement {
+    Cgroup(CgroupLeaf),
+    Signals {
+        percent: Arc<AtomicU32>,
+        stop: Arc<AtomicBool>,
+        thread: Option<JoinHandle<()>>,
+    },
+}
+
+struct Limit {
+    info: CpuLimitInfo,
+    start_time: u64,
+    enforcement: Enforcement,
+}
+
+impl Limit {
+    /// Undo the enforcement; duty-cycled processes are resumed before this returns
+    fn end(self) -> Result<(), String> {
+        match self.enforcement {
+            Enforcement::Cgroup(leaf) => platform::cgroup_release(&leaf)
+                .map_err(|e| format!("Failed to release cgroup {}: {e}", leaf.path.display())),
+            Enforcement::Signals { stop, thread, .. } => {
+                stop.store(true, Ordering::SeqCst);
+                if let Some(thread) = thread {
+                    let _ = thread.join();
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Result of [`CpuLimiter::limit`]
+#[derive(Debug, Clone)]
+pub struct AppliedLimit {
+    pub info: CpuLimitInfo,
+    /// An existing limit was changed
+    pub updated: bool,
+    /// Why the cgroup method was passed over when no method was requested
+    pub fallback_reason: Option<String>,
+}
+
+#[derive(Default)]
+pub struct CpuLimiter {
+    limits: Mutex<HashMap<u32, Limit>>,
+}
+
+impl CpuLimiter {
+    /// Highest percent `method` can enforce
+    #[must_use]
+    pub fn max_percent(method: CpuLimitMethod) -> u32 {
+        match method {
+            // Running for the whole period is no limit at all
+            CpuLimitMethod::Signals => 99,
+            CpuLimitMethod::Cgroup => {
+                let cores = std::thread::available_parallelism().map_or(1, NonZeroUsize::get);
+                100 * u32::try_from(cores).unwrap_or(u32::MAX / 100)
+            }
+        }
+    }
+
+    /// Cap `pid` at `percent` of one core, or change the percent of its existing limit
+    ///
+    /// Without a `method` the cgroup method is tried first and signals used
+    /// when it is unavailable.
+    pub fn limit(
+        self: &Arc<Self>,
+        pid: u32,
+        name: &str,
+        start_time: u64,
+        percent: u32,
+        method: Option<CpuLimitMethod>,
+    ) -> Result<AppliedLimit, String> {
+        if percent == 0 {
+            return Err("percent must be at least 1".to_string());
+        }
+        // A limit left behind by an earlier process with the same PID
+        let stale = {
+            let mut limits = self.lock();
+            match limits.get(&pid) {
+                Some(limit) if limit.start_time != start_time => limits.remove(&pid),
+                _ => None,
+            }
+        };
+        if let Some(stale) = stale {
+            let _ = stale.end();
+        }
+
+        let mut limits = self.lock();
+        if let Some(existing) = limits.get_mut(&pid) {
+            let current = existing.info.method;
+            if method.is_some_and(|method| method != current) {
+                return Err(format!(
+                    "PID {pid} is already limited via {}; lift the limit first",
+                    current.name()
+                ));
+            }
+            check(current, percent)?;
+            match &existing.enforcement {
+                Enforcement::Cgroup(leaf) => platform::cgroup_set(leaf, "cpu.max", &cpu_max(percent))
+                    .map_err(|e| format!("Failed to update cpu.max: {e}"))?,
+                Enforcement::Signals { percent: shared, .. } => shared.store(percent, Ordering::SeqCst),
+            }
+            existing.info.percent = percent;
+            return Ok(AppliedLimit {
+                info: existing.info.clone(),
+                updated: true,
+                fallback_reason: None,
+            });
+        }
+
+        let (enforcement, fallback_reason) = match method {
+            Some(CpuLimitMethod::Cgroup) => (confine(pid, percent)?, None),
+            Some(CpuLimitMethod::Signals) => (self.duty_cycle(pid, start_time, percent)?, None),
+            None => match confine(pid, percent) {
+                Ok(enforcement) => (enforcement, None),
+                Err(reason) => {
+                    let enforcement = self
+                        .duty_cycle(pid, start_time, percent)
+                        .map_err(|e| format!("{e} (cgroup method: {reason})"))?;
+                    (enforcement, Some(reason))
+                }
+            },
+        };
+        let (method, cgroup) = match &enforcement {
+            Enforcement::Cgroup(leaf) => (CpuLimitMethod::Cgroup, Some(leaf.path.display().to_string())),
+            Enforcement::Signals { .. } => (CpuLimitMethod::Signals, None),
+        };
+        let info = CpuLimitInfo {
+            pid,
+            name: name.to_string(),
+            percent,
+            method,
+            cgroup,
+            since: chrono::Utc::now().to_rfc3339(),
+        };
+        limits.insert(
+            pid,
+            Limit {
+                info: info.clone(),
+                start_time,
+                enforcement,
+            },
+        );
+        Ok(AppliedLimit {
+            info,
+            updated: false,
+            fallback_reason,
+        })
+    }
+
+    /// Lift the limit on `pid`; `Ok(None)` if it had none
+    pub fn lift(&self, pid: u32) -> Result<Option<CpuLimitInfo>, String> {
+        let Some(limit) = self.lock().remove(&pid) else {
+            return Ok(None);
+        };
+        let info = limit.info.clone();
+        limit.end()?;
+        Ok(Some(info))
+    }
+
+    /// The limit on this exact process, if any
+    #[must_use]
+    pub fn get(&self, pid: u32, start_time: u64) -> Option<CpuLimitInfo> {
+        self.lock()
+            .get(&pid)
+            .filter(|limit| limit.start_time == start_time)
+            .map(|limit| limit.info.clone())
+    }
+
+    /// Active limits by PID, after dropping those whose process exited
+    #[must_use]
+    pub fn list(&self) -> Vec<CpuLimitInfo> {
+        let pids: Vec<Pid> = self.lock().keys().map(|pid| Pid::from_u32(*pid)).collect();
+        let mut system = System::new();
+        system.refresh_processes(ProcessesToUpdate::Some(&pids), true);
+
+        let (gone, mut infos) = {
+            let mut limits = self.lock();
+            let exited: Vec<u32> = limits
+                .iter()
+                .filter(|(pid, limit)| {
+                    system
+                        .process(Pid::from_u32(**pid))
+                        .is_none_or(|process| process.start_time() != limit.start_time)
+                })
+                .map(|(pid, _)| *pid)
+                .collect();
+            let gone: Vec<Limit> = exited.iter().filter_map(|pid| limits.remove(pid)).collect();
+            let infos: Vec<CpuLimitInfo> = limits.values().map(|limit| limit.info.clone()).collect();
+            (gone, infos)
+        };
+        for limit in gone {
+            if let Err(e) = limit.end() {
+                log::warn!("{e}");
+            }
+        }
+        infos.sort_by_key(|info| info.pid);
+        infos
+    }
+
+    /// Lift every limit, e.g. at shutdown
+    pub fn release_all(&self) {
+        let limits: Vec<Limit> = self.lock().drain().map(|(_, limit)| limit).collect();
+        // Signal every duty cycle first so they wind down together
+        for limit in &limits {
+            if let Enforcement::Signals { stop, .. } = &limit.enforcement {
+                stop.store(true, Ordering::SeqCst);
+            }
+        }
+        for limit in limits {
+            if let Err(e) = limit.end() {
+                log::warn!("{e}");
+            }
+        }
+    }
+
+    fn duty_cycle(self: &Arc<Self>, pid: u32, start_time: u64, percent: u32) -> Result<Enforcement, String> {
+        check(CpuLimitMethod::Signals, percent)?;
+        if !cfg!(unix) {
+            return Err("SIGSTOP/SIGCONT duty cycling needs a Unix platform".to_string());
+        }
+        let shared = Arc::new(AtomicU32::new(percent));
+        let stop = Arc::new(AtomicBool::new(false));
+        let limiter = self.clone();
+        let (cycle_percent, cycle_stop) = (shared.clone(), stop.clone());
+        let thread = std::thread::Builder::new()
+            .name(format!("cpu-limit-{pid}"))
+            .spawn(move || limiter.cycle(pid, start_time, &cycle_percent, &cycle_stop))
+            .map_err(|e| format!("Failed to start the duty cycle: {e}"))?;
+        Ok(Enforcement::Signals {
+            percent: shared,
+            stop,
+            thread: Some(thread),
+        })
+    }
+
+    /// Duty cycle loop; ends with the process running
+    fn cycle(&self, pid: u32, start_time: u64, percent: &AtomicU32, stop: &AtomicBool) {
+        let mut system = System::new();
+        let mut checked = Instant::now();
+        let alive = loop {
+            if stop.load(Ordering::SeqCst) {
+                break true;
+            }
+            let run = DUTY_PERIOD * percent.load(Ordering::SeqCst) / 100;
+            if platform::continue_process(pid).is_err() {
+                break false;
+            }
+            std::thread::sleep(run);
+            if stop.load(Ordering::SeqCst) {
+                break true;
+            }
+            if platform::stop_process(pid).is_err() {
+                break false;
+            }
+            std::thread::sleep(DUTY_PERIOD.saturating_sub(run));
+            if checked.elapsed() >= IDENTITY_CHECK {
+                checked = Instant::now();
+                system.refresh_processes(ProcessesToUpdate::Some(&[Pid::from_u32(pid)]), true);
+                if system
+                    .process(Pid::from_u32(pid))
+                    .is_none_or(|process| process.start_time() != start_time)
+                {
+                    break false;
+                }
+            }
+        };
+        if alive {
+            let _ = platform::continue_process(pid);
+        } else {
+            let mut limits = self.lock();
+            if limits.get(&pid).is_some_and(|limit| limit.start_time == start_time) {
+                limits.remove(&pid);
+            }
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<u32, Limit>> {
+        self.limits.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn check(method: CpuLimitMethod, percent: u32) -> Result<(), String> {
+    let max = CpuLimiter::max_percent(method);
+    if percent > max {
+        return Err(format!("the {} method allows at most {max} percent", method.name()));
+    }
+    Ok(())
+}
+
+/// `cpu.max` value allowing `percent` of one core per period
+pub(crate) fn cpu_max(percent: u32) -> String {
+    format!("{} {CPU_MAX_PERIOD_US}", CPU_MAX_PERIOD_US * u64::from(percent) / 100)
+}
+
+fn confine(pid: u32, percent: u32) -> Result<Enforcement, String> {
+    check(CpuLimitMethod::Cgroup, percent)?;
+    let leaf = platform::cgroup_confine(pid, &["cpu"]).map_err(|e| format!("cgroup unavailable: {e}"))?;
+    if let Err(e) = platform::cgroup_set(&leaf, "cpu.max", &cpu_max(percent)) {
+        let _ = platform::cgroup_release(&leaf);
+        return Err(format!("Failed to set cpu.max: {e}"));
+    }
+    Ok(Enforcement::Cgroup(leaf))
+}
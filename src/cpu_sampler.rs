@@ -0,0 +1,62 @@
+//! CPU usage for native process enumerations
+//!
+//! sysinfo derives CPU percentages from two of its own refreshes. The native
+//! backends only report cumulative CPU time, so the previous listing's times
+//! are kept here and usage is the difference over the elapsed wall time. A
+//! process seen for the first time reports its lifetime average, like `ps`.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use crate::platform::NativeProcess;
+
+/// Shorter intervals are too noisy to divide by
+const MIN_INTERVAL_SECS: f64 = 0.05;
+
+struct Sample {
+    at: Instant,
+    /// Cumulative CPU seconds keyed by (pid, start_time)
+    cpu_secs: HashMap<(u32, u64), f64>,
+}
+
+#[derive(Default)]
+pub struct CpuSampler {
+    last: Mutex<Option<Sample>>,
+}
+
+impl CpuSampler {
+    /// CPU percent of each process (100 = one full core), in input order
+    pub(crate) fn sample(&self, processes: &[NativeProcess]) -> Vec<f32> {
+        let now = Instant::now();
+        let mut last = self.last.lock().unwrap_or_else(|e| e.into_inner());
+        let previous = last
+            .as_ref()
+            .map(|sample| (now.duration_since(sample.at).as_secs_f64(), &sample.cpu_secs))
+            .filter(|(elapsed, _)| *elapsed >= MIN_INTERVAL_SECS);
+
+        let percents = processes
+            .iter()
+            .map(|process| {
+                let since_last = previous.and_then(|(elapsed, cpu_secs)| {
+                    let before = cpu_secs.get(&(process.pid, process.start_time))?;
+                    Some((process.cpu_secs - before).max(0.0) / elapsed)
+                });
+                let fraction = since_last.unwrap_or_else(|| match process.age_secs {
+                    age if age > 0.0 => process.cpu_secs / age,
+                    _ => 0.0,
+                });
+                (fraction * 100.0) as f32
+            })
+            .collect();
+
+        *last = Some(Sample {
+            at: now,
+            cpu_secs: processes
+                .iter()
+                .map(|process| ((process.pid, process.start_time), process.cpu_secs))
+                .collect(),
+        });
+        percents
+    }
+}
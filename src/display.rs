@@ -0,0 +1,130 @@
+//! Human-readable summary rendering shared by all process tools
+//!
+//! Every tool builds its summary through these helpers so that `plain` output
+//! never contains escape codes, private-use glyphs or other non-ASCII marks
+//! such as arrows and ellipses.
+
+use crate::schema::SummaryStyle;
+
+/// Nerd Font glyphs used in ANSI summaries
+pub(crate) const ICON_KILL: char = '\u{f057}';
+pub(crate) const ICON_INFO: char = '\u{f05a}';
+
+/// Summary colors (ANSI 16-color palette)
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Color {
+    Red,
+    Green,
+    Cyan,
+}
+
+impl Color {
+    fn code(self) -> u8 {
+        match self {
+            Self::Red => 31,
+            Self::Green => 32,
+            Self::Cyan => 36,
+        }
+    }
+}
+
+impl SummaryStyle {
+    /// Pick the per-call style if given, otherwise the server default
+    #[must_use]
+    pub(crate) fn resolve(per_call: Option<Self>, default: Self) -> Self {
+        per_call.unwrap_or(default)
+    }
+
+    /// Wrap text in a color (no-op in plain style)
+    pub(crate) fn paint(self, color: Color, text: &str) -> String {
+        match self {
+            Self::Ansi => format!("\x1b[{}m{}\x1b[0m", color.code(), text),
+            Self::Plain => text.to_string(),
+        }
+    }
+
+    /// Icon prefix followed by a space (empty in plain style)
+    pub(crate) fn icon(self, glyph: char) -> String {
+        match self {
+            Self::Ansi => format!("{glyph} "),
+            Self::Plain => String::new(),
+        }
+    }
+
+    /// Line bullet
+    pub(crate) fn bullet(self) -> &'static str {
+        match self {
+            Self::Ansi => "•",
+            Self::Plain => "-",
+        }
+    }
+
+    /// Separator between fields on one line
+    pub(crate) fn sep(self) -> &'static str {
+        match self {
+            Self::Ansi => " · ",
+            Self::Plain => " | ",
+        }
+    }
+
+    /// Arrow from a before value to an after value, or along a chain
+    pub(crate) fn arrow(self) -> &'static str {
+        match self {
+            Self::Ansi => " → ",
+            Self::Plain => " -> ",
+        }
+    }
+
+    /// Arrow pointing back, e.g. from a process to its parent
+    pub(crate) fn back_arrow(self) -> &'static str {
+        match self {
+            Self::Ansi => " ← ",
+            Self::Plain => " <- ",
+        }
+    }
+
+    /// Marker for omitted items
+    pub(crate) fn ellipsis(self) -> &'static str {
+        match self {
+            Self::Ansi => "…",
+            Self::Plain => "...",
+        }
+    }
+
+    /// Label of the coefficient of determination of a fit
+    pub(crate) fn r_squared(self) -> &'static str {
+        match self {
+            Self::Ansi => "R²",
+            Self::Plain => "R^2",
+        }
+    }
+}
+
+/// Number of rows embedded in `detailed` summaries
+pub(crate) const DETAIL_ROWS: usize = 10;
+
+/// Size in binary units with two decimals, e.g. "1.42 GiB" (whole bytes below 1 KiB)
+pub(crate) fn human_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 6] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.2} {}", UNITS[unit])
+    }
+}
+
+/// Truncate to `width` characters (UTF-8 safe), marking the cut with "..."
+pub(crate) fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() > width {
+        let kept: String = text.chars().take(width.saturating_sub(3)).collect();
+        format!("{kept}...")
+    } else {
+        text.to_string()
+    }
+}
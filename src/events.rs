@@ -0,0 +1,110 @@
+//! Process event bus
+//!
+//! Lifecycle events from the watch monitor, the supervisor, the kill tools,
+//! the memory watchdog, CPU spike detection and the exec tracer
+//! are fanned out to in-process subscribers such as the `/events` WebSocket
+//! and the gRPC `Watch` stream. Publishing never blocks; a subscriber that
+//! falls more than [`SUBSCRIBER_CAPACITY`] events behind misses the oldest.
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+use crate::schema::{CpuSpike, KilledProcess, OomIntervention, SupervisedProcess, TracedProcess, WatchEvent};
+
+/// Events buffered per subscriber before it starts missing some
+pub const SUBSCRIBER_CAPACITY: usize = 256;
+
+/// One event, serialized with its kind in a `type` field
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ProcessEvent {
+    /// A watched process exited
+    Exited(WatchEvent),
+    /// This server launched a process
+    Spawned(SupervisedProcess),
+    /// A process launched by this server exited
+    Finished(SupervisedProcess),
+    /// A process was terminated through this server
+    Killed(KilledProcess),
+    /// The memory watchdog killed or froze a process over its ceiling
+    OomGuard(OomIntervention),
+    /// A process jumped from idle to pegged and stayed there
+    CpuSpike(CpuSpike),
+    /// The kernel traced an exec, including of processes too short-lived to be listed
+    Exec(TracedProcess),
+    /// The kernel traced a process exit
+    Exit(TracedProcess),
+}
+
+impl ProcessEvent {
+    /// Every value of the `type` field
+    pub const KINDS: &[&str] = &["exited", "spawned", "finished", "killed", "oom_guard", "cpu_spike", "exec", "exit"];
+
+    /// Value of the `type` field
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Exited(_) => "exited",
+            Self::Spawned(_) => "spawned",
+            Self::Finished(_) => "finished",
+            Self::Killed(_) => "killed",
+            Self::OomGuard(_) => "oom_guard",
+            Self::CpuSpike(_) => "cpu_spike",
+            Self::Exec(_) => "exec",
+            Self::Exit(_) => "exit",
+        }
+    }
+
+    /// PID the event is about
+    #[must_use]
+    pub fn pid(&self) -> u32 {
+        match self {
+            Self::Exited(event) => event.pid,
+            Self::Spawned(process) | Self::Finished(process) => process.pid,
+            Self::Killed(process) => process.pid,
+            Self::OomGuard(intervention) => intervention.pid,
+            Self::CpuSpike(spike) => spike.pid,
+            Self::Exec(process) | Self::Exit(process) => process.pid,
+        }
+    }
+
+    /// Process name, or the command line for processes launched here
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Exited(event) => &event.name,
+            Self::Spawned(process) | Self::Finished(process) => &process.command,
+            Self::Killed(process) => &process.name,
+            Self::OomGuard(intervention) => &intervention.name,
+            Self::CpuSpike(spike) => &spike.name,
+            Self::Exec(process) | Self::Exit(process) => &process.name,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct EventBus {
+    feed: broadcast::Sender<ProcessEvent>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self {
+            feed: broadcast::channel(SUBSCRIBER_CAPACITY).0,
+        }
+    }
+}
+
+impl EventBus {
+    /// Deliver `event` to every current subscriber
+    pub fn publish(&self, event: ProcessEvent) {
+        // No subscribers is the normal case
+        let _ = self.feed.send(event);
+    }
+
+    /// Receive every event published from now on
+    #[must_use]
+    pub fn subscribe(&self) -> broadcast::Receiver<ProcessEvent> {
+        self.feed.subscribe()
+    }
+}
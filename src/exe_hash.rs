@@ -0,0 +1,82 @@
+//! SHA-256 digests of process executables
+//!
+//! Hashing reads the whole binary, so a digest is cached by path until the
+//! file's modification time or size changes. Bytes are read from the image
+//! the process actually runs (`/proc/<pid>/exe` on Linux), so a binary that was
+//! deleted or replaced since the process started still hashes to what is in
+//! memory; such digests are not cached under the path.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use sha2::{Digest, Sha256};
+
+use crate::platform;
+
+/// Paths kept before the cache starts over
+const CAPACITY: usize = 4096;
+
+struct Cached {
+    modified: SystemTime,
+    len: u64,
+    sha256: String,
+}
+
+#[derive(Default)]
+pub struct ExeHashes {
+    cache: Mutex<HashMap<PathBuf, Cached>>,
+}
+
+impl ExeHashes {
+    /// Hex SHA-256 of the executable `pid` runs; `None` if it cannot be read
+    ///
+    /// Blocks for as long as reading the file takes on a cache miss.
+    pub fn sha256(&self, pid: u32) -> Option<String> {
+        let path = platform::exe_path(pid)?;
+        let image = platform::exe_image(pid)?;
+        let metadata = std::fs::metadata(&image).ok()?;
+        let modified = metadata.modified().ok()?;
+        // Linux marks the link target of an unlinked binary this way
+        let replaced = path.to_string_lossy().ends_with(" (deleted)");
+
+        if !replaced
+            && let Some(cached) = self.lock().get(&path)
+            && cached.modified == modified
+            && cached.len == metadata.len()
+        {
+            return Some(cached.sha256.clone());
+        }
+
+        let sha256 = hash_file(&image)?;
+        if !replaced {
+            let mut cache = self.lock();
+            if cache.len() >= CAPACITY {
+                cache.clear();
+            }
+            cache.insert(path, Cached { modified, len: metadata.len(), sha256: sha256.clone() });
+        }
+        Some(sha256)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<PathBuf, Cached>> {
+        self.cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn hash_file(path: &std::path::Path) -> Option<String> {
+    let mut file = std::fs::File::open(path).ok()?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; 64 * 1024];
+    loop {
+        match file.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => hasher.update(&buffer[..read]),
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(_) => return None,
+        }
+    }
+    Some(hasher.finalize().iter().map(|byte| format!("{byte:02x}")).collect())
+}
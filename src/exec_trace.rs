@@ -0,0 +1,136 @@
+//! Exec and exit events from the kernel
+//!
+//! Process tables only show what is alive when they are read, so a compiler
+//! or hook that runs for 50 ms between two listings is never seen. With the
+//! `ebpf` feature on Linux the tracer in [`crate::platform`] reports every
+//! exec and every process exit as it happens, and a dedicated thread turns
+//! them into `exec` and `exit` events on the bus. The exec of each live
+//! process is remembered so its exit can carry the filename and runtime.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use crate::config::ProcessConfig;
+use crate::events::ProcessEvent;
+use crate::platform::{self, ExecTracer, TraceKind, TraceRecord};
+use crate::schema::TracedProcess;
+use crate::state::ProcessState;
+
+/// Wait per poll, after which dropped events are checked
+const POLL_MS: i32 = 1000;
+
+/// Execs remembered for their exits before the ones of exited processes are pruned
+const MAX_TRACKED: usize = 65_536;
+
+/// Exec of a process that has not exited yet
+struct Started {
+    parent: Option<u32>,
+    filename: Option<String>,
+    monotonic_ns: u64,
+}
+
+/// Start tracing unless disabled
+///
+/// Failing to load the programs (no privileges, old kernel, no tracefs) only
+/// logs a warning: the server runs on without the events.
+pub(crate) fn start(config: &ProcessConfig, state: Arc<ProcessState>) {
+    if !config.exec_trace {
+        return;
+    }
+    let mut tracer = match ExecTracer::open() {
+        Ok(tracer) => tracer,
+        Err(e) => {
+            log::warn!("{}", platform::exec_trace_unavailable(&e));
+            return;
+        }
+    };
+    let spawned = std::thread::Builder::new()
+        .name("exec-trace".to_string())
+        .spawn(move || run(&mut tracer, &state));
+    match spawned {
+        Ok(_) => log::info!("Tracing process exec and exit with eBPF"),
+        Err(e) => log::warn!("Failed to start the exec tracer thread: {e}"),
+    }
+}
+
+fn run(tracer: &mut ExecTracer, state: &ProcessState) {
+    // Tracepoints stamp CLOCK_MONOTONIC; events report wall-clock time
+    let wall_offset_ns = Utc::now().timestamp_nanos_opt().unwrap_or_default() - i64::try_from(monotonic_ns()).unwrap_or_default();
+    let mut started: HashMap<u32, Started> = HashMap::new();
+    let mut lost = 0;
+    loop {
+        let polled = tracer.poll(POLL_MS, |record| {
+            let event = traced(record, wall_offset_ns, &mut started);
+            state.events.publish(event);
+        });
+        if let Err(e) = polled {
+            log::warn!("Exec tracing stopped: {e}");
+            return;
+        }
+        let dropped = tracer.lost();
+        if dropped > lost {
+            log::warn!("{} exec/exit events were dropped: the trace ring buffer was full", dropped - lost);
+            lost = dropped;
+        }
+        if started.len() > MAX_TRACKED {
+            // Exits can be dropped too; forget processes that are gone
+            started.retain(|pid, _| std::path::Path::new(&format!("/proc/{pid}")).exists());
+        }
+    }
+}
+
+/// Bus event for one record, updating the remembered execs
+fn traced(record: TraceRecord, wall_offset_ns: i64, started: &mut HashMap<u32, Started>) -> ProcessEvent {
+    let at = DateTime::from_timestamp_nanos(i64::try_from(record.monotonic_ns).unwrap_or_default() + wall_offset_ns).to_rfc3339();
+    match record.kind {
+        TraceKind::Exec => {
+            // Read right away; a process that already exited keeps no parent
+            let parent = parent(record.pid);
+            started.insert(
+                record.pid,
+                Started {
+                    parent,
+                    filename: record.filename.clone(),
+                    monotonic_ns: record.monotonic_ns,
+                },
+            );
+            ProcessEvent::Exec(TracedProcess {
+                pid: record.pid,
+                parent,
+                name: record.comm,
+                filename: record.filename,
+                at,
+                runtime_secs: None,
+            })
+        }
+        TraceKind::Exit => {
+            let exec = started.remove(&record.pid);
+            ProcessEvent::Exit(TracedProcess {
+                pid: record.pid,
+                parent: exec.as_ref().and_then(|exec| exec.parent),
+                name: record.comm,
+                runtime_secs: exec
+                    .as_ref()
+                    .map(|exec| record.monotonic_ns.saturating_sub(exec.monotonic_ns) as f64 / 1e9),
+                filename: exec.and_then(|exec| exec.filename),
+                at,
+            })
+        }
+    }
+}
+
+/// Parent PID from `/proc/<pid>/stat`
+fn parent(pid: u32) -> Option<u32> {
+    let stat = std::fs::read_to_string(format!("/proc/{pid}/stat")).ok()?;
+    // "pid (comm) state ppid ..."; comm may contain spaces and parentheses
+    stat[stat.rfind(')')? + 1..].split_whitespace().nth(1)?.parse().ok()
+}
+
+fn monotonic_ns() -> u64 {
+    let mut now = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+    // SAFETY: now is a valid out-pointer.
+    unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut now) };
+    u64::try_from(now.tv_sec).unwrap_or_default() * 1_000_000_000 + u64::try_from(now.tv_nsec).unwrap_or_default()
+}
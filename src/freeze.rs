@@ -0,0 +1,220 @@
+//! Freezing whole process trees
+//!
+//! The cgroup method moves the tree into a cgroup of its own and writes
+//! `cgroup.freeze`, which stops every member at once; children forked by a
+//! member land in the same cgroup and are frozen with it. Without cgroup v2
+//! the tree is stopped with SIGSTOP from the root down, re-scanning until a
+//! pass finds no running child. Every frozen tree is thawed when the server
+//! shuts down.
+
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::platform::{self, CgroupLeaf};
+use crate::provider::{ProcessEntry, ProcessProvider};
+use crate::schema::{FreezeInfo, FreezeMethod};
+use crate::tree;
+
+/// Enumeration passes before a tree that keeps growing is given up on
+const SCAN_ROUNDS: usize = 8;
+
+/// How long the kernel gets to confirm a freeze or thaw
+const SETTLE: Duration = Duration::from_secs(1);
+
+struct Frozen {
+    info: FreezeInfo,
+    start_time: u64,
+    leaf: Option<CgroupLeaf>,
+}
+
+impl Frozen {
+    /// Resume every member; signalled ones last-stopped first
+    fn thaw(&self) -> io::Result<()> {
+        if let Some(leaf) = &self.leaf {
+            platform::cgroup_freeze(leaf, false, SETTLE)?;
+            platform::cgroup_release(leaf)?;
+        }
+        for pid in self.info.signalled.iter().rev() {
+            let _ = platform::continue_process(*pid);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct Freezes {
+    frozen: Mutex<HashMap<u32, Frozen>>,
+}
+
+impl Freezes {
+    /// Freeze `root` and all its descendants
+    ///
+    /// Without a `method` the cgroup freezer is tried first; the second value is
+    /// why it was not used.
+    pub fn freeze(
+        &self,
+        provider: &dyn ProcessProvider,
+        root: &ProcessEntry,
+        method: Option<FreezeMethod>,
+    ) -> io::Result<(FreezeInfo, Option<String>)> {
+        let mut frozen = self.lock();
+        if let Some(existing) = frozen.get(&root.pid) {
+            if existing.start_time == root.start_time {
+                return Err(io::Error::new(io::ErrorKind::AlreadyExists, "tree is already frozen"));
+            }
+            // Left behind by an earlier process with the same PID
+            if let Some(stale) = frozen.remove(&root.pid)
+                && let Some(leaf) = &stale.leaf
+            {
+                let _ = platform::cgroup_freeze(leaf, false, SETTLE);
+                let _ = platform::cgroup_release(leaf);
+            }
+        }
+
+        let mut fallback_reason = None;
+        let attempt = match method {
+            Some(FreezeMethod::Signals) => None,
+            Some(FreezeMethod::Cgroup) => Some(freeze_cgroup(provider, root.pid)?),
+            None => match freeze_cgroup(provider, root.pid) {
+                Ok(attempt) => Some(attempt),
+                Err(e) => {
+                    fallback_reason = Some(format!("cgroup freezer unavailable: {e}"));
+                    None
+                }
+            },
+        };
+        let (method, leaf, members, signalled, settled) = match attempt {
+            Some((leaf, members, signalled, settled)) => (FreezeMethod::Cgroup, Some(leaf), members, signalled, settled),
+            None => {
+                platform::stop_process(root.pid)?;
+                let mut signalled = vec![root.pid];
+                let settled = stop_tree(provider, root.pid, &HashSet::new(), &mut signalled);
+                (FreezeMethod::Signals, None, Vec::new(), signalled, settled)
+            }
+        };
+
+        let mut all: Vec<u32> = members.into_iter().chain(signalled.iter().copied()).collect();
+        all.sort_unstable();
+        all.dedup();
+        let info = FreezeInfo {
+            pid: root.pid,
+            name: root.name.clone(),
+            method,
+            members: all,
+            signalled,
+            cgroup: leaf.as_ref().map(|leaf| leaf.path.display().to_string()),
+            settled,
+            since: chrono::Utc::now().to_rfc3339(),
+        };
+        frozen.insert(
+            root.pid,
+            Frozen {
+                info: info.clone(),
+                start_time: root.start_time,
+                leaf,
+            },
+        );
+        Ok((info, fallback_reason))
+    }
+
+    /// Thaw the tree rooted at `pid`; `Ok(None)` if it was not frozen
+    pub fn thaw(&self, pid: u32) -> io::Result<Option<FreezeInfo>> {
+        let mut frozen = self.lock();
+        let Some(entry) = frozen.get(&pid) else {
+            return Ok(None);
+        };
+        entry.thaw()?;
+        Ok(frozen.remove(&pid).map(|entry| entry.info))
+    }
+
+    /// Freeze rooted at this exact process, if any
+    #[must_use]
+    pub fn get(&self, pid: u32, start_time: u64) -> Option<FreezeInfo> {
+        self.lock()
+            .get(&pid)
+            .filter(|entry| entry.start_time == start_time)
+            .map(|entry| entry.info.clone())
+    }
+
+    /// Thaw every frozen tree, e.g. at shutdown
+    pub fn release_all(&self) {
+        for (pid, entry) in self.lock().drain() {
+            if let Err(e) = entry.thaw() {
+                log::warn!("Failed to thaw process tree {pid}: {e}");
+            }
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<u32, Frozen>> {
+        self.frozen.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Gather the tree into a fresh cgroup and freeze it
+///
+/// Returns the cgroup, its frozen members, descendants living in other cgroups
+/// that were stopped with SIGSTOP instead, and whether the freeze settled.
+fn freeze_cgroup(provider: &dyn ProcessProvider, root: u32) -> io::Result<(CgroupLeaf, Vec<u32>, Vec<u32>, bool)> {
+    let leaf = platform::cgroup_confine(root, &[])?;
+    // Forks by moved processes already land in the leaf; re-scan for those
+    // made by descendants that had not been moved yet
+    let mut seen = HashSet::from([root]);
+    for _ in 0..SCAN_ROUNDS {
+        let processes = provider.enumerate();
+        let fresh: Vec<u32> = tree::descendants(&processes, root)
+            .into_iter()
+            .map(|process| process.pid)
+            .filter(|pid| seen.insert(*pid))
+            .collect();
+        if fresh.is_empty() {
+            break;
+        }
+        for pid in fresh {
+            // Exited, or in another cgroup: SIGSTOP picks it up below
+            let _ = platform::cgroup_join(&leaf, pid);
+        }
+    }
+
+    let frozen = platform::cgroup_freeze(&leaf, true, SETTLE).and_then(|settled| {
+        let members = platform::cgroup_get(&leaf, "cgroup.procs")?;
+        Ok((settled, members.split_whitespace().filter_map(|pid| pid.parse().ok()).collect::<Vec<u32>>()))
+    });
+    let (settled, members) = match frozen {
+        Ok(frozen) => frozen,
+        Err(e) => {
+            let _ = platform::cgroup_freeze(&leaf, false, SETTLE);
+            let _ = platform::cgroup_release(&leaf);
+            return Err(e);
+        }
+    };
+
+    let mut signalled = Vec::new();
+    let converged = stop_tree(provider, root, &members.iter().copied().collect(), &mut signalled);
+    Ok((leaf, members, signalled, settled && converged))
+}
+
+/// SIGSTOP every descendant of `root` not in `skip` or `stopped`, parents first
+///
+/// Re-scans until a pass stops nothing new and returns whether that happened
+/// within [`SCAN_ROUNDS`].
+fn stop_tree(provider: &dyn ProcessProvider, root: u32, skip: &HashSet<u32>, stopped: &mut Vec<u32>) -> bool {
+    for _ in 0..SCAN_ROUNDS {
+        let processes = provider.enumerate();
+        let mut progressed = false;
+        for process in tree::descendants(&processes, root) {
+            if skip.contains(&process.pid) || stopped.contains(&process.pid) {
+                continue;
+            }
+            if platform::stop_process(process.pid).is_ok() {
+                stopped.push(process.pid);
+                progressed = true;
+            }
+        }
+        if !progressed {
+            return true;
+        }
+    }
+    false
+}
@@ -0,0 +1,91 @@
+//! User-defined named process groups
+//!
+//! A group is a set of case-insensitive globs matched against process names
+//! plus explicit PIDs. Explicit PIDs are pinned to their start time, so a
+//! recycled PID does not silently join a group.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+
+use crate::provider::ProcessEntry;
+
+pub struct ProcessGroup {
+    pub name: String,
+    pub patterns: Vec<String>,
+    /// Explicit members as (pid, start_time)
+    pub pids: Vec<(u32, u64)>,
+    matcher: GlobSet,
+}
+
+impl ProcessGroup {
+    /// Whether the process `pid` started at `start_time` and named `name` belongs to the group
+    #[must_use]
+    pub fn matches(&self, pid: u32, start_time: u64, name: &str) -> bool {
+        self.pids
+            .iter()
+            .any(|(member, member_start)| *member == pid && *member_start == start_time)
+            || self.matcher.is_match(name)
+    }
+
+    /// PIDs of live members among `processes`, sorted
+    #[must_use]
+    pub fn members(&self, processes: &[ProcessEntry]) -> Vec<u32> {
+        let mut members: Vec<u32> = processes
+            .iter()
+            .filter(|process| self.matches(process.pid, process.start_time, &process.name))
+            .map(|process| process.pid)
+            .collect();
+        members.sort_unstable();
+        members
+    }
+}
+
+#[derive(Default)]
+pub struct GroupStore {
+    groups: Mutex<BTreeMap<String, Arc<ProcessGroup>>>,
+}
+
+impl GroupStore {
+    /// Create or replace a group
+    pub fn define(&self, name: &str, patterns: Vec<String>, pids: Vec<(u32, u64)>) -> Result<Arc<ProcessGroup>, String> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in &patterns {
+            let glob = GlobBuilder::new(pattern)
+                .case_insensitive(true)
+                .build()
+                .map_err(|e| format!("Invalid pattern {pattern:?}: {e}"))?;
+            builder.add(glob);
+        }
+        let matcher = builder.build().map_err(|e| format!("Invalid patterns: {e}"))?;
+        let group = Arc::new(ProcessGroup {
+            name: name.to_string(),
+            patterns,
+            pids,
+            matcher,
+        });
+        self.lock().insert(name.to_string(), group.clone());
+        Ok(group)
+    }
+
+    /// Delete a group, returning whether it existed
+    pub fn remove(&self, name: &str) -> bool {
+        self.lock().remove(name).is_some()
+    }
+
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<Arc<ProcessGroup>> {
+        self.lock().get(name).cloned()
+    }
+
+    /// All groups, sorted by name
+    #[must_use]
+    pub fn all(&self) -> Vec<Arc<ProcessGroup>> {
+        self.lock().values().cloned().collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, Arc<ProcessGroup>>> {
+        self.groups.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
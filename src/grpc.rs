@@ -0,0 +1,488 @@
+//! gRPC mirror of the core process tools (`grpc` feature)
+//!
+//! Implements `kodegen.process.v1.ProcessService` from `proto/process.proto`
+//! for consumers that standardize on gRPC. Unary calls run the same code as
+//! the MCP tools; `Watch` and `Logs` stream from the watchlist feed and the
+//! supervisor's live output. Served on its own listener (`grpc.bind`), with
+//! the bearer tokens of `grpc.tokens` checked before every call (see
+//! [`crate::auth`]). `Spawn` starts spawn profiles; free-form commands need
+//! `grpc.allow_commands`, since they run anything for whoever holds a token.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use kodegen_mcp_schema::McpError;
+use kodegen_server_http::Managers;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{Notify, mpsc};
+use tokio_stream::wrappers::{ReceiverStream, TcpListenerStream};
+use tonic::{Request, Response, Status};
+
+use crate::auth::{self, Caller};
+use crate::config::ProcessConfig;
+use crate::events::ProcessEvent;
+use crate::profiles;
+use crate::rest::StopOnShutdown;
+use crate::sandbox;
+use crate::schema::{
+    CpuLimitInfo, KillBudget, ProcessColumn, ProcessDetails, ProcessInfo, ProcessInfoArgs, ProcessKillArgs, ProcessKind,
+    ProcessListArgs, ProcessMemory, PodInfo, WatchEvent,
+};
+use crate::spawn::{SpawnSpec, check_cpus, check_nice, parse_env};
+use crate::state::ProcessState;
+use crate::supervisor::LogLine;
+use crate::{ProcessInfoTool, ProcessKillTool, ProcessListTool};
+
+/// Generated messages, server and client for `kodegen.process.v1`
+#[allow(clippy::all, clippy::pedantic)]
+pub mod pb {
+    tonic::include_proto!("kodegen.process.v1");
+}
+
+use pb::process_service_server::{ProcessService, ProcessServiceServer};
+
+/// Messages buffered per stream before the producer waits for the client
+const STREAM_BUFFER: usize = 32;
+
+struct Service {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+    list: ProcessListTool,
+    info: ProcessInfoTool,
+    kill: ProcessKillTool,
+}
+
+fn status(error: McpError) -> Status {
+    match error {
+        McpError::InvalidArguments(message) => Status::invalid_argument(message),
+        McpError::PermissionDenied(message) | McpError::Authentication(message) => Status::permission_denied(message),
+        McpError::ResourceNotFound(message) => Status::not_found(message),
+        McpError::RateLimitExceeded => Status::resource_exhausted("Rate limit exceeded"),
+        other => Status::internal(other.to_string()),
+    }
+}
+
+fn columns(names: &[String]) -> Result<Vec<ProcessColumn>, Status> {
+    names
+        .iter()
+        .map(|name| name.parse())
+        .collect::<Result<_, String>>()
+        .map_err(Status::invalid_argument)
+}
+
+/// Caller attached by the authentication interceptor
+fn caller<T>(request: &Request<T>) -> Result<Caller, Status> {
+    request
+        .extensions()
+        .get::<Caller>()
+        .cloned()
+        .ok_or_else(|| Status::unauthenticated("request was not authenticated"))
+}
+
+/// Kill budget key of `request`'s caller
+fn client<T>(request: &Request<T>) -> Result<Option<String>, Status> {
+    Ok(caller(request)?.client("grpc", request.remote_addr().map(|peer| peer.ip())))
+}
+
+/// Empty proto strings stand for "not set"
+fn non_empty(value: String) -> Option<String> {
+    (!value.is_empty()).then_some(value)
+}
+
+impl From<ProcessInfo> for pb::Process {
+    fn from(info: ProcessInfo) -> Self {
+        let extra_json = if info.extra.is_empty() {
+            String::new()
+        } else {
+            serde_json::to_string(&info.extra).unwrap_or_default()
+        };
+        Self {
+            pid: info.pid,
+            name: info.name,
+            cpu_percent: info.cpu_percent,
+            rss_bytes: info.rss_bytes,
+            virtual_bytes: info.virtual_bytes,
+            tags: info.tags,
+            cmdline: info.cmdline.unwrap_or_default(),
+            handle_count: info.handle_count,
+            exe: info.exe,
+            cwd: info.cwd,
+            tty: info.tty,
+            kind: info.kind.map(|kind| kind.name().to_string()),
+            packaging: info.packaging.map(|packaging| packaging.name().to_string()),
+            wsl_distro: info.wsl_distro,
+            arch: info.arch,
+            exe_sha256: info.exe_sha256,
+            spawned_by: info.spawned_by,
+            rss_human: info.rss_human,
+            start_time: info.start_time,
+            proc_id: info.proc_id,
+            emulated: info.emulated,
+            security_label: info.security_label,
+            integrity_level: info.integrity_level,
+            pod: info.pod.as_ref().map(PodInfo::qualified_name),
+            pod_container: info.pod.and_then(|pod| pod.container),
+            cgroup: info.cgroup,
+            extra_json,
+        }
+    }
+}
+
+impl From<ProcessDetails> for pb::ProcessDetail {
+    fn from(info: ProcessDetails) -> Self {
+        Self {
+            pid: info.pid,
+            proc_id: info.proc_id,
+            name: info.name,
+            parent: info.parent,
+            status: info.status,
+            user: info.user,
+            cmdline: info.cmdline,
+            exe: info.exe,
+            cwd: info.cwd,
+            start_time: info.start_time,
+            started_at: info.started_at,
+            age_secs: info.age_secs,
+            cpu_time_secs: info.cpu_time_secs,
+            threads: info.threads.map(|threads| threads as u64),
+            memory: Some(info.memory.into()),
+            tty: info.tty,
+            cgroup: info.cgroup,
+            security_label: info.security_label,
+            seccomp: info.seccomp.map(|seccomp| seccomp.name().to_string()),
+            integrity_level: info.integrity_level,
+            app_container: info.app_container,
+            cpu_limit: info.cpu_limit.map(Into::into),
+            // Counted per call, not per process; `Info` fills it in
+            redacted: 0,
+        }
+    }
+}
+
+impl From<ProcessMemory> for pb::Memory {
+    fn from(memory: ProcessMemory) -> Self {
+        Self {
+            rss_bytes: memory.rss_bytes,
+            virtual_bytes: memory.virtual_bytes,
+            private_bytes: memory.private_bytes,
+            anon_bytes: memory.anon_bytes,
+            file_bytes: memory.file_bytes,
+            shmem_bytes: memory.shmem_bytes,
+            swap_bytes: memory.swap_bytes,
+        }
+    }
+}
+
+impl From<CpuLimitInfo> for pb::CpuLimit {
+    fn from(limit: CpuLimitInfo) -> Self {
+        Self {
+            percent: limit.percent,
+            method: limit.method.name().to_string(),
+            cgroup: limit.cgroup,
+            since: limit.since,
+        }
+    }
+}
+
+impl From<KillBudget> for pb::Budget {
+    fn from(budget: KillBudget) -> Self {
+        Self {
+            window_secs: budget.window_secs,
+            client_remaining: budget.client_remaining,
+            global_remaining: budget.global_remaining,
+        }
+    }
+}
+
+impl From<WatchEvent> for pb::WatchEvent {
+    fn from(event: WatchEvent) -> Self {
+        Self {
+            id: event.id,
+            watch_id: event.watch_id,
+            pid: event.pid,
+            name: event.name,
+            started_at: event.started_at,
+            exited_at: event.exited_at,
+            runtime_secs: event.runtime_secs,
+            last_cpu_percent: event.last_cpu_percent,
+            last_rss_bytes: event.last_rss_bytes,
+        }
+    }
+}
+
+impl From<LogLine> for pb::LogLine {
+    fn from(line: LogLine) -> Self {
+        Self {
+            stream: line.stream.name().to_string(),
+            text: line.text,
+        }
+    }
+}
+
+impl Service {
+    /// `Spawn` of a spawn profile, like process_profile_start
+    async fn spawn_profile(
+        &self,
+        name: &str,
+        params: &BTreeMap<String, String>,
+        client: Option<&str>,
+    ) -> Result<Response<pb::SpawnResponse>, Status> {
+        let (profile, _) = self
+            .state
+            .profiles
+            .get(name)
+            .ok_or_else(|| Status::not_found(format!("Spawn profile {name:?} is not defined (see process_profiles)")))?;
+        let resolved = profiles::resolve(&profile, params, &self.config.sandbox).map_err(Status::invalid_argument)?;
+        let supervised = profiles::start(&self.state, profile, resolved.spec, client).await.map_err(status)?;
+        Ok(Response::new(pb::SpawnResponse {
+            pid: supervised.pid,
+            supervisor_id: supervised.id,
+            log_dir: supervised.log_dir,
+        }))
+    }
+}
+
+#[tonic::async_trait]
+impl ProcessService for Service {
+    async fn list(&self, request: Request<pb::ListRequest>) -> Result<Response<pb::ListResponse>, Status> {
+        let request = request.into_inner();
+        let args = ProcessListArgs {
+            filter: non_empty(request.filter),
+            limit: request.limit as usize,
+            tag: non_empty(request.tag),
+            group: non_empty(request.group),
+            columns: columns(&request.columns)?,
+            named_filters: request.named_filters,
+            kind: non_empty(request.kind)
+                .map(|kind| kind.parse::<ProcessKind>())
+                .transpose()
+                .map_err(Status::invalid_argument)?,
+            wsl: request.wsl,
+            pod: non_empty(request.pod),
+            pids: request.pids,
+            reveal_secrets: request.reveal_secrets,
+            ..ProcessListArgs::default()
+        };
+        let output = self.list.list(args).await.map_err(status)?.metadata;
+        Ok(Response::new(pb::ListResponse {
+            processes: output.processes.into_iter().map(Into::into).collect(),
+            redacted: u32::try_from(output.redacted).unwrap_or(u32::MAX),
+            capped_by: output.capped.map(|capped| capped.by),
+            warnings: output.warnings,
+            missing: output.missing,
+        }))
+    }
+
+    async fn info(&self, request: Request<pb::InfoRequest>) -> Result<Response<pb::ProcessDetail>, Status> {
+        let request = request.into_inner();
+        let args = ProcessInfoArgs {
+            pid: Some(request.pid),
+            proc_id: None,
+            pids: Vec::new(),
+            reveal_secrets: request.reveal_secrets,
+            style: None,
+            verbosity: None,
+        };
+        let output = self.info.info(args).await.map_err(status)?.metadata;
+        let process = output
+            .process
+            .ok_or_else(|| Status::not_found(format!("Process {} not found", request.pid)))?;
+        Ok(Response::new(pb::ProcessDetail {
+            redacted: u32::try_from(output.redacted).unwrap_or(u32::MAX),
+            ..process.into()
+        }))
+    }
+
+    async fn kill(&self, request: Request<pb::KillRequest>) -> Result<Response<pb::KillResponse>, Status> {
+        // Budgets are per token name (or caller address), like per-connection budgets over MCP
+        let client = client(&request)?;
+        let request = request.into_inner();
+        let args = ProcessKillArgs {
+            pid: Some(request.pid),
+            confirm: request.confirm,
+            start_time: request.start_time,
+            ..ProcessKillArgs::default()
+        };
+        let output = self.kill.kill(args, client.as_deref(), None).await.map_err(status)?.metadata;
+        Ok(Response::new(pb::KillResponse {
+            pid: output.pid,
+            message: output.message,
+            record_id: output.record_id,
+            budget: Some(output.budget.into()),
+        }))
+    }
+
+    async fn spawn(&self, request: Request<pb::SpawnRequest>) -> Result<Response<pb::SpawnResponse>, Status> {
+        let client = client(&request)?;
+        let request = request.into_inner();
+        if !request.profile.is_empty() {
+            if !request.program.is_empty()
+                || !request.args.is_empty()
+                || !request.cwd.is_empty()
+                || !request.env.is_empty()
+                || request.nice.is_some()
+                || !request.cpus.is_empty()
+                || !request.sandbox.is_empty()
+            {
+                return Err(Status::invalid_argument("profile fixes the launch; pass only params with it"));
+            }
+            let params: BTreeMap<String, String> = request.params.into_iter().collect();
+            return self.spawn_profile(&request.profile, &params, client.as_deref()).await;
+        }
+        if !self.config.grpc.allow_commands {
+            return Err(Status::permission_denied(
+                "Spawn only starts spawn profiles (see process_profiles); the server must set \
+                 grpc.allow_commands to run free-form commands",
+            ));
+        }
+        if request.program.trim().is_empty() {
+            return Err(Status::invalid_argument("program must not be empty"));
+        }
+        if let Some(nice) = request.nice {
+            check_nice(nice).map_err(Status::invalid_argument)?;
+        }
+        let cpus: Vec<usize> = request.cpus.iter().map(|&cpu| cpu as usize).collect();
+        check_cpus(&cpus).map_err(Status::invalid_argument)?;
+        let env = (!request.env.is_empty()).then(|| {
+            let mut env: HashMap<String, String> = std::env::vars().collect();
+            env.extend(parse_env(&request.env));
+            env.into_iter().collect()
+        });
+        let sandbox = sandbox::profile(&self.config.sandbox, non_empty(request.sandbox).as_deref())
+            .map_err(Status::invalid_argument)?;
+        let spec = SpawnSpec {
+            program: request.program,
+            args: request.args,
+            cwd: non_empty(request.cwd).map(PathBuf::from),
+            env,
+            io_priority: None,
+            nice: request.nice,
+            cpus,
+        };
+        let spec = match sandbox {
+            Some(profile) => sandbox::confine(spec, profile).map_err(Status::failed_precondition)?,
+            None => spec,
+        };
+        let supervised = self
+            .state
+            .supervisor
+            .spawn(&spec, "grpc", client.as_deref())
+            .map_err(|e| Status::invalid_argument(format!("Failed to spawn {}: {e}", spec.command_line())))?;
+        Ok(Response::new(pb::SpawnResponse {
+            pid: supervised.pid,
+            supervisor_id: supervised.id,
+            log_dir: supervised.log_dir,
+        }))
+    }
+
+    type WatchStream = ReceiverStream<Result<pb::WatchEvent, Status>>;
+
+    async fn watch(&self, request: Request<pb::WatchRequest>) -> Result<Response<Self::WatchStream>, Status> {
+        use pb::watch_request::Target;
+
+        // Subscribe first so an exit right after the watch is created is not missed
+        let mut events = self.state.events.subscribe();
+        let watch = match request.into_inner().target {
+            Some(Target::Pid(pid)) => self.state.watch.watch_pid(pid, None, None).map_err(Status::not_found)?,
+            Some(Target::Pattern(pattern)) => {
+                self.state.watch.watch_pattern(&pattern, None, None).map_err(Status::invalid_argument)?
+            }
+            None => return Err(Status::invalid_argument("Pass pid or pattern")),
+        };
+
+        let (sender, receiver) = mpsc::channel(STREAM_BUFFER);
+        let watchlist = self.state.watch.clone();
+        tokio::spawn(async move {
+            loop {
+                let received = tokio::select! {
+                    () = sender.closed() => break,
+                    received = events.recv() => received,
+                };
+                match received {
+                    Ok(ProcessEvent::Exited(event)) if event.watch_id == watch.id => {
+                        if sender.send(Ok(event.into())).await.is_err() || watch.pid.is_some() {
+                            break;
+                        }
+                    }
+                    Ok(_) | Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => break,
+                }
+            }
+            // The watch only exists for this stream
+            watchlist.unwatch(watch.id);
+        });
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
+
+    type LogsStream = ReceiverStream<Result<pb::LogLine, Status>>;
+
+    async fn logs(&self, request: Request<pb::LogsRequest>) -> Result<Response<Self::LogsStream>, Status> {
+        let id = request.into_inner().supervisor_id;
+        let mut lines = self.state.supervisor.follow(id).map_err(Status::not_found)?;
+
+        let (sender, receiver) = mpsc::channel(STREAM_BUFFER);
+        tokio::spawn(async move {
+            loop {
+                let received = tokio::select! {
+                    () = sender.closed() => break,
+                    received = lines.recv() => received,
+                };
+                match received {
+                    Ok(line) => {
+                        if sender.send(Ok(line.into())).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(RecvError::Lagged(missed)) => {
+                        log::debug!("gRPC log stream of #{id} missed {missed} lines");
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
+}
+
+/// Bind `bind` and serve the gRPC service until the server shuts down
+pub(crate) async fn start(
+    bind: &str,
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+    managers: &Managers,
+) -> anyhow::Result<()> {
+    let listener = tokio::net::TcpListener::bind(bind)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to bind gRPC listener {bind}: {e}"))?;
+    auth::check_bind("grpc", listener.local_addr()?, &config.grpc.tokens)?;
+    let tokens = config.grpc.tokens.clone();
+    let authenticate = move |mut request: Request<()>| {
+        let authorization = request.metadata().get("authorization").and_then(|value| value.to_str().ok());
+        let caller = auth::authenticate(&tokens, authorization).map_err(Status::unauthenticated)?;
+        request.extensions_mut().insert(caller);
+        Ok(request)
+    };
+    let service = Service {
+        list: ProcessListTool::new(config.clone(), state.clone()),
+        info: ProcessInfoTool::new(config.clone(), state.clone()),
+        kill: ProcessKillTool::new(config.clone(), state.clone()),
+        config,
+        state,
+    };
+
+    let stop = Arc::new(Notify::new());
+    managers.register(StopOnShutdown(stop.clone())).await;
+    log::info!("gRPC service listening on {bind}");
+    tokio::spawn(async move {
+        let shutdown = async move { stop.notified().await };
+        let served = tonic::transport::Server::builder()
+            .add_service(ProcessServiceServer::with_interceptor(service, authenticate))
+            .serve_with_incoming_shutdown(TcpListenerStream::new(listener), shutdown)
+            .await;
+        if let Err(e) = served {
+            log::warn!("gRPC listener stopped: {e}");
+        }
+    });
+    Ok(())
+}
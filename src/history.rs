@@ -0,0 +1,211 @@
+//! Rolling per-process resource history
+//!
+//! A task samples resident memory and CPU of every process once per
+//! `interval_secs` and keeps `retention_secs` worth of samples per process,
+//! keyed by PID and start time so a reused PID starts a fresh series. Series
+//! of exited processes are dropped on the next sample. Trend analyses such as
+//! `process_leaks` read from here instead of sampling for a window themselves,
+//! and every sample also drives CPU spike detection.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
+
+use crate::config::HistoryConfig;
+use crate::events::ProcessEvent;
+use crate::state::ProcessState;
+
+/// One sample of one process
+#[derive(Debug, Clone, Copy)]
+pub struct HistorySample {
+    /// Seconds since the Unix epoch
+    pub at: f64,
+    pub rss_bytes: u64,
+    pub cpu_percent: f32,
+}
+
+/// Samples of one process, oldest first
+#[derive(Debug, Clone)]
+pub struct ProcessSeries {
+    pub pid: u32,
+    pub name: String,
+    pub start_time: u64,
+    pub samples: Vec<HistorySample>,
+}
+
+struct Series {
+    name: String,
+    samples: VecDeque<HistorySample>,
+}
+
+pub struct History {
+    interval: Duration,
+    retention: Duration,
+    series: Mutex<HashMap<(u32, u64), Series>>,
+    /// Available system memory at the last sample
+    available_memory: AtomicU64,
+    /// Unix time of the first sample, 0 before it
+    since: Mutex<f64>,
+}
+
+impl Default for History {
+    fn default() -> Self {
+        Self::new(&HistoryConfig::default())
+    }
+}
+
+impl History {
+    #[must_use]
+    pub fn new(config: &HistoryConfig) -> Self {
+        Self {
+            interval: Duration::from_secs(config.interval_secs),
+            retention: Duration::from_secs(config.retention_secs.max(config.interval_secs)),
+            series: Mutex::default(),
+            available_memory: AtomicU64::new(0),
+            since: Mutex::new(0.0),
+        }
+    }
+
+    /// Whether samples are being recorded
+    #[must_use]
+    pub fn enabled(&self) -> bool {
+        !self.interval.is_zero()
+    }
+
+    /// Time between samples
+    #[must_use]
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// How far back samples are kept
+    #[must_use]
+    pub fn retention(&self) -> Duration {
+        self.retention
+    }
+
+    /// Seconds of history recorded so far, up to the retention
+    #[must_use]
+    pub fn covered_secs(&self) -> f64 {
+        let since = *self.lock_since();
+        if since == 0.0 {
+            return 0.0;
+        }
+        (now() - since).min(self.retention.as_secs_f64())
+    }
+
+    /// Available system memory at the last sample (0 before the first)
+    #[must_use]
+    pub fn available_memory(&self) -> u64 {
+        self.available_memory.load(Ordering::Relaxed)
+    }
+
+    /// Series of the processes `keep` accepts, restricted to samples of the last `window`
+    pub fn series(&self, window: Duration, keep: impl Fn(u32, &str) -> bool) -> Vec<ProcessSeries> {
+        let cutoff = now() - window.as_secs_f64();
+        let series = self.lock();
+        let mut found: Vec<ProcessSeries> = series
+            .iter()
+            .filter(|((pid, _), series)| keep(*pid, &series.name))
+            .map(|((pid, start_time), series)| ProcessSeries {
+                pid: *pid,
+                name: series.name.clone(),
+                start_time: *start_time,
+                samples: series.samples.iter().filter(|sample| sample.at >= cutoff).copied().collect(),
+            })
+            .collect();
+        found.sort_by_key(|series| series.pid);
+        found
+    }
+
+    /// Append one sample per process of a refreshed `system`
+    fn record(&self, system: &System) {
+        let at = now();
+        let cutoff = at - self.retention.as_secs_f64();
+        let mut series = self.lock();
+        let mut live = HashMap::with_capacity(system.processes().len());
+        for (pid, process) in system.processes() {
+            let key = (pid.as_u32(), process.start_time());
+            let mut entry = series.remove(&key).unwrap_or_else(|| Series {
+                name: process.name().to_string_lossy().to_string(),
+                samples: VecDeque::new(),
+            });
+            while entry.samples.front().is_some_and(|sample| sample.at < cutoff) {
+                entry.samples.pop_front();
+            }
+            entry.samples.push_back(HistorySample {
+                at,
+                rss_bytes: process.memory(),
+                cpu_percent: process.cpu_usage(),
+            });
+            live.insert(key, entry);
+        }
+        // Whatever is left over has exited
+        *series = live;
+        drop(series);
+        self.available_memory.store(system.available_memory(), Ordering::Relaxed);
+        let mut since = self.lock_since();
+        if *since == 0.0 {
+            *since = at;
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<(u32, u64), Series>> {
+        self.series.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn lock_since(&self) -> std::sync::MutexGuard<'_, f64> {
+        self.since.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Start sampling unless disabled
+///
+/// Must be called from within the Tokio runtime.
+pub(crate) fn start(state: Arc<ProcessState>) {
+    if !state.history.enabled() {
+        return;
+    }
+    tokio::spawn(async move {
+        let mut system = System::new();
+        loop {
+            let recorder = state.clone();
+            let Ok((sampled, spikes)) = tokio::task::spawn_blocking(move || {
+                let kind = ProcessRefreshKind::nothing().with_memory().with_cpu().without_tasks();
+                system.refresh_processes_specifics(ProcessesToUpdate::All, true, kind);
+                system.refresh_memory();
+                recorder.history.record(&system);
+                let spikes = if recorder.cpu_spikes.enabled() {
+                    recorder.cpu_spikes.observe(&system)
+                } else {
+                    Vec::new()
+                };
+                (system, spikes)
+            })
+            .await
+            else {
+                break;
+            };
+            system = sampled;
+            for spike in spikes {
+                log::info!(
+                    "CPU spike: PID {} ({}) from {:.1}% to {:.1}% for {:.0} s",
+                    spike.pid,
+                    spike.name,
+                    spike.before_percent,
+                    spike.cpu_percent,
+                    spike.sustained_secs
+                );
+                state.events.publish(ProcessEvent::CpuSpike(spike));
+            }
+            tokio::time::sleep(state.history.interval()).await;
+        }
+    });
+}
+
+fn now() -> f64 {
+    chrono::Utc::now().timestamp_millis() as f64 / 1000.0
+}
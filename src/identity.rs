@@ -0,0 +1,69 @@
+//! Reuse-safe process references
+//!
+//! A PID names whichever process holds it right now, which after an exit can
+//! be an unrelated one. A `proc_id` pairs the PID with the start time so a
+//! reference taken from one call can only ever mean that process in the next.
+//! Listing and info tools return it, mutating tools accept it instead of
+//! `pid` and refuse the call once the PID has been reused. The format,
+//! `<pid>-<start_time>`, is not part of the contract: callers treat it as
+//! opaque.
+
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::provider::ProcessProvider;
+use crate::state::ProcessState;
+
+/// Identity handle of the process `pid` that started at `start_time`
+pub(crate) fn proc_id(pid: u32, start_time: u64) -> String {
+    format!("{pid}-{start_time}")
+}
+
+/// PID and start time a handle stands for
+pub(crate) fn parse(proc_id: &str) -> Result<(u32, u64), McpError> {
+    proc_id
+        .split_once('-')
+        .and_then(|(pid, start_time)| Some((pid.parse().ok()?, start_time.parse().ok()?)))
+        .filter(|&(pid, _)| pid != 0)
+        .ok_or_else(|| McpError::InvalidArguments(format!("Invalid proc_id {proc_id:?}; pass one returned by process_list")))
+}
+
+/// PID a call targets and, for a handle, the start time it must still have
+///
+/// Exactly one of `pid` and `proc_id` has to be given.
+pub(crate) fn target(pid: Option<u32>, proc_id: Option<&str>) -> Result<(u32, Option<u64>), McpError> {
+    match (pid, proc_id) {
+        (Some(pid), None) => Ok((pid, None)),
+        (None, Some(proc_id)) => parse(proc_id).map(|(pid, start_time)| (pid, Some(start_time))),
+        _ => Err(McpError::InvalidArguments("Pass exactly one of pid or proc_id".to_string())),
+    }
+}
+
+/// `Ok` when `pid` still belongs to the process that started at `start_time`
+pub(crate) fn verify(provider: &dyn ProcessProvider, pid: u32, start_time: u64) -> Result<(), McpError> {
+    match provider.lookup(pid, false) {
+        Some(process) if process.start_time == start_time => Ok(()),
+        Some(_) => Err(McpError::ResourceNotFound(format!(
+            "Process {} has exited; PID {pid} now belongs to another process",
+            self::proc_id(pid, start_time)
+        ))),
+        None => Err(McpError::ResourceNotFound(format!(
+            "Process {} not found",
+            self::proc_id(pid, start_time)
+        ))),
+    }
+}
+
+/// PID named by `pid` or `proc_id`, with a handle checked against the live process
+pub(crate) async fn resolve(state: &Arc<ProcessState>, pid: Option<u32>, proc_id: Option<&str>) -> Result<u32, McpError> {
+    let (pid, start_time) = target(pid, proc_id)?;
+    let Some(start_time) = start_time else {
+        return Ok(pid);
+    };
+    let state = state.clone();
+    // Provider lookups block on OS calls
+    tokio::task::spawn_blocking(move || verify(state.provider.as_ref(), pid, start_time))
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Process lookup task failed: {e}")))??;
+    Ok(pid)
+}
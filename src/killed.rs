@@ -0,0 +1,105 @@
+//! Bounded registry of processes terminated through this server
+//!
+//! Answers "what exactly did I just kill?" after the fact. Entries are
+//! snapshots taken right before the signal was sent; the oldest entry is
+//! dropped once the configured capacity is reached.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use crate::config::DEFAULT_KILLED_HISTORY;
+use crate::provider::ProcessEntry;
+use crate::schema::KilledProcess;
+
+#[derive(Default)]
+struct Entries {
+    next_id: u64,
+    records: VecDeque<KilledProcess>,
+}
+
+pub struct KilledRegistry {
+    capacity: usize,
+    entries: Mutex<Entries>,
+}
+
+impl Default for KilledRegistry {
+    fn default() -> Self {
+        Self::new(DEFAULT_KILLED_HISTORY)
+    }
+}
+
+impl KilledRegistry {
+    /// Registry keeping at most `capacity` entries (0 disables recording)
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(Entries::default()),
+        }
+    }
+
+    /// Whether kills should capture a snapshot at all
+    #[must_use]
+    pub fn enabled(&self) -> bool {
+        self.capacity > 0
+    }
+
+    /// Store a snapshot and return the id assigned to it
+    pub fn record(&self, mut entry: KilledProcess) -> u64 {
+        let mut entries = self.lock();
+        entries.next_id += 1;
+        entry.id = entries.next_id;
+        if entries.records.len() >= self.capacity {
+            entries.records.pop_front();
+        }
+        entries.records.push_back(entry);
+        entries.next_id
+    }
+
+    /// Newest-first entries, optionally restricted to one PID
+    #[must_use]
+    pub fn recent(&self, pid: Option<u32>, limit: usize) -> Vec<KilledProcess> {
+        let entries = self.lock();
+        let matching = entries
+            .records
+            .iter()
+            .rev()
+            .filter(|entry| pid.is_none_or(|pid| entry.pid == pid))
+            .cloned();
+        if limit > 0 {
+            matching.take(limit).collect()
+        } else {
+            matching.collect()
+        }
+    }
+
+    /// Look up a single entry by record id
+    #[must_use]
+    pub fn get(&self, id: u64) -> Option<KilledProcess> {
+        self.lock().records.iter().find(|entry| entry.id == id).cloned()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Entries> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Capture what is needed to answer "what did I kill?" before the signal
+///
+/// `process` should come from a `details` lookup so cmdline, cwd and env are filled.
+pub(crate) fn snapshot(process: ProcessEntry) -> KilledProcess {
+    let lossy = |path: std::path::PathBuf| path.to_string_lossy().to_string();
+    KilledProcess {
+        id: 0,
+        pid: process.pid,
+        name: process.name,
+        cmdline: process.cmdline,
+        exe: process.exe.map(lossy),
+        cwd: process.cwd.map(lossy),
+        env: process.env,
+        user: process.user,
+        start_time: process.start_time,
+        killed_at: chrono::Utc::now().to_rfc3339(),
+        client: None,
+    }
+}
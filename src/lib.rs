@@ -4,12 +4,733 @@
 /// consistent PID handling across all process-related tools.
 pub type ProcessId = u32;
 
+pub mod config;
+pub use config::ProcessConfig;
+
+pub mod schema;
+pub use schema::*;
+
+mod auth;
+mod compact;
+mod container;
+mod display;
+#[cfg(all(target_os = "linux", feature = "ebpf"))]
+mod exec_trace;
+mod identity;
+mod output_log;
+mod packaging;
+mod pidfile;
+mod platform;
+mod protection;
+mod resilience;
+mod rest;
+mod sandbox;
+mod signals;
+mod spawn;
+mod tree;
+mod users;
+
+pub mod children;
+pub mod client_types;
+pub mod confirm;
+pub mod containment;
+pub mod cpu_limit;
+pub mod cpu_sampler;
+pub mod events;
+pub mod exe_hash;
+pub mod freeze;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+pub mod groups;
+pub mod history;
+pub mod killed;
+pub mod list_delta;
+pub mod markers;
+pub mod oom_guard;
+pub mod plugins;
+pub mod pods;
+pub mod profiles;
+pub mod provider;
+pub mod reaper;
+pub mod redact;
+pub mod sampling;
+pub mod scheduler;
+pub mod spikes;
+pub mod state;
+pub mod supervisor;
+pub mod tags;
+pub mod throttle;
+pub mod watch;
+pub use children::{ChildRegistry, RegisteredChild};
+pub use confirm::ConfirmPolicy;
+pub use containment::Containments;
+pub use cpu_limit::CpuLimiter;
+pub use cpu_sampler::CpuSampler;
+pub use events::{EventBus, ProcessEvent};
+pub use exe_hash::ExeHashes;
+pub use freeze::Freezes;
+pub use groups::GroupStore;
+pub use history::History;
+pub use killed::KilledRegistry;
+pub use list_delta::ListSnapshots;
+pub use markers::Markers;
+pub use oom_guard::OomGuard;
+pub use plugins::Plugins;
+pub use pods::PodIndex;
+pub use provider::{ProcessEntry, ProcessProvider, SignalError, SysinfoProvider};
+pub use reaper::Reaper;
+pub use redact::Redactor;
+pub use sampling::Sampling;
+pub use scheduler::Scheduler;
+pub use spikes::CpuSpikes;
+pub use state::ProcessState;
+pub use supervisor::Supervisor;
+pub use tags::TagStore;
+pub use throttle::KillThrottle;
+pub use watch::Watchlist;
+
 pub mod process_list;
 pub use process_list::*;
 
 pub mod process_kill;
 pub use process_kill::*;
 
+pub mod process_can_kill;
+pub use process_can_kill::*;
+
+pub mod process_capabilities;
+pub use process_capabilities::*;
+
+pub mod process_recently_killed;
+pub use process_recently_killed::*;
+
+pub mod process_respawn;
+pub use process_respawn::*;
+
+pub mod process_tag;
+pub use process_tag::*;
+
+pub mod process_tags;
+pub use process_tags::*;
+
+pub mod process_group_define;
+pub use process_group_define::*;
+
+pub mod process_groups;
+pub use process_groups::*;
+
+pub mod process_group_kill;
+pub use process_group_kill::*;
+
+pub mod process_watch;
+pub use process_watch::*;
+
+pub mod process_unwatch;
+pub use process_unwatch::*;
+
+pub mod process_watch_events;
+pub use process_watch_events::*;
+
+pub mod process_status;
+pub use process_status::*;
+
+pub mod process_logs;
+pub use process_logs::*;
+
+pub mod process_logs_follow;
+pub use process_logs_follow::*;
+
+pub mod process_run;
+pub use process_run::*;
+
+pub mod process_pidfile;
+pub use process_pidfile::*;
+
+pub mod process_pidfile_cleanup;
+pub use process_pidfile_cleanup::*;
+
+pub mod process_lock_check;
+pub use process_lock_check::*;
+
+pub mod process_sampling;
+pub use process_sampling::*;
+
+pub mod process_terminate;
+pub use process_terminate::*;
+
+pub mod process_renice;
+pub use process_renice::*;
+pub mod process_ionice;
+pub use process_ionice::*;
+
+pub mod process_cpu_limit;
+pub use process_cpu_limit::*;
+
+pub mod process_cpu_unlimit;
+pub use process_cpu_unlimit::*;
+
+pub mod process_cpu_limits;
+pub use process_cpu_limits::*;
+
+pub mod process_contain;
+pub use process_contain::*;
+
+pub mod process_uncontain;
+pub use process_uncontain::*;
+
+pub mod process_freeze;
+pub use process_freeze::*;
+
+pub mod process_thaw;
+pub use process_thaw::*;
+
+pub mod process_stats;
+pub use process_stats::*;
+
+pub mod process_mark;
+pub use process_mark::*;
+
+pub mod process_mark_compare;
+pub use process_mark_compare::*;
+
+pub mod process_kill_schedule;
+pub use process_kill_schedule::*;
+
+pub mod process_kill_schedules;
+pub use process_kill_schedules::*;
+
+pub mod process_kill_unschedule;
+pub use process_kill_unschedule::*;
+
+pub mod process_kill_if_idle;
+pub use process_kill_if_idle::*;
+
+pub mod process_oom_guard;
+pub use process_oom_guard::*;
+
+pub mod process_leaks;
+pub use process_leaks::*;
+pub mod process_forensics;
+pub use process_forensics::*;
+pub mod process_provenance;
+pub use process_provenance::*;
+pub mod process_code_signature;
+pub use process_code_signature::*;
+pub mod process_audit;
+pub use process_audit::*;
+pub mod process_env_diff;
+pub use process_env_diff::*;
+pub mod process_compare;
+pub use process_compare::*;
+pub mod process_pid_translate;
+pub use process_pid_translate::*;
+pub mod process_signal_check;
+pub use process_signal_check::*;
+pub mod process_profile_define;
+pub use process_profile_define::*;
+pub mod process_profiles;
+pub use process_profiles::*;
+pub mod process_profile_start;
+pub use process_profile_start::*;
+pub mod process_spawn;
+pub use process_spawn::*;
+pub mod process_tree;
+pub use process_tree::*;
+pub mod process_info;
+pub use process_info::*;
+pub mod process_signal;
+pub use process_signal::*;
+pub mod process_suspend;
+pub use process_suspend::*;
+pub mod process_resume;
+pub use process_resume::*;
+pub mod process_wait;
+pub use process_wait::*;
+pub mod process_kill_by_name;
+pub use process_kill_by_name::*;
+pub mod process_kill_tree;
+pub use process_kill_tree::*;
+pub mod process_pgrep;
+pub use process_pgrep::*;
+pub mod process_ancestors;
+pub use process_ancestors::*;
+pub mod process_env;
+pub use process_env::*;
+pub mod process_open_files;
+pub use process_open_files::*;
+pub mod process_connections;
+pub use process_connections::*;
+pub mod port_lookup;
+pub use port_lookup::*;
+pub mod process_priority;
+pub use process_priority::*;
+pub mod process_affinity;
+pub use process_affinity::*;
+
+/// Build the router set containing every process tool
+///
+/// Shared by the `kodegen-process` binary and [`start_server_with_listener`]
+/// so both expose the same tools backed by the same server configuration.
+pub async fn build_router_set() -> anyhow::Result<kodegen_server_http::RouterSet<kodegen_server_http::HttpServer>> {
+    build_router_set_with_plugins(Plugins::default()).await
+}
+
+/// Build the router set with embedder-supplied `process_list` extensions
+pub async fn build_router_set_with_plugins(
+    plugins: Plugins,
+) -> anyhow::Result<kodegen_server_http::RouterSet<kodegen_server_http::HttpServer>> {
+    use kodegen_server_http::{Managers, RouterSet, register_tool};
+    use rmcp::handler::server::router::{prompt::PromptRouter, tool::ToolRouter};
+    use std::sync::Arc;
+
+    let config = Arc::new(ProcessConfig::load());
+    let state = Arc::new(ProcessState::new(&config).with_plugins(plugins));
+    if config.subreaper
+        && cfg!(target_os = "linux")
+        && let Err(e) = platform::set_child_subreaper()
+    {
+        log::warn!("Failed to become child subreaper; orphans of spawned processes go to init: {e}");
+    }
+    reaper::start(state.clone());
+    oom_guard::start(config.clone(), state.clone());
+    history::start(state.clone());
+    #[cfg(all(target_os = "linux", feature = "ebpf"))]
+    exec_trace::start(&config, state.clone());
+
+    let mut tool_router = ToolRouter::new();
+    let mut prompt_router = PromptRouter::new();
+    let managers = Managers::new();
+
+    // Register all 69 process tools
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessListTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessKillTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessCanKillTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessCapabilitiesTool::new(config.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessRecentlyKilledTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessRespawnTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessTagTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessTagsTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessGroupDefineTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessGroupsTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessGroupKillTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessWatchTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessUnwatchTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessWatchEventsTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessStatusTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessLogsTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessLogsFollowTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessRunTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessPidfileTool::new(config.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessPidfileCleanupTool::new(config.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessLockCheckTool::new(config.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessSamplingTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessTerminateTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessReniceTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessIoniceTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessCpuLimitTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessCpuUnlimitTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessCpuLimitsTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessContainTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessUncontainTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessFreezeTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessThawTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessStatsTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessMarkTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessMarkCompareTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessKillScheduleTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessKillSchedulesTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessKillUnscheduleTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessKillIfIdleTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessOomGuardTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessLeaksTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessForensicsTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessProvenanceTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessCodeSignatureTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessAuditTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessEnvDiffTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessCompareTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessPidTranslateTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessSignalCheckTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessProfileDefineTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessProfilesTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessProfileStartTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessSpawnTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessTreeTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessInfoTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessSignalTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessSuspendTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessResumeTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessWaitTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessKillByNameTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessKillTreeTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessPgrepTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessAncestorsTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessEnvTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessOpenFilesTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessConnectionsTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::PortLookupTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessPriorityTool::new(config.clone(), state.clone()),
+    );
+
+    (tool_router, prompt_router) = register_tool(
+        tool_router,
+        prompt_router,
+        crate::ProcessAffinityTool::new(config.clone(), state.clone()),
+    );
+
+    managers.register(state::ReleaseOnShutdown(state.clone())).await;
+
+    if let Some(bind) = &config.rest.bind {
+        rest::start(bind, config.clone(), state.clone(), &managers).await?;
+    }
+    if let Some(bind) = &config.grpc.bind {
+        #[cfg(feature = "grpc")]
+        grpc::start(bind, config.clone(), state.clone(), &managers).await?;
+        #[cfg(not(feature = "grpc"))]
+        log::warn!("grpc.bind is {bind} but this build lacks the grpc feature; gRPC stays off");
+    }
+
+    Ok(RouterSet::new(tool_router, prompt_router, managers))
+}
+
 /// Start the process tools HTTP server programmatically
 ///
 /// Returns a ServerHandle for graceful shutdown control.
@@ -56,31 +777,24 @@ pub async fn start_server_with_listener(
     listener: tokio::net::TcpListener,
     tls_config: Option<(std::path::PathBuf, std::path::PathBuf)>,
 ) -> anyhow::Result<kodegen_server_http::ServerHandle> {
-    use kodegen_server_http::{ServerBuilder, Managers, RouterSet, register_tool};
-    use rmcp::handler::server::router::{prompt::PromptRouter, tool::ToolRouter};
+    start_server_with_plugins(listener, tls_config, Plugins::default()).await
+}
+
+/// Start process tools HTTP server on a pre-bound listener with custom extensions
+///
+/// Same as [`start_server_with_listener`], plus `plugins` whose enrichers add
+/// `extra` fields to `process_list` results and whose filters can be selected
+/// via its `named_filters` argument.
+pub async fn start_server_with_plugins(
+    listener: tokio::net::TcpListener,
+    tls_config: Option<(std::path::PathBuf, std::path::PathBuf)>,
+    plugins: Plugins,
+) -> anyhow::Result<kodegen_server_http::ServerHandle> {
+    use kodegen_server_http::ServerBuilder;
 
     let mut builder = ServerBuilder::new()
         .category(kodegen_config::CATEGORY_PROCESS)
-        .register_tools(|| async {
-            let mut tool_router = ToolRouter::new();
-            let mut prompt_router = PromptRouter::new();
-            let managers = Managers::new();
-
-            // Register all 2 process tools
-            (tool_router, prompt_router) = register_tool(
-                tool_router,
-                prompt_router,
-                crate::ProcessListTool::new(),
-            );
-
-            (tool_router, prompt_router) = register_tool(
-                tool_router,
-                prompt_router,
-                crate::ProcessKillTool::new(),
-            );
-
-            Ok(RouterSet::new(tool_router, prompt_router, managers))
-        })
+        .register_tools(move || build_router_set_with_plugins(plugins))
         .with_listener(listener);
 
     if let Some((cert, key)) = tls_config {
@@ -0,0 +1,142 @@
+//! Snapshots of `process_list` results for delta polling
+//!
+//! A monitoring loop passes the token of its previous listing and receives
+//! only what changed since then. Rows are keyed by PID and start time, so a
+//! recycled PID shows up as one removal plus one addition. Unchanged rows keep
+//! their baseline values in the next snapshot, which makes slow drift surface
+//! once it adds up instead of being swallowed poll by poll.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use crate::schema::{ListDelta, ProcessInfo};
+
+/// Snapshots kept before the oldest token expires
+pub const LIST_SNAPSHOT_CAPACITY: usize = 16;
+
+/// CPU change in percentage points that counts as significant
+pub const DELTA_CPU_POINTS: f32 = 5.0;
+
+/// Relative resident memory change that counts as significant
+pub const DELTA_MEMORY_RATIO: f64 = 0.1;
+
+/// Memory changes below this are never significant, whatever the ratio
+const DELTA_MEMORY_MIN_BYTES: u64 = 1024 * 1024;
+
+/// Rows of one listing keyed by PID, with the start time they belong to
+type Rows = HashMap<u32, (u64, ProcessInfo)>;
+
+#[derive(Default)]
+struct Entries {
+    next_id: u64,
+    snapshots: VecDeque<(String, Arc<Rows>)>,
+}
+
+#[derive(Default)]
+pub struct ListSnapshots {
+    entries: Mutex<Entries>,
+}
+
+impl ListSnapshots {
+    /// Diff `current` (rows with their start time) against the snapshot `since`
+    ///
+    /// Without `since` every row counts as added. Returns the rows to send,
+    /// the delta summary and the token of the new snapshot. `None` when the
+    /// `since` token is unknown or already evicted.
+    ///
+    /// `admit` is asked before each added or changed row is sent. A row it
+    /// turns away is left out of the delta and keeps its `since` state in the
+    /// new snapshot, so the next call reports it again.
+    pub fn advance(
+        &self,
+        since: Option<&str>,
+        current: Vec<(u64, ProcessInfo)>,
+        mut admit: impl FnMut(&ProcessInfo) -> bool,
+    ) -> Option<(Vec<ProcessInfo>, Option<ListDelta>, String)> {
+        let base = match since {
+            Some(token) => Some(self.get(token)?),
+            None => None,
+        };
+
+        let mut next = Rows::with_capacity(current.len());
+        let mut send = Vec::new();
+        let mut delta = ListDelta {
+            since: since.unwrap_or_default().to_string(),
+            added: Vec::new(),
+            changed: Vec::new(),
+            removed: Vec::new(),
+            unchanged: 0,
+        };
+        for (start_time, info) in current {
+            let pid = info.pid;
+            let previous = base.as_ref().and_then(|base| base.get(&pid));
+            if let Some((base_start, base_info)) = previous
+                && *base_start == start_time
+                && !significant(base_info, &info)
+            {
+                delta.unchanged += 1;
+                next.insert(pid, (start_time, base_info.clone()));
+                continue;
+            }
+            if !admit(&info) {
+                // Not sent, so the next call still diffs against what the client last saw
+                if let Some(previous) = previous {
+                    next.insert(pid, previous.clone());
+                }
+                continue;
+            }
+            match previous {
+                Some((base_start, _)) if *base_start == start_time => delta.changed.push(pid),
+                Some(_) => {
+                    // Same PID, different process
+                    delta.removed.push(pid);
+                    delta.added.push(pid);
+                }
+                None => delta.added.push(pid),
+            }
+            send.push(info.clone());
+            next.insert(pid, (start_time, info));
+        }
+        if let Some(base) = &base {
+            delta.removed.extend(base.keys().filter(|pid| !next.contains_key(pid)));
+            delta.removed.sort_unstable();
+        }
+
+        let token = self.store(next);
+        Some((send, base.map(|_| delta), token))
+    }
+
+    fn get(&self, token: &str) -> Option<Arc<Rows>> {
+        self.lock()
+            .snapshots
+            .iter()
+            .find(|(candidate, _)| candidate == token)
+            .map(|(_, rows)| rows.clone())
+    }
+
+    fn store(&self, rows: Rows) -> String {
+        let mut entries = self.lock();
+        entries.next_id += 1;
+        let token = format!("snap-{}", entries.next_id);
+        if entries.snapshots.len() >= LIST_SNAPSHOT_CAPACITY {
+            entries.snapshots.pop_front();
+        }
+        entries.snapshots.push_back((token.clone(), Arc::new(rows)));
+        token
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Entries> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Whether `new` differs enough from `old` to be re-sent
+pub(crate) fn significant(old: &ProcessInfo, new: &ProcessInfo) -> bool {
+    let memory_delta = old.rss_bytes.abs_diff(new.rss_bytes);
+    (new.cpu_percent - old.cpu_percent).abs() >= DELTA_CPU_POINTS
+        || (memory_delta >= DELTA_MEMORY_MIN_BYTES
+            && memory_delta as f64 >= old.rss_bytes as f64 * DELTA_MEMORY_RATIO)
+        || old.name != new.name
+        || old.tags != new.tags
+        || old.cmdline != new.cmdline
+}
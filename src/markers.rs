@@ -0,0 +1,150 @@
+//! Named process snapshots for before/after comparisons
+//!
+//! `process_mark` records the process table under a name such as
+//! `before-deploy`; `process_mark_compare` diffs the current table against it.
+//! Rows are keyed by PID and start time like delta snapshots, so a restarted
+//! service shows up as vanished plus new rather than as changed. Markers live
+//! in memory and are lost when the server restarts.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use crate::list_delta;
+use crate::schema::{MarkerChange, MarkerComparison, MarkerInfo, MarkerProcess, MarkerTotals, ProcessInfo};
+
+/// Markers kept at once; recording another name beyond this is refused
+pub const MARKER_CAPACITY: usize = 32;
+
+pub struct Marker {
+    pub info: MarkerInfo,
+    rows: HashMap<u32, (u64, ProcessInfo)>,
+}
+
+impl Marker {
+    /// Diff `current` (rows with their start time) against the marked table
+    #[must_use]
+    pub fn compare(&self, current: Vec<(u64, ProcessInfo)>) -> MarkerComparison {
+        let mut comparison = MarkerComparison {
+            before: self.info.totals,
+            after: totals(current.iter().map(|(_, info)| info)),
+            added: Vec::new(),
+            removed: Vec::new(),
+            changed: Vec::new(),
+            unchanged: 0,
+        };
+        let mut seen = HashSet::with_capacity(current.len());
+        for (start_time, info) in current {
+            match self.rows.get(&info.pid) {
+                Some((marked_start, marked)) if *marked_start == start_time => {
+                    seen.insert(info.pid);
+                    if list_delta::significant(marked, &info) {
+                        comparison.changed.push(MarkerChange {
+                            pid: info.pid,
+                            name: info.name.clone(),
+                            cpu_before: marked.cpu_percent,
+                            cpu_after: info.cpu_percent,
+                            rss_before: marked.rss_bytes,
+                            rss_after: info.rss_bytes,
+                            rss_delta_bytes: info.rss_bytes as i64 - marked.rss_bytes as i64,
+                        });
+                    } else {
+                        comparison.unchanged += 1;
+                    }
+                }
+                _ => comparison.added.push(process(&info)),
+            }
+        }
+        comparison.removed = self
+            .rows
+            .iter()
+            .filter(|(pid, _)| !seen.contains(pid))
+            .map(|(_, (_, info))| process(info))
+            .collect();
+
+        comparison.added.sort_unstable_by_key(|process| process.pid);
+        comparison.removed.sort_unstable_by_key(|process| process.pid);
+        comparison
+            .changed
+            .sort_by_key(|change| std::cmp::Reverse(change.rss_delta_bytes.unsigned_abs()));
+        comparison
+    }
+}
+
+#[derive(Default)]
+pub struct Markers {
+    markers: Mutex<BTreeMap<String, Arc<Marker>>>,
+}
+
+impl Markers {
+    /// Record `rows` under `name`, replacing a marker of the same name
+    ///
+    /// Returns the new marker and whether one was replaced.
+    pub fn record(
+        &self,
+        name: &str,
+        filter: Option<String>,
+        rows: Vec<(u64, ProcessInfo)>,
+    ) -> Result<(MarkerInfo, bool), String> {
+        let mut markers = self.lock();
+        if markers.len() >= MARKER_CAPACITY && !markers.contains_key(name) {
+            return Err(format!(
+                "{MARKER_CAPACITY} markers are already recorded; drop one with process_mark_compare first"
+            ));
+        }
+        let info = MarkerInfo {
+            name: name.to_string(),
+            filter,
+            taken_at: chrono::Utc::now().to_rfc3339(),
+            totals: totals(rows.iter().map(|(_, info)| info)),
+        };
+        let rows = rows.into_iter().map(|(start_time, info)| (info.pid, (start_time, info))).collect();
+        let replaced = markers
+            .insert(
+                name.to_string(),
+                Arc::new(Marker {
+                    info: info.clone(),
+                    rows,
+                }),
+            )
+            .is_some();
+        Ok((info, replaced))
+    }
+
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<Arc<Marker>> {
+        self.lock().get(name).cloned()
+    }
+
+    /// Delete a marker, returning whether it existed
+    pub fn remove(&self, name: &str) -> bool {
+        self.lock().remove(name).is_some()
+    }
+
+    /// Names of all markers, sorted
+    #[must_use]
+    pub fn names(&self) -> Vec<String> {
+        self.lock().keys().cloned().collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, Arc<Marker>>> {
+        self.markers.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn totals<'a>(rows: impl Iterator<Item = &'a ProcessInfo>) -> MarkerTotals {
+    rows.fold(MarkerTotals::default(), |mut totals, info| {
+        totals.count += 1;
+        totals.cpu_percent += f64::from(info.cpu_percent);
+        totals.rss_bytes += info.rss_bytes;
+        totals
+    })
+}
+
+fn process(info: &ProcessInfo) -> MarkerProcess {
+    MarkerProcess {
+        pid: info.pid,
+        name: info.name.clone(),
+        cpu_percent: info.cpu_percent,
+        rss_bytes: info.rss_bytes,
+    }
+}
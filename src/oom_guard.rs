@@ -0,0 +1,265 @@
+//! Memory watchdog that acts before the kernel OOM killer does
+//!
+//! A task compares every process's resident memory with the first configured
+//! rule whose pattern matches its name, once per interval. A process over
+//! its ceiling is killed through `process_terminate` (paid from the
+//! [`OOM_GUARD_CLIENT`] budget) or frozen, once per process lifetime. Each
+//! intervention is logged, kept for `process_oom_guard` and published as an
+//! [`ProcessEvent::OomGuard`] event. Protected processes are never touched.
+
+use std::collections::{HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use globset::{GlobBuilder, GlobMatcher};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+
+use crate::config::{OomGuardConfig, ProcessConfig};
+use crate::display::human_bytes;
+use crate::events::ProcessEvent;
+use crate::identity;
+use crate::process_terminate::ProcessTerminateTool;
+use crate::protection::protected_reason;
+use crate::sampling::MIN_WATCH_INTERVAL_MS;
+use crate::schema::{
+    OomGuardAction, OomGuardRule, OomIntervention, ProcessTerminateArgs, SummaryVerbosity, default_escalation,
+};
+use crate::state::ProcessState;
+
+/// Client the watchdog's kills are budgeted and recorded under
+pub const OOM_GUARD_CLIENT: &str = "oom-guard";
+
+/// Interventions kept for `process_oom_guard`
+const HISTORY: usize = 64;
+
+struct Rule {
+    info: OomGuardRule,
+    matcher: GlobMatcher,
+}
+
+#[derive(Default)]
+struct Log {
+    next_id: u64,
+    recent: VecDeque<OomIntervention>,
+}
+
+/// A process found over its ceiling
+struct Offender {
+    pid: u32,
+    name: String,
+    start_time: u64,
+    rss_bytes: u64,
+    rule: OomGuardRule,
+}
+
+#[derive(Default)]
+pub struct OomGuard {
+    rules: Vec<Rule>,
+    interval: Duration,
+    log: Mutex<Log>,
+}
+
+impl OomGuard {
+    /// Compile the rules; invalid patterns are logged and skipped
+    #[must_use]
+    pub fn new(config: &OomGuardConfig) -> Self {
+        let rules = config
+            .rules
+            .iter()
+            .filter_map(|rule| match GlobBuilder::new(&rule.pattern).case_insensitive(true).build() {
+                Ok(glob) => Some(Rule {
+                    info: rule.clone(),
+                    matcher: glob.compile_matcher(),
+                }),
+                Err(e) => {
+                    log::warn!("Ignoring invalid OOM guard pattern {:?}: {e}", rule.pattern);
+                    None
+                }
+            })
+            .collect();
+        Self {
+            rules,
+            interval: Duration::from_millis(config.interval_ms.max(MIN_WATCH_INTERVAL_MS)),
+            log: Mutex::default(),
+        }
+    }
+
+    /// Whether there is anything to enforce
+    #[must_use]
+    pub fn enabled(&self) -> bool {
+        !self.rules.is_empty()
+    }
+
+    /// Period between memory checks
+    #[must_use]
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Rules in the order they are checked
+    #[must_use]
+    pub fn rules(&self) -> Vec<OomGuardRule> {
+        self.rules.iter().map(|rule| rule.info.clone()).collect()
+    }
+
+    /// Newest-first interventions (`limit` 0 returns all)
+    #[must_use]
+    pub fn recent(&self, limit: usize) -> Vec<OomIntervention> {
+        let log = self.lock();
+        let newest = log.recent.iter().rev().cloned();
+        if limit > 0 { newest.take(limit).collect() } else { newest.collect() }
+    }
+
+    /// Store an intervention and return it with its id
+    fn record(&self, mut entry: OomIntervention) -> OomIntervention {
+        let mut log = self.lock();
+        log.next_id += 1;
+        entry.id = log.next_id;
+        if log.recent.len() >= HISTORY {
+            log.recent.pop_front();
+        }
+        log.recent.push_back(entry.clone());
+        entry
+    }
+
+    /// Processes over the ceiling of their first matching rule
+    fn offenders(&self, system: &System) -> Vec<Offender> {
+        system
+            .processes()
+            .iter()
+            .filter_map(|(pid, process)| {
+                let name = process.name().to_string_lossy();
+                let rule = self.rules.iter().find(|rule| rule.matcher.is_match(name.as_ref()))?;
+                if process.memory() <= rule.info.memory_max_bytes {
+                    return None;
+                }
+                let parent = process.parent().map(Pid::as_u32);
+                if protected_reason(pid.as_u32(), &name, parent).is_some() {
+                    return None;
+                }
+                Some(Offender {
+                    pid: pid.as_u32(),
+                    name: name.to_string(),
+                    start_time: process.start_time(),
+                    rss_bytes: process.memory(),
+                    rule: rule.info.clone(),
+                })
+            })
+            .collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Log> {
+        self.log.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Start the watchdog if any rule is configured
+///
+/// Must be called from within the Tokio runtime.
+pub(crate) fn start(config: Arc<ProcessConfig>, state: Arc<ProcessState>) {
+    if !state.oom_guard.enabled() {
+        return;
+    }
+    tokio::spawn(async move {
+        let mut system = System::new();
+        // (pid, start_time) already acted on, so each process is handled once
+        let mut handled: HashSet<(u32, u64)> = HashSet::new();
+        loop {
+            tokio::time::sleep(state.oom_guard.interval()).await;
+            let sampler = state.clone();
+            let Ok((sampled, offenders)) = tokio::task::spawn_blocking(move || {
+                let kind = ProcessRefreshKind::nothing().with_memory().without_tasks();
+                system.refresh_processes_specifics(ProcessesToUpdate::All, true, kind);
+                let offenders = sampler.oom_guard.offenders(&system);
+                (system, offenders)
+            })
+            .await
+            else {
+                break;
+            };
+            system = sampled;
+            handled.retain(|(pid, start_time)| {
+                system
+                    .process(Pid::from_u32(*pid))
+                    .is_some_and(|process| process.start_time() == *start_time)
+            });
+            for offender in offenders {
+                if handled.insert((offender.pid, offender.start_time)) {
+                    intervene(&config, &state, offender).await;
+                }
+            }
+        }
+    });
+}
+
+/// Kill or freeze one offender and record what happened
+async fn intervene(config: &Arc<ProcessConfig>, state: &Arc<ProcessState>, offender: Offender) {
+    let pid = offender.pid;
+    let (success, outcome) = match offender.rule.action {
+        OomGuardAction::Kill => {
+            let args = ProcessTerminateArgs {
+                pid: None,
+                proc_id: Some(identity::proc_id(pid, offender.start_time)),
+                container: None,
+                plan: default_escalation(),
+                // The rule is the operator's standing confirmation
+                confirm: true,
+                style: None,
+                verbosity: Some(SummaryVerbosity::Quiet),
+            };
+            match ProcessTerminateTool::new(config.clone(), state.clone())
+                .terminate(args, Some(OOM_GUARD_CLIENT))
+                .await
+            {
+                Ok(response) if response.metadata.success => (
+                    true,
+                    format!(
+                        "killed by {}",
+                        response.metadata.succeeded_signal.as_deref().unwrap_or("signal")
+                    ),
+                ),
+                Ok(_) => (false, "survived the escalation plan".to_string()),
+                Err(e) => (false, e.to_string()),
+            }
+        }
+        OomGuardAction::Freeze => {
+            let freezer = state.clone();
+            let start_time = offender.start_time;
+            tokio::task::spawn_blocking(move || {
+                let Some(process) = freezer.provider.lookup(pid, false).filter(|p| p.start_time == start_time) else {
+                    return (false, "exited before it could be frozen".to_string());
+                };
+                match freezer.freezes.freeze(freezer.provider.as_ref(), &process, None) {
+                    Ok((info, _)) => (
+                        true,
+                        format!("froze {} process(es) via {}; thaw with process_thaw", info.members.len(), info.method.name()),
+                    ),
+                    Err(e) => (false, format!("freeze failed: {e}")),
+                }
+            })
+            .await
+            .unwrap_or_else(|e| (false, format!("freeze task failed: {e}")))
+        }
+    };
+
+    log::warn!(
+        "OOM guard: PID {pid} ({}) at {} exceeded {} ({}): {outcome}",
+        offender.name,
+        human_bytes(offender.rss_bytes),
+        human_bytes(offender.rule.memory_max_bytes),
+        offender.rule.pattern
+    );
+    let entry = state.oom_guard.record(OomIntervention {
+        id: 0,
+        pid,
+        name: offender.name,
+        pattern: offender.rule.pattern,
+        rss_bytes: offender.rss_bytes,
+        memory_max_bytes: offender.rule.memory_max_bytes,
+        action: offender.rule.action,
+        success,
+        outcome,
+        at: chrono::Utc::now().to_rfc3339(),
+    });
+    state.events.publish(ProcessEvent::OomGuard(entry));
+}
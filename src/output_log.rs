@@ -0,0 +1,103 @@
+//! Size-rotated capture of supervised process output
+//!
+//! Each stream is written to `<stream>.log` in the process's log directory.
+//! When a write would push the live segment past the configured size it is
+//! renamed to `<stream>.log.1`, older segments shift up by one, and anything
+//! beyond the retained count is deleted.
+
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::config::OutputLogConfig;
+use crate::schema::{LogSegment, LogStream};
+
+/// Writer for one stream of one process
+pub(crate) struct RotatingLog {
+    path: PathBuf,
+    file: File,
+    written: u64,
+    segment_bytes: u64,
+    segments: usize,
+}
+
+impl RotatingLog {
+    /// Create (or truncate) the live segment for `stream` in `dir`
+    pub fn create(dir: &Path, stream: LogStream, config: &OutputLogConfig) -> std::io::Result<Self> {
+        let path = segment_path(dir, stream, 0);
+        Ok(Self {
+            file: File::create(&path)?,
+            path,
+            written: 0,
+            segment_bytes: config.segment_bytes,
+            segments: config.segments,
+        })
+    }
+
+    /// Append one chunk of output, rotating first if it would overflow the segment
+    pub fn write(&mut self, chunk: &[u8]) -> std::io::Result<()> {
+        let len = chunk.len() as u64;
+        if self.segment_bytes > 0 && self.written > 0 && self.written + len > self.segment_bytes {
+            self.rotate()?;
+        }
+        self.file.write_all(chunk)?;
+        self.written += len;
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> std::io::Result<()> {
+        if self.segments > 0 {
+            let rotated = |index: usize| PathBuf::from(format!("{}.{index}", self.path.display()));
+            // Oldest first so nothing is overwritten; the last one falls off
+            let _ = std::fs::remove_file(rotated(self.segments));
+            for index in (1..self.segments).rev() {
+                let from = rotated(index);
+                if from.exists() {
+                    std::fs::rename(&from, rotated(index + 1))?;
+                }
+            }
+            std::fs::rename(&self.path, rotated(1))?;
+        }
+        self.file = OpenOptions::new().write(true).create(true).truncate(true).open(&self.path)?;
+        self.written = 0;
+        Ok(())
+    }
+}
+
+/// Path of segment `index` (0 is the live segment)
+#[must_use]
+pub(crate) fn segment_path(dir: &Path, stream: LogStream, index: u32) -> PathBuf {
+    match index {
+        0 => dir.join(format!("{}.log", stream.name())),
+        index => dir.join(format!("{}.log.{index}", stream.name())),
+    }
+}
+
+/// Existing segments of `stream`, live segment first
+#[must_use]
+pub(crate) fn segments(dir: &Path, stream: LogStream) -> Vec<LogSegment> {
+    (0..)
+        .map_while(|index| {
+            let path = segment_path(dir, stream, index);
+            let metadata = std::fs::metadata(&path).ok()?;
+            Some(LogSegment {
+                index,
+                path: path.to_string_lossy().to_string(),
+                bytes: metadata.len(),
+                modified: metadata
+                    .modified()
+                    .ok()
+                    .map(|modified| chrono::DateTime::<chrono::Utc>::from(modified).to_rfc3339()),
+            })
+        })
+        .collect()
+}
+
+/// Last `lines` lines of a segment (all when 0), plus whether lines were dropped
+pub(crate) fn tail(path: &Path, lines: usize) -> std::io::Result<(Vec<String>, bool)> {
+    let content = std::fs::read(path)?;
+    let content = String::from_utf8_lossy(&content);
+    let all: Vec<&str> = content.lines().collect();
+    let skip = if lines == 0 { 0 } else { all.len().saturating_sub(lines) };
+    Ok((all[skip..].iter().map(|line| (*line).to_string()).collect(), skip > 0))
+}
@@ -0,0 +1,58 @@
+//! Packaging and runtime origin of a process, guessed from its exe path and cgroup
+//!
+//! Nothing here asks a package manager: paths are matched against where snap,
+//! flatpak, AppImage and Homebrew put executables and where the OS keeps its own.
+
+use std::path::Path;
+
+use crate::platform;
+use crate::schema::{Packaging, ProcessKind};
+
+/// Where Homebrew installs on Apple silicon, Intel macOS and Linux
+const HOMEBREW_PREFIXES: &[&str] = &["/opt/homebrew/", "/usr/local/Cellar/", "/usr/local/Homebrew/", "/home/linuxbrew/.linuxbrew/"];
+
+/// Directories owned by the OS or its package manager
+const SYSTEM_PREFIXES: &[&str] = &[
+    "/usr/bin/", "/usr/sbin/", "/usr/lib/", "/usr/lib64/", "/usr/libexec/", "/usr/share/", "/bin/", "/sbin/", "/lib/",
+    "/lib64/", "/System/", "/Library/Apple/",
+];
+
+/// Classify `pid`; `None` for kernel threads and when too little can be read to tell
+pub(crate) fn packaging(pid: u32, kind: Option<ProcessKind>) -> Option<Packaging> {
+    let kind = kind.or_else(|| platform::process_kind(pid));
+    if kind == Some(ProcessKind::Kernel) {
+        return None;
+    }
+    let exe = platform::exe_path(pid);
+    let cgroup = platform::cgroup_paths(pid).map(|lines| lines.join("\n")).unwrap_or_default();
+    classify(exe.as_deref(), &cgroup, kind == Some(ProcessKind::Container))
+}
+
+fn classify(exe: Option<&Path>, cgroup: &str, container: bool) -> Option<Packaging> {
+    let exe = exe.map(|exe| exe.to_string_lossy()).unwrap_or_default();
+    // systemd names the scopes of sandboxed apps after their runtime
+    if exe.starts_with("/snap/") || cgroup.contains("/snap.") {
+        return Some(Packaging::Snap);
+    }
+    if cgroup.contains("app-flatpak-") || exe.starts_with("/app/") {
+        return Some(Packaging::Flatpak);
+    }
+    // An AppImage runs from its FUSE mount, /tmp/.mount_<name><random>
+    if exe.contains("/.mount_") {
+        return Some(Packaging::AppImage);
+    }
+    if container {
+        return Some(Packaging::Container);
+    }
+    if exe.is_empty() {
+        return None;
+    }
+    if HOMEBREW_PREFIXES.iter().any(|prefix| exe.starts_with(prefix)) {
+        return Some(Packaging::Homebrew);
+    }
+    let windows = exe.get(1..).is_some_and(|rest| rest.to_ascii_lowercase().starts_with(":\\windows\\"));
+    if windows || SYSTEM_PREFIXES.iter().any(|prefix| exe.starts_with(prefix)) {
+        return Some(Packaging::System);
+    }
+    Some(Packaging::Unpackaged)
+}
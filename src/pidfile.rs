@@ -0,0 +1,132 @@
+//! PID files written for daemonized processes
+//!
+//! A pidfile holds the decimal PID followed by a newline. Writes go through a
+//! temporary file and a rename so readers never see a partial PID. Reads are
+//! validated against the live process table, see [`check`].
+
+use std::path::{Path, PathBuf};
+
+use sysinfo::{Pid, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, System, UpdateKind};
+
+use crate::schema::PidfileStatus;
+
+/// Atomically write `pid` to `path`, creating parent directories
+pub(crate) fn write(path: &Path, pid: u32) -> std::io::Result<()> {
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_file_name(format!(
+        ".{}.tmp",
+        path.file_name().map_or_else(|| "pid".into(), |name| name.to_string_lossy())
+    ));
+    std::fs::write(&tmp, format!("{pid}\n"))?;
+    std::fs::rename(&tmp, path)
+}
+
+/// Resolve a caller-supplied pidfile path against the client's working directory
+#[must_use]
+pub(crate) fn resolve(path: &str, pwd: Option<&Path>) -> PathBuf {
+    match pwd {
+        Some(pwd) => pwd.join(path),
+        None => PathBuf::from(path),
+    }
+}
+
+/// Read `path` and check it against the live process table
+///
+/// A pidfile is stale when it cannot be parsed, names a PID that is not
+/// running, names a process whose name differs from `expected_name`, or names
+/// a process that started after the file was written (a recycled PID).
+#[must_use]
+pub(crate) fn check(path: &Path, expected_name: Option<&str>) -> PidfileStatus {
+    let mut status = PidfileStatus {
+        path: path.to_string_lossy().to_string(),
+        exists: false,
+        pid: None,
+        alive: false,
+        name: None,
+        name_matches: None,
+        written_at: None,
+        stale: true,
+        reason: None,
+    };
+
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            status.reason = Some(format!("unreadable: {e}"));
+            return status;
+        }
+    };
+    status.exists = true;
+    let written = std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok();
+    status.written_at = written.map(|written| chrono::DateTime::<chrono::Utc>::from(written).to_rfc3339());
+
+    let Some(pid) = content
+        .split_whitespace()
+        .next()
+        .and_then(|token| token.parse::<u32>().ok())
+        .filter(|pid| *pid > 0)
+    else {
+        status.reason = Some("does not contain a PID".to_string());
+        return status;
+    };
+    status.pid = Some(pid);
+
+    let sysinfo_pid = Pid::from_u32(pid);
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[sysinfo_pid]),
+        true,
+        ProcessRefreshKind::nothing().with_exe(UpdateKind::OnlyIfNotSet),
+    );
+    let Some(process) = system.process(sysinfo_pid) else {
+        status.reason = Some(format!("PID {pid} is not running"));
+        return status;
+    };
+    if process.status() == ProcessStatus::Zombie {
+        status.reason = Some(format!("PID {pid} has exited and not been reaped yet"));
+        return status;
+    }
+    status.alive = true;
+    let name = process.name().to_string_lossy().to_string();
+
+    if let Some(expected) = expected_name {
+        let matches = name_matches(&name, process.exe(), expected);
+        status.name_matches = Some(matches);
+        if !matches {
+            status.reason = Some(format!("PID {pid} is {name}, not {expected}"));
+            status.name = Some(name);
+            return status;
+        }
+    }
+    status.name = Some(name);
+
+    let written_secs = written
+        .and_then(|written| written.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|since| since.as_secs());
+    // One second of slack for filesystems with coarse timestamps
+    if let Some(written_secs) = written_secs
+        && process.start_time() > written_secs + 1
+    {
+        status.reason = Some(format!("PID {pid} was reused: the process started after the pidfile was written"));
+        return status;
+    }
+
+    status.stale = false;
+    status
+}
+
+/// Case-insensitive match against the process name or executable file name, ignoring extensions
+fn name_matches(name: &str, exe: Option<&Path>, expected: &str) -> bool {
+    let stem = |text: &str| {
+        Path::new(text)
+            .file_stem()
+            .map_or_else(|| text.to_lowercase(), |stem| stem.to_string_lossy().to_lowercase())
+    };
+    let expected = stem(expected);
+    stem(name) == expected
+        || exe
+            .and_then(Path::file_stem)
+            .is_some_and(|exe| exe.to_string_lossy().to_lowercase() == expected)
+}
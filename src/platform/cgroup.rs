@@ -0,0 +1,163 @@
+//! cgroup v2 confinement (Linux only)
+//!
+//! Confined processes get a leaf cgroup of their own below one base
+//! directory at the top of the unified hierarchy and are moved back to the
+//! cgroup they came from on release.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use super::CgroupLeaf;
+
+/// Directory below the hierarchy root holding the server's cgroups
+const BASE_DIR: &str = "kodegen-process";
+
+/// Mount point of the unified hierarchy from `/proc/self/mountinfo`
+fn unified_mount() -> Option<PathBuf> {
+    let mountinfo = fs::read_to_string("/proc/self/mountinfo").ok()?;
+    mountinfo.lines().find_map(|line| {
+        let (mount, filesystem) = line.split_once(" - ")?;
+        if filesystem.split_whitespace().next()? == "cgroup2" {
+            Some(PathBuf::from(mount.split_whitespace().nth(4)?))
+        } else {
+            None
+        }
+    })
+}
+
+/// `pid`'s cgroup relative to the hierarchy root, from the `0::` line of `/proc/<pid>/cgroup`
+fn membership(pid: u32) -> Option<String> {
+    fs::read_to_string(format!("/proc/{pid}/cgroup"))
+        .ok()?
+        .lines()
+        .find_map(|line| line.strip_prefix("0::"))
+        .map(str::to_string)
+}
+
+/// Whether a whitespace-separated control file value contains `item`
+fn listed(list: &str, item: &str) -> bool {
+    list.split_whitespace().any(|entry| entry == item)
+}
+
+/// Enable `controllers` for the children of `dir` where not already enabled
+fn enable_controllers(dir: &Path, controllers: &[&str]) -> io::Result<()> {
+    let control = dir.join("cgroup.subtree_control");
+    let enabled = fs::read_to_string(&control)?;
+    let missing: Vec<String> = controllers
+        .iter()
+        .filter(|controller| !listed(&enabled, controller))
+        .map(|controller| format!("+{controller}"))
+        .collect();
+    if missing.is_empty() {
+        return Ok(());
+    }
+    fs::write(control, missing.join(" "))
+}
+
+/// Move `pid` into a leaf cgroup of its own with `controllers` enabled
+pub(crate) fn cgroup_confine(pid: u32, controllers: &[&str]) -> io::Result<CgroupLeaf> {
+    let root = unified_mount().ok_or_else(|| io::Error::new(io::ErrorKind::Unsupported, "no cgroup v2 hierarchy"))?;
+    let available = fs::read_to_string(root.join("cgroup.controllers"))?;
+    let missing: Vec<&str> = controllers
+        .iter()
+        .copied()
+        .filter(|controller| !listed(&available, controller))
+        .collect();
+    if !missing.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("cgroup v2 lacks the {} controller", missing.join("/")),
+        ));
+    }
+    let previous = membership(pid).ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+    let previous = root.join(previous.trim_start_matches('/'));
+    let base = root.join(BASE_DIR);
+    if previous.starts_with(&base) {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, "already in a managed cgroup"));
+    }
+
+    enable_controllers(&root, controllers)?;
+    match fs::create_dir(&base) {
+        Err(e) if e.kind() != io::ErrorKind::AlreadyExists => return Err(e),
+        _ => {}
+    }
+    enable_controllers(&base, controllers)?;
+    let path = base.join(format!("pid-{pid}"));
+    match fs::create_dir(&path) {
+        Err(e) if e.kind() != io::ErrorKind::AlreadyExists => return Err(e),
+        _ => {}
+    }
+    if let Err(e) = fs::write(path.join("cgroup.procs"), pid.to_string()) {
+        let _ = fs::remove_dir(&path);
+        return Err(e);
+    }
+    Ok(CgroupLeaf { path, previous })
+}
+
+/// Move `pid` into `leaf` if it lives in the cgroup the confined process came from
+///
+/// Returns `false` for processes in any other cgroup, which are left alone.
+pub(crate) fn cgroup_join(leaf: &CgroupLeaf, pid: u32) -> io::Result<bool> {
+    let (Some(current), Some(root)) = (membership(pid), leaf.path.parent().and_then(Path::parent)) else {
+        return Err(io::Error::from(io::ErrorKind::NotFound));
+    };
+    if root.join(current.trim_start_matches('/')) != leaf.previous {
+        return Ok(false);
+    }
+    fs::write(leaf.path.join("cgroup.procs"), pid.to_string())?;
+    Ok(true)
+}
+
+/// Freeze or thaw every member of `leaf` through `cgroup.freeze`
+///
+/// Waits up to `settle` for `cgroup.events` to confirm and returns whether it did;
+/// tasks in uninterruptible sleep can hold the transition back.
+pub(crate) fn cgroup_freeze(leaf: &CgroupLeaf, frozen: bool, settle: Duration) -> io::Result<bool> {
+    let wanted = if frozen { "frozen 1" } else { "frozen 0" };
+    fs::write(leaf.path.join("cgroup.freeze"), if frozen { "1" } else { "0" })?;
+    let started = Instant::now();
+    loop {
+        if fs::read_to_string(leaf.path.join("cgroup.events"))?.lines().any(|line| line == wanted) {
+            return Ok(true);
+        }
+        if started.elapsed() >= settle {
+            return Ok(false);
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// Write one control file of a confined process's cgroup, e.g. `cpu.max`
+pub(crate) fn cgroup_set(leaf: &CgroupLeaf, file: &str, value: &str) -> io::Result<()> {
+    fs::write(leaf.path.join(file), value)
+}
+
+/// Read one control file of a confined process's cgroup, e.g. `memory.current`
+pub(crate) fn cgroup_get(leaf: &CgroupLeaf, file: &str) -> io::Result<String> {
+    fs::read_to_string(leaf.path.join(file)).map(|value| value.trim().to_string())
+}
+
+/// Move every member back to where the confined process came from and remove the leaf
+///
+/// Children forked while confined go back too, so the leaf can be removed;
+/// falls back to the hierarchy root when the original cgroup is gone.
+pub(crate) fn cgroup_release(leaf: &CgroupLeaf) -> io::Result<()> {
+    let members = fs::read_to_string(leaf.path.join("cgroup.procs"))?;
+    for pid in members.split_whitespace() {
+        let moved = fs::write(leaf.previous.join("cgroup.procs"), pid).or_else(|e| {
+            match leaf.path.parent().and_then(Path::parent) {
+                Some(root) if e.raw_os_error() != Some(libc::ESRCH) => fs::write(root.join("cgroup.procs"), pid),
+                _ => Err(e),
+            }
+        });
+        // ESRCH: the process exited in between
+        if let Err(e) = moved
+            && e.raw_os_error() != Some(libc::ESRCH)
+        {
+            return Err(e);
+        }
+    }
+    fs::remove_dir(&leaf.path)
+}
@@ -0,0 +1,489 @@
+//! Exec and exit tracing with eBPF on the `sched` tracepoints
+//!
+//! Two small programs, assembled here so no BPF toolchain or loader crate is
+//! needed, run on `sched_process_exec` and `sched_process_exit` and copy the
+//! PID, comm, a monotonic timestamp and (for exec) the filename into a BPF
+//! ring buffer. Unlike diffing process tables they see every process, however
+//! short-lived. Events the kernel could not queue because the ring was full
+//! are counted in a second map instead of vanishing silently.
+
+use std::io;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+
+/// Bytes of the ring buffer shared with the programs (a power of two)
+const RING_BYTES: u32 = 1 << 20;
+
+/// Size of one event record written by the programs
+const EVENT_BYTES: usize = 288;
+
+/// Longest filename copied per exec, including the terminating NUL
+const FILENAME_BYTES: usize = 256;
+
+/// Where tracefs is mounted on current and older systems
+const TRACEFS: &[&str] = &["/sys/kernel/tracing", "/sys/kernel/debug/tracing"];
+
+/// What a traced event reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TraceKind {
+    Exec,
+    Exit,
+}
+
+/// One event as copied out of the kernel
+#[derive(Debug, Clone)]
+pub(crate) struct TraceRecord {
+    pub kind: TraceKind,
+    /// Thread group ID, i.e. the process ID
+    pub pid: u32,
+    /// `CLOCK_MONOTONIC` nanoseconds when the tracepoint fired
+    pub monotonic_ns: u64,
+    /// Task name after the exec, or at exit
+    pub comm: String,
+    /// Path passed to `execve`; exec events only
+    pub filename: Option<String>,
+}
+
+/// Loaded programs, their tracepoint attachments and the mapped ring buffer
+pub(crate) struct ExecTracer {
+    ring: OwnedFd,
+    lost: OwnedFd,
+    /// Perf events the programs are attached through; dropping them detaches
+    _attachments: Vec<OwnedFd>,
+    consumer: *mut u8,
+    producer: *mut u8,
+    page: usize,
+}
+
+// SAFETY: the mappings are owned by the tracer and only touched through `&mut self`.
+unsafe impl Send for ExecTracer {}
+
+impl ExecTracer {
+    /// Load and attach both programs
+    ///
+    /// Needs root or `CAP_BPF` with `CAP_PERFMON`, a kernel with BPF ring
+    /// buffers (5.8+) and tracefs mounted.
+    pub(crate) fn open() -> io::Result<Self> {
+        let ring = map_create(BPF_MAP_TYPE_RINGBUF, 0, 0, RING_BYTES)?;
+        let lost = map_create(BPF_MAP_TYPE_ARRAY, 4, 8, 1)?;
+        let mut attachments = Vec::new();
+        for (tracepoint, kind) in [("sched_process_exec", TraceKind::Exec), ("sched_process_exit", TraceKind::Exit)] {
+            let program = prog_load(&program(kind, ring.as_raw_fd(), lost.as_raw_fd()))?;
+            attachments.push(attach(tracepoint, &program)?);
+        }
+
+        // SAFETY: sysconf has no preconditions.
+        let page = usize::try_from(unsafe { libc::sysconf(libc::_SC_PAGESIZE) }).unwrap_or(4096);
+        // The consumer position page is writable; the producer page is followed
+        // by the data pages, mapped twice so a record never wraps
+        let consumer = map_shared(&ring, page, libc::PROT_READ | libc::PROT_WRITE, 0)?;
+        let producer = match map_shared(&ring, page + 2 * RING_BYTES as usize, libc::PROT_READ, page) {
+            Ok(producer) => producer,
+            Err(e) => {
+                // SAFETY: consumer was mapped above with this length.
+                unsafe { libc::munmap(consumer.cast(), page) };
+                return Err(e);
+            }
+        };
+        Ok(Self {
+            ring,
+            lost,
+            _attachments: attachments,
+            consumer,
+            producer,
+            page,
+        })
+    }
+
+    /// Wait up to `timeout_ms` for events and hand every queued one to `sink`
+    pub(crate) fn poll(&mut self, timeout_ms: i32, mut sink: impl FnMut(TraceRecord)) -> io::Result<()> {
+        let mut wait = libc::pollfd {
+            fd: self.ring.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        // SAFETY: wait is one valid pollfd.
+        if unsafe { libc::poll(&mut wait, 1, timeout_ms) } < 0 {
+            let error = io::Error::last_os_error();
+            return if error.kind() == io::ErrorKind::Interrupted { Ok(()) } else { Err(error) };
+        }
+
+        // SAFETY: both positions are 8-byte aligned u64s at the start of their pages,
+        // which stay mapped for the tracer's lifetime.
+        let (consumer, producer) = unsafe { (&*self.consumer.cast::<AtomicU64>(), &*self.producer.cast::<AtomicU64>()) };
+        // SAFETY: the data pages start one page after the producer position.
+        let data = unsafe { self.producer.add(self.page) };
+        let mask = u64::from(RING_BYTES) - 1;
+        let mut position = consumer.load(Ordering::Acquire);
+        while position < producer.load(Ordering::Acquire) {
+            // SAFETY: every record starts with an 8-byte aligned header inside the data pages.
+            let header = unsafe { &*data.add((position & mask) as usize).cast::<AtomicU32>() }.load(Ordering::Acquire);
+            if header & RINGBUF_BUSY_BIT != 0 {
+                break;
+            }
+            let len = (header & !(RINGBUF_BUSY_BIT | RINGBUF_DISCARD_BIT)) as usize;
+            if header & RINGBUF_DISCARD_BIT == 0 && len >= EVENT_BYTES {
+                // SAFETY: the record body follows its header and, thanks to the double
+                // mapping, is contiguous even when it crosses the end of the ring.
+                let body = unsafe { std::slice::from_raw_parts(data.add((position & mask) as usize + 8), EVENT_BYTES) };
+                sink(decode(body));
+            }
+            position += (len as u64 + 8).next_multiple_of(8);
+            consumer.store(position, Ordering::Release);
+        }
+        Ok(())
+    }
+
+    /// Events dropped so far because the ring buffer was full
+    pub(crate) fn lost(&self) -> u64 {
+        let key = 0u32;
+        let mut value = 0u64;
+        let mut attr = MapElemAttr {
+            map_fd: self.lost.as_raw_fd() as u32,
+            _pad: 0,
+            key: (&raw const key) as u64,
+            value: (&raw mut value) as u64,
+            flags: 0,
+        };
+        match bpf(BPF_MAP_LOOKUP_ELEM, &mut attr) {
+            Ok(_) => value,
+            Err(_) => 0,
+        }
+    }
+}
+
+impl Drop for ExecTracer {
+    fn drop(&mut self) {
+        // SAFETY: both regions were mapped in `open` with these lengths and are unmapped once.
+        unsafe {
+            libc::munmap(self.consumer.cast(), self.page);
+            libc::munmap(self.producer.cast(), self.page + 2 * RING_BYTES as usize);
+        }
+    }
+}
+
+fn decode(body: &[u8]) -> TraceRecord {
+    let text = |bytes: &[u8]| {
+        let end = bytes.iter().position(|byte| *byte == 0).unwrap_or(bytes.len());
+        String::from_utf8_lossy(&bytes[..end]).to_string()
+    };
+    let kind = if u32::from_ne_bytes([body[0], body[1], body[2], body[3]]) == 0 { TraceKind::Exec } else { TraceKind::Exit };
+    let filename = text(&body[32..32 + FILENAME_BYTES]);
+    TraceRecord {
+        kind,
+        pid: u32::from_ne_bytes([body[4], body[5], body[6], body[7]]),
+        monotonic_ns: u64::from_ne_bytes(body[8..16].try_into().unwrap_or_default()),
+        comm: text(&body[16..32]),
+        filename: (kind == TraceKind::Exec && !filename.is_empty()).then_some(filename),
+    }
+}
+
+// ============================================================================
+// PROGRAMS
+// ============================================================================
+
+const BPF_PSEUDO_MAP_FD: u8 = 1;
+
+const HELPER_MAP_LOOKUP_ELEM: i32 = 1;
+const HELPER_KTIME_GET_NS: i32 = 5;
+const HELPER_GET_CURRENT_PID_TGID: i32 = 14;
+const HELPER_GET_CURRENT_COMM: i32 = 16;
+const HELPER_PROBE_READ_KERNEL_STR: i32 = 115;
+const HELPER_RINGBUF_OUTPUT: i32 = 130;
+
+/// Offset of the `__data_loc` filename field in the `sched_process_exec` record
+const EXEC_FILENAME_LOC: i16 = 8;
+
+/// Frame pointer offsets of the event record and the lost-counter key
+const EVENT_AT: i16 = -(EVENT_BYTES as i16);
+const KEY_AT: i16 = EVENT_AT - 8;
+
+/// Program for one tracepoint
+///
+/// The event is built on the stack: kind (u32), tgid (u32), monotonic ns
+/// (u64), comm (16 bytes) and filename (256 bytes). Exit events of threads
+/// other than the group leader are ignored, so each process exits once.
+fn program(kind: TraceKind, ring: i32, lost: i32) -> Vec<Insn> {
+    let mut asm = Asm::default();
+    asm.push(Insn::new(0xbf, 6, 1, 0, 0)); // r6 = ctx
+    for offset in (1..=EVENT_BYTES as i16 / 8).map(|slot| -slot * 8) {
+        asm.push(Insn::new(0x7a, 10, 0, offset, 0)); // *(u64 *)(fp + offset) = 0
+    }
+    asm.call(HELPER_GET_CURRENT_PID_TGID);
+    asm.push(Insn::new(0xbf, 7, 0, 0, 0)); // r7 = pid_tgid
+    asm.push(Insn::new(0x77, 7, 0, 0, 32)); // r7 >>= 32: tgid
+    if kind == TraceKind::Exit {
+        asm.push(Insn::new(0xbc, 8, 0, 0, 0)); // w8 = w0: thread id
+        asm.jump_to_end(0x5d, 8, 7, 0); // thread id != tgid: not the leader
+    }
+    asm.push(Insn::new(0x62, 10, 0, EVENT_AT, i32::from(kind == TraceKind::Exit))); // kind
+    asm.push(Insn::new(0x63, 10, 7, EVENT_AT + 4, 0)); // tgid
+    asm.call(HELPER_KTIME_GET_NS);
+    asm.push(Insn::new(0x7b, 10, 0, EVENT_AT + 8, 0)); // timestamp
+    asm.stack_pointer(1, EVENT_AT + 16);
+    asm.push(Insn::new(0xb7, 2, 0, 0, 16));
+    asm.call(HELPER_GET_CURRENT_COMM);
+    if kind == TraceKind::Exec {
+        // The low half of `__data_loc` is the string's offset within the record
+        asm.push(Insn::new(0x61, 3, 6, EXEC_FILENAME_LOC, 0)); // r3 = data_loc
+        asm.push(Insn::new(0x57, 3, 0, 0, 0xffff)); // r3 &= 0xffff
+        asm.push(Insn::new(0x0f, 3, 6, 0, 0)); // r3 += ctx
+        asm.stack_pointer(1, EVENT_AT + 32);
+        asm.push(Insn::new(0xb7, 2, 0, 0, FILENAME_BYTES as i32));
+        asm.call(HELPER_PROBE_READ_KERNEL_STR);
+    }
+    asm.map(1, ring);
+    asm.stack_pointer(2, EVENT_AT);
+    asm.push(Insn::new(0xb7, 3, 0, 0, EVENT_BYTES as i32));
+    asm.push(Insn::new(0xb7, 4, 0, 0, 0));
+    asm.call(HELPER_RINGBUF_OUTPUT);
+    asm.jump_to_end(0x15, 0, 0, 0); // queued
+    // Ring full: count the drop
+    asm.push(Insn::new(0x62, 10, 0, KEY_AT, 0));
+    asm.map(1, lost);
+    asm.stack_pointer(2, KEY_AT);
+    asm.call(HELPER_MAP_LOOKUP_ELEM);
+    asm.jump_to_end(0x15, 0, 0, 0);
+    asm.push(Insn::new(0xb7, 1, 0, 0, 1));
+    asm.push(Insn::new(0xdb, 0, 1, 0, 0)); // lock *(u64 *)(r0 + 0) += r1
+    asm.finish()
+}
+
+/// One BPF instruction
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct Insn {
+    code: u8,
+    /// Destination register in the low nibble, source in the high one
+    regs: u8,
+    off: i16,
+    imm: i32,
+}
+
+impl Insn {
+    const fn new(code: u8, dst: u8, src: u8, off: i16, imm: i32) -> Self {
+        Self { code, regs: (src << 4) | dst, off, imm }
+    }
+}
+
+/// Instruction list whose early exits all jump to a shared `return 0`
+#[derive(Default)]
+struct Asm {
+    insns: Vec<Insn>,
+    exits: Vec<usize>,
+}
+
+impl Asm {
+    fn push(&mut self, insn: Insn) {
+        self.insns.push(insn);
+    }
+
+    fn call(&mut self, helper: i32) {
+        self.push(Insn::new(0x85, 0, 0, 0, helper));
+    }
+
+    /// `reg = fp + offset`
+    fn stack_pointer(&mut self, reg: u8, offset: i16) {
+        self.push(Insn::new(0xbf, reg, 10, 0, 0));
+        self.push(Insn::new(0x07, reg, 0, 0, i32::from(offset)));
+    }
+
+    /// `reg = map`, a two-slot load the kernel resolves from the map fd
+    fn map(&mut self, reg: u8, fd: i32) {
+        self.push(Insn::new(0x18, reg, BPF_PSEUDO_MAP_FD, 0, fd));
+        self.push(Insn::new(0, 0, 0, 0, 0));
+    }
+
+    /// Conditional jump to the final `return 0`
+    fn jump_to_end(&mut self, code: u8, dst: u8, src: u8, imm: i32) {
+        self.exits.push(self.insns.len());
+        self.push(Insn::new(code, dst, src, 0, imm));
+    }
+
+    fn finish(mut self) -> Vec<Insn> {
+        let end = self.insns.len();
+        for at in std::mem::take(&mut self.exits) {
+            self.insns[at].off = i16::try_from(end - at - 1).unwrap_or(i16::MAX);
+        }
+        self.push(Insn::new(0xb7, 0, 0, 0, 0)); // r0 = 0
+        self.push(Insn::new(0x95, 0, 0, 0, 0)); // exit
+        self.insns
+    }
+}
+
+// ============================================================================
+// SYSCALLS
+// ============================================================================
+
+const BPF_MAP_CREATE: libc::c_long = 0;
+const BPF_MAP_LOOKUP_ELEM: libc::c_long = 1;
+const BPF_PROG_LOAD: libc::c_long = 5;
+
+const BPF_MAP_TYPE_ARRAY: u32 = 2;
+const BPF_MAP_TYPE_RINGBUF: u32 = 27;
+const BPF_PROG_TYPE_TRACEPOINT: u32 = 5;
+
+const RINGBUF_BUSY_BIT: u32 = 1 << 31;
+const RINGBUF_DISCARD_BIT: u32 = 1 << 30;
+
+const PERF_TYPE_TRACEPOINT: u32 = 2;
+const PERF_FLAG_FD_CLOEXEC: libc::c_ulong = 1 << 3;
+const PERF_EVENT_IOC_ENABLE: libc::c_ulong = 0x2400;
+const PERF_EVENT_IOC_SET_BPF: libc::c_ulong = 0x4004_2408;
+
+#[repr(C)]
+struct MapCreateAttr {
+    map_type: u32,
+    key_size: u32,
+    value_size: u32,
+    max_entries: u32,
+    map_flags: u32,
+}
+
+#[repr(C)]
+struct MapElemAttr {
+    map_fd: u32,
+    _pad: u32,
+    key: u64,
+    value: u64,
+    flags: u64,
+}
+
+#[repr(C)]
+struct ProgLoadAttr {
+    prog_type: u32,
+    insn_cnt: u32,
+    insns: u64,
+    license: u64,
+    log_level: u32,
+    log_size: u32,
+    log_buf: u64,
+    kern_version: u32,
+    prog_flags: u32,
+}
+
+/// First fields of `perf_event_attr` (`PERF_ATTR_SIZE_VER0`)
+#[repr(C)]
+struct PerfEventAttr {
+    kind: u32,
+    size: u32,
+    config: u64,
+    sample_period: u64,
+    sample_type: u64,
+    read_format: u64,
+    flags: u64,
+    wakeup_events: u32,
+    bp_type: u32,
+    config1: u64,
+}
+
+fn bpf<T>(command: libc::c_long, attr: &mut T) -> io::Result<libc::c_long> {
+    // SAFETY: attr is a live, fully initialized attribute struct of the size passed.
+    let result = unsafe { libc::syscall(libc::SYS_bpf, command, (attr as *mut T).cast::<libc::c_void>(), size_of::<T>()) };
+    if result < 0 { Err(io::Error::last_os_error()) } else { Ok(result) }
+}
+
+fn owned_fd(fd: libc::c_long) -> io::Result<OwnedFd> {
+    let fd = i32::try_from(fd).map_err(|_| io::Error::other("descriptor out of range"))?;
+    // SAFETY: the kernel just returned this descriptor and nothing else owns it.
+    Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+}
+
+fn map_create(map_type: u32, key_size: u32, value_size: u32, max_entries: u32) -> io::Result<OwnedFd> {
+    let mut attr = MapCreateAttr {
+        map_type,
+        key_size,
+        value_size,
+        max_entries,
+        map_flags: 0,
+    };
+    owned_fd(bpf(BPF_MAP_CREATE, &mut attr)?)
+}
+
+/// Load a tracepoint program; a rejected one is retried for the verifier's reason
+fn prog_load(insns: &[Insn]) -> io::Result<OwnedFd> {
+    // GPL-compatible, as the probe-read helpers require
+    let license = c"Dual MIT/GPL";
+    let mut attr = ProgLoadAttr {
+        prog_type: BPF_PROG_TYPE_TRACEPOINT,
+        insn_cnt: u32::try_from(insns.len()).unwrap_or(u32::MAX),
+        insns: insns.as_ptr() as u64,
+        license: license.as_ptr() as u64,
+        log_level: 0,
+        log_size: 0,
+        log_buf: 0,
+        kern_version: 0,
+        prog_flags: 0,
+    };
+    match bpf(BPF_PROG_LOAD, &mut attr) {
+        Ok(fd) => owned_fd(fd),
+        Err(error) if error.raw_os_error() == Some(libc::EPERM) => Err(error),
+        Err(error) => {
+            let mut log = vec![0u8; 64 * 1024];
+            attr.log_level = 1;
+            attr.log_size = log.len() as u32;
+            attr.log_buf = log.as_mut_ptr() as u64;
+            let _ = bpf(BPF_PROG_LOAD, &mut attr);
+            let log = String::from_utf8_lossy(&log[..log.iter().position(|byte| *byte == 0).unwrap_or(0)]).to_string();
+            match log.lines().rev().find(|line| !line.trim().is_empty()) {
+                Some(reason) => Err(io::Error::new(error.kind(), format!("{error}: {reason}"))),
+                None => Err(error),
+            }
+        }
+    }
+}
+
+/// Attach `program` to `sched/<tracepoint>` through a perf event
+///
+/// A tracepoint's programs run on every CPU, so one event on CPU 0 is enough.
+fn attach(tracepoint: &str, program: &OwnedFd) -> io::Result<OwnedFd> {
+    let id = TRACEFS
+        .iter()
+        .find_map(|root| std::fs::read_to_string(format!("{root}/events/sched/{tracepoint}/id")).ok())
+        .and_then(|id| id.trim().parse::<u64>().ok())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("tracepoint sched/{tracepoint} not found (is tracefs mounted?)")))?;
+    let mut attr = PerfEventAttr {
+        kind: PERF_TYPE_TRACEPOINT,
+        size: size_of::<PerfEventAttr>() as u32,
+        config: id,
+        sample_period: 1,
+        sample_type: 0,
+        read_format: 0,
+        flags: 0,
+        wakeup_events: 1,
+        bp_type: 0,
+        config1: 0,
+    };
+    // SAFETY: attr is a valid perf_event_attr of the size it declares.
+    let fd = unsafe {
+        libc::syscall(libc::SYS_perf_event_open, (&raw mut attr).cast::<libc::c_void>(), -1, 0, -1, PERF_FLAG_FD_CLOEXEC)
+    };
+    if fd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let event = owned_fd(fd)?;
+    // SAFETY: both descriptors are open; SET_BPF takes the program fd by value.
+    let attached = unsafe { libc::ioctl(event.as_raw_fd(), PERF_EVENT_IOC_SET_BPF as _, program.as_raw_fd()) == 0
+        && libc::ioctl(event.as_raw_fd(), PERF_EVENT_IOC_ENABLE as _, 0) == 0 };
+    if !attached {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(event)
+}
+
+fn map_shared(fd: &OwnedFd, len: usize, protection: libc::c_int, offset: usize) -> io::Result<*mut u8> {
+    let offset = libc::off_t::try_from(offset).map_err(|_| io::Error::other("offset out of range"))?;
+    // SAFETY: a fresh shared mapping of the ring map; failure is reported as MAP_FAILED.
+    let address = unsafe { libc::mmap(std::ptr::null_mut(), len, protection, libc::MAP_SHARED, fd.as_raw_fd(), offset) };
+    if address == libc::MAP_FAILED { Err(io::Error::last_os_error()) } else { Ok(address.cast()) }
+}
+
+/// Log line for a tracer that failed to open, with the usual cause
+pub(crate) fn exec_trace_unavailable(error: &io::Error) -> String {
+    let hint = match error.raw_os_error() {
+        Some(libc::EPERM | libc::EACCES) => " (needs root or CAP_BPF and CAP_PERFMON)",
+        Some(libc::EINVAL) => " (needs Linux 5.8 or later for BPF ring buffers)",
+        _ => "",
+    };
+    format!("eBPF exec tracing unavailable: {error}{hint}")
+}
@@ -0,0 +1,67 @@
+//! Fallback for platforms without native inspection support
+
+use std::path::PathBuf;
+
+use super::{FileHolder, PortSocket, PrivateMemory, ProcessArch, ProcessTable};
+
+pub(crate) fn private_memory(_pid: u32) -> PrivateMemory {
+    PrivateMemory::default()
+}
+
+pub(crate) fn server_privilege() -> Option<&'static str> {
+    None
+}
+
+pub(crate) fn file_holders(_path: &std::path::Path) -> Option<Vec<FileHolder>> {
+    None
+}
+
+pub(crate) fn process_table() -> Option<ProcessTable> {
+    None
+}
+
+pub(crate) fn cmdline(_pid: u32) -> Option<Vec<String>> {
+    None
+}
+
+pub(crate) fn handle_count(_pid: u32) -> Option<u64> {
+    None
+}
+
+pub(crate) fn open_files(_pid: u32) -> Option<Vec<crate::schema::OpenFile>> {
+    None
+}
+
+pub(crate) fn connections(_pid: u32) -> Option<Vec<crate::schema::SocketInfo>> {
+    None
+}
+
+pub(crate) fn port_sockets(_port: u16) -> Option<Vec<PortSocket>> {
+    None
+}
+
+pub(crate) fn exe_path(_pid: u32) -> Option<PathBuf> {
+    None
+}
+
+pub(crate) fn cwd(_pid: u32) -> Option<PathBuf> {
+    None
+}
+
+pub(crate) fn process_kind(_pid: u32) -> Option<crate::schema::ProcessKind> {
+    None
+}
+
+pub(crate) fn process_arch(_pid: u32) -> Option<ProcessArch> {
+    None
+}
+
+#[cfg(not(unix))]
+pub(crate) fn nice(_pid: u32) -> Option<i32> {
+    None
+}
+
+#[cfg(not(unix))]
+pub(crate) fn set_nice(_pid: u32, _nice: i32) -> std::io::Result<()> {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+}
@@ -0,0 +1,912 @@
+//! Linux implementations backed by procfs and Linux-only syscalls
+
+use std::collections::{BTreeMap, HashMap};
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+
+use super::{FileHolder, NativeProcess, PortSocket, PrivateMemory, ProcessArch, ProcessTable};
+use crate::resilience;
+use crate::schema::{
+    IoClass, IoPriority, MemoryMap, NamespaceInfo, OpenFile, ProcessKind, ResourceLimit, SeccompMode, SocketInfo,
+    ThreadInfo,
+};
+
+/// Read USS from `/proc/<pid>/smaps_rollup` (Private_Clean + Private_Dirty)
+pub(crate) fn private_memory(pid: u32) -> PrivateMemory {
+    let uss_bytes = std::fs::read_to_string(format!("/proc/{pid}/smaps_rollup"))
+        .ok()
+        .map(|rollup| {
+            rollup
+                .lines()
+                .filter(|line| line.starts_with("Private_Clean:") || line.starts_with("Private_Dirty:"))
+                .filter_map(|line| line.split_whitespace().nth(1)?.parse::<u64>().ok())
+                .sum::<u64>()
+                * 1024
+        });
+
+    PrivateMemory {
+        uss_bytes,
+        private_bytes: None,
+    }
+}
+
+/// Linux capability number of CAP_KILL
+const CAP_KILL: u32 = 5;
+
+/// Look up one `Key:\tvalue` line of `/proc/<pid>/status` (`pid` may be "self")
+pub(super) fn status_field(pid: impl std::fmt::Display, key: &str) -> Option<String> {
+    let status = std::fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+    status_value(&status, key).map(str::to_string)
+}
+
+/// Find `key` in already-read `/proc/<pid>/status` contents
+fn status_value<'a>(status: &'a str, key: &str) -> Option<&'a str> {
+    status.lines().find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        (name == key).then(|| value.trim())
+    })
+}
+
+/// Privilege that lets the server signal processes owned by other users
+pub(crate) fn server_privilege() -> Option<&'static str> {
+    let cap_eff = u64::from_str_radix(&status_field("self", "CapEff")?, 16).ok()?;
+    (cap_eff & (1 << CAP_KILL) != 0).then_some("CAP_KILL")
+}
+
+/// Security module label from `/proc/<pid>/attr/current`
+///
+/// Falls back to the AppArmor-specific file used when several LSMs are stacked.
+pub(crate) fn security_label(pid: u32) -> Option<String> {
+    ["attr/current", "attr/apparmor/current"].iter().find_map(|file| {
+        let raw = std::fs::read(format!("/proc/{pid}/{file}")).ok()?;
+        let label = String::from_utf8_lossy(&raw).trim_end_matches(['\0', '\n']).to_string();
+        (!label.is_empty()).then_some(label)
+    })
+}
+
+/// Raw capability masks from `/proc/<pid>/status`
+pub(crate) fn capability_sets(pid: u32) -> Option<super::CapabilityMasks> {
+    let status = std::fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+    let field = |key: &str| u64::from_str_radix(status_value(&status, key)?, 16).ok();
+    Some(super::CapabilityMasks {
+        effective: field("CapEff")?,
+        permitted: field("CapPrm")?,
+        inheritable: field("CapInh")?,
+        bounding: field("CapBnd")?,
+        ambient: field("CapAmb"),
+    })
+}
+
+/// `RssAnon`, `RssFile`, `RssShmem` and `VmSwap` from `/proc/<pid>/status`
+pub(crate) fn memory_status(pid: u32) -> Option<super::MemoryStatus> {
+    let status = std::fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+    let field = |key: &str| Some(status_value(&status, key)?.trim_end_matches(" kB").parse::<u64>().ok()? * 1024);
+    Some(super::MemoryStatus {
+        anon_bytes: field("RssAnon")?,
+        file_bytes: field("RssFile")?,
+        shmem_bytes: field("RssShmem")?,
+        swap_bytes: field("VmSwap")?,
+    })
+}
+
+/// Signal masks from `/proc/<pid>/status` and the status of each thread
+pub(crate) fn signal_masks(pid: u32) -> Option<super::SignalMasks> {
+    let status = std::fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+    let mask = |status: &str, key: &str| u64::from_str_radix(status_value(status, key)?, 16).ok();
+    let mut masks = super::SignalMasks {
+        caught: mask(&status, "SigCgt")?,
+        ignored: mask(&status, "SigIgn")?,
+        // The main thread's, until the other threads are known
+        blocked: mask(&status, "SigBlk")?,
+        pending: mask(&status, "ShdPnd")? | mask(&status, "SigPnd")?,
+        switches: 0,
+        state: status_value(&status, "State")?.chars().next()?,
+    };
+    let threads: Vec<String> = std::fs::read_dir(format!("/proc/{pid}/task"))
+        .map(|tasks| tasks.flatten().filter_map(|task| std::fs::read_to_string(task.path().join("status")).ok()).collect())
+        .unwrap_or_default();
+    if !threads.is_empty() {
+        // A process-directed signal goes to any thread that does not block it
+        masks.blocked = threads.iter().fold(u64::MAX, |blocked, thread| blocked & mask(thread, "SigBlk").unwrap_or_default());
+    }
+    for thread in &threads {
+        masks.pending |= mask(thread, "SigPnd").unwrap_or_default();
+        masks.switches += ["voluntary_ctxt_switches", "nonvoluntary_ctxt_switches"]
+            .iter()
+            .filter_map(|key| status_value(thread, key)?.parse::<u64>().ok())
+            .sum::<u64>();
+    }
+    Some(masks)
+}
+
+/// Seccomp mode from the `Seccomp:` line of `/proc/<pid>/status`
+pub(crate) fn seccomp_mode(pid: u32) -> Option<SeccompMode> {
+    match status_field(pid, "Seccomp")?.as_str() {
+        "0" => Some(SeccompMode::Disabled),
+        "1" => Some(SeccompMode::Strict),
+        "2" => Some(SeccompMode::Filter),
+        _ => None,
+    }
+}
+
+/// Processes holding `path` open (`/proc/<pid>/fd`) or locked (`/proc/locks`)
+///
+/// Only processes whose fd table the server may read are seen, so without
+/// root other users' holders are reported through `/proc/locks` alone.
+pub(crate) fn file_holders(path: &Path) -> Option<Vec<FileHolder>> {
+    let metadata = std::fs::metadata(path).ok()?;
+    let canonical = std::fs::canonicalize(path).ok()?;
+    let (major, minor) = (libc::major(metadata.dev()), libc::minor(metadata.dev()));
+    let mut holders = BTreeMap::new();
+
+    // "1: FLOCK  ADVISORY  WRITE 1234 08:01:5678 0 EOF"; blocked waiters carry "->"
+    if let Ok(locks) = std::fs::read_to_string("/proc/locks") {
+        for line in locks.lines() {
+            let fields: Vec<&str> = line.split_whitespace().filter(|field| *field != "->").collect();
+            let (Some(pid), Some(id)) = (fields.get(4), fields.get(5)) else {
+                continue;
+            };
+            // OFD locks report -1: they belong to no single process
+            let Some(pid) = pid.parse::<u32>().ok().filter(|pid| *pid > 0) else {
+                continue;
+            };
+            let mut parts = id.split(':');
+            let lock_major = parts.next().and_then(|part| u32::from_str_radix(part, 16).ok());
+            let lock_minor = parts.next().and_then(|part| u32::from_str_radix(part, 16).ok());
+            let lock_inode = parts.next().and_then(|part| part.parse::<u64>().ok());
+            // Locks taken by a process that has since exited, via an fd inherited by its
+            // children, keep reporting the dead PID; the children show up in the fd scan
+            if lock_inode == Some(metadata.ino())
+                && lock_major == Some(major)
+                && lock_minor == Some(minor)
+                && Path::new(&format!("/proc/{pid}")).exists()
+            {
+                holders.insert(pid, true);
+            }
+        }
+    }
+
+    for entry in std::fs::read_dir("/proc").ok()?.flatten() {
+        let Some(pid) = entry.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) else {
+            continue;
+        };
+        let Ok(fds) = std::fs::read_dir(entry.path().join("fd")) else {
+            continue;
+        };
+        if fds
+            .flatten()
+            .any(|fd| std::fs::read_link(fd.path()).is_ok_and(|target| target == canonical))
+        {
+            holders.entry(pid).or_insert(false);
+        }
+    }
+
+    Some(holders.into_iter().map(|(pid, locked)| FileHolder { pid, locked }).collect())
+}
+
+/// Enumerate processes from `/proc/<pid>/stat` alone
+///
+/// One read per process instead of sysinfo's status/statm/cmdline/environ
+/// refresh; `stat` already carries CPU times, start time, RSS and VSZ.
+pub(crate) fn process_table() -> Option<ProcessTable> {
+    // SAFETY: sysconf has no preconditions.
+    let (ticks, page_size) = unsafe { (libc::sysconf(libc::_SC_CLK_TCK), libc::sysconf(libc::_SC_PAGESIZE)) };
+    let (ticks, page_size) = (u64::try_from(ticks).ok().filter(|ticks| *ticks > 0)?, u64::try_from(page_size).ok()?);
+    let boot_time: u64 = std::fs::read_to_string("/proc/stat")
+        .ok()?
+        .lines()
+        .find_map(|line| line.strip_prefix("btime ")?.trim().parse().ok())?;
+    let uptime: f64 = std::fs::read_to_string("/proc/uptime")
+        .ok()?
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()?;
+
+    let mut table = ProcessTable::default();
+    for entry in resilience::retry(|| std::fs::read_dir("/proc")).ok()?.flatten() {
+        let Some(pid) = entry.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) else {
+            continue;
+        };
+        // Gone between readdir and read, or hidden by hidepid: skip and count
+        let stat = match resilience::retry(|| std::fs::read_to_string(format!("/proc/{pid}/stat"))) {
+            Ok(stat) => stat,
+            Err(e) => {
+                table.skipped.record(&e);
+                continue;
+            }
+        };
+        // "pid (comm) state ppid ..."; comm may itself contain spaces and parentheses
+        let (Some(open), Some(close)) = (stat.find('('), stat.rfind(')')) else {
+            table.skipped.failed += 1;
+            continue;
+        };
+        let fields: Vec<&str> = stat[close + 1..].split_whitespace().collect();
+        let field = |index: usize| fields.get(index).and_then(|value| value.parse::<u64>().ok());
+        // Indexes are proc(5) field numbers minus 3
+        let (Some(utime), Some(stime), Some(start_ticks), Some(vsize), Some(rss)) =
+            (field(11), field(12), field(19), field(20), field(21))
+        else {
+            table.skipped.failed += 1;
+            continue;
+        };
+        table.processes.push(NativeProcess {
+            pid,
+            name: stat[open + 1..close].to_string(),
+            start_time: boot_time + start_ticks / ticks,
+            cpu_secs: (utime + stime) as f64 / ticks as f64,
+            age_secs: (uptime - start_ticks as f64 / ticks as f64).max(0.0),
+            rss_bytes: rss * page_size,
+            virtual_bytes: vsize,
+        });
+    }
+    Some(table)
+}
+
+/// Command line from `/proc/<pid>/cmdline`
+pub(crate) fn cmdline(pid: u32) -> Option<Vec<String>> {
+    let raw = std::fs::read(format!("/proc/{pid}/cmdline")).ok()?;
+    Some(
+        raw.split(|byte| *byte == 0)
+            .filter(|arg| !arg.is_empty())
+            .map(|arg| String::from_utf8_lossy(arg).to_string())
+            .collect(),
+    )
+}
+
+/// Number of entries in `/proc/<pid>/fd`
+pub(crate) fn handle_count(pid: u32) -> Option<u64> {
+    Some(std::fs::read_dir(format!("/proc/{pid}/fd")).ok()?.count() as u64)
+}
+
+/// Executable path from the `/proc/<pid>/exe` link
+pub(crate) fn exe_path(pid: u32) -> Option<PathBuf> {
+    std::fs::read_link(format!("/proc/{pid}/exe")).ok()
+}
+
+/// Whether `pid` is a thread of another process (sysinfo lists those as processes)
+pub(crate) fn is_thread(pid: u32) -> bool {
+    std::fs::read_to_string(format!("/proc/{pid}/status")).is_ok_and(|status| {
+        status
+            .lines()
+            .find_map(|line| line.strip_prefix("Tgid:"))
+            .and_then(|tgid| tgid.trim().parse::<u32>().ok())
+            .is_some_and(|tgid| tgid != pid)
+    })
+}
+
+/// The running image, readable even after its path was deleted or replaced
+pub(crate) fn exe_image(pid: u32) -> Option<PathBuf> {
+    Some(PathBuf::from(format!("/proc/{pid}/exe")))
+}
+
+/// Working directory from the `/proc/<pid>/cwd` link
+pub(crate) fn cwd(pid: u32) -> Option<PathBuf> {
+    std::fs::read_link(format!("/proc/{pid}/cwd")).ok()
+}
+
+// ============================================================================
+// I/O PRIORITY
+// ============================================================================
+
+const IOPRIO_WHO_PROCESS: libc::c_long = 1;
+const IOPRIO_CLASS_SHIFT: u32 = 13;
+
+/// I/O class and level via `ioprio_get`
+pub(crate) fn io_priority(pid: u32) -> Option<IoPriority> {
+    // SAFETY: ioprio_get only reads its arguments.
+    let value = unsafe { libc::syscall(libc::SYS_ioprio_get, IOPRIO_WHO_PROCESS, libc::c_long::from(pid)) };
+    let value = u32::try_from(value).ok()?;
+    let class = match value >> IOPRIO_CLASS_SHIFT {
+        1 => IoClass::Realtime,
+        2 => IoClass::BestEffort,
+        3 => IoClass::Idle,
+        _ => IoClass::None,
+    };
+    let level = u8::try_from(value & ((1 << IOPRIO_CLASS_SHIFT) - 1)).unwrap_or(u8::MAX);
+    Some(IoPriority { class, level })
+}
+
+/// Set I/O class and level via `ioprio_set`; PID 0 means the calling process
+///
+/// Only issues the syscall, so it is safe to call between fork and exec.
+pub(crate) fn set_io_priority(pid: u32, priority: IoPriority) -> std::io::Result<()> {
+    let class: u32 = match priority.class {
+        IoClass::None => 0,
+        IoClass::Realtime => 1,
+        IoClass::BestEffort => 2,
+        IoClass::Idle => 3,
+    };
+    let value = (class << IOPRIO_CLASS_SHIFT) | u32::from(priority.level);
+    // SAFETY: ioprio_set only reads its arguments.
+    let rc = unsafe {
+        libc::syscall(
+            libc::SYS_ioprio_set,
+            IOPRIO_WHO_PROCESS,
+            libc::c_long::from(pid),
+            libc::c_long::from(value),
+        )
+    };
+    if rc == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+/// Pin `pid` to `cpus` via `sched_setaffinity`; PID 0 means the calling process
+///
+/// Threads started afterwards inherit the mask. Only issues the syscall, so
+/// it is safe to call between fork and exec.
+pub(crate) fn set_affinity(pid: u32, cpus: &[usize]) -> std::io::Result<()> {
+    let pid = libc::pid_t::try_from(pid).map_err(|_| std::io::Error::from(std::io::ErrorKind::InvalidInput))?;
+    // SAFETY: cpu_set_t is plain data and the CPU_* helpers stay within it.
+    unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        for &cpu in cpus {
+            if cpu >= libc::CPU_SETSIZE as usize {
+                return Err(std::io::Error::from(std::io::ErrorKind::InvalidInput));
+            }
+            libc::CPU_SET(cpu, &mut set);
+        }
+        if libc::sched_setaffinity(pid, std::mem::size_of::<libc::cpu_set_t>(), &set) == 0 {
+            Ok(())
+        } else {
+            Err(std::io::Error::last_os_error())
+        }
+    }
+}
+
+/// CPUs `pid` may run on, from `sched_getaffinity` (the mask of its main thread)
+pub(crate) fn affinity(pid: u32) -> Option<Vec<usize>> {
+    let pid = libc::pid_t::try_from(pid).ok()?;
+    // SAFETY: cpu_set_t is plain data, the kernel fills at most its size and CPU_ISSET stays within it.
+    unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        if libc::sched_getaffinity(pid, std::mem::size_of::<libc::cpu_set_t>(), &mut set) != 0 {
+            return None;
+        }
+        Some((0..libc::CPU_SETSIZE as usize).filter(|&cpu| libc::CPU_ISSET(cpu, &set)).collect())
+    }
+}
+
+/// Pin every thread of a running process to `cpus`
+///
+/// `sched_setaffinity` affects one thread, so the main thread is pinned first
+/// (its errors are the process's) and then each entry of `/proc/<pid>/task`;
+/// threads that exit meanwhile are skipped.
+pub(crate) fn set_process_affinity(pid: u32, cpus: &[usize]) -> std::io::Result<()> {
+    set_affinity(pid, cpus)?;
+    let Ok(tasks) = std::fs::read_dir(format!("/proc/{pid}/task")) else {
+        return Ok(());
+    };
+    for tid in tasks.flatten().filter_map(|task| task.file_name().to_str()?.parse::<u32>().ok()) {
+        if tid == pid {
+            continue;
+        }
+        match set_affinity(tid, cpus) {
+            Err(e) if e.raw_os_error() != Some(libc::ESRCH) => return Err(e),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+// ============================================================================
+// SUBREAPER
+// ============================================================================
+
+/// Make the server the reaper of orphaned descendants via `PR_SET_CHILD_SUBREAPER`
+pub(crate) fn set_child_subreaper() -> std::io::Result<()> {
+    // SAFETY: prctl with PR_SET_CHILD_SUBREAPER only reads its integer argument.
+    if unsafe { libc::prctl(libc::PR_SET_CHILD_SUBREAPER, 1 as libc::c_ulong) } == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+/// Whether the server is a child subreaper (`PR_GET_CHILD_SUBREAPER`)
+pub(crate) fn is_child_subreaper() -> bool {
+    let mut flag: libc::c_int = 0;
+    // SAFETY: the kernel writes one int through the pointer, which outlives the call.
+    unsafe { libc::prctl(libc::PR_GET_CHILD_SUBREAPER, &raw mut flag) == 0 && flag != 0 }
+}
+
+/// Zombie children of the server with their names, from `/proc/<pid>/stat`
+pub(crate) fn zombie_children() -> Vec<(u32, String)> {
+    let server = std::process::id();
+    let Ok(entries) = std::fs::read_dir("/proc") else {
+        return Vec::new();
+    };
+    let mut zombies = Vec::new();
+    for entry in entries.flatten() {
+        let Some(pid) = entry.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) else {
+            continue;
+        };
+        let Ok(stat) = std::fs::read_to_string(format!("/proc/{pid}/stat")) else {
+            continue;
+        };
+        let (Some(open), Some(close)) = (stat.find('('), stat.rfind(')')) else {
+            continue;
+        };
+        let mut fields = stat[close + 1..].split_whitespace();
+        if fields.next() == Some("Z") && fields.next().and_then(|ppid| ppid.parse::<u32>().ok()) == Some(server) {
+            zombies.push((pid, stat[open + 1..close].to_string()));
+        }
+    }
+    zombies
+}
+
+/// Collect the exit status of child `pid` without blocking; `None` while it still runs
+pub(crate) fn reap_child(pid: u32) -> std::io::Result<Option<std::process::ExitStatus>> {
+    use std::os::unix::process::ExitStatusExt;
+
+    let pid = libc::pid_t::try_from(pid).map_err(std::io::Error::other)?;
+    let mut status: libc::c_int = 0;
+    // SAFETY: waitpid writes one int through the pointer, which outlives the call.
+    match unsafe { libc::waitpid(pid, &raw mut status, libc::WNOHANG) } {
+        -1 => Err(std::io::Error::last_os_error()),
+        0 => Ok(None),
+        _ => Ok(Some(std::process::ExitStatus::from_raw(status))),
+    }
+}
+
+// ============================================================================
+// FORENSICS
+// ============================================================================
+
+/// Descriptors and their targets from the `/proc/<pid>/fd` links
+pub(crate) fn open_files(pid: u32) -> Option<Vec<OpenFile>> {
+    let mut files: Vec<OpenFile> = std::fs::read_dir(format!("/proc/{pid}/fd"))
+        .ok()?
+        .flatten()
+        .filter_map(|entry| {
+            let fd = entry.file_name().to_str()?.parse().ok()?;
+            let target = std::fs::read_link(entry.path()).ok()?;
+            Some(OpenFile {
+                fd,
+                target: target.to_string_lossy().to_string(),
+            })
+        })
+        .collect();
+    files.sort_by_key(|file| file.fd);
+    Some(files)
+}
+
+/// Sockets of a process, from its descriptors and the tables of its network namespace
+pub(crate) fn connections(pid: u32) -> Option<Vec<SocketInfo>> {
+    sockets(pid, &open_files(pid)?)
+}
+
+/// Sockets among `files`, resolved via the tables of the process's network namespace
+pub(crate) fn sockets(pid: u32, files: &[OpenFile]) -> Option<Vec<SocketInfo>> {
+    let fds: HashMap<u64, u32> = files
+        .iter()
+        .filter_map(|file| Some((socket_inode(&file.target)?, file.fd)))
+        .collect();
+    let mut sockets = Vec::new();
+    let mut readable = false;
+
+    if let Some(inet) = inet_sockets(&format!("/proc/{pid}/net")) {
+        readable = true;
+        sockets.extend(inet.into_iter().filter_map(|mut socket| {
+            socket.fd = Some(*fds.get(&socket.inode?)?);
+            Some(socket)
+        }));
+    }
+
+    // "Num RefCount Protocol Flags Type St Inode Path"
+    if let Ok(table) = std::fs::read_to_string(format!("/proc/{pid}/net/unix")) {
+        readable = true;
+        for line in table.lines().skip(1) {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let Some(inode) = fields.get(6).and_then(|inode| inode.parse::<u64>().ok()) else {
+                continue;
+            };
+            if let Some(fd) = fds.get(&inode) {
+                sockets.push(SocketInfo {
+                    fd: Some(*fd),
+                    protocol: "unix".to_string(),
+                    local: fields.get(7).map_or_else(String::new, |path| (*path).to_string()),
+                    remote: None,
+                    state: None,
+                    inode: Some(inode),
+                });
+            }
+        }
+    }
+
+    sockets.sort_by_key(|socket| socket.fd);
+    readable.then_some(sockets)
+}
+
+/// Every TCP and UDP socket in the tables of a network namespace (`net` is e.g. `/proc/self/net`)
+fn inet_sockets(net: &str) -> Option<Vec<SocketInfo>> {
+    let mut sockets = Vec::new();
+    let mut readable = false;
+    // "sl local_address rem_address st tx_queue:rx_queue tr:tm->when retrnsmt uid timeout inode"
+    for protocol in ["tcp", "tcp6", "udp", "udp6"] {
+        let Ok(table) = std::fs::read_to_string(format!("{net}/{protocol}")) else {
+            continue;
+        };
+        readable = true;
+        for line in table.lines().skip(1) {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let Some(inode) = fields.get(9).and_then(|inode| inode.parse::<u64>().ok()) else {
+                continue;
+            };
+            let Some(local) = fields.get(1).and_then(|local| inet_address(local)) else {
+                continue;
+            };
+            sockets.push(SocketInfo {
+                fd: None,
+                protocol: protocol.to_string(),
+                local,
+                remote: fields.get(2).and_then(|remote| inet_address(remote)),
+                state: protocol
+                    .starts_with("tcp")
+                    .then(|| fields.get(3).map(|state| tcp_state(state).to_string()))
+                    .flatten(),
+                inode: Some(inode),
+            });
+        }
+    }
+    readable.then_some(sockets)
+}
+
+/// TCP and UDP sockets on local `port`, each with every process holding it
+///
+/// Read from the server's own network namespace, so ports bound inside a
+/// container are not seen. Holders are found by scanning `/proc/<pid>/fd`;
+/// a socket whose holder may not be read (another user's, without root) is
+/// reported with no PID.
+pub(crate) fn port_sockets(port: u16) -> Option<Vec<PortSocket>> {
+    let suffix = format!(":{port}");
+    let sockets: Vec<SocketInfo> = inet_sockets("/proc/self/net")?
+        .into_iter()
+        .filter(|socket| socket.local.ends_with(&suffix))
+        .collect();
+    if sockets.is_empty() {
+        return Some(Vec::new());
+    }
+
+    let mut holders: HashMap<u64, Vec<(u32, u32)>> = HashMap::new();
+    for entry in std::fs::read_dir("/proc").ok()?.flatten() {
+        let Some(pid) = entry.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) else {
+            continue;
+        };
+        for file in open_files(pid).unwrap_or_default() {
+            if let Some(inode) = socket_inode(&file.target)
+                && sockets.iter().any(|socket| socket.inode == Some(inode))
+            {
+                holders.entry(inode).or_default().push((pid, file.fd));
+            }
+        }
+    }
+
+    let mut found = Vec::new();
+    for socket in sockets {
+        match socket.inode.and_then(|inode| holders.get(&inode)) {
+            Some(pids) => found.extend(pids.iter().map(|(pid, fd)| PortSocket {
+                pid: Some(*pid),
+                socket: SocketInfo {
+                    fd: Some(*fd),
+                    ..socket.clone()
+                },
+            })),
+            None => found.push(PortSocket { pid: None, socket }),
+        }
+    }
+    Some(found)
+}
+
+/// Inode of a `socket:[inode]` descriptor target
+fn socket_inode(target: &str) -> Option<u64> {
+    target.strip_prefix("socket:[")?.strip_suffix(']')?.parse().ok()
+}
+
+/// `ADDR:PORT` from a `/proc/net` table, where each 32-bit word of the address is in host order
+fn inet_address(field: &str) -> Option<String> {
+    let (address, port) = field.split_once(':')?;
+    let port = u16::from_str_radix(port, 16).ok()?;
+    let mut bytes = Vec::with_capacity(16);
+    for word in 0..address.len() / 8 {
+        let word = u32::from_str_radix(address.get(word * 8..word * 8 + 8)?, 16).ok()?;
+        bytes.extend_from_slice(&word.to_ne_bytes());
+    }
+    match bytes.len() {
+        4 => Some(format!("{}:{port}", std::net::Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3]))),
+        16 => {
+            let octets: [u8; 16] = bytes.try_into().ok()?;
+            Some(format!("[{}]:{port}", std::net::Ipv6Addr::from(octets)))
+        }
+        _ => None,
+    }
+}
+
+/// Name of a TCP state number as printed in `/proc/net/tcp`
+fn tcp_state(state: &str) -> &'static str {
+    match u8::from_str_radix(state, 16).unwrap_or(0) {
+        0x01 => "ESTABLISHED",
+        0x02 => "SYN_SENT",
+        0x03 => "SYN_RECV",
+        0x04 => "FIN_WAIT1",
+        0x05 => "FIN_WAIT2",
+        0x06 => "TIME_WAIT",
+        0x07 => "CLOSE",
+        0x08 => "CLOSE_WAIT",
+        0x09 => "LAST_ACK",
+        0x0A => "LISTEN",
+        0x0B => "CLOSING",
+        _ => "UNKNOWN",
+    }
+}
+
+/// Mappings from `/proc/<pid>/maps`
+pub(crate) fn memory_maps(pid: u32) -> Option<Vec<MemoryMap>> {
+    let maps = std::fs::read_to_string(format!("/proc/{pid}/maps")).ok()?;
+    // "start-end perms offset dev inode [path]"
+    Some(
+        maps.lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(6, char::is_whitespace);
+                let address = fields.next()?;
+                let perms = fields.next()?;
+                let path = fields.nth(3).map(str::trim).filter(|path| !path.is_empty());
+                let (start, end) = address.split_once('-')?;
+                let size = u64::from_str_radix(end, 16).ok()? - u64::from_str_radix(start, 16).ok()?;
+                Some(MemoryMap {
+                    address: address.to_string(),
+                    perms: perms.to_string(),
+                    size_bytes: size,
+                    path: path.map(str::to_string),
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Threads with name and state from `/proc/<pid>/task/<tid>/stat`
+pub(crate) fn threads(pid: u32) -> Option<Vec<ThreadInfo>> {
+    let mut threads: Vec<ThreadInfo> = std::fs::read_dir(format!("/proc/{pid}/task"))
+        .ok()?
+        .flatten()
+        .filter_map(|entry| {
+            let tid = entry.file_name().to_str()?.parse().ok()?;
+            let stat = std::fs::read_to_string(entry.path().join("stat")).ok()?;
+            let (open, close) = (stat.find('(')?, stat.rfind(')')?);
+            Some(ThreadInfo {
+                tid,
+                name: stat[open + 1..close].to_string(),
+                state: stat[close + 1..].split_whitespace().next()?.to_string(),
+            })
+        })
+        .collect();
+    threads.sort_by_key(|thread| thread.tid);
+    Some(threads)
+}
+
+/// Lines of `/proc/<pid>/cgroup`
+pub(crate) fn cgroup_paths(pid: u32) -> Option<Vec<String>> {
+    let cgroup = std::fs::read_to_string(format!("/proc/{pid}/cgroup")).ok()?;
+    Some(cgroup.lines().map(str::to_string).collect())
+}
+
+/// PID of `pid` in each nested PID namespace, outermost first (`NSpid:` in `/proc/<pid>/status`)
+pub(crate) fn namespace_pids(pid: u32) -> Option<Vec<u32>> {
+    status_field(pid, "NSpid")?.split_whitespace().map(|pid| pid.parse().ok()).collect()
+}
+
+/// Container ID in the cgroup path of `pid`
+///
+/// Runtimes name the cgroup after the container: `/docker/<id>`,
+/// `docker-<id>.scope`, `cri-containerd-<id>.scope`, `crio-<id>.scope`,
+/// `libpod-<id>.scope` or a bare `<id>` under `kubepods`, with a 64-digit hex
+/// ID. LXC containers use their name (`lxc.payload.<name>`, `/lxc/<name>`).
+pub(crate) fn container_id(pid: u32) -> Option<String> {
+    let cgroup = std::fs::read_to_string(format!("/proc/{pid}/cgroup")).ok()?;
+    let paths = || cgroup.lines().filter_map(|line| line.splitn(3, ':').nth(2));
+    let hex = paths().find_map(|path| {
+        path.rsplit('/').find_map(|segment| {
+            let segment = segment.strip_suffix(".scope").unwrap_or(segment);
+            let id = segment.rsplit(['-', ':']).next()?;
+            (id.len() == 64 && id.bytes().all(|byte| byte.is_ascii_hexdigit())).then(|| id.to_string())
+        })
+    });
+    hex.or_else(|| {
+        paths().find_map(|path| {
+            let segments: Vec<&str> = path.split('/').collect();
+            segments
+                .iter()
+                .find_map(|segment| segment.strip_prefix("lxc.payload."))
+                .or_else(|| segments.iter().position(|segment| *segment == "lxc").and_then(|at| segments.get(at + 1).copied()))
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+        })
+    })
+}
+
+/// Namespaces from the `/proc/<pid>/ns` links
+pub(crate) fn namespaces(pid: u32) -> Option<Vec<NamespaceInfo>> {
+    let mut namespaces: Vec<NamespaceInfo> = std::fs::read_dir(format!("/proc/{pid}/ns"))
+        .ok()?
+        .flatten()
+        .filter_map(|entry| {
+            let id = std::fs::read_link(entry.path()).ok()?;
+            Some(NamespaceInfo {
+                kind: entry.file_name().to_string_lossy().to_string(),
+                id: id.to_string_lossy().to_string(),
+            })
+        })
+        .collect();
+    // Reading another user's links fails one by one rather than as a whole
+    if namespaces.is_empty() {
+        return None;
+    }
+    namespaces.sort_by(|a, b| a.kind.cmp(&b.kind));
+    Some(namespaces)
+}
+
+/// Soft and hard limits from the fixed-width table in `/proc/<pid>/limits`
+pub(crate) fn resource_limits(pid: u32) -> Option<Vec<ResourceLimit>> {
+    let limits = std::fs::read_to_string(format!("/proc/{pid}/limits")).ok()?;
+    let mut lines = limits.lines();
+    let header = lines.next()?;
+    let (soft, hard, units) = (header.find("Soft Limit")?, header.find("Hard Limit")?, header.find("Units")?);
+    let column = |line: &str, from: usize, to: usize| line.get(from..to.min(line.len())).unwrap_or("").trim().to_string();
+    Some(
+        lines
+            .map(|line| ResourceLimit {
+                resource: column(line, 0, soft),
+                soft: column(line, soft, hard),
+                hard: column(line, hard, units),
+                units: Some(column(line, units, line.len())).filter(|units| !units.is_empty()),
+            })
+            .filter(|limit| !limit.resource.is_empty())
+            .collect(),
+    )
+}
+
+// ============================================================================
+// TERMINAL AND UNIT
+// ============================================================================
+
+/// Controlling terminal from `tty_nr` in `/proc/<pid>/stat`, e.g. `pts/3`, or `none`
+pub(crate) fn controlling_tty(pid: u32) -> Option<String> {
+    let stat = std::fs::read_to_string(format!("/proc/{pid}/stat")).ok()?;
+    // Fields after the parenthesised name: state ppid pgrp session tty_nr
+    let tty: u32 = stat[stat.rfind(')')? + 1..].split_whitespace().nth(4)?.parse().ok()?;
+    if tty == 0 {
+        return Some("none".to_string());
+    }
+    let major = (tty >> 8) & 0xfff;
+    let minor = (tty & 0xff) | ((tty >> 12) & 0xfff00);
+    Some(match major {
+        136..=143 => format!("pts/{}", (major - 136) * 256 + minor),
+        4 if minor < 64 => format!("tty{minor}"),
+        4 => format!("ttyS{}", minor - 64),
+        5 if minor == 1 => "console".to_string(),
+        _ => format!("{major}:{minor}"),
+    })
+}
+
+/// systemd unit the process runs in, from its cgroup path, e.g. `nginx.service` or `session-3.scope`
+pub(crate) fn service_unit(pid: u32) -> Option<String> {
+    unit_of(&systemd_cgroup(pid)?).map(str::to_string)
+}
+
+/// Cgroup path of `pid`: the systemd view where there is one, else the first v1 controller's
+pub(crate) fn cgroup_path(pid: u32) -> Option<String> {
+    systemd_cgroup(pid).or_else(|| {
+        let cgroup = std::fs::read_to_string(format!("/proc/{pid}/cgroup")).ok()?;
+        cgroup.lines().find_map(|line| line.splitn(3, ':').nth(2)).map(str::to_string)
+    })
+}
+
+/// Cgroup path in the unified hierarchy ("0::") or systemd's named v1 hierarchy
+fn systemd_cgroup(pid: u32) -> Option<String> {
+    let cgroup = std::fs::read_to_string(format!("/proc/{pid}/cgroup")).ok()?;
+    cgroup.lines().find_map(|line| {
+        line.strip_prefix("0::")
+            .or_else(|| line.split_once(":name=systemd:").map(|(_, path)| path))
+            .map(str::to_string)
+    })
+}
+
+/// Innermost `.service` or `.scope` component of a cgroup path
+fn unit_of(path: &str) -> Option<&str> {
+    path.rsplit('/').find(|unit| unit.ends_with(".service") || unit.ends_with(".scope"))
+}
+
+/// `PF_KTHREAD` in the `flags` field of `/proc/<pid>/stat`
+const PF_KTHREAD: u64 = 0x0020_0000;
+
+/// Cgroup path fragments left by container runtimes
+const CONTAINER_CGROUPS: &[&str] = &["docker", "containerd", "kubepods", "libpod", "crio", "lxc", "/machine.slice/"];
+
+/// Classify from the stat flags and terminal, the cgroup path and the PID namespace
+pub(crate) fn process_kind(pid: u32) -> Option<ProcessKind> {
+    let stat = std::fs::read_to_string(format!("/proc/{pid}/stat")).ok()?;
+    // Fields after the parenthesised name: state ppid pgrp session tty_nr tpgid flags
+    let fields: Vec<&str> = stat[stat.rfind(')')? + 1..].split_whitespace().collect();
+    let tty: u32 = fields.get(4)?.parse().ok()?;
+    let flags: u64 = fields.get(6)?.parse().ok()?;
+    if flags & PF_KTHREAD != 0 {
+        return Some(ProcessKind::Kernel);
+    }
+
+    let cgroup = systemd_cgroup(pid).or_else(|| std::fs::read_to_string(format!("/proc/{pid}/cgroup")).ok());
+    let pid_ns = |pid: u32| std::fs::read_link(format!("/proc/{pid}/ns/pid")).ok();
+    let own_namespace = pid != 1 && pid_ns(pid).zip(pid_ns(1)).is_some_and(|(own, init)| own != init);
+    let containerized = cgroup
+        .as_deref()
+        .is_some_and(|cgroup| CONTAINER_CGROUPS.iter().any(|marker| cgroup.contains(marker)));
+    if own_namespace || containerized {
+        return Some(ProcessKind::Container);
+    }
+
+    let unit = cgroup.as_deref().and_then(unit_of);
+    // Login sessions and desktop apps are scopes; user@.service only hosts them
+    let in_session = unit.is_some_and(|unit| unit.starts_with("session-") || unit.starts_with("app-"))
+        || cgroup.as_deref().is_some_and(|cgroup| cgroup.contains("/app.slice/") && !unit.is_some_and(|unit| unit.ends_with(".service")));
+    Some(if tty != 0 || in_session {
+        ProcessKind::Interactive
+    } else if unit.is_some_and(|unit| unit.ends_with(".service")) {
+        ProcessKind::ServiceManaged
+    } else {
+        ProcessKind::Daemon
+    })
+}
+
+// ============================================================================
+// ARCHITECTURE
+// ============================================================================
+
+/// Architecture from the ELF header of `/proc/<pid>/exe`
+///
+/// A binary of another architecture family than the server's runs under a
+/// binfmt_misc emulator such as qemu-user.
+pub(crate) fn process_arch(pid: u32) -> Option<ProcessArch> {
+    use std::io::Read;
+
+    let mut header = [0u8; 20];
+    std::fs::File::open(format!("/proc/{pid}/exe")).ok()?.read_exact(&mut header).ok()?;
+    if &header[..4] != b"\x7fELF" {
+        return None;
+    }
+    let bits = match header[4] {
+        1 => 32,
+        2 => 64,
+        _ => return None,
+    };
+    let machine = match header[5] {
+        2 => u16::from_be_bytes([header[18], header[19]]),
+        _ => u16::from_le_bytes([header[18], header[19]]),
+    };
+    let (arch, family) = match (machine, bits) {
+        (3, _) => ("x86", "x86"),
+        (62, 64) => ("x86_64", "x86"),
+        (62, _) => ("x32", "x86"),
+        (40, _) => ("arm", "arm"),
+        (183, _) => ("arm64", "arm"),
+        (243, 64) => ("riscv64", "riscv"),
+        (243, _) => ("riscv32", "riscv"),
+        (20, _) => ("ppc", "ppc"),
+        (21, _) => ("ppc64", "ppc"),
+        (22, 64) => ("s390x", "s390"),
+        (22, _) => ("s390", "s390"),
+        (258, _) => ("loongarch64", "loongarch"),
+        _ => return None,
+    };
+    let host = match std::env::consts::ARCH {
+        "x86" | "x86_64" => "x86",
+        "arm" | "aarch64" => "arm",
+        "riscv32" | "riscv64" => "riscv",
+        "powerpc" | "powerpc64" => "ppc",
+        "s390x" => "s390",
+        "loongarch64" => "loongarch",
+        other => other,
+    };
+    Some(ProcessArch { arch, bits, emulated: family != host })
+}
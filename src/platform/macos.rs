@@ -0,0 +1,574 @@
+//! macOS implementations backed by libproc
+
+use std::ffi::CStr;
+use std::path::{Path, PathBuf};
+
+use super::{FileHolder, NativeProcess, PortSocket, PrivateMemory, ProcessArch, ProcessTable};
+use crate::schema::{CodeSignature, OpenFile, ProcessKind, SignatureState, SocketInfo};
+
+/// Read the physical footprint (what Activity Monitor reports as "Memory")
+pub(crate) fn private_memory(pid: u32) -> PrivateMemory {
+    let Ok(pid) = libc::c_int::try_from(pid) else {
+        return PrivateMemory::default();
+    };
+
+    // SAFETY: rusage_info_v2 is plain old data and proc_pid_rusage writes at
+    // most size_of::<rusage_info_v2>() bytes for RUSAGE_INFO_V2.
+    let mut info: libc::rusage_info_v2 = unsafe { std::mem::zeroed() };
+    let rc = unsafe {
+        libc::proc_pid_rusage(
+            pid,
+            libc::RUSAGE_INFO_V2,
+            (&raw mut info).cast::<libc::rusage_info_t>(),
+        )
+    };
+
+    PrivateMemory {
+        uss_bytes: None,
+        private_bytes: (rc == 0).then_some(info.ri_phys_footprint),
+    }
+}
+
+// ============================================================================
+// TARGETED LOOKUPS
+// ============================================================================
+
+/// `proc_pidinfo` into a zeroed `T`, `None` unless the whole struct was filled
+fn pid_info<T>(pid: u32, flavor: libc::c_int) -> Option<T> {
+    let pid = libc::c_int::try_from(pid).ok()?;
+    let size = libc::c_int::try_from(std::mem::size_of::<T>()).ok()?;
+    // SAFETY: the libproc structs are plain old data; zeroed is a valid value.
+    let mut info: T = unsafe { std::mem::zeroed() };
+    // SAFETY: info is writable for `size` bytes.
+    let written = unsafe { libc::proc_pidinfo(pid, flavor, 0, (&raw mut info).cast(), size) };
+    (written == size).then_some(info)
+}
+
+/// Nanoseconds per mach absolute time unit (1 on Intel, 125/3 on Apple silicon)
+#[allow(deprecated)] // libc points at the mach2 crate; the call itself is stable
+fn timebase() -> f64 {
+    static TIMEBASE: std::sync::OnceLock<f64> = std::sync::OnceLock::new();
+    *TIMEBASE.get_or_init(|| {
+        let mut info = libc::mach_timebase_info { numer: 0, denom: 0 };
+        // SAFETY: info is a valid out-pointer.
+        let rc = unsafe { libc::mach_timebase_info(&mut info) };
+        if rc == 0 && info.denom > 0 {
+            f64::from(info.numer) / f64::from(info.denom)
+        } else {
+            1.0
+        }
+    })
+}
+
+/// One process via `PROC_PIDTASKALLINFO`, without touching any other PID
+pub(crate) fn task_info(pid: u32) -> Option<NativeProcess> {
+    let info: libc::proc_taskallinfo = pid_info(pid, libc::PROC_PIDTASKALLINFO)?;
+    let text = |chars: &[libc::c_char]| {
+        // SAFETY: c_char and u8 have the same layout.
+        let bytes = unsafe { std::slice::from_raw_parts(chars.as_ptr().cast::<u8>(), chars.len()) };
+        let end = bytes.iter().position(|byte| *byte == 0).unwrap_or(bytes.len());
+        String::from_utf8_lossy(&bytes[..end]).to_string()
+    };
+    let name = match text(&info.pbsd.pbi_name) {
+        name if name.is_empty() => text(&info.pbsd.pbi_comm),
+        name => name,
+    };
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0.0, |now| now.as_secs_f64());
+    let started = info.pbsd.pbi_start_tvsec as f64 + info.pbsd.pbi_start_tvusec as f64 / 1e6;
+    Some(NativeProcess {
+        pid,
+        name,
+        start_time: info.pbsd.pbi_start_tvsec,
+        cpu_secs: (info.ptinfo.pti_total_user + info.ptinfo.pti_total_system) as f64 * timebase() / 1e9,
+        age_secs: (now - started).max(0.0),
+        rss_bytes: info.ptinfo.pti_resident_size,
+        virtual_bytes: info.ptinfo.pti_virtual_size,
+    })
+}
+
+/// Enumerate processes with `proc_listallpids` plus one `task_info` each
+pub(crate) fn process_table() -> Option<ProcessTable> {
+    // SAFETY: a null buffer asks for the current PID count.
+    let count = unsafe { libc::proc_listallpids(std::ptr::null_mut(), 0) };
+    // Room for processes started in between
+    let mut pids = vec![0 as libc::c_int; usize::try_from(count).ok()? + 64];
+    let bytes = libc::c_int::try_from(pids.len() * std::mem::size_of::<libc::c_int>()).ok()?;
+    // SAFETY: pids is writable for `bytes` bytes.
+    let count = unsafe { libc::proc_listallpids(pids.as_mut_ptr().cast(), bytes) };
+    pids.truncate(usize::try_from(count).ok()?);
+    // Processes that exit mid-scan or deny access are skipped and counted
+    let mut table = ProcessTable::default();
+    for pid in pids.into_iter().filter_map(|pid| u32::try_from(pid).ok()) {
+        match task_info(pid) {
+            Some(process) => table.processes.push(process),
+            None => table.skipped.record(&std::io::Error::last_os_error()),
+        }
+    }
+    Some(table)
+}
+
+/// Executable path via `proc_pidpath`
+pub(crate) fn exe_path(pid: u32) -> Option<PathBuf> {
+    let pid = libc::c_int::try_from(pid).ok()?;
+    let mut buffer = vec![0u8; libc::PROC_PIDPATHINFO_MAXSIZE as usize];
+    // SAFETY: buffer is writable for its full length.
+    let len = unsafe { libc::proc_pidpath(pid, buffer.as_mut_ptr().cast(), buffer.len() as u32) };
+    buffer.truncate(usize::try_from(len).ok().filter(|len| *len > 0)?);
+    Some(PathBuf::from(String::from_utf8_lossy(&buffer).to_string()))
+}
+
+/// Working directory via `PROC_PIDVNODEPATHINFO`
+pub(crate) fn cwd(pid: u32) -> Option<PathBuf> {
+    let info: libc::proc_vnodepathinfo = pid_info(pid, libc::PROC_PIDVNODEPATHINFO)?;
+    // SAFETY: vip_path is a NUL-terminated MAXPATHLEN buffer split into rows.
+    let path = unsafe { CStr::from_ptr(info.pvi_cdir.vip_path.as_ptr().cast()) };
+    let path = path.to_string_lossy();
+    (!path.is_empty()).then(|| PathBuf::from(path.to_string()))
+}
+
+/// Descriptor table via `PROC_PIDLISTFDS`
+fn fd_table(pid: u32) -> Option<Vec<libc::proc_fdinfo>> {
+    let pid = libc::c_int::try_from(pid).ok()?;
+    // SAFETY: a null buffer asks for the size of the descriptor table.
+    let bytes = unsafe { libc::proc_pidinfo(pid, libc::PROC_PIDLISTFDS, 0, std::ptr::null_mut(), 0) };
+    let mut fds: Vec<libc::proc_fdinfo> =
+        Vec::with_capacity(usize::try_from(bytes).ok()? / std::mem::size_of::<libc::proc_fdinfo>() + 16);
+    let capacity = libc::c_int::try_from(fds.capacity() * std::mem::size_of::<libc::proc_fdinfo>()).ok()?;
+    // SAFETY: fds has room for `capacity` bytes; the kernel reports how many it wrote.
+    let written = unsafe { libc::proc_pidinfo(pid, libc::PROC_PIDLISTFDS, 0, fds.as_mut_ptr().cast(), capacity) };
+    let written = usize::try_from(written).ok().filter(|written| *written > 0)?;
+    // SAFETY: the kernel initialized `written` bytes of whole entries, within the capacity.
+    unsafe { fds.set_len(written / std::mem::size_of::<libc::proc_fdinfo>()) };
+    Some(fds)
+}
+
+/// Number of open file descriptors via `PROC_PIDLISTFDS`
+pub(crate) fn handle_count(pid: u32) -> Option<u64> {
+    fd_table(pid).map(|fds| fds.len() as u64)
+}
+
+/// `PROC_PIDFDVNODEPATHINFO` from <sys/proc_info.h>
+const PROC_PIDFDVNODEPATHINFO: libc::c_int = 2;
+
+/// `struct proc_fileinfo`, which libc does not declare
+#[repr(C)]
+struct ProcFileInfo {
+    open_flags: u32,
+    status: u32,
+    offset: i64,
+    kind: i32,
+    guard_flags: u32,
+}
+
+/// `struct vnode_fdinfowithpath`
+#[repr(C)]
+struct VnodeFdInfoWithPath {
+    file: ProcFileInfo,
+    path: libc::vnode_info_path,
+}
+
+/// Descriptors with their paths via `proc_pidfdinfo`; non-vnode descriptors are named by kind
+pub(crate) fn open_files(pid: u32) -> Option<Vec<OpenFile>> {
+    let fds = fd_table(pid)?;
+    let c_pid = libc::c_int::try_from(pid).ok()?;
+    let mut files: Vec<OpenFile> = fds
+        .iter()
+        .filter_map(|entry| {
+            let fd = u32::try_from(entry.proc_fd).ok()?;
+            let target = match entry.proc_fdtype as libc::c_int {
+                libc::PROX_FDTYPE_VNODE => {
+                    // SAFETY: an all-zero vnode_fdinfowithpath is valid plain old data.
+                    let mut info: VnodeFdInfoWithPath = unsafe { std::mem::zeroed() };
+                    let size = std::mem::size_of::<VnodeFdInfoWithPath>() as libc::c_int;
+                    // SAFETY: info is writable for `size` bytes.
+                    let written = unsafe {
+                        libc::proc_pidfdinfo(c_pid, entry.proc_fd, PROC_PIDFDVNODEPATHINFO, (&raw mut info).cast(), size)
+                    };
+                    if written != size {
+                        // Closed since the table was read
+                        return None;
+                    }
+                    // SAFETY: vip_path is a NUL-terminated MAXPATHLEN buffer split into rows.
+                    let path = unsafe { CStr::from_ptr(info.path.vip_path.as_ptr().cast()) };
+                    path.to_string_lossy().to_string()
+                }
+                libc::PROX_FDTYPE_SOCKET => "socket".to_string(),
+                libc::PROX_FDTYPE_PIPE => "pipe".to_string(),
+                libc::PROX_FDTYPE_KQUEUE => "kqueue".to_string(),
+                libc::PROX_FDTYPE_PSHM => "shm".to_string(),
+                libc::PROX_FDTYPE_PSEM => "semaphore".to_string(),
+                libc::PROX_FDTYPE_FSEVENTS => "fsevents".to_string(),
+                _ => "other".to_string(),
+            };
+            Some(OpenFile { fd, target })
+        })
+        .collect();
+    files.sort_by_key(|file| file.fd);
+    Some(files)
+}
+
+/// TCP and UDP sockets of a process, as reported by `lsof`
+///
+/// Like `file_holders`: libproc's `socket_fdinfo` is a nest of unions libc
+/// does not declare. Unix domain sockets are not listed.
+pub(crate) fn connections(pid: u32) -> Option<Vec<SocketInfo>> {
+    let pid = pid.to_string();
+    let sockets = lsof_sockets(&["-a", "-i", "-p", &pid])?;
+    Some(sockets.into_iter().map(|socket| socket.socket).collect())
+}
+
+/// TCP and UDP sockets on local `port` with the processes holding them, via `lsof`
+///
+/// Without root `lsof` only sees the server user's processes.
+pub(crate) fn port_sockets(port: u16) -> Option<Vec<PortSocket>> {
+    let suffix = format!(":{port}");
+    let mut sockets = lsof_sockets(&["-i", &suffix])?;
+    // The selection also matches connections to that port on a remote host
+    sockets.retain(|socket| socket.socket.local.ends_with(&suffix));
+    Some(sockets)
+}
+
+/// Internet sockets `lsof <selection>` reports, sorted by PID and descriptor
+fn lsof_sockets(selection: &[&str]) -> Option<Vec<PortSocket>> {
+    let output = std::process::Command::new("lsof")
+        .args(["-w", "-n", "-P", "-Ts", "-F", "pftPnT"])
+        .args(selection)
+        .output()
+        .ok()?;
+    // lsof exits 1 both for "no sockets" and for errors; errors print to stderr
+    if !output.status.success() && !output.stderr.is_empty() {
+        return None;
+    }
+
+    // One row per descriptor, starting at its "f" line
+    #[derive(Default)]
+    struct Row<'a> {
+        pid: Option<u32>,
+        fd: Option<u32>,
+        ipv6: bool,
+        protocol: &'a str,
+        name: &'a str,
+        state: Option<&'a str>,
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut rows: Vec<Row> = Vec::new();
+    let mut pid = None;
+    for line in stdout.lines() {
+        let Some((field, value)) = line.split_at_checked(1) else {
+            continue;
+        };
+        match field {
+            "p" => pid = value.parse().ok(),
+            "f" => rows.push(Row {
+                pid,
+                fd: value.parse().ok(),
+                ..Row::default()
+            }),
+            _ => {
+                let Some(row) = rows.last_mut() else {
+                    continue;
+                };
+                match field {
+                    "t" => row.ipv6 = value == "IPv6",
+                    "P" => row.protocol = value,
+                    "n" => row.name = value,
+                    "T" => row.state = value.strip_prefix("ST=").or(row.state),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    let mut sockets: Vec<PortSocket> = rows
+        .into_iter()
+        .filter(|row| !row.protocol.is_empty())
+        .map(|row| {
+            // "*:port" binds every address
+            let wildcard = if row.ipv6 { "[::]:" } else { "0.0.0.0:" };
+            let address = |address: &str| address.replacen("*:", wildcard, 1);
+            let (local, remote) = match row.name.split_once("->") {
+                Some((local, remote)) => (address(local), Some(address(remote))),
+                None => (address(row.name), None),
+            };
+            PortSocket {
+                pid: row.pid,
+                socket: SocketInfo {
+                    fd: row.fd,
+                    protocol: format!("{}{}", row.protocol.to_lowercase(), if row.ipv6 { "6" } else { "" }),
+                    local,
+                    remote,
+                    state: row.state.map(|state| tcp_state(state).to_string()),
+                    inode: None,
+                },
+            }
+        })
+        .collect();
+    sockets.sort_by_key(|socket| (socket.pid, socket.socket.fd));
+    Some(sockets)
+}
+
+/// BSD TCP state names as spelled on Linux, so connections read the same everywhere
+fn tcp_state(state: &str) -> &str {
+    match state {
+        "SYN_RCVD" => "SYN_RECV",
+        "FIN_WAIT_1" => "FIN_WAIT1",
+        "FIN_WAIT_2" => "FIN_WAIT2",
+        "CLOSED" => "CLOSE",
+        state => state,
+    }
+}
+
+/// `PROC_FLAG_CONTROLT` from <sys/proc_info.h>: the process has a controlling terminal
+const HAS_CONTROLLING_TTY: u32 = 0x80;
+
+fn has_tty(info: &libc::proc_bsdinfo) -> bool {
+    info.pbi_flags & HAS_CONTROLLING_TTY != 0 && info.e_tdev != u32::MAX
+}
+
+/// Controlling terminal via `PROC_PIDTBSDINFO`, e.g. `ttys002`, or `none`
+pub(crate) fn controlling_tty(pid: u32) -> Option<String> {
+    let info: libc::proc_bsdinfo = pid_info(pid, libc::PROC_PIDTBSDINFO)?;
+    if !has_tty(&info) {
+        return Some("none".to_string());
+    }
+    let (major, minor) = (info.e_tdev >> 24, info.e_tdev & 0x00ff_ffff);
+    Some(match major {
+        // Pseudo-terminal slaves, /dev/ttysNNN
+        16 => format!("ttys{minor:03}"),
+        0 if minor == 0 => "console".to_string(),
+        _ => format!("{major}:{minor}"),
+    })
+}
+
+/// Classify from the terminal and parent: launchd (PID 1) runs every job and app
+pub(crate) fn process_kind(pid: u32) -> Option<ProcessKind> {
+    if pid == 0 {
+        return Some(ProcessKind::Kernel);
+    }
+    let info: libc::proc_bsdinfo = pid_info(pid, libc::PROC_PIDTBSDINFO)?;
+    Some(if has_tty(&info) {
+        ProcessKind::Interactive
+    } else if info.pbi_ppid == 1 {
+        ProcessKind::ServiceManaged
+    } else {
+        ProcessKind::Daemon
+    })
+}
+
+/// `PROC_PIDARCHINFO` from the private <sys/proc_info.h>
+const PROC_PIDARCHINFO: libc::c_int = 19;
+
+/// `struct proc_archinfo`: the Mach-O slice the process was started from
+#[repr(C)]
+struct ProcArchInfo {
+    cpu_type: libc::cpu_type_t,
+    cpu_subtype: libc::cpu_subtype_t,
+}
+
+/// `CPU_ARCH_ABI64` from <mach/machine.h>
+const CPU_ARCH_ABI64: libc::cpu_type_t = 0x0100_0000;
+
+/// Whether the machine is Apple silicon, even if the server itself runs under Rosetta
+fn arm64_host() -> bool {
+    let mut value: libc::c_int = 0;
+    let mut size = std::mem::size_of::<libc::c_int>();
+    // SAFETY: the name is NUL-terminated and value is writable for `size` bytes.
+    let rc = unsafe {
+        libc::sysctlbyname(c"hw.optional.arm64".as_ptr(), (&raw mut value).cast(), &mut size, std::ptr::null_mut(), 0)
+    };
+    rc == 0 && value == 1
+}
+
+/// Architecture of the running slice; x86_64 on Apple silicon is Rosetta 2
+pub(crate) fn process_arch(pid: u32) -> Option<ProcessArch> {
+    let info: ProcArchInfo = pid_info(pid, PROC_PIDARCHINFO)?;
+    let arch = match info.cpu_type & !CPU_ARCH_ABI64 {
+        7 => if info.cpu_type & CPU_ARCH_ABI64 != 0 { "x86_64" } else { "x86" },
+        12 => if info.cpu_type & CPU_ARCH_ABI64 != 0 { "arm64" } else { "arm" },
+        18 => "ppc",
+        _ => return None,
+    };
+    let bits = if info.cpu_type & CPU_ARCH_ABI64 != 0 { 64 } else { 32 };
+    Some(ProcessArch { arch, bits, emulated: !arch.starts_with("arm") && arm64_host() })
+}
+
+/// Command line via the `KERN_PROCARGS2` sysctl
+///
+/// The buffer holds argc, the exec path, NUL padding, then argc arguments.
+pub(crate) fn cmdline(pid: u32) -> Option<Vec<String>> {
+    let mut mib = [libc::CTL_KERN, libc::KERN_PROCARGS2, libc::c_int::try_from(pid).ok()?];
+    let mut size: libc::size_t = 0;
+    // SAFETY: a null buffer asks for the required size.
+    let rc = unsafe { libc::sysctl(mib.as_mut_ptr(), 3, std::ptr::null_mut(), &mut size, std::ptr::null_mut(), 0) };
+    if rc != 0 || size < 4 {
+        return None;
+    }
+    let mut buffer = vec![0u8; size];
+    // SAFETY: buffer is writable for `size` bytes.
+    let rc = unsafe {
+        libc::sysctl(mib.as_mut_ptr(), 3, buffer.as_mut_ptr().cast(), &mut size, std::ptr::null_mut(), 0)
+    };
+    if rc != 0 {
+        return None;
+    }
+    buffer.truncate(size);
+
+    let argc = i32::from_ne_bytes(buffer.get(..4)?.try_into().ok()?);
+    let rest = &buffer[4..];
+    // Skip the exec path and the padding after it
+    let exec_end = rest.iter().position(|byte| *byte == 0)?;
+    let args_start = exec_end + rest[exec_end..].iter().position(|byte| *byte != 0)?;
+    Some(
+        rest[args_start..]
+            .split(|byte| *byte == 0)
+            .take(usize::try_from(argc).unwrap_or_default())
+            .map(|arg| String::from_utf8_lossy(arg).to_string())
+            .collect(),
+    )
+}
+
+/// Privilege that lets the server signal processes owned by other users
+pub(crate) fn server_privilege() -> Option<&'static str> {
+    // SAFETY: geteuid has no preconditions and cannot fail.
+    (unsafe { libc::geteuid() } == 0).then_some("root")
+}
+
+/// Processes holding `path` open, as reported by `lsof`
+///
+/// libproc has no stable Rust binding for `proc_listpidspath`, and `lsof`
+/// ships with every macOS install. `None` when it cannot be run.
+pub(crate) fn file_holders(path: &Path) -> Option<Vec<FileHolder>> {
+    let output = std::process::Command::new("lsof")
+        .args(["-w", "-F", "pl", "--"])
+        .arg(path)
+        .output()
+        .ok()?;
+    // lsof exits 1 both for "no holders" and for errors; errors print to stderr
+    if !output.status.success() && !output.stderr.is_empty() {
+        return None;
+    }
+
+    let mut holders: Vec<FileHolder> = Vec::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        match line.split_at_checked(1) {
+            Some(("p", pid)) => {
+                if let Ok(pid) = pid.parse() {
+                    holders.push(FileHolder { pid, locked: false });
+                }
+            }
+            // Lock status of the preceding fd; a space means unlocked
+            Some(("l", lock)) if !lock.trim().is_empty() => {
+                if let Some(holder) = holders.last_mut() {
+                    holder.locked = true;
+                }
+            }
+            _ => {}
+        }
+    }
+    Some(holders)
+}
+
+// ============================================================================
+// CODE SIGNING
+// ============================================================================
+
+/// Longest a `codesign` or `spctl` call may take
+const SIGNATURE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// `CS_RUNTIME` in the CodeDirectory flags: hardened runtime
+const CS_RUNTIME: u32 = 0x0001_0000;
+
+/// Signature details from `codesign -dvvv`, verified with `codesign --verify --strict`
+///
+/// Notarization comes from Gatekeeper's `spctl --assess`, which only rates
+/// app bundles; bare command-line tools leave it unknown.
+pub(crate) fn code_signature(exe: &Path) -> Option<CodeSignature> {
+    let codesign = |args: &[&str]| {
+        super::run_captured(std::process::Command::new("/usr/bin/codesign").args(args).arg(exe), SIGNATURE_TIMEOUT)
+    };
+    let display = codesign(&["-dvvv"])?;
+    // codesign writes everything to stderr
+    let text = String::from_utf8_lossy(&display.stderr).to_string();
+    if !display.status.success() {
+        return text.contains("not signed at all").then(|| CodeSignature {
+            state: SignatureState::Unsigned,
+            ..Default::default()
+        });
+    }
+    let field = |key: &str| {
+        text.lines()
+            .find_map(|line| line.strip_prefix(key)?.strip_prefix('='))
+            .map(str::to_string)
+    };
+    let authorities: Vec<String> = text.lines().filter_map(|line| line.strip_prefix("Authority=")).map(str::to_string).collect();
+    let flags = text
+        .lines()
+        .filter(|line| line.starts_with("CodeDirectory"))
+        .flat_map(str::split_whitespace)
+        .find_map(|word| word.strip_prefix("flags=0x"))
+        .and_then(|hex| u32::from_str_radix(hex.split('(').next()?, 16).ok())
+        .unwrap_or(0);
+    let ad_hoc = field("Signature").as_deref() == Some("adhoc");
+    let os_binary = field("Platform identifier").is_some();
+    let hardened_runtime = flags & CS_RUNTIME != 0;
+
+    let verify = codesign(&["--verify", "--strict"])?;
+    let (state, detail) = if verify.status.success() {
+        (SignatureState::Valid, None)
+    } else {
+        let stderr = String::from_utf8_lossy(&verify.stderr).to_string();
+        let reason = stderr.lines().rev().find(|line| !line.trim().is_empty()).map(|line| {
+            let prefix = format!("{}: ", exe.display());
+            line.strip_prefix(&prefix).unwrap_or(line).to_string()
+        });
+        (SignatureState::Invalid, reason)
+    };
+
+    let notarized = if os_binary {
+        None
+    } else {
+        super::run_captured(
+            std::process::Command::new("/usr/sbin/spctl").args(["--assess", "--type", "execute", "-vv"]).arg(exe),
+            SIGNATURE_TIMEOUT,
+        )
+        .and_then(|assess| {
+            let stderr = String::from_utf8_lossy(&assess.stderr).to_string();
+            let source = stderr.lines().find_map(|line| line.strip_prefix("source="))?.to_string();
+            Some(source.contains("Notarized"))
+        })
+    };
+
+    let mut notes = Vec::new();
+    if os_binary {
+        notes.push(
+            "Apple platform binary: System Integrity Protection refuses debugging, code injection and \
+             task_for_pid on it, even as root"
+                .to_string(),
+        );
+    } else if hardened_runtime {
+        notes.push(
+            "Hardened runtime: debugger attach and task_for_pid are refused unless the binary carries the \
+             get-task-allow entitlement, so memory and thread inspection may be denied"
+                .to_string(),
+        );
+    }
+    if ad_hoc {
+        notes.push("Ad-hoc signature: no certificate identifies who built this binary".to_string());
+    }
+
+    Some(CodeSignature {
+        state,
+        detail,
+        signer: authorities.first().cloned(),
+        authorities,
+        team_id: field("TeamIdentifier").filter(|team| team != "not set"),
+        identifier: field("Identifier"),
+        ad_hoc: Some(ad_hoc),
+        hardened_runtime: Some(hardened_runtime),
+        notarized,
+        os_binary: Some(os_binary),
+        notes,
+    })
+}
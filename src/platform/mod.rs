@@ -0,0 +1,484 @@
+//! Platform-specific process inspection not covered by sysinfo
+//!
+//! Each OS module exposes the same set of functions; unsupported platforms
+//! fall back to `None`/empty results so callers never need their own `cfg`.
+
+#[cfg(target_os = "linux")]
+mod linux;
+#[cfg(target_os = "linux")]
+pub(crate) use linux::*;
+
+#[cfg(target_os = "macos")]
+mod macos;
+#[cfg(target_os = "macos")]
+pub(crate) use macos::*;
+
+#[cfg(windows)]
+mod windows;
+#[cfg(windows)]
+pub(crate) use windows::*;
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+mod fallback;
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+pub(crate) use fallback::*;
+
+#[cfg(unix)]
+mod unix;
+#[cfg(unix)]
+pub(crate) use unix::*;
+
+#[cfg(target_os = "linux")]
+mod cgroup;
+#[cfg(target_os = "linux")]
+pub(crate) use cgroup::*;
+
+#[cfg(all(target_os = "linux", feature = "ebpf"))]
+mod ebpf;
+#[cfg(all(target_os = "linux", feature = "ebpf"))]
+pub(crate) use ebpf::*;
+
+/// Highest scheduling priority as a Unix nice value
+pub(crate) const MIN_NICE: i32 = -20;
+
+/// Lowest scheduling priority as a Unix nice value
+pub(crate) const MAX_NICE: i32 = 19;
+
+/// Memory figures that need a per-process OS query beyond sysinfo's refresh
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct PrivateMemory {
+    /// Unique set size (Linux)
+    pub uss_bytes: Option<u64>,
+    /// Private committed / footprint bytes (Windows, macOS)
+    pub private_bytes: Option<u64>,
+}
+
+/// Resident memory split by kind from `/proc/<pid>/status` (Linux)
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct MemoryStatus {
+    pub anon_bytes: u64,
+    pub file_bytes: u64,
+    pub shmem_bytes: u64,
+    pub swap_bytes: u64,
+}
+
+/// Process that has a file open
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct FileHolder {
+    pub pid: u32,
+    /// Known to hold a lock on the file (not reported on Windows)
+    pub locked: bool,
+}
+
+/// Socket on a looked-up port and the process holding it, see `port_sockets`
+#[derive(Debug, Clone)]
+pub(crate) struct PortSocket {
+    /// `None` when the holder could not be determined (another user's process without privileges)
+    pub pid: Option<u32>,
+    pub socket: crate::schema::SocketInfo,
+}
+
+/// One row of a native process enumeration, see `process_table`
+#[derive(Debug, Clone)]
+pub(crate) struct NativeProcess {
+    pub pid: u32,
+    pub name: String,
+    /// Seconds since the Unix epoch, computed the way sysinfo does
+    pub start_time: u64,
+    /// User plus system CPU time consumed so far
+    pub cpu_secs: f64,
+    /// Seconds since the process started
+    pub age_secs: f64,
+    pub rss_bytes: u64,
+    pub virtual_bytes: u64,
+}
+
+/// A native process enumeration, see `process_table`
+#[derive(Debug, Default)]
+pub(crate) struct ProcessTable {
+    pub processes: Vec<NativeProcess>,
+    /// Entries left out because they exited mid-scan or could not be read
+    pub skipped: crate::resilience::Skipped,
+}
+
+/// One row of a WSL distro's process table, see `wsl_processes`
+#[derive(Debug, Clone)]
+pub(crate) struct WslProcess {
+    /// PID inside the distro
+    pub pid: u32,
+    pub name: String,
+    /// As `ps` reports it: CPU time over the process's lifetime
+    pub cpu_percent: f32,
+    pub rss_bytes: u64,
+    pub virtual_bytes: u64,
+}
+
+/// Instruction set of a process's executable, see `process_arch`
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ProcessArch {
+    /// `x86_64`, `x86`, `arm64`, `arm`, ...
+    pub arch: &'static str,
+    pub bits: u8,
+    /// Translated or emulated rather than run natively (Rosetta 2, x64 on Arm, qemu-user)
+    pub emulated: bool,
+}
+
+/// Linux capability bitmasks of one process
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CapabilityMasks {
+    pub effective: u64,
+    pub permitted: u64,
+    pub inheritable: u64,
+    pub bounding: u64,
+    pub ambient: Option<u64>,
+}
+
+/// Signal dispositions of one process, with bit `n - 1` set for signal `n`
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SignalMasks {
+    /// Signals with a handler installed
+    pub caught: u64,
+    pub ignored: u64,
+    /// Signals every thread blocks, so none can take them
+    pub blocked: u64,
+    /// Signals queued for the process or one of its threads
+    pub pending: u64,
+    /// Voluntary and involuntary context switches of all threads
+    pub switches: u64,
+    /// Scheduler state letter, e.g. 'S', 'D', 'T', 'Z'
+    pub state: char,
+}
+
+/// Process moved into a cgroup of its own (Linux cgroup v2)
+#[derive(Debug, Clone)]
+pub(crate) struct CgroupLeaf {
+    /// Directory of the process's cgroup
+    pub path: std::path::PathBuf,
+    /// Cgroup directory the process was moved out of
+    pub previous: std::path::PathBuf,
+}
+
+/// Pause a process with SIGSTOP (Unix only)
+#[cfg(not(unix))]
+pub(crate) fn stop_process(_pid: u32) -> std::io::Result<()> {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+}
+
+/// Resume a stopped process with SIGCONT (Unix only)
+#[cfg(not(unix))]
+pub(crate) fn continue_process(_pid: u32) -> std::io::Result<()> {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+}
+
+/// Check with signal 0 that a process exists and may be signalled (Unix only)
+#[cfg(not(unix))]
+pub(crate) fn probe_signal(_pid: u32) -> std::io::Result<()> {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+}
+
+/// Send SIGURG, which does nothing unless the process handles it (Unix only)
+#[cfg(not(unix))]
+pub(crate) fn send_urgent(_pid: u32) -> std::io::Result<()> {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+}
+
+/// Move a process into a cgroup of its own (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn cgroup_confine(_pid: u32, _controllers: &[&str]) -> std::io::Result<CgroupLeaf> {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+}
+
+/// Move a process into an existing leaf cgroup (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn cgroup_join(_leaf: &CgroupLeaf, _pid: u32) -> std::io::Result<bool> {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+}
+
+/// Freeze or thaw a leaf cgroup (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn cgroup_freeze(_leaf: &CgroupLeaf, _frozen: bool, _settle: std::time::Duration) -> std::io::Result<bool> {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+}
+
+/// Write a control file of a confined process's cgroup (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn cgroup_set(_leaf: &CgroupLeaf, _file: &str, _value: &str) -> std::io::Result<()> {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+}
+
+/// Read a control file of a confined process's cgroup (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn cgroup_get(_leaf: &CgroupLeaf, _file: &str) -> std::io::Result<String> {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+}
+
+/// Move a confined process and its children back and remove their cgroup (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn cgroup_release(_leaf: &CgroupLeaf) -> std::io::Result<()> {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+}
+
+/// I/O scheduling class and level (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn io_priority(_pid: u32) -> Option<crate::schema::IoPriority> {
+    None
+}
+
+/// Set I/O scheduling class and level (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn set_io_priority(_pid: u32, _priority: crate::schema::IoPriority) -> std::io::Result<()> {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+}
+
+/// Pin a process to CPUs (Linux and Windows; macOS has no process affinity)
+#[cfg(not(any(target_os = "linux", windows)))]
+pub(crate) fn set_affinity(_pid: u32, _cpus: &[usize]) -> std::io::Result<()> {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+}
+
+/// CPUs a process may run on (Linux and Windows)
+#[cfg(not(any(target_os = "linux", windows)))]
+pub(crate) fn affinity(_pid: u32) -> Option<Vec<usize>> {
+    None
+}
+
+/// Pin every thread of a running process to CPUs (Linux and Windows)
+#[cfg(not(any(target_os = "linux", windows)))]
+pub(crate) fn set_process_affinity(_pid: u32, _cpus: &[usize]) -> std::io::Result<()> {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+}
+
+/// Become the reaper of orphaned descendants (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn set_child_subreaper() -> std::io::Result<()> {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+}
+
+/// Whether the server is a child subreaper (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn is_child_subreaper() -> bool {
+    false
+}
+
+/// Zombie children of the server (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn zombie_children() -> Vec<(u32, String)> {
+    Vec::new()
+}
+
+/// Collect the exit status of a zombie child (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn reap_child(_pid: u32) -> std::io::Result<Option<std::process::ExitStatus>> {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+}
+
+/// Explain why a management operation on `pid` was denied (Windows only)
+#[cfg(not(windows))]
+pub(crate) fn permission_hint(_pid: u32) -> Option<String> {
+    None
+}
+
+/// Explain why the target's integrity level blocks management (Windows only)
+#[cfg(not(windows))]
+pub(crate) fn integrity_denial(_pid: u32) -> Option<String> {
+    None
+}
+
+/// Whether `pid` is a thread of another process (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn is_thread(_pid: u32) -> bool {
+    false
+}
+
+/// File to read the running image from (the exe path outside Linux)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn exe_image(pid: u32) -> Option<std::path::PathBuf> {
+    exe_path(pid)
+}
+
+/// Linux security module label (SELinux/AppArmor)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn security_label(_pid: u32) -> Option<String> {
+    None
+}
+
+/// Linux capability sets (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn capability_sets(_pid: u32) -> Option<CapabilityMasks> {
+    None
+}
+
+/// Resident memory by kind and swap usage (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn memory_status(_pid: u32) -> Option<MemoryStatus> {
+    None
+}
+
+/// Signal dispositions, masks and context switches (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn signal_masks(_pid: u32) -> Option<SignalMasks> {
+    None
+}
+
+/// Seccomp mode (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn seccomp_mode(_pid: u32) -> Option<crate::schema::SeccompMode> {
+    None
+}
+
+/// Mandatory integrity level (Windows only)
+#[cfg(not(windows))]
+pub(crate) fn integrity_level(_pid: u32) -> Option<&'static str> {
+    None
+}
+
+/// AppContainer flag (Windows only)
+#[cfg(not(windows))]
+pub(crate) fn is_app_container(_pid: u32) -> Option<bool> {
+    None
+}
+
+/// Priority class name for a nice value (Windows only)
+#[cfg(not(windows))]
+pub(crate) fn priority_class_name(_nice: i32) -> Option<&'static str> {
+    None
+}
+
+/// Sockets among the open files (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn sockets(_pid: u32, _files: &[crate::schema::OpenFile]) -> Option<Vec<crate::schema::SocketInfo>> {
+    None
+}
+
+/// Address space mappings (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn memory_maps(_pid: u32) -> Option<Vec<crate::schema::MemoryMap>> {
+    None
+}
+
+/// Threads with name and state (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn threads(_pid: u32) -> Option<Vec<crate::schema::ThreadInfo>> {
+    None
+}
+
+/// Cgroup membership (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn cgroup_paths(_pid: u32) -> Option<Vec<String>> {
+    None
+}
+
+/// Cgroup path (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn cgroup_path(_pid: u32) -> Option<String> {
+    None
+}
+
+/// PIDs in nested PID namespaces (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn namespace_pids(_pid: u32) -> Option<Vec<u32>> {
+    None
+}
+
+/// Container ID from the cgroup path (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn container_id(_pid: u32) -> Option<String> {
+    None
+}
+
+/// Namespaces (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn namespaces(_pid: u32) -> Option<Vec<crate::schema::NamespaceInfo>> {
+    None
+}
+
+/// Resource limits (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn resource_limits(_pid: u32) -> Option<Vec<crate::schema::ResourceLimit>> {
+    None
+}
+
+/// Controlling terminal such as `pts/3`, or `none` (Linux and macOS)
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+pub(crate) fn controlling_tty(_pid: u32) -> Option<String> {
+    None
+}
+
+/// systemd unit the process runs in (Linux only)
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn service_unit(_pid: u32) -> Option<String> {
+    None
+}
+
+/// Names of the running WSL distros (Windows only)
+#[cfg(not(windows))]
+pub(crate) fn wsl_distros() -> Vec<String> {
+    Vec::new()
+}
+
+/// Process table of a WSL distro (Windows only)
+#[cfg(not(windows))]
+pub(crate) fn wsl_processes(_distro: &str) -> Option<Vec<WslProcess>> {
+    None
+}
+
+/// Code signature of an executable (macOS and Windows)
+#[cfg(not(any(target_os = "macos", windows)))]
+pub(crate) fn code_signature(_exe: &std::path::Path) -> Option<crate::schema::CodeSignature> {
+    Some(crate::schema::CodeSignature {
+        notes: vec![
+            "Executables carry no OS-verified signature here; compare the exe_hash column against an \
+             allowlist or verify the owning package (dpkg --verify, rpm -V)"
+                .to_string(),
+        ],
+        ..Default::default()
+    })
+}
+
+/// Run `command` with stdout and stderr captured
+///
+/// `None` if it cannot start or is still running after `timeout`, in which
+/// case it is killed.
+pub(crate) fn run_captured(command: &mut std::process::Command, timeout: std::time::Duration) -> Option<std::process::Output> {
+    use std::io::Read;
+
+    let mut child = crate::spawn::spawn_helper(
+        command
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped()),
+    )
+    .ok()?;
+    // Drained on the side so a chatty child cannot fill a pipe and stall
+    let drain = |mut pipe: Box<dyn Read + Send>| {
+        std::thread::spawn(move || {
+            let mut bytes = Vec::new();
+            pipe.read_to_end(&mut bytes).map(|_| bytes)
+        })
+    };
+    let stdout = drain(Box::new(child.stdout.take()?));
+    let stderr = drain(Box::new(child.stderr.take()?));
+    let deadline = std::time::Instant::now() + timeout;
+    let status = loop {
+        // Through the spawn registry, which keeps the orphan reaper off the child
+        match crate::spawn::try_reap(&mut child) {
+            Ok(Some(status)) => break status,
+            Ok(None) if std::time::Instant::now() < deadline => std::thread::sleep(std::time::Duration::from_millis(50)),
+            _ => {
+                crate::spawn::reap(&mut child);
+                return None;
+            }
+        }
+    };
+    Some(std::process::Output {
+        status,
+        stdout: stdout.join().ok()?.ok()?,
+        stderr: stderr.join().ok()?.ok()?,
+    })
+}
+
+/// Whether every user may create or replace files in `dir` (Unix and Windows)
+#[cfg(not(any(unix, windows)))]
+pub(crate) fn world_writable_dir(_dir: &std::path::Path) -> bool {
+    false
+}
@@ -0,0 +1,126 @@
+//! POSIX calls shared by every Unix target
+
+use super::{MAX_NICE, MIN_NICE};
+
+fn who(pid: u32) -> std::io::Result<libc::id_t> {
+    libc::id_t::try_from(pid).map_err(|_| std::io::Error::from(std::io::ErrorKind::InvalidInput))
+}
+
+/// Nice value via `getpriority`
+pub(crate) fn nice(pid: u32) -> Option<i32> {
+    let who = who(pid).ok()?;
+    // SAFETY: getpriority only reads its arguments.
+    let nice = unsafe { libc::getpriority(libc::PRIO_PROCESS as _, who) };
+    if nice != -1 {
+        return Some(nice);
+    }
+    // -1 is also a valid nice value; it is an error only if the process is gone
+    // SAFETY: signal 0 performs the existence and permission check only.
+    let exists = unsafe { libc::kill(pid as libc::pid_t, 0) } == 0
+        || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM);
+    exists.then_some(-1)
+}
+
+/// Set the nice value via `setpriority`; lowering it needs privileges
+pub(crate) fn set_nice(pid: u32, nice: i32) -> std::io::Result<()> {
+    let who = who(pid)?;
+    let nice = nice.clamp(MIN_NICE, MAX_NICE);
+    // SAFETY: setpriority only reads its arguments.
+    if unsafe { libc::setpriority(libc::PRIO_PROCESS as _, who, nice) } == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+fn send(pid: u32, signal: libc::c_int) -> std::io::Result<()> {
+    let pid = libc::pid_t::try_from(pid).map_err(|_| std::io::Error::from(std::io::ErrorKind::InvalidInput))?;
+    // SAFETY: kill only reads its arguments.
+    if unsafe { libc::kill(pid, signal) } == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+/// Pause a process with SIGSTOP
+pub(crate) fn stop_process(pid: u32) -> std::io::Result<()> {
+    send(pid, libc::SIGSTOP)
+}
+
+/// Resume a stopped process with SIGCONT
+pub(crate) fn continue_process(pid: u32) -> std::io::Result<()> {
+    send(pid, libc::SIGCONT)
+}
+
+/// Check with signal 0 that a process exists and may be signalled
+pub(crate) fn probe_signal(pid: u32) -> std::io::Result<()> {
+    send(pid, 0)
+}
+
+/// Send SIGURG, which the kernel discards unless the process handles it
+pub(crate) fn send_urgent(pid: u32) -> std::io::Result<()> {
+    send(pid, libc::SIGURG)
+}
+
+/// Block until child `pid` has exited, leaving it unreaped (`waitid` with `WNOWAIT`)
+pub(crate) fn await_exit(child: &std::process::Child) -> std::io::Result<()> {
+    let pid = libc::id_t::from(child.id());
+    loop {
+        // SAFETY: info is plain old data the kernel fills in.
+        let mut info: libc::siginfo_t = unsafe { std::mem::zeroed() };
+        // SAFETY: waitid writes into info, which outlives the call.
+        if unsafe { libc::waitid(libc::P_PID, pid, &raw mut info, libc::WEXITED | libc::WNOWAIT) } == 0 {
+            return Ok(());
+        }
+        let error = std::io::Error::last_os_error();
+        if error.kind() != std::io::ErrorKind::Interrupted {
+            return Err(error);
+        }
+    }
+}
+
+/// Reap exited child `pid` with its resource usage via `wait4`
+pub(crate) fn reap_exited(
+    child: &std::process::Child,
+) -> std::io::Result<(std::process::ExitStatus, crate::schema::ResourceUsage)> {
+    use std::os::unix::process::ExitStatusExt;
+
+    let pid = libc::pid_t::try_from(child.id()).map_err(std::io::Error::other)?;
+    let mut status: libc::c_int = 0;
+    // SAFETY: usage is plain old data the kernel fills in.
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    loop {
+        // SAFETY: wait4 writes through both pointers, which outlive the call.
+        if unsafe { libc::wait4(pid, &raw mut status, 0, &raw mut usage) } == pid {
+            break;
+        }
+        let error = std::io::Error::last_os_error();
+        if error.kind() != std::io::ErrorKind::Interrupted {
+            return Err(error);
+        }
+    }
+    let secs = |time: libc::timeval| time.tv_sec as f64 + time.tv_usec as f64 / 1_000_000.0;
+    // Kilobytes on Linux, bytes on macOS
+    let max_rss = u64::try_from(usage.ru_maxrss).unwrap_or_default();
+    let max_rss_bytes = if cfg!(target_os = "macos") { max_rss } else { max_rss * 1024 };
+    let minor = u64::try_from(usage.ru_minflt).unwrap_or_default();
+    let major = u64::try_from(usage.ru_majflt).unwrap_or_default();
+    Ok((
+        std::process::ExitStatus::from_raw(status),
+        crate::schema::ResourceUsage {
+            max_rss_bytes,
+            user_cpu_secs: secs(usage.ru_utime),
+            system_cpu_secs: secs(usage.ru_stime),
+            page_faults: minor + major,
+            major_faults: Some(major),
+        },
+    ))
+}
+
+/// Whether every user may create or replace files in `dir`
+pub(crate) fn world_writable_dir(dir: &std::path::Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    std::fs::metadata(dir).is_ok_and(|metadata| metadata.permissions().mode() & 0o002 != 0)
+}
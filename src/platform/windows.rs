@@ -0,0 +1,1142 @@
+//! Windows implementations backed by Win32 process APIs
+
+use std::os::windows::ffi::{OsStrExt, OsStringExt};
+use std::path::{Path, PathBuf};
+
+use super::{FileHolder, NativeProcess, PortSocket, PrivateMemory, ProcessArch, ProcessTable, WslProcess};
+use crate::schema::{CodeSignature, OpenFile, ProcessKind, SignatureState, SocketInfo};
+use windows_sys::Wdk::System::SystemInformation::{NtQuerySystemInformation, SystemProcessInformation};
+use windows_sys::Wdk::System::Threading::{NtQueryInformationProcess, ProcessCommandLineInformation};
+use windows_sys::Win32::Foundation::{
+    CloseHandle, DUPLICATE_SAME_ACCESS, DuplicateHandle, ERROR_INSUFFICIENT_BUFFER, ERROR_MORE_DATA, ERROR_SUCCESS,
+    FILETIME, HANDLE, LocalFree, STATUS_INFO_LENGTH_MISMATCH, UNICODE_STRING, WAIT_OBJECT_0,
+};
+use windows_sys::Win32::NetworkManagement::IpHelper::{
+    GetExtendedTcpTable, GetExtendedUdpTable, MIB_TCP6ROW_OWNER_PID, MIB_TCPROW_OWNER_PID, MIB_UDP6ROW_OWNER_PID,
+    MIB_UDPROW_OWNER_PID, TCP_TABLE_OWNER_PID_ALL, UDP_TABLE_OWNER_PID,
+};
+use windows_sys::Win32::Networking::WinSock::{AF_INET, AF_INET6};
+use windows_sys::Win32::Security::{
+    GetSidSubAuthority, GetSidSubAuthorityCount, GetTokenInformation, LUID_AND_ATTRIBUTES,
+    LookupPrivilegeValueW, PRIVILEGE_SET, PrivilegeCheck,
+    SE_DEBUG_NAME, SE_PRIVILEGE_ENABLED, TOKEN_ELEVATION, TOKEN_MANDATORY_LABEL, TOKEN_QUERY,
+    TokenElevation, TokenIntegrityLevel, TokenIsAppContainer,
+};
+use windows_sys::Win32::Storage::FileSystem::{
+    FILE_NAME_NORMALIZED, FILE_TYPE_CHAR, FILE_TYPE_DISK, FILE_TYPE_PIPE, GetFileType, GetFinalPathNameByHandleW,
+    VOLUME_NAME_DOS,
+};
+use windows_sys::Win32::System::RemoteDesktop::ProcessIdToSessionId;
+use windows_sys::Win32::System::RestartManager::{
+    CCH_RM_SESSION_KEY, RM_PROCESS_INFO, RmEndSession, RmGetList, RmRegisterResources, RmStartSession,
+};
+use windows_sys::Win32::System::ProcessStatus::{GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS, PROCESS_MEMORY_COUNTERS_EX};
+use windows_sys::Win32::System::SystemInformation::{
+    IMAGE_FILE_MACHINE, IMAGE_FILE_MACHINE_AMD64, IMAGE_FILE_MACHINE_ARM64, IMAGE_FILE_MACHINE_ARMNT,
+    IMAGE_FILE_MACHINE_I386, IMAGE_FILE_MACHINE_UNKNOWN,
+};
+use windows_sys::Win32::System::SystemServices::PRIVILEGE_SET_ALL_NECESSARY;
+use windows_sys::Win32::System::Threading::{
+    ABOVE_NORMAL_PRIORITY_CLASS, BELOW_NORMAL_PRIORITY_CLASS, GetCurrentProcess, GetExitCodeProcess, GetPriorityClass,
+    GetProcessAffinityMask, GetProcessHandleCount, GetProcessInformation, GetProcessTimes, HIGH_PRIORITY_CLASS, IDLE_PRIORITY_CLASS, INFINITE,
+    IsWow64Process2, NORMAL_PRIORITY_CLASS, OpenProcess, OpenProcessToken, PROCESS_DUP_HANDLE, PROCESS_MACHINE_INFORMATION,
+    PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_SET_INFORMATION, ProcessMachineTypeInfo,
+    QueryFullProcessImageNameW, REALTIME_PRIORITY_CLASS, SetPriorityClass, SetProcessAffinityMask, WaitForSingleObject,
+};
+use windows_sys::Win32::System::WindowsProgramming::SYSTEM_PROCESS_INFORMATION;
+use windows_sys::Win32::UI::Shell::CommandLineToArgvW;
+
+/// Owned kernel handle closed on drop
+struct OwnedHandle(HANDLE);
+
+impl OwnedHandle {
+    fn open_process(pid: u32, access: u32) -> Option<Self> {
+        // SAFETY: OpenProcess has no preconditions; a null return means failure.
+        let handle = unsafe { OpenProcess(access, 0, pid) };
+        (!handle.is_null()).then_some(Self(handle))
+    }
+
+    /// Query token of a process handle (pseudo handles are accepted)
+    fn open_token(process: HANDLE) -> Option<Self> {
+        let mut token: HANDLE = std::ptr::null_mut();
+        // SAFETY: token is a valid out-pointer; failure leaves it null.
+        let ok = unsafe { OpenProcessToken(process, TOKEN_QUERY, &mut token) };
+        (ok != 0 && !token.is_null()).then_some(Self(token))
+    }
+}
+
+impl Drop for OwnedHandle {
+    fn drop(&mut self) {
+        // SAFETY: the handle is owned and closed exactly once.
+        unsafe { CloseHandle(self.0) };
+    }
+}
+
+/// Read PrivateUsage (commit charge) via GetProcessMemoryInfo
+pub(crate) fn private_memory(pid: u32) -> PrivateMemory {
+    let Some(handle) = OwnedHandle::open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION) else {
+        return PrivateMemory::default();
+    };
+
+    // SAFETY: counters is plain old data sized correctly via cb.
+    let mut counters: PROCESS_MEMORY_COUNTERS_EX = unsafe { std::mem::zeroed() };
+    counters.cb = std::mem::size_of::<PROCESS_MEMORY_COUNTERS_EX>() as u32;
+    let ok = unsafe {
+        GetProcessMemoryInfo(
+            handle.0,
+            (&raw mut counters).cast::<PROCESS_MEMORY_COUNTERS>(),
+            counters.cb,
+        )
+    };
+
+    PrivateMemory {
+        uss_bytes: None,
+        private_bytes: (ok != 0).then_some(counters.PrivateUsage as u64),
+    }
+}
+
+// ============================================================================
+// NATIVE PROCESS TABLE
+// ============================================================================
+
+/// Seconds between 1601-01-01 (FILETIME epoch) and 1970-01-01
+const FILETIME_UNIX_OFFSET_SECS: u64 = 11_644_473_600;
+
+/// FILETIME ticks (100 ns) per second
+const FILETIME_TICKS: u64 = 10_000_000;
+
+/// Enumerate processes with one `NtQuerySystemInformation` call
+///
+/// The kernel returns names, times and memory counters for every process in
+/// a single buffer, where sysinfo opens each process separately.
+pub(crate) fn process_table() -> Option<ProcessTable> {
+    // u64 elements keep the buffer 8-byte aligned for the structs inside
+    let mut buffer: Vec<u64> = vec![0; 64 * 1024];
+    loop {
+        let bytes = u32::try_from(buffer.len() * 8).ok()?;
+        let mut needed = 0u32;
+        // SAFETY: buffer is writable for `bytes` bytes; needed is a valid out-pointer.
+        let status = unsafe {
+            NtQuerySystemInformation(SystemProcessInformation, buffer.as_mut_ptr().cast(), bytes, &mut needed)
+        };
+        match status {
+            0 => break,
+            // Leave headroom for processes started before the retry
+            STATUS_INFO_LENGTH_MISMATCH => buffer = vec![0; (needed as usize / 8) * 2 + 1024],
+            _ => return None,
+        }
+    }
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0.0, |now| now.as_secs_f64());
+    let base = buffer.as_ptr().cast::<u8>();
+    let mut offset = 0usize;
+    let mut processes = Vec::new();
+    loop {
+        // SAFETY: offsets come from NextEntryOffset chains inside the buffer the kernel filled.
+        let info = unsafe { &*base.add(offset).cast::<SYSTEM_PROCESS_INFORMATION>() };
+        // Reserved1 holds WorkingSetPrivateSize, HardFaultCount, NumberOfThreadsHighWatermark,
+        // CycleTime, CreateTime, UserTime and KernelTime (see the NT headers)
+        let time_at = |at: usize| {
+            u64::from_ne_bytes(info.Reserved1[at..at + 8].try_into().unwrap_or_default())
+        };
+        let (created, user, kernel) = (time_at(24), time_at(32), time_at(40));
+        let pid = info.UniqueProcessId as usize as u32;
+        let start_time = (created / FILETIME_TICKS).saturating_sub(FILETIME_UNIX_OFFSET_SECS);
+        let name = match unicode_string(&info.ImageName) {
+            Some(name) => name,
+            None if pid == 0 => "System Idle Process".to_string(),
+            None => String::new(),
+        };
+        processes.push(NativeProcess {
+            pid,
+            name,
+            start_time,
+            cpu_secs: (user + kernel) as f64 / FILETIME_TICKS as f64,
+            age_secs: match created {
+                0 => 0.0,
+                _ => (now - (created as f64 / FILETIME_TICKS as f64 - FILETIME_UNIX_OFFSET_SECS as f64)).max(0.0),
+            },
+            rss_bytes: info.WorkingSetSize as u64,
+            virtual_bytes: info.PagefileUsage as u64,
+        });
+        if info.NextEntryOffset == 0 {
+            break;
+        }
+        offset += info.NextEntryOffset as usize;
+    }
+    // One kernel snapshot: nothing is read per process, so nothing is skipped
+    Some(ProcessTable {
+        processes,
+        skipped: Default::default(),
+    })
+}
+
+/// Copy a counted UTF-16 string; `None` when empty
+fn unicode_string(string: &UNICODE_STRING) -> Option<String> {
+    if string.Buffer.is_null() || string.Length == 0 {
+        return None;
+    }
+    // SAFETY: Buffer points at Length bytes of UTF-16 owned by the enclosing buffer.
+    let units = unsafe { std::slice::from_raw_parts(string.Buffer, usize::from(string.Length) / 2) };
+    Some(String::from_utf16_lossy(units))
+}
+
+/// Command line of `pid`, split like the C runtime does
+///
+/// `ProcessCommandLineInformation` returns the string from the target's PEB
+/// without reading its memory, so only query-limited access is needed.
+/// Processes the server cannot open fall back to WMI.
+pub(crate) fn cmdline(pid: u32) -> Option<Vec<String>> {
+    native_cmdline(pid).or_else(|| wmi_process(pid)?.command_line.map(|line| split_command_line(&line)))
+}
+
+fn native_cmdline(pid: u32) -> Option<Vec<String>> {
+    let handle = OwnedHandle::open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION)?;
+    let mut buffer: Vec<u64> = vec![0; 512];
+    loop {
+        let bytes = u32::try_from(buffer.len() * 8).ok()?;
+        let mut needed = 0u32;
+        // SAFETY: buffer is writable for `bytes` bytes; needed is a valid out-pointer.
+        let status = unsafe {
+            NtQueryInformationProcess(
+                handle.0,
+                ProcessCommandLineInformation,
+                buffer.as_mut_ptr().cast(),
+                bytes,
+                &mut needed,
+            )
+        };
+        match status {
+            0 => break,
+            STATUS_INFO_LENGTH_MISMATCH if needed > bytes => buffer = vec![0; needed as usize / 8 + 1],
+            _ => return None,
+        }
+    }
+    // SAFETY: on success the buffer starts with a UNICODE_STRING pointing into itself.
+    let line = unicode_string(unsafe { &*buffer.as_ptr().cast::<UNICODE_STRING>() })?;
+    Some(split_command_line(&line))
+}
+
+/// Arguments of a command line string via `CommandLineToArgvW`
+fn split_command_line(line: &str) -> Vec<String> {
+    let wide: Vec<u16> = line.encode_utf16().chain(std::iter::once(0)).collect();
+    let mut count = 0i32;
+    // SAFETY: wide is NUL-terminated; the returned array is freed with LocalFree below.
+    let argv = unsafe { CommandLineToArgvW(wide.as_ptr(), &mut count) };
+    if argv.is_null() {
+        return vec![line.to_string()];
+    }
+    let args = (0..usize::try_from(count).unwrap_or_default())
+        .map(|index| {
+            // SAFETY: argv holds `count` NUL-terminated wide strings.
+            let arg = unsafe { *argv.add(index) };
+            let len = (0..).take_while(|at| unsafe { *arg.add(*at) } != 0).count();
+            String::from_utf16_lossy(unsafe { std::slice::from_raw_parts(arg, len) })
+        })
+        .collect();
+    // SAFETY: argv was allocated by CommandLineToArgvW and is freed once.
+    unsafe { LocalFree(argv.cast()) };
+    args
+}
+
+/// Number of open kernel handles
+pub(crate) fn handle_count(pid: u32) -> Option<u64> {
+    let handle = OwnedHandle::open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION)?;
+    let mut count = 0u32;
+    // SAFETY: count is a valid out-pointer for the handle's lifetime.
+    let ok = unsafe { GetProcessHandleCount(handle.0, &mut count) };
+    (ok != 0).then_some(u64::from(count))
+}
+
+/// Executable path via `QueryFullProcessImageNameW`, or WMI when access is denied
+pub(crate) fn exe_path(pid: u32) -> Option<PathBuf> {
+    native_exe_path(pid).or_else(|| wmi_process(pid)?.exe)
+}
+
+fn native_exe_path(pid: u32) -> Option<PathBuf> {
+    let handle = OwnedHandle::open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION)?;
+    let mut buffer = vec![0u16; 32 * 1024];
+    let mut len = buffer.len() as u32;
+    // SAFETY: buffer holds `len` UTF-16 units; len is updated to the written length.
+    let ok = unsafe { QueryFullProcessImageNameW(handle.0, PROCESS_NAME_WIN32, buffer.as_mut_ptr(), &mut len) };
+    if ok == 0 {
+        return None;
+    }
+    buffer.truncate(len as usize);
+    Some(PathBuf::from(std::ffi::OsString::from_wide(&buffer)))
+}
+
+/// Working directory (lives in the target's PEB; reading it needs VM access, so not supported)
+pub(crate) fn cwd(_pid: u32) -> Option<PathBuf> {
+    None
+}
+
+// ============================================================================
+// WMI FALLBACK
+// ============================================================================
+
+/// Longest the WMI query may take; PowerShell's cold start alone can take seconds
+const WMI_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(20);
+
+/// How long one WMI snapshot answers lookups before it is queried again
+///
+/// Fallbacks come in bursts (one per denied row of a listing), so a snapshot
+/// turns a listing into one PowerShell run instead of one per process.
+const WMI_MAX_AGE: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// `@(...)` keeps a single process an array; ConvertTo-Json would unwrap it
+const WMI_SCRIPT: &str = "\
+    ConvertTo-Json -Compress -InputObject @(Get-CimInstance Win32_Process \
+        -Property ProcessId,CommandLine,ExecutablePath | \
+        Select-Object ProcessId,CommandLine,ExecutablePath)";
+
+/// Fields of one `Win32_Process` instance
+#[derive(Debug, Clone, Default)]
+struct WmiProcess {
+    command_line: Option<String>,
+    exe: Option<PathBuf>,
+}
+
+/// Last WMI snapshot and when it was taken; empty when the query failed
+static WMI_SNAPSHOT: std::sync::Mutex<Option<(std::time::Instant, std::collections::HashMap<u32, WmiProcess>)>> =
+    std::sync::Mutex::new(None);
+
+/// `Win32_Process` fields of `pid` from a snapshot at most [`WMI_MAX_AGE`] old
+///
+/// The WMI service reads process details with its own rights, so it can fill
+/// in command lines and paths of processes a non-elevated server cannot open.
+fn wmi_process(pid: u32) -> Option<WmiProcess> {
+    let mut snapshot = WMI_SNAPSHOT.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+    if snapshot.as_ref().is_none_or(|(taken, _)| taken.elapsed() > WMI_MAX_AGE) {
+        // A failed query is cached too, so a host without PowerShell is not retried per row
+        *snapshot = Some((std::time::Instant::now(), wmi_processes().unwrap_or_default()));
+    }
+    snapshot.as_ref()?.1.get(&pid).cloned()
+}
+
+/// Every `Win32_Process` instance by PID through PowerShell's `Get-CimInstance`
+fn wmi_processes() -> Option<std::collections::HashMap<u32, WmiProcess>> {
+    let output = super::run_captured(
+        std::process::Command::new("powershell.exe").args(["-NoProfile", "-NonInteractive", "-Command", WMI_SCRIPT]),
+        WMI_TIMEOUT,
+    )?;
+    if !output.status.success() {
+        return None;
+    }
+    let instances: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).ok()?;
+    Some(
+        instances
+            .iter()
+            .filter_map(|instance| {
+                let text = |key: &str| {
+                    instance
+                        .get(key)
+                        .and_then(serde_json::Value::as_str)
+                        .filter(|value| !value.is_empty())
+                        .map(str::to_string)
+                };
+                let pid = u32::try_from(instance.get("ProcessId")?.as_u64()?).ok()?;
+                Some((pid, WmiProcess { command_line: text("CommandLine"), exe: text("ExecutablePath").map(PathBuf::from) }))
+            })
+            .collect(),
+    )
+}
+
+// ============================================================================
+// ELEVATION / INTEGRITY
+// ============================================================================
+
+/// Whether the server process token is elevated (UAC)
+fn server_is_elevated() -> bool {
+    // SAFETY: GetCurrentProcess returns a pseudo handle that needs no closing.
+    let Some(token) = OwnedHandle::open_token(unsafe { GetCurrentProcess() }) else {
+        return false;
+    };
+    let mut elevation = TOKEN_ELEVATION { TokenIsElevated: 0 };
+    let mut len = 0u32;
+    // SAFETY: elevation is sized for the TokenElevation class.
+    let ok = unsafe {
+        GetTokenInformation(
+            token.0,
+            TokenElevation,
+            (&raw mut elevation).cast(),
+            std::mem::size_of::<TOKEN_ELEVATION>() as u32,
+            &mut len,
+        )
+    };
+    ok != 0 && elevation.TokenIsElevated != 0
+}
+
+/// Whether SeDebugPrivilege is present and enabled on the server token
+fn server_has_debug_privilege() -> bool {
+    // SAFETY: GetCurrentProcess returns a pseudo handle that needs no closing.
+    let Some(token) = OwnedHandle::open_token(unsafe { GetCurrentProcess() }) else {
+        return false;
+    };
+    let mut set = PRIVILEGE_SET {
+        PrivilegeCount: 1,
+        Control: PRIVILEGE_SET_ALL_NECESSARY,
+        Privilege: [LUID_AND_ATTRIBUTES {
+            Luid: unsafe { std::mem::zeroed() },
+            Attributes: SE_PRIVILEGE_ENABLED,
+        }],
+    };
+    // SAFETY: SE_DEBUG_NAME is a static wide string; Luid is a valid out-pointer.
+    if unsafe { LookupPrivilegeValueW(std::ptr::null(), SE_DEBUG_NAME, &mut set.Privilege[0].Luid) } == 0 {
+        return false;
+    }
+    let mut result = 0;
+    // SAFETY: set describes exactly one privilege; result is a valid out-pointer.
+    let ok = unsafe { PrivilegeCheck(token.0, &mut set, &mut result) };
+    ok != 0 && result != 0
+}
+
+/// Mandatory integrity level RID of a process token
+fn integrity_rid(pid: u32) -> Option<u32> {
+    let process = OwnedHandle::open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION)?;
+    let token = OwnedHandle::open_token(process.0)?;
+
+    let mut len = 0u32;
+    // SAFETY: a null buffer with zero length only queries the required size.
+    unsafe { GetTokenInformation(token.0, TokenIntegrityLevel, std::ptr::null_mut(), 0, &mut len) };
+    if len == 0 {
+        return None;
+    }
+    // u64 backing keeps the TOKEN_MANDATORY_LABEL header suitably aligned
+    let mut buffer = vec![0u64; (len as usize).div_ceil(8)];
+    // SAFETY: buffer holds at least len bytes.
+    let ok = unsafe {
+        GetTokenInformation(token.0, TokenIntegrityLevel, buffer.as_mut_ptr().cast(), len, &mut len)
+    };
+    if ok == 0 {
+        return None;
+    }
+
+    // SAFETY: on success the buffer starts with a TOKEN_MANDATORY_LABEL whose
+    // Sid points into the same buffer.
+    unsafe {
+        let label = &*buffer.as_ptr().cast::<TOKEN_MANDATORY_LABEL>();
+        let sid = label.Label.Sid;
+        let count = *GetSidSubAuthorityCount(sid);
+        Some(*GetSidSubAuthority(sid, u32::from(count.checked_sub(1)?)))
+    }
+}
+
+fn integrity_name(rid: u32) -> &'static str {
+    match rid {
+        0x0000..0x1000 => "Untrusted",
+        0x1000..0x2000 => "Low",
+        0x2000..0x3000 => "Medium",
+        0x3000..0x4000 => "High",
+        0x4000..0x5000 => "System",
+        _ => "Protected",
+    }
+}
+
+/// Mandatory integrity level of a process ("Low", "Medium", "High", "System", ...)
+pub(crate) fn integrity_level(pid: u32) -> Option<&'static str> {
+    integrity_rid(pid).map(integrity_name)
+}
+
+/// Whether the process token is an AppContainer token
+pub(crate) fn is_app_container(pid: u32) -> Option<bool> {
+    let process = OwnedHandle::open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION)?;
+    let token = OwnedHandle::open_token(process.0)?;
+    let mut value = 0u32;
+    let mut len = 0u32;
+    // SAFETY: TokenIsAppContainer returns a DWORD into value.
+    let ok = unsafe {
+        GetTokenInformation(
+            token.0,
+            TokenIsAppContainer,
+            (&raw mut value).cast(),
+            std::mem::size_of::<u32>() as u32,
+            &mut len,
+        )
+    };
+    (ok != 0).then_some(value != 0)
+}
+
+/// Classify by PID and session: the kernel is PID 0 and 4, and services run in session 0
+pub(crate) fn process_kind(pid: u32) -> Option<ProcessKind> {
+    if pid == 0 || pid == 4 {
+        return Some(ProcessKind::Kernel);
+    }
+    let mut session = 0u32;
+    // SAFETY: session is a valid out-pointer.
+    if unsafe { ProcessIdToSessionId(pid, &mut session) } == 0 {
+        return None;
+    }
+    Some(if session == 0 { ProcessKind::ServiceManaged } else { ProcessKind::Interactive })
+}
+
+fn machine_name(machine: IMAGE_FILE_MACHINE) -> Option<(&'static str, u8)> {
+    match machine {
+        IMAGE_FILE_MACHINE_I386 => Some(("x86", 32)),
+        IMAGE_FILE_MACHINE_AMD64 => Some(("x86_64", 64)),
+        IMAGE_FILE_MACHINE_ARMNT => Some(("arm", 32)),
+        IMAGE_FILE_MACHINE_ARM64 => Some(("arm64", 64)),
+        _ => None,
+    }
+}
+
+/// Image architecture against the OS's native one
+///
+/// `IsWow64Process2` only reports 32-bit images, so x64 on Arm needs the
+/// machine type query of Windows 11.
+pub(crate) fn process_arch(pid: u32) -> Option<ProcessArch> {
+    let process = OwnedHandle::open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION)?;
+    let (mut wow64, mut native) = (0, 0);
+    // SAFETY: both are valid out-pointers.
+    if unsafe { IsWow64Process2(process.0, &mut wow64, &mut native) } == 0 {
+        return None;
+    }
+    let mut info = PROCESS_MACHINE_INFORMATION::default();
+    // SAFETY: info is writable for its size.
+    let queried = unsafe {
+        GetProcessInformation(
+            process.0,
+            ProcessMachineTypeInfo,
+            (&raw mut info).cast(),
+            std::mem::size_of::<PROCESS_MACHINE_INFORMATION>() as u32,
+        )
+    } != 0;
+    let machine = match wow64 {
+        IMAGE_FILE_MACHINE_UNKNOWN if queried => info.ProcessMachine,
+        IMAGE_FILE_MACHINE_UNKNOWN => native,
+        wow64 => wow64,
+    };
+    let (arch, bits) = machine_name(machine)?;
+    // 32-bit x86 on x64 is WOW64's thunking, not emulation
+    let emulated = machine != native && !(machine == IMAGE_FILE_MACHINE_I386 && native == IMAGE_FILE_MACHINE_AMD64);
+    Some(ProcessArch { arch, bits, emulated })
+}
+
+/// Privilege that lets the server open processes owned by other users
+pub(crate) fn server_privilege() -> Option<&'static str> {
+    server_has_debug_privilege().then_some("SeDebugPrivilege")
+}
+
+/// Explain why the target's integrity level blocks management by the server
+pub(crate) fn integrity_denial(pid: u32) -> Option<String> {
+    let target = integrity_rid(pid)?;
+    let server = integrity_rid(std::process::id())?;
+    (target > server).then(|| {
+        format!(
+            "target integrity level {} is above server integrity level {}",
+            integrity_name(target),
+            integrity_name(server)
+        )
+    })
+}
+
+/// Explain why a management operation on `pid` was denied
+pub(crate) fn permission_hint(pid: u32) -> Option<String> {
+    let elevated = server_is_elevated();
+    let debug = server_has_debug_privilege();
+    let target = integrity_level(pid).unwrap_or("unknown (target token not readable)");
+
+    let mut causes = Vec::new();
+    if !elevated {
+        causes.push("server is not elevated");
+    }
+    if !debug {
+        causes.push("SeDebugPrivilege is not enabled for the server");
+    }
+
+    Some(if causes.is_empty() {
+        format!(
+            "server is elevated with SeDebugPrivilege; target integrity level: {target} \
+             (likely a protected process or Windows service)"
+        )
+    } else {
+        format!(
+            "{}; target integrity level: {target}. Run the server from an elevated \
+             (Administrator) session to manage High/System integrity processes",
+            causes.join(" and ")
+        )
+    })
+}
+
+/// Processes holding `path` open, via the Restart Manager
+pub(crate) fn file_holders(path: &Path) -> Option<Vec<FileHolder>> {
+    let mut session = 0u32;
+    let mut key = [0u16; CCH_RM_SESSION_KEY as usize + 1];
+    // SAFETY: key has room for CCH_RM_SESSION_KEY characters plus the terminator.
+    if unsafe { RmStartSession(&mut session, 0, key.as_mut_ptr()) } != ERROR_SUCCESS {
+        return None;
+    }
+    let holders = session_file_holders(session, path);
+    // SAFETY: the session was started above and is ended exactly once.
+    unsafe { RmEndSession(session) };
+    holders
+}
+
+fn session_file_holders(session: u32, path: &Path) -> Option<Vec<FileHolder>> {
+    let wide: Vec<u16> = path.as_os_str().encode_wide().chain(std::iter::once(0)).collect();
+    let files = [wide.as_ptr()];
+    // SAFETY: files holds one valid NUL-terminated wide string that outlives the call.
+    let rc = unsafe {
+        RmRegisterResources(session, 1, files.as_ptr(), 0, std::ptr::null(), 0, std::ptr::null())
+    };
+    if rc != ERROR_SUCCESS {
+        return None;
+    }
+
+    let mut infos: Vec<RM_PROCESS_INFO> = Vec::new();
+    // The holder count can grow between the sizing call and the fetch
+    for _ in 0..3 {
+        let mut needed = 0u32;
+        let mut count = infos.len() as u32;
+        let mut reasons = 0u32;
+        // SAFETY: infos has room for `count` entries; RmGetList writes at most that many.
+        let rc = unsafe { RmGetList(session, &mut needed, &mut count, infos.as_mut_ptr(), &mut reasons) };
+        match rc {
+            ERROR_SUCCESS => {
+                infos.truncate(count as usize);
+                return Some(
+                    infos
+                        .iter()
+                        .map(|info| FileHolder {
+                            pid: info.Process.dwProcessId,
+                            locked: false,
+                        })
+                        .collect(),
+                );
+            }
+            ERROR_MORE_DATA => infos = vec![RM_PROCESS_INFO::default(); needed as usize],
+            _ => return None,
+        }
+    }
+    None
+}
+
+/// `SystemExtendedHandleInformation`: every open handle on the system (see the NT headers)
+const SYSTEM_EXTENDED_HANDLE_INFORMATION: i32 = 64;
+
+/// `SYSTEM_HANDLE_TABLE_ENTRY_INFO_EX`, which windows-sys does not declare
+#[repr(C)]
+struct HandleEntry {
+    object: *mut std::ffi::c_void,
+    pid: usize,
+    handle: usize,
+    granted_access: u32,
+    creator_back_trace_index: u16,
+    object_type_index: u16,
+    attributes: u32,
+    reserved: u32,
+}
+
+/// File, pipe and console handles of a process, like `/proc/<pid>/fd` on Linux
+///
+/// Each handle is duplicated into this process to ask for its type; only
+/// disk files are asked for a path, because name queries on pipes can block
+/// forever. `fd` is the handle value.
+pub(crate) fn open_files(pid: u32) -> Option<Vec<OpenFile>> {
+    let process = OwnedHandle::open_process(pid, PROCESS_DUP_HANDLE)?;
+    // usize elements keep the buffer aligned for the entries inside
+    let mut buffer: Vec<usize> = vec![0; 64 * 1024];
+    loop {
+        let bytes = u32::try_from(buffer.len() * size_of::<usize>()).ok()?;
+        let mut needed = 0u32;
+        // SAFETY: buffer is writable for `bytes` bytes; needed is a valid out-pointer.
+        let status = unsafe {
+            NtQuerySystemInformation(SYSTEM_EXTENDED_HANDLE_INFORMATION, buffer.as_mut_ptr().cast(), bytes, &mut needed)
+        };
+        match status {
+            0 => break,
+            // Leave headroom for handles opened before the retry
+            STATUS_INFO_LENGTH_MISMATCH => buffer = vec![0; (needed as usize / size_of::<usize>()) * 2 + 1024],
+            _ => return None,
+        }
+    }
+
+    // Header: NumberOfHandles and a reserved word, then the entries
+    let count = buffer[0].min((buffer.len() - 2) * size_of::<usize>() / size_of::<HandleEntry>());
+    // SAFETY: the kernel wrote `count` entries after the two-word header, within the buffer.
+    let entries = unsafe { std::slice::from_raw_parts(buffer.as_ptr().add(2).cast::<HandleEntry>(), count) };
+    let mut files = Vec::new();
+    for entry in entries.iter().filter(|entry| entry.pid == pid as usize) {
+        let mut duplicate: HANDLE = std::ptr::null_mut();
+        // SAFETY: process has PROCESS_DUP_HANDLE; duplicate is a valid out-pointer.
+        let ok = unsafe {
+            DuplicateHandle(
+                process.0,
+                entry.handle as HANDLE,
+                GetCurrentProcess(),
+                &mut duplicate,
+                0,
+                0,
+                DUPLICATE_SAME_ACCESS,
+            )
+        };
+        if ok == 0 {
+            continue;
+        }
+        let duplicate = OwnedHandle(duplicate);
+        // SAFETY: duplicate is an open handle owned by this process.
+        let target = match unsafe { GetFileType(duplicate.0) } {
+            FILE_TYPE_DISK => final_path(duplicate.0).unwrap_or_else(|| "file".to_string()),
+            FILE_TYPE_PIPE => "pipe".to_string(),
+            FILE_TYPE_CHAR => "char".to_string(),
+            // Events, keys, threads and the other non-file objects
+            _ => continue,
+        };
+        files.push(OpenFile {
+            fd: entry.handle as u32,
+            target,
+        });
+    }
+    files.sort_by_key(|file| file.fd);
+    Some(files)
+}
+
+/// TCP and UDP sockets owned by a process, from the IP Helper owner tables
+///
+/// Windows sockets have no descriptor or inode, so `fd` and `inode` stay empty.
+pub(crate) fn connections(pid: u32) -> Option<Vec<SocketInfo>> {
+    let sockets = owner_sockets()?;
+    Some(
+        sockets
+            .into_iter()
+            .filter(|socket| socket.pid == Some(pid))
+            .map(|socket| socket.socket)
+            .collect(),
+    )
+}
+
+/// TCP and UDP sockets on local `port` with their owning processes
+pub(crate) fn port_sockets(port: u16) -> Option<Vec<PortSocket>> {
+    let suffix = format!(":{port}");
+    let mut sockets = owner_sockets()?;
+    sockets.retain(|socket| socket.socket.local.ends_with(&suffix));
+    Some(sockets)
+}
+
+/// Every TCP and UDP socket on the system with its owning process
+fn owner_sockets() -> Option<Vec<PortSocket>> {
+    let tcp = |family: u16| {
+        // SAFETY: the table pointer and size come from owner_table, which sizes the buffer.
+        move |table: *mut std::ffi::c_void, size: &mut u32| unsafe {
+            GetExtendedTcpTable(table, size, 0, u32::from(family), TCP_TABLE_OWNER_PID_ALL, 0)
+        }
+    };
+    let udp = |family: u16| {
+        // SAFETY: as for tcp.
+        move |table: *mut std::ffi::c_void, size: &mut u32| unsafe {
+            GetExtendedUdpTable(table, size, 0, u32::from(family), UDP_TABLE_OWNER_PID, 0)
+        }
+    };
+    let port = |port: u32| u16::from_be(port as u16);
+    let v4 = |address: u32, port: u16| format!("{}:{port}", std::net::Ipv4Addr::from(address.to_ne_bytes()));
+    let v6 = |address: [u8; 16], port: u16| format!("[{}]:{port}", std::net::Ipv6Addr::from(address));
+    let socket = |pid: u32, protocol: &str, local: String, remote: Option<String>, state: Option<u32>| PortSocket {
+        pid: Some(pid),
+        socket: SocketInfo {
+            fd: None,
+            protocol: protocol.to_string(),
+            local,
+            remote,
+            state: state.map(|state| tcp_state(state).to_string()),
+            inode: None,
+        },
+    };
+
+    let mut sockets = Vec::new();
+    let mut readable = false;
+    if let Some(rows) = owner_table::<MIB_TCPROW_OWNER_PID>(tcp(AF_INET)) {
+        readable = true;
+        sockets.extend(rows.iter().map(|row| {
+            socket(
+                row.dwOwningPid,
+                "tcp",
+                v4(row.dwLocalAddr, port(row.dwLocalPort)),
+                Some(v4(row.dwRemoteAddr, port(row.dwRemotePort))),
+                Some(row.dwState),
+            )
+        }));
+    }
+    if let Some(rows) = owner_table::<MIB_TCP6ROW_OWNER_PID>(tcp(AF_INET6)) {
+        readable = true;
+        sockets.extend(rows.iter().map(|row| {
+            socket(
+                row.dwOwningPid,
+                "tcp6",
+                v6(row.ucLocalAddr, port(row.dwLocalPort)),
+                Some(v6(row.ucRemoteAddr, port(row.dwRemotePort))),
+                Some(row.dwState),
+            )
+        }));
+    }
+    if let Some(rows) = owner_table::<MIB_UDPROW_OWNER_PID>(udp(AF_INET)) {
+        readable = true;
+        sockets.extend(
+            rows.iter()
+                .map(|row| socket(row.dwOwningPid, "udp", v4(row.dwLocalAddr, port(row.dwLocalPort)), None, None)),
+        );
+    }
+    if let Some(rows) = owner_table::<MIB_UDP6ROW_OWNER_PID>(udp(AF_INET6)) {
+        readable = true;
+        sockets.extend(
+            rows.iter()
+                .map(|row| socket(row.dwOwningPid, "udp6", v6(row.ucLocalAddr, port(row.dwLocalPort)), None, None)),
+        );
+    }
+    readable.then_some(sockets)
+}
+
+/// Rows of a `MIB_*TABLE_OWNER_PID`: a `u32` count followed by the rows
+fn owner_table<Row: Copy>(query: impl Fn(*mut std::ffi::c_void, &mut u32) -> u32) -> Option<Vec<Row>> {
+    // u32 elements keep the buffer aligned for the rows, which hold only u32 and byte arrays
+    let mut buffer: Vec<u32> = vec![0; 4096];
+    // The table can grow between the sizing call and the fetch
+    for _ in 0..3 {
+        let mut size = u32::try_from(buffer.len() * 4).ok()?;
+        match query(buffer.as_mut_ptr().cast(), &mut size) {
+            0 => {
+                let count = (buffer[0] as usize).min((buffer.len() - 1) * 4 / size_of::<Row>());
+                // SAFETY: the call wrote `count` rows after the count, within the buffer.
+                let rows = unsafe { std::slice::from_raw_parts(buffer.as_ptr().add(1).cast::<Row>(), count) };
+                return Some(rows.to_vec());
+            }
+            ERROR_INSUFFICIENT_BUFFER => buffer = vec![0; size as usize / 4 + 1024],
+            _ => return None,
+        }
+    }
+    None
+}
+
+/// `MIB_TCP_STATE` as spelled on Linux, so connections read the same everywhere
+fn tcp_state(state: u32) -> &'static str {
+    match state {
+        1 | 12 => "CLOSE",
+        2 => "LISTEN",
+        3 => "SYN_SENT",
+        4 => "SYN_RECV",
+        5 => "ESTABLISHED",
+        6 => "FIN_WAIT1",
+        7 => "FIN_WAIT2",
+        8 => "CLOSE_WAIT",
+        9 => "CLOSING",
+        10 => "LAST_ACK",
+        11 => "TIME_WAIT",
+        _ => "UNKNOWN",
+    }
+}
+
+/// DOS path of an open disk file, without the `\\?\` prefix
+fn final_path(handle: HANDLE) -> Option<String> {
+    let mut buffer = vec![0u16; 32 * 1024];
+    // SAFETY: buffer holds `buffer.len()` UTF-16 units; the return value is the written length.
+    let len = unsafe {
+        GetFinalPathNameByHandleW(
+            handle,
+            buffer.as_mut_ptr(),
+            buffer.len() as u32,
+            FILE_NAME_NORMALIZED | VOLUME_NAME_DOS,
+        )
+    } as usize;
+    if len == 0 || len >= buffer.len() {
+        return None;
+    }
+    let path = String::from_utf16_lossy(&buffer[..len]);
+    Some(path.strip_prefix(r"\\?\").map_or_else(|| path.clone(), str::to_string))
+}
+
+// ============================================================================
+// PRIORITY
+// ============================================================================
+
+/// Priority classes from lowest to highest with the nice value each stands for
+///
+/// Realtime is never set: it can starve the rest of the system.
+const PRIORITY_CLASSES: [(u32, i32); 5] = [
+    (IDLE_PRIORITY_CLASS, 19),
+    (BELOW_NORMAL_PRIORITY_CLASS, 10),
+    (NORMAL_PRIORITY_CLASS, 0),
+    (ABOVE_NORMAL_PRIORITY_CLASS, -5),
+    (HIGH_PRIORITY_CLASS, -10),
+];
+
+/// Priority class expressed as a nice value (realtime reads as -20)
+pub(crate) fn nice(pid: u32) -> Option<i32> {
+    let handle = OwnedHandle::open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION)?;
+    // SAFETY: the handle is valid for its lifetime.
+    let class = unsafe { GetPriorityClass(handle.0) };
+    if class == REALTIME_PRIORITY_CLASS {
+        return Some(super::MIN_NICE);
+    }
+    PRIORITY_CLASSES.iter().find(|(known, _)| *known == class).map(|(_, nice)| *nice)
+}
+
+/// Priority class closest to a nice value (also a process creation flag)
+pub(crate) fn priority_class(nice: i32) -> u32 {
+    match nice {
+        15.. => IDLE_PRIORITY_CLASS,
+        1..=14 => BELOW_NORMAL_PRIORITY_CLASS,
+        0 => NORMAL_PRIORITY_CLASS,
+        -9..=-1 => ABOVE_NORMAL_PRIORITY_CLASS,
+        _ => HIGH_PRIORITY_CLASS,
+    }
+}
+
+/// Name of the priority class a nice value from `nice` stands for
+pub(crate) fn priority_class_name(nice: i32) -> Option<&'static str> {
+    if nice == super::MIN_NICE {
+        return Some("realtime");
+    }
+    Some(match priority_class(nice) {
+        IDLE_PRIORITY_CLASS => "idle",
+        BELOW_NORMAL_PRIORITY_CLASS => "below_normal",
+        NORMAL_PRIORITY_CLASS => "normal",
+        ABOVE_NORMAL_PRIORITY_CLASS => "above_normal",
+        _ => "high",
+    })
+}
+
+/// Set the priority class closest to a nice value
+pub(crate) fn set_nice(pid: u32, nice: i32) -> std::io::Result<()> {
+    let class = priority_class(nice);
+    let handle = OwnedHandle::open_process(pid, PROCESS_SET_INFORMATION).ok_or_else(std::io::Error::last_os_error)?;
+    // SAFETY: the handle is valid for its lifetime.
+    if unsafe { SetPriorityClass(handle.0, class) } == 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// CPUs of its processor group that `pid` may run on
+pub(crate) fn affinity(pid: u32) -> Option<Vec<usize>> {
+    let handle = OwnedHandle::open_process(pid, PROCESS_QUERY_LIMITED_INFORMATION)?;
+    let mut mask: usize = 0;
+    let mut system: usize = 0;
+    // SAFETY: the handle is valid and both out-pointers are locals.
+    if unsafe { GetProcessAffinityMask(handle.0, &mut mask, &mut system) } == 0 {
+        return None;
+    }
+    Some((0..usize::BITS as usize).filter(|cpu| mask & (1 << cpu) != 0).collect())
+}
+
+/// Restrict `pid` to `cpus` of its processor group; running threads move at once
+pub(crate) fn set_affinity(pid: u32, cpus: &[usize]) -> std::io::Result<()> {
+    let mut mask: usize = 0;
+    for &cpu in cpus {
+        if cpu >= usize::BITS as usize {
+            return Err(std::io::Error::from(std::io::ErrorKind::InvalidInput));
+        }
+        mask |= 1 << cpu;
+    }
+    let handle = OwnedHandle::open_process(pid, PROCESS_SET_INFORMATION).ok_or_else(std::io::Error::last_os_error)?;
+    // SAFETY: the handle is valid for its lifetime.
+    if unsafe { SetProcessAffinityMask(handle.0, mask) } == 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Pin a running process to `cpus`; the process mask already covers every thread
+pub(crate) fn set_process_affinity(pid: u32, cpus: &[usize]) -> std::io::Result<()> {
+    set_affinity(pid, cpus)
+}
+
+// ============================================================================
+// CHILD EXIT
+// ============================================================================
+
+/// Block until `child` has exited
+pub(crate) fn await_exit(child: &std::process::Child) -> std::io::Result<()> {
+    use std::os::windows::io::AsRawHandle;
+
+    // SAFETY: the handle stays open while `child` is borrowed.
+    if unsafe { WaitForSingleObject(child.as_raw_handle() as HANDLE, INFINITE) } == WAIT_OBJECT_0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+/// Exit code, CPU times and peak working set of an exited `child`
+pub(crate) fn reap_exited(
+    child: &std::process::Child,
+) -> std::io::Result<(std::process::ExitStatus, crate::schema::ResourceUsage)> {
+    use std::os::windows::io::AsRawHandle;
+    use std::os::windows::process::ExitStatusExt;
+
+    let handle = child.as_raw_handle() as HANDLE;
+    let mut code = 0u32;
+    // SAFETY: code is a valid out-pointer and the handle stays open while `child` is borrowed.
+    if unsafe { GetExitCodeProcess(handle, &mut code) } == 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    let zero = FILETIME {
+        dwLowDateTime: 0,
+        dwHighDateTime: 0,
+    };
+    let (mut created, mut exited, mut kernel, mut user) = (zero, zero, zero, zero);
+    // SAFETY: all four are valid out-pointers.
+    let timed = unsafe { GetProcessTimes(handle, &mut created, &mut exited, &mut kernel, &mut user) } != 0;
+    let secs = |time: FILETIME| {
+        ((u64::from(time.dwHighDateTime) << 32) | u64::from(time.dwLowDateTime)) as f64 / FILETIME_TICKS as f64
+    };
+    // SAFETY: counters is plain old data sized correctly via cb.
+    let mut counters: PROCESS_MEMORY_COUNTERS = unsafe { std::mem::zeroed() };
+    counters.cb = std::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32;
+    // SAFETY: as above; the counters of an exited process stay readable while its handle is open.
+    let measured = unsafe { GetProcessMemoryInfo(handle, &mut counters, counters.cb) } != 0;
+    Ok((
+        std::process::ExitStatus::from_raw(code),
+        crate::schema::ResourceUsage {
+            max_rss_bytes: if measured { counters.PeakWorkingSetSize as u64 } else { 0 },
+            user_cpu_secs: if timed { secs(user) } else { 0.0 },
+            system_cpu_secs: if timed { secs(kernel) } else { 0.0 },
+            page_faults: if measured { u64::from(counters.PageFaultCount) } else { 0 },
+            major_faults: None,
+        },
+    ))
+}
+
+/// Whether `dir` is under `C:\Users\Public`, which every user may write to
+///
+/// Checking the real DACL would need the full security descriptor API; the
+/// public profile is where the default ACLs grant everyone write access.
+pub(crate) fn world_writable_dir(dir: &Path) -> bool {
+    dir.to_string_lossy().to_lowercase().contains("\\users\\public")
+}
+
+// ============================================================================
+// WSL
+// ============================================================================
+
+/// Longest a `wsl.exe` call may take before it is killed
+const WSL_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Stdout of `wsl.exe args`, `None` if it is missing, fails or hangs
+fn wsl(args: &[&str]) -> Option<String> {
+    // Newer releases print UTF-8 instead of UTF-16 for their own output with this set
+    let output = super::run_captured(std::process::Command::new("wsl.exe").args(args).env("WSL_UTF8", "1"), WSL_TIMEOUT)?;
+    if !output.status.success() {
+        return None;
+    }
+    let bytes = output.stdout;
+    // Older releases ignore WSL_UTF8 and print UTF-16LE
+    if bytes.len() >= 2 && bytes.iter().skip(1).step_by(2).all(|byte| *byte == 0) {
+        let units: Vec<u16> = bytes.as_chunks::<2>().0.iter().map(|pair| u16::from_le_bytes(*pair)).collect();
+        return Some(String::from_utf16_lossy(&units).trim_start_matches('\u{feff}').to_string());
+    }
+    Some(String::from_utf8_lossy(&bytes).to_string())
+}
+
+/// Names of the running WSL distros (empty without WSL)
+pub(crate) fn wsl_distros() -> Vec<String> {
+    wsl(&["--list", "--running", "--quiet"])
+        .map(|list| {
+            list.lines()
+                .map(|line| line.trim().to_string())
+                .filter(|line| !line.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Process table of `distro` from `ps` run inside it
+pub(crate) fn wsl_processes(distro: &str) -> Option<Vec<WslProcess>> {
+    let table = wsl(&["-d", distro, "-e", "ps", "-eo", "pid=,pcpu=,rss=,vsz=,comm="])?;
+    Some(
+        table
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                let pid = fields.next()?.parse().ok()?;
+                let cpu_percent = fields.next()?.parse().ok()?;
+                let rss_kib: u64 = fields.next()?.parse().ok()?;
+                let vsz_kib: u64 = fields.next()?.parse().ok()?;
+                Some(WslProcess {
+                    pid,
+                    name: fields.collect::<Vec<_>>().join(" "),
+                    cpu_percent,
+                    rss_bytes: rss_kib * 1024,
+                    virtual_bytes: vsz_kib * 1024,
+                })
+            })
+            .collect(),
+    )
+}
+
+// ============================================================================
+// CODE SIGNING
+// ============================================================================
+
+/// Longest the PowerShell check may take; a cold start alone can take seconds
+const SIGNATURE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(20);
+
+/// Reads the path from `KODEGEN_SIGNATURE_PATH` so it needs no quoting; the
+/// chain is built offline so a revocation lookup cannot stall it
+const AUTHENTICODE_SCRIPT: &str = "\
+    $s = Get-AuthenticodeSignature -LiteralPath $env:KODEGEN_SIGNATURE_PATH; \
+    $chain = @(); \
+    if ($s.SignerCertificate) { \
+        $c = New-Object System.Security.Cryptography.X509Certificates.X509Chain; \
+        $c.ChainPolicy.RevocationMode = 'NoCheck'; \
+        [void]$c.Build($s.SignerCertificate); \
+        $chain = @($c.ChainElements | ForEach-Object { $_.Certificate.Subject }) \
+    }; \
+    [pscustomobject]@{ Status = [string]$s.Status; Message = $s.StatusMessage; \
+        Signer = $s.SignerCertificate.Subject; Type = [string]$s.SignatureType; \
+        OsBinary = [bool]$s.IsOSBinary; Chain = $chain } | ConvertTo-Json -Compress";
+
+/// `CN=` value of an X.500 subject, or the whole subject without one
+fn common_name(subject: &str) -> String {
+    subject
+        .split(", ")
+        .find_map(|part| part.strip_prefix("CN="))
+        .map_or_else(|| subject.to_string(), |name| name.trim_matches('"').to_string())
+}
+
+/// Authenticode check through PowerShell's `Get-AuthenticodeSignature`
+///
+/// Covers both embedded signatures and the catalog files most OS binaries
+/// are signed through.
+pub(crate) fn code_signature(exe: &Path) -> Option<CodeSignature> {
+    let output = super::run_captured(
+        std::process::Command::new("powershell.exe")
+            .args(["-NoProfile", "-NonInteractive", "-Command", AUTHENTICODE_SCRIPT])
+            .env("KODEGEN_SIGNATURE_PATH", exe),
+        SIGNATURE_TIMEOUT,
+    )?;
+    if !output.status.success() {
+        return None;
+    }
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).ok()?;
+    let text = |key: &str| report.get(key).and_then(serde_json::Value::as_str).filter(|value| !value.is_empty());
+    let status = text("Status")?;
+    let state = match status {
+        "Valid" => SignatureState::Valid,
+        "NotSigned" => SignatureState::Unsigned,
+        _ => SignatureState::Invalid,
+    };
+    // ConvertTo-Json turns a one-element array into a bare string on older PowerShell
+    let authorities: Vec<String> = match report.get("Chain") {
+        Some(serde_json::Value::Array(chain)) => chain.iter().filter_map(serde_json::Value::as_str).map(common_name).collect(),
+        Some(serde_json::Value::String(subject)) => vec![common_name(subject)],
+        _ => Vec::new(),
+    };
+
+    let mut notes = Vec::new();
+    if text("Type") == Some("Catalog") {
+        notes.push("Signed through a Windows catalog file rather than an embedded signature".to_string());
+    }
+    if status == "HashMismatch" {
+        notes.push("The file was modified after it was signed".to_string());
+    }
+    Some(CodeSignature {
+        state,
+        detail: (state != SignatureState::Valid).then(|| status.to_string()),
+        signer: text("Signer").map(common_name),
+        authorities,
+        os_binary: report.get("OsBinary").and_then(serde_json::Value::as_bool),
+        notes,
+        ..Default::default()
+    })
+}
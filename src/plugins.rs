@@ -0,0 +1,105 @@
+//! Embedder-supplied `process_list` extensions
+//!
+//! Crates that embed the server (via [`crate::start_server_with_plugins`])
+//! can attach organisation-specific metadata without forking: enrichers add
+//! fields under `extra` for every returned process, and named filters become
+//! selectable through the `named_filters` argument.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::schema::ProcessInfo;
+
+type Enrich = dyn Fn(&ProcessInfo) -> Option<Value> + Send + Sync;
+type Predicate = dyn Fn(&ProcessInfo) -> bool + Send + Sync;
+
+#[derive(Clone)]
+struct NamedFilter {
+    description: String,
+    predicate: Arc<Predicate>,
+}
+
+/// Registered enrichers and named filters
+///
+/// Both see the process after name, CPU, memory and tags are known but
+/// before optional columns (cmdline, paths, ...) are loaded.
+#[derive(Clone, Default)]
+pub struct Plugins {
+    enrichers: BTreeMap<String, Arc<Enrich>>,
+    filters: BTreeMap<String, NamedFilter>,
+}
+
+impl Plugins {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `extra.<key>` to each returned process for which `enrich` returns a value
+    ///
+    /// Runs once per returned row, after `limit`, so it may do a lookup per call.
+    #[must_use]
+    pub fn with_enricher<F>(mut self, key: &str, enrich: F) -> Self
+    where
+        F: Fn(&ProcessInfo) -> Option<Value> + Send + Sync + 'static,
+    {
+        self.enrichers.insert(key.to_string(), Arc::new(enrich));
+        self
+    }
+
+    /// Make `predicate` selectable as `named_filters: ["<name>"]`
+    ///
+    /// Runs for every process before sorting and `limit`, so keep it cheap.
+    #[must_use]
+    pub fn with_filter<F>(mut self, name: &str, description: &str, predicate: F) -> Self
+    where
+        F: Fn(&ProcessInfo) -> bool + Send + Sync + 'static,
+    {
+        self.filters.insert(
+            name.to_string(),
+            NamedFilter {
+                description: description.to_string(),
+                predicate: Arc::new(predicate),
+            },
+        );
+        self
+    }
+
+    /// Registered filter names with their descriptions, sorted by name
+    #[must_use]
+    pub fn filters(&self) -> Vec<(&str, &str)> {
+        self.filters
+            .iter()
+            .map(|(name, filter)| (name.as_str(), filter.description.as_str()))
+            .collect()
+    }
+
+    /// Resolve filter names into one predicate requiring all of them
+    pub(crate) fn predicate(&self, names: &[String]) -> Result<impl Fn(&ProcessInfo) -> bool + use<>, String> {
+        let predicates = names
+            .iter()
+            .map(|name| {
+                self.filters.get(name).map(|filter| filter.predicate.clone()).ok_or_else(|| {
+                    let known: Vec<&str> = self.filters.keys().map(String::as_str).collect();
+                    if known.is_empty() {
+                        format!("Unknown filter {name:?}: this server has no named filters")
+                    } else {
+                        format!("Unknown filter {name:?} (available: {})", known.join(", "))
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(move |process: &ProcessInfo| predicates.iter().all(|predicate| predicate(process)))
+    }
+
+    /// Fill `process.extra` from every enricher
+    pub(crate) fn enrich(&self, process: &mut ProcessInfo) {
+        for (key, enrich) in &self.enrichers {
+            if let Some(value) = enrich(process) {
+                process.extra.insert(key.clone(), value);
+            }
+        }
+    }
+}
@@ -0,0 +1,233 @@
+//! Kubernetes pods of node processes
+//!
+//! The kubelet puts every pod in a cgroup named after the pod UID
+//! (`/kubepods/burstable/pod<uid>/<container>` with cgroupfs,
+//! `kubepods-burstable-pod<uid>.slice/cri-containerd-<container>.scope` with
+//! the systemd driver), so the UID and the container ID of a process are read
+//! from `/proc/<pid>/cgroup`. Namespaces, pod names and container names are
+//! what `kubectl` shows; they come from the kubelet API and the pod log
+//! directories, cached for [`KubernetesConfig::refresh_secs`].
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+
+use crate::config::KubernetesConfig;
+use crate::platform;
+use crate::schema::PodInfo;
+
+/// Pod log directories, named `<namespace>_<pod>_<uid>`
+const POD_LOGS: &str = "/var/log/pods";
+
+/// Container log links, named `<pod>_<namespace>_<container>-<container id>.log`
+const CONTAINER_LOGS: &str = "/var/log/containers";
+
+/// Longest a kubelet request may take
+const KUBELET_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// What is known about the node's pods
+#[derive(Default)]
+struct Known {
+    fetched: Option<Instant>,
+    /// Namespace and name by pod UID
+    pods: HashMap<String, (String, String)>,
+    /// Container name by container ID
+    containers: HashMap<String, String>,
+}
+
+pub struct PodIndex {
+    config: KubernetesConfig,
+    known: Mutex<Known>,
+    /// One refresh at a time, so concurrent listings share a kubelet request
+    refreshing: tokio::sync::Mutex<()>,
+    /// The kubelet failure was logged once already
+    warned: AtomicBool,
+}
+
+impl Default for PodIndex {
+    fn default() -> Self {
+        Self::new(&KubernetesConfig::default())
+    }
+}
+
+impl PodIndex {
+    #[must_use]
+    pub fn new(config: &KubernetesConfig) -> Self {
+        Self {
+            config: config.clone(),
+            known: Mutex::new(Known::default()),
+            refreshing: tokio::sync::Mutex::new(()),
+            warned: AtomicBool::new(false),
+        }
+    }
+
+    /// Fetch pod and container names unless the cached ones are recent enough
+    pub async fn refresh(&self) {
+        let _refreshing = self.refreshing.lock().await;
+        let max_age = Duration::from_secs(self.config.refresh_secs);
+        if self.lock().fetched.is_some_and(|fetched| fetched.elapsed() < max_age) {
+            return;
+        }
+        let mut known = tokio::task::spawn_blocking(from_logs).await.unwrap_or_default();
+        // Logs are only read locally; a token says the server runs as a pod whose account may ask the kubelet
+        if let Some(url) = &self.config.kubelet_url
+            && self.config.token_file.exists()
+        {
+            match self.kubelet(url).await {
+                Ok(pods) => {
+                    known.pods.extend(pods.pods);
+                    known.containers.extend(pods.containers);
+                }
+                Err(e) if !self.warned.swap(true, Ordering::Relaxed) => {
+                    log::warn!("Kubelet pod list unavailable, naming pods from {POD_LOGS}: {e}");
+                }
+                Err(e) => log::debug!("Kubelet pod list unavailable: {e}"),
+            }
+        }
+        known.fetched = Some(Instant::now());
+        *self.lock() = known;
+    }
+
+    /// Pod of `pid`, from its cgroup and the names fetched by the last [`Self::refresh`]
+    ///
+    /// `None` for processes outside any pod. Reads `/proc`, so it blocks briefly.
+    pub fn pod(&self, pid: u32) -> Option<PodInfo> {
+        let cgroup = platform::cgroup_paths(pid)?;
+        let (uid, qos_class) = cgroup.iter().find_map(|line| pod_cgroup(line))?;
+        let container_id = platform::container_id(pid);
+        let known = self.lock();
+        let (namespace, name) = known.pods.get(&uid).cloned().unzip();
+        Some(PodInfo {
+            container: container_id.as_ref().and_then(|id| known.containers.get(id)).cloned(),
+            uid,
+            namespace,
+            name,
+            container_id,
+            qos_class: Some(qos_class.to_string()),
+        })
+    }
+
+    async fn kubelet(&self, url: &str) -> Result<Known, String> {
+        let token = std::fs::read_to_string(&self.config.token_file)
+            .map_err(|e| format!("cannot read {}: {e}", self.config.token_file.display()))?;
+        let mut client = reqwest::Client::builder().timeout(KUBELET_TIMEOUT);
+        if self.config.insecure_tls {
+            client = client.danger_accept_invalid_certs(true);
+        } else if let Ok(pem) = std::fs::read(&self.config.ca_file) {
+            let ca = reqwest::Certificate::from_pem(&pem).map_err(|e| format!("invalid CA bundle {}: {e}", self.config.ca_file.display()))?;
+            client = client.add_root_certificate(ca);
+        }
+        let client = client.build().map_err(|e| e.to_string())?;
+        let list: Value = client
+            .get(format!("{}/pods", url.trim_end_matches('/')))
+            .bearer_auth(token.trim())
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| e.to_string())?
+            .json()
+            .await
+            .map_err(|e| format!("unexpected pod list: {e}"))?;
+        Ok(from_pod_list(&list))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Known> {
+        self.known.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Whether `pod` is the one `wanted` names: `namespace/name`, a bare name or the UID
+#[must_use]
+pub(crate) fn pod_matches(pod: &PodInfo, wanted: &str) -> bool {
+    if pod.uid == wanted {
+        return true;
+    }
+    match wanted.split_once('/') {
+        Some((namespace, name)) => pod.namespace.as_deref() == Some(namespace) && pod.name.as_deref() == Some(name),
+        None => pod.name.as_deref() == Some(wanted),
+    }
+}
+
+/// Pod UID and QoS class in one `/proc/<pid>/cgroup` line
+fn pod_cgroup(line: &str) -> Option<(String, &'static str)> {
+    let path = line.splitn(3, ':').nth(2)?;
+    if !path.contains("kubepods") {
+        return None;
+    }
+    let uid = path.split('/').find_map(|segment| {
+        let segment = segment.strip_suffix(".slice").unwrap_or(segment);
+        // `pod<uid>` with cgroupfs; `kubepods-<qos>-pod<uid>` with systemd, which writes dashes as underscores
+        let uid = segment
+            .rsplit_once("-pod")
+            .map(|(_, uid)| uid)
+            .or_else(|| segment.strip_prefix("pod"))?
+            .replace('_', "-");
+        (uid.len() == 36 && uid.bytes().all(|byte| byte.is_ascii_hexdigit() || byte == b'-')).then_some(uid)
+    })?;
+    let qos_class = if path.contains("besteffort") {
+        "besteffort"
+    } else if path.contains("burstable") {
+        "burstable"
+    } else {
+        "guaranteed"
+    };
+    Some((uid, qos_class))
+}
+
+/// Names from the kubelet's `/pods` (a `PodList`)
+fn from_pod_list(list: &Value) -> Known {
+    let mut known = Known::default();
+    for pod in list["items"].as_array().into_iter().flatten() {
+        let metadata = &pod["metadata"];
+        let (Some(uid), Some(namespace), Some(name)) =
+            (metadata["uid"].as_str(), metadata["namespace"].as_str(), metadata["name"].as_str())
+        else {
+            continue;
+        };
+        known.pods.insert(uid.to_string(), (namespace.to_string(), name.to_string()));
+        let status = &pod["status"];
+        let statuses = ["containerStatuses", "initContainerStatuses", "ephemeralContainerStatuses"]
+            .into_iter()
+            .filter_map(|key| status[key].as_array())
+            .flatten();
+        for container in statuses {
+            // e.g. "containerd://<id>"
+            let id = container["containerID"].as_str().and_then(|id| id.split_once("://")).map(|(_, id)| id);
+            if let (Some(id), Some(name)) = (id, container["name"].as_str()) {
+                known.containers.insert(id.to_string(), name.to_string());
+            }
+        }
+    }
+    known
+}
+
+/// Names from the pod log directories every kubelet writes
+fn from_logs() -> Known {
+    let mut known = Known::default();
+    for name in entries(Path::new(POD_LOGS)) {
+        let mut parts = name.splitn(3, '_');
+        if let (Some(namespace), Some(pod), Some(uid)) = (parts.next(), parts.next(), parts.next()) {
+            known.pods.insert(uid.to_string(), (namespace.to_string(), pod.to_string()));
+        }
+    }
+    for name in entries(Path::new(CONTAINER_LOGS)) {
+        // Pod, namespace and container names cannot contain '_'
+        let Some((rest, id)) = name.strip_suffix(".log").and_then(|name| name.rsplit_once('-')) else {
+            continue;
+        };
+        if let Some(container) = rest.splitn(3, '_').nth(2) {
+            known.containers.insert(id.to_string(), container.to_string());
+        }
+    }
+    known
+}
+
+fn entries(dir: &Path) -> Vec<String> {
+    std::fs::read_dir(dir)
+        .map(|entries| entries.flatten().map(|entry| entry.file_name().to_string_lossy().to_string()).collect())
+        .unwrap_or_default()
+}
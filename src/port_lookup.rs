@@ -0,0 +1,168 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, truncate};
+use crate::identity;
+use crate::platform;
+use crate::schema::{
+    PORT_LOOKUP, PortLookupArgs, PortLookupOutput, PortLookupPrompts, PortOwner, SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct PortLookupTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl PortLookupTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for PortLookupTool {
+    type Args = PortLookupArgs;
+    type Prompts = PortLookupPrompts;
+
+    fn name() -> &'static str {
+        PORT_LOOKUP
+    }
+
+    fn description() -> &'static str {
+        "Answer \"port 8080 is already in use, by whom?\": returns the processes with a socket \
+         on a local port, with pid, proc_id, name, command line (secrets masked) and the \
+         socket's address and TCP state, listeners first. protocol is \"tcp\" (default), \
+         \"udp\" or \"any\"; IPv4 and IPv6 are both covered. in_use is true whenever something \
+         is bound to the port, and unknown_owner counts sockets whose process the server may \
+         not inspect (another user's, without root). On Linux ports bound inside another \
+         network namespace, such as a container, are not seen."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<PortLookupOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let port = args.port;
+        let protocol = args.protocol;
+        if port == 0 {
+            return Err(McpError::InvalidArguments("Port 0 is not a real port; pass 1-65535".to_string()));
+        }
+        let redact = self.state.redactor.active(false)?;
+
+        let state = self.state.clone();
+        let (owners, unknown_owner) = tokio::task::spawn_blocking(move || {
+            let sockets = platform::port_sockets(port)
+                .ok_or_else(|| McpError::Other(anyhow::anyhow!("Cannot read the socket tables on this platform")))?;
+            let mut owners = Vec::new();
+            let mut unknown_owner = 0;
+            for found in sockets.into_iter().filter(|found| protocol.matches(&found.socket.protocol)) {
+                // The holder can exit between the socket scan and the lookup
+                let Some(process) = found.pid.and_then(|pid| state.provider.lookup(pid, true)) else {
+                    unknown_owner += 1;
+                    continue;
+                };
+                let mut cmdline = process.cmdline;
+                if redact {
+                    state.redactor.cmdline(&mut cmdline);
+                }
+                owners.push(PortOwner {
+                    pid: process.pid,
+                    proc_id: identity::proc_id(process.pid, process.start_time),
+                    name: process.name,
+                    cmdline,
+                    user: process.user,
+                    protocol: found.socket.protocol,
+                    local: found.socket.local,
+                    remote: found.socket.remote,
+                    state: found.socket.state,
+                });
+            }
+            owners.sort_by_key(|owner| (owner.state.as_deref() != Some("LISTEN"), owner.pid));
+            Ok::<_, McpError>((owners, unknown_owner))
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to look up port {port}: {e}")))??;
+
+        let in_use = !owners.is_empty() || unknown_owner > 0;
+        let mut pids: Vec<u32> = owners.iter().map(|owner| owner.pid).collect();
+        pids.sort_unstable();
+        pids.dedup();
+        let mut summary = match owners.first() {
+            Some(owner) => {
+                let mut headline = format!(
+                    "{} {port}/{} is held by PID {} ({})",
+                    style.paint(Color::Red, "Port In Use:"),
+                    protocol.name(),
+                    owner.pid,
+                    owner.name
+                );
+                if pids.len() > 1 {
+                    headline.push_str(&format!(" and {} other process(es)", pids.len() - 1));
+                }
+                headline
+            }
+            None if in_use => format!(
+                "{} {port}/{} is bound by a process the server may not inspect",
+                style.paint(Color::Red, "Port In Use:"),
+                protocol.name()
+            ),
+            None => format!("{} {port}/{} is not in use", style.paint(Color::Green, "Port Free:"), protocol.name()),
+        };
+        if verbosity != SummaryVerbosity::Quiet {
+            for owner in &owners {
+                summary.push_str(&format!(
+                    "\n{} PID {} ({}){}{} {}",
+                    style.bullet(),
+                    owner.pid,
+                    owner.name,
+                    style.sep(),
+                    owner.protocol,
+                    owner.local
+                ));
+                if let Some(remote) = owner.remote.as_deref().filter(|remote| !remote.ends_with(":0")) {
+                    summary.push_str(&format!("{}{remote}", style.arrow()));
+                }
+                if let Some(state) = &owner.state {
+                    summary.push_str(&format!(" {state}"));
+                }
+                if verbosity == SummaryVerbosity::Detailed && !owner.cmdline.is_empty() {
+                    summary.push_str(&format!("\n    {}", truncate(&owner.cmdline.join(" "), 120)));
+                }
+            }
+            if unknown_owner > 0 && !owners.is_empty() {
+                summary.push_str(&format!("\n{} {unknown_owner} socket(s) with an unknown owner", style.bullet()));
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            PortLookupOutput {
+                success: true,
+                port,
+                protocol,
+                in_use,
+                owners,
+                unknown_owner,
+            },
+        ))
+    }
+}
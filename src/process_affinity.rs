@@ -0,0 +1,168 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::identity;
+use crate::platform;
+use crate::protection::protected_reason;
+use crate::schema::{
+    PROCESS_AFFINITY, ProcessAffinityArgs, ProcessAffinityOutput, ProcessAffinityPrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::spawn::{check_cpus, cpu_limit};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessAffinityTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessAffinityTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+/// CPU indices as compact ranges, e.g. `0-3,6`
+fn cpu_ranges(cpus: &[usize]) -> String {
+    let mut ranges: Vec<String> = Vec::new();
+    let mut iter = cpus.iter().copied().peekable();
+    while let Some(first) = iter.next() {
+        let mut last = first;
+        while iter.peek() == Some(&(last + 1)) {
+            last += 1;
+            iter.next();
+        }
+        ranges.push(if first == last { first.to_string() } else { format!("{first}-{last}") });
+    }
+    ranges.join(",")
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessAffinityTool {
+    type Args = ProcessAffinityArgs;
+    type Prompts = ProcessAffinityPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_AFFINITY
+    }
+
+    fn description() -> &'static str {
+        "Read the CPUs one process may run on, or pin it by passing cpus (CPU indices such as \
+         [2, 3]), e.g. to keep a noisy workload away from a latency-sensitive service while \
+         benchmarking. Every current thread is moved and new threads inherit the mask. Pinning \
+         another user's process needs root or CAP_SYS_NICE (an elevated server on Windows); \
+         protected processes are refused. Linux and Windows only: macOS has no process \
+         affinity, and on Windows only the first processor group (64 CPUs) is covered. Use \
+         process_spawn with cpus to start a process already pinned."
+    }
+
+    fn read_only() -> bool {
+        false // Changes the affinity mask when cpus is given
+    }
+
+    fn idempotent() -> bool {
+        true // Pinning to the same CPUs again changes nothing
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessAffinityOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let wanted = match args.cpus {
+            Some(mut cpus) => {
+                if cpus.is_empty() {
+                    return Err(McpError::InvalidArguments(
+                        "cpus must name at least one CPU; omit it to only read the mask".to_string(),
+                    ));
+                }
+                check_cpus(&cpus).map_err(McpError::InvalidArguments)?;
+                cpus.sort_unstable();
+                cpus.dedup();
+                Some(cpus)
+            }
+            None => None,
+        };
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+
+        let state = self.state.clone();
+        let output = tokio::task::spawn_blocking(move || {
+            let process = state
+                .provider
+                .lookup(pid, false)
+                .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?;
+            let before = platform::affinity(pid);
+            if let Some(cpus) = &wanted {
+                if let Some(reason) = protected_reason(pid, &process.name, process.parent) {
+                    return Err(McpError::PermissionDenied(format!(
+                        "Refusing to change the CPU affinity of process {pid} ({}): {reason}",
+                        process.name
+                    )));
+                }
+                platform::set_process_affinity(pid, cpus).map_err(|e| match e.kind() {
+                    std::io::ErrorKind::PermissionDenied => McpError::PermissionDenied(format!(
+                        "Cannot pin process {pid}: another user's process needs root or CAP_SYS_NICE \
+                         (an elevated server on Windows)"
+                    )),
+                    // The kernel rejects masks with no CPU the process's cpuset allows
+                    std::io::ErrorKind::InvalidInput => McpError::InvalidArguments(format!(
+                        "None of CPUs {} may be used by process {pid} (outside its cpuset or offline?)",
+                        cpu_ranges(cpus)
+                    )),
+                    std::io::ErrorKind::NotFound => McpError::ResourceNotFound(format!("Process {pid} exited")),
+                    std::io::ErrorKind::Unsupported => McpError::Other(anyhow::anyhow!(
+                        "Setting CPU affinity is not supported on this platform"
+                    )),
+                    _ => McpError::Other(anyhow::anyhow!("Failed to pin process {pid}: {e}")),
+                })?;
+            }
+            let cpus = if wanted.is_some() { platform::affinity(pid) } else { before.clone() };
+            Ok(ProcessAffinityOutput {
+                success: true,
+                pid,
+                name: process.name,
+                before,
+                cpus,
+                cpu_count: cpu_limit(),
+                changed: wanted.is_some(),
+            })
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Affinity task for process {pid} failed: {e}")))??;
+
+        let current = output.cpus.as_deref().map_or_else(|| "unknown".to_string(), cpu_ranges);
+        let mut summary = if output.changed {
+            format!(
+                "{} PID {pid} ({}) CPUs {}{}{current}",
+                style.paint(Color::Green, "Affinity Set:"),
+                output.name,
+                output.before.as_deref().map_or_else(|| "?".to_string(), cpu_ranges),
+                style.arrow()
+            )
+        } else {
+            format!("{} PID {pid} ({}) CPUs {current}", style.paint(Color::Cyan, "Affinity:"), output.name)
+        };
+        if verbosity != SummaryVerbosity::Quiet
+            && let Some(cpus) = &output.cpus
+        {
+            summary.push_str(&format!(
+                "\n{} {} of {} CPU(s) allowed",
+                style.bullet(),
+                cpus.len(),
+                output.cpu_count
+            ));
+        }
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
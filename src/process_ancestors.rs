@@ -0,0 +1,140 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::identity;
+use crate::schema::{
+    PROCESS_ANCESTORS, ProcessAncestor, ProcessAncestorsArgs, ProcessAncestorsOutput, ProcessAncestorsPrompts,
+    SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+use crate::tree;
+
+/// Ancestors followed before giving up on a (corrupt or cyclic) parent chain
+const MAX_DEPTH: usize = 64;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessAncestorsTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessAncestorsTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessAncestorsTool {
+    type Args = ProcessAncestorsArgs;
+    type Prompts = ProcessAncestorsPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_ANCESTORS
+    }
+
+    fn description() -> &'static str {
+        "Answer \"what launched this process?\": walks parent PIDs from a process up to init \
+         (PID 1) and returns each ancestor's pid, name and start time, nearest parent first. \
+         broken_at names the parent PID where the chain ends early because that process has \
+         exited (a parent younger than its child counts as exited, its PID was reused). Read \
+         from one snapshot, so it is cheap; process_provenance adds users, the terminal, the \
+         systemd unit and the launch through this server."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessAncestorsOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+
+        let state = self.state.clone();
+        let output = tokio::task::spawn_blocking(move || {
+            let processes = state.provider.enumerate();
+            let process = processes
+                .iter()
+                .find(|process| process.pid == pid)
+                .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?;
+            let (chain, broken_at) = tree::ancestors(&processes, process, MAX_DEPTH);
+            let truncated = broken_at.is_none() && chain.len() == MAX_DEPTH;
+            Ok::<_, McpError>(ProcessAncestorsOutput {
+                success: true,
+                pid,
+                name: process.name.clone(),
+                ancestors: chain
+                    .into_iter()
+                    .map(|ancestor| ProcessAncestor {
+                        pid: ancestor.pid,
+                        name: ancestor.name.clone(),
+                        user: None,
+                        start_time: ancestor.start_time,
+                    })
+                    .collect(),
+                broken_at,
+                truncated,
+            })
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to walk parents of process {pid}: {e}")))??;
+
+        let mut summary = format!(
+            "{} PID {pid} ({}) has {} ancestor(s)",
+            style.paint(Color::Cyan, "Ancestors:"),
+            output.name,
+            output.ancestors.len()
+        );
+        if let Some(parent) = output.broken_at {
+            summary.push_str(&format!(
+                "{}{}",
+                style.sep(),
+                style.paint(Color::Red, &format!("parent {parent} exited"))
+            ));
+        }
+        if verbosity != SummaryVerbosity::Quiet {
+            let chain: Vec<String> = std::iter::once(format!("{} ({pid})", output.name))
+                .chain(output.ancestors.iter().map(|ancestor| format!("{} ({})", ancestor.name, ancestor.pid)))
+                .collect();
+            summary.push_str(&format!("\n{} Chain: {}", style.bullet(), chain.join(style.back_arrow())));
+            if let Some(parent) = output.broken_at {
+                summary.push_str(&format!("{}PID {parent} (exited)", style.back_arrow()));
+            } else if output.truncated {
+                summary.push_str(&format!("{}{}", style.back_arrow(), style.ellipsis()));
+            }
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            for ancestor in &output.ancestors {
+                let started = i64::try_from(ancestor.start_time)
+                    .ok()
+                    .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+                    .map_or_else(|| ancestor.start_time.to_string(), |started| started.to_rfc3339());
+                summary.push_str(&format!(
+                    "\n{} PID {} ({}){}started {started}",
+                    style.bullet(),
+                    ancestor.pid,
+                    ancestor.name,
+                    style.sep()
+                ));
+            }
+        }
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
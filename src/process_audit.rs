@@ -0,0 +1,282 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, DETAIL_ROWS};
+use crate::identity;
+use crate::platform;
+use crate::provider::ProcessEntry;
+use crate::schema::{
+    AuditCheck, AuditFinding, AuditFlag, PROCESS_AUDIT, ProcessAuditArgs, ProcessAuditOutput, ProcessAuditPrompts,
+    ProcessKind, SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+/// Where malware likes to drop binaries: writable by everyone and rarely looked at
+const TEMP_DIRS: &[&str] = &["/tmp/", "/var/tmp/", "/dev/shm/", "/private/tmp/", "/private/var/tmp/"];
+
+/// Lowercased path fragments of per-user and system temp directories on Windows
+const WINDOWS_TEMP_DIRS: &[&str] = &["\\appdata\\local\\temp\\", "\\windows\\temp\\"];
+
+/// Name prefixes of Linux kernel threads that userspace processes imitate
+const KERNEL_THREAD_NAMES: &[&str] = &["kworker", "ksoftirqd", "kthreadd", "kswapd", "migration/", "rcu_", "watchdog/"];
+
+/// Linux appends this to the exe link of an unlinked binary
+const DELETED_SUFFIX: &str = " (deleted)";
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessAuditTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessAuditTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessAuditTool {
+    type Args = ProcessAuditArgs;
+    type Prompts = ProcessAuditPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_AUDIT
+    }
+
+    fn description() -> &'static str {
+        "Scan running processes for red flags and score them: fileless executables (memfd), \
+         names or argv[0] posing as kernel threads, deleted or replaced executables, binaries \
+         in temp or world-writable directories, wiped or spoofed argv and names that do not \
+         match the executable. Findings come highest score first with every flag explained. \
+         These are heuristics with benign causes too (a package upgrade leaves daemons running \
+         deleted executables); follow up with process_provenance and process_code_signature. \
+         Without privileges, other users' executables are unreadable and counted in unreadable."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        false // Processes come and go between scans
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessAuditOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let state = self.state.clone();
+        let pids = args.pids.clone();
+        let min_score = args.min_score.max(1);
+
+        // One exe link and command line read per process
+        let (scanned, unreadable, mut findings) = tokio::task::spawn_blocking(move || {
+            let mut scanned = 0;
+            let mut unreadable = 0;
+            let mut findings = Vec::new();
+            for process in state.provider.enumerate() {
+                if !pids.is_empty() && !pids.contains(&process.pid) {
+                    continue;
+                }
+                if platform::is_thread(process.pid) || platform::process_kind(process.pid) == Some(ProcessKind::Kernel) {
+                    continue;
+                }
+                scanned += 1;
+                let exe = platform::exe_path(process.pid);
+                if exe.is_none() {
+                    unreadable += 1;
+                }
+                let cmdline = platform::cmdline(process.pid);
+                let finding = audit(&process, exe.as_deref(), cmdline.as_deref());
+                if finding.score >= min_score {
+                    findings.push(finding);
+                }
+            }
+            (scanned, unreadable, findings)
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to audit processes: {e}")))?;
+
+        findings.sort_by(|a, b| b.score.cmp(&a.score).then(a.pid.cmp(&b.pid)));
+        let total_findings = findings.len();
+        if args.limit > 0 {
+            findings.truncate(args.limit);
+        }
+
+        let high = findings.iter().filter(|finding| finding.severity == "high").count();
+        let mut summary = format!(
+            "{} {total_findings} of {scanned} processes flagged",
+            style.paint(Color::Cyan, "Process Audit:")
+        );
+        if high > 0 {
+            summary.push_str(&format!("{}{}", style.sep(), style.paint(Color::Red, &format!("{high} high"))));
+        }
+        if verbosity != SummaryVerbosity::Quiet {
+            let shown = if verbosity == SummaryVerbosity::Detailed { findings.len() } else { DETAIL_ROWS };
+            for finding in findings.iter().take(shown) {
+                let checks: Vec<&str> = finding.flags.iter().map(|flag| flag.check.name()).collect();
+                summary.push_str(&format!(
+                    "\n{} PID {} ({}) score {}{}{}",
+                    style.bullet(),
+                    finding.pid,
+                    finding.name,
+                    finding.score,
+                    style.sep(),
+                    checks.join(", ")
+                ));
+                if verbosity == SummaryVerbosity::Detailed {
+                    for flag in &finding.flags {
+                        summary.push_str(&format!("\n  {} {}", style.bullet(), flag.detail));
+                    }
+                }
+            }
+            if unreadable > 0 {
+                summary.push_str(&format!(
+                    "\n{} {unreadable} executables unreadable (run with more privileges for a full scan)",
+                    style.bullet()
+                ));
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessAuditOutput {
+                success: true,
+                scanned,
+                unreadable,
+                total_findings,
+                findings,
+            },
+        ))
+    }
+}
+
+// ============================================================================
+// HEURISTICS
+// ============================================================================
+
+/// Apply every check to one process
+fn audit(process: &ProcessEntry, exe: Option<&Path>, cmdline: Option<&[String]>) -> AuditFinding {
+    let mut flags = Vec::new();
+    let mut flag = |check: AuditCheck, detail: String| {
+        flags.push(AuditFlag {
+            check,
+            weight: check.weight(),
+            detail,
+        });
+    };
+
+    let name = normalize(&process.name);
+    let exe_text = exe.map(|exe| exe.to_string_lossy().to_string());
+    let argv0 = cmdline.and_then(|argv| argv.first()).map(String::as_str);
+
+    let posing = KERNEL_THREAD_NAMES.iter().any(|prefix| process.name.starts_with(prefix))
+        || argv0.is_some_and(|argv0| argv0.starts_with('[') && argv0.ends_with(']'));
+    if posing {
+        flag(
+            AuditCheck::KernelMasquerade,
+            format!("Looks like a kernel thread ({}) but is a user process", argv0.unwrap_or(&process.name)),
+        );
+    }
+
+    if let Some(text) = &exe_text {
+        let path = text.strip_suffix(DELETED_SUFFIX).unwrap_or(text);
+        if path.starts_with("/memfd:") {
+            flag(AuditCheck::FilelessExe, format!("Runs from anonymous memory {path}, not from a file"));
+        } else if text.ends_with(DELETED_SUFFIX) || (!cfg!(target_os = "linux") && !Path::new(path).exists()) {
+            flag(
+                AuditCheck::DeletedExe,
+                format!("Executable {path} was deleted or replaced since start (also after package upgrades)"),
+            );
+        }
+
+        let lower = path.to_lowercase();
+        let in_temp = TEMP_DIRS.iter().any(|dir| path.starts_with(dir))
+            || WINDOWS_TEMP_DIRS.iter().any(|dir| lower.contains(dir));
+        if in_temp {
+            flag(AuditCheck::TempExe, format!("Executable lives in a temporary directory: {path}"));
+        } else if let Some(dir) = Path::new(path).parent()
+            && platform::world_writable_dir(dir)
+        {
+            flag(AuditCheck::WorldWritableDir, format!("Any user can write to {}", dir.display()));
+        }
+
+        let stem = normalize(Path::new(path).file_name().map(|file| file.to_string_lossy()).as_deref().unwrap_or(""));
+        // Shebang scripts take the script's name while exe is the interpreter
+        let script = cmdline
+            .unwrap_or_default()
+            .iter()
+            .skip(1)
+            .take(2)
+            .any(|arg| related(&name, &normalize(base_name(arg))));
+        if !posing && !script && !related(&name, &stem) {
+            flag(
+                AuditCheck::NameMismatch,
+                format!("Name {:?} does not match executable {stem:?}", process.name),
+            );
+        }
+
+        // Zombies and kernel threads have empty command lines but no exe either
+        match argv0 {
+            None if cmdline.is_some() => {
+                flag(AuditCheck::HiddenArgv, "Command line is empty although the process has an executable".to_string());
+            }
+            Some(argv0) if !posing => {
+                let program = argv0.split([':', ' ']).next().unwrap_or(argv0);
+                let program = normalize(base_name(program).trim_start_matches('-'));
+                if !program.is_empty() && !related(&program, &name) && !related(&program, &stem) {
+                    flag(
+                        AuditCheck::SpoofedArgv,
+                        format!("argv[0] {argv0:?} names neither the process nor its executable"),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    flags.sort_by_key(|flag| std::cmp::Reverse(flag.weight));
+    let score = flags.iter().map(|flag| flag.weight).sum();
+    let severity = match score {
+        60.. => "high",
+        30.. => "medium",
+        _ => "low",
+    };
+    AuditFinding {
+        pid: process.pid,
+        proc_id: identity::proc_id(process.pid, process.start_time),
+        name: process.name.clone(),
+        exe: exe_text,
+        score,
+        severity: severity.to_string(),
+        flags,
+    }
+}
+
+/// Last component of a Unix or Windows path
+fn base_name(path: &str) -> &str {
+    path.rsplit(['/', '\\']).next().unwrap_or(path)
+}
+
+/// Lowercase without a Windows `.exe` suffix
+fn normalize(name: &str) -> String {
+    let lower = name.to_lowercase();
+    lower.strip_suffix(".exe").map_or(lower.clone(), str::to_string)
+}
+
+/// One name is a prefix of the other (Linux truncates names to 15 bytes; `python3` runs `python3.12`)
+fn related(a: &str, b: &str) -> bool {
+    !a.is_empty() && !b.is_empty() && (a.starts_with(b) || b.starts_with(a))
+}
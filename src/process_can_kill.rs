@@ -0,0 +1,156 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::platform;
+use crate::protection::protected_reason;
+use crate::schema::{
+    DO_NOT_KILL_TAG, PROCESS_CAN_KILL, ProcessCanKillArgs, ProcessCanKillOutput, ProcessCanKillPrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessCanKillTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessCanKillTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessCanKillTool {
+    type Args = ProcessCanKillArgs;
+    type Prompts = ProcessCanKillPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_CAN_KILL
+    }
+
+    fn description() -> &'static str {
+        "Check whether the server could plausibly kill a process without sending any signal. \
+         Compares process ownership with the server's identity, checks privileges (root/CAP_KILL, \
+         Windows elevation and integrity levels) and the protected-process list. Use before \
+         proposing process_kill to the user."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessCanKillOutput>, McpError> {
+        let pid = args.pid;
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let state = self.state.clone();
+        let output = tokio::task::spawn_blocking(move || check(pid, &state))
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to check process {pid}: {e}")))?;
+
+        let (color, verdict) = if output.can_kill {
+            (Color::Green, "yes")
+        } else {
+            (Color::Red, "no")
+        };
+        let name = output.name.as_deref().unwrap_or("not found");
+        let mut summary = format!(
+            "{} PID {pid} ({name}): {}",
+            style.paint(Color::Cyan, "Can Kill"),
+            style.paint(color, verdict)
+        );
+        let shown = match verbosity {
+            SummaryVerbosity::Quiet => 0,
+            SummaryVerbosity::Normal => 1,
+            SummaryVerbosity::Detailed => output.reasons.len(),
+        };
+        for reason in output.reasons.iter().take(shown) {
+            summary.push_str(&format!("\n{} {reason}", style.bullet()));
+        }
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
+
+// ============================================================================
+// PERMISSION CHECK
+// ============================================================================
+
+fn check(pid: u32, state: &ProcessState) -> ProcessCanKillOutput {
+    // Same lookups as the kill path, so both agree on what they see
+    let server_user = state.provider.lookup(std::process::id(), true).and_then(|server| server.user);
+
+    let Some(target) = state.provider.lookup(pid, true) else {
+        return ProcessCanKillOutput {
+            success: true,
+            pid,
+            exists: false,
+            name: None,
+            can_kill: false,
+            protected: false,
+            target_user: None,
+            server_user,
+            reasons: vec!["Process not found".to_string()],
+        };
+    };
+
+    let mut reasons = Vec::new();
+
+    let protected = protected_reason(pid, &target.name, target.parent);
+    if let Some(reason) = &protected {
+        reasons.push(format!("Protected: {reason} (process_kill will refuse)"));
+    }
+    let guarded = state.tags.has(pid, target.start_time, DO_NOT_KILL_TAG);
+    if guarded {
+        reasons.push(format!("Guarded: tagged \"{DO_NOT_KILL_TAG}\" (process_kill will refuse)"));
+    }
+
+    let integrity = platform::integrity_denial(pid);
+    let privilege = platform::server_privilege();
+    let target_user = target.user;
+    let same_owner = server_user.is_some() && target_user == server_user;
+
+    let permitted = if let Some(privilege) = privilege {
+        reasons.push(format!("Server holds {privilege}, which overrides ownership checks"));
+        true
+    } else if let Some(denial) = integrity {
+        reasons.push(format!("Denied: {denial}"));
+        false
+    } else if same_owner {
+        reasons.push("Target is owned by the server's user".to_string());
+        true
+    } else {
+        reasons.push(format!(
+            "Denied: target owned by {} but server runs as {}",
+            target_user.as_deref().unwrap_or("unknown user"),
+            server_user.as_deref().unwrap_or("unknown user")
+        ));
+        false
+    };
+
+    ProcessCanKillOutput {
+        success: true,
+        pid,
+        exists: true,
+        name: Some(target.name),
+        can_kill: permitted && protected.is_none() && !guarded,
+        protected: protected.is_some(),
+        target_user,
+        server_user,
+        reasons,
+    }
+}
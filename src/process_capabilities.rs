@@ -0,0 +1,185 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::platform;
+use crate::schema::{
+    CapabilitySet, PROCESS_CAPABILITIES, ProcessCapabilitiesArgs, ProcessCapabilitiesOutput,
+    ProcessCapabilitiesPrompts, SummaryStyle, SummaryVerbosity,
+};
+
+/// Capability names indexed by bit number (include/uapi/linux/capability.h)
+const CAPABILITY_NAMES: &[&str] = &[
+    "CAP_CHOWN",
+    "CAP_DAC_OVERRIDE",
+    "CAP_DAC_READ_SEARCH",
+    "CAP_FOWNER",
+    "CAP_FSETID",
+    "CAP_KILL",
+    "CAP_SETGID",
+    "CAP_SETUID",
+    "CAP_SETPCAP",
+    "CAP_LINUX_IMMUTABLE",
+    "CAP_NET_BIND_SERVICE",
+    "CAP_NET_BROADCAST",
+    "CAP_NET_ADMIN",
+    "CAP_NET_RAW",
+    "CAP_IPC_LOCK",
+    "CAP_IPC_OWNER",
+    "CAP_SYS_MODULE",
+    "CAP_SYS_RAWIO",
+    "CAP_SYS_CHROOT",
+    "CAP_SYS_PTRACE",
+    "CAP_SYS_PACCT",
+    "CAP_SYS_ADMIN",
+    "CAP_SYS_BOOT",
+    "CAP_SYS_NICE",
+    "CAP_SYS_RESOURCE",
+    "CAP_SYS_TIME",
+    "CAP_SYS_TTY_CONFIG",
+    "CAP_MKNOD",
+    "CAP_LEASE",
+    "CAP_AUDIT_WRITE",
+    "CAP_AUDIT_CONTROL",
+    "CAP_SETFCAP",
+    "CAP_MAC_OVERRIDE",
+    "CAP_MAC_ADMIN",
+    "CAP_SYSLOG",
+    "CAP_WAKE_ALARM",
+    "CAP_BLOCK_SUSPEND",
+    "CAP_AUDIT_READ",
+    "CAP_PERFMON",
+    "CAP_BPF",
+    "CAP_CHECKPOINT_RESTORE",
+];
+
+/// Effective capabilities worth explaining in plain language
+const NOTABLE: &[(&str, &str)] = &[
+    ("CAP_NET_BIND_SERVICE", "can bind TCP/UDP ports below 1024"),
+    ("CAP_KILL", "can send signals to processes owned by other users"),
+    ("CAP_SYS_PTRACE", "can ptrace/inspect other processes"),
+    ("CAP_SYS_NICE", "can raise priority and change other processes' scheduling"),
+    ("CAP_NET_ADMIN", "can reconfigure network interfaces and firewall rules"),
+    ("CAP_NET_RAW", "can open raw sockets (ping, packet capture)"),
+    ("CAP_DAC_OVERRIDE", "bypasses file read/write/execute permission checks"),
+    ("CAP_SYS_ADMIN", "holds CAP_SYS_ADMIN (near-root: mounts, namespaces, many ioctls)"),
+];
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessCapabilitiesTool {
+    config: Arc<ProcessConfig>,
+}
+
+impl ProcessCapabilitiesTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>) -> Self {
+        Self { config }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessCapabilitiesTool {
+    type Args = ProcessCapabilitiesArgs;
+    type Prompts = ProcessCapabilitiesPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_CAPABILITIES
+    }
+
+    fn description() -> &'static str {
+        "Decode the Linux capability sets of a process (CapEff, CapPrm, CapInh, CapBnd, CapAmb \
+         from /proc/<pid>/status) into named capabilities, with plain-language notes for notable \
+         ones. Explains why a non-root process can bind port 80 or why a kill against a \
+         capability-holding target fails. Linux only."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessCapabilitiesOutput>, McpError> {
+        let pid = args.pid;
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        if !cfg!(target_os = "linux") {
+            return Err(McpError::Other(anyhow::anyhow!(
+                "Linux capabilities are only available on Linux"
+            )));
+        }
+
+        let masks = platform::capability_sets(pid)
+            .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?;
+
+        let effective = decode(masks.effective);
+        let notes = NOTABLE
+            .iter()
+            .filter(|(name, _)| effective.names.iter().any(|n| n == name))
+            .map(|(_, note)| (*note).to_string())
+            .collect();
+
+        let output = ProcessCapabilitiesOutput {
+            success: true,
+            pid,
+            effective,
+            permitted: decode(masks.permitted),
+            inheritable: decode(masks.inheritable),
+            bounding: decode(masks.bounding),
+            ambient: masks.ambient.map(decode),
+            notes,
+        };
+
+        let mut summary = format!(
+            "{} PID {pid}: {} effective{}{} permitted",
+            style.paint(Color::Cyan, "Capabilities"),
+            output.effective.names.len(),
+            style.sep(),
+            output.permitted.names.len()
+        );
+        if verbosity != SummaryVerbosity::Quiet {
+            let names = if output.effective.names.is_empty() {
+                "none".to_string()
+            } else {
+                output.effective.names.join(", ")
+            };
+            summary.push_str(&format!("\n{} Effective: {names}", style.bullet()));
+            for note in &output.notes {
+                summary.push_str(&format!("\n{} {note}", style.bullet()));
+            }
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            summary.push_str(&format!(
+                "\n{} Bounding: {}",
+                style.bullet(),
+                output.bounding.names.join(", ")
+            ));
+        }
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
+
+/// Decode a capability mask into names (unknown bits become `CAP_<n>`)
+fn decode(mask: u64) -> CapabilitySet {
+    let names = (0..64)
+        .filter(|bit| mask & (1u64 << bit) != 0)
+        .map(|bit| match CAPABILITY_NAMES.get(bit as usize) {
+            Some(name) => (*name).to_string(),
+            None => format!("CAP_{bit}"),
+        })
+        .collect();
+
+    CapabilitySet {
+        raw: format!("{mask:016x}"),
+        names,
+    }
+}
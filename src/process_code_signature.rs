@@ -0,0 +1,140 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::platform;
+use crate::schema::{
+    PROCESS_CODE_SIGNATURE, ProcessCodeSignatureArgs, ProcessCodeSignatureOutput, ProcessCodeSignaturePrompts,
+    SignatureState, SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessCodeSignatureTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessCodeSignatureTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessCodeSignatureTool {
+    type Args = ProcessCodeSignatureArgs;
+    type Prompts = ProcessCodeSignaturePrompts;
+
+    fn name() -> &'static str {
+        PROCESS_CODE_SIGNATURE
+    }
+
+    fn description() -> &'static str {
+        "Check the code signature of a process's executable: state (valid, invalid, unsigned, \
+         or unsupported on Linux), the signer and certificate chain, and on macOS the team ID, \
+         ad-hoc and hardened runtime flags and notarization. Uses Authenticode via PowerShell's \
+         Get-AuthenticodeSignature on Windows and codesign/spctl on macOS. notes explain \
+         operations the OS will refuse on the process, e.g. debugging a hardened or platform \
+         binary."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessCodeSignatureOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let pid = args.pid;
+        let state = self.state.clone();
+
+        // codesign and PowerShell run as child processes
+        let (name, exe, signature) = tokio::task::spawn_blocking(move || {
+            let Some(process) = state.provider.lookup(pid, true) else {
+                return Err(McpError::ResourceNotFound(format!("Process {pid} not found")));
+            };
+            let Some(exe) = process.exe.or_else(|| platform::exe_path(pid)) else {
+                return Err(McpError::PermissionDenied(format!("Executable path of process {pid} is not readable")));
+            };
+            let signature = platform::code_signature(&exe).ok_or_else(|| {
+                McpError::Other(anyhow::anyhow!("Signature check of {} failed or timed out", exe.display()))
+            })?;
+            Ok((process.name, exe.display().to_string(), signature))
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to check process {pid}: {e}")))??;
+
+        let color = match signature.state {
+            SignatureState::Valid => Color::Green,
+            SignatureState::Invalid | SignatureState::Unsigned => Color::Red,
+            SignatureState::Unsupported => Color::Cyan,
+        };
+        let mut summary = format!(
+            "{} PID {pid} ({name}) {}",
+            style.paint(Color::Cyan, "Code Signature:"),
+            style.paint(color, signature.state.name())
+        );
+        if let Some(signer) = &signature.signer {
+            summary.push_str(&format!("{}{signer}", style.sep()));
+        }
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!("\n{} {exe}", style.bullet()));
+            if let Some(detail) = &signature.detail {
+                summary.push_str(&format!("{}{detail}", style.sep()));
+            }
+            let flags: Vec<&str> = [
+                (signature.os_binary, "OS binary"),
+                (signature.ad_hoc, "ad-hoc"),
+                (signature.hardened_runtime, "hardened runtime"),
+                (signature.notarized, "notarized"),
+            ]
+            .into_iter()
+            .filter_map(|(flag, label)| (flag == Some(true)).then_some(label))
+            .collect();
+            if !flags.is_empty() {
+                summary.push_str(&format!("\n{} {}", style.bullet(), flags.join(style.sep())));
+            }
+            for note in &signature.notes {
+                summary.push_str(&format!("\n{} {note}", style.bullet()));
+            }
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            if let Some(team) = &signature.team_id {
+                summary.push_str(&format!("\n{} Team: {team}", style.bullet()));
+            }
+            if let Some(identifier) = &signature.identifier {
+                summary.push_str(&format!("\n{} Identifier: {identifier}", style.bullet()));
+            }
+            if !signature.authorities.is_empty() {
+                summary.push_str(&format!("\n{} Chain: {}", style.bullet(), signature.authorities.join(style.arrow())));
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessCodeSignatureOutput {
+                success: true,
+                pid,
+                name,
+                exe,
+                signature,
+            },
+        ))
+    }
+}
@@ -0,0 +1,192 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use serde_json::{Value, json};
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, DETAIL_ROWS, human_bytes, truncate};
+use crate::platform;
+use crate::process_env_diff::diff_limits;
+use crate::provider::ProcessEntry;
+use crate::schema::{
+    ComparedField, PROCESS_COMPARE, ProcessCompareArgs, ProcessCompareOutput, ProcessComparePrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessCompareTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessCompareTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessCompareTool {
+    type Args = ProcessCompareArgs;
+    type Prompts = ProcessComparePrompts;
+
+    fn name() -> &'static str {
+        PROCESS_COMPARE
+    }
+
+    fn description() -> &'static str {
+        "Compare two processes side by side, e.g. the good instance and the bad one: name, \
+         exe, cmdline, user, parent, working directory, cgroup, open handle count, threads, \
+         CPU and memory, plus the resource limits that differ (Linux). Every field comes with \
+         both values and whether they match; only_differences drops the matching ones. Use \
+         process_env_diff for the environment."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        false // CPU and memory move between calls
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessCompareOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let redact = self.state.redactor.active(args.reveal_secrets)?;
+        let state = self.state.clone();
+        let (pid_a, pid_b) = (args.pid_a, args.pid_b);
+
+        // Procfs reads per process
+        let (names, mut fields, limits, redacted) = tokio::task::spawn_blocking(move || {
+            let lookup = |pid: u32| {
+                state
+                    .provider
+                    .lookup(pid, true)
+                    .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))
+            };
+            let (mut a, mut b) = (lookup(pid_a)?, lookup(pid_b)?);
+            let cmdline_same = a.cmdline == b.cmdline;
+            let mut redacted = 0;
+            if redact {
+                redacted = state.redactor.cmdline(&mut a.cmdline) + state.redactor.cmdline(&mut b.cmdline);
+            }
+            let (values_a, values_b) = (values(&a), values(&b));
+            let fields: Vec<ComparedField> = values_a
+                .into_iter()
+                .zip(values_b)
+                .map(|((field, a), (_, b))| ComparedField {
+                    field: field.to_string(),
+                    // Masked arguments may look alike while the real ones differ
+                    same: if field == "cmdline" { cmdline_same } else { a == b },
+                    a,
+                    b,
+                })
+                .collect();
+            let limits = diff_limits(
+                platform::resource_limits(pid_a).as_deref(),
+                platform::resource_limits(pid_b).as_deref(),
+            );
+            Ok::<_, McpError>(((a.name, b.name), fields, limits, redacted))
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to compare processes {pid_a} and {pid_b}: {e}")))??;
+
+        let differing = fields.iter().filter(|field| !field.same).count() + limits.len();
+        if args.only_differences {
+            fields.retain(|field| !field.same);
+        }
+
+        let color = if differing == 0 { Color::Green } else { Color::Cyan };
+        let mut summary = format!(
+            "{} PID {pid_a} ({}) vs PID {pid_b} ({}){}{differing} differences",
+            style.paint(color, "Compare:"),
+            names.0,
+            names.1,
+            style.sep()
+        );
+        if verbosity != SummaryVerbosity::Quiet {
+            let shown = if verbosity == SummaryVerbosity::Detailed { usize::MAX } else { DETAIL_ROWS };
+            let side = |field: &str, value: &Value| match value {
+                Value::Null => "?".to_string(),
+                Value::Number(bytes) if field.ends_with("_bytes") => human_bytes(bytes.as_u64().unwrap_or_default()),
+                Value::String(text) => truncate(text, 50),
+                Value::Array(items) => {
+                    let words: Vec<String> = items.iter().map(|item| item.as_str().unwrap_or_default().to_string()).collect();
+                    truncate(&words.join(" "), 50)
+                }
+                other => other.to_string(),
+            };
+            let mut rows: Vec<String> = fields
+                .iter()
+                .filter(|field| !field.same)
+                .map(|field| {
+                    format!(
+                        "{}: {}{}{}",
+                        field.field,
+                        side(&field.field, &field.a),
+                        style.arrow(),
+                        side(&field.field, &field.b)
+                    )
+                })
+                .collect();
+            rows.extend(limits.iter().map(|limit| {
+                let side = |value: &Option<String>| value.clone().unwrap_or_else(|| "?".to_string());
+                format!("{}: {}{}{}", limit.key, side(&limit.a), style.arrow(), side(&limit.b))
+            }));
+            for row in rows.iter().take(shown) {
+                summary.push_str(&format!("\n{} {row}", style.bullet()));
+            }
+            if rows.len() > shown {
+                summary.push_str(&format!("\n{} {} {} more", style.bullet(), style.ellipsis(), rows.len() - shown));
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessCompareOutput {
+                success: true,
+                pid_a,
+                pid_b,
+                fields,
+                limits,
+                differing,
+                redacted,
+            },
+        ))
+    }
+}
+
+// ============================================================================
+// FIELDS
+// ============================================================================
+
+/// Compared fields of one process in output order; `null` where unreadable
+fn values(process: &ProcessEntry) -> Vec<(&'static str, Value)> {
+    let pid = process.pid;
+    let lossy = |path: &Option<std::path::PathBuf>| path.as_ref().map(|path| path.display().to_string());
+    vec![
+        ("name", json!(process.name)),
+        ("exe", json!(lossy(&process.exe))),
+        ("cmdline", if process.cmdline.is_empty() { Value::Null } else { json!(process.cmdline) }),
+        ("user", json!(process.user)),
+        ("parent", json!(process.parent)),
+        ("cwd", json!(lossy(&process.cwd))),
+        ("cgroup", json!(platform::cgroup_paths(pid))),
+        ("handles", json!(platform::handle_count(pid))),
+        ("threads", json!(platform::threads(pid).map(|threads| threads.len()))),
+        ("cpu_percent", json!(process.cpu_percent)),
+        ("rss_bytes", json!(process.rss_bytes)),
+        ("virtual_bytes", json!(process.virtual_bytes)),
+    ]
+}
@@ -0,0 +1,132 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, DETAIL_ROWS};
+use crate::identity;
+use crate::platform;
+use crate::schema::{
+    PROCESS_CONNECTIONS, ProcessConnectionsArgs, ProcessConnectionsOutput, ProcessConnectionsPrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessConnectionsTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessConnectionsTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessConnectionsTool {
+    type Args = ProcessConnectionsArgs;
+    type Prompts = ProcessConnectionsPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_CONNECTIONS
+    }
+
+    fn description() -> &'static str {
+        "List the TCP and UDP sockets a process owns, like netstat -p or lsof -i: protocol, \
+         local and remote address and TCP state such as LISTEN or ESTABLISHED. state keeps only \
+         TCP sockets in that state; include_unix adds Unix domain sockets (Linux). Read from \
+         /proc/<pid>/net on Linux (so addresses are those of the process's network namespace), \
+         lsof on macOS and the IP Helper tables on Windows. readable is false when they could \
+         not be read; other users' processes need privileges. Use port_lookup to go from a \
+         port to its process."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessConnectionsOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+
+        let state = self.state.clone();
+        let wanted = args.state;
+        let include_unix = args.include_unix;
+        let output = tokio::task::spawn_blocking(move || {
+            let process = state
+                .provider
+                .lookup(pid, false)
+                .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?;
+            let sockets = platform::connections(pid);
+            let readable = sockets.is_some();
+            let mut sockets = sockets.unwrap_or_default();
+            sockets.retain(|socket| include_unix || socket.protocol != "unix");
+            if let Some(wanted) = &wanted {
+                sockets.retain(|socket| socket.state.as_ref().is_some_and(|state| state.eq_ignore_ascii_case(wanted)));
+            }
+            let listening = sockets.iter().filter(|socket| socket.state.as_deref() == Some("LISTEN")).count();
+            Ok::<_, McpError>(ProcessConnectionsOutput {
+                success: true,
+                pid,
+                name: process.name,
+                sockets,
+                listening,
+                readable,
+            })
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to list sockets of process {pid}: {e}")))??;
+
+        let mut summary = if output.readable {
+            format!(
+                "{} PID {pid} ({}){}{} socket(s){}{} listening",
+                style.paint(Color::Cyan, "Connections:"),
+                output.name,
+                style.sep(),
+                output.sockets.len(),
+                style.sep(),
+                output.listening
+            )
+        } else {
+            format!(
+                "{} PID {pid} ({}): sockets unreadable (another user's process?)",
+                style.paint(Color::Red, "Connections:"),
+                output.name
+            )
+        };
+        if verbosity != SummaryVerbosity::Quiet {
+            let shown = if verbosity == SummaryVerbosity::Detailed { usize::MAX } else { DETAIL_ROWS };
+            for socket in output.sockets.iter().take(shown) {
+                let mut line = format!("\n{} {:<5} {}", style.bullet(), socket.protocol, socket.local);
+                // Listeners and unconnected UDP sockets have only a zero peer
+                if let Some(remote) = socket.remote.as_deref().filter(|remote| !remote.ends_with(":0")) {
+                    line.push_str(&format!("{}{remote}", style.arrow()));
+                }
+                if let Some(state) = &socket.state {
+                    line.push_str(&format!(" {state}"));
+                }
+                summary.push_str(&line);
+            }
+            if output.sockets.len() > shown {
+                summary.push_str(&format!("\n{} {} {} more", style.bullet(), style.ellipsis(), output.sockets.len() - shown));
+            }
+        }
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
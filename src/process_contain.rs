@@ -0,0 +1,170 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::io::ErrorKind;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::cpu_limit::CpuLimiter;
+use crate::display::{Color, human_bytes};
+use crate::identity;
+use crate::protection::protected_reason;
+use crate::schema::{
+    ContainmentCaps, ContainmentInfo, CpuLimitMethod, MIN_MEMORY_MAX_BYTES, PROCESS_CONTAIN, ProcessContainArgs,
+    ProcessContainOutput, ProcessContainPrompts, SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessContainTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessContainTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessContainTool {
+    type Args = ProcessContainArgs;
+    type Prompts = ProcessContainPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_CONTAIN
+    }
+
+    fn description() -> &'static str {
+        "Contain a runaway process in place: move it into a managed cgroup v2 and cap it with \
+         memory_max_bytes (memory.max), cpu_percent (cpu.max) and/or pids_max (pids.max). \
+         Children it forks afterwards stay inside. Calling again changes only the caps given; \
+         process_uncontain moves it back. Linux only; needs root or a delegated cgroup \
+         hierarchy with the cpu, memory and pids controllers. Protected processes are refused."
+    }
+
+    fn read_only() -> bool {
+        false // Moves the process between cgroups
+    }
+
+    fn idempotent() -> bool {
+        true // The same caps again change nothing
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessContainOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+        let caps = ContainmentCaps {
+            memory_max_bytes: args.memory_max_bytes,
+            cpu_percent: args.cpu_percent,
+            pids_max: args.pids_max,
+        };
+
+        check_caps(&caps).map_err(McpError::InvalidArguments)?;
+
+        let state = self.state.clone();
+        let (containment, updated) = tokio::task::spawn_blocking(move || {
+            let process = state
+                .provider
+                .lookup(pid, false)
+                .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?;
+            if let Some(reason) = protected_reason(pid, &process.name, process.parent) {
+                return Err(McpError::PermissionDenied(format!("Refusing to contain process {pid}: {reason}")));
+            }
+            state
+                .containments
+                .contain(pid, &process.name, process.start_time, caps)
+                .map_err(|e| match e.kind() {
+                    ErrorKind::PermissionDenied => McpError::PermissionDenied(format!(
+                        "Failed to contain process {pid}: {e} (needs root or a delegated cgroup v2 hierarchy)"
+                    )),
+                    ErrorKind::AlreadyExists => McpError::InvalidArguments(format!(
+                        "Process {pid} is already in a managed cgroup; lift its CPU limit with process_cpu_unlimit first"
+                    )),
+                    ErrorKind::InvalidInput => McpError::InvalidArguments(format!("Failed to contain process {pid}: {e}")),
+                    _ => McpError::Other(anyhow::anyhow!("Failed to contain process {pid}: {e}")),
+                })
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Containment task failed: {e}")))??;
+
+        let headline = if updated { "Containment Changed:" } else { "Contained:" };
+        let mut summary = format!(
+            "{} PID {pid} ({}) with {}",
+            style.paint(Color::Green, headline),
+            containment.name,
+            caps_text(&containment.caps)
+        );
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!("\n{} Usage: {}", style.bullet(), usage_text(&containment)));
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            summary.push_str(&format!(
+                "\n{} Cgroup: {}{}Since: {}",
+                style.bullet(),
+                containment.cgroup,
+                style.sep(),
+                containment.since
+            ));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessContainOutput {
+                success: true,
+                containment,
+                updated,
+            },
+        ))
+    }
+}
+
+/// "memory 2.00 GiB, cpu 50%, pids 64"
+pub(crate) fn caps_text(caps: &ContainmentCaps) -> String {
+    let mut parts = Vec::new();
+    if let Some(bytes) = caps.memory_max_bytes {
+        parts.push(format!("memory {}", human_bytes(bytes)));
+    }
+    if let Some(percent) = caps.cpu_percent {
+        parts.push(format!("cpu {percent}%"));
+    }
+    if let Some(pids) = caps.pids_max {
+        parts.push(format!("pids {pids}"));
+    }
+    parts.join(", ")
+}
+
+/// "812.00 MiB memory, 3 pids"
+pub(crate) fn usage_text(containment: &ContainmentInfo) -> String {
+    let memory = containment
+        .memory_current_bytes
+        .map_or_else(|| "?".to_string(), human_bytes);
+    let pids = containment
+        .pids_current
+        .map_or_else(|| "?".to_string(), |pids| pids.to_string());
+    format!("{memory} memory, {pids} pids")
+}
+
+/// Reject caps the cgroup files would refuse or that make no sense
+pub(crate) fn check_caps(caps: &ContainmentCaps) -> Result<(), String> {
+    if caps.memory_max_bytes.is_some_and(|bytes| bytes < MIN_MEMORY_MAX_BYTES) {
+        return Err(format!("memory_max_bytes must be at least {MIN_MEMORY_MAX_BYTES}"));
+    }
+    let max_percent = CpuLimiter::max_percent(CpuLimitMethod::Cgroup);
+    if caps.cpu_percent.is_some_and(|percent| !(1..=max_percent).contains(&percent)) {
+        return Err(format!("cpu_percent must be between 1 and {max_percent}"));
+    }
+    if caps.pids_max == Some(0) {
+        return Err("pids_max must be at least 1".to_string());
+    }
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::identity;
+use crate::protection::protected_reason;
+use crate::schema::{
+    PROCESS_CPU_LIMIT, ProcessCpuLimitArgs, ProcessCpuLimitOutput, ProcessCpuLimitPrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessCpuLimitTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessCpuLimitTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessCpuLimitTool {
+    type Args = ProcessCpuLimitArgs;
+    type Prompts = ProcessCpuLimitPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_CPU_LIMIT
+    }
+
+    fn description() -> &'static str {
+        "Cap a running process at percent of one CPU core instead of killing it. Uses cgroup v2 \
+         cpu.max on Linux when available (percent may exceed 100 for multi-threaded work), \
+         otherwise SIGSTOP/SIGCONT duty cycling over a 100 ms period (1-99 percent). Calling \
+         again adjusts the limit. It lasts until process_cpu_unlimit, process exit or server \
+         shutdown; see process_cpu_limits. Protected processes are refused."
+    }
+
+    fn read_only() -> bool {
+        false // Throttles a process
+    }
+
+    fn idempotent() -> bool {
+        true // The same percent again changes nothing
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessCpuLimitOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+
+        let state = self.state.clone();
+        let applied = tokio::task::spawn_blocking(move || {
+            let process = state
+                .provider
+                .lookup(pid, false)
+                .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?;
+            if let Some(reason) = protected_reason(pid, &process.name, process.parent) {
+                return Err(McpError::PermissionDenied(format!("Refusing to limit process {pid}: {reason}")));
+            }
+            state
+                .cpu_limits
+                .limit(pid, &process.name, process.start_time, args.percent, args.method)
+                .map_err(|e| McpError::InvalidArguments(format!("Failed to limit process {pid}: {e}")))
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("CPU limit task failed: {e}")))??;
+
+        let limit = &applied.info;
+        let headline = if applied.updated { "CPU Limit Changed:" } else { "CPU Limited:" };
+        let mut summary = format!(
+            "{} PID {pid} to {}% via {}",
+            style.paint(Color::Green, headline),
+            limit.percent,
+            limit.method.name()
+        );
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!("\n{} Name: {}{}Since: {}", style.bullet(), limit.name, style.sep(), limit.since));
+            if let Some(reason) = &applied.fallback_reason {
+                summary.push_str(&format!("\n{} Not cgroup: {reason}", style.bullet()));
+            }
+        }
+        if verbosity == SummaryVerbosity::Detailed
+            && let Some(cgroup) = &limit.cgroup
+        {
+            summary.push_str(&format!("\n{} Cgroup: {cgroup}", style.bullet()));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessCpuLimitOutput {
+                success: true,
+                limit: applied.info,
+                updated: applied.updated,
+                fallback_reason: applied.fallback_reason,
+            },
+        ))
+    }
+}
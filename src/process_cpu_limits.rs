@@ -0,0 +1,90 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, DETAIL_ROWS};
+use crate::schema::{
+    PROCESS_CPU_LIMITS, ProcessCpuLimitsArgs, ProcessCpuLimitsOutput, ProcessCpuLimitsPrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessCpuLimitsTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessCpuLimitsTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessCpuLimitsTool {
+    type Args = ProcessCpuLimitsArgs;
+    type Prompts = ProcessCpuLimitsPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_CPU_LIMITS
+    }
+
+    fn description() -> &'static str {
+        "List processes whose CPU use is capped by process_cpu_limit, with percent, enforcement \
+         method (cgroup or signals) and start time."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        false // Limits end as processes exit
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessCpuLimitsOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let state = self.state.clone();
+        let limits = tokio::task::spawn_blocking(move || state.cpu_limits.list())
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("CPU limit task failed: {e}")))?;
+
+        let mut summary = format!("{} {} active", style.paint(Color::Cyan, "CPU Limits:"), limits.len());
+        let shown = match verbosity {
+            SummaryVerbosity::Quiet => 0,
+            SummaryVerbosity::Normal => DETAIL_ROWS,
+            SummaryVerbosity::Detailed => limits.len(),
+        };
+        for limit in limits.iter().take(shown) {
+            summary.push_str(&format!(
+                "\n{} PID {} ({}): {}% via {}",
+                style.bullet(),
+                limit.pid,
+                limit.name,
+                limit.percent,
+                limit.method.name()
+            ));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessCpuLimitsOutput {
+                success: true,
+                count: limits.len(),
+                limits,
+            },
+        ))
+    }
+}
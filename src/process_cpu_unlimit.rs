@@ -0,0 +1,78 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::identity;
+use crate::schema::{
+    PROCESS_CPU_UNLIMIT, ProcessCpuUnlimitArgs, ProcessCpuUnlimitOutput, ProcessCpuUnlimitPrompts, SummaryStyle,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessCpuUnlimitTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessCpuUnlimitTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessCpuUnlimitTool {
+    type Args = ProcessCpuUnlimitArgs;
+    type Prompts = ProcessCpuUnlimitPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_CPU_UNLIMIT
+    }
+
+    fn description() -> &'static str {
+        "Lift a CPU limit set by process_cpu_limit. A duty-cycled process is resumed; a cgroup-limited \
+         one is moved back to its original cgroup."
+    }
+
+    fn read_only() -> bool {
+        false // Removes a server-side limit
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessCpuUnlimitOutput>, McpError> {
+        // Single-line result at every verbosity
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+
+        let state = self.state.clone();
+        let limit = tokio::task::spawn_blocking(move || state.cpu_limits.lift(pid))
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("CPU limit task failed: {e}")))?
+            .map_err(|e| McpError::Other(anyhow::anyhow!(e)))?
+            .ok_or_else(|| {
+                McpError::ResourceNotFound(format!(
+                    "PID {pid} has no CPU limit (limits end when the process exits)"
+                ))
+            })?;
+
+        Ok(ToolResponse::new(
+            format!(
+                "{} PID {pid} ({}), was {}% via {}",
+                style.paint(Color::Cyan, "CPU Limit Lifted:"),
+                limit.name,
+                limit.percent,
+                limit.method.name()
+            ),
+            ProcessCpuUnlimitOutput { success: true, limit },
+        ))
+    }
+}
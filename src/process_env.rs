@@ -0,0 +1,141 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use globset::{GlobBuilder, GlobSetBuilder};
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, DETAIL_ROWS, truncate};
+use crate::identity;
+use crate::process_env_diff::env_map;
+use crate::schema::{
+    PROCESS_ENV, ProcessEnvArgs, ProcessEnvOutput, ProcessEnvPrompts, SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessEnvTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessEnvTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessEnvTool {
+    type Args = ProcessEnvArgs;
+    type Prompts = ProcessEnvPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_ENV
+    }
+
+    fn description() -> &'static str {
+        "Read the environment a process was started with, e.g. to find out why a daemon picked \
+         up the wrong config. keys limits the result to variables whose names match any of the \
+         given case-insensitive globs, such as [\"PATH\", \"NODE_*\"]. Values of secret-looking \
+         variables (TOKEN, SECRET, KEY, PASSWORD, CREDENTIAL by default) are masked unless \
+         reveal_secrets=true and the server allows it. readable is false when nothing could be \
+         read; other users' environments need privileges. Use process_env_diff to compare two \
+         processes."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessEnvOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let redact = self.state.redactor.active(args.reveal_secrets)?;
+
+        let mut builder = GlobSetBuilder::new();
+        for pattern in &args.keys {
+            let glob = GlobBuilder::new(pattern)
+                .case_insensitive(true)
+                .build()
+                .map_err(|e| McpError::InvalidArguments(format!("Invalid key pattern {pattern:?}: {e}")))?;
+            builder.add(glob);
+        }
+        let keys = builder
+            .build()
+            .map_err(|e| McpError::InvalidArguments(format!("Invalid key patterns: {e}")))?;
+        let filtered = !args.keys.is_empty();
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+
+        let state = self.state.clone();
+        let output = tokio::task::spawn_blocking(move || {
+            let process = state
+                .provider
+                .lookup(pid, true)
+                .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?;
+            let total = env_map(&process.env).len();
+            let mut entries: Vec<String> = process
+                .env
+                .into_iter()
+                .filter(|entry| !filtered || keys.is_match(entry.split_once('=').map_or(entry.as_str(), |(key, _)| key)))
+                .collect();
+            let redacted = if redact { state.redactor.env(&mut entries) } else { 0 };
+            Ok::<_, McpError>(ProcessEnvOutput {
+                success: true,
+                pid,
+                name: process.name,
+                env: env_map(&entries),
+                total,
+                redacted,
+                readable: total > 0,
+            })
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to read environment of process {pid}: {e}")))??;
+
+        let mut summary = if output.readable {
+            let mut headline = format!(
+                "{} PID {pid} ({}){}{} variables",
+                style.paint(Color::Cyan, "Environment:"),
+                output.name,
+                style.sep(),
+                output.total
+            );
+            if filtered {
+                headline.push_str(&format!(", {} matching", output.env.len()));
+            }
+            if output.redacted > 0 {
+                headline.push_str(&format!("{}{} masked", style.sep(), output.redacted));
+            }
+            headline
+        } else {
+            format!(
+                "{} PID {pid} ({}): environment unreadable (another user's process?) or empty",
+                style.paint(Color::Red, "Environment:"),
+                output.name
+            )
+        };
+        if verbosity != SummaryVerbosity::Quiet {
+            let shown = if verbosity == SummaryVerbosity::Detailed { usize::MAX } else { DETAIL_ROWS };
+            for (key, value) in output.env.iter().take(shown) {
+                summary.push_str(&format!("\n{} {key}={}", style.bullet(), truncate(value, 60)));
+            }
+            if output.env.len() > shown {
+                summary.push_str(&format!("\n{} {} {} more", style.bullet(), style.ellipsis(), output.env.len() - shown));
+            }
+        }
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
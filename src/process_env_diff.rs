@@ -0,0 +1,238 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, DETAIL_ROWS, truncate};
+use crate::platform;
+use crate::redact::REDACTED;
+use crate::schema::{
+    PROCESS_ENV_DIFF, ProcessEnvDiffArgs, ProcessEnvDiffOutput, ProcessEnvDiffPrompts, ResourceLimit, SummaryStyle,
+    SummaryVerbosity, ValueDifference,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessEnvDiffTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessEnvDiffTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessEnvDiffTool {
+    type Args = ProcessEnvDiffArgs;
+    type Prompts = ProcessEnvDiffPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_ENV_DIFF
+    }
+
+    fn description() -> &'static str {
+        "Compare the environment of two processes and return only the variables that differ: \
+         set in one but not the other, or set to different values. include_cwd and \
+         include_limits add the working directory and resource limits (Linux) to the \
+         comparison. The fastest answer to \"why does it work under that process but not this \
+         one\". Secret values are compared unmasked but shown masked per the redaction policy; \
+         other users' environments need privileges to read."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessEnvDiffOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let redact = self.state.redactor.active(args.reveal_secrets)?;
+        let state = self.state.clone();
+        let (pid_a, pid_b) = (args.pid_a, args.pid_b);
+
+        let output = tokio::task::spawn_blocking(move || {
+            let lookup = |pid: u32| {
+                state
+                    .provider
+                    .lookup(pid, true)
+                    .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))
+            };
+            let (a, b) = (lookup(pid_a)?, lookup(pid_b)?);
+
+            let mut unavailable = Vec::new();
+            for process in [&a, &b] {
+                if process.env.is_empty() {
+                    unavailable.push(format!("environment of PID {}", process.pid));
+                }
+            }
+            let (same, mut env) = diff(&env_map(&a.env), &env_map(&b.env));
+            let mut redacted = 0;
+            if redact {
+                for difference in &mut env {
+                    if state.redactor.is_secret_env_key(&difference.key) {
+                        redacted += mask(&mut difference.a) + mask(&mut difference.b);
+                    }
+                }
+            }
+
+            let cwd = args
+                .include_cwd
+                .then(|| {
+                    let lossy = |cwd: &Option<std::path::PathBuf>| cwd.as_ref().map(|cwd| cwd.display().to_string());
+                    let (cwd_a, cwd_b) = (lossy(&a.cwd), lossy(&b.cwd));
+                    if cwd_a.is_none() || cwd_b.is_none() {
+                        unavailable.push("cwd".to_string());
+                    }
+                    (cwd_a != cwd_b).then(|| ValueDifference {
+                        key: "cwd".to_string(),
+                        a: cwd_a,
+                        b: cwd_b,
+                    })
+                })
+                .flatten();
+
+            let limits = if args.include_limits {
+                let (limits_a, limits_b) = (platform::resource_limits(pid_a), platform::resource_limits(pid_b));
+                if limits_a.is_none() || limits_b.is_none() {
+                    unavailable.push("limits".to_string());
+                }
+                diff_limits(limits_a.as_deref(), limits_b.as_deref())
+            } else {
+                Vec::new()
+            };
+
+            Ok::<_, McpError>(ProcessEnvDiffOutput {
+                success: true,
+                pid_a,
+                name_a: a.name,
+                pid_b,
+                name_b: b.name,
+                same,
+                env,
+                cwd,
+                limits,
+                redacted,
+                unavailable,
+            })
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to compare processes {pid_a} and {pid_b}: {e}")))??;
+
+        let differing = output.env.len() + output.limits.len() + usize::from(output.cwd.is_some());
+        let color = if differing == 0 { Color::Green } else { Color::Cyan };
+        let mut summary = format!(
+            "{} PID {} ({}) vs PID {} ({}){}{differing} differences{}{} variables equal",
+            style.paint(color, "Env Diff:"),
+            output.pid_a,
+            output.name_a,
+            output.pid_b,
+            output.name_b,
+            style.sep(),
+            style.sep(),
+            output.same
+        );
+        if verbosity != SummaryVerbosity::Quiet {
+            let shown = if verbosity == SummaryVerbosity::Detailed { usize::MAX } else { DETAIL_ROWS };
+            let side = |value: &Option<String>| value.as_deref().map_or_else(|| "(unset)".to_string(), |value| truncate(value, 60));
+            for difference in output.cwd.iter().chain(&output.env).chain(&output.limits).take(shown) {
+                summary.push_str(&format!(
+                    "\n{} {}: {}{}{}",
+                    style.bullet(),
+                    difference.key,
+                    side(&difference.a),
+                    style.arrow(),
+                    side(&difference.b)
+                ));
+            }
+            if differing > shown {
+                summary.push_str(&format!("\n{} {} {} more", style.bullet(), style.ellipsis(), differing - shown));
+            }
+            if !output.unavailable.is_empty() {
+                summary.push_str(&format!("\n{} Unreadable: {}", style.bullet(), output.unavailable.join(", ")));
+            }
+        }
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
+
+// ============================================================================
+// COMPARISON
+// ============================================================================
+
+/// `KEY=VALUE` entries by key; later duplicates win as they do for getenv
+pub(crate) fn env_map(entries: &[String]) -> BTreeMap<String, String> {
+    entries
+        .iter()
+        .map(|entry| {
+            let (key, value) = entry.split_once('=').unwrap_or((entry.as_str(), ""));
+            (key.to_string(), value.to_string())
+        })
+        .collect()
+}
+
+/// Number of equal keys and the differing ones, sorted by key
+pub(crate) fn diff(a: &BTreeMap<String, String>, b: &BTreeMap<String, String>) -> (usize, Vec<ValueDifference>) {
+    let keys: BTreeSet<&String> = a.keys().chain(b.keys()).collect();
+    let mut same = 0;
+    let mut differences = Vec::new();
+    for key in keys {
+        let (value_a, value_b) = (a.get(key), b.get(key));
+        if value_a == value_b {
+            same += 1;
+        } else {
+            differences.push(ValueDifference {
+                key: key.to_string(),
+                a: value_a.cloned(),
+                b: value_b.cloned(),
+            });
+        }
+    }
+    (same, differences)
+}
+
+/// Limits whose soft or hard value differ, each side as `soft / hard [units]`
+pub(crate) fn diff_limits(a: Option<&[ResourceLimit]>, b: Option<&[ResourceLimit]>) -> Vec<ValueDifference> {
+    // A side that could not be read is not a difference
+    let (Some(a), Some(b)) = (a, b) else {
+        return Vec::new();
+    };
+    let render = |limits: &[ResourceLimit]| -> BTreeMap<String, String> {
+        limits
+            .iter()
+            .map(|limit| {
+                let units = limit.units.as_deref().map_or_else(String::new, |units| format!(" {units}"));
+                (limit.resource.clone(), format!("{} / {}{units}", limit.soft, limit.hard))
+            })
+            .collect()
+    };
+    diff(&render(a), &render(b)).1
+}
+
+/// Mask a present, non-empty value; returns how many were masked
+fn mask(value: &mut Option<String>) -> usize {
+    match value {
+        Some(value) if !value.is_empty() => {
+            *value = REDACTED.to_string();
+            1
+        }
+        _ => 0,
+    }
+}
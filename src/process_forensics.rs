@@ -0,0 +1,218 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::identity;
+use crate::platform;
+use crate::provider::ProcessEntry;
+use crate::schema::{
+    ForensicRecord, PROCESS_FORENSICS, ProcessForensicsArgs, ProcessForensicsOutput, ProcessForensicsPrompts,
+    SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessForensicsTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessForensicsTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessForensicsTool {
+    type Args = ProcessForensicsArgs;
+    type Prompts = ProcessForensicsPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_FORENSICS
+    }
+
+    fn description() -> &'static str {
+        "Capture everything about one process before killing it: command line, environment \
+         (secrets masked unless reveal_secrets), open files, sockets with addresses and TCP \
+         state, memory maps, threads, cgroup, namespaces and resource limits. The record is \
+         written as JSON to forensics/<pid>-<name>-<timestamp>.json under the output log \
+         directory; the response carries the path and per-section counts. Sections the OS \
+         does not provide (open files are read on Linux, macOS and Windows, the sections after \
+         them on Linux only) or the server may not read are listed in unavailable."
+    }
+
+    fn read_only() -> bool {
+        false // Writes the dump file
+    }
+
+    fn idempotent() -> bool {
+        false // Every call writes a new file
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessForensicsOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let redact = self.state.redactor.active(args.reveal_secrets)?;
+        let pid = args.pid;
+        let dir = self.config.output_log.root().join("forensics");
+        let state = self.state.clone();
+
+        // Procfs reads and the file write block
+        let (record, path, file_bytes) = tokio::task::spawn_blocking(move || {
+            let Some(process) = state.provider.lookup(pid, true) else {
+                return Err(McpError::ResourceNotFound(format!("Process {pid} not found")));
+            };
+            let mut record = capture(process);
+            if redact {
+                record.redacted = state.redactor.env(&mut record.env) + state.redactor.cmdline(&mut record.cmdline);
+            }
+            let (path, file_bytes) = write(&dir, &record)
+                .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to write forensic snapshot to {}: {e}", dir.display())))?;
+            Ok((record, path, file_bytes))
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to capture process {pid}: {e}")))??;
+
+        let counts = counts(&record);
+        let path = path.display().to_string();
+        let mut summary = format!(
+            "{} PID {pid} ({}){}{path}",
+            style.paint(Color::Cyan, "Forensic Snapshot:"),
+            record.name,
+            style.arrow()
+        );
+        if verbosity != SummaryVerbosity::Quiet {
+            let sections: Vec<String> = counts.iter().map(|(section, count)| format!("{count} {section}")).collect();
+            summary.push_str(&format!("\n{} {}", style.bullet(), sections.join(style.sep())));
+            if !record.unavailable.is_empty() {
+                summary.push_str(&format!("\n{} Unavailable: {}", style.bullet(), record.unavailable.join(", ")));
+            }
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            summary.push_str(&format!("\n{} {:.1} KB written", style.bullet(), file_bytes as f64 / 1024.0));
+            if record.redacted > 0 {
+                summary.push_str(&format!("{}{} secrets redacted", style.sep(), record.redacted));
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessForensicsOutput {
+                success: true,
+                pid,
+                name: record.name,
+                path,
+                file_bytes,
+                counts,
+                unavailable: record.unavailable,
+                redacted: record.redacted,
+            },
+        ))
+    }
+}
+
+// ============================================================================
+// CAPTURE
+// ============================================================================
+
+/// Read every section for `process`, noting the ones that could not be read
+fn capture(process: ProcessEntry) -> ForensicRecord {
+    let pid = process.pid;
+    let mut unavailable = Vec::new();
+    let mut note = |section: &str, found: bool| {
+        if !found {
+            unavailable.push(section.to_string());
+        }
+    };
+
+    let open_files = platform::open_files(pid);
+    let sockets = open_files.as_deref().and_then(|files| platform::sockets(pid, files));
+    let memory_maps = platform::memory_maps(pid);
+    let threads = platform::threads(pid);
+    let cgroup = platform::cgroup_paths(pid);
+    let namespaces = platform::namespaces(pid);
+    let limits = platform::resource_limits(pid);
+    note("exe", process.exe.is_some());
+    note("cwd", process.cwd.is_some());
+    note("env", !process.env.is_empty());
+    note("open_files", open_files.is_some());
+    note("sockets", sockets.is_some());
+    note("memory_maps", memory_maps.is_some());
+    note("threads", threads.is_some());
+    note("cgroup", cgroup.is_some());
+    note("namespaces", namespaces.is_some());
+    note("limits", limits.is_some());
+
+    ForensicRecord {
+        pid,
+        name: process.name,
+        parent: process.parent,
+        user: process.user,
+        start_time: process.start_time,
+        proc_id: identity::proc_id(pid, process.start_time),
+        captured_at: chrono::Utc::now().to_rfc3339(),
+        exe: process.exe.map(|exe| exe.display().to_string()),
+        cwd: process.cwd.map(|cwd| cwd.display().to_string()),
+        cmdline: process.cmdline,
+        env: process.env,
+        cpu_percent: process.cpu_percent,
+        rss_bytes: process.rss_bytes,
+        virtual_bytes: process.virtual_bytes,
+        open_files,
+        sockets,
+        memory_maps,
+        threads,
+        cgroup,
+        namespaces,
+        limits,
+        unavailable,
+        redacted: 0,
+    }
+}
+
+/// Entries per captured section
+fn counts(record: &ForensicRecord) -> BTreeMap<String, usize> {
+    let sections = [
+        ("env", Some(record.env.len())),
+        ("open_files", record.open_files.as_ref().map(Vec::len)),
+        ("sockets", record.sockets.as_ref().map(Vec::len)),
+        ("memory_maps", record.memory_maps.as_ref().map(Vec::len)),
+        ("threads", record.threads.as_ref().map(Vec::len)),
+        ("cgroup", record.cgroup.as_ref().map(Vec::len)),
+        ("namespaces", record.namespaces.as_ref().map(Vec::len)),
+        ("limits", record.limits.as_ref().map(Vec::len)),
+    ];
+    sections
+        .into_iter()
+        .filter_map(|(section, count)| Some((section.to_string(), count?)))
+        .collect()
+}
+
+/// Write `record` to `<dir>/<pid>-<name>-<timestamp>.json`; returns the path and its size
+fn write(dir: &std::path::Path, record: &ForensicRecord) -> std::io::Result<(PathBuf, u64)> {
+    std::fs::create_dir_all(dir)?;
+    let name: String = record
+        .name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') { c } else { '_' })
+        .collect();
+    let stamp = chrono::Utc::now().format("%Y%m%dT%H%M%S%.3fZ");
+    let path = dir.join(format!("{}-{name}-{stamp}.json", record.pid));
+    let json = serde_json::to_vec_pretty(record).map_err(std::io::Error::other)?;
+    std::fs::write(&path, &json)?;
+    Ok((path, json.len() as u64))
+}
@@ -0,0 +1,152 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::io::ErrorKind;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::identity;
+use crate::protection::protected_reason;
+use crate::schema::{
+    CpuLimitMethod, FreezeMethod, PROCESS_FREEZE, ProcessFreezeArgs, ProcessFreezeOutput, ProcessFreezePrompts,
+    SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+use crate::tree::descendants;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessFreezeTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessFreezeTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessFreezeTool {
+    type Args = ProcessFreezeArgs;
+    type Prompts = ProcessFreezePrompts;
+
+    fn name() -> &'static str {
+        PROCESS_FREEZE
+    }
+
+    fn description() -> &'static str {
+        "Freeze a process and all its descendants without the race of stopping them one by one \
+         while they keep forking. On Linux with cgroup v2 the tree moves into a managed cgroup \
+         and cgroup.freeze stops every member at once; otherwise SIGSTOP goes top-down, \
+         re-scanning for new children. Lasts until process_thaw or server shutdown. Refused if \
+         any member is protected or under a signals CPU limit."
+    }
+
+    fn read_only() -> bool {
+        false // Stops a process tree
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessFreezeOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+
+        let state = self.state.clone();
+        let (freeze, fallback_reason) = tokio::task::spawn_blocking(move || {
+            let processes = state.provider.enumerate();
+            let root = processes
+                .iter()
+                .find(|process| process.pid == pid)
+                .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?;
+            for process in std::iter::once(root).chain(descendants(&processes, pid)) {
+                if let Some(reason) = protected_reason(process.pid, &process.name, process.parent) {
+                    return Err(McpError::PermissionDenied(format!(
+                        "Refusing to freeze process tree {pid}: member {} ({}) is protected: {reason}",
+                        process.pid, process.name
+                    )));
+                }
+                // Its duty cycle would SIGCONT the member straight back
+                if state
+                    .cpu_limits
+                    .get(process.pid, process.start_time)
+                    .is_some_and(|limit| limit.method == CpuLimitMethod::Signals)
+                {
+                    return Err(McpError::InvalidArguments(format!(
+                        "Member {} of process tree {pid} has a signals CPU limit; lift it with process_cpu_unlimit first",
+                        process.pid
+                    )));
+                }
+            }
+            state
+                .freezes
+                .freeze(state.provider.as_ref(), root, args.method)
+                .map_err(|e| match e.kind() {
+                    ErrorKind::PermissionDenied => McpError::PermissionDenied(format!(
+                        "Failed to freeze process tree {pid}: {e} (needs root or a delegated cgroup v2 hierarchy for cgroup, or ownership of the processes for signals)"
+                    )),
+                    ErrorKind::AlreadyExists => {
+                        McpError::InvalidArguments(format!("Failed to freeze process tree {pid}: {e}"))
+                    }
+                    ErrorKind::Unsupported => McpError::InvalidArguments(format!(
+                        "Failed to freeze process tree {pid}: {e} (not supported on this platform)"
+                    )),
+                    _ => McpError::Other(anyhow::anyhow!("Failed to freeze process tree {pid}: {e}")),
+                })
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Freeze task failed: {e}")))??;
+
+        let mut summary = format!(
+            "{} PID {pid} ({}) and {} descendant(s) via {}",
+            style.paint(Color::Green, "Frozen:"),
+            freeze.name,
+            freeze.members.len().saturating_sub(1),
+            freeze.method.name()
+        );
+        if !freeze.settled {
+            summary.push_str(&format!(
+                "\n{} {}",
+                style.bullet(),
+                style.paint(Color::Red, "Not settled: some members may still be running")
+            ));
+        }
+        if verbosity != SummaryVerbosity::Quiet {
+            if freeze.method == FreezeMethod::Cgroup && !freeze.signalled.is_empty() {
+                summary.push_str(&format!(
+                    "\n{} SIGSTOP for {} member(s) in other cgroups",
+                    style.bullet(),
+                    freeze.signalled.len()
+                ));
+            }
+            if let Some(reason) = &fallback_reason {
+                summary.push_str(&format!("\n{} Not cgroup: {reason}", style.bullet()));
+            }
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            let members: Vec<String> = freeze.members.iter().map(u32::to_string).collect();
+            summary.push_str(&format!("\n{} Members: {}", style.bullet(), members.join(", ")));
+            if let Some(cgroup) = &freeze.cgroup {
+                summary.push_str(&format!("\n{} Cgroup: {cgroup}", style.bullet()));
+            }
+            summary.push_str(&format!("\n{} Since: {}", style.bullet(), freeze.since));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessFreezeOutput {
+                success: true,
+                freeze,
+                fallback_reason,
+            },
+        ))
+    }
+}
@@ -0,0 +1,161 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::identity;
+use crate::schema::{
+    PROCESS_GROUP_DEFINE, ProcessGroupDefineArgs, ProcessGroupDefineOutput, ProcessGroupDefinePrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessGroupDefineTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessGroupDefineTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessGroupDefineTool {
+    type Args = ProcessGroupDefineArgs;
+    type Prompts = ProcessGroupDefinePrompts;
+
+    fn name() -> &'static str {
+        PROCESS_GROUP_DEFINE
+    }
+
+    fn description() -> &'static str {
+        "Define a named logical process group from case-insensitive name globs (e.g. \"frontend\" = \
+         vite, node, esbuild) and/or explicit PIDs, replacing any group with the same name. Use the \
+         name with process_list (group filter), process_groups (stats) and process_group_kill. \
+         Pass delete=true to remove a group."
+    }
+
+    fn read_only() -> bool {
+        false // Modifies server-side group definitions
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessGroupDefineOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let name = args.name.trim().to_string();
+        if name.is_empty() {
+            return Err(McpError::InvalidArguments("Group name must not be empty".to_string()));
+        }
+
+        if args.delete {
+            if !self.state.groups.remove(&name) {
+                return Err(McpError::ResourceNotFound(format!("Process group {name:?} is not defined")));
+            }
+            return Ok(ToolResponse::new(
+                format!("{} {name}", style.paint(Color::Cyan, "Group Deleted:")),
+                ProcessGroupDefineOutput {
+                    success: true,
+                    name,
+                    deleted: true,
+                    patterns: Vec::new(),
+                    pids: Vec::new(),
+                    members: Vec::new(),
+                },
+            ));
+        }
+
+        if args.patterns.is_empty() && args.pids.is_empty() && args.proc_ids.is_empty() {
+            return Err(McpError::InvalidArguments(
+                "A group needs at least one pattern or PID".to_string(),
+            ));
+        }
+
+        let handles = args
+            .proc_ids
+            .iter()
+            .map(|proc_id| identity::parse(proc_id))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let state = self.state.clone();
+        let (group, members, missing) = tokio::task::spawn_blocking(move || {
+            let processes = state.provider.enumerate();
+
+            let mut pinned = Vec::new();
+            let mut missing = Vec::new();
+            for pid in args.pids {
+                match processes.iter().find(|process| process.pid == pid) {
+                    Some(process) => pinned.push((pid, process.start_time)),
+                    None => missing.push(pid),
+                }
+            }
+            // A handle whose PID was reused names a process that is gone
+            for (pid, start_time) in handles {
+                if processes.iter().any(|process| process.pid == pid && process.start_time == start_time) {
+                    pinned.push((pid, start_time));
+                } else {
+                    missing.push(pid);
+                }
+            }
+            let group = state.groups.define(&name, args.patterns, pinned)?;
+            let members = group.members(&processes);
+            Ok::<_, String>((group, members, missing))
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to define process group: {e}")))?
+        .map_err(McpError::InvalidArguments)?;
+
+        let mut summary = format!(
+            "{} {}: {} live members",
+            style.paint(Color::Cyan, "Group Defined:"),
+            group.name,
+            members.len()
+        );
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!(
+                "\n{} Patterns: {}{}PIDs: {}",
+                style.bullet(),
+                if group.patterns.is_empty() { "none".to_string() } else { group.patterns.join(", ") },
+                style.sep(),
+                group.pids.len()
+            ));
+            if !missing.is_empty() {
+                summary.push_str(&format!(
+                    "\n{} Not running (ignored): {}",
+                    style.bullet(),
+                    missing.iter().map(u32::to_string).collect::<Vec<_>>().join(", ")
+                ));
+            }
+        }
+        if verbosity == SummaryVerbosity::Detailed && !members.is_empty() {
+            summary.push_str(&format!(
+                "\n{} Members: {}",
+                style.bullet(),
+                members.iter().map(u32::to_string).collect::<Vec<_>>().join(", ")
+            ));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessGroupDefineOutput {
+                success: true,
+                name: group.name.clone(),
+                deleted: false,
+                patterns: group.patterns.clone(),
+                pids: group.pids.iter().map(|(pid, _)| *pid).collect(),
+                members,
+            },
+        ))
+    }
+}
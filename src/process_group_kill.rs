@@ -0,0 +1,280 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::confirm;
+use crate::display::{Color, ICON_KILL};
+use crate::events::ProcessEvent;
+use crate::killed::snapshot;
+use crate::platform;
+use crate::protection::protected_reason;
+use crate::provider::{ProcessEntry, SignalError};
+use crate::schema::{
+    DO_NOT_KILL_TAG, KillBudget, PROCESS_GROUP_KILL, ProcessGroupKillArgs, ProcessGroupKillOutput,
+    ProcessGroupKillPrompts, ProcessSignal, SignalResult, SkippedProcess, SummaryStyle, SummaryVerbosity,
+};
+use crate::signals::terminates;
+use crate::state::ProcessState;
+use crate::throttle::budget_text;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessGroupKillTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessGroupKillTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessGroupKillTool {
+    type Args = ProcessGroupKillArgs;
+    type Prompts = ProcessGroupKillPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_GROUP_KILL
+    }
+
+    fn description() -> &'static str {
+        "Send a signal (default SIGKILL; term, int, hup, quit, usr1, usr2 on Unix) to every live \
+         member of a named process group defined with process_group_define. Protected processes, \
+         the server itself and processes tagged do-not-kill are skipped. Each terminating signal \
+         consumes kill budget. Members the server's confirmation policy holds back are skipped \
+         unless confirm=true; only pass it after the user agreed. Use dry_run=true to preview the \
+         members first."
+    }
+
+    fn read_only() -> bool {
+        false // Signals processes
+    }
+
+    fn destructive() -> bool {
+        true // Terminates every member by default
+    }
+
+    fn idempotent() -> bool {
+        false // Members change between calls
+    }
+
+    async fn execute(&self, args: Self::Args, ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessGroupKillOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let group = self.state.groups.get(&args.group).ok_or_else(|| {
+            McpError::ResourceNotFound(format!("Process group {:?} is not defined", args.group))
+        })?;
+
+        let client = ctx.connection_id().map(str::to_string);
+        let state = self.state.clone();
+        let signal = args.signal;
+        let dry_run = args.dry_run;
+        let confirm = args.confirm;
+
+        let (outcomes, budget) = tokio::task::spawn_blocking(move || {
+            let mut members: Vec<_> = state
+                .provider
+                .enumerate()
+                .into_iter()
+                .filter(|process| group.matches(process.pid, process.start_time, &process.name))
+                .collect();
+            members.sort_unstable_by_key(|process| process.pid);
+            signal_each(&state, members, signal, dry_run, confirm, client.as_deref())
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to signal process group: {e}")))?;
+        let mut signalled = Vec::new();
+        let mut exited = Vec::new();
+        let mut skipped = Vec::new();
+        for result in outcomes {
+            match result.reason {
+                None => signalled.push(result.pid),
+                Some(_) if result.exited => exited.push(result.pid),
+                Some(reason) => skipped.push(SkippedProcess {
+                    pid: result.pid,
+                    name: result.name,
+                    reason,
+                }),
+            }
+        }
+
+        let headline = if dry_run {
+            format!(
+                "{} {} would receive {}: {} members",
+                style.paint(Color::Cyan, "Dry Run:"),
+                args.group,
+                signal.name(),
+                signalled.len()
+            )
+        } else {
+            style.paint(
+                Color::Red,
+                &format!(
+                    "{}Group {}: {} sent to {} members",
+                    style.icon(ICON_KILL),
+                    args.group,
+                    signal.name(),
+                    signalled.len()
+                ),
+            )
+        };
+        let mut summary = headline;
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!(
+                "\n{} Exited first: {}{}Skipped: {}{}Budget: {}",
+                style.bullet(),
+                exited.len(),
+                style.sep(),
+                skipped.len(),
+                style.sep(),
+                budget_text(&budget)
+            ));
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            if !signalled.is_empty() {
+                summary.push_str(&format!(
+                    "\n{} PIDs: {}",
+                    style.bullet(),
+                    signalled.iter().map(u32::to_string).collect::<Vec<_>>().join(", ")
+                ));
+            }
+            for skip in &skipped {
+                summary.push_str(&format!("\n  PID {} ({}): {}", skip.pid, skip.name, skip.reason));
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessGroupKillOutput {
+                success: true,
+                group: args.group,
+                signal: signal.name().to_string(),
+                dry_run,
+                signalled,
+                exited,
+                skipped,
+                budget,
+            },
+        ))
+    }
+}
+
+/// Reason [`signal_each`] gives for a process that was gone by its turn
+const EXITED_FIRST: &str = "exited before the signal";
+
+/// Send `signal` to each of `members` in order, skipping protected, tagged and
+/// unconfirmed processes
+///
+/// Terminating signals consume kill budget per process and are recorded like
+/// `process_kill`. On a dry run nothing is sent and every process that would
+/// be signalled reports `signalled`. Returns the budget left afterwards.
+pub(crate) fn signal_each(
+    state: &ProcessState,
+    members: Vec<ProcessEntry>,
+    signal: ProcessSignal,
+    dry_run: bool,
+    confirm: bool,
+    client: Option<&str>,
+) -> (Vec<SignalResult>, KillBudget) {
+    let record = !dry_run && terminates(signal) && state.killed.enabled();
+    let mut outcomes = Vec::with_capacity(members.len());
+    let mut budget = None;
+    for process in members {
+        let pid = process.pid;
+        let name = process.name.clone();
+
+        if let Some(reason) = protected_reason(pid, &name, process.parent) {
+            outcomes.push(outcome(pid, &name, Err(format!("protected: {reason}"))));
+            continue;
+        }
+        if state.tags.has(pid, process.start_time, DO_NOT_KILL_TAG) {
+            outcomes.push(outcome(pid, &name, Err(format!("tagged \"{DO_NOT_KILL_TAG}\""))));
+            continue;
+        }
+        if !confirm && let Some(reason) = state.confirm.reason(&name, state.spawned_by(pid, process.start_time).is_some()) {
+            outcomes.push(outcome(pid, &name, Err(confirm::ask(&reason))));
+            continue;
+        }
+        if dry_run {
+            outcomes.push(outcome(pid, &name, Ok(())));
+            continue;
+        }
+        let reservation = if terminates(signal) {
+            match state.throttle.acquire(client) {
+                Ok((reservation, left)) => {
+                    budget = Some(left);
+                    Some(reservation)
+                }
+                Err(reason) => {
+                    outcomes.push(outcome(pid, &name, Err(reason)));
+                    continue;
+                }
+            }
+        } else {
+            None
+        };
+
+        // cmdline, cwd and env are only loaded for processes about to be signalled
+        let entry = terminates(signal).then(|| {
+            let details = record.then(|| state.provider.lookup(pid, true)).flatten();
+            snapshot(details.unwrap_or(process))
+        });
+        match state.provider.signal(pid, signal) {
+            Ok(()) => {
+                if let Some(mut entry) = entry {
+                    entry.client = client.map(str::to_string);
+                    if record {
+                        entry.id = state.killed.record(entry.clone());
+                    }
+                    state.events.publish(ProcessEvent::Killed(entry));
+                }
+                outcomes.push(outcome(pid, &name, Ok(())));
+            }
+            Err(failure) => {
+                if let Some(reservation) = &reservation {
+                    budget = Some(state.throttle.release(reservation));
+                }
+                outcomes.push(match failure {
+                    SignalError::NotFound => SignalResult {
+                        exited: true,
+                        ..outcome(pid, &name, Err(EXITED_FIRST.to_string()))
+                    },
+                    SignalError::Denied => outcome(
+                        pid,
+                        &name,
+                        Err(match platform::permission_hint(pid) {
+                            Some(hint) => format!("permission denied: {hint}"),
+                            None => "permission denied".to_string(),
+                        }),
+                    ),
+                    SignalError::Unsupported => {
+                        outcome(pid, &name, Err(format!("{} is not supported on this platform", signal.name())))
+                    }
+                });
+            }
+        }
+    }
+    let budget = budget.unwrap_or_else(|| state.throttle.remaining(client));
+    (outcomes, budget)
+}
+
+/// Result for a member that was signalled (`Ok`) or left alone for the given reason
+fn outcome(pid: u32, name: &str, result: Result<(), String>) -> SignalResult {
+    SignalResult {
+        pid,
+        name: name.to_string(),
+        signalled: result.is_ok(),
+        exited: false,
+        reason: result.err(),
+    }
+}
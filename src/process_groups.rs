@@ -0,0 +1,116 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, human_bytes};
+use crate::schema::{
+    PROCESS_GROUPS, ProcessGroupInfo, ProcessGroupsArgs, ProcessGroupsOutput, ProcessGroupsPrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessGroupsTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessGroupsTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessGroupsTool {
+    type Args = ProcessGroupsArgs;
+    type Prompts = ProcessGroupsPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_GROUPS
+    }
+
+    fn description() -> &'static str {
+        "List named process groups defined with process_group_define, with their live members \
+         and aggregate CPU and resident memory. Optionally restrict to one group by name."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessGroupsOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let groups = match &args.name {
+            Some(name) => vec![self.state.groups.get(name).ok_or_else(|| {
+                McpError::ResourceNotFound(format!("Process group {name:?} is not defined"))
+            })?],
+            None => self.state.groups.all(),
+        };
+
+        let state = self.state.clone();
+        let groups = tokio::task::spawn_blocking(move || {
+            let processes = state.provider.enumerate();
+
+            groups
+                .iter()
+                .map(|group| {
+                    let members = group.members(&processes);
+                    let (cpu_percent, rss_bytes) = processes
+                        .iter()
+                        .filter(|process| members.binary_search(&process.pid).is_ok())
+                        .fold((0.0, 0), |(cpu, rss), process| (cpu + process.cpu_percent, rss + process.rss_bytes));
+                    ProcessGroupInfo {
+                        name: group.name.clone(),
+                        patterns: group.patterns.clone(),
+                        pids: group.pids.iter().map(|(pid, _)| *pid).collect(),
+                        members,
+                        cpu_percent,
+                        rss_bytes,
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to list process groups: {e}")))?;
+
+        let mut summary = format!("{}: {}", style.paint(Color::Cyan, "Process Groups"), groups.len());
+        if verbosity != SummaryVerbosity::Quiet {
+            for group in &groups {
+                summary.push_str(&format!(
+                    "\n{} {}: {} members{}CPU: {:.1}%{}Mem: {}",
+                    style.bullet(),
+                    group.name,
+                    group.members.len(),
+                    style.sep(),
+                    group.cpu_percent,
+                    style.sep(),
+                    human_bytes(group.rss_bytes)
+                ));
+                if verbosity == SummaryVerbosity::Detailed {
+                    summary.push_str(&format!("\n  Patterns: {}", group.patterns.join(", ")));
+                }
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessGroupsOutput {
+                success: true,
+                count: groups.len(),
+                groups,
+            },
+        ))
+    }
+}
@@ -0,0 +1,329 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::collections::HashSet;
+use std::sync::Arc;
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, human_bytes, truncate};
+use crate::identity;
+use crate::platform;
+use crate::provider::ProcessEntry;
+use crate::schema::{
+    PROCESS_INFO, ProcessDetails, ProcessInfoArgs, ProcessInfoOutput, ProcessInfoPrompts, ProcessMemory, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessInfoTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessInfoTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessInfoTool {
+    type Args = ProcessInfoArgs;
+    type Prompts = ProcessInfoPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_INFO
+    }
+
+    fn description() -> &'static str {
+        "Show everything about one process (by pid or proc_id): command line (secrets masked \
+         unless reveal_secrets), executable, working directory, user, parent, start time and \
+         age, scheduler state, thread count, cumulative CPU time, and memory split into \
+         resident, virtual and private bytes, plus anonymous, file-backed, shared and swapped \
+         on Linux. Also the controlling terminal, cgroup, security context (SELinux/AppArmor \
+         label and seccomp mode on Linux, integrity level and AppContainer on Windows) and any \
+         CPU limit from process_cpu_limit. Fields the OS does not provide are left out. Pass pids \
+         instead to read a list of processes in one pass (e.g. a whole tree); PIDs without a \
+         process come back in missing."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessInfoOutput>, McpError> {
+        self.info(args).await
+    }
+}
+
+impl ProcessInfoTool {
+    /// Inspect one process or a batch of PIDs; shared by the MCP tool and the REST and gRPC routes
+    pub(crate) async fn info(&self, args: ProcessInfoArgs) -> Result<ToolResponse<ProcessInfoOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let redact = self.state.redactor.active(args.reveal_secrets)?;
+        if !args.pids.is_empty() {
+            if args.pid.is_some() || args.proc_id.is_some() {
+                return Err(McpError::InvalidArguments(
+                    "Give either pids or one pid/proc_id, not both".to_string(),
+                ));
+            }
+            return self.batch(args.pids, redact, style, verbosity).await;
+        }
+
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+        let state = self.state.clone();
+        // Provider lookups and procfs reads block
+        let (mut processes, redacted) = tokio::task::spawn_blocking(move || inspect(&state, &[pid], redact))
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to inspect process {pid}: {e}")))?;
+        let process = processes
+            .pop()
+            .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?;
+
+        let summary = render(&process, style, verbosity);
+        Ok(ToolResponse::new(
+            summary,
+            ProcessInfoOutput {
+                success: true,
+                process: Some(process),
+                processes: Vec::new(),
+                missing: Vec::new(),
+                redacted,
+            },
+        ))
+    }
+
+    /// Inspect every PID of `pids` with one refresh, reporting the ones without a process
+    async fn batch(
+        &self,
+        mut pids: Vec<u32>,
+        redact: bool,
+        style: SummaryStyle,
+        verbosity: SummaryVerbosity,
+    ) -> Result<ToolResponse<ProcessInfoOutput>, McpError> {
+        let mut seen = HashSet::new();
+        pids.retain(|pid| seen.insert(*pid));
+        let state = self.state.clone();
+        let wanted = pids.clone();
+        let (processes, redacted) = tokio::task::spawn_blocking(move || inspect(&state, &wanted, redact))
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to inspect processes: {e}")))?;
+        let found: HashSet<u32> = processes.iter().map(|process| process.pid).collect();
+        let missing: Vec<u32> = pids.iter().copied().filter(|pid| !found.contains(pid)).collect();
+
+        let mut summary = format!(
+            "{} {} of {} process(es)",
+            style.paint(Color::Cyan, "Process Info:"),
+            processes.len(),
+            pids.len()
+        );
+        if !missing.is_empty() {
+            summary.push_str(&format!("{}{} missing", style.sep(), missing.len()));
+        }
+        if verbosity != SummaryVerbosity::Quiet {
+            for process in &processes {
+                summary.push_str(&format!(
+                    "\n{} PID {} ({}){}{}{}{} resident",
+                    style.bullet(),
+                    process.pid,
+                    process.name,
+                    style.sep(),
+                    process.status.as_deref().unwrap_or("unknown state"),
+                    style.sep(),
+                    human_bytes(process.memory.rss_bytes)
+                ));
+                if verbosity == SummaryVerbosity::Detailed {
+                    summary.push_str(&format!(
+                        "{}{}",
+                        style.sep(),
+                        truncate(&process.cmdline.join(" "), 120)
+                    ));
+                }
+            }
+            if !missing.is_empty() {
+                let missing: Vec<String> = missing.iter().map(u32::to_string).collect();
+                summary.push_str(&format!(
+                    "\n{} {} {}",
+                    style.bullet(),
+                    style.paint(Color::Red, "Not found:"),
+                    missing.join(", ")
+                ));
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessInfoOutput {
+                success: true,
+                process: None,
+                processes,
+                missing,
+                redacted,
+            },
+        ))
+    }
+}
+
+/// Summary of a single process
+fn render(process: &ProcessDetails, style: SummaryStyle, verbosity: SummaryVerbosity) -> String {
+    let mut summary = format!(
+        "{} PID {} ({}){}{}{}{} resident",
+        style.paint(Color::Cyan, "Process Info:"),
+        process.pid,
+        process.name,
+        style.sep(),
+        process.status.as_deref().unwrap_or("unknown state"),
+        style.sep(),
+        human_bytes(process.memory.rss_bytes)
+    );
+    if verbosity != SummaryVerbosity::Quiet {
+        summary.push_str(&format!(
+            "\n{} {}",
+            style.bullet(),
+            truncate(&process.cmdline.join(" "), if verbosity == SummaryVerbosity::Detailed { 400 } else { 120 })
+        ));
+        let mut facts = vec![format!("started {}", process.started_at), format!("CPU {:.1}s", process.cpu_time_secs)];
+        if let Some(user) = &process.user {
+            facts.insert(0, format!("User: {user}"));
+        }
+        if let Some(threads) = process.threads {
+            facts.push(format!("{threads} threads"));
+        }
+        if let Some(limit) = &process.cpu_limit {
+            facts.push(format!("CPU capped at {}%", limit.percent));
+        }
+        summary.push_str(&format!("\n{} {}", style.bullet(), facts.join(style.sep())));
+    }
+    if verbosity == SummaryVerbosity::Detailed {
+        if let Some(exe) = &process.exe {
+            summary.push_str(&format!("\n{} Exe: {exe}", style.bullet()));
+        }
+        if let Some(cwd) = &process.cwd {
+            summary.push_str(&format!("\n{} Cwd: {cwd}", style.bullet()));
+        }
+        let mut placement = Vec::new();
+        if let Some(tty) = &process.tty {
+            placement.push(format!("TTY {tty}"));
+        }
+        if let Some(cgroup) = &process.cgroup {
+            placement.push(format!("cgroup {cgroup}"));
+        }
+        if !placement.is_empty() {
+            summary.push_str(&format!("\n{} {}", style.bullet(), placement.join(style.sep())));
+        }
+        let mut security = Vec::new();
+        if let Some(label) = &process.security_label {
+            security.push(label.clone());
+        }
+        if let Some(seccomp) = process.seccomp {
+            security.push(format!("seccomp {}", seccomp.name()));
+        }
+        if let Some(level) = &process.integrity_level {
+            security.push(format!("{level} integrity"));
+        }
+        if process.app_container == Some(true) {
+            security.push("AppContainer".to_string());
+        }
+        if !security.is_empty() {
+            summary.push_str(&format!("\n{} Security: {}", style.bullet(), security.join(style.sep())));
+        }
+        let memory = &process.memory;
+        let mut parts = vec![format!("virtual {}", human_bytes(memory.virtual_bytes))];
+        for (label, bytes) in [
+            ("private", memory.private_bytes),
+            ("anon", memory.anon_bytes),
+            ("file", memory.file_bytes),
+            ("shmem", memory.shmem_bytes),
+            ("swap", memory.swap_bytes),
+        ] {
+            if let Some(bytes) = bytes {
+                parts.push(format!("{label} {}", human_bytes(bytes)));
+            }
+        }
+        summary.push_str(&format!("\n{} Memory: {}", style.bullet(), parts.join(style.sep())));
+    }
+    summary
+}
+
+/// Collect the details of the processes of `pids` that exist, with the masked argument count
+fn inspect(state: &ProcessState, pids: &[u32], redact: bool) -> (Vec<ProcessDetails>, usize) {
+    let entries = state.provider.lookup_many(pids, true);
+
+    // Status and CPU time are not in the provider's entry; skip them where a PID was reused meanwhile
+    let found: Vec<Pid> = entries.iter().map(|process| Pid::from_u32(process.pid)).collect();
+    let mut system = System::new();
+    system.refresh_processes_specifics(ProcessesToUpdate::Some(&found), true, ProcessRefreshKind::nothing().with_cpu());
+
+    let now = u64::try_from(chrono::Utc::now().timestamp()).unwrap_or(0);
+    let mut redacted = 0;
+    let details = entries
+        .into_iter()
+        .map(|mut process| {
+            if redact {
+                redacted += state.redactor.cmdline(&mut process.cmdline);
+            }
+            let native = system
+                .process(Pid::from_u32(process.pid))
+                .filter(|native| native.start_time() == process.start_time);
+            details(state, process, native, now)
+        })
+        .collect();
+    (details, redacted)
+}
+
+/// Details of one provider entry, completed from `native` and per-process OS reads
+fn details(state: &ProcessState, process: ProcessEntry, native: Option<&sysinfo::Process>, now: u64) -> ProcessDetails {
+    let pid = process.pid;
+    let private = platform::private_memory(pid);
+    let status = platform::memory_status(pid);
+    let started_at = i64::try_from(process.start_time)
+        .ok()
+        .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+        .map_or_else(|| process.start_time.to_string(), |started| started.to_rfc3339());
+    ProcessDetails {
+        pid,
+        proc_id: identity::proc_id(pid, process.start_time),
+        name: process.name,
+        parent: process.parent,
+        status: native.map(|native| native.status().to_string()),
+        user: process.user,
+        cmdline: process.cmdline,
+        exe: process.exe.map(|exe| exe.display().to_string()),
+        cwd: process.cwd.map(|cwd| cwd.display().to_string()),
+        start_time: process.start_time,
+        started_at,
+        age_secs: now.saturating_sub(process.start_time),
+        cpu_time_secs: native.map_or(0.0, |native| native.accumulated_cpu_time() as f64 / 1000.0),
+        threads: platform::threads(pid).map(|threads| threads.len()),
+        memory: ProcessMemory {
+            rss_bytes: process.rss_bytes,
+            virtual_bytes: process.virtual_bytes,
+            private_bytes: private.uss_bytes.or(private.private_bytes),
+            anon_bytes: status.map(|status| status.anon_bytes),
+            file_bytes: status.map(|status| status.file_bytes),
+            shmem_bytes: status.map(|status| status.shmem_bytes),
+            swap_bytes: status.map(|status| status.swap_bytes),
+        },
+        tty: platform::controlling_tty(pid),
+        cgroup: platform::cgroup_path(pid),
+        security_label: platform::security_label(pid),
+        seccomp: platform::seccomp_mode(pid),
+        integrity_level: platform::integrity_level(pid).map(str::to_string),
+        app_container: platform::is_app_container(pid),
+        cpu_limit: state.cpu_limits.get(pid, process.start_time),
+    }
+}
@@ -0,0 +1,152 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::identity;
+use crate::platform;
+use crate::protection::protected_reason;
+use crate::schema::{
+    IoClass, IoPriority, PROCESS_IONICE, ProcessIoniceArgs, ProcessIoniceOutput, ProcessIonicePrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::spawn::check_io_priority;
+use crate::state::ProcessState;
+
+/// Level used when a class is set without one (the kernel's default)
+const DEFAULT_IO_LEVEL: u8 = 4;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessIoniceTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessIoniceTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessIoniceTool {
+    type Args = ProcessIoniceArgs;
+    type Prompts = ProcessIonicePrompts;
+
+    fn name() -> &'static str {
+        PROCESS_IONICE
+    }
+
+    fn description() -> &'static str {
+        "Read or set the I/O scheduling of a process on Linux (ioprio). Pass only pid to read the \
+         class and level; pass class (\"idle\", \"best-effort\", \"realtime\" or \"none\") and \
+         optionally level (0 highest to 7) to change it. Use idle or a low best-effort level to \
+         keep disk-heavy background jobs from starving interactive work instead of killing them. \
+         realtime needs CAP_SYS_ADMIN; other users' processes need CAP_SYS_NICE."
+    }
+
+    fn read_only() -> bool {
+        false // Sets I/O priority when class is given
+    }
+
+    fn idempotent() -> bool {
+        true // Setting the same class again changes nothing
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessIoniceOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+
+        let requested = match (args.class, args.level) {
+            (Some(class), level) => Some(IoPriority {
+                class,
+                level: level.unwrap_or(DEFAULT_IO_LEVEL),
+            }),
+            (None, Some(_)) => {
+                return Err(McpError::InvalidArguments("level requires class".to_string()));
+            }
+            (None, None) => None,
+        };
+        if let Some(priority) = requested {
+            check_io_priority(priority).map_err(McpError::InvalidArguments)?;
+        }
+
+        let state = self.state.clone();
+        let (name, before, now) = tokio::task::spawn_blocking(move || {
+            let process = state
+                .provider
+                .lookup(pid, false)
+                .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?;
+            let read = || {
+                platform::io_priority(pid).ok_or_else(|| {
+                    McpError::Other(anyhow::anyhow!("Could not read the I/O priority of process {pid}"))
+                })
+            };
+            let before = read()?;
+            let Some(priority) = requested else {
+                return Ok((process.name, before, before));
+            };
+            if let Some(reason) = protected_reason(pid, &process.name, process.parent) {
+                return Err(McpError::PermissionDenied(format!("Refusing to change process {pid}: {reason}")));
+            }
+            platform::set_io_priority(pid, priority).map_err(|e| match e.kind() {
+                std::io::ErrorKind::PermissionDenied => McpError::PermissionDenied(format!(
+                    "Failed to set I/O priority of process {pid}: {e} (realtime needs CAP_SYS_ADMIN, other users' processes CAP_SYS_NICE)"
+                )),
+                _ => McpError::Other(anyhow::anyhow!("Failed to set I/O priority of process {pid}: {e}")),
+            })?;
+            Ok((process.name, before, read()?))
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("I/O priority task failed: {e}")))??;
+
+        let changed = requested.is_some();
+        let mut summary = if changed {
+            format!(
+                "{} PID {pid} now {}",
+                style.paint(Color::Green, "I/O Priority Set:"),
+                describe(now)
+            )
+        } else {
+            format!("{} PID {pid} is {}", style.paint(Color::Cyan, "I/O Priority:"), describe(now))
+        };
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!("\n{} Name: {name}", style.bullet()));
+            if changed {
+                summary.push_str(&format!("{}Before: {}", style.sep(), describe(before)));
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessIoniceOutput {
+                success: true,
+                pid,
+                name,
+                before,
+                io_priority: now,
+                changed,
+            },
+        ))
+    }
+}
+
+/// "idle", "best-effort level 4", "none (follows nice)"
+fn describe(priority: IoPriority) -> String {
+    match priority.class {
+        IoClass::None => "none (follows the nice value)".to_string(),
+        IoClass::Idle => "idle".to_string(),
+        IoClass::BestEffort => format!("best-effort level {}", priority.level),
+        IoClass::Realtime => format!("realtime level {}", priority.level),
+    }
+}
@@ -1,24 +1,39 @@
 use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
 use kodegen_mcp_schema::McpError;
-use kodegen_mcp_schema::process::{ProcessKillArgs, ProcessKillOutput, ProcessKillPrompts, PROCESS_KILL};
-use sysinfo::{Pid, ProcessesToUpdate, Signal, System};
+use kodegen_mcp_schema::process::{ProcessKillPrompts, PROCESS_KILL};
+use std::path::Path;
+use std::sync::Arc;
 
-// Compile-time platform validation for PID conversion safety
-// This ensures u32 → usize conversion cannot truncate
-#[cfg(not(any(target_pointer_width = "32", target_pointer_width = "64")))]
-compile_error!("ProcessKillTool only supports 32-bit and 64-bit platforms");
+use crate::config::ProcessConfig;
+use crate::container;
+use crate::display::{Color, ICON_INFO, ICON_KILL};
+use crate::platform;
+use crate::events::ProcessEvent;
+use crate::identity;
+use crate::killed::snapshot;
+use crate::pidfile;
+use crate::protection::kill_guard;
+use crate::provider::SignalError;
+use crate::schema::{
+    ContainerDelivery, ProcessKillArgs, ProcessKillOutput, ProcessSignal, SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+use crate::throttle::budget_text;
 
 // ============================================================================
 // TOOL STRUCT
 // ============================================================================
 
 #[derive(Clone, Default)]
-pub struct ProcessKillTool;
+pub struct ProcessKillTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
 
 impl ProcessKillTool {
     #[must_use]
-    pub fn new() -> Self {
-        Self
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
     }
 }
 
@@ -37,7 +52,13 @@ impl Tool for ProcessKillTool {
     fn description() -> &'static str {
         "Terminate a running process by its PID. Sends SIGKILL signal to forcefully stop the \
          process. Use with caution as this does not allow graceful shutdown. Returns success \
-         if process was terminated, error if process not found or permission denied."
+         if process was terminated, error if process not found or permission denied. Pass \
+         pidfile (optionally with expected_name) instead of pid for daemons managed that way; \
+         stale pidfiles are refused. Pass the proc_id from process_list (or pid with its \
+         start_time) to refuse the kill if the PID has been reused by another process since. Pass \
+         container as \"<container_id>:<pid>\" with the PID a container's logs show to deliver the \
+         signal through docker, podman or nerdctl, or nsenter without one (Linux). When the server's policy requires confirmation the kill is \
+         refused with the reason; ask the user and only then call again with confirm=true."
     }
 
     fn read_only() -> bool {
@@ -52,8 +73,87 @@ impl Tool for ProcessKillTool {
         false // Killing twice will fail (process no longer exists)
     }
 
-    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessKillOutput>, McpError> {
-        let pid = args.pid;
+    async fn execute(&self, args: Self::Args, ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessKillOutput>, McpError> {
+        self.kill(args, ctx.connection_id(), ctx.pwd()).await
+    }
+}
+
+// ============================================================================
+// KILL
+// ============================================================================
+
+impl ProcessKillTool {
+    /// Kill with all policy checks, independent of the MCP transport (shared with the REST routes)
+    ///
+    /// `client` keys the kill budget and `pwd` resolves relative pidfile paths.
+    pub async fn kill(
+        &self,
+        args: ProcessKillArgs,
+        client: Option<&str>,
+        pwd: Option<&Path>,
+    ) -> Result<ToolResponse<ProcessKillOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let mut expected_start = args.start_time;
+        let located = match args.container.as_deref() {
+            Some(_) if args.pid.is_some() || args.pidfile.is_some() || args.proc_id.is_some() || args.expected_name.is_some() => {
+                return Err(McpError::InvalidArguments(
+                    "container replaces pid, pidfile and proc_id; pass only one of them".to_string(),
+                ));
+            }
+            Some(target) => {
+                let target = container::parse(target)?;
+                let state = self.state.clone();
+                let located = tokio::task::spawn_blocking(move || {
+                    container::locate(&state, &target.container, target.pid).map(|located| (located, target.pid))
+                })
+                .await
+                .map_err(|e| McpError::Other(anyhow::anyhow!("Container lookup task failed: {e}")))??;
+                Some(located)
+            }
+            None => None,
+        };
+        let (pid, pidfile) = if let Some((located, _)) = &located {
+            (located.host_pid, None)
+        } else {
+            match (args.pid, &args.pidfile, args.proc_id.as_deref()) {
+                (_, None, _) if args.expected_name.is_some() => {
+                    return Err(McpError::InvalidArguments("expected_name requires pidfile".to_string()));
+                }
+                (Some(pid), None, None) => (pid, None),
+                (None, None, Some(proc_id)) => {
+                    if args.start_time.is_some() {
+                        return Err(McpError::InvalidArguments("proc_id already carries the start time; drop start_time".to_string()));
+                    }
+                    let (pid, start_time) = identity::parse(proc_id)?;
+                    expected_start = Some(start_time);
+                    (pid, None)
+                }
+                (None, Some(path), None) => {
+                    let path = pidfile::resolve(path, pwd);
+                    let expected = args.expected_name.clone();
+                    let status = tokio::task::spawn_blocking(move || pidfile::check(&path, expected.as_deref()))
+                        .await
+                        .map_err(|e| McpError::Other(anyhow::anyhow!("Pidfile check task failed: {e}")))?;
+                    match (status.stale, status.pid) {
+                        (false, Some(pid)) => (pid, Some(status.path)),
+                        _ => {
+                            return Err(McpError::InvalidArguments(format!(
+                                "Refusing to kill via stale pidfile {}: {}",
+                                status.path,
+                                status.reason.as_deref().unwrap_or("unknown")
+                            )));
+                        }
+                    }
+                }
+                _ => {
+                    return Err(McpError::InvalidArguments(
+                        "Pass exactly one of pid, pidfile, proc_id or container".to_string(),
+                    ));
+                }
+            }
+        };
 
         // Validate PID
         if pid == 0 {
@@ -62,50 +162,93 @@ impl Tool for ProcessKillTool {
             ));
         }
 
-        // Use spawn_blocking for sysinfo operations
+        // Reserve budget up front so concurrent calls cannot overshoot the cap
+        let (reservation, budget) = self
+            .state
+            .throttle
+            .acquire(client)
+            .map_err(|reason| McpError::PermissionDenied(format!("Refusing to kill process {pid}: {reason}")))?;
+
+        let record = self.state.killed.enabled();
+        let confirm = args.confirm;
+        let state = self.state.clone();
+        let inside = located.as_ref().map(|(located, container_pid)| (located.container_id.clone(), *container_pid));
+
+        // Provider lookups and signals block on OS calls
         let result = tokio::task::spawn_blocking(move || {
-            let mut system = System::new();
-            system.refresh_processes(ProcessesToUpdate::All, true);
-
-            // Platform-validated PID conversion
-            // Safe: u32 fits in usize on 32-bit and 64-bit platforms
-            #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
-            let sysinfo_pid = {
-                // Document why conversion is safe on supported platforms:
-                // - On 64-bit: usize = u64, conversion u32 → u64 is lossless
-                // - On 32-bit: usize = u32, conversion u32 → u32 is identity
-                Pid::from(pid as usize)
+            let Some(process) = state.provider.lookup(pid, record) else {
+                return Err("Process not found".to_string());
             };
-
-            #[cfg(not(any(target_pointer_width = "32", target_pointer_width = "64")))]
+            // The PID was recycled since the caller looked it up
+            if let Some(expected) = expected_start
+                && process.start_time != expected
             {
-                return Err("Process termination not supported on this platform");
+                return Err(format!(
+                    "start time {} does not match {expected}; the PID now belongs to another process",
+                    process.start_time
+                ));
             }
+            kill_guard(&state, pid, &process, confirm)?;
+            let process_name = process.name.clone();
+            let snapshot = snapshot(process);
 
-            if let Some(process) = system.process(sysinfo_pid) {
-                let process_name = process.name().to_string_lossy().to_string();
-                let killed = process.kill_with(Signal::Kill);
-
-                match killed {
-                    Some(true) => Ok(process_name),
-                    Some(false) => Err("Permission denied or process protected"),
-                    None => Err("Failed to send kill signal"),
-                }
-            } else {
-                Err("Process not found")
+            if let Some((container_id, container_pid)) = inside {
+                return container::signal(&container_id, container_pid, pid, ProcessSignal::Kill)
+                    .map(|via| (process_name, snapshot, Some(via)))
+                    .map_err(|reason| format!("delivery inside container {} failed: {reason}", container::short(&container_id)));
+            }
+            match state.provider.kill(pid) {
+                Ok(()) => Ok((process_name, snapshot, None)),
+                Err(SignalError::NotFound) => Err("Process not found".to_string()),
+                Err(SignalError::Denied) => Err(match platform::permission_hint(pid) {
+                    Some(hint) => format!("Permission denied or process protected: {hint}"),
+                    None => "Permission denied or process protected".to_string(),
+                }),
+                Err(SignalError::Unsupported) => Err("Failed to send kill signal".to_string()),
             }
         })
         .await
-        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to kill process: {e}")))?;
+        .map_err(|e| {
+            self.state.throttle.release(&reservation);
+            McpError::Other(anyhow::anyhow!("Failed to kill process: {e}"))
+        })?;
 
         match result {
-            Ok(_process_name) => {
-                // Human-readable summary with ANSI red color and Nerd Font icons
-                let summary = format!(
-                    "\x1b[31m Process Killed: PID {}\x1b[0m\n\
-                      Signal: SIGKILL · Status: terminated",
-                    pid
+            Ok((process_name, snapshot, via)) => {
+                let mut entry = snapshot;
+                entry.client = client.map(str::to_string);
+                let record_id = record.then(|| self.state.killed.record(entry.clone()));
+                entry.id = record_id.unwrap_or_default();
+                self.state.events.publish(ProcessEvent::Killed(entry));
+
+                // Human-readable summary (red + Nerd Font icons in ANSI style)
+                let mut summary = style.paint(
+                    Color::Red,
+                    &format!("{}Process Killed: PID {pid}", style.icon(ICON_KILL)),
                 );
+                if let Some((located, container_pid)) = &located {
+                    summary.push_str(&format!(" (PID {container_pid} in container {})", container::short(&located.container_id)));
+                }
+                if verbosity != SummaryVerbosity::Quiet {
+                    summary.push_str(&format!(
+                        "\n{}Signal: SIGKILL{}Status: terminated",
+                        style.icon(ICON_INFO),
+                        style.sep(),
+                    ));
+                    summary.push_str(&format!("\n{} Budget: {}", style.bullet(), budget_text(&budget)));
+                }
+                if verbosity == SummaryVerbosity::Detailed {
+                    summary.push_str(&format!("\n{} Name: {process_name}", style.bullet()));
+                    if let Some(pidfile) = &pidfile {
+                        summary.push_str(&format!("\n{} Pidfile: {pidfile}", style.bullet()));
+                    }
+                    if let Some(via) = via {
+                        summary.push_str(&format!("\n{} Delivered by: {via}", style.bullet()));
+                    }
+                    if let Some(id) = record_id {
+                        summary.push_str(&format!("\n{} Recorded: #{id} (process_recently_killed)", style.bullet()));
+                    }
+                }
 
                 Ok(ToolResponse::new(
                     summary,
@@ -113,12 +256,25 @@ impl Tool for ProcessKillTool {
                         success: true,
                         pid,
                         message: format!("Successfully terminated process {}", pid),
+                        budget,
+                        pidfile,
+                        record_id,
+                        container: located.map(|(located, container_pid)| ContainerDelivery {
+                            id: located.container_id,
+                            pid: container_pid,
+                            via: via.map(str::to_string),
+                        }),
                     },
                 ))
             }
-            Err(reason) => Err(McpError::PermissionDenied(format!(
-                "Failed to kill process {pid}: {reason}"
-            ))),
+            Err(reason) => {
+                // Failed attempts do not count against the budget
+                let budget = self.state.throttle.release(&reservation);
+                Err(McpError::PermissionDenied(format!(
+                    "Failed to kill process {pid}: {reason} (budget: {})",
+                    budget_text(&budget)
+                )))
+            }
         }
     }
 }
@@ -0,0 +1,200 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use globset::GlobBuilder;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, ICON_KILL};
+use crate::process_group_kill::signal_each;
+use crate::provider::ProcessEntry;
+use crate::schema::{
+    PROCESS_KILL_BY_NAME, ProcessKillByNameArgs, ProcessKillByNameOutput, ProcessKillByNamePrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::spawn::SPAWN_MARKER_ENV;
+use crate::state::ProcessState;
+use crate::throttle::budget_text;
+use crate::tree;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessKillByNameTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessKillByNameTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessKillByNameTool {
+    type Args = ProcessKillByNameArgs;
+    type Prompts = ProcessKillByNamePrompts;
+
+    fn name() -> &'static str {
+        PROCESS_KILL_BY_NAME
+    }
+
+    fn description() -> &'static str {
+        "Send a signal (default SIGKILL; term, int, hup, quit, usr1, usr2 on Unix) to every \
+         process whose name matches pattern, a case-insensitive glob such as \"node\" or \
+         \"esbuild*\". launched_only=true restricts it to processes launched through this server \
+         and their descendants, e.g. what a test run left behind. Returns one result per \
+         matching PID with whether it was signalled and why not. Protected processes, the server \
+         itself and processes tagged do-not-kill are skipped; each terminating signal consumes \
+         kill budget. Matches the confirmation policy holds back are skipped unless \
+         confirm=true; only pass it after the user agreed. Use dry_run=true to preview."
+    }
+
+    fn read_only() -> bool {
+        false // Signals processes
+    }
+
+    fn destructive() -> bool {
+        true // Terminates every match by default
+    }
+
+    fn idempotent() -> bool {
+        false // Matches change between calls
+    }
+
+    async fn execute(&self, args: Self::Args, ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessKillByNameOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        if args.pattern.trim().is_empty() {
+            return Err(McpError::InvalidArguments("pattern must not be empty".to_string()));
+        }
+        let matcher = GlobBuilder::new(&args.pattern)
+            .case_insensitive(true)
+            .build()
+            .map_err(|e| McpError::InvalidArguments(format!("Invalid pattern {:?}: {e}", args.pattern)))?
+            .compile_matcher();
+
+        let client = ctx.connection_id().map(str::to_string);
+        let state = self.state.clone();
+        let signal = args.signal;
+        let dry_run = args.dry_run;
+        let confirm = args.confirm;
+        let launched_only = args.launched_only;
+
+        let (results, budget) = tokio::task::spawn_blocking(move || {
+            let processes = state.provider.enumerate();
+            let launched = launched_only.then(|| launches(&state, &processes));
+            let mut matches: Vec<ProcessEntry> = processes
+                .iter()
+                .filter(|process| matcher.is_match(&process.name))
+                .filter(|process| {
+                    launched
+                        .as_ref()
+                        .is_none_or(|launched| launched.contains(&process.pid) || marked(&state, process.pid))
+                })
+                .cloned()
+                .collect();
+            matches.sort_unstable_by_key(|process| process.pid);
+            signal_each(&state, matches, signal, dry_run, confirm, client.as_deref())
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to signal matching processes: {e}")))?;
+
+        let signalled = results.iter().filter(|result| result.signalled).count();
+        let exited = results.iter().filter(|result| result.exited).count();
+        let skipped = results.len() - signalled - exited;
+
+        let mut summary = if results.is_empty() {
+            format!(
+                "{} No {}process matches {:?}",
+                style.paint(Color::Cyan, "Nothing To Do:"),
+                if launched_only { "launched " } else { "" },
+                args.pattern
+            )
+        } else if dry_run {
+            format!(
+                "{} {} of {} matches for {:?} would receive {}",
+                style.paint(Color::Cyan, "Dry Run:"),
+                signalled,
+                results.len(),
+                args.pattern,
+                signal.name()
+            )
+        } else {
+            style.paint(
+                Color::Red,
+                &format!(
+                    "{}{} sent to {} of {} matches for {:?}",
+                    style.icon(ICON_KILL),
+                    signal.name(),
+                    signalled,
+                    results.len(),
+                    args.pattern
+                ),
+            )
+        };
+        if verbosity != SummaryVerbosity::Quiet && !results.is_empty() {
+            summary.push_str(&format!(
+                "\n{} Exited first: {exited}{}Skipped: {skipped}{}Budget: {}",
+                style.bullet(),
+                style.sep(),
+                style.sep(),
+                budget_text(&budget)
+            ));
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            for result in &results {
+                summary.push_str(&format!(
+                    "\n  PID {} ({}): {}",
+                    result.pid,
+                    result.name,
+                    result.reason.as_deref().unwrap_or(if dry_run { "would be signalled" } else { "signalled" })
+                ));
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessKillByNameOutput {
+                success: signalled > 0 && skipped == 0,
+                pattern: args.pattern,
+                signal: signal.name().to_string(),
+                dry_run,
+                signalled,
+                results,
+                budget,
+            },
+        ))
+    }
+}
+
+/// PIDs launched by a kodegen server, with everything below them in the tree
+fn launches(state: &ProcessState, processes: &[ProcessEntry]) -> HashSet<u32> {
+    let mut launched = HashSet::new();
+    for process in processes {
+        if state.spawned_by(process.pid, process.start_time).is_some() {
+            launched.insert(process.pid);
+            launched.extend(tree::descendants(processes, process.pid).into_iter().map(|child| child.pid));
+        }
+    }
+    launched
+}
+
+/// Whether `pid` inherited the spawn marker of one of this server's launches,
+/// which survives reparenting when the launch itself has exited
+fn marked(state: &ProcessState, pid: u32) -> bool {
+    state.provider.lookup(pid, true).is_some_and(|details| {
+        details
+            .env
+            .iter()
+            .filter_map(|entry| entry.strip_prefix(SPAWN_MARKER_ENV)?.strip_prefix('='))
+            .any(|marker| state.supervisor.by_marker(marker).is_some())
+    })
+}
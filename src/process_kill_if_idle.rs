@@ -0,0 +1,325 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use sysinfo::{Pid, ProcessesToUpdate, System};
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::identity;
+use crate::process_terminate::{ProcessTerminateTool, check_plan};
+use crate::protection::kill_guard;
+use crate::schema::{
+    IdleVerdict, MAX_IDLE_WINDOW_SECS, PROCESS_KILL_IF_IDLE, ProcessKillIfIdleArgs,
+    ProcessKillIfIdleOutput, ProcessKillIfIdlePrompts, ProcessTerminateArgs, SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+/// Time between CPU samples
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessKillIfIdleTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessKillIfIdleTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessKillIfIdleTool {
+    type Args = ProcessKillIfIdleArgs;
+    type Prompts = ProcessKillIfIdlePrompts;
+
+    fn name() -> &'static str {
+        PROCESS_KILL_IF_IDLE
+    }
+
+    fn description() -> &'static str {
+        "Terminate a process only if it is idle: CPU is sampled every second for window_secs \
+         (default 30) and must stay below cpu_below percent (default 1) throughout. With \
+         children (default) descendants' CPU counts too, and a child starting or exiting counts \
+         as activity. Active processes are left alone and reported \"still active, not killed\" \
+         with the sample that showed it; idle ones get the escalation plan of process_terminate \
+         (default: term, wait 5 s, kill). The safe way to reap abandoned dev servers."
+    }
+
+    fn read_only() -> bool {
+        false // Kills the process when idle
+    }
+
+    fn destructive() -> bool {
+        true // Ends with SIGKILL unless the plan says otherwise
+    }
+
+    fn idempotent() -> bool {
+        false // The process is gone after an idle verdict
+    }
+
+    async fn execute(&self, args: Self::Args, ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessKillIfIdleOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+
+        if pid == 0 {
+            return Err(McpError::InvalidArguments(
+                "Invalid PID 0: cannot kill process with ID 0".to_string(),
+            ));
+        }
+        if !args.cpu_below.is_finite() || args.cpu_below <= 0.0 {
+            return Err(McpError::InvalidArguments("cpu_below must be above 0".to_string()));
+        }
+        if args.window_secs == 0 || args.window_secs > MAX_IDLE_WINDOW_SECS {
+            return Err(McpError::InvalidArguments(format!(
+                "window_secs must be 1 to {MAX_IDLE_WINDOW_SECS}"
+            )));
+        }
+        check_plan(&args.plan)?;
+
+        // Checked up front so a refusal does not cost a whole window
+        let confirm = args.confirm;
+        let state = self.state.clone();
+        let target = tokio::task::spawn_blocking(move || {
+            let Some(process) = state.provider.lookup(pid, false) else {
+                return Err(McpError::ResourceNotFound(format!("Process {pid} not found")));
+            };
+            kill_guard(&state, pid, &process, confirm)
+                .map_err(|reason| McpError::PermissionDenied(format!("Refusing to kill process {pid}: {reason}")))?;
+            Ok(process)
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Lookup task failed: {e}")))??;
+
+        let observation = observe(
+            pid,
+            target.start_time,
+            args.cpu_below,
+            Duration::from_secs(args.window_secs),
+            args.children,
+        )
+        .await?;
+
+        let terminate = if observation.verdict == IdleVerdict::Idle {
+            let terminate_args = ProcessTerminateArgs {
+                pid: None,
+                // Pinned so a PID reused during the observation window is not hit
+                proc_id: Some(identity::proc_id(pid, target.start_time)),
+                container: None,
+                plan: args.plan,
+                confirm: true,
+                style: args.style,
+                verbosity: Some(SummaryVerbosity::Quiet),
+            };
+            let response = ProcessTerminateTool::new(self.config.clone(), self.state.clone())
+                .terminate(terminate_args, ctx.connection_id())
+                .await?;
+            Some(response.metadata)
+        } else {
+            None
+        };
+        let killed = terminate.as_ref().is_some_and(|terminate| terminate.success);
+
+        let mut summary = match (observation.verdict, &terminate) {
+            (IdleVerdict::Idle, Some(terminate)) if killed => style.paint(
+                Color::Red,
+                &format!(
+                    "Idle, killed: PID {pid} ({}) by {}",
+                    target.name,
+                    terminate.succeeded_signal.as_deref().unwrap_or("signal")
+                ),
+            ),
+            (IdleVerdict::Idle, _) => format!(
+                "{} PID {pid} ({}) was idle but survived the plan",
+                style.paint(Color::Cyan, "Still Running:"),
+                target.name
+            ),
+            (IdleVerdict::Exited, _) => format!(
+                "{} PID {pid} ({}) exited during the window",
+                style.paint(Color::Cyan, "Gone:"),
+                target.name
+            ),
+            _ => format!(
+                "{} PID {pid} ({}), not killed",
+                style.paint(Color::Green, "Still active:"),
+                target.name
+            ),
+        };
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!(
+                "\n{} {}{}Observed {:.0} s{}Peak {:.1}%{}Mean {:.1}%",
+                style.bullet(),
+                observation.reason,
+                style.sep(),
+                observation.observed_secs,
+                style.sep(),
+                observation.peak_cpu_percent,
+                style.sep(),
+                observation.mean_cpu_percent
+            ));
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            summary.push_str(&format!(
+                "\n{} Samples: {}{}Descendants: {}",
+                style.bullet(),
+                observation.samples,
+                style.sep(),
+                observation.descendants
+            ));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessKillIfIdleOutput {
+                success: true,
+                pid,
+                name: target.name,
+                killed,
+                verdict: observation.verdict,
+                reason: observation.reason,
+                observed_secs: observation.observed_secs,
+                samples: observation.samples,
+                peak_cpu_percent: observation.peak_cpu_percent,
+                mean_cpu_percent: observation.mean_cpu_percent,
+                descendants: observation.descendants,
+                terminate,
+            },
+        ))
+    }
+}
+
+// ============================================================================
+// OBSERVATION
+// ============================================================================
+
+struct Observation {
+    verdict: IdleVerdict,
+    reason: String,
+    observed_secs: f64,
+    samples: usize,
+    peak_cpu_percent: f32,
+    mean_cpu_percent: f32,
+    descendants: usize,
+}
+
+/// Sample the target every [`SAMPLE_INTERVAL`] until `window` has passed or it shows activity
+async fn observe(
+    pid: u32,
+    start_time: u64,
+    cpu_below: f32,
+    window: Duration,
+    children: bool,
+) -> Result<Observation, McpError> {
+    let began = Instant::now();
+    let mut system = refresh(System::new()).await?;
+    let initial = descendants(&system, pid);
+    let mut observation = Observation {
+        verdict: IdleVerdict::Idle,
+        reason: String::new(),
+        observed_secs: 0.0,
+        samples: 0,
+        peak_cpu_percent: 0.0,
+        mean_cpu_percent: 0.0,
+        descendants: if children { initial.len() } else { 0 },
+    };
+    let mut total = 0.0;
+
+    while began.elapsed() < window {
+        tokio::time::sleep(SAMPLE_INTERVAL).await;
+        system = refresh(system).await?;
+        let at = began.elapsed().as_secs_f64();
+        observation.observed_secs = at;
+
+        let alive = system
+            .process(Pid::from_u32(pid))
+            .is_some_and(|process| process.start_time() == start_time);
+        if !alive {
+            observation.verdict = IdleVerdict::Exited;
+            observation.reason = format!("exited after {at:.0} s");
+            break;
+        }
+        let current = descendants(&system, pid);
+        let mut cpu = system.process(Pid::from_u32(pid)).map_or(0.0, sysinfo::Process::cpu_usage);
+        if children {
+            cpu += current
+                .iter()
+                .filter_map(|(child, _)| system.process(Pid::from_u32(*child)))
+                .map(sysinfo::Process::cpu_usage)
+                .sum::<f32>();
+        }
+        observation.samples += 1;
+        total += cpu;
+        observation.peak_cpu_percent = observation.peak_cpu_percent.max(cpu);
+
+        if cpu >= cpu_below {
+            observation.verdict = IdleVerdict::Active;
+            observation.reason = format!("{cpu:.1}% CPU at {at:.0} s, threshold {cpu_below}%");
+            break;
+        }
+        if children && current != initial {
+            let started = current.difference(&initial).count();
+            let exited = initial.difference(&current).count();
+            observation.verdict = IdleVerdict::ChildActivity;
+            observation.reason = format!("{started} child(ren) started and {exited} exited by {at:.0} s");
+            break;
+        }
+    }
+
+    if observation.samples > 0 {
+        observation.mean_cpu_percent = total / observation.samples as f32;
+    }
+    if observation.verdict == IdleVerdict::Idle {
+        observation.reason = format!("below {cpu_below}% CPU for {:.0} s", observation.observed_secs);
+    }
+    Ok(observation)
+}
+
+/// Refresh every process; sysinfo blocks on the OS
+async fn refresh(mut system: System) -> Result<System, McpError> {
+    tokio::task::spawn_blocking(move || {
+        system.refresh_processes(ProcessesToUpdate::All, true);
+        system
+    })
+    .await
+    .map_err(|e| McpError::Other(anyhow::anyhow!("Sampling task failed: {e}")))
+}
+
+/// Descendants of `root` as (pid, start_time)
+///
+/// Threads are skipped: their CPU is already part of their process, and a
+/// thread pool growing is not child activity.
+fn descendants(system: &System, root: u32) -> BTreeSet<(u32, u64)> {
+    let mut children: HashMap<u32, Vec<(u32, u64)>> = HashMap::new();
+    for (pid, process) in system.processes() {
+        if process.thread_kind().is_none()
+            && let Some(parent) = process.parent()
+        {
+            children
+                .entry(parent.as_u32())
+                .or_default()
+                .push((pid.as_u32(), process.start_time()));
+        }
+    }
+    let mut found = BTreeSet::new();
+    let mut queue = vec![root];
+    while let Some(pid) = queue.pop() {
+        for child in children.get(&pid).into_iter().flatten() {
+            if found.insert(*child) {
+                queue.push(child.0);
+            }
+        }
+    }
+    found
+}
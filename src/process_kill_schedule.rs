@@ -0,0 +1,179 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::identity;
+use crate::process_terminate::check_plan;
+use crate::protection::kill_guard;
+use crate::scheduler;
+use crate::schema::{
+    MAX_SCHEDULE_DELAY_SECS, PROCESS_KILL_SCHEDULE, ProcessKillScheduleArgs,
+    ProcessKillScheduleOutput, ProcessKillSchedulePrompts, ScheduleStatus, ScheduledKill, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessKillScheduleTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessKillScheduleTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessKillScheduleTool {
+    type Args = ProcessKillScheduleArgs;
+    type Prompts = ProcessKillSchedulePrompts;
+
+    fn name() -> &'static str {
+        PROCESS_KILL_SCHEDULE
+    }
+
+    fn description() -> &'static str {
+        "Schedule a kill for later: after delay_secs or at an RFC 3339 time (up to 7 days \
+         ahead). When due, the escalation plan runs as with process_terminate (default: term, \
+         wait 5 s, kill) and consumes kill budget then. If the process exits first, or its PID \
+         is reused, nothing is sent. Do-not-kill tags and the confirmation policy are checked \
+         now; the tag is checked again when due. Cancel with process_kill_unschedule. Pending \
+         kills are dropped when the server restarts."
+    }
+
+    fn read_only() -> bool {
+        false // Kills a process later
+    }
+
+    fn destructive() -> bool {
+        true // Ends with SIGKILL unless the plan says otherwise
+    }
+
+    fn idempotent() -> bool {
+        false // Every call schedules another kill
+    }
+
+    async fn execute(&self, args: Self::Args, ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessKillScheduleOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+
+        if pid == 0 {
+            return Err(McpError::InvalidArguments(
+                "Invalid PID 0: cannot kill process with ID 0".to_string(),
+            ));
+        }
+        check_plan(&args.plan)?;
+        let now = chrono::Utc::now();
+        let delay = match (args.delay_secs, &args.at) {
+            (Some(secs), None) => Duration::from_secs(secs),
+            (None, Some(at)) => {
+                let due = chrono::DateTime::parse_from_rfc3339(at)
+                    .map_err(|e| McpError::InvalidArguments(format!("Invalid at {at:?}: {e} (expected RFC 3339)")))?;
+                (due.with_timezone(&chrono::Utc) - now)
+                    .to_std()
+                    .map_err(|_| McpError::InvalidArguments(format!("at {at:?} is in the past")))?
+            }
+            _ => {
+                return Err(McpError::InvalidArguments(
+                    "Pass exactly one of delay_secs or at".to_string(),
+                ));
+            }
+        };
+        if delay.as_secs() > MAX_SCHEDULE_DELAY_SECS {
+            return Err(McpError::InvalidArguments(format!(
+                "Kills can be scheduled at most {MAX_SCHEDULE_DELAY_SECS} s (7 days) ahead"
+            )));
+        }
+
+        let confirm = args.confirm;
+        let state = self.state.clone();
+        let target = tokio::task::spawn_blocking(move || {
+            let Some(process) = state.provider.lookup(pid, false) else {
+                return Err(McpError::ResourceNotFound(format!("Process {pid} not found")));
+            };
+            kill_guard(&state, pid, &process, confirm).map_err(|reason| {
+                McpError::PermissionDenied(format!("Refusing to schedule a kill of process {pid}: {reason}"))
+            })?;
+            Ok(process)
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Lookup task failed: {e}")))??;
+
+        let due = now + chrono::Duration::from_std(delay).unwrap_or_default();
+        let info = ScheduledKill {
+            id: 0,
+            pid,
+            name: target.name.clone(),
+            plan: args.plan,
+            note: args.note,
+            scheduled_at: now.to_rfc3339(),
+            due_at: due.to_rfc3339(),
+            status: ScheduleStatus::Pending,
+            outcome: None,
+            finished_at: None,
+        };
+        let kill = scheduler::schedule(
+            self.config.clone(),
+            self.state.clone(),
+            info,
+            target.start_time,
+            ctx.connection_id().map(str::to_string),
+            delay,
+        )
+        .map_err(McpError::InvalidArguments)?;
+
+        let mut summary = format!(
+            "{} #{} kills PID {pid} ({}) in {}",
+            style.paint(Color::Cyan, "Kill scheduled:"),
+            kill.id,
+            kill.name,
+            delay_text(delay)
+        );
+        if verbosity != SummaryVerbosity::Quiet {
+            let plan: Vec<String> = kill.plan.iter().map(|step| step.signal.name().to_string()).collect();
+            summary.push_str(&format!(
+                "\n{} Due: {}{}Plan: {}",
+                style.bullet(),
+                kill.due_at,
+                style.sep(),
+                plan.join(style.arrow())
+            ));
+        }
+        if verbosity == SummaryVerbosity::Detailed
+            && let Some(note) = &kill.note
+        {
+            summary.push_str(&format!("\n{} Note: {note}", style.bullet()));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessKillScheduleOutput { success: true, kill },
+        ))
+    }
+}
+
+/// "1h 30m", "45s"
+fn delay_text(delay: Duration) -> String {
+    let secs = delay.as_secs();
+    match (secs / 3600, secs % 3600 / 60, secs % 60) {
+        (0, 0, s) => format!("{s}s"),
+        (0, m, 0) => format!("{m}m"),
+        (0, m, s) => format!("{m}m {s}s"),
+        (h, 0, _) => format!("{h}h"),
+        (h, m, _) => format!("{h}h {m}m"),
+    }
+}
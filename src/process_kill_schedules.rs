@@ -0,0 +1,102 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, DETAIL_ROWS};
+use crate::schema::{
+    PROCESS_KILL_SCHEDULES, ProcessKillSchedulesArgs, ProcessKillSchedulesOutput, ProcessKillSchedulesPrompts,
+    SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessKillSchedulesTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessKillSchedulesTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessKillSchedulesTool {
+    type Args = ProcessKillSchedulesArgs;
+    type Prompts = ProcessKillSchedulesPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_KILL_SCHEDULES
+    }
+
+    fn description() -> &'static str {
+        "List kills scheduled with process_kill_schedule: pending ones soonest first with their \
+         due time, plan and note, and the last 16 that ran, were skipped because the process \
+         had exited, or were cancelled, with their outcome."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        false // Pending kills run as they fall due
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessKillSchedulesOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let (pending, recent) = self.state.scheduler.all();
+
+        let mut summary = format!(
+            "{} {} pending{}{} recent",
+            style.paint(Color::Cyan, "Scheduled Kills:"),
+            pending.len(),
+            style.sep(),
+            recent.len()
+        );
+        let shown = match verbosity {
+            SummaryVerbosity::Quiet => 0,
+            SummaryVerbosity::Normal => DETAIL_ROWS,
+            SummaryVerbosity::Detailed => pending.len() + recent.len(),
+        };
+        for kill in pending.iter().chain(&recent).take(shown) {
+            summary.push_str(&format!(
+                "\n{} #{} PID {} ({}): {} {}",
+                style.bullet(),
+                kill.id,
+                kill.pid,
+                kill.name,
+                kill.status.name(),
+                kill.finished_at.as_deref().unwrap_or(&kill.due_at)
+            ));
+            if let Some(outcome) = &kill.outcome {
+                summary.push_str(&format!("{}{outcome}", style.sep()));
+            }
+            if verbosity == SummaryVerbosity::Detailed
+                && let Some(note) = &kill.note
+            {
+                summary.push_str(&format!("{}{note}", style.sep()));
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessKillSchedulesOutput {
+                success: true,
+                pending,
+                recent,
+            },
+        ))
+    }
+}
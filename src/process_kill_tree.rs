@@ -0,0 +1,172 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, ICON_KILL};
+use crate::identity;
+use crate::process_group_kill::signal_each;
+use crate::protection::protected_reason;
+use crate::provider::ProcessEntry;
+use crate::schema::{
+    PROCESS_KILL_TREE, ProcessKillTreeArgs, ProcessKillTreeOutput, ProcessKillTreePrompts, SummaryStyle,
+    SummaryVerbosity, TreeKillOrder,
+};
+use crate::state::ProcessState;
+use crate::throttle::budget_text;
+use crate::tree::descendants;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessKillTreeTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessKillTreeTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessKillTreeTool {
+    type Args = ProcessKillTreeArgs;
+    type Prompts = ProcessKillTreePrompts;
+
+    fn name() -> &'static str {
+        PROCESS_KILL_TREE
+    }
+
+    fn description() -> &'static str {
+        "Send a signal (default SIGKILL; term, int, hup, quit, usr1, usr2 on Unix) to a process \
+         and every descendant, so grandchildren are not left behind as orphans. order \
+         \"children_first\" (default) signals the deepest processes first and the root last; \
+         \"parent_first\" starts at the root, for supervisors that restart children they see \
+         exit. Refused if any member is protected or is the server itself. Members tagged \
+         do-not-kill are skipped, each terminating signal consumes kill budget, and members the \
+         confirmation policy holds back are skipped unless confirm=true; only pass it after the \
+         user agreed. Returns one result per member. Use dry_run=true to preview the tree."
+    }
+
+    fn read_only() -> bool {
+        false // Signals processes
+    }
+
+    fn destructive() -> bool {
+        true // Terminates the whole tree by default
+    }
+
+    fn idempotent() -> bool {
+        false // The tree changes between calls
+    }
+
+    async fn execute(&self, args: Self::Args, ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessKillTreeOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+
+        let client = ctx.connection_id().map(str::to_string);
+        let state = self.state.clone();
+        let signal = args.signal;
+        let order = args.order;
+        let dry_run = args.dry_run;
+        let confirm = args.confirm;
+
+        let (name, results, budget) = tokio::task::spawn_blocking(move || {
+            let processes = state.provider.enumerate();
+            let root = processes
+                .iter()
+                .find(|process| process.pid == pid)
+                .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?;
+            // Breadth-first, so every parent precedes its children
+            let mut members: Vec<ProcessEntry> =
+                std::iter::once(root).chain(descendants(&processes, pid)).cloned().collect();
+            // Skipping a protected member would leave a tree that holds something vital half killed
+            for process in &members {
+                if let Some(reason) = protected_reason(process.pid, &process.name, process.parent) {
+                    return Err(McpError::PermissionDenied(format!(
+                        "Refusing to signal process tree {pid}: member {} ({}) is protected: {reason}",
+                        process.pid, process.name
+                    )));
+                }
+            }
+            if order == TreeKillOrder::ChildrenFirst {
+                members.reverse();
+            }
+            let name = root.name.clone();
+            let (results, budget) = signal_each(&state, members, signal, dry_run, confirm, client.as_deref());
+            Ok((name, results, budget))
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to signal process tree: {e}")))??;
+
+        let signalled = results.iter().filter(|result| result.signalled).count();
+        let exited = results.iter().filter(|result| result.exited).count();
+        let skipped = results.len() - signalled - exited;
+        let mut summary = if dry_run {
+            format!(
+                "{} PID {pid} ({name}) and {} descendant(s) would receive {}",
+                style.paint(Color::Cyan, "Dry Run:"),
+                results.len() - 1,
+                signal.name()
+            )
+        } else {
+            style.paint(
+                Color::Red,
+                &format!(
+                    "{}Tree {pid} ({name}): {} sent to {signalled} of {} members",
+                    style.icon(ICON_KILL),
+                    signal.name(),
+                    results.len()
+                ),
+            )
+        };
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!(
+                "\n{} Order: {}{}Exited first: {exited}{}Skipped: {skipped}{}Budget: {}",
+                style.bullet(),
+                order.name(),
+                style.sep(),
+                style.sep(),
+                style.sep(),
+                budget_text(&budget)
+            ));
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            for result in &results {
+                summary.push_str(&format!(
+                    "\n  PID {} ({}): {}",
+                    result.pid,
+                    result.name,
+                    result.reason.as_deref().unwrap_or(if dry_run { "would be signalled" } else { "signalled" })
+                ));
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessKillTreeOutput {
+                // A parent often exits by itself once its children are gone
+                success: results
+                    .iter()
+                    .all(|result| result.signalled || result.exited),
+                pid,
+                name,
+                signal: signal.name().to_string(),
+                order,
+                dry_run,
+                signalled,
+                results,
+                budget,
+            },
+        ))
+    }
+}
@@ -0,0 +1,77 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::schema::{
+    PROCESS_KILL_UNSCHEDULE, ProcessKillUnscheduleArgs, ProcessKillUnscheduleOutput, ProcessKillUnschedulePrompts,
+    SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessKillUnscheduleTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessKillUnscheduleTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessKillUnscheduleTool {
+    type Args = ProcessKillUnscheduleArgs;
+    type Prompts = ProcessKillUnschedulePrompts;
+
+    fn name() -> &'static str {
+        PROCESS_KILL_UNSCHEDULE
+    }
+
+    fn description() -> &'static str {
+        "Cancel a pending kill scheduled with process_kill_schedule, by the id it returned. \
+         Nothing is sent to the process. Kills that already ran cannot be cancelled."
+    }
+
+    fn read_only() -> bool {
+        false // Removes a pending kill
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessKillUnscheduleOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let id = args.id;
+
+        let kill = self
+            .state
+            .scheduler
+            .cancel(id)
+            .ok_or_else(|| McpError::ResourceNotFound(format!("No pending scheduled kill #{id}")))?;
+
+        let mut summary = format!(
+            "{} #{id} for PID {} ({})",
+            style.paint(Color::Green, "Kill cancelled:"),
+            kill.pid,
+            kill.name
+        );
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!("\n{} Was due: {}", style.bullet(), kill.due_at));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessKillUnscheduleOutput { success: true, kill },
+        ))
+    }
+}
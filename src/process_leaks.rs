@@ -0,0 +1,216 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, DETAIL_ROWS};
+use crate::history::ProcessSeries;
+use crate::identity;
+use crate::schema::{
+    LeakSuspect, PROCESS_LEAKS, ProcessLeaksArgs, ProcessLeaksOutput, ProcessLeaksPrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessLeaksTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessLeaksTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessLeaksTool {
+    type Args = ProcessLeaksArgs;
+    type Prompts = ProcessLeaksPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_LEAKS
+    }
+
+    fn description() -> &'static str {
+        "Flag likely memory leaks from the server's rolling history (one sample every 10 s by \
+         default): processes whose resident memory grew over window_secs without dropping more \
+         than tolerance_percent between samples and by at least min_growth_bytes. Each suspect \
+         has its growth, least-squares slope, R² and time_to_oom_secs, the time until that \
+         slope would use up the memory available now. Shortly after startup the history covers \
+         less than the window; history_secs says how much."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        false // The history moves on between calls
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessLeaksOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let history = &self.state.history;
+        if !history.enabled() {
+            return Err(McpError::Other(anyhow::anyhow!(
+                "Resource history is disabled (history.interval_secs is 0 in process.json)"
+            )));
+        }
+        let retention = history.retention().as_secs();
+        if args.window_secs == 0 || args.window_secs > retention {
+            return Err(McpError::InvalidArguments(format!(
+                "window_secs must be 1 to {retention} (the history retention)"
+            )));
+        }
+        if args.min_samples < 2 {
+            return Err(McpError::InvalidArguments("min_samples must be at least 2".to_string()));
+        }
+        if !args.tolerance_percent.is_finite() || !(0.0..100.0).contains(&args.tolerance_percent) {
+            return Err(McpError::InvalidArguments("tolerance_percent must be 0 to below 100".to_string()));
+        }
+
+        let filter = args.filter.as_deref().map(str::to_lowercase);
+        let series = history.series(Duration::from_secs(args.window_secs), |pid, name| {
+            (args.pids.is_empty() || args.pids.contains(&pid))
+                && filter.as_deref().is_none_or(|filter| name.to_lowercase().contains(filter))
+        });
+        let available = history.available_memory();
+        let judged: Vec<&ProcessSeries> = series.iter().filter(|series| series.samples.len() >= args.min_samples).collect();
+        let mut suspects: Vec<LeakSuspect> = judged
+            .iter()
+            .filter_map(|series| suspect(series, &args, available))
+            .collect();
+        suspects.sort_by(|a, b| b.slope_bytes_per_sec.total_cmp(&a.slope_bytes_per_sec));
+        let truncated = args.limit > 0 && suspects.len() > args.limit;
+        if truncated {
+            suspects.truncate(args.limit);
+        }
+        let history_secs = history.covered_secs();
+
+        let mb = |bytes: f64| bytes / 1024.0 / 1024.0;
+        let mut summary = if suspects.is_empty() {
+            format!(
+                "{} none of {} process(es) grew steadily over {} s",
+                style.paint(Color::Green, "No Leaks:"),
+                judged.len(),
+                args.window_secs
+            )
+        } else {
+            format!(
+                "{} {} of {} process(es) grew steadily over {} s",
+                style.paint(Color::Red, "Leak Suspects:"),
+                suspects.len(),
+                judged.len(),
+                args.window_secs
+            )
+        };
+        if verbosity != SummaryVerbosity::Quiet && history_secs < args.window_secs as f64 {
+            summary.push_str(&format!("{}history covers {history_secs:.0} s so far", style.sep()));
+        }
+        let shown = match verbosity {
+            SummaryVerbosity::Quiet => 0,
+            SummaryVerbosity::Normal => 3,
+            SummaryVerbosity::Detailed => DETAIL_ROWS,
+        };
+        for suspect in suspects.iter().take(shown) {
+            let oom = suspect
+                .time_to_oom_secs
+                .map_or_else(String::new, |secs| format!("{}OOM in ~{:.1} h", style.sep(), secs / 3600.0));
+            summary.push_str(&format!(
+                "\n{} PID {} ({}): {:.1}{}{:.1} MB{}{:+.1} MB/h{}{} {:.2}{oom}",
+                style.bullet(),
+                suspect.pid,
+                suspect.name,
+                mb(suspect.first_rss_bytes as f64),
+                style.arrow(),
+                mb(suspect.last_rss_bytes as f64),
+                style.sep(),
+                mb(suspect.slope_bytes_per_sec * 3600.0),
+                style.sep(),
+                style.r_squared(),
+                suspect.r_squared
+            ));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessLeaksOutput {
+                success: true,
+                window_secs: args.window_secs,
+                history_secs,
+                interval_secs: history.interval().as_secs(),
+                examined: judged.len(),
+                available_memory_bytes: available,
+                suspects,
+                truncated,
+            },
+        ))
+    }
+}
+
+// ============================================================================
+// ANALYSIS
+// ============================================================================
+
+/// Judge one series; `Some` when it grew steadily enough to be a suspect
+fn suspect(series: &ProcessSeries, args: &ProcessLeaksArgs, available: u64) -> Option<LeakSuspect> {
+    let samples = &series.samples;
+    let (first, last) = (samples.first()?, samples.last()?);
+    let keep = 1.0 - args.tolerance_percent / 100.0;
+    let monotonic = samples
+        .windows(2)
+        .all(|pair| pair[1].rss_bytes as f64 >= pair[0].rss_bytes as f64 * keep);
+    let growth = last.rss_bytes.saturating_sub(first.rss_bytes);
+    if !monotonic || growth < args.min_growth_bytes.max(1) {
+        return None;
+    }
+
+    // Least-squares fit of rss = intercept + slope * t
+    let n = samples.len() as f64;
+    let t0 = first.at;
+    let mean_t = samples.iter().map(|sample| sample.at - t0).sum::<f64>() / n;
+    let mean_rss = samples.iter().map(|sample| sample.rss_bytes as f64).sum::<f64>() / n;
+    let (mut covariance, mut variance_t, mut variance_rss) = (0.0, 0.0, 0.0);
+    for sample in samples {
+        let dt = sample.at - t0 - mean_t;
+        let drss = sample.rss_bytes as f64 - mean_rss;
+        covariance += dt * drss;
+        variance_t += dt * dt;
+        variance_rss += drss * drss;
+    }
+    if variance_t == 0.0 {
+        return None;
+    }
+    let slope = covariance / variance_t;
+    if slope <= 0.0 {
+        return None;
+    }
+    let r_squared = if variance_rss == 0.0 { 0.0 } else { covariance * covariance / (variance_t * variance_rss) };
+
+    Some(LeakSuspect {
+        pid: series.pid,
+        proc_id: identity::proc_id(series.pid, series.start_time),
+        name: series.name.clone(),
+        samples: samples.len(),
+        observed_secs: last.at - first.at,
+        first_rss_bytes: first.rss_bytes,
+        last_rss_bytes: last.rss_bytes,
+        growth_bytes: growth,
+        slope_bytes_per_sec: slope,
+        r_squared,
+        time_to_oom_secs: (available > 0).then(|| available as f64 / slope),
+    })
+}
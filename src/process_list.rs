@@ -1,21 +1,36 @@
 use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
 use kodegen_mcp_schema::McpError;
-use kodegen_mcp_schema::process::{
-    ProcessListArgs, ProcessListOutput, ProcessListPrompts, ProcessInfo, PROCESS_LIST
+use kodegen_mcp_schema::process::{ProcessListPrompts, PROCESS_LIST};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use sysinfo::{Pid, System};
+
+use crate::compact;
+use crate::config::{ListBackend, ProcessConfig};
+use crate::display::{Color, DETAIL_ROWS, human_bytes, truncate};
+use crate::identity;
+use crate::packaging;
+use crate::platform;
+use crate::pods::pod_matches;
+use crate::schema::{
+    ProcessColumn, ProcessInfo, ProcessListArgs, ProcessListOutput, ResultCap, SummaryStyle, SummaryVerbosity,
 };
-use sysinfo::System;
+use crate::state::ProcessState;
 
 // ============================================================================
 // TOOL STRUCT
 // ============================================================================
 
 #[derive(Clone, Default)]
-pub struct ProcessListTool;
+pub struct ProcessListTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
 
 impl ProcessListTool {
     #[must_use]
-    pub fn new() -> Self {
-        Self
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
     }
 }
 
@@ -42,35 +57,268 @@ impl Tool for ProcessListTool {
     }
 
     async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessListOutput>, McpError> {
+        self.list(args).await
+    }
+}
+
+// ============================================================================
+// LISTING
+// ============================================================================
+
+/// What [`ProcessListTool::rows`] found: rows, masked arguments, warnings and missing PIDs
+pub(crate) type Rows = (Vec<(u64, ProcessInfo)>, usize, Vec<String>, Vec<u32>);
+
+impl ProcessListTool {
+    /// The listing itself, independent of the MCP transport (shared with the REST routes)
+    pub(crate) async fn list(&self, mut args: ProcessListArgs) -> Result<ToolResponse<ProcessListOutput>, McpError> {
         // Clone filter before moving args into closure
         let filter_clone = args.filter.clone();
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let compact = args.compact;
+        let since = args.since.clone();
+        let delta_mode = args.delta || since.is_some();
+        let caps = &self.config.results;
+        // One row past the server cap tells whether it cut anything. A delta is computed over
+        // every process instead, and max_rows then caps the rows it sends
+        let row_capped = !delta_mode && caps.max_rows > 0 && (args.limit == 0 || args.limit > caps.max_rows);
+        if row_capped {
+            args.limit = caps.max_rows + 1;
+        }
+        let (current, redacted, warnings, missing) = self.rows(args).await?;
+        let mut capped_by = None;
+        // Spent before the snapshot is taken, so rows cut by either cap are not recorded as seen;
+        // once a row is turned away every later one is too
+        let (mut sent, mut bytes) = (0, 0);
+        let mut fits = |info: &ProcessInfo| {
+            if capped_by.is_some() {
+                return false;
+            }
+            if caps.max_rows > 0 && sent == caps.max_rows {
+                capped_by = Some("max_rows");
+                return false;
+            }
+            bytes += serde_json::to_vec(info).map_or(0, |row| row.len()) + 1;
+            if caps.max_bytes > 0 && bytes > caps.max_bytes {
+                capped_by = Some("max_bytes");
+                return false;
+            }
+            sent += 1;
+            true
+        };
+        let (processes, delta, snapshot): (Vec<ProcessInfo>, _, _) = if delta_mode {
+            let (processes, delta, snapshot) =
+                self.state.list_snapshots.advance(since.as_deref(), current, &mut fits).ok_or_else(|| {
+                    McpError::ResourceNotFound(format!(
+                        "Snapshot {:?} has expired (only the most recent are kept); call again without since",
+                        since.as_deref().unwrap_or_default()
+                    ))
+                })?;
+            (processes, delta, Some(snapshot))
+        } else {
+            (current.into_iter().map(|(_, info)| info).take_while(|info| fits(info)).collect(), None, None)
+        };
+        let capped = capped_by.map(|by| ResultCap {
+            by: by.to_string(),
+            max_rows: caps.max_rows,
+            max_bytes: caps.max_bytes,
+        });
+
+        // Human-readable summary
+        let filter_text = filter_clone.as_deref().unwrap_or("none");
+        let mut summary = render_summary(&processes, filter_text, style, verbosity);
+        if let Some(delta) = &delta
+            && verbosity != SummaryVerbosity::Quiet
+        {
+            summary.push_str(&format!(
+                "\n{} Since {}: {} added{}{} changed{}{} removed{}{} unchanged",
+                style.bullet(),
+                delta.since,
+                delta.added.len(),
+                style.sep(),
+                delta.changed.len(),
+                style.sep(),
+                delta.removed.len(),
+                style.sep(),
+                delta.unchanged
+            ));
+        }
+
+        if let Some(capped) = &capped {
+            summary.push_str(&format!(
+                "\n{} Capped by the server ({}); narrow with filter, tag or named_filters",
+                style.bullet(),
+                capped.by
+            ));
+        }
+        if verbosity != SummaryVerbosity::Quiet {
+            for warning in &warnings {
+                summary.push_str(&format!("\n{} Warning: {warning}", style.bullet()));
+            }
+            if !missing.is_empty() {
+                let pids: Vec<String> = missing.iter().map(u32::to_string).collect();
+                summary.push_str(&format!("\n{} Not found: {}", style.bullet(), pids.join(", ")));
+            }
+        }
+
+        let count = processes.len();
+        let (processes, header, rows) = if compact {
+            let (header, rows) = compact::table(&processes);
+            (Vec::new(), header, rows)
+        } else {
+            (processes, Vec::new(), Vec::new())
+        };
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessListOutput {
+                success: true,
+                count,
+                processes,
+                header,
+                rows,
+                redacted,
+                snapshot,
+                delta,
+                capped,
+                warnings,
+                missing,
+            },
+        ))
+    }
+
+    /// Filtered and enriched rows with their start times, sorted by CPU, plus the number of
+    /// masked arguments, scan warnings and the requested `pids` that do not exist; `since`,
+    /// `delta` and `compact` are left to the caller
+    pub(crate) async fn rows(&self, args: ProcessListArgs) -> Result<Rows, McpError> {
+        let state = self.state.clone();
+        let redact = self.state.redactor.active(args.reveal_secrets)?;
+        let group = match &args.group {
+            Some(name) => Some(self.state.groups.get(name).ok_or_else(|| {
+                McpError::ResourceNotFound(format!("Process group {name:?} is not defined"))
+            })?),
+            None => None,
+        };
+        let named_filter = self.state.plugins.predicate(&args.named_filters).map_err(McpError::InvalidArguments)?;
+        if args.wsl && (args.delta || args.since.is_some()) {
+            return Err(McpError::InvalidArguments(
+                "wsl cannot be combined with delta or since (WSL PIDs overlap with host PIDs)".to_string(),
+            ));
+        }
+
+        let native = self.config.list_backend != ListBackend::Sysinfo;
+        if args.pod.is_some() || args.columns.contains(&ProcessColumn::Pod) {
+            self.state.pods.refresh().await;
+        }
 
         // Use spawn_blocking because sysinfo operations are CPU-intensive
-        let processes = tokio::task::spawn_blocking(move || {
-            let mut system = System::new_all();
-            system.refresh_all();
-
-            let mut process_list: Vec<ProcessInfo> = system
-                .processes()
-                .iter()
-                .map(|(pid, process)| {
-                    ProcessInfo {
-                        pid: pid.as_u32(),
-                        name: process.name().to_string_lossy().to_string(),
-                        cpu_percent: process.cpu_usage(),
-                        // Note: Precision loss is acceptable for display purposes
-                        memory_mb: f64::from(u32::try_from(process.memory()).unwrap_or(u32::MAX))
-                            / 1024.0
-                            / 1024.0,
-                    }
+        tokio::task::spawn_blocking(move || {
+            // A table younger than sampling.list_max_age_ms is reused instead of rescanning
+            let cached = state.sampling.cached_table();
+            let table = if native && cached.is_none() { platform::process_table() } else { None };
+            // Full sysinfo data is only loaded when the native table is unavailable
+            let system = (cached.is_none() && table.is_none()).then(|| {
+                let mut system = System::new_all();
+                system.refresh_all();
+                system
+            });
+            let rows: Vec<(u64, ProcessInfo)> = match (&cached, &table, &system) {
+                (Some(cached), _, _) => cached.to_vec(),
+                (None, Some(table), _) => {
+                    let cpu = state.cpu_sampler.sample(&table.processes);
+                    table
+                        .processes
+                        .iter()
+                        .zip(cpu)
+                        .map(|(process, cpu_percent)| {
+                            let info = row(process.pid, process.name.clone(), cpu_percent, process.rss_bytes, process.virtual_bytes);
+                            (process.start_time, info)
+                        })
+                        .collect()
+                }
+                (None, None, Some(system)) => system
+                    .processes()
+                    .iter()
+                    .map(|(pid, process)| {
+                        let info = row(
+                            pid.as_u32(),
+                            process.name().to_string_lossy().to_string(),
+                            process.cpu_usage(),
+                            process.memory(),
+                            process.virtual_memory(),
+                        );
+                        (process.start_time(), info)
+                    })
+                    .collect(),
+                (None, None, None) => Vec::new(),
+            };
+            if cached.is_none() {
+                state.sampling.store_table(&rows);
+            }
+            // Entries that vanished or denied access mid-scan degrade the listing instead of failing it
+            let warnings = table.as_ref().map(|table| table.skipped.warnings()).unwrap_or_default();
+            let start_of: HashMap<u32, u64> = rows.iter().map(|(start_time, info)| (info.pid, *start_time)).collect();
+            let mut missing: Vec<u32> = args.pids.iter().copied().filter(|pid| !start_of.contains_key(pid)).collect();
+            missing.sort_unstable();
+            missing.dedup();
+
+            // Fresh process data is at hand, so drop tags and registrations of exited processes now
+            state.tags.prune_with(|pid, start_time| start_of.get(&pid) == Some(&start_time));
+            state.children.prune_with(|pid, start_time| start_of.get(&pid) == Some(&start_time));
+
+            let mut process_list: Vec<ProcessInfo> = rows
+                .into_iter()
+                .filter(|(start_time, info)| {
+                    (args.pids.is_empty() || args.pids.contains(&info.pid))
+                        && group.as_ref().is_none_or(|group| group.matches(info.pid, *start_time, &info.name))
+                })
+                .map(|(start_time, mut info)| {
+                    info.start_time = start_time;
+                    info.proc_id = Some(identity::proc_id(info.pid, start_time));
+                    info.tags = state
+                        .tags
+                        .get(info.pid, start_time)
+                        .map(|entry| entry.tags)
+                        .unwrap_or_default();
+                    info.spawned_by = state.spawned_by(info.pid, start_time);
+                    info
                 })
                 .collect();
 
+            // Only plain listings take WSL rows; PIDs, groups, kinds and pods refer to host processes
+            if args.wsl && args.pids.is_empty() && group.is_none() && args.kind.is_none() && args.pod.is_none() {
+                for distro in platform::wsl_distros() {
+                    for process in platform::wsl_processes(&distro).unwrap_or_default() {
+                        let mut info = row(process.pid, process.name, process.cpu_percent, process.rss_bytes, process.virtual_bytes);
+                        info.wsl_distro = Some(distro.clone());
+                        process_list.push(info);
+                    }
+                }
+            }
+
             // Apply filter if provided
             if let Some(filter) = &args.filter {
                 let filter_lower = filter.to_lowercase();
                 process_list.retain(|p| p.name.to_lowercase().contains(&filter_lower));
             }
+            if let Some(tag) = &args.tag {
+                process_list.retain(|p| p.tags.contains(tag));
+            }
+            if !args.named_filters.is_empty() {
+                process_list.retain(&named_filter);
+            }
+            if let Some(kind) = args.kind {
+                process_list.retain_mut(|p| {
+                    p.kind = platform::process_kind(p.pid);
+                    p.kind == Some(kind)
+                });
+            }
+            if let Some(wanted) = &args.pod {
+                process_list.retain_mut(|p| {
+                    p.pod = state.pods.pod(p.pid);
+                    p.pod.as_ref().is_some_and(|pod| pod_matches(pod, wanted))
+                });
+            }
 
             // Sort by CPU usage (descending) for useful output
             process_list.sort_by(|a, b| {
@@ -84,26 +332,195 @@ impl Tool for ProcessListTool {
                 process_list.truncate(args.limit);
             }
 
-            process_list
+            // Per-process OS queries only for the rows actually returned
+            let mut redacted = 0;
+            for info in &mut process_list {
+                // Host OS queries would hit whatever host process has the same PID
+                if info.wsl_distro.is_some() {
+                    continue;
+                }
+                state.plugins.enrich(info);
+                if args.detailed_memory {
+                    let private = platform::private_memory(info.pid);
+                    info.uss_bytes = private.uss_bytes;
+                    info.private_bytes = private.private_bytes;
+                }
+                for column in &args.columns {
+                    match column {
+                        ProcessColumn::SecurityLabel => {
+                            info.security_label = platform::security_label(info.pid);
+                        }
+                        ProcessColumn::Seccomp => {
+                            info.seccomp = platform::seccomp_mode(info.pid);
+                        }
+                        ProcessColumn::Integrity => {
+                            info.integrity_level = platform::integrity_level(info.pid).map(str::to_string);
+                            info.app_container = platform::is_app_container(info.pid);
+                        }
+                        ProcessColumn::Cmdline => {
+                            // sysinfo leaves the command line empty when access is denied,
+                            // where the platform read may have a fallback (WMI on Windows)
+                            let cmdline = system
+                                .as_ref()
+                                .and_then(|system| system.process(Pid::from_u32(info.pid)))
+                                .map(|process| process.cmd().iter().map(|arg| arg.to_string_lossy().to_string()).collect::<Vec<_>>())
+                                .filter(|cmdline| !cmdline.is_empty())
+                                .or_else(|| platform::cmdline(info.pid));
+                            let Some(mut cmdline) = cmdline else {
+                                continue;
+                            };
+                            if redact {
+                                redacted += state.redactor.cmdline(&mut cmdline);
+                            }
+                            info.cmdline = Some(cmdline);
+                        }
+                        ProcessColumn::Handles => {
+                            info.handle_count = platform::handle_count(info.pid);
+                        }
+                        ProcessColumn::Paths => {
+                            let lossy = |path: std::path::PathBuf| path.to_string_lossy().to_string();
+                            info.exe = platform::exe_path(info.pid).map(lossy);
+                            info.cwd = platform::cwd(info.pid).map(lossy);
+                        }
+                        ProcessColumn::Tty => {
+                            info.tty = platform::controlling_tty(info.pid);
+                        }
+                        ProcessColumn::Kind => {
+                            if info.kind.is_none() {
+                                info.kind = platform::process_kind(info.pid);
+                            }
+                        }
+                        ProcessColumn::Packaging => {
+                            info.packaging = packaging::packaging(info.pid, info.kind);
+                        }
+                        ProcessColumn::ExeHash => {
+                            info.exe_sha256 = state.exe_hashes.sha256(info.pid);
+                        }
+                        ProcessColumn::Cgroup => {
+                            info.cgroup = platform::cgroup_path(info.pid);
+                        }
+                        ProcessColumn::Pod => {
+                            if info.pod.is_none() {
+                                info.pod = state.pods.pod(info.pid);
+                            }
+                        }
+                        ProcessColumn::Arch => {
+                            if let Some(arch) = platform::process_arch(info.pid) {
+                                info.arch = Some(arch.arch.to_string());
+                                info.arch_bits = Some(arch.bits);
+                                info.emulated = Some(arch.emulated);
+                            }
+                        }
+                    }
+                }
+            }
+
+            let rows = process_list
+                .into_iter()
+                .map(|info| {
+                    let start_time = match info.wsl_distro {
+                        Some(_) => 0,
+                        None => start_of.get(&info.pid).copied().unwrap_or_default(),
+                    };
+                    (start_time, info)
+                })
+                .collect();
+            (rows, redacted, warnings, missing)
         })
         .await
-        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to list processes: {e}")))?;
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to list processes: {e}")))
+    }
 
-        // Human-readable summary
-        let filter_text = filter_clone.as_deref().unwrap_or("none");
-        let summary = format!(
-            "\x1b[36mProcesses\x1b[0m\n• Count: {} · Filter: {}",
+}
+
+/// Row with the always-present columns filled in
+fn row(pid: u32, name: String, cpu_percent: f32, rss_bytes: u64, virtual_bytes: u64) -> ProcessInfo {
+    ProcessInfo {
+        pid,
+        name,
+        start_time: 0,
+        proc_id: None,
+        cpu_percent,
+        // Note: Precision loss is acceptable for display purposes
+        memory_mb: rss_bytes as f64 / 1024.0 / 1024.0,
+        rss_bytes,
+        rss_human: human_bytes(rss_bytes),
+        virtual_bytes,
+        uss_bytes: None,
+        private_bytes: None,
+        security_label: None,
+        seccomp: None,
+        integrity_level: None,
+        app_container: None,
+        tags: Vec::new(),
+        spawned_by: None,
+        cmdline: None,
+        handle_count: None,
+        exe: None,
+        cwd: None,
+        tty: None,
+        kind: None,
+        packaging: None,
+        exe_sha256: None,
+        arch: None,
+        arch_bits: None,
+        emulated: None,
+        cgroup: None,
+        pod: None,
+        wsl_distro: None,
+        extra: BTreeMap::new(),
+    }
+}
+
+// ============================================================================
+// SUMMARY RENDERING
+// ============================================================================
+
+fn render_summary(
+    processes: &[ProcessInfo],
+    filter_text: &str,
+    style: SummaryStyle,
+    verbosity: SummaryVerbosity,
+) -> String {
+    if verbosity == SummaryVerbosity::Quiet {
+        return format!(
+            "{}: {}{}Filter: {}",
+            style.paint(Color::Cyan, "Processes"),
             processes.len(),
+            style.sep(),
             filter_text
         );
+    }
 
-        Ok(ToolResponse::new(
-            summary,
-            ProcessListOutput {
-                success: true,
-                count: processes.len(),
-                processes,
-            },
-        ))
+    let mut summary = format!(
+        "{}\n{} Count: {}{}Filter: {}",
+        style.paint(Color::Cyan, "Processes"),
+        style.bullet(),
+        processes.len(),
+        style.sep(),
+        filter_text
+    );
+
+    if verbosity == SummaryVerbosity::Detailed {
+        summary.push_str(&format!(
+            "\n{} Top {} by CPU usage:",
+            style.bullet(),
+            processes.len().min(DETAIL_ROWS)
+        ));
+        for (i, proc) in processes.iter().take(DETAIL_ROWS).enumerate() {
+            summary.push_str(&format!(
+                "\n  {:2}. PID {:6}{}{:20}{}CPU: {:5.1}%{}Mem: {:>10}",
+                i + 1,
+                proc.pid,
+                style.sep(),
+                truncate(&proc.name, 20),
+                style.sep(),
+                proc.cpu_percent,
+                style.sep(),
+                proc.rss_human
+            ));
+        }
     }
+
+    summary
 }
@@ -0,0 +1,284 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, DETAIL_ROWS};
+use crate::identity;
+use crate::pidfile;
+use crate::platform;
+use crate::schema::{
+    LockHolder, PROCESS_LOCK_CHECK, ProcessLockCheckArgs, ProcessLockCheckOutput, ProcessLockCheckPrompts,
+    SummaryStyle, SummaryVerbosity,
+};
+
+/// Larger files are lock payloads, not a recorded owner PID
+const PID_CONTENT_MAX_BYTES: u64 = 64;
+
+/// Lock file names without a `.lock` extension
+const LOCK_NAMES: &[&str] = &[".package-cache", "LOCK", "lock"];
+
+/// Dependency manifests that end in `.lock` but are source files, not locks
+const DEPENDENCY_LOCKFILES: &[&str] = &[
+    "Cargo.lock",
+    "Gemfile.lock",
+    "Pipfile.lock",
+    "Podfile.lock",
+    "bun.lock",
+    "composer.lock",
+    "conan.lock",
+    "flake.lock",
+    "mix.lock",
+    "pdm.lock",
+    "poetry.lock",
+    "pubspec.lock",
+    "uv.lock",
+    "yarn.lock",
+];
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessLockCheckTool {
+    config: Arc<ProcessConfig>,
+}
+
+impl ProcessLockCheckTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>) -> Self {
+        Self { config }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessLockCheckTool {
+    type Args = ProcessLockCheckArgs;
+    type Prompts = ProcessLockCheckPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_LOCK_CHECK
+    }
+
+    fn description() -> &'static str {
+        "Check whether a lock file (e.g. .git/index.lock, cargo's .package-cache) is still owned \
+         by a live process: lists processes holding it open or locked, and checks the PID written \
+         in the file when there is one. A lock is stale when neither is alive and it is older \
+         than min_age_secs. Pass remove=true to delete it when stale; held locks are never removed, \
+         and removal is refused for directories, symlinks and files that are neither named like a \
+         lock (*.lock, .package-cache) nor record a PID. Dependency lockfiles such as Cargo.lock \
+         are never removed."
+    }
+
+    fn read_only() -> bool {
+        false // Removes the file when asked
+    }
+
+    fn destructive() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        false // Ownership changes over time
+    }
+
+    async fn execute(&self, args: Self::Args, ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessLockCheckOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let path = pidfile::resolve(&args.path, ctx.pwd());
+        let min_age = Duration::from_secs(args.min_age_secs);
+        let remove = args.remove;
+        // Removal happens right after the check, on the same blocking thread
+        let output = tokio::task::spawn_blocking(move || {
+            let mut output = check(&path, min_age);
+            if remove && output.stale {
+                if let Some(reason) = removal_refusal(&path, output.pid_in_file) {
+                    return Err(McpError::InvalidArguments(format!(
+                        "Refusing to remove {}: {reason}",
+                        output.path
+                    )));
+                }
+                std::fs::remove_file(&path).map_err(|e| match e.kind() {
+                    std::io::ErrorKind::PermissionDenied => {
+                        McpError::PermissionDenied(format!("Cannot remove stale lock {}: {e}", output.path))
+                    }
+                    _ => McpError::Other(anyhow::anyhow!("Failed to remove stale lock {}: {e}", output.path)),
+                })?;
+                output.removed = true;
+            }
+            Ok(output)
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Lock check task failed: {e}")))??;
+
+        let mut summary = match (output.exists, output.stale) {
+            (false, _) => style.paint(Color::Cyan, &format!("No lock file at {}", output.path)),
+            (true, true) if output.removed => style.paint(Color::Green, &format!("Removed stale lock {}", output.path)),
+            (true, true) => style.paint(Color::Red, &format!("Stale lock {}", output.path)),
+            (true, false) => style.paint(Color::Cyan, &format!("Lock not stale {}", output.path)),
+        };
+        if verbosity != SummaryVerbosity::Quiet && output.exists {
+            summary.push_str(&format!("\n{} {}", style.bullet(), output.reason));
+        }
+        let shown = match verbosity {
+            SummaryVerbosity::Quiet => 0,
+            SummaryVerbosity::Normal | SummaryVerbosity::Detailed => DETAIL_ROWS,
+        };
+        for holder in output.holders.iter().take(shown) {
+            summary.push_str(&format!(
+                "\n{} PID {} {}{}",
+                style.bullet(),
+                holder.pid,
+                holder.name.as_deref().unwrap_or("?"),
+                if holder.locked { " (locked)" } else { "" }
+            ));
+        }
+        if verbosity == SummaryVerbosity::Detailed && output.exists {
+            summary.push_str(&format!(
+                "\n{} Age: {}s{}{} bytes",
+                style.bullet(),
+                output.age_secs.unwrap_or(0),
+                style.sep(),
+                output.bytes.unwrap_or(0)
+            ));
+            if !output.holder_detection {
+                summary.push_str(&format!("\n{} Open handles cannot be enumerated on this platform", style.bullet()));
+            }
+        }
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
+
+// ============================================================================
+// LOCK CHECK
+// ============================================================================
+
+/// Decide whether `path` is a lock nobody owns any more
+fn check(path: &Path, min_age: Duration) -> ProcessLockCheckOutput {
+    let mut output = ProcessLockCheckOutput {
+        success: true,
+        path: path.to_string_lossy().to_string(),
+        exists: false,
+        bytes: None,
+        modified: None,
+        age_secs: None,
+        pid_in_file: None,
+        pid_in_file_alive: None,
+        holders: Vec::new(),
+        holder_detection: false,
+        stale: false,
+        reason: String::new(),
+        removed: false,
+    };
+
+    let metadata = match std::fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(e) => {
+            output.reason = format!("not present: {e}");
+            return output;
+        }
+    };
+    output.exists = true;
+    output.bytes = Some(metadata.len());
+    let modified = metadata.modified().ok();
+    output.modified = modified.map(|modified| chrono::DateTime::<chrono::Utc>::from(modified).to_rfc3339());
+    // A timestamp in the future counts as brand new
+    let age = modified.map(|modified| SystemTime::now().duration_since(modified).unwrap_or_default());
+    output.age_secs = age.map(|age| age.as_secs());
+
+    // Tools like git write nothing; others record their PID (validated like a pidfile)
+    if metadata.len() <= PID_CONTENT_MAX_BYTES {
+        let status = pidfile::check(path, None);
+        if let Some(pid) = status.pid {
+            output.pid_in_file = Some(pid);
+            output.pid_in_file_alive = Some(!status.stale);
+        }
+    }
+
+    let holders = platform::file_holders(path);
+    output.holder_detection = holders.is_some();
+    let holders = holders.unwrap_or_default();
+    if !holders.is_empty() {
+        let pids: Vec<Pid> = holders.iter().map(|holder| Pid::from_u32(holder.pid)).collect();
+        let mut system = System::new();
+        system.refresh_processes_specifics(ProcessesToUpdate::Some(&pids), true, ProcessRefreshKind::nothing());
+        output.holders = holders
+            .into_iter()
+            .map(|holder| {
+                let process = system.process(Pid::from_u32(holder.pid));
+                LockHolder {
+                    pid: holder.pid,
+                    proc_id: process.map(|process| identity::proc_id(holder.pid, process.start_time())),
+                    name: process.map(|process| process.name().to_string_lossy().to_string()),
+                    locked: holder.locked,
+                }
+            })
+            .collect();
+        // Lock owners first: they are the ones to wait for
+        output.holders.sort_by_key(|holder| !holder.locked);
+    }
+
+    output.reason = if let Some(holder) = output.holders.first() {
+        let others = match output.holders.len() {
+            1 => String::new(),
+            2 => " and 1 other process".to_string(),
+            n => format!(" and {} other processes", n - 1),
+        };
+        format!(
+            "{} by PID {} ({}){others}",
+            if holder.locked { "locked" } else { "open" },
+            holder.pid,
+            holder.name.as_deref().unwrap_or("?")
+        )
+    } else if let (Some(pid), Some(true)) = (output.pid_in_file, output.pid_in_file_alive) {
+        format!("owner PID {pid} from the file is running")
+    } else if age.is_none_or(|age| age < min_age) {
+        format!(
+            "modified {}s ago, the owner may still be starting (min_age_secs {})",
+            output.age_secs.unwrap_or(0),
+            min_age.as_secs()
+        )
+    } else if !output.holder_detection && output.pid_in_file.is_none() {
+        "owner unknown: open handles cannot be enumerated here and the file records no PID".to_string()
+    } else {
+        output.stale = true;
+        match output.pid_in_file {
+            Some(pid) => format!("owner PID {pid} is gone and no process has the file open"),
+            None => "no process has the file open".to_string(),
+        }
+    };
+    output
+}
+
+/// Why a stale `path` must not be deleted, if it must not
+///
+/// Only regular files that look like a lock are removed: named like one, or
+/// recording an owner PID. A mistyped path to a source file is refused.
+fn removal_refusal(path: &Path, pid_in_file: Option<u32>) -> Option<String> {
+    let metadata = match std::fs::symlink_metadata(path) {
+        Ok(metadata) => metadata,
+        Err(e) => return Some(format!("cannot stat it: {e}")),
+    };
+    if metadata.file_type().is_symlink() {
+        return Some("it is a symlink".to_string());
+    }
+    if !metadata.is_file() {
+        return Some("it is not a regular file".to_string());
+    }
+    let name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+    if DEPENDENCY_LOCKFILES.contains(&name.as_str()) {
+        return Some(format!("{name} is a dependency lockfile, not a lock"));
+    }
+    let lock_like = name.ends_with(".lock") || LOCK_NAMES.contains(&name.as_str());
+    (!lock_like && pid_in_file.is_none())
+        .then(|| "it is not named like a lock (*.lock, .package-cache) and records no PID".to_string())
+}
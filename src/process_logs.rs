@@ -0,0 +1,119 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, DETAIL_ROWS, truncate};
+use crate::output_log::{segment_path, segments, tail};
+use crate::schema::{
+    PROCESS_LOGS, ProcessLogsArgs, ProcessLogsOutput, ProcessLogsPrompts, SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessLogsTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessLogsTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessLogsTool {
+    type Args = ProcessLogsArgs;
+    type Prompts = ProcessLogsPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_LOGS
+    }
+
+    fn description() -> &'static str {
+        "Read captured stdout or stderr of a process started by this server (supervisor id from \
+         process_status). Output is stored in size-rotated segments; the response lists every \
+         segment and returns the trailing lines of the requested one (live segment by default)."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        false // Logs grow while the process runs
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessLogsOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let Some(process) = self.state.supervisor.list(Some(args.id), None).pop() else {
+            return Err(McpError::ResourceNotFound(format!(
+                "No supervised process #{} (never started here, or its exit record expired)",
+                args.id
+            )));
+        };
+        let Some(dir) = process.log_dir.map(PathBuf::from) else {
+            return Err(McpError::ResourceNotFound(format!(
+                "Output of supervised process #{} was not captured",
+                args.id
+            )));
+        };
+
+        let stream = args.stream;
+        let (segments, lines, truncated) = tokio::task::spawn_blocking(move || {
+            let segments = segments(&dir, stream);
+            let path = segment_path(&dir, stream, args.segment);
+            tail(&path, args.lines).map(|(lines, truncated)| (segments, lines, truncated))
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Log read task failed: {e}")))?
+        .map_err(|e| {
+            McpError::ResourceNotFound(format!("No {} segment {} for #{}: {e}", stream.name(), args.segment, args.id))
+        })?;
+
+        let mut summary = format!(
+            "{} #{} {} segment {}: {} lines{}{} segments",
+            style.paint(Color::Cyan, "Logs"),
+            args.id,
+            stream.name(),
+            args.segment,
+            lines.len(),
+            style.sep(),
+            segments.len()
+        );
+        let shown = match verbosity {
+            SummaryVerbosity::Quiet => 0,
+            SummaryVerbosity::Normal => 5,
+            SummaryVerbosity::Detailed => DETAIL_ROWS,
+        };
+        let skip = lines.len().saturating_sub(shown);
+        for line in &lines[skip..] {
+            summary.push_str(&format!("\n{} {}", style.bullet(), truncate(line, 120)));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessLogsOutput {
+                success: true,
+                id: args.id,
+                stream,
+                segment: args.segment,
+                segments,
+                lines,
+                truncated,
+            },
+        ))
+    }
+}
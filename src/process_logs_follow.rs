@@ -0,0 +1,207 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use regex::Regex;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::error::{RecvError, TryRecvError};
+use tokio::time::Instant;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, truncate};
+use crate::schema::{
+    DEFAULT_ERROR_PATTERN, FollowEnd, LogStream, PROCESS_LOGS_FOLLOW, ProcessLogsFollowArgs, ProcessLogsFollowOutput,
+    ProcessLogsFollowPrompts, SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+use crate::supervisor::LogLine;
+
+/// Lines sent per notification when output arrives in bursts
+const BATCH_LINES: usize = 50;
+
+/// Matching lines kept for the final result
+const ERROR_LINES_KEPT: usize = 20;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessLogsFollowTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessLogsFollowTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessLogsFollowTool {
+    type Args = ProcessLogsFollowArgs;
+    type Prompts = ProcessLogsFollowPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_LOGS_FOLLOW
+    }
+
+    fn description() -> &'static str {
+        "Stream new stdout/stderr lines of a process started by this server as notifications \
+         until the request is cancelled, the timeout elapses, max_lines is reached or the output \
+         ends. Lines matching error_pattern are highlighted and returned in the final result. \
+         Use process_logs for output written before the call."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        false // Streams whatever the process writes during the call
+    }
+
+    async fn execute(&self, args: Self::Args, ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessLogsFollowOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let pattern = args.error_pattern.as_deref().unwrap_or(DEFAULT_ERROR_PATTERN);
+        let errors = Regex::new(pattern)
+            .map_err(|e| McpError::InvalidArguments(format!("Invalid error_pattern: {e}")))?;
+        let mut lines = self.state.supervisor.follow(args.id).map_err(McpError::ResourceNotFound)?;
+
+        let deadline = (args.timeout_secs > 0).then(|| Instant::now() + Duration::from_secs(args.timeout_secs));
+        let wanted = |line: &LogLine| args.stream.is_none_or(|stream| stream == line.stream);
+        let mut streamed = 0;
+        let mut missed = 0;
+        let mut error_count = 0;
+        let mut error_lines = Vec::new();
+
+        let ended = loop {
+            let first = tokio::select! {
+                () = ctx.cancellation_token().cancelled() => break FollowEnd::Cancelled,
+                () = sleep_until(deadline) => break FollowEnd::Timeout,
+                received = lines.recv() => received,
+            };
+            let mut batch = Vec::new();
+            let mut closed = false;
+            match first {
+                Ok(line) => batch.push(line),
+                Err(RecvError::Lagged(skipped)) => missed += skipped,
+                Err(RecvError::Closed) => break FollowEnd::Ended,
+            }
+            // Drain whatever else is already buffered into the same notification
+            while batch.len() < BATCH_LINES {
+                match lines.try_recv() {
+                    Ok(line) => batch.push(line),
+                    Err(TryRecvError::Lagged(skipped)) => missed += skipped,
+                    Err(TryRecvError::Closed) => {
+                        closed = true;
+                        break;
+                    }
+                    Err(TryRecvError::Empty) => break,
+                }
+            }
+
+            batch.retain(wanted);
+            if args.max_lines > 0 {
+                batch.truncate(args.max_lines - streamed);
+            }
+            if !batch.is_empty() {
+                let mut message = Vec::with_capacity(batch.len());
+                for line in &batch {
+                    let is_error = errors.is_match(&line.text);
+                    if is_error {
+                        error_count += 1;
+                        if error_lines.len() == ERROR_LINES_KEPT {
+                            error_lines.remove(0);
+                        }
+                        error_lines.push(line.text.clone());
+                    }
+                    message.push(render(style, line, is_error, args.stream.is_none()));
+                }
+                streamed += batch.len();
+                if ctx.stream(message.join("\n")).await.is_err() {
+                    break FollowEnd::Cancelled;
+                }
+            }
+
+            if closed {
+                break FollowEnd::Ended;
+            }
+            if args.max_lines > 0 && streamed >= args.max_lines {
+                break FollowEnd::MaxLines;
+            }
+        };
+
+        let reason = match ended {
+            FollowEnd::Ended => "output ended",
+            FollowEnd::Cancelled => "cancelled",
+            FollowEnd::Timeout => "timed out",
+            FollowEnd::MaxLines => "line limit reached",
+        };
+        let mut summary = format!(
+            "{} #{}: {streamed} lines, {reason}",
+            style.paint(Color::Cyan, "Followed"),
+            args.id
+        );
+        if error_count > 0 {
+            summary.push_str(&format!(
+                "{}{}",
+                style.sep(),
+                style.paint(Color::Red, &format!("{error_count} error lines"))
+            ));
+        }
+        if missed > 0 {
+            summary.push_str(&format!("{}{missed} lines missed", style.sep()));
+        }
+        let shown = match verbosity {
+            SummaryVerbosity::Quiet => 0,
+            SummaryVerbosity::Normal => 3,
+            SummaryVerbosity::Detailed => ERROR_LINES_KEPT,
+        };
+        let skip = error_lines.len().saturating_sub(shown);
+        for line in &error_lines[skip..] {
+            summary.push_str(&format!("\n{} {}", style.bullet(), truncate(line, 120)));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessLogsFollowOutput {
+                success: true,
+                id: args.id,
+                ended,
+                lines_streamed: streamed,
+                lines_missed: missed,
+                error_count,
+                error_lines,
+            },
+        ))
+    }
+}
+
+/// Resolve at `deadline`, or never
+async fn sleep_until(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
+/// One streamed line: stderr tagged when both streams are followed, errors highlighted
+fn render(style: SummaryStyle, line: &LogLine, is_error: bool, tag_stream: bool) -> String {
+    let text = if tag_stream && line.stream == LogStream::Stderr {
+        format!("[stderr] {}", line.text)
+    } else {
+        line.text.clone()
+    };
+    match (is_error, style) {
+        (false, _) => text,
+        (true, SummaryStyle::Ansi) => style.paint(Color::Red, &text),
+        (true, SummaryStyle::Plain) => format!("!! {text}"),
+    }
+}
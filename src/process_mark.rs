@@ -0,0 +1,103 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, human_bytes};
+use crate::process_list::ProcessListTool;
+use crate::schema::{
+    PROCESS_MARK, ProcessListArgs, ProcessMarkArgs, ProcessMarkOutput, ProcessMarkPrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessMarkTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessMarkTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessMarkTool {
+    type Args = ProcessMarkArgs;
+    type Prompts = ProcessMarkPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_MARK
+    }
+
+    fn description() -> &'static str {
+        "Record the current process table under a name such as \"before-deploy\", optionally \
+         narrowed by a name filter. process_mark_compare later reports new, vanished and \
+         changed processes against it. Recording a name again replaces the snapshot; markers \
+         are kept in memory until the server restarts."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessMarkOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        if args.name.trim().is_empty() {
+            return Err(McpError::InvalidArguments("Marker name must not be empty".to_string()));
+        }
+
+        let (rows, ..) = ProcessListTool::new(self.config.clone(), self.state.clone())
+            .rows(ProcessListArgs {
+                filter: args.filter.clone(),
+                ..ProcessListArgs::default()
+            })
+            .await?;
+        let (marker, replaced) = self
+            .state
+            .markers
+            .record(&args.name, args.filter, rows)
+            .map_err(McpError::InvalidArguments)?;
+
+        let mut summary = format!(
+            "{} {:?} with {} process(es){}{:.1}% CPU{}{} resident",
+            style.paint(Color::Green, if replaced { "Marker replaced:" } else { "Marker recorded:" }),
+            marker.name,
+            marker.totals.count,
+            style.sep(),
+            marker.totals.cpu_percent,
+            style.sep(),
+            human_bytes(marker.totals.rss_bytes)
+        );
+        if verbosity != SummaryVerbosity::Quiet
+            && let Some(filter) = &marker.filter
+        {
+            summary.push_str(&format!("\n{} Filter: {filter}", style.bullet()));
+        }
+        let markers = self.state.markers.names();
+        if verbosity == SummaryVerbosity::Detailed {
+            summary.push_str(&format!("\n{} Markers: {}", style.bullet(), markers.join(", ")));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessMarkOutput {
+                success: true,
+                marker,
+                replaced,
+                markers,
+            },
+        ))
+    }
+}
@@ -0,0 +1,178 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, DETAIL_ROWS, human_bytes, truncate};
+use crate::process_list::ProcessListTool;
+use crate::schema::{
+    PROCESS_MARK_COMPARE, ProcessListArgs, ProcessMarkCompareArgs, ProcessMarkCompareOutput,
+    ProcessMarkComparePrompts, SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessMarkCompareTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessMarkCompareTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessMarkCompareTool {
+    type Args = ProcessMarkCompareArgs;
+    type Prompts = ProcessMarkComparePrompts;
+
+    fn name() -> &'static str {
+        PROCESS_MARK_COMPARE
+    }
+
+    fn description() -> &'static str {
+        "Compare the current process table against a marker recorded with process_mark: \
+         processes started and gone since (PIDs are matched with their start time, so a \
+         restart shows as both), processes whose CPU or memory moved noticeably with \
+         before/after values, and total count, CPU and memory on both sides. drop=true \
+         deletes the marker afterwards."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessMarkCompareOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let marker = self.state.markers.get(&args.name).ok_or_else(|| {
+            let known = self.state.markers.names();
+            McpError::ResourceNotFound(if known.is_empty() {
+                format!("Marker {:?} is not recorded (no markers yet; use process_mark)", args.name)
+            } else {
+                format!("Marker {:?} is not recorded (known: {})", args.name, known.join(", "))
+            })
+        })?;
+
+        let (rows, ..) = ProcessListTool::new(self.config.clone(), self.state.clone())
+            .rows(ProcessListArgs {
+                filter: marker.info.filter.clone(),
+                ..ProcessListArgs::default()
+            })
+            .await?;
+        let mut comparison = marker.compare(rows);
+        let (added, removed, changed) = (comparison.added.len(), comparison.removed.len(), comparison.changed.len());
+        let truncated = args.limit > 0 && added.max(removed).max(changed) > args.limit;
+        if args.limit > 0 {
+            comparison.added.truncate(args.limit);
+            comparison.removed.truncate(args.limit);
+            comparison.changed.truncate(args.limit);
+        }
+        let dropped = args.drop && self.state.markers.remove(&args.name);
+
+        let growth = |before: u64, after: u64| {
+            let sign = if after < before { "-" } else { "+" };
+            format!("{sign}{}", human_bytes(after.abs_diff(before)))
+        };
+        let (before, after) = (comparison.before, comparison.after);
+        let mut summary = format!(
+            "{} {:?}{}{} new{}{} vanished{}{} changed{}{} unchanged",
+            style.paint(Color::Cyan, "Since marker"),
+            marker.info.name,
+            style.sep(),
+            added,
+            style.sep(),
+            removed,
+            style.sep(),
+            changed,
+            style.sep(),
+            comparison.unchanged
+        );
+        if truncated {
+            summary.push_str(&format!(" (lists cut at {})", args.limit));
+        }
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!(
+                "\n{} Processes {}{}{}{}CPU {:.1}%{}{:.1}%{}RSS {}{}{} ({})",
+                style.bullet(),
+                before.count,
+                style.arrow(),
+                after.count,
+                style.sep(),
+                before.cpu_percent,
+                style.arrow(),
+                after.cpu_percent,
+                style.sep(),
+                human_bytes(before.rss_bytes),
+                style.arrow(),
+                human_bytes(after.rss_bytes),
+                growth(before.rss_bytes, after.rss_bytes)
+            ));
+            summary.push_str(&format!("\n{} Marked at {}", style.bullet(), marker.info.taken_at));
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            for process in comparison.added.iter().take(DETAIL_ROWS) {
+                summary.push_str(&format!(
+                    "\n  {} PID {:6}{}{:20}{}Mem: {:>10}",
+                    style.paint(Color::Green, "+"),
+                    process.pid,
+                    style.sep(),
+                    truncate(&process.name, 20),
+                    style.sep(),
+                    human_bytes(process.rss_bytes)
+                ));
+            }
+            for process in comparison.removed.iter().take(DETAIL_ROWS) {
+                summary.push_str(&format!(
+                    "\n  {} PID {:6}{}{:20}{}Mem: {:>10}",
+                    style.paint(Color::Red, "-"),
+                    process.pid,
+                    style.sep(),
+                    truncate(&process.name, 20),
+                    style.sep(),
+                    human_bytes(process.rss_bytes)
+                ));
+            }
+            for change in comparison.changed.iter().take(DETAIL_ROWS) {
+                summary.push_str(&format!(
+                    "\n  ~ PID {:6}{}{:20}{}Mem: {:>10}{}{}{}CPU: {:.1}%{}{:.1}%",
+                    change.pid,
+                    style.sep(),
+                    truncate(&change.name, 20),
+                    style.sep(),
+                    human_bytes(change.rss_before),
+                    style.arrow(),
+                    human_bytes(change.rss_after),
+                    style.sep(),
+                    change.cpu_before,
+                    style.arrow(),
+                    change.cpu_after
+                ));
+            }
+        }
+        if dropped {
+            summary.push_str(&format!("\n{} Marker dropped", style.bullet()));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessMarkCompareOutput {
+                success: true,
+                marker: marker.info.clone(),
+                comparison,
+                truncated,
+                dropped,
+            },
+        ))
+    }
+}
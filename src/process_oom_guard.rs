@@ -0,0 +1,121 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, DETAIL_ROWS, human_bytes};
+use crate::schema::{
+    PROCESS_OOM_GUARD, ProcessOomGuardArgs, ProcessOomGuardOutput, ProcessOomGuardPrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessOomGuardTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessOomGuardTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessOomGuardTool {
+    type Args = ProcessOomGuardArgs;
+    type Prompts = ProcessOomGuardPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_OOM_GUARD
+    }
+
+    fn description() -> &'static str {
+        "Show the memory watchdog: the ceilings configured under oom_guard in process.json and \
+         the processes it acted on, newest first. A process whose resident memory exceeds the \
+         ceiling of the first rule matching its name is killed (term, wait 5 s, kill) or frozen \
+         once, before the kernel OOM killer picks a victim of its own. Use when a process \
+         vanished or stopped responding unexpectedly."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        false // Content changes with every intervention
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessOomGuardOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let guard = &self.state.oom_guard;
+        let rules = guard.rules();
+        let interventions = guard.recent(args.limit);
+
+        let mut summary = if guard.enabled() {
+            format!(
+                "{}: {} rule(s), {} intervention(s)",
+                style.paint(Color::Cyan, "OOM Guard"),
+                rules.len(),
+                interventions.len()
+            )
+        } else {
+            format!("{}: off (no oom_guard rules configured)", style.paint(Color::Cyan, "OOM Guard"))
+        };
+        if verbosity == SummaryVerbosity::Detailed {
+            for rule in &rules {
+                summary.push_str(&format!(
+                    "\n{} {} above {}{}{}",
+                    style.bullet(),
+                    rule.pattern,
+                    human_bytes(rule.memory_max_bytes),
+                    style.sep(),
+                    rule.action.name()
+                ));
+            }
+        }
+        let shown = match verbosity {
+            SummaryVerbosity::Quiet => 0,
+            SummaryVerbosity::Normal => 1,
+            SummaryVerbosity::Detailed => DETAIL_ROWS,
+        };
+        for entry in interventions.iter().take(shown) {
+            let color = if entry.success { Color::Red } else { Color::Cyan };
+            summary.push_str(&format!(
+                "\n{} #{} PID {} ({}) at {} over {}{}{}{}{}",
+                style.bullet(),
+                entry.id,
+                entry.pid,
+                entry.name,
+                human_bytes(entry.rss_bytes),
+                human_bytes(entry.memory_max_bytes),
+                style.sep(),
+                style.paint(color, &entry.outcome),
+                style.sep(),
+                entry.at
+            ));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessOomGuardOutput {
+                success: true,
+                enabled: guard.enabled(),
+                interval_ms: u64::try_from(guard.interval().as_millis()).unwrap_or(u64::MAX),
+                rules,
+                interventions,
+            },
+        ))
+    }
+}
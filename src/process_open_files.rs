@@ -0,0 +1,123 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, DETAIL_ROWS};
+use crate::identity;
+use crate::platform;
+use crate::schema::{
+    PROCESS_OPEN_FILES, ProcessOpenFilesArgs, ProcessOpenFilesOutput, ProcessOpenFilesPrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessOpenFilesTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessOpenFilesTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessOpenFilesTool {
+    type Args = ProcessOpenFilesArgs;
+    type Prompts = ProcessOpenFilesPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_OPEN_FILES
+    }
+
+    fn description() -> &'static str {
+        "List the open file descriptors of a process (handles on Windows) and what each refers \
+         to, like lsof -p: paths, plus sockets, pipes and other kinds. contains keeps only \
+         targets containing the text, e.g. \".log\" or \"(deleted)\" for deleted files still \
+         holding disk space on Linux. readable is false when the table could not be read; \
+         other users' processes need privileges. To find the processes holding a given file, \
+         use process_lock_check."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessOpenFilesOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+
+        let state = self.state.clone();
+        let contains = args.contains;
+        let output = tokio::task::spawn_blocking(move || {
+            let process = state
+                .provider
+                .lookup(pid, false)
+                .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?;
+            let files = platform::open_files(pid);
+            let readable = files.is_some();
+            let mut files = files.unwrap_or_default();
+            let total = files.len();
+            if let Some(text) = &contains {
+                files.retain(|file| file.target.contains(text.as_str()));
+            }
+            Ok::<_, McpError>(ProcessOpenFilesOutput {
+                success: true,
+                pid,
+                name: process.name,
+                files,
+                total,
+                readable,
+            })
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to list open files of process {pid}: {e}")))??;
+
+        let mut summary = if output.readable {
+            let mut headline = format!(
+                "{} PID {pid} ({}){}{} open",
+                style.paint(Color::Cyan, "Open Files:"),
+                output.name,
+                style.sep(),
+                output.total
+            );
+            if output.files.len() != output.total {
+                headline.push_str(&format!(", {} matching", output.files.len()));
+            }
+            headline
+        } else {
+            format!(
+                "{} PID {pid} ({}): descriptor table unreadable (another user's process?)",
+                style.paint(Color::Red, "Open Files:"),
+                output.name
+            )
+        };
+        if verbosity != SummaryVerbosity::Quiet {
+            let shown = if verbosity == SummaryVerbosity::Detailed { usize::MAX } else { DETAIL_ROWS };
+            for file in output.files.iter().take(shown) {
+                summary.push_str(&format!("\n{} {:>4} {}", style.bullet(), file.fd, file.target));
+            }
+            if output.files.len() > shown {
+                summary.push_str(&format!("\n{} {} {} more", style.bullet(), style.ellipsis(), output.files.len() - shown));
+            }
+        }
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
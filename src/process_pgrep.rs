@@ -0,0 +1,163 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use regex::RegexBuilder;
+use std::collections::HashMap;
+use std::sync::Arc;
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::schema::{
+    PROCESS_PGREP, PgrepMatch, ProcessPgrepArgs, ProcessPgrepOutput, ProcessPgrepPrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+/// Matches named in a Normal summary
+const SUMMARY_MATCHES: usize = 20;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessPgrepTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessPgrepTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessPgrepTool {
+    type Args = ProcessPgrepArgs;
+    type Prompts = ProcessPgrepPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_PGREP
+    }
+
+    fn description() -> &'static str {
+        "Find processes by regex, like pgrep: returns only pid and name of each match, sorted by \
+         PID, which is much lighter than process_list when an agent just needs a PID. The \
+         pattern is searched for in the process name (use ^ and $ to anchor it; Linux truncates \
+         names to 15 characters), or with full=true in the command line joined by spaces. \
+         Command lines are matched after secret redaction, and processes whose command line \
+         cannot be read are matched by name. ignore_case=true matches case-insensitively."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessPgrepOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let regex = RegexBuilder::new(&args.pattern)
+            .case_insensitive(args.ignore_case)
+            .build()
+            .map_err(|e| McpError::InvalidArguments(format!("Invalid pattern {:?}: {e}", args.pattern)))?;
+        // Regexes over raw command lines would let a caller probe for masked secrets
+        let redact = args.full && self.state.redactor.active(false)?;
+
+        let state = self.state.clone();
+        let full = args.full;
+        let matches = tokio::task::spawn_blocking(move || {
+            let processes = state.provider.enumerate();
+            let cmdlines: HashMap<u32, Vec<String>> = if full {
+                let mut system = System::new();
+                system.refresh_processes_specifics(
+                    ProcessesToUpdate::All,
+                    true,
+                    ProcessRefreshKind::nothing().with_cmd(UpdateKind::OnlyIfNotSet),
+                );
+                system
+                    .processes()
+                    .iter()
+                    .map(|(pid, process)| {
+                        let cmdline = process.cmd().iter().map(|arg| arg.to_string_lossy().to_string()).collect();
+                        (pid.as_u32(), cmdline)
+                    })
+                    .collect()
+            } else {
+                HashMap::new()
+            };
+
+            let mut matches: Vec<PgrepMatch> = processes
+                .into_iter()
+                .filter(|process| {
+                    if !full {
+                        return regex.is_match(&process.name);
+                    }
+                    // Empty when access is denied and for kernel threads; the per-process platform
+                    // fallbacks (WMI on Windows) are too slow to run for every process
+                    let Some(mut cmdline) = cmdlines.get(&process.pid).filter(|cmdline| !cmdline.is_empty()).cloned()
+                    else {
+                        return regex.is_match(&process.name);
+                    };
+                    if redact {
+                        state.redactor.cmdline(&mut cmdline);
+                    }
+                    regex.is_match(&cmdline.join(" "))
+                })
+                .map(|process| PgrepMatch {
+                    pid: process.pid,
+                    name: process.name,
+                })
+                .collect();
+            matches.sort_unstable_by_key(|found| found.pid);
+            matches
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Process search task failed: {e}")))?;
+
+        let mut summary = if matches.is_empty() {
+            format!(
+                "{} No process {} matches {:?}",
+                style.paint(Color::Red, "Not Found:"),
+                if full { "command line" } else { "name" },
+                args.pattern
+            )
+        } else {
+            format!(
+                "{} {} process(es) match {:?}",
+                style.paint(Color::Green, "Found:"),
+                matches.len(),
+                args.pattern
+            )
+        };
+        let shown = match verbosity {
+            SummaryVerbosity::Quiet => 0,
+            SummaryVerbosity::Normal => SUMMARY_MATCHES,
+            SummaryVerbosity::Detailed => matches.len(),
+        };
+        if shown > 0 && !matches.is_empty() {
+            let listed: Vec<String> = matches
+                .iter()
+                .take(shown)
+                .map(|found| format!("{} {}", found.pid, found.name))
+                .collect();
+            summary.push_str(&format!("\n{} {}", style.bullet(), listed.join(style.sep())));
+            if matches.len() > shown {
+                summary.push_str(&format!("{}+{} more", style.sep(), matches.len() - shown));
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessPgrepOutput {
+                success: !matches.is_empty(),
+                pattern: args.pattern,
+                matches,
+            },
+        ))
+    }
+}
@@ -0,0 +1,168 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::container::{self, short};
+use crate::display::Color;
+use crate::platform;
+use crate::schema::{
+    PROCESS_PID_TRANSLATE, ProcessPidTranslateArgs, ProcessPidTranslateOutput, ProcessPidTranslatePrompts,
+    SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessPidTranslateTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessPidTranslateTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessPidTranslateTool {
+    type Args = ProcessPidTranslateArgs;
+    type Prompts = ProcessPidTranslatePrompts;
+
+    fn name() -> &'static str {
+        PROCESS_PID_TRANSLATE
+    }
+
+    fn description() -> &'static str {
+        "Translate PIDs between the host and a container. With pid, report the PID the process \
+         has inside its container (as container logs and `ps` there show it), every nested \
+         namespace PID and the container ID. With container (ID or unique prefix) and \
+         container_pid, find the host PID to pass to kill tools. Reads NSpid from \
+         /proc/<pid>/status and the container ID from the cgroup path; on Kubernetes nodes the \
+         result names the pod too. Linux only."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        false // PIDs are reused after exits
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessPidTranslateOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        if !cfg!(target_os = "linux") {
+            return Err(McpError::Other(anyhow::anyhow!(
+                "PID namespaces are only available on Linux"
+            )));
+        }
+        let container = args.container.map(|container| container.trim().to_lowercase()).filter(|container| !container.is_empty());
+        let state = self.state.clone();
+        state.pods.refresh().await;
+
+        // One procfs read per process when searching a container
+        let output = tokio::task::spawn_blocking(move || match (args.pid, container, args.container_pid) {
+            (Some(pid), None, None) => {
+                let name = state
+                    .provider
+                    .lookup(pid, false)
+                    .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?
+                    .name;
+                translate(&state, pid, name)
+            }
+            (None, Some(container), Some(container_pid)) => {
+                let located = container::locate(&state, &container, container_pid)?;
+                translate(&state, located.host_pid, located.name)
+            }
+            _ => Err(McpError::InvalidArguments(
+                "Pass either pid, or container together with container_pid".to_string(),
+            )),
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("PID translation task failed: {e}")))??;
+
+        let mut summary = match &output.container_id {
+            Some(id) => format!(
+                "{} host PID {} ({}) is PID {} in container {}",
+                style.paint(Color::Cyan, "PID:"),
+                output.host_pid,
+                output.name,
+                output.container_pid,
+                short(id)
+            ),
+            None if output.in_container => format!(
+                "{} host PID {} ({}) is PID {} in its PID namespace",
+                style.paint(Color::Cyan, "PID:"),
+                output.host_pid,
+                output.name,
+                output.container_pid
+            ),
+            None => format!(
+                "{} PID {} ({}) is not in a container{}same PID everywhere",
+                style.paint(Color::Cyan, "PID:"),
+                output.host_pid,
+                output.name,
+                style.sep()
+            ),
+        };
+        if verbosity != SummaryVerbosity::Quiet
+            && let Some(pod) = &output.pod
+        {
+            let name = pod.qualified_name();
+            match &pod.container {
+                Some(container) => summary.push_str(&format!("\n{} Pod: {name}{}Container: {container}", style.bullet(), style.sep())),
+                None => summary.push_str(&format!("\n{} Pod: {name}", style.bullet())),
+            }
+        }
+        if verbosity != SummaryVerbosity::Quiet && output.namespace_pids.len() > 2 {
+            let chain: Vec<String> = output.namespace_pids.iter().map(u32::to_string).collect();
+            summary.push_str(&format!("\n{} Nested namespaces: {}", style.bullet(), chain.join(style.arrow())));
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            if let Some(namespace) = &output.pid_namespace {
+                summary.push_str(&format!("\n{} Namespace: {namespace}", style.bullet()));
+            }
+            if let Some(id) = &output.container_id {
+                summary.push_str(&format!("\n{} Container ID: {id}", style.bullet()));
+            }
+        }
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
+
+// ============================================================================
+// TRANSLATION
+// ============================================================================
+
+/// Container view of the host process `pid`
+fn translate(state: &ProcessState, pid: u32, name: String) -> Result<ProcessPidTranslateOutput, McpError> {
+    let namespace_pids = platform::namespace_pids(pid)
+        .filter(|pids| !pids.is_empty())
+        .ok_or_else(|| McpError::ResourceNotFound(format!("Cannot read the namespace PIDs of process {pid} (needs Linux 4.1+)")))?;
+    let container_id = platform::container_id(pid);
+    Ok(ProcessPidTranslateOutput {
+        success: true,
+        host_pid: pid,
+        container_pid: namespace_pids.last().copied().unwrap_or(pid),
+        in_container: namespace_pids.len() > 1 || container_id.is_some(),
+        pid_namespace: platform::namespaces(pid)
+            .and_then(|namespaces| namespaces.into_iter().find(|namespace| namespace.kind == "pid"))
+            .map(|namespace| namespace.id),
+        namespace_pids,
+        container_id,
+        pod: state.pods.pod(pid),
+        name,
+    })
+}
@@ -0,0 +1,90 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::pidfile;
+use crate::schema::{
+    PROCESS_PIDFILE, ProcessPidfileArgs, ProcessPidfileOutput, ProcessPidfilePrompts, SummaryStyle, SummaryVerbosity,
+};
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessPidfileTool {
+    config: Arc<ProcessConfig>,
+}
+
+impl ProcessPidfileTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>) -> Self {
+        Self { config }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessPidfileTool {
+    type Args = ProcessPidfileArgs;
+    type Prompts = ProcessPidfilePrompts;
+
+    fn name() -> &'static str {
+        PROCESS_PIDFILE
+    }
+
+    fn description() -> &'static str {
+        "Read a pidfile and validate it: the PID must be running, match expected_name (process \
+         or executable name, case-insensitive) when given, and must not have started after the \
+         file was written (a recycled PID). Reports stale=true with a reason otherwise."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        false // Liveness changes over time
+    }
+
+    async fn execute(&self, args: Self::Args, ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessPidfileOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let path = pidfile::resolve(&args.path, ctx.pwd());
+        let expected = args.expected_name.clone();
+        let status = tokio::task::spawn_blocking(move || pidfile::check(&path, expected.as_deref()))
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Pidfile check task failed: {e}")))?;
+
+        let mut summary = match (status.stale, status.pid, &status.name) {
+            (false, Some(pid), Some(name)) => {
+                style.paint(Color::Green, &format!("Pidfile OK: PID {pid} ({name}) is running"))
+            }
+            _ => style.paint(
+                Color::Red,
+                &format!("Pidfile stale: {}", status.reason.as_deref().unwrap_or("unknown")),
+            ),
+        };
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!("\n{} Path: {}", style.bullet(), status.path));
+        }
+        if verbosity == SummaryVerbosity::Detailed
+            && let Some(written_at) = &status.written_at
+        {
+            summary.push_str(&format!("\n{} Written: {written_at}", style.bullet()));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessPidfileOutput {
+                success: true,
+                pidfile: status,
+            },
+        ))
+    }
+}
@@ -0,0 +1,139 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, DETAIL_ROWS};
+use crate::pidfile;
+use crate::schema::{
+    PROCESS_PIDFILE_CLEANUP, ProcessPidfileCleanupArgs, ProcessPidfileCleanupOutput, ProcessPidfileCleanupPrompts,
+    SummaryStyle, SummaryVerbosity,
+};
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessPidfileCleanupTool {
+    config: Arc<ProcessConfig>,
+}
+
+impl ProcessPidfileCleanupTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>) -> Self {
+        Self { config }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessPidfileCleanupTool {
+    type Args = ProcessPidfileCleanupArgs;
+    type Prompts = ProcessPidfileCleanupPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_PIDFILE_CLEANUP
+    }
+
+    fn description() -> &'static str {
+        "Check pidfiles and delete the stale ones: naming a dead PID, a process other than \
+         expected_name, or a PID recycled after the file was written. Files naming a live \
+         matching process are kept, and so are files that do not contain a PID, which are \
+         reported as invalid instead. Use dry_run=true to preview."
+    }
+
+    fn read_only() -> bool {
+        false // Deletes files
+    }
+
+    fn destructive() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        true // Removing an already-removed stale file is a no-op
+    }
+
+    async fn execute(&self, args: Self::Args, ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessPidfileCleanupOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        if args.paths.is_empty() {
+            return Err(McpError::InvalidArguments("paths must not be empty".to_string()));
+        }
+        let paths: Vec<_> = args.paths.iter().map(|path| pidfile::resolve(path, ctx.pwd())).collect();
+        let expected = args.expected_name.clone();
+        let dry_run = args.dry_run;
+
+        let (checked, removed, failed, invalid) = tokio::task::spawn_blocking(move || {
+            let mut checked = Vec::new();
+            let mut removed = Vec::new();
+            let mut failed = Vec::new();
+            let mut invalid = Vec::new();
+            for path in paths {
+                let status = pidfile::check(&path, expected.as_deref());
+                // Missing files have nothing to remove; a file without a PID may be anything
+                if status.exists && status.pid.is_none() {
+                    invalid.push(status.path.clone());
+                } else if status.stale && status.exists {
+                    let result = if dry_run { Ok(()) } else { std::fs::remove_file(&path) };
+                    match result {
+                        Ok(()) => removed.push(status.path.clone()),
+                        Err(e) => failed.push(format!("{}: {e}", status.path)),
+                    }
+                }
+                checked.push(status);
+            }
+            (checked, removed, failed, invalid)
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Pidfile cleanup task failed: {e}")))?;
+
+        let verb = if dry_run { "Would remove" } else { "Removed" };
+        let mut summary = format!(
+            "{}: {} stale{}{} checked",
+            style.paint(Color::Cyan, verb),
+            removed.len(),
+            style.sep(),
+            checked.len()
+        );
+        if !invalid.is_empty() {
+            summary.push_str(&format!("{}{} invalid (kept)", style.sep(), invalid.len()));
+        }
+        if !failed.is_empty() {
+            summary.push_str(&format!(
+                "{}{}",
+                style.sep(),
+                style.paint(Color::Red, &format!("{} failed", failed.len()))
+            ));
+        }
+        let shown = match verbosity {
+            SummaryVerbosity::Quiet => 0,
+            SummaryVerbosity::Normal | SummaryVerbosity::Detailed => DETAIL_ROWS,
+        };
+        for status in checked.iter().take(shown) {
+            let verdict = match (&status.reason, verbosity) {
+                (None, _) => "kept".to_string(),
+                (Some(reason), _) if status.exists && status.pid.is_none() => format!("invalid, {reason}, kept"),
+                (Some(reason), SummaryVerbosity::Detailed) => format!("stale, {reason}"),
+                (Some(_), _) => "stale".to_string(),
+            };
+            summary.push_str(&format!("\n{} {}: {verdict}", style.bullet(), status.path));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessPidfileCleanupOutput {
+                success: failed.is_empty(),
+                dry_run,
+                checked,
+                removed,
+                failed,
+                invalid,
+            },
+        ))
+    }
+}
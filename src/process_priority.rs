@@ -0,0 +1,135 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::identity;
+use crate::platform::{self, MAX_NICE, MIN_NICE};
+use crate::process_renice::nice_error;
+use crate::protection::protected_reason;
+use crate::schema::{
+    PROCESS_PRIORITY, ProcessPriorityArgs, ProcessPriorityOutput, ProcessPriorityPrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessPriorityTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessPriorityTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessPriorityTool {
+    type Args = ProcessPriorityArgs;
+    type Prompts = ProcessPriorityPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_PRIORITY
+    }
+
+    fn description() -> &'static str {
+        "Read the scheduling priority of one process, or set it by passing nice (-20 highest to 19 \
+         lowest), e.g. nice=19 to make a background build yield without killing it. Raising \
+         priority (a nice value below the current one) needs root or CAP_SYS_NICE, or an \
+         elevated server on Windows, and is refused with a permission error otherwise; \
+         protected processes are refused too. On Windows the nearest priority class is set and \
+         reported in priority_class. Use process_renice to change many processes at once."
+    }
+
+    fn read_only() -> bool {
+        false // Changes scheduling priority when nice is given
+    }
+
+    fn idempotent() -> bool {
+        true // Setting the same nice value again changes nothing
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessPriorityOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        if let Some(nice) = args.nice
+            && !(MIN_NICE..=MAX_NICE).contains(&nice)
+        {
+            return Err(McpError::InvalidArguments(format!(
+                "nice must be between {MIN_NICE} and {MAX_NICE}, got {nice}"
+            )));
+        }
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+
+        let state = self.state.clone();
+        let wanted = args.nice;
+        let output = tokio::task::spawn_blocking(move || {
+            let process = state
+                .provider
+                .lookup(pid, false)
+                .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?;
+            let before = platform::nice(pid);
+            if let Some(nice) = wanted {
+                if let Some(reason) = protected_reason(pid, &process.name, process.parent) {
+                    return Err(McpError::PermissionDenied(format!(
+                        "Refusing to change the priority of process {pid} ({}): {reason}",
+                        process.name
+                    )));
+                }
+                platform::set_nice(pid, nice).map_err(|e| {
+                    let reason = nice_error(pid, before, nice, &e);
+                    match e.kind() {
+                        std::io::ErrorKind::PermissionDenied => McpError::PermissionDenied(format!(
+                            "Cannot set process {pid} to nice {nice}: {reason}"
+                        )),
+                        std::io::ErrorKind::NotFound => McpError::ResourceNotFound(format!("Process {pid} {reason}")),
+                        _ => McpError::Other(anyhow::anyhow!("Failed to set the priority of process {pid}: {reason}")),
+                    }
+                })?;
+            }
+            let nice = if wanted.is_some() { platform::nice(pid) } else { before };
+            Ok(ProcessPriorityOutput {
+                success: true,
+                pid,
+                name: process.name,
+                before,
+                nice,
+                priority_class: nice.and_then(platform::priority_class_name).map(str::to_string),
+                changed: wanted.is_some(),
+            })
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Priority task for process {pid} failed: {e}")))??;
+
+        let current = output.nice.map_or_else(|| "unknown".to_string(), |nice| nice.to_string());
+        let mut summary = if output.changed {
+            format!(
+                "{} PID {pid} ({}) nice {}{}{current}",
+                style.paint(Color::Green, "Priority Set:"),
+                output.name,
+                output.before.map_or_else(|| "?".to_string(), |before| before.to_string()),
+                style.arrow()
+            )
+        } else {
+            format!("{} PID {pid} ({}) nice {current}", style.paint(Color::Cyan, "Priority:"), output.name)
+        };
+        if verbosity != SummaryVerbosity::Quiet
+            && let Some(class) = &output.priority_class
+        {
+            summary.push_str(&format!("\n{} Priority class: {class}", style.bullet()));
+        }
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
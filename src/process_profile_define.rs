@@ -0,0 +1,134 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::schema::{
+    PROCESS_PROFILE_DEFINE, ProcessProfileDefineArgs, ProcessProfileDefineOutput, ProcessProfileDefinePrompts,
+    SpawnProfile, SummaryStyle, SummaryVerbosity,
+};
+use crate::spawn::SpawnSpec;
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessProfileDefineTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessProfileDefineTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessProfileDefineTool {
+    type Args = ProcessProfileDefineArgs;
+    type Prompts = ProcessProfileDefinePrompts;
+
+    fn name() -> &'static str {
+        PROCESS_PROFILE_DEFINE
+    }
+
+    fn description() -> &'static str {
+        "Define a spawn profile at runtime: a fixed command, arguments, cwd, environment, nice, \
+         cpus, sandbox, cgroup limits and restart policy (never, on_failure, always) that \
+         process_profile_start launches by name. Only declared params can vary; each value \
+         replaces its {param} placeholder after matching the param's regex (by default one word \
+         not starting with '-'). Replaces a runtime profile of the same name; delete=true removes \
+         one. Only available when the server config sets spawn_profiles.allow_define, and \
+         profiles from the config file cannot be changed."
+    }
+
+    fn read_only() -> bool {
+        false // Modifies server-side profile definitions
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessProfileDefineOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let name = args.profile.name.trim().to_string();
+
+        if args.delete {
+            self.state.profiles.remove(&name)?;
+            return Ok(ToolResponse::new(
+                format!("{} {name}", style.paint(Color::Cyan, "Profile Deleted:")),
+                ProcessProfileDefineOutput {
+                    success: true,
+                    name,
+                    deleted: true,
+                    replaced: true,
+                    command: String::new(),
+                    params: Vec::new(),
+                },
+            ));
+        }
+
+        let profile = SpawnProfile { name, ..args.profile };
+        let command = template_line(&profile);
+        let params: Vec<String> = profile.params.iter().map(|param| param.name.clone()).collect();
+        let mut details = String::new();
+        if verbosity != SummaryVerbosity::Quiet {
+            details.push_str(&format!("\n{} Command: {command}", style.bullet()));
+            details.push_str(&format!(
+                "\n{} Params: {}{}Restart: {}",
+                style.bullet(),
+                if params.is_empty() { "none".to_string() } else { params.join(", ") },
+                style.sep(),
+                profile.restart.name()
+            ));
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            for param in &profile.params {
+                details.push_str(&format!(
+                    "\n{} {}: {}{}",
+                    style.bullet(),
+                    param.name,
+                    param.pattern.as_deref().unwrap_or("one word"),
+                    param.default.as_ref().map_or_else(String::new, |default| format!(" (default {default})"))
+                ));
+            }
+        }
+        let name = profile.name.clone();
+        let replaced = self.state.profiles.define(profile, &self.config.sandbox)?;
+
+        let headline = if replaced { "Profile Replaced:" } else { "Profile Defined:" };
+        let summary = format!("{} {name}{details}", style.paint(Color::Green, headline));
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessProfileDefineOutput {
+                success: true,
+                name,
+                deleted: false,
+                replaced,
+                command,
+                params,
+            },
+        ))
+    }
+}
+
+/// Command line of `profile` with its placeholders left in
+pub(crate) fn template_line(profile: &SpawnProfile) -> String {
+    SpawnSpec {
+        program: profile.command.clone(),
+        args: profile.args.clone(),
+        cwd: None,
+        env: None,
+        io_priority: None,
+        nice: None,
+        cpus: Vec::new(),
+    }
+    .command_line()
+}
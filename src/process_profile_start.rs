@@ -0,0 +1,130 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, truncate};
+use crate::process_contain::caps_text;
+use crate::profiles;
+use crate::schema::{
+    PROCESS_PROFILE_START, ProcessProfileStartArgs, ProcessProfileStartOutput, ProcessProfileStartPrompts,
+    RestartPolicy, SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessProfileStartTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessProfileStartTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessProfileStartTool {
+    type Args = ProcessProfileStartArgs;
+    type Prompts = ProcessProfileStartPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_PROFILE_START
+    }
+
+    fn description() -> &'static str {
+        "Start a spawn profile by name (see process_profiles). The command, arguments, cwd, \
+         environment, sandbox and limits are fixed by the profile; params only fill in its \
+         declared {param} placeholders and are refused unless they match the param's pattern. \
+         The process runs supervised in the background: follow it with process_logs_follow or \
+         process_status using the returned supervisor_id. With a restart policy the server \
+         starts it again after it exits, up to max_restarts; a kill through the server stops that."
+    }
+
+    fn read_only() -> bool {
+        false // Starts a process
+    }
+
+    fn destructive() -> bool {
+        true // The profile's command may modify anything the server can
+    }
+
+    fn idempotent() -> bool {
+        false
+    }
+
+    fn open_world() -> bool {
+        true // Commands may reach the network or other systems
+    }
+
+    async fn execute(&self, args: Self::Args, ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessProfileStartOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let (profile, _) = self.state.profiles.get(&args.profile).ok_or_else(|| {
+            McpError::ResourceNotFound(format!("Spawn profile {:?} is not defined (see process_profiles)", args.profile))
+        })?;
+        let resolved =
+            profiles::resolve(&profile, &args.params, &self.config.sandbox).map_err(McpError::InvalidArguments)?;
+        let supervised = profiles::start(&self.state, profile.clone(), resolved.spec, ctx.connection_id()).await?;
+
+        let mut summary = format!(
+            "{} {} as PID {}{}{}",
+            style.paint(Color::Green, "Started"),
+            profile.name,
+            supervised.pid,
+            style.sep(),
+            truncate(&resolved.command, 60)
+        );
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!("\n{} Supervisor id: {}", style.bullet(), supervised.id));
+            if profile.restart != RestartPolicy::Never {
+                summary.push_str(&format!(
+                    "{}restart {} (up to {})",
+                    style.sep(),
+                    profile.restart.name(),
+                    profile.max_restarts
+                ));
+            }
+            if let Some(sandbox) = &resolved.sandbox {
+                summary.push_str(&format!("{}sandbox {sandbox}", style.sep()));
+            }
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            if let Some(cwd) = &resolved.cwd {
+                summary.push_str(&format!("\n{} Cwd: {cwd}", style.bullet()));
+            }
+            if let Some(caps) = profile.limits.filter(|caps| !caps.is_empty()) {
+                summary.push_str(&format!("\n{} Limits: {}", style.bullet(), caps_text(&caps)));
+            }
+            if let Some(log_dir) = &supervised.log_dir {
+                summary.push_str(&format!("\n{} Logs: {log_dir}", style.bullet()));
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessProfileStartOutput {
+                success: true,
+                profile: profile.name.clone(),
+                supervisor_id: supervised.id,
+                pid: supervised.pid,
+                command: resolved.command,
+                cwd: resolved.cwd,
+                log_dir: supervised.log_dir,
+                sandbox: resolved.sandbox,
+                limits: profile.limits.filter(|caps| !caps.is_empty()),
+                restart: profile.restart,
+            },
+        ))
+    }
+}
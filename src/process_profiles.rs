@@ -0,0 +1,147 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::process_contain::caps_text;
+use crate::process_profile_define::template_line;
+use crate::schema::{
+    PROCESS_PROFILES, ProcessProfilesArgs, ProcessProfilesOutput, ProcessProfilesPrompts, ProfileInfo, ProfileSource,
+    RestartPolicy, SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessProfilesTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessProfilesTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessProfilesTool {
+    type Args = ProcessProfilesArgs;
+    type Prompts = ProcessProfilesPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_PROFILES
+    }
+
+    fn description() -> &'static str {
+        "List the spawn profiles process_profile_start can launch: the operator-approved command \
+         with its {param} placeholders, each param's default and pattern, cwd, limits, sandbox and \
+         restart policy, whether it comes from the config or process_profile_define, and the \
+         launches made from it (current supervisor id and PID, running, restart count)."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessProfilesOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let profiles = match &args.name {
+            Some(name) => vec![
+                self.state
+                    .profiles
+                    .get(name)
+                    .ok_or_else(|| McpError::ResourceNotFound(format!("Spawn profile {name:?} is not defined")))?,
+            ],
+            None => self.state.profiles.all(),
+        };
+        let profiles: Vec<ProfileInfo> = profiles
+            .into_iter()
+            .map(|(profile, source)| ProfileInfo {
+                launches: self.state.profiles.launches(&profile.name, &self.state),
+                profile: (*profile).clone(),
+                source,
+            })
+            .collect();
+        let allow_define = self.state.profiles.allow_define();
+
+        let mut summary = format!(
+            "{} {} profiles{}runtime definitions {}",
+            style.paint(Color::Cyan, "Spawn Profiles:"),
+            profiles.len(),
+            style.sep(),
+            if allow_define { "allowed" } else { "disabled" }
+        );
+        if verbosity != SummaryVerbosity::Quiet {
+            for info in &profiles {
+                let profile = &info.profile;
+                let running = info.launches.iter().filter(|launch| launch.running).count();
+                summary.push_str(&format!(
+                    "\n{} {}{}: {}",
+                    style.bullet(),
+                    profile.name,
+                    if info.source == ProfileSource::Defined { " (defined)" } else { "" },
+                    template_line(profile)
+                ));
+                if running > 0 {
+                    summary.push_str(&format!("{}{}", style.sep(), style.paint(Color::Green, &format!("{running} running"))));
+                }
+                if verbosity == SummaryVerbosity::Detailed {
+                    if !profile.description.is_empty() {
+                        summary.push_str(&format!("\n  {}", profile.description));
+                    }
+                    for param in &profile.params {
+                        summary.push_str(&format!(
+                            "\n  {{{}}}: {}{}",
+                            param.name,
+                            param.pattern.as_deref().unwrap_or("one word"),
+                            param.default.as_ref().map_or_else(String::new, |default| format!(" (default {default})"))
+                        ));
+                    }
+                    if profile.restart != RestartPolicy::Never {
+                        summary.push_str(&format!(
+                            "\n  Restart: {} (up to {}, {}s apart)",
+                            profile.restart.name(),
+                            profile.max_restarts,
+                            profile.restart_delay_secs
+                        ));
+                    }
+                    if let Some(caps) = profile.limits.filter(|caps| !caps.is_empty()) {
+                        summary.push_str(&format!("\n  Limits: {}", caps_text(&caps)));
+                    }
+                    for launch in &info.launches {
+                        summary.push_str(&format!(
+                            "\n  #{} PID {}{}{}{}{} restarts",
+                            launch.supervisor_id,
+                            launch.pid,
+                            style.sep(),
+                            if launch.running { "running" } else { "exited" },
+                            style.sep(),
+                            launch.restarts
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessProfilesOutput {
+                success: true,
+                count: profiles.len(),
+                allow_define,
+                profiles,
+            },
+        ))
+    }
+}
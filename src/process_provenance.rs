@@ -0,0 +1,215 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::identity;
+use crate::platform;
+use crate::schema::{
+    LaunchOrigin, PROCESS_PROVENANCE, ProcessAncestor, ProcessProvenanceArgs, ProcessProvenanceOutput,
+    ProcessProvenancePrompts, SummaryStyle, SummaryVerbosity,
+};
+use crate::spawn::SPAWN_MARKER_ENV;
+use crate::state::ProcessState;
+
+/// Ancestors followed before giving up on a (corrupt or cyclic) parent chain
+const MAX_DEPTH: usize = 64;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessProvenanceTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessProvenanceTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessProvenanceTool {
+    type Args = ProcessProvenanceArgs;
+    type Prompts = ProcessProvenancePrompts;
+
+    fn name() -> &'static str {
+        PROCESS_PROVENANCE
+    }
+
+    fn description() -> &'static str {
+        "Explain how a process came to exist: its ancestor chain up to the root with names and \
+         users, its controlling terminal (\"none\" for daemons) or systemd unit, its start time, \
+         and for processes launched through this server, or descended from one, the tool and \
+         client connection that launched it. broken_at names the parent PID where the chain \
+         ends early because that process is gone."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessProvenanceOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let pid = args.pid;
+        let state = self.state.clone();
+
+        // Provider lookups block on OS calls
+        let output = tokio::task::spawn_blocking(move || provenance(&state, pid))
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to trace process {pid}: {e}")))?
+            .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?;
+
+        let mut summary = format!(
+            "{} PID {pid} ({}) started {}",
+            style.paint(Color::Cyan, "Provenance:"),
+            output.name,
+            output.started_at
+        );
+        if let Some(launch) = &output.launched_by {
+            summary.push_str(&format!(
+                "{}{}",
+                style.sep(),
+                style.paint(Color::Green, &format!("launched via {} (#{})", launch.origin, launch.supervisor_id))
+            ));
+        }
+        if verbosity != SummaryVerbosity::Quiet {
+            let chain: Vec<String> = std::iter::once(format!("{} ({pid})", output.name))
+                .chain(output.ancestors.iter().map(|ancestor| format!("{} ({})", ancestor.name, ancestor.pid)))
+                .collect();
+            summary.push_str(&format!("\n{} Chain: {}", style.bullet(), chain.join(style.back_arrow())));
+            if let Some(parent) = output.broken_at {
+                summary.push_str(&format!("{}PID {parent} (exited)", style.back_arrow()));
+            }
+            let mut origin = Vec::new();
+            if let Some(tty) = &output.tty {
+                origin.push(format!("TTY: {tty}"));
+            }
+            if let Some(unit) = &output.unit {
+                origin.push(format!("Unit: {unit}"));
+            }
+            if let Some(user) = &output.user {
+                origin.push(format!("User: {user}"));
+            }
+            if !origin.is_empty() {
+                summary.push_str(&format!("\n{} {}", style.bullet(), origin.join(style.sep())));
+            }
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            if let Some(launch) = &output.launched_by {
+                let relation = if launch.direct {
+                    "launched directly".to_string()
+                } else {
+                    format!("descends from PID {}", launch.pid)
+                };
+                summary.push_str(&format!(
+                    "\n{} {relation}{}client {}{}{}",
+                    style.bullet(),
+                    style.sep(),
+                    launch.client.as_deref().unwrap_or("unknown"),
+                    style.sep(),
+                    launch.command
+                ));
+            }
+            for ancestor in &output.ancestors {
+                summary.push_str(&format!(
+                    "\n{} PID {} ({}){}{}",
+                    style.bullet(),
+                    ancestor.pid,
+                    ancestor.name,
+                    style.sep(),
+                    ancestor.user.as_deref().unwrap_or("unknown user")
+                ));
+            }
+        }
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
+
+// ============================================================================
+// TRACING
+// ============================================================================
+
+/// Build the report for `pid`; `None` if it does not exist
+fn provenance(state: &ProcessState, pid: u32) -> Option<ProcessProvenanceOutput> {
+    let process = state.provider.lookup(pid, true)?;
+
+    // Walk up while each parent is older than its child; a younger one took over a reused PID
+    let mut ancestors: Vec<ProcessAncestor> = Vec::new();
+    let mut broken_at = None;
+    let (mut parent, mut child_start) = (process.parent, process.start_time);
+    while let Some(ppid) = parent
+        && ancestors.len() < MAX_DEPTH
+    {
+        match state.provider.lookup(ppid, true).filter(|ancestor| ancestor.start_time <= child_start) {
+            Some(ancestor) => {
+                (parent, child_start) = (ancestor.parent.filter(|next| *next != ppid), ancestor.start_time);
+                ancestors.push(ProcessAncestor {
+                    pid: ancestor.pid,
+                    name: ancestor.name,
+                    user: ancestor.user,
+                    start_time: ancestor.start_time,
+                });
+            }
+            None => {
+                broken_at = Some(ppid);
+                break;
+            }
+        }
+    }
+
+    // The spawn marker survives reparenting; the chain covers processes whose environment is unreadable
+    let marker = process
+        .env
+        .iter()
+        .find_map(|entry| entry.strip_prefix(SPAWN_MARKER_ENV)?.strip_prefix('='));
+    let launched = marker.and_then(|marker| state.supervisor.by_marker(marker)).or_else(|| {
+        std::iter::once(pid)
+            .chain(ancestors.iter().map(|ancestor| ancestor.pid))
+            .find_map(|pid| state.supervisor.list(None, Some(pid)).into_iter().find(|launch| launch.running))
+    });
+    let launched_by = launched.map(|launch| LaunchOrigin {
+        supervisor_id: launch.id,
+        pid: launch.pid,
+        direct: launch.pid == pid,
+        origin: launch.origin,
+        client: launch.client,
+        command: launch.command,
+        started_at: launch.started_at,
+    });
+
+    let started_at = i64::try_from(process.start_time)
+        .ok()
+        .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+        .map_or_else(|| process.start_time.to_string(), |started| started.to_rfc3339());
+    Some(ProcessProvenanceOutput {
+        success: true,
+        pid,
+        name: process.name,
+        user: process.user,
+        exe: process.exe.map(|exe| exe.display().to_string()),
+        start_time: process.start_time,
+        proc_id: identity::proc_id(pid, process.start_time),
+        started_at,
+        tty: platform::controlling_tty(pid),
+        unit: platform::service_unit(pid),
+        ancestors,
+        broken_at,
+        launched_by,
+    })
+}
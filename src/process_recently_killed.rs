@@ -0,0 +1,107 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, DETAIL_ROWS, truncate};
+use crate::schema::{
+    PROCESS_RECENTLY_KILLED, ProcessRecentlyKilledArgs, ProcessRecentlyKilledOutput, ProcessRecentlyKilledPrompts,
+    SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessRecentlyKilledTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessRecentlyKilledTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessRecentlyKilledTool {
+    type Args = ProcessRecentlyKilledArgs;
+    type Prompts = ProcessRecentlyKilledPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_RECENTLY_KILLED
+    }
+
+    fn description() -> &'static str {
+        "List processes recently terminated through process_kill, newest first. Each entry is a \
+         snapshot taken right before the kill: full cmdline, executable, cwd, environment, owner \
+         and start time. Secret-looking environment values and arguments are masked per \
+         the server's redaction policy. Use to answer what exactly was killed, or before relaunching it."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        false // Content changes with every kill
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessRecentlyKilledOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let redact = self.state.redactor.active(args.reveal_secrets)?;
+        let mut entries = self.state.killed.recent(args.pid, args.limit);
+        let redacted = if redact {
+            entries
+                .iter_mut()
+                .map(|entry| self.state.redactor.env(&mut entry.env) + self.state.redactor.cmdline(&mut entry.cmdline))
+                .sum()
+        } else {
+            0
+        };
+
+        let mut summary = format!("{}: {}", style.paint(Color::Cyan, "Recently Killed"), entries.len());
+        if !self.state.killed.enabled() {
+            summary.push_str(&format!("{}recording disabled", style.sep()));
+        }
+        if redacted > 0 && verbosity == SummaryVerbosity::Detailed {
+            summary.push_str(&format!("{}{redacted} secrets redacted", style.sep()));
+        }
+        let shown = match verbosity {
+            SummaryVerbosity::Quiet => 0,
+            SummaryVerbosity::Normal => 1,
+            SummaryVerbosity::Detailed => DETAIL_ROWS,
+        };
+        for entry in entries.iter().take(shown) {
+            summary.push_str(&format!(
+                "\n{} #{} PID {} ({}) at {}{}{}",
+                style.bullet(),
+                entry.id,
+                entry.pid,
+                entry.name,
+                entry.killed_at,
+                style.sep(),
+                truncate(&entry.cmdline.join(" "), 60)
+            ));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessRecentlyKilledOutput {
+                success: true,
+                count: entries.len(),
+                entries,
+                redacted,
+            },
+        ))
+    }
+}
@@ -0,0 +1,217 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::identity;
+use crate::platform::{self, MAX_NICE, MIN_NICE};
+use crate::protection::protected_reason;
+use crate::schema::{
+    PROCESS_RENICE, ProcessReniceArgs, ProcessReniceOutput, ProcessRenicePrompts, ReniceResult, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessReniceTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessReniceTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessReniceTool {
+    type Args = ProcessReniceArgs;
+    type Prompts = ProcessRenicePrompts;
+
+    fn name() -> &'static str {
+        PROCESS_RENICE
+    }
+
+    fn description() -> &'static str {
+        "Change the scheduling priority of every process matching filter (name substring), group \
+         and/or pids to the given nice value (-20 highest to 19 lowest). Use it to slow down \
+         background work such as compilers instead of killing it. Returns before/after values per \
+         process; dry_run=true previews. Raising priority usually needs root; protected processes \
+         are skipped. On Windows the nearest priority class is used."
+    }
+
+    fn read_only() -> bool {
+        false // Changes scheduling priority
+    }
+
+    fn idempotent() -> bool {
+        true // Setting the same nice value again changes nothing
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessReniceOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        if !(MIN_NICE..=MAX_NICE).contains(&args.nice) {
+            return Err(McpError::InvalidArguments(format!(
+                "nice must be between {MIN_NICE} and {MAX_NICE}, got {}",
+                args.nice
+            )));
+        }
+        if args.filter.is_none() && args.group.is_none() && args.pids.is_empty() && args.proc_ids.is_empty() {
+            return Err(McpError::InvalidArguments(
+                "Pass filter, group, pids or proc_ids to select processes".to_string(),
+            ));
+        }
+        let handles = args
+            .proc_ids
+            .iter()
+            .map(|proc_id| identity::parse(proc_id))
+            .collect::<Result<Vec<_>, _>>()?;
+        let group = match &args.group {
+            Some(name) => Some(self.state.groups.get(name).ok_or_else(|| {
+                McpError::ResourceNotFound(format!("Process group {name:?} is not defined"))
+            })?),
+            None => None,
+        };
+
+        let state = self.state.clone();
+        let filter = args.filter.as_ref().map(|filter| filter.to_lowercase());
+        let pids = args.pids.clone();
+        let nice = args.nice;
+        let dry_run = args.dry_run;
+        let results = tokio::task::spawn_blocking(move || {
+            let mut matching: Vec<_> = state
+                .provider
+                .enumerate()
+                .into_iter()
+                .filter(|process| {
+                    filter.as_ref().is_none_or(|filter| process.name.to_lowercase().contains(filter))
+                        && group
+                            .as_ref()
+                            .is_none_or(|group| group.matches(process.pid, process.start_time, &process.name))
+                        && ((pids.is_empty() && handles.is_empty())
+                            || pids.contains(&process.pid)
+                            || handles.contains(&(process.pid, process.start_time)))
+                })
+                .collect();
+            matching.sort_unstable_by_key(|process| process.pid);
+
+            matching
+                .into_iter()
+                .map(|process| {
+                    let pid = process.pid;
+                    let before = platform::nice(pid);
+                    let mut result = ReniceResult {
+                        pid,
+                        name: process.name.clone(),
+                        before,
+                        after: None,
+                        changed: false,
+                        error: None,
+                    };
+                    if let Some(reason) = protected_reason(pid, &process.name, process.parent) {
+                        result.error = Some(format!("protected: {reason}"));
+                    } else if dry_run {
+                        result.changed = true;
+                    } else {
+                        match platform::set_nice(pid, nice) {
+                            Ok(()) => {
+                                result.changed = true;
+                                result.after = platform::nice(pid);
+                            }
+                            Err(e) => result.error = Some(nice_error(pid, before, nice, &e)),
+                        }
+                    }
+                    result
+                })
+                .collect::<Vec<_>>()
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to renice processes: {e}")))?;
+
+        let changed = results.iter().filter(|result| result.changed).count();
+        let failed = results.len() - changed;
+
+        let headline = if dry_run {
+            format!(
+                "{} {changed} processes would be set to nice {nice}",
+                style.paint(Color::Cyan, "Dry Run:")
+            )
+        } else {
+            format!(
+                "{} {changed} processes set to nice {nice}",
+                style.paint(Color::Green, "Reniced:")
+            )
+        };
+        let mut summary = headline;
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!(
+                "\n{} Matched: {}{}Failed: {failed}",
+                style.bullet(),
+                results.len(),
+                style.sep()
+            ));
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            for result in &results {
+                let before = result.before.map_or_else(|| "?".to_string(), |before| before.to_string());
+                let outcome = match (&result.error, result.after) {
+                    (Some(error), _) => error.clone(),
+                    (None, Some(after)) => format!("{before}{}{after}", style.arrow()),
+                    (None, None) => format!("{before}{}{nice}", style.arrow()),
+                };
+                summary.push_str(&format!("\n{} {} ({}): {outcome}", style.bullet(), result.pid, result.name));
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessReniceOutput {
+                success: true,
+                nice,
+                dry_run,
+                changed,
+                failed,
+                results,
+            },
+        ))
+    }
+}
+
+// ============================================================================
+// ERRORS
+// ============================================================================
+
+/// Why `set_nice` failed, shared with process_priority
+///
+/// Denials carry the platform's explanation, e.g. that a Windows server is
+/// not elevated.
+pub(crate) fn nice_error(pid: u32, before: Option<i32>, nice: i32, error: &std::io::Error) -> String {
+    match error.kind() {
+        std::io::ErrorKind::PermissionDenied => {
+            let reason = if before.is_some_and(|before| nice < before) {
+                "permission denied (raising priority needs root or CAP_SYS_NICE)"
+            } else {
+                "permission denied (another user's process?)"
+            };
+            match platform::permission_hint(pid) {
+                Some(hint) => format!("{reason}: {hint}"),
+                None => reason.to_string(),
+            }
+        }
+        std::io::ErrorKind::NotFound => "exited before the change".to_string(),
+        std::io::ErrorKind::Unsupported => "not supported on this platform".to_string(),
+        _ => error.to_string(),
+    }
+}
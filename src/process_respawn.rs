@@ -0,0 +1,208 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::profiles;
+use crate::schema::{
+    DaemonInfo, KilledProcess, PROCESS_RESPAWN, ProcessRespawnArgs, ProcessRespawnOutput, ProcessRespawnPrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::spawn::{DaemonSpec, SpawnSpec, parse_env, spawn_daemon};
+use crate::state::ProcessState;
+use crate::users::server_user_label;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessRespawnTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessRespawnTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessRespawnTool {
+    type Args = ProcessRespawnArgs;
+    type Prompts = ProcessRespawnPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_RESPAWN
+    }
+
+    fn description() -> &'static str {
+        "Relaunch a process previously terminated by process_kill, using the cmdline, cwd and \
+         environment recorded right before the kill (see process_recently_killed). Without \
+         confirm=true only previews exactly what would run; show the preview to the user before \
+         confirming. The new process gets a new PID and runs as the server's user. Pass daemon \
+         to detach it fully (new session, output to files, optional pidfile) so it outlives the \
+         server."
+    }
+
+    fn read_only() -> bool {
+        false // Launches a process
+    }
+
+    fn idempotent() -> bool {
+        false // Every confirmed call launches another copy
+    }
+
+    async fn execute(&self, args: Self::Args, ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessRespawnOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        profiles::check_free_form(&self.config.spawn_profiles, PROCESS_RESPAWN)?;
+        let entry = self.state.killed.get(args.id).ok_or_else(|| {
+            McpError::ResourceNotFound(format!(
+                "No kill record #{} (see process_recently_killed; old records are evicted)",
+                args.id
+            ))
+        })?;
+
+        let (spec, mut notes) = plan(&entry)?;
+        if let (Some(original), Some(server)) = (&entry.user, server_user_label())
+            && *original != server
+        {
+            notes.push(format!("Originally ran as {original}; will run as {server}"));
+        }
+
+        let daemon = args.daemon.as_ref().map(|options| {
+            DaemonSpec::resolve(
+                &spec,
+                &self.config.output_log,
+                options.stdout.as_deref(),
+                options.stderr.as_deref(),
+                options.pidfile.as_deref(),
+            )
+        });
+        let failed = |e: std::io::Error| McpError::Other(anyhow::anyhow!("Failed to respawn record #{}: {e}", args.id));
+        let (pid, supervised) = match (&daemon, args.confirm) {
+            (_, false) => (None, None),
+            (Some(daemon), true) => (Some(spawn_daemon(&spec, daemon).await.map_err(failed)?), None),
+            (None, true) => {
+                let supervised = self.state.supervisor.spawn(&spec, PROCESS_RESPAWN, ctx.connection_id()).map_err(failed)?;
+                (Some(supervised.pid), Some(supervised))
+            }
+        };
+
+        let command_line = spec.command_line();
+        let mut summary = match pid {
+            Some(pid) => style.paint(Color::Green, &format!("Respawned #{} as PID {pid}", args.id)),
+            None => format!(
+                "{} #{} (not started, pass confirm=true)",
+                style.paint(Color::Cyan, "Respawn Preview"),
+                args.id
+            ),
+        };
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!("\n{} Command: {command_line}", style.bullet()));
+            if let Some(cwd) = &spec.cwd {
+                summary.push_str(&format!("\n{} Cwd: {}", style.bullet(), cwd.display()));
+            }
+            if let Some(daemon) = &daemon {
+                summary.push_str(&format!("\n{} Daemon output: {}", style.bullet(), daemon.stdout.display()));
+                if let Some(pidfile) = &daemon.pidfile {
+                    summary.push_str(&format!("{}pidfile {}", style.sep(), pidfile.display()));
+                }
+            }
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            summary.push_str(&format!(
+                "\n{} Env: {} variables{}Original PID: {}",
+                style.bullet(),
+                spec.env.as_ref().map_or(0, Vec::len),
+                style.sep(),
+                entry.pid
+            ));
+            for note in &notes {
+                summary.push_str(&format!("\n{} {note}", style.bullet()));
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessRespawnOutput {
+                success: true,
+                id: args.id,
+                spawned: pid.is_some(),
+                pid,
+                supervisor_id: supervised.map(|supervised| supervised.id),
+                env_count: spec.env.as_ref().map_or(0, Vec::len),
+                daemon: daemon.map(|daemon| DaemonInfo {
+                    stdout: daemon.stdout.to_string_lossy().to_string(),
+                    stderr: daemon.stderr.to_string_lossy().to_string(),
+                    pidfile: daemon.pidfile.map(|pidfile| pidfile.to_string_lossy().to_string()),
+                }),
+                cwd: spec.cwd.as_ref().map(|cwd| cwd.to_string_lossy().to_string()),
+                program: spec.program,
+                args: spec.args,
+                notes,
+            },
+        ))
+    }
+}
+
+// ============================================================================
+// RESPAWN PLAN
+// ============================================================================
+
+/// Turn a kill record into a spawn spec plus caveats
+fn plan(entry: &KilledProcess) -> Result<(SpawnSpec, Vec<String>), McpError> {
+    let mut notes = Vec::new();
+    let Some((argv0, rest)) = entry.cmdline.split_first() else {
+        return Err(McpError::InvalidArguments(format!(
+            "Kill record #{} has no command line (kernel thread or access denied), cannot respawn",
+            entry.id
+        )));
+    };
+
+    let program = match &entry.exe {
+        Some(exe) if Path::new(exe).exists() => exe.clone(),
+        Some(exe) => {
+            notes.push(format!("Recorded executable {exe} no longer exists; using argv[0]"));
+            argv0.clone()
+        }
+        None => argv0.clone(),
+    };
+
+    let cwd = entry.cwd.as_ref().map(PathBuf::from).filter(|cwd| {
+        let exists = cwd.is_dir();
+        if !exists {
+            notes.push(format!("Recorded cwd {} no longer exists; using the server's", cwd.display()));
+        }
+        exists
+    });
+
+    let env = if entry.env.is_empty() {
+        notes.push("No environment was recorded; inheriting the server's".to_string());
+        None
+    } else {
+        Some(parse_env(&entry.env))
+    };
+
+    Ok((
+        SpawnSpec {
+            program,
+            args: rest.to_vec(),
+            cwd,
+            env,
+            io_priority: None,
+            nice: None,
+            cpus: Vec::new(),
+        },
+        notes,
+    ))
+}
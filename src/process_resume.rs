@@ -0,0 +1,124 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+use sysinfo::ProcessStatus;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::identity;
+use crate::platform;
+use crate::process_suspend::{run_status, settle, signal_error};
+use crate::schema::{
+    PROCESS_RESUME, ProcessResumeArgs, ProcessResumeOutput, ProcessResumePrompts, SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessResumeTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessResumeTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessResumeTool {
+    type Args = ProcessResumeArgs;
+    type Prompts = ProcessResumePrompts;
+
+    fn name() -> &'static str {
+        PROCESS_RESUME
+    }
+
+    fn description() -> &'static str {
+        "Continue a stopped process with SIGCONT, e.g. one paused by process_suspend or Ctrl+Z. \
+         Returns the scheduler state before and after and running, whether it left the \
+         stopped state within a second; a process held by a debugger stays stopped. Roots of \
+         trees frozen with process_freeze are refused, use process_thaw. Unix only."
+    }
+
+    fn read_only() -> bool {
+        false // Continues a process
+    }
+
+    fn idempotent() -> bool {
+        true // A running process ignores SIGCONT
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessResumeOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+
+        let state = self.state.clone();
+        let output = tokio::task::spawn_blocking(move || {
+            let process = state
+                .provider
+                .lookup(pid, false)
+                .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?;
+            // The cgroup freezer ignores SIGCONT, and the freeze would stay on record
+            if state.freezes.get(pid, process.start_time).is_some() {
+                return Err(McpError::InvalidArguments(format!(
+                    "Process {pid} is the root of a frozen tree; resume it with process_thaw"
+                )));
+            }
+
+            let previous = run_status(pid, process.start_time);
+            platform::continue_process(pid).map_err(|e| signal_error("resume", pid, &e))?;
+            let now = settle(pid, process.start_time, |status| !stopped(status));
+            let running = now.is_some_and(|status| !stopped(status));
+            Ok(ProcessResumeOutput {
+                success: running,
+                pid,
+                name: process.name,
+                previous_state: previous.map(|status| status.to_string()),
+                state: now.map(|status| status.to_string()),
+                was_stopped: previous.is_some_and(stopped),
+                running,
+            })
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Resume task failed: {e}")))??;
+
+        let mut summary = format!(
+            "{} PID {pid} ({}){}{}{}{}",
+            style.paint(if output.running { Color::Green } else { Color::Red }, "Resumed:"),
+            output.name,
+            style.sep(),
+            output.previous_state.as_deref().unwrap_or("unknown"),
+            style.arrow(),
+            output.state.as_deref().unwrap_or("gone")
+        );
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!(
+                "\n{} {}",
+                style.bullet(),
+                match (output.was_stopped, output.running) {
+                    (false, true) => "Was not stopped; SIGCONT had no effect",
+                    (true, true) => "Running again",
+                    (_, false) if output.state.is_none() => "Exited right after SIGCONT",
+                    (_, false) => "SIGCONT sent but the process is still stopped (traced by a debugger?)",
+                }
+            ));
+        }
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
+
+/// Stopped by a signal or held by a tracer
+fn stopped(status: ProcessStatus) -> bool {
+    matches!(status, ProcessStatus::Stop | ProcessStatus::Tracing)
+}
@@ -0,0 +1,291 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, truncate};
+use crate::profiles;
+use crate::sandbox;
+use crate::schema::{
+    PROCESS_RUN, ProcessRunArgs, ProcessRunOutput, ProcessRunPrompts, SummaryStyle, SummaryVerbosity,
+};
+use crate::spawn::{SpawnSpec, adopted, check_cpus, check_io_priority, check_nice, command, exit_info, launch, next_marker, parse_env};
+use crate::process_status::usage_text;
+use crate::state::ProcessState;
+
+/// How long to keep draining pipes after the command exits (grandchildren may hold them)
+const DRAIN_GRACE: Duration = Duration::from_secs(1);
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessRunTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessRunTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessRunTool {
+    type Args = ProcessRunArgs;
+    type Prompts = ProcessRunPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_RUN
+    }
+
+    fn description() -> &'static str {
+        "Run a one-shot command and wait for it to finish (up to timeout_secs, default 60), \
+         returning exit code or signal, duration, resource usage (peak RSS, CPU times, page \
+         faults) and captured stdout/stderr. Set shell=true to pass a full command line with \
+         pipes or globs to sh -c (cmd /C on Windows). On timeout or cancellation the command \
+         and its process group are killed, and in subreaper mode (Linux) so are descendants \
+         that double-forked away from the group. nice starts a background build \
+         deprioritized instead of renicing it afterwards, and cpus pins it to cores \
+         (Linux and Windows). sandbox runs it under a configured profile that limits writes \
+         and network (bubblewrap on Linux, sandbox-exec on macOS)."
+    }
+
+    fn read_only() -> bool {
+        false // Runs an arbitrary command
+    }
+
+    fn destructive() -> bool {
+        true // The command itself may modify anything the server can
+    }
+
+    fn idempotent() -> bool {
+        false
+    }
+
+    fn open_world() -> bool {
+        true // Commands may reach the network or other systems
+    }
+
+    async fn execute(&self, args: Self::Args, ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessRunOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        profiles::check_free_form(&self.config.spawn_profiles, PROCESS_RUN)?;
+        if args.command.trim().is_empty() {
+            return Err(McpError::InvalidArguments("command must not be empty".to_string()));
+        }
+        if args.shell && !args.args.is_empty() {
+            return Err(McpError::InvalidArguments(
+                "args cannot be combined with shell=true; put the full command line in command".to_string(),
+            ));
+        }
+        if let Some(priority) = args.io_priority {
+            check_io_priority(priority).map_err(McpError::InvalidArguments)?;
+        }
+        if let Some(nice) = args.nice {
+            check_nice(nice).map_err(McpError::InvalidArguments)?;
+        }
+        check_cpus(&args.cpus).map_err(McpError::InvalidArguments)?;
+        let (program, program_args) = if args.shell {
+            shell_command(&args.command)
+        } else {
+            (args.command.clone(), args.args.clone())
+        };
+        let sandbox = sandbox::profile(&self.config.sandbox, args.sandbox.as_deref()).map_err(McpError::InvalidArguments)?;
+        let spec = SpawnSpec {
+            program,
+            args: program_args,
+            cwd: args.cwd.as_ref().map(PathBuf::from),
+            env: None,
+            io_priority: args.io_priority,
+            nice: args.nice,
+            cpus: args.cpus.clone(),
+        };
+        let command_line = if args.shell { args.command.clone() } else { spec.command_line() };
+        let spec = match sandbox {
+            Some(profile) => sandbox::confine(spec, profile).map_err(McpError::InvalidArguments)?,
+            None => spec,
+        };
+
+        let marker = next_marker();
+        let mut cmd = command(&spec, &marker);
+        cmd.envs(parse_env(&args.env))
+            .stdin(if args.stdin.is_some() { Stdio::piped() } else { Stdio::null() })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        let started = Instant::now();
+        let mut child =
+            launch(&mut cmd, true).map_err(|e| McpError::InvalidArguments(format!("Failed to run {command_line}: {e}")))?;
+        let pid = child.pid;
+        #[cfg(windows)]
+        crate::spawn::pin(&spec, pid);
+
+        if let (Some(input), Some(mut stdin)) = (args.stdin, child.stdin.take()) {
+            tokio::spawn(async move {
+                // Commands that never read stdin close it early; that is not an error
+                let _ = stdin.write_all(input.as_bytes()).await;
+            });
+        }
+        let limit = args.max_output_bytes;
+        let stdout = child.stdout.take().map(|pipe| Reader::start(pipe, limit));
+        let stderr = child.stderr.take().map(|pipe| Reader::start(pipe, limit));
+
+        let timeout = Duration::from_secs(args.timeout_secs);
+        let exit = &mut child.exit;
+        let (waited, timed_out) = tokio::select! {
+            waited = exit.wait() => (waited, false),
+            () = tokio::time::sleep(timeout) => {
+                exit.kill_group();
+                (exit.wait().await, true)
+            }
+            () = ctx.cancellation_token().cancelled() => {
+                exit.kill_group();
+                (exit.wait().await, true)
+            }
+        };
+        let duration = started.elapsed();
+        let (status, usage) =
+            waited.map_err(|e| McpError::Other(anyhow::anyhow!("Failed to wait for {command_line}: {e}")))?;
+        if timed_out && crate::platform::is_child_subreaper() {
+            // Double-forked descendants left the process group but not the server
+            let provider = self.state.provider.clone();
+            let _ = tokio::task::spawn_blocking(move || {
+                for orphan in adopted(provider.as_ref(), &marker, pid) {
+                    let _ = provider.kill(orphan.pid);
+                }
+            })
+            .await;
+        }
+        let exit = exit_info(status, duration, Some(usage));
+
+        let ((stdout, stdout_truncated), (stderr, stderr_truncated)) =
+            tokio::join!(Reader::finish(stdout), Reader::finish(stderr));
+
+        let success = exit.success && !timed_out;
+        let outcome = if timed_out {
+            style.paint(Color::Red, &format!("Timed out after {}s", args.timeout_secs))
+        } else if let Some(code) = exit.code {
+            let color = if exit.success { Color::Green } else { Color::Red };
+            style.paint(color, &format!("Exited {code}"))
+        } else {
+            let signal = exit.signal_name.clone().unwrap_or_else(|| "a signal".to_string());
+            style.paint(Color::Red, &format!("Killed by {signal}"))
+        };
+        let mut summary = format!(
+            "{outcome}: {}{}{:.1}s",
+            truncate(&command_line, 60),
+            style.sep(),
+            exit.duration_secs
+        );
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!("{}{}", style.sep(), usage_text(&usage)));
+            if let Some(profile) = sandbox {
+                summary.push_str(&format!("{}sandbox {}", style.sep(), profile.name));
+            }
+        }
+        let tail_lines = match verbosity {
+            SummaryVerbosity::Quiet => 0,
+            SummaryVerbosity::Normal => 5,
+            SummaryVerbosity::Detailed => 20,
+        };
+        // Errors usually explain a failure better than stdout does
+        let shown = if success || stderr.trim().is_empty() { &stdout } else { &stderr };
+        let lines: Vec<&str> = shown.lines().collect();
+        for line in &lines[lines.len().saturating_sub(tail_lines)..] {
+            summary.push_str(&format!("\n{} {}", style.bullet(), truncate(line, 120)));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessRunOutput {
+                success,
+                command: command_line,
+                pid,
+                timed_out,
+                exit,
+                stdout,
+                stderr,
+                stdout_truncated,
+                stderr_truncated,
+                sandbox: sandbox.map(|profile| profile.name.clone()),
+            },
+        ))
+    }
+}
+
+/// Program and arguments that run `line` through the platform shell
+fn shell_command(line: &str) -> (String, Vec<String>) {
+    if cfg!(windows) {
+        ("cmd".to_string(), vec!["/C".to_string(), line.to_string()])
+    } else {
+        ("sh".to_string(), vec!["-c".to_string(), line.to_string()])
+    }
+}
+
+/// Background reader of one output pipe
+struct Reader {
+    task: JoinHandle<(Vec<u8>, bool)>,
+    stop: oneshot::Sender<()>,
+}
+
+impl Reader {
+    /// Read `pipe` until EOF or stop, keeping only the last `limit` bytes (all when 0)
+    fn start(mut pipe: impl AsyncRead + Unpin + Send + 'static, limit: usize) -> Self {
+        let (stop, mut stopped) = oneshot::channel();
+        let task = tokio::spawn(async move {
+            let mut kept = Vec::new();
+            let mut truncated = false;
+            let mut chunk = [0u8; 8192];
+            loop {
+                let read = tokio::select! {
+                    read = pipe.read(&mut chunk) => read,
+                    _ = &mut stopped => break,
+                };
+                match read {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => {
+                        kept.extend_from_slice(&chunk[..n]);
+                        if limit > 0 && kept.len() > limit {
+                            kept.drain(..kept.len() - limit);
+                            truncated = true;
+                        }
+                    }
+                }
+            }
+            (kept, truncated)
+        });
+        Self { task, stop }
+    }
+
+    /// Collected output, waiting at most [`DRAIN_GRACE`] for EOF
+    async fn finish(reader: Option<Self>) -> (String, bool) {
+        let Some(Self { mut task, stop }) = reader else {
+            return (String::new(), false);
+        };
+        let joined = match tokio::time::timeout(DRAIN_GRACE, &mut task).await {
+            Ok(joined) => joined,
+            Err(_) => {
+                // A background grandchild still holds the pipe; keep what arrived so far
+                let _ = stop.send(());
+                task.await
+            }
+        };
+        match joined {
+            Ok((bytes, truncated)) => (String::from_utf8_lossy(&bytes).to_string(), truncated),
+            Err(_) => (String::new(), false),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::schema::{
+    PROCESS_SAMPLING, ProcessSamplingArgs, ProcessSamplingOutput, ProcessSamplingPrompts, SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessSamplingTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessSamplingTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessSamplingTool {
+    type Args = ProcessSamplingArgs;
+    type Prompts = ProcessSamplingPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_SAMPLING
+    }
+
+    fn description() -> &'static str {
+        "Show or change how often the server samples processes: watch_interval_ms is the \
+         period at which process_watch entries are checked, list_max_age_ms is how long \
+         process_list may reuse one full process table (0 = scan on every call). Larger values \
+         cost less CPU on busy hosts but report older data. Call without arguments to read the \
+         current values. Changes apply immediately and last until the server restarts."
+    }
+
+    fn read_only() -> bool {
+        false // Changes server-wide sampling
+    }
+
+    fn idempotent() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessSamplingOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let sampling = &self.state.sampling;
+
+        let before = (sampling.watch_interval_ms(), sampling.list_max_age_ms());
+        if let Some(millis) = args.watch_interval_ms {
+            sampling.set_watch_interval_ms(millis);
+        }
+        if let Some(millis) = args.list_max_age_ms {
+            sampling.set_list_max_age_ms(millis);
+        }
+        let (watch_interval_ms, list_max_age_ms) = (sampling.watch_interval_ms(), sampling.list_max_age_ms());
+        let changed = before != (watch_interval_ms, list_max_age_ms);
+
+        let mut summary = if changed {
+            style.paint(Color::Green, "Sampling updated")
+        } else {
+            style.paint(Color::Cyan, "Sampling")
+        };
+        if verbosity != SummaryVerbosity::Quiet {
+            let reuse = match list_max_age_ms {
+                0 => "fresh on every call".to_string(),
+                millis => format!("reused for {millis} ms"),
+            };
+            summary.push_str(&format!(
+                "\n{} Watch interval: {watch_interval_ms} ms{}List table: {reuse}",
+                style.bullet(),
+                style.sep()
+            ));
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            let requested = [
+                ("watch_interval_ms", args.watch_interval_ms, watch_interval_ms),
+                ("list_max_age_ms", args.list_max_age_ms, list_max_age_ms),
+            ];
+            for (key, requested, applied) in requested {
+                if let Some(requested) = requested
+                    && requested != applied
+                {
+                    summary.push_str(&format!("\n{} {key} {requested} clamped to {applied}", style.bullet()));
+                }
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessSamplingOutput {
+                success: true,
+                watch_interval_ms,
+                list_max_age_ms,
+                changed,
+            },
+        ))
+    }
+}
@@ -0,0 +1,182 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, ICON_INFO};
+use crate::events::ProcessEvent;
+use crate::identity;
+use crate::killed::snapshot;
+use crate::platform;
+use crate::protection::kill_guard;
+use crate::provider::SignalError;
+use crate::schema::{
+    PROCESS_SIGNAL, ProcessSignalArgs, ProcessSignalOutput, ProcessSignalPrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::signals::terminates;
+use crate::state::ProcessState;
+use crate::throttle::budget_text;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessSignalTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessSignalTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessSignalTool {
+    type Args = ProcessSignalArgs;
+    type Prompts = ProcessSignalPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_SIGNAL
+    }
+
+    fn description() -> &'static str {
+        "Send one signal to a process by name, e.g. hup to make a daemon reload its config, \
+         usr1 to reopen logs or term to ask it to exit: kill, term, int, hup, quit, usr1 or usr2 \
+         (\"SIGHUP\" works too). Returns once the signal is delivered without waiting for the \
+         process to react; use process_terminate to wait for an exit. Terminating signals \
+         (kill, term, int, quit) consume kill budget and are recorded like process_kill; \
+         do-not-kill tags and the confirmation policy apply to every signal. On Windows only \
+         kill is delivered."
+    }
+
+    fn read_only() -> bool {
+        false // Signals processes
+    }
+
+    fn destructive() -> bool {
+        true // Most signals end the process unless it handles them
+    }
+
+    fn idempotent() -> bool {
+        false // Each call delivers another signal
+    }
+
+    async fn execute(&self, args: Self::Args, ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessSignalOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let client = ctx.connection_id();
+
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+        if pid == 0 {
+            return Err(McpError::InvalidArguments(
+                "Invalid PID 0: cannot signal process with ID 0".to_string(),
+            ));
+        }
+        let signal = args.signal;
+        let terminating = terminates(signal);
+
+        // Reserve budget up front so concurrent calls cannot overshoot the cap
+        let reserved = if terminating {
+            Some(self.state.throttle.acquire(client).map_err(|reason| {
+                McpError::PermissionDenied(format!("Refusing to send {} to process {pid}: {reason}", signal.name()))
+            })?)
+        } else {
+            None
+        };
+
+        let record = terminating && self.state.killed.enabled();
+        let confirm = args.confirm;
+        let state = self.state.clone();
+
+        // Provider lookups and signals block on OS calls
+        let result = tokio::task::spawn_blocking(move || {
+            let Some(process) = state.provider.lookup(pid, record) else {
+                return Err("Process not found".to_string());
+            };
+            kill_guard(&state, pid, &process, confirm)?;
+            let name = process.name.clone();
+            let snapshot = terminating.then(|| snapshot(process));
+            match state.provider.signal(pid, signal) {
+                Ok(()) => Ok((name, snapshot)),
+                Err(SignalError::NotFound) => Err("Process not found".to_string()),
+                Err(SignalError::Denied) => Err(match platform::permission_hint(pid) {
+                    Some(hint) => format!("Permission denied or process protected: {hint}"),
+                    None => "Permission denied or process protected".to_string(),
+                }),
+                Err(SignalError::Unsupported) => Err(format!("{} is not supported on this platform", signal.name())),
+            }
+        })
+        .await
+        .map_err(|e| {
+            if let Some((reservation, _)) = &reserved {
+                self.state.throttle.release(reservation);
+            }
+            McpError::Other(anyhow::anyhow!("Failed to signal process: {e}"))
+        })?;
+        let (name, snapshot) = result.map_err(|reason| {
+            let failure = format!("Failed to send {} to process {pid}: {reason}", signal.name());
+            // Failed attempts do not count against the budget
+            McpError::PermissionDenied(match &reserved {
+                Some((reservation, _)) => {
+                    format!("{failure} (budget: {})", budget_text(&self.state.throttle.release(reservation)))
+                }
+                None => failure,
+            })
+        })?;
+        let budget = reserved.map(|(_, budget)| budget);
+
+        let mut record_id = None;
+        if let Some(mut entry) = snapshot {
+            entry.client = client.map(str::to_string);
+            record_id = record.then(|| self.state.killed.record(entry.clone()));
+            entry.id = record_id.unwrap_or_default();
+            self.state.events.publish(ProcessEvent::Killed(entry));
+        }
+
+        let color = if terminating { Color::Red } else { Color::Green };
+        let mut summary = format!(
+            "{} PID {pid} ({name})",
+            style.paint(color, &format!("Signal Sent: {}", signal.name()))
+        );
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!(
+                "\n{}{}",
+                style.icon(ICON_INFO),
+                if terminating {
+                    "Ends the process unless it handles the signal"
+                } else {
+                    "Delivered; the process decides how to react"
+                }
+            ));
+            if let Some(budget) = &budget {
+                summary.push_str(&format!("\n{} Budget: {}", style.bullet(), budget_text(budget)));
+            }
+        }
+        if verbosity == SummaryVerbosity::Detailed
+            && let Some(id) = record_id
+        {
+            summary.push_str(&format!("\n{} Recorded: #{id} (process_recently_killed)", style.bullet()));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessSignalOutput {
+                success: true,
+                pid,
+                name,
+                signal: signal.name().to_string(),
+                terminating,
+                budget,
+                record_id,
+            },
+        ))
+    }
+}
@@ -0,0 +1,308 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::io::ErrorKind;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::identity;
+use crate::platform::{self, SignalMasks};
+use crate::schema::{
+    PROCESS_SIGNAL_CHECK, ProcessSignal, ProcessSignalCheckArgs, ProcessSignalCheckOutput, ProcessSignalCheckPrompts,
+    SignalVerdict, SummaryStyle, SummaryVerbosity,
+};
+use crate::signals;
+use crate::state::ProcessState;
+
+/// How long a thread has to run after the SIGURG of `wake_probe`
+const WAKE_WINDOW: Duration = Duration::from_millis(250);
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessSignalCheckTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessSignalCheckTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessSignalCheckTool {
+    type Args = ProcessSignalCheckArgs;
+    type Prompts = ProcessSignalCheckPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_SIGNAL_CHECK
+    }
+
+    fn description() -> &'static str {
+        "Check whether a graceful shutdown signal would work before sending it. Probes the \
+         process with signal 0 (exists, may be signalled), then reads its signal dispositions \
+         on Linux (SigCgt, SigIgn, SigBlk of every thread, pending signals) to tell whether \
+         SIGTERM (or another signal) runs a handler, kills it outright, is ignored or blocked, \
+         or waits on a stopped process. wake_probe also sends the harmless SIGURG to processes \
+         that handle it and watches a thread wake up. Never sends a signal that ends the process."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessSignalCheckOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+
+        let state = self.state.clone();
+        let output = tokio::task::spawn_blocking(move || check(&state, pid, args.signal, args.wake_probe))
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to check process {pid}: {e}")))??;
+
+        let color = if output.graceful { Color::Green } else { Color::Red };
+        let mut summary = format!(
+            "{} PID {pid} ({}): {} {} {}",
+            style.paint(Color::Cyan, "Signal Check"),
+            output.name,
+            output.signal,
+            style.sep(),
+            style.paint(color, verdict_label(output.verdict))
+        );
+        let shown = match verbosity {
+            SummaryVerbosity::Quiet => 0,
+            SummaryVerbosity::Normal => 1,
+            SummaryVerbosity::Detailed => output.notes.len(),
+        };
+        for note in output.notes.iter().take(shown) {
+            summary.push_str(&format!("\n{} {note}", style.bullet()));
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            if let Some(state) = &output.state {
+                summary.push_str(&format!("\n{} State: {state}", style.bullet()));
+            }
+            for (label, names) in [
+                ("Handled", &output.handled),
+                ("Ignored", &output.ignored),
+                ("Blocked", &output.blocked),
+                ("Pending", &output.pending),
+            ] {
+                if !names.is_empty() {
+                    summary.push_str(&format!("\n{} {label}: {}", style.bullet(), names.join(", ")));
+                }
+            }
+        }
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
+
+fn verdict_label(verdict: SignalVerdict) -> &'static str {
+    match verdict {
+        SignalVerdict::Graceful => "graceful",
+        SignalVerdict::DefaultAction => "ends it without cleanup",
+        SignalVerdict::Blocked => "blocked",
+        SignalVerdict::Ignored => "ignored",
+        SignalVerdict::Stopped => "stopped, acted on after SIGCONT",
+        SignalVerdict::Zombie => "already exited (zombie)",
+        SignalVerdict::NotPermitted => "not permitted",
+        SignalVerdict::Unknown => "unknown",
+    }
+}
+
+// ============================================================================
+// SIGNAL CHECK
+// ============================================================================
+
+fn check(state: &ProcessState, pid: u32, signal: ProcessSignal, wake_probe: bool) -> Result<ProcessSignalCheckOutput, McpError> {
+    let entry = state
+        .provider
+        .lookup(pid, false)
+        .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?;
+    let permitted = match platform::probe_signal(pid) {
+        Ok(()) => Some(true),
+        Err(e) if e.kind() == ErrorKind::PermissionDenied => Some(false),
+        Err(e) if e.kind() == ErrorKind::Unsupported => None,
+        Err(_) => return Err(McpError::ResourceNotFound(format!("Process {pid} exited during the check"))),
+    };
+
+    let mut output = ProcessSignalCheckOutput {
+        success: true,
+        pid,
+        name: entry.name,
+        signal: signal.name().to_string(),
+        permitted,
+        state: None,
+        verdict: SignalVerdict::Unknown,
+        graceful: false,
+        handled: Vec::new(),
+        ignored: Vec::new(),
+        blocked: Vec::new(),
+        pending: Vec::new(),
+        woke: None,
+        notes: Vec::new(),
+    };
+    let (Some(masks), Some(number)) = (platform::signal_masks(pid), signals::number(signal)) else {
+        output.verdict = if permitted == Some(false) { SignalVerdict::NotPermitted } else { SignalVerdict::Unknown };
+        output.notes.push(if permitted == Some(false) {
+            format!("The server may not signal process {pid}")
+        } else {
+            "Signal dispositions are only readable on Linux".to_string()
+        });
+        return Ok(output);
+    };
+
+    output.state = Some(state_name(masks.state).to_string());
+    output.handled = signals::mask_names(masks.caught);
+    output.ignored = signals::mask_names(masks.ignored);
+    output.blocked = signals::mask_names(masks.blocked);
+    output.pending = signals::mask_names(masks.pending);
+    output.verdict = assess(pid, signal, number, &masks, permitted, &mut output.notes);
+    output.graceful = output.verdict == SignalVerdict::Graceful;
+
+    if wake_probe {
+        output.woke = probe_wake(pid, &masks, output.verdict, &mut output.notes);
+    }
+    Ok(output)
+}
+
+/// What `signal` (numbered `number`) would do, with the reasons in `notes`
+fn assess(
+    pid: u32,
+    signal: ProcessSignal,
+    number: i32,
+    masks: &SignalMasks,
+    permitted: Option<bool>,
+    notes: &mut Vec<String>,
+) -> SignalVerdict {
+    let name = signal.name();
+    let bit = 1u64 << (number - 1);
+    let (verdict, reason) = verdict(pid, signal, bit, masks, permitted);
+    notes.push(reason);
+    if verdict == SignalVerdict::Blocked {
+        notes.push(format!(
+            "Programs that read signals through signalfd or sigwait block them on purpose and still shut down on {name}"
+        ));
+    }
+    if masks.state == 'D' {
+        notes.push(format!("In uninterruptible sleep: {name} is acted on once the current I/O completes"));
+    }
+    if masks.pending & bit != 0 && verdict != SignalVerdict::Zombie {
+        notes.push(format!("{name} is already pending: an earlier one has not been taken yet"));
+    }
+    verdict
+}
+
+fn verdict(pid: u32, signal: ProcessSignal, bit: u64, masks: &SignalMasks, permitted: Option<bool>) -> (SignalVerdict, String) {
+    let name = signal.name();
+    if masks.state == 'Z' {
+        return (
+            SignalVerdict::Zombie,
+            "The process has exited; only its parent collecting the status removes it".to_string(),
+        );
+    }
+    if permitted == Some(false) {
+        return (
+            SignalVerdict::NotPermitted,
+            format!("The server may not signal process {pid} (different owner, no CAP_KILL)"),
+        );
+    }
+    if signal == ProcessSignal::Kill {
+        return (
+            SignalVerdict::DefaultAction,
+            "SIGKILL cannot be caught, ignored or blocked: the process ends without cleanup".to_string(),
+        );
+    }
+    if masks.ignored & bit != 0 {
+        return (SignalVerdict::Ignored, format!("{name} is set to SIG_IGN: sending it has no effect"));
+    }
+    if matches!(masks.state, 'T' | 't') {
+        return (
+            SignalVerdict::Stopped,
+            format!(
+                "The process is {}: {name} stays pending until it is continued (SIGCONT, process_thaw)",
+                state_name(masks.state)
+            ),
+        );
+    }
+    if masks.blocked & bit != 0 {
+        return (
+            SignalVerdict::Blocked,
+            format!("Every thread blocks {name}: it stays pending until the process unblocks it"),
+        );
+    }
+    if masks.caught & bit != 0 {
+        return (
+            SignalVerdict::Graceful,
+            format!("A {name} handler is installed and a thread accepts it: the process can shut down cleanly"),
+        );
+    }
+    // The kernel drops default-action signals sent to a namespace init from outside
+    if platform::namespace_pids(pid).is_some_and(|pids| pids.len() > 1 && pids.last() == Some(&1)) {
+        return (
+            SignalVerdict::Ignored,
+            format!("PID 1 of its PID namespace without a {name} handler: the kernel drops it (use SIGKILL, or an init such as tini)"),
+        );
+    }
+    (
+        SignalVerdict::DefaultAction,
+        format!("No {name} handler: the default action ends the process at once, without cleanup"),
+    )
+}
+
+/// Send SIGURG and report whether a thread ran to handle it
+fn probe_wake(pid: u32, masks: &SignalMasks, verdict: SignalVerdict, notes: &mut Vec<String>) -> Option<bool> {
+    let has_urgent = |mask: u64| signals::mask_names(mask).iter().any(|name| name == "SIGURG");
+    if !has_urgent(masks.caught) {
+        notes.push("Wake probe skipped: without a SIGURG handler the kernel discards it without waking anything".to_string());
+        return None;
+    }
+    if matches!(verdict, SignalVerdict::Zombie | SignalVerdict::NotPermitted | SignalVerdict::Stopped) {
+        notes.push("Wake probe skipped: the process cannot run a handler now".to_string());
+        return None;
+    }
+    if has_urgent(masks.blocked) {
+        notes.push("Wake probe skipped: every thread blocks SIGURG".to_string());
+        return None;
+    }
+    let before = masks.switches;
+    if let Err(e) = platform::send_urgent(pid) {
+        notes.push(format!("Wake probe failed: {e}"));
+        return None;
+    }
+    std::thread::sleep(WAKE_WINDOW);
+    let after = platform::signal_masks(pid)?.switches;
+    let woke = after > before;
+    notes.push(if woke {
+        "A thread woke up to handle SIGURG: signal handling is live".to_string()
+    } else {
+        format!("No thread ran within {} ms of SIGURG: its signal handling may be stuck", WAKE_WINDOW.as_millis())
+    });
+    Some(woke)
+}
+
+/// Name of a scheduler state letter of `/proc/<pid>/status`
+fn state_name(state: char) -> &'static str {
+    match state {
+        'R' => "running",
+        'S' => "sleeping",
+        'D' => "disk_sleep",
+        'T' => "stopped",
+        't' => "traced",
+        'Z' => "zombie",
+        'X' => "dead",
+        'I' => "idle",
+        'P' => "parked",
+        _ => "unknown",
+    }
+}
@@ -0,0 +1,249 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, truncate};
+use crate::profiles;
+use crate::events::ProcessEvent;
+use crate::output_log::{segments, tail};
+use crate::sandbox;
+use crate::schema::{
+    LogStream, MAX_SPAWN_WAIT_MS, PROCESS_SPAWN, ProcessSpawnArgs, ProcessSpawnOutput, ProcessSpawnPrompts,
+    SummaryStyle, SummaryVerbosity,
+};
+use crate::spawn::{SpawnSpec, check_cpus, check_io_priority, check_nice, parse_env};
+use crate::state::ProcessState;
+
+/// How long to wait for the pipes to close after an early exit (grandchildren may hold them)
+const DRAIN_GRACE: Duration = Duration::from_secs(1);
+
+/// Poll period while waiting for the pipes to close
+const DRAIN_POLL: Duration = Duration::from_millis(20);
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessSpawnTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessSpawnTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessSpawnTool {
+    type Args = ProcessSpawnArgs;
+    type Prompts = ProcessSpawnPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_SPAWN
+    }
+
+    fn description() -> &'static str {
+        "Start a long-running process in the background and return its PID, supervisor id and \
+         the first lines of stdout and stderr collected during wait_ms (default 500, returns \
+         early when it exits, with the exit code). The process keeps running after the call, \
+         supervised by the server: process_status, process_logs and process_logs_follow take \
+         the supervisor id, and process_terminate stops it. env adds KEY=VALUE entries to the \
+         server's environment. nice, cpus, io_priority and sandbox work as in process_run. Use \
+         process_run instead for commands that should be waited for."
+    }
+
+    fn read_only() -> bool {
+        false // Starts a process
+    }
+
+    fn destructive() -> bool {
+        true // The command itself may modify anything the server can
+    }
+
+    fn idempotent() -> bool {
+        false
+    }
+
+    fn open_world() -> bool {
+        true // Commands may reach the network or other systems
+    }
+
+    async fn execute(&self, args: Self::Args, ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessSpawnOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        profiles::check_free_form(&self.config.spawn_profiles, PROCESS_SPAWN)?;
+        if args.command.trim().is_empty() {
+            return Err(McpError::InvalidArguments("command must not be empty".to_string()));
+        }
+        if args.wait_ms > MAX_SPAWN_WAIT_MS {
+            return Err(McpError::InvalidArguments(format!("wait_ms must be at most {MAX_SPAWN_WAIT_MS}")));
+        }
+        if let Some(priority) = args.io_priority {
+            check_io_priority(priority).map_err(McpError::InvalidArguments)?;
+        }
+        if let Some(nice) = args.nice {
+            check_nice(nice).map_err(McpError::InvalidArguments)?;
+        }
+        check_cpus(&args.cpus).map_err(McpError::InvalidArguments)?;
+        let env = (!args.env.is_empty()).then(|| {
+            let mut env: HashMap<String, String> = std::env::vars().collect();
+            env.extend(parse_env(&args.env));
+            env.into_iter().collect()
+        });
+        let sandbox = sandbox::profile(&self.config.sandbox, args.sandbox.as_deref()).map_err(McpError::InvalidArguments)?;
+        let spec = SpawnSpec {
+            program: args.command.clone(),
+            args: args.args.clone(),
+            cwd: args.cwd.as_ref().map(PathBuf::from),
+            env,
+            io_priority: args.io_priority,
+            nice: args.nice,
+            cpus: args.cpus.clone(),
+        };
+        let command_line = spec.command_line();
+        let spec = match sandbox {
+            Some(profile) => sandbox::confine(spec, profile).map_err(McpError::InvalidArguments)?,
+            None => spec,
+        };
+
+        // Subscribe first so an exit right after the launch is not missed
+        let mut events = self.state.events.subscribe();
+        let supervised = self
+            .state
+            .supervisor
+            .spawn(&spec, PROCESS_SPAWN, ctx.connection_id())
+            .map_err(|e| McpError::InvalidArguments(format!("Failed to start {command_line}: {e}")))?;
+        let id = supervised.id;
+
+        let deadline = tokio::time::sleep(Duration::from_millis(args.wait_ms));
+        tokio::pin!(deadline);
+        let exited = loop {
+            tokio::select! {
+                () = &mut deadline => break false,
+                () = ctx.cancellation_token().cancelled() => break false,
+                received = events.recv() => match received {
+                    Ok(ProcessEvent::Finished(process)) if process.id == id => break true,
+                    Ok(_) | Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => break false,
+                },
+            }
+        };
+        if exited {
+            // Output written just before the exit may still be in the pipes
+            let started = Instant::now();
+            while self.state.supervisor.follow(id).is_ok() && started.elapsed() < DRAIN_GRACE {
+                tokio::time::sleep(DRAIN_POLL).await;
+            }
+        }
+        let current = self.state.supervisor.list(Some(id), None).pop().unwrap_or(supervised);
+
+        let lines = args.output_lines;
+        let dir = current.log_dir.clone().map(PathBuf::from);
+        let ((stdout, stdout_truncated), (stderr, stderr_truncated)) = tokio::task::spawn_blocking(move || match dir {
+            Some(dir) => (early_lines(&dir, LogStream::Stdout, lines), early_lines(&dir, LogStream::Stderr, lines)),
+            None => ((Vec::new(), false), (Vec::new(), false)),
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Log read task failed: {e}")))?;
+
+        let success = current.running || current.exit.as_ref().is_some_and(|exit| exit.success);
+        let mut summary = match &current.exit {
+            None => format!(
+                "{} PID {}{}{}",
+                style.paint(Color::Green, "Spawned"),
+                current.pid,
+                style.sep(),
+                truncate(&command_line, 60)
+            ),
+            Some(exit) => {
+                let outcome = match (exit.code, &exit.signal_name) {
+                    (Some(code), _) => format!("exited {code}"),
+                    (None, Some(signal)) => format!("killed by {signal}"),
+                    (None, None) => "exited".to_string(),
+                };
+                let color = if exit.success { Color::Green } else { Color::Red };
+                format!(
+                    "{} PID {} {} after {:.1}s{}{}",
+                    style.paint(color, "Spawned"),
+                    current.pid,
+                    style.paint(color, &outcome),
+                    exit.duration_secs,
+                    style.sep(),
+                    truncate(&command_line, 60)
+                )
+            }
+        };
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!(
+                "\n{} Supervisor id: {}{}{} stdout, {} stderr lines",
+                style.bullet(),
+                current.id,
+                style.sep(),
+                stdout.len(),
+                stderr.len()
+            ));
+            if let Some(profile) = sandbox {
+                summary.push_str(&format!("{}sandbox {}", style.sep(), profile.name));
+            }
+        }
+        let shown = match verbosity {
+            SummaryVerbosity::Quiet => 0,
+            SummaryVerbosity::Normal => 5,
+            SummaryVerbosity::Detailed => 20,
+        };
+        // Errors usually explain a failed start better than stdout does
+        let first = if success || stderr.is_empty() { &stdout } else { &stderr };
+        for line in first.iter().take(shown) {
+            summary.push_str(&format!("\n{} {}", style.bullet(), truncate(line, 120)));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessSpawnOutput {
+                success,
+                pid: current.pid,
+                supervisor_id: current.id,
+                command: command_line,
+                cwd: current.cwd,
+                log_dir: current.log_dir,
+                running: current.running,
+                exit: current.exit,
+                stdout,
+                stderr,
+                stdout_truncated,
+                stderr_truncated,
+                sandbox: sandbox.map(|profile| profile.name.clone()),
+            },
+        ))
+    }
+}
+
+/// First `limit` lines of `stream` (all when 0), oldest segment first, and whether more exist
+fn early_lines(dir: &Path, stream: LogStream, limit: usize) -> (Vec<String>, bool) {
+    let mut lines = Vec::new();
+    for segment in segments(dir, stream).iter().rev() {
+        let Ok((segment_lines, _)) = tail(Path::new(&segment.path), 0) else {
+            continue;
+        };
+        for line in segment_lines {
+            if limit > 0 && lines.len() == limit {
+                return (lines, true);
+            }
+            lines.push(line);
+        }
+    }
+    (lines, false)
+}
@@ -0,0 +1,167 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, human_bytes};
+use crate::process_list::ProcessListTool;
+use crate::schema::{
+    MetricStats, PROCESS_STATS, ProcessColumn, ProcessInfo, ProcessListArgs, ProcessStatsArgs, ProcessStatsOutput,
+    ProcessStatsPrompts, SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessStatsTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessStatsTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessStatsTool {
+    type Args = ProcessStatsArgs;
+    type Prompts = ProcessStatsPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_STATS
+    }
+
+    fn description() -> &'static str {
+        "Aggregate CPU and memory across every process matching a filter (name, path of the \
+         executable or working directory, PIDs, group, tag, named filters): count plus total, \
+         mean, p50/p90/p99 and max of each, e.g. total memory of everything under /srv/app, \
+         without fetching and summing the full process_list."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessStatsOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        // Same filters and CPU sampling as process_list
+        let listing = ProcessListTool::new(self.config.clone(), self.state.clone())
+            .list(ProcessListArgs {
+                filter: args.filter.clone(),
+                pids: args.pids.clone(),
+                group: args.group.clone(),
+                tag: args.tag.clone(),
+                named_filters: args.named_filters.clone(),
+                columns: if args.path.is_some() { vec![ProcessColumn::Paths] } else { Vec::new() },
+                ..ProcessListArgs::default()
+            })
+            .await?;
+        let mut processes = listing.metadata.processes;
+        if let Some(path) = &args.path {
+            let below = |dir: &Option<String>| dir.as_deref().is_some_and(|dir| Path::new(dir).starts_with(path));
+            processes.retain(|info| below(&info.exe) || below(&info.cwd));
+        }
+
+        let cpu_percent = metric(&processes, |info| f64::from(info.cpu_percent), |cpu| cpu);
+        let rss_bytes = metric(&processes, |info| info.rss_bytes, |bytes| bytes as f64);
+        let names = processes.iter().map(|info| info.name.as_str()).collect::<HashSet<_>>().len();
+
+        let mut summary = format!(
+            "{} {} process(es){}{:.1}% CPU{}{} resident",
+            style.paint(Color::Cyan, "Stats:"),
+            processes.len(),
+            style.sep(),
+            cpu_percent.total,
+            style.sep(),
+            human_bytes(rss_bytes.total)
+        );
+        if verbosity != SummaryVerbosity::Quiet && !processes.is_empty() {
+            summary.push_str(&format!(
+                "\n{} CPU p50 {:.1}%{}p90 {:.1}%{}max {:.1}%",
+                style.bullet(),
+                cpu_percent.p50,
+                style.sep(),
+                cpu_percent.p90,
+                style.sep(),
+                cpu_percent.max
+            ));
+            summary.push_str(&format!(
+                "\n{} RSS p50 {}{}p90 {}{}max {}",
+                style.bullet(),
+                human_bytes(rss_bytes.p50),
+                style.sep(),
+                human_bytes(rss_bytes.p90),
+                style.sep(),
+                human_bytes(rss_bytes.max)
+            ));
+        }
+        if verbosity == SummaryVerbosity::Detailed && !processes.is_empty() {
+            let holder = |pid: Option<u32>| {
+                processes
+                    .iter()
+                    .find(|info| Some(info.pid) == pid)
+                    .map_or_else(|| "none".to_string(), |info| format!("{} ({})", info.pid, info.name))
+            };
+            summary.push_str(&format!(
+                "\n{} Busiest: {}{}Largest: {}{}{names} distinct name(s)",
+                style.bullet(),
+                holder(cpu_percent.max_pid),
+                style.sep(),
+                holder(rss_bytes.max_pid),
+                style.sep()
+            ));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessStatsOutput {
+                success: true,
+                count: processes.len(),
+                cpu_percent,
+                rss_bytes,
+                names,
+            },
+        ))
+    }
+}
+
+// ============================================================================
+// AGGREGATION
+// ============================================================================
+
+/// Total, mean, nearest-rank percentiles and maximum of `value`
+fn metric<T>(processes: &[ProcessInfo], value: impl Fn(&ProcessInfo) -> T, as_f64: fn(T) -> f64) -> MetricStats<T>
+where
+    T: Copy + Default + PartialOrd + std::iter::Sum<T>,
+{
+    let mut values: Vec<(T, u32)> = processes.iter().map(|info| (value(info), info.pid)).collect();
+    if values.is_empty() {
+        return MetricStats::default();
+    }
+    values.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+    let total: T = values.iter().map(|(value, _)| *value).sum();
+    let rank = |p: f64| values[((p * values.len() as f64).ceil() as usize).clamp(1, values.len()) - 1].0;
+    let (max, max_pid) = values[values.len() - 1];
+    MetricStats {
+        total,
+        mean: as_f64(total) / values.len() as f64,
+        p50: rank(0.5),
+        p90: rank(0.9),
+        p99: rank(0.99),
+        max,
+        max_pid: (max > T::default()).then_some(max_pid),
+    }
+}
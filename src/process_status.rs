@@ -0,0 +1,167 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, DETAIL_ROWS, human_bytes, truncate};
+use crate::platform;
+use crate::schema::{
+    PROCESS_STATUS, ProcessStatusArgs, ProcessStatusOutput, ProcessStatusPrompts, ResourceUsage, SummaryStyle,
+    SummaryVerbosity, SupervisedProcess,
+};
+use crate::spawn::adopted;
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessStatusTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessStatusTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessStatusTool {
+    type Args = ProcessStatusArgs;
+    type Prompts = ProcessStatusPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_STATUS
+    }
+
+    fn description() -> &'static str {
+        "Show processes started by this server (e.g. via process_respawn), newest first. Running \
+         entries report their PID; exited entries keep exit code, terminating signal, core dump \
+         flag, wall-clock duration and resource usage (peak RSS, CPU times, page faults) for the \
+         configured retention period. In subreaper mode \
+         (Linux) each entry also lists orphans: descendants that double-forked away from it and \
+         are still running; orphans that exit are reaped and counted under reaper."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        false // Entries change state as processes exit
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessStatusOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let mut processes = self.state.supervisor.list(args.id, args.pid);
+        if let Some(id) = args.id
+            && processes.is_empty()
+        {
+            return Err(McpError::ResourceNotFound(format!(
+                "No supervised process #{id} (never started here, or its exit record expired)"
+            )));
+        }
+        let subreaper = platform::is_child_subreaper();
+        if subreaper && !processes.is_empty() {
+            let state = self.state.clone();
+            processes = tokio::task::spawn_blocking(move || {
+                for process in &mut processes {
+                    if let Some(marker) = state.supervisor.marker(process.id) {
+                        process.orphans = adopted(state.provider.as_ref(), &marker, process.pid)
+                            .into_iter()
+                            .map(|orphan| orphan.pid)
+                            .collect();
+                    }
+                }
+                processes
+            })
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Orphan scan failed: {e}")))?;
+        }
+
+        let running = processes.iter().filter(|p| p.running).count();
+        let mut summary = format!(
+            "{}: {running} running{}{} exited",
+            style.paint(Color::Cyan, "Supervised"),
+            style.sep(),
+            processes.len() - running
+        );
+        let shown = match verbosity {
+            SummaryVerbosity::Quiet => 0,
+            SummaryVerbosity::Normal | SummaryVerbosity::Detailed => DETAIL_ROWS,
+        };
+        for process in processes.iter().take(shown) {
+            summary.push_str(&format!(
+                "\n{} #{} PID {}: {}",
+                style.bullet(),
+                process.id,
+                process.pid,
+                status_text(process)
+            ));
+            if !process.orphans.is_empty() {
+                summary.push_str(&format!(", {} orphan(s) running", process.orphans.len()));
+            }
+            if verbosity == SummaryVerbosity::Detailed {
+                if let Some(usage) = process.exit.as_ref().and_then(|exit| exit.usage.as_ref()) {
+                    summary.push_str(&format!("{}{}", style.sep(), usage_text(usage)));
+                }
+                summary.push_str(&format!("{}{}", style.sep(), truncate(&process.command, 60)));
+            }
+        }
+
+        let reaper = self.state.reaper.stats();
+        if verbosity != SummaryVerbosity::Quiet && reaper.reaped > 0 {
+            summary.push_str(&format!(
+                "\n{} Reaped {} orphan(s), {} killed by a signal",
+                style.bullet(),
+                reaper.reaped,
+                reaper.signalled
+            ));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessStatusOutput {
+                success: true,
+                count: processes.len(),
+                processes,
+                subreaper,
+                reaper,
+            },
+        ))
+    }
+}
+
+/// "running", "exited 0 after 3.2s", "killed by SIGSEGV (core dumped) after 1.0s"
+fn status_text(process: &SupervisedProcess) -> String {
+    let Some(exit) = &process.exit else {
+        return "running".to_string();
+    };
+    let how = match (exit.code, &exit.signal_name, exit.signal) {
+        (Some(code), _, _) => format!("exited {code}"),
+        (None, Some(name), _) => format!("killed by {name}"),
+        (None, None, Some(signal)) => format!("killed by signal {signal}"),
+        (None, None, None) => "exited".to_string(),
+    };
+    let core = if exit.core_dumped { " (core dumped)" } else { "" };
+    format!("{how}{core} after {:.1}s", exit.duration_secs)
+}
+
+/// "peak 812.00 MiB, 3.2s user + 0.4s system CPU"
+pub(crate) fn usage_text(usage: &ResourceUsage) -> String {
+    format!(
+        "peak {}, {:.1}s user + {:.1}s system CPU",
+        human_bytes(usage.max_rss_bytes),
+        usage.user_cpu_secs,
+        usage.system_cpu_secs
+    )
+}
@@ -0,0 +1,174 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::io::ErrorKind;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, System};
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::identity;
+use crate::platform;
+use crate::protection::protected_reason;
+use crate::schema::{
+    CpuLimitMethod, PROCESS_SUSPEND, ProcessSuspendArgs, ProcessSuspendOutput, ProcessSuspendPrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+/// How long to wait for the kernel to act on SIGSTOP or SIGCONT
+pub(crate) const SETTLE: Duration = Duration::from_secs(1);
+
+/// Poll period while waiting for the state change
+const SETTLE_POLL: Duration = Duration::from_millis(20);
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessSuspendTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessSuspendTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessSuspendTool {
+    type Args = ProcessSuspendArgs;
+    type Prompts = ProcessSuspendPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_SUSPEND
+    }
+
+    fn description() -> &'static str {
+        "Pause one process with SIGSTOP without ending it, e.g. a runaway job to look at later. \
+         Returns the scheduler state it was in before (Runnable, Sleeping, ...) and whether it \
+         was seen stopped within a second. It stays stopped until process_resume, also after \
+         the server exits. Refused for protected processes (init, this server, kernel threads) \
+         and under a signals CPU limit. Children keep running; process_freeze stops a whole \
+         tree. Unix only."
+    }
+
+    fn read_only() -> bool {
+        false // Stops a process
+    }
+
+    fn idempotent() -> bool {
+        true // A stopped process stays stopped
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessSuspendOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+
+        let state = self.state.clone();
+        let output = tokio::task::spawn_blocking(move || {
+            let process = state
+                .provider
+                .lookup(pid, false)
+                .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?;
+            if let Some(reason) = protected_reason(pid, &process.name, process.parent) {
+                return Err(McpError::PermissionDenied(format!("Refusing to suspend process {pid}: {reason}")));
+            }
+            // Its duty cycle would SIGCONT the process straight back
+            if state
+                .cpu_limits
+                .get(pid, process.start_time)
+                .is_some_and(|limit| limit.method == CpuLimitMethod::Signals)
+            {
+                return Err(McpError::InvalidArguments(format!(
+                    "Process {pid} has a signals CPU limit; lift it with process_cpu_unlimit first"
+                )));
+            }
+
+            let previous = run_status(pid, process.start_time);
+            platform::stop_process(pid).map_err(|e| signal_error("suspend", pid, &e))?;
+            let now = settle(pid, process.start_time, |status| status == ProcessStatus::Stop);
+            let stopped = now == Some(ProcessStatus::Stop);
+            let already_stopped = previous == Some(ProcessStatus::Stop);
+            Ok(ProcessSuspendOutput {
+                success: stopped || already_stopped,
+                pid,
+                name: process.name,
+                previous_state: previous.map(|status| status.to_string()),
+                state: now.map(|status| status.to_string()),
+                stopped,
+                already_stopped,
+            })
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Suspend task failed: {e}")))??;
+
+        let mut summary = format!(
+            "{} PID {pid} ({}){}was {}",
+            style.paint(if output.success { Color::Green } else { Color::Red }, "Suspended:"),
+            output.name,
+            style.sep(),
+            output.previous_state.as_deref().unwrap_or("unknown")
+        );
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!(
+                "\n{} {}",
+                style.bullet(),
+                match (output.already_stopped, output.stopped) {
+                    (true, _) => "Already stopped before the call",
+                    (false, true) => "Stopped; continue it with process_resume",
+                    (false, false) => "SIGSTOP sent but the process was not seen stopped within a second",
+                }
+            ));
+        }
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
+
+// ============================================================================
+// STATE
+// ============================================================================
+
+/// Scheduler status of `pid`, `None` once it is gone or the PID was reused
+pub(crate) fn run_status(pid: u32, start_time: u64) -> Option<ProcessStatus> {
+    let mut system = System::new();
+    system.refresh_processes_specifics(ProcessesToUpdate::Some(&[Pid::from_u32(pid)]), true, ProcessRefreshKind::nothing());
+    system
+        .process(Pid::from_u32(pid))
+        .filter(|process| process.start_time() == start_time)
+        .map(sysinfo::Process::status)
+}
+
+/// Poll the status of `pid` for up to [`SETTLE`] until `done` accepts it; the last status seen
+pub(crate) fn settle(pid: u32, start_time: u64, done: impl Fn(ProcessStatus) -> bool) -> Option<ProcessStatus> {
+    let started = Instant::now();
+    loop {
+        let status = run_status(pid, start_time);
+        if status.is_none_or(&done) || started.elapsed() >= SETTLE {
+            return status;
+        }
+        std::thread::sleep(SETTLE_POLL);
+    }
+}
+
+/// Map a failed SIGSTOP or SIGCONT to the tool error
+pub(crate) fn signal_error(action: &str, pid: u32, error: &std::io::Error) -> McpError {
+    match error.kind() {
+        ErrorKind::PermissionDenied => {
+            McpError::PermissionDenied(format!("Failed to {action} process {pid}: {error}"))
+        }
+        ErrorKind::Unsupported => {
+            McpError::InvalidArguments(format!("Failed to {action} process {pid}: not supported on this platform"))
+        }
+        _ => McpError::Other(anyhow::anyhow!("Failed to {action} process {pid}: {error}")),
+    }
+}
@@ -0,0 +1,140 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::identity;
+use crate::schema::{
+    PROCESS_TAG, ProcessTagArgs, ProcessTagOutput, ProcessTagPrompts, SummaryStyle, SummaryVerbosity, TaggedProcess,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessTagTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessTagTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessTagTool {
+    type Args = ProcessTagArgs;
+    type Prompts = ProcessTagPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_TAG
+    }
+
+    fn description() -> &'static str {
+        "Attach server-side tags and a note to a running process, e.g. \"my-dev-server\", so it \
+         can be found again across turns with process_list or process_tags. Tags are cleared \
+         automatically when the process exits. The \"do-not-kill\" tag makes process_kill refuse \
+         the PID until it is removed."
+    }
+
+    fn read_only() -> bool {
+        false // Modifies server-side tag state
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessTagOutput>, McpError> {
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let add = clean_tags(&args.tags)?;
+        let remove = clean_tags(&args.remove)?;
+
+        let fresh = tokio::task::spawn_blocking(move || {
+            let mut system = System::new();
+            system.refresh_processes_specifics(
+                ProcessesToUpdate::Some(&[Pid::from_u32(pid)]),
+                true,
+                ProcessRefreshKind::nothing(),
+            );
+            system.process(Pid::from_u32(pid)).map(|process| TaggedProcess {
+                pid,
+                name: process.name().to_string_lossy().to_string(),
+                start_time: process.start_time(),
+                proc_id: identity::proc_id(pid, process.start_time()),
+                tags: Vec::new(),
+                note: None,
+                tagged_at: chrono::Utc::now().to_rfc3339(),
+            })
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to look up process {pid}: {e}")))?
+        .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?;
+
+        let state = self.state.clone();
+        tokio::task::spawn_blocking(move || state.tags.prune_exited())
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to prune tags: {e}")))?;
+
+        let name = fresh.name.clone();
+        let entry = self.state.tags.update(fresh, |entry| {
+            if args.clear {
+                entry.tags.clear();
+                entry.note = None;
+            }
+            entry.tags.retain(|tag| !remove.contains(tag));
+            entry.tags.extend(add);
+            if let Some(note) = args.note {
+                let note = note.trim();
+                entry.note = (!note.is_empty()).then(|| note.to_string());
+            }
+        });
+
+        let tags = entry
+            .as_ref()
+            .map_or_else(|| "none".to_string(), |entry| entry.tags.join(", "));
+        let mut summary = format!("{} PID {pid} ({name}): {tags}", style.paint(Color::Cyan, "Tags"));
+        if verbosity != SummaryVerbosity::Quiet
+            && let Some(note) = entry.as_ref().and_then(|entry| entry.note.as_deref())
+        {
+            summary.push_str(&format!("\n{} Note: {note}", style.bullet()));
+        }
+        if verbosity == SummaryVerbosity::Detailed
+            && let Some(entry) = &entry
+        {
+            summary.push_str(&format!("\n{} Tagged at: {}", style.bullet(), entry.tagged_at));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessTagOutput {
+                success: true,
+                pid,
+                entry,
+            },
+        ))
+    }
+}
+
+/// Trim tags and reject empty ones
+fn clean_tags(tags: &[String]) -> Result<Vec<String>, McpError> {
+    tags.iter()
+        .map(|tag| {
+            let tag = tag.trim();
+            if tag.is_empty() {
+                Err(McpError::InvalidArguments("Tags must not be empty".to_string()))
+            } else {
+                Ok(tag.to_string())
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,103 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, DETAIL_ROWS};
+use crate::schema::{PROCESS_TAGS, ProcessTagsArgs, ProcessTagsOutput, ProcessTagsPrompts, SummaryStyle, SummaryVerbosity};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessTagsTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessTagsTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessTagsTool {
+    type Args = ProcessTagsArgs;
+    type Prompts = ProcessTagsPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_TAGS
+    }
+
+    fn description() -> &'static str {
+        "List live processes that were tagged with process_tag, with their tags and notes. \
+         Optionally filter by a single tag. Processes that exited since being tagged are dropped."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        false // Entries disappear as processes exit
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessTagsOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let state = self.state.clone();
+        let mut entries = tokio::task::spawn_blocking(move || {
+            state.tags.prune_exited();
+            state.tags.snapshot()
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to list tags: {e}")))?;
+
+        if let Some(tag) = &args.tag {
+            entries.retain(|entry| entry.tags.iter().any(|t| t == tag));
+        }
+
+        let mut summary = format!(
+            "{}: {}{}Tag: {}",
+            style.paint(Color::Cyan, "Tagged Processes"),
+            entries.len(),
+            style.sep(),
+            args.tag.as_deref().unwrap_or("any")
+        );
+        let shown = match verbosity {
+            SummaryVerbosity::Quiet => 0,
+            SummaryVerbosity::Normal | SummaryVerbosity::Detailed => DETAIL_ROWS,
+        };
+        for entry in entries.iter().take(shown) {
+            summary.push_str(&format!(
+                "\n{} PID {} ({}): {}",
+                style.bullet(),
+                entry.pid,
+                entry.name,
+                entry.tags.join(", ")
+            ));
+            if verbosity == SummaryVerbosity::Detailed
+                && let Some(note) = &entry.note
+            {
+                summary.push_str(&format!("{}{note}", style.sep()));
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessTagsOutput {
+                success: true,
+                count: entries.len(),
+                entries,
+            },
+        ))
+    }
+}
@@ -0,0 +1,364 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::config::ProcessConfig;
+use crate::container;
+use crate::display::{Color, ICON_KILL};
+use crate::events::ProcessEvent;
+use crate::identity;
+use crate::killed::snapshot;
+use crate::platform;
+use crate::protection::kill_guard;
+use crate::provider::SignalError;
+use crate::schema::{
+    ContainerDelivery, EscalationOutcome, EscalationStep, FINAL_STEP_WAIT_MS, MAX_ESCALATION_STEPS, MAX_STEP_WAIT_MS, PROCESS_TERMINATE,
+    ProcessTerminateArgs, ProcessTerminateOutput, ProcessTerminatePrompts, SummaryStyle, SummaryVerbosity,
+};
+use crate::state::ProcessState;
+use crate::throttle::budget_text;
+
+/// How often the target is checked while a step waits
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessTerminateTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessTerminateTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessTerminateTool {
+    type Args = ProcessTerminateArgs;
+    type Prompts = ProcessTerminatePrompts;
+
+    fn name() -> &'static str {
+        PROCESS_TERMINATE
+    }
+
+    fn description() -> &'static str {
+        "Terminate a process gracefully. Runs an escalation plan, an ordered list of \
+         {signal, wait_ms} steps such as hup for 2000 ms, term for 5000 ms, then kill, and stops \
+         as soon as the process exits. Default plan: term, wait 5 s, kill. Reports each step and \
+         which one succeeded. Consumes one unit of kill budget; do-not-kill tags and the \
+         confirmation policy apply as for process_kill. Pass container as \"<container_id>:<pid>\" \
+         to signal a PID inside a container through its runtime or nsenter (Linux). On Windows \
+         only kill is delivered."
+    }
+
+    fn read_only() -> bool {
+        false // Signals processes
+    }
+
+    fn destructive() -> bool {
+        true // Ends with SIGKILL unless the plan says otherwise
+    }
+
+    fn idempotent() -> bool {
+        false // The process is gone after the first successful call
+    }
+
+    async fn execute(&self, args: Self::Args, ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessTerminateOutput>, McpError> {
+        self.terminate(args, ctx.connection_id()).await
+    }
+}
+
+// ============================================================================
+// TERMINATE
+// ============================================================================
+
+impl ProcessTerminateTool {
+    /// Run the escalation plan with all policy checks, independent of the MCP transport
+    /// (shared with scheduled kills)
+    ///
+    /// `client` keys the kill budget.
+    pub(crate) async fn terminate(
+        &self,
+        args: ProcessTerminateArgs,
+        client: Option<&str>,
+    ) -> Result<ToolResponse<ProcessTerminateOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let located = match args.container.as_deref() {
+            Some(_) if args.pid.is_some() || args.proc_id.is_some() => {
+                return Err(McpError::InvalidArguments(
+                    "container replaces pid and proc_id; pass only one of them".to_string(),
+                ));
+            }
+            Some(target) => {
+                let target = container::parse(target)?;
+                let state = self.state.clone();
+                let located = tokio::task::spawn_blocking(move || {
+                    container::locate(&state, &target.container, target.pid).map(|located| (located, target.pid))
+                })
+                .await
+                .map_err(|e| McpError::Other(anyhow::anyhow!("Container lookup task failed: {e}")))??;
+                Some(located)
+            }
+            None => None,
+        };
+        let pid = match &located {
+            Some((located, _)) => located.host_pid,
+            None => identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?,
+        };
+        let plan = args.plan;
+
+        if pid == 0 {
+            return Err(McpError::InvalidArguments(
+                "Invalid PID 0: cannot terminate process with ID 0".to_string(),
+            ));
+        }
+        check_plan(&plan)?;
+
+        let (reservation, budget) = self
+            .state
+            .throttle
+            .acquire(client)
+            .map_err(|reason| McpError::PermissionDenied(format!("Refusing to terminate process {pid}: {reason}")))?;
+        let refuse = |reason: String| {
+            // Failed attempts do not count against the budget
+            let budget = self.state.throttle.release(&reservation);
+            McpError::PermissionDenied(format!(
+                "Failed to terminate process {pid}: {reason} (budget: {})",
+                budget_text(&budget)
+            ))
+        };
+
+        let record = self.state.killed.enabled();
+        let confirm = args.confirm;
+        let state = self.state.clone();
+        let target = tokio::task::spawn_blocking(move || {
+            let Some(process) = state.provider.lookup(pid, record) else {
+                return Err("Process not found".to_string());
+            };
+            kill_guard(&state, pid, &process, confirm)?;
+            Ok(process)
+        })
+        .await
+        .map_err(|e| refuse(format!("lookup failed: {e}")))?
+        .map_err(refuse)?;
+
+        let name = target.name.clone();
+        let start_time = target.start_time;
+        let mut entry = snapshot(target);
+
+        // ====================================================================
+        // ESCALATION
+        // ====================================================================
+
+        let started = Instant::now();
+        let mut steps = Vec::with_capacity(plan.len());
+        let mut succeeded_step = None;
+        let mut denied = false;
+        let mut via = None;
+        for (index, step) in plan.iter().enumerate() {
+            let state = self.state.clone();
+            let signal = step.signal;
+            // Why a container delivery failed, reported instead of "not supported"
+            let mut failure = None;
+            let delivery = match &located {
+                Some((located, container_pid)) => {
+                    let (container_id, container_pid) = (located.container_id.clone(), *container_pid);
+                    let delivered = tokio::task::spawn_blocking(move || container::signal(&container_id, container_pid, pid, signal))
+                        .await
+                        .unwrap_or_else(|e| Err(e.to_string()));
+                    match delivered {
+                        Ok(runtime) => {
+                            via = Some(runtime);
+                            Ok(())
+                        }
+                        Err(_) if self.wait_for_exit(pid, start_time, Duration::ZERO).await.0 => Err(SignalError::NotFound),
+                        Err(reason) => {
+                            failure = Some(reason);
+                            Err(SignalError::Unsupported)
+                        }
+                    }
+                }
+                None => tokio::task::spawn_blocking(move || state.provider.signal(pid, signal))
+                    .await
+                    .unwrap_or(Err(SignalError::Unsupported)),
+            };
+
+            let (delivered, error, exited, waited) = match delivery {
+                Ok(()) => {
+                    let wait = if index + 1 == plan.len() {
+                        step.wait_ms.max(FINAL_STEP_WAIT_MS)
+                    } else {
+                        step.wait_ms
+                    };
+                    let (exited, waited) = self.wait_for_exit(pid, start_time, Duration::from_millis(wait)).await;
+                    (true, None, exited, waited)
+                }
+                Err(SignalError::NotFound) => (false, Some("process already exited".to_string()), true, Duration::ZERO),
+                Err(SignalError::Denied) => {
+                    denied = true;
+                    let error = match platform::permission_hint(pid) {
+                        Some(hint) => format!("permission denied: {hint}"),
+                        None => "permission denied".to_string(),
+                    };
+                    (false, Some(error), false, Duration::ZERO)
+                }
+                Err(SignalError::Unsupported) => (
+                    false,
+                    Some(failure.unwrap_or_else(|| format!("{} is not supported on this platform", signal.name()))),
+                    false,
+                    Duration::ZERO,
+                ),
+            };
+            steps.push(EscalationOutcome {
+                signal: signal.name().to_string(),
+                delivered,
+                error,
+                waited_ms: u64::try_from(waited.as_millis()).unwrap_or(u64::MAX),
+                exited,
+            });
+            if exited {
+                succeeded_step = Some(index);
+                break;
+            }
+        }
+        let elapsed_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        if succeeded_step.is_none() && !steps.iter().any(|step| step.delivered) {
+            let reason = match steps.last().and_then(|step| step.error.as_deref()) {
+                _ if denied => "permission denied for every step".to_string(),
+                Some(error) if located.is_some() => format!("no step could be delivered: {error}"),
+                _ => "no step could be delivered".to_string(),
+            };
+            return Err(refuse(reason));
+        }
+
+        // ====================================================================
+        // RESULT
+        // ====================================================================
+
+        let (budget, record_id) = match succeeded_step {
+            Some(_) => {
+                entry.client = client.map(str::to_string);
+                let record_id = record.then(|| self.state.killed.record(entry.clone()));
+                entry.id = record_id.unwrap_or_default();
+                self.state.events.publish(ProcessEvent::Killed(entry));
+                (budget, record_id)
+            }
+            // The process survived, so the attempt does not count
+            None => (self.state.throttle.release(&reservation), None),
+        };
+        let succeeded_signal = succeeded_step.map(|index| steps[index].signal.clone());
+
+        let mut summary = match (succeeded_step, &succeeded_signal) {
+            (Some(index), Some(signal)) => style.paint(
+                Color::Red,
+                &format!(
+                    "{}Process Terminated: PID {pid} by {signal} (step {} of {})",
+                    style.icon(ICON_KILL),
+                    index + 1,
+                    plan.len()
+                ),
+            ),
+            _ => format!(
+                "{} PID {pid} survived all {} steps",
+                style.paint(Color::Cyan, "Still Running:"),
+                plan.len()
+            ),
+        };
+        if let Some((located, container_pid)) = &located {
+            summary.push_str(&format!(" (PID {container_pid} in container {})", container::short(&located.container_id)));
+        }
+        if verbosity != SummaryVerbosity::Quiet {
+            let ran: Vec<String> = steps
+                .iter()
+                .map(|step| match &step.error {
+                    Some(error) => format!("{} ({error})", step.signal),
+                    None => format!("{} ({} ms)", step.signal, step.waited_ms),
+                })
+                .collect();
+            summary.push_str(&format!("\n{} Steps: {}", style.bullet(), ran.join(style.arrow())));
+            summary.push_str(&format!("\n{} Budget: {}", style.bullet(), budget_text(&budget)));
+        }
+        if verbosity == SummaryVerbosity::Detailed {
+            summary.push_str(&format!("\n{} Name: {name}{}Elapsed: {elapsed_ms} ms", style.bullet(), style.sep()));
+            if let Some(via) = via {
+                summary.push_str(&format!("\n{} Delivered by: {via}", style.bullet()));
+            }
+            if let Some(id) = record_id {
+                summary.push_str(&format!("\n{} Recorded: #{id} (process_recently_killed)", style.bullet()));
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessTerminateOutput {
+                success: succeeded_step.is_some(),
+                pid,
+                name,
+                succeeded_step,
+                succeeded_signal,
+                steps,
+                elapsed_ms,
+                budget,
+                record_id,
+                container: located.map(|(located, container_pid)| ContainerDelivery {
+                    id: located.container_id,
+                    pid: container_pid,
+                    via: via.map(str::to_string),
+                }),
+            },
+        ))
+    }
+
+    /// Poll until the process is gone or `wait` has passed; returns whether it exited and the time waited
+    ///
+    /// A different start time under the same PID counts as exited (PID reuse).
+    async fn wait_for_exit(&self, pid: u32, start_time: u64, wait: Duration) -> (bool, Duration) {
+        let began = Instant::now();
+        loop {
+            let state = self.state.clone();
+            let gone = tokio::task::spawn_blocking(move || {
+                state
+                    .provider
+                    .lookup(pid, false)
+                    .is_none_or(|process| process.start_time != start_time)
+            })
+            .await
+            .unwrap_or(false);
+            let waited = began.elapsed();
+            if gone || waited >= wait {
+                return (gone, waited);
+            }
+            tokio::time::sleep(POLL_INTERVAL.min(wait - waited)).await;
+        }
+    }
+}
+
+/// Reject plans with no or too many steps, or an overlong wait
+pub(crate) fn check_plan(plan: &[EscalationStep]) -> Result<(), McpError> {
+    if plan.is_empty() || plan.len() > MAX_ESCALATION_STEPS {
+        return Err(McpError::InvalidArguments(format!(
+            "plan needs 1 to {MAX_ESCALATION_STEPS} steps, got {}",
+            plan.len()
+        )));
+    }
+    if let Some(step) = plan.iter().find(|step| step.wait_ms > MAX_STEP_WAIT_MS) {
+        return Err(McpError::InvalidArguments(format!(
+            "wait_ms {} of {} exceeds the maximum of {MAX_STEP_WAIT_MS}",
+            step.wait_ms,
+            step.signal.name()
+        )));
+    }
+    Ok(())
+}
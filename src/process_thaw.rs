@@ -0,0 +1,86 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::identity;
+use crate::schema::{PROCESS_THAW, ProcessThawArgs, ProcessThawOutput, ProcessThawPrompts, SummaryStyle, SummaryVerbosity};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessThawTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessThawTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessThawTool {
+    type Args = ProcessThawArgs;
+    type Prompts = ProcessThawPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_THAW
+    }
+
+    fn description() -> &'static str {
+        "Resume a process tree frozen by process_freeze. With the cgroup method every member \
+         thaws at once and moves back to its original cgroup; members stopped with SIGSTOP get \
+         SIGCONT, deepest first."
+    }
+
+    fn read_only() -> bool {
+        false // Resumes a process tree
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessThawOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+
+        let state = self.state.clone();
+        let freeze = tokio::task::spawn_blocking(move || state.freezes.thaw(pid))
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Thaw task failed: {e}")))?
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to thaw process tree {pid}: {e}")))?
+            .ok_or_else(|| McpError::ResourceNotFound(format!("Process tree {pid} is not frozen")))?;
+
+        let mut summary = format!(
+            "{} PID {pid} ({}) and {} descendant(s)",
+            style.paint(Color::Cyan, "Thawed:"),
+            freeze.name,
+            freeze.members.len().saturating_sub(1)
+        );
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!(
+                "\n{} Method: {}{}Frozen since: {}",
+                style.bullet(),
+                freeze.method.name(),
+                style.sep(),
+                freeze.since
+            ));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessThawOutput {
+                success: true,
+                freeze,
+            },
+        ))
+    }
+}
@@ -0,0 +1,190 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::ProcessId;
+use crate::config::ProcessConfig;
+use crate::display::{Color, human_bytes};
+use crate::identity;
+use crate::provider::ProcessEntry;
+use crate::schema::{
+    PROCESS_TREE, ProcessTreeArgs, ProcessTreeNode, ProcessTreeOutput, ProcessTreePrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+use crate::tree::{children_of, roots};
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessTreeTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessTreeTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessTreeTool {
+    type Args = ProcessTreeArgs;
+    type Prompts = ProcessTreePrompts;
+
+    fn name() -> &'static str {
+        PROCESS_TREE
+    }
+
+    fn description() -> &'static str {
+        "Show the parent/child hierarchy as nested JSON: each node has pid, proc_id, name, CPU, \
+         resident memory and children[] sorted by PID. Rooted at pid (or proc_id) when given, \
+         otherwise every process without a parent is a root. max_depth limits the levels \
+         returned; a cut-off node reports its hidden_descendants."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessTreeOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let root = if args.pid.is_some() || args.proc_id.is_some() {
+            Some(identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?)
+        } else {
+            None
+        };
+
+        let provider = self.state.provider.clone();
+        let max_depth = args.max_depth;
+        let roots = tokio::task::spawn_blocking(move || {
+            let processes = provider.enumerate();
+            let children = children_of(&processes);
+            let top: Vec<&ProcessEntry> = match root {
+                Some(pid) => vec![
+                    processes
+                        .iter()
+                        .find(|process| process.pid == pid)
+                        .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?,
+                ],
+                None => roots(&processes),
+            };
+            let mut seen = HashSet::new();
+            Ok::<_, McpError>(
+                top.into_iter()
+                    .map(|process| node(process, &children, max_depth, 0, &mut seen))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Process tree task failed: {e}")))??;
+
+        let count: usize = roots.iter().map(size).sum();
+        let mut summary = format!(
+            "{} {count} processes{}{} roots",
+            style.paint(Color::Cyan, "Process Tree:"),
+            style.sep(),
+            roots.len()
+        );
+        let budget = match verbosity {
+            SummaryVerbosity::Quiet => 0,
+            SummaryVerbosity::Normal => 30,
+            SummaryVerbosity::Detailed => 200,
+        };
+        let mut lines = Vec::new();
+        for node in &roots {
+            render(node, 0, verbosity, &mut lines, budget);
+        }
+        for line in &lines {
+            summary.push_str(&format!("\n{line}"));
+        }
+        if lines.len() < count && budget > 0 {
+            summary.push_str(&format!("\n{} {} more", style.bullet(), count - lines.len()));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessTreeOutput {
+                success: true,
+                root,
+                count,
+                roots,
+            },
+        ))
+    }
+}
+
+/// `process` and its descendants down to `max_depth` levels below the roots
+fn node(
+    process: &ProcessEntry,
+    children: &HashMap<ProcessId, Vec<&ProcessEntry>>,
+    max_depth: Option<usize>,
+    depth: usize,
+    seen: &mut HashSet<ProcessId>,
+) -> ProcessTreeNode {
+    seen.insert(process.pid);
+    let mut tree = ProcessTreeNode {
+        pid: process.pid,
+        proc_id: identity::proc_id(process.pid, process.start_time),
+        name: process.name.clone(),
+        cpu_percent: process.cpu_percent,
+        memory_bytes: process.rss_bytes,
+        children: Vec::new(),
+        hidden_descendants: 0,
+    };
+    let kids = children.get(&process.pid).into_iter().flatten();
+    if max_depth.is_some_and(|max| depth >= max) {
+        tree.hidden_descendants = kids.map(|child| 1 + hidden(child.pid, children, seen)).sum();
+        return tree;
+    }
+    for child in kids {
+        // A PID reused while the snapshot was taken can loop back to an ancestor
+        if !seen.contains(&child.pid) {
+            tree.children.push(node(child, children, max_depth, depth + 1, seen));
+        }
+    }
+    tree
+}
+
+/// Number of descendants of `pid` not yet in `seen`
+fn hidden(pid: ProcessId, children: &HashMap<ProcessId, Vec<&ProcessEntry>>, seen: &mut HashSet<ProcessId>) -> usize {
+    let mut count = 0;
+    for child in children.get(&pid).into_iter().flatten() {
+        if seen.insert(child.pid) {
+            count += 1 + hidden(child.pid, children, seen);
+        }
+    }
+    count
+}
+
+/// Nodes in `tree`, hidden descendants excluded
+fn size(tree: &ProcessTreeNode) -> usize {
+    1 + tree.children.iter().map(size).sum::<usize>()
+}
+
+/// Indented lines for `tree`, at most `budget` in total
+fn render(tree: &ProcessTreeNode, depth: usize, verbosity: SummaryVerbosity, lines: &mut Vec<String>, budget: usize) {
+    if lines.len() >= budget {
+        return;
+    }
+    let mut line = format!("{}{} ({})", "  ".repeat(depth), tree.name, tree.pid);
+    if verbosity == SummaryVerbosity::Detailed {
+        line.push_str(&format!(" {:.1}% {}", tree.cpu_percent, human_bytes(tree.memory_bytes)));
+    }
+    if tree.hidden_descendants > 0 {
+        line.push_str(&format!(" [+{} below]", tree.hidden_descendants));
+    }
+    lines.push(line);
+    for child in &tree.children {
+        render(child, depth + 1, verbosity, lines, budget);
+    }
+}
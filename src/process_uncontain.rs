@@ -0,0 +1,84 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::identity;
+use crate::process_contain::{caps_text, usage_text};
+use crate::schema::{
+    PROCESS_UNCONTAIN, ProcessUncontainArgs, ProcessUncontainOutput, ProcessUncontainPrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessUncontainTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessUncontainTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessUncontainTool {
+    type Args = ProcessUncontainArgs;
+    type Prompts = ProcessUncontainPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_UNCONTAIN
+    }
+
+    fn description() -> &'static str {
+        "Release a process contained by process_contain. It and any children forked while \
+         contained move back to the original cgroup, and the managed cgroup is removed. Reports \
+         the final memory and pids usage."
+    }
+
+    fn read_only() -> bool {
+        false // Moves the process between cgroups
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessUncontainOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+        let pid = identity::resolve(&self.state, args.pid, args.proc_id.as_deref()).await?;
+
+        let state = self.state.clone();
+        let containment = tokio::task::spawn_blocking(move || state.containments.release(pid))
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Containment task failed: {e}")))?
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to release process {pid}: {e}")))?
+            .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} is not contained")))?;
+
+        let mut summary = format!(
+            "{} PID {pid} ({}), was {}",
+            style.paint(Color::Cyan, "Released:"),
+            containment.name,
+            caps_text(&containment.caps)
+        );
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!("\n{} Final usage: {}", style.bullet(), usage_text(&containment)));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessUncontainOutput {
+                success: true,
+                containment,
+            },
+        ))
+    }
+}
@@ -0,0 +1,67 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::schema::{PROCESS_UNWATCH, ProcessUnwatchArgs, ProcessUnwatchOutput, ProcessUnwatchPrompts, SummaryStyle};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessUnwatchTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessUnwatchTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessUnwatchTool {
+    type Args = ProcessUnwatchArgs;
+    type Prompts = ProcessUnwatchPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_UNWATCH
+    }
+
+    fn description() -> &'static str {
+        "Remove a watchlist entry created by process_watch. Events already recorded stay \
+         available through process_watch_events."
+    }
+
+    fn read_only() -> bool {
+        false // Removes a server-side watch
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessUnwatchOutput>, McpError> {
+        // Single-line result at every verbosity
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+
+        if !self.state.watch.unwatch(args.id) {
+            return Err(McpError::ResourceNotFound(format!(
+                "Watch #{} not found (PID watches end when the process exits)",
+                args.id
+            )));
+        }
+
+        Ok(ToolResponse::new(
+            format!("{} #{}", style.paint(Color::Cyan, "Unwatched"), args.id),
+            ProcessUnwatchOutput {
+                success: true,
+                id: args.id,
+            },
+        ))
+    }
+}
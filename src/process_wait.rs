@@ -0,0 +1,222 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use sysinfo::ProcessStatus;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::config::ProcessConfig;
+use crate::display::Color;
+use crate::events::ProcessEvent;
+use crate::identity;
+use crate::process_suspend::run_status;
+use crate::schema::{
+    ExitInfo, MAX_WAIT_TIMEOUT_MS, PROCESS_WAIT, ProcessWaitArgs, ProcessWaitOutput, ProcessWaitPrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+/// How often the target is checked
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How long a supervised process may look gone before its exit is reaped and reported
+const REAP_GRACE: Duration = Duration::from_secs(1);
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessWaitTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessWaitTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessWaitTool {
+    type Args = ProcessWaitArgs;
+    type Prompts = ProcessWaitPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_WAIT
+    }
+
+    fn description() -> &'static str {
+        "Block until a process exits or timeout_ms passes (default 30000, at most 600000), e.g. \
+         to confirm a kill took effect or to wait out a build. Returns exited, the time waited \
+         and, for processes launched through this server (process_spawn, process_run, \
+         profiles), the exit code or terminating signal. A PID that is already gone returns at \
+         once; a zombie or a PID reused by a newer process counts as exited. Pass proc_id to \
+         wait for exactly the process it names."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        true
+    }
+
+    async fn execute(&self, args: Self::Args, ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessWaitOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        if args.timeout_ms > MAX_WAIT_TIMEOUT_MS {
+            return Err(McpError::InvalidArguments(format!("timeout_ms must be at most {MAX_WAIT_TIMEOUT_MS}")));
+        }
+        // A handle whose process is gone is what the caller waits for, not an error
+        let (pid, expected_start) = identity::target(args.pid, args.proc_id.as_deref())?;
+
+        // Subscribe first so an exit right after the lookup is not missed
+        let mut events = self.state.events.subscribe();
+        let state = self.state.clone();
+        let target = tokio::task::spawn_blocking(move || {
+            state
+                .provider
+                .lookup(pid, false)
+                .filter(|process| expected_start.is_none_or(|start_time| process.start_time == start_time))
+                .filter(|process| run_status(pid, process.start_time) != Some(ProcessStatus::Zombie))
+        })
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Process lookup task failed: {e}")))?;
+        // Only a launch still running is this process (it shows running while its zombie awaits
+        // the reaper); finished entries on the same PID are earlier processes it was reused from
+        let supervised = self
+            .state
+            .supervisor
+            .list(None, Some(pid))
+            .into_iter()
+            .filter(|process| process.running)
+            .max_by_key(|process| process.id)
+            .map(|process| process.id);
+
+        let started = Instant::now();
+        let mut exit: Option<ExitInfo> = None;
+        let mut cancelled = false;
+        let exited = match &target {
+            None => true,
+            Some(process) => {
+                let start_time = process.start_time;
+                let deadline = tokio::time::sleep(Duration::from_millis(args.timeout_ms));
+                tokio::pin!(deadline);
+                let mut events_open = true;
+                loop {
+                    tokio::select! {
+                        () = &mut deadline => break false,
+                        () = ctx.cancellation_token().cancelled() => {
+                            cancelled = true;
+                            break false;
+                        }
+                        received = events.recv(), if events_open && supervised.is_some() => match received {
+                            Ok(ProcessEvent::Finished(process)) if Some(process.id) == supervised => {
+                                exit = process.exit;
+                                break true;
+                            }
+                            Ok(_) | Err(RecvError::Lagged(_)) => {}
+                            Err(RecvError::Closed) => events_open = false,
+                        },
+                        () = tokio::time::sleep(POLL_INTERVAL) => {
+                            if self.gone(pid, start_time).await {
+                                break true;
+                            }
+                        }
+                    }
+                }
+            }
+        };
+        if exited && exit.is_none() && let Some(id) = supervised {
+            exit = self.reaped(id).await;
+        }
+        let elapsed_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        let name = target.map(|process| process.name);
+        let label = name.as_deref().map_or_else(|| format!("PID {pid}"), |name| format!("PID {pid} ({name})"));
+        let waited = format!("{:.1}s", elapsed_ms as f64 / 1000.0);
+        let mut summary = if exited {
+            let color = if exit.as_ref().is_none_or(|exit| exit.success) { Color::Green } else { Color::Red };
+            let mut line = if name.is_some() {
+                format!("{} {label} after {waited}", style.paint(color, "Exited:"))
+            } else {
+                format!("{} {label} was already gone", style.paint(color, "Exited:"))
+            };
+            if let Some(exit) = &exit {
+                let outcome = match (exit.code, &exit.signal_name) {
+                    (Some(code), _) => format!("exit code {code}"),
+                    (None, Some(signal)) => format!("killed by {signal}"),
+                    (None, None) => "no exit status".to_string(),
+                };
+                line.push_str(&format!("{}{}", style.sep(), style.paint(color, &outcome)));
+            }
+            line
+        } else {
+            format!(
+                "{} {label} still running after {waited}",
+                style.paint(Color::Red, if cancelled { "Cancelled:" } else { "Timed Out:" })
+            )
+        };
+        if verbosity == SummaryVerbosity::Detailed
+            && let Some(exit) = &exit
+        {
+            summary.push_str(&format!(
+                "\n{} Ran {:.1}s{}exited at {}",
+                style.bullet(),
+                exit.duration_secs,
+                style.sep(),
+                exit.exited_at
+            ));
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessWaitOutput {
+                success: exited,
+                pid,
+                already_exited: name.is_none(),
+                name,
+                exited,
+                cancelled,
+                elapsed_ms,
+                exit,
+            },
+        ))
+    }
+}
+
+impl ProcessWaitTool {
+    /// Exit status of supervised child `id`, once the supervisor has reaped it
+    async fn reaped(&self, id: u64) -> Option<ExitInfo> {
+        let deadline = Instant::now() + REAP_GRACE;
+        loop {
+            let entry = self.state.supervisor.list(Some(id), None).pop()?;
+            if entry.exit.is_some() || !entry.running || Instant::now() >= deadline {
+                return entry.exit;
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Whether the process that started at `start_time` has exited, zombies included
+    async fn gone(&self, pid: u32, start_time: u64) -> bool {
+        let state = self.state.clone();
+        tokio::task::spawn_blocking(move || {
+            state
+                .provider
+                .lookup(pid, false)
+                .is_none_or(|process| process.start_time != start_time)
+                || run_status(pid, start_time) == Some(ProcessStatus::Zombie)
+        })
+        .await
+        .unwrap_or(false)
+    }
+}
@@ -0,0 +1,117 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, ICON_INFO};
+use crate::identity;
+use crate::schema::{PROCESS_WATCH, ProcessWatchArgs, ProcessWatchOutput, ProcessWatchPrompts, SummaryStyle, SummaryVerbosity};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessWatchTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessWatchTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessWatchTool {
+    type Args = ProcessWatchArgs;
+    type Prompts = ProcessWatchPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_WATCH
+    }
+
+    fn description() -> &'static str {
+        "Put a PID or a process-name glob on the watchlist. The server samples watched processes \
+         every second and, when one exits, streams an MCP notification to this client, POSTs the \
+         event to an optional webhook and records it for process_watch_events, including runtime \
+         and last known CPU/memory. Use instead of polling process_list while babysitting builds."
+    }
+
+    fn read_only() -> bool {
+        false // Adds a server-side watch
+    }
+
+    fn idempotent() -> bool {
+        false // Each call creates a new watch
+    }
+
+    fn open_world() -> bool {
+        true // Optional webhook calls
+    }
+
+    async fn execute(&self, args: Self::Args, ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessWatchOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        if let Some(url) = &args.webhook
+            && !(url.starts_with("http://") || url.starts_with("https://"))
+        {
+            return Err(McpError::InvalidArguments(format!("Webhook must be an http(s) URL: {url}")));
+        }
+
+        let pid = match (args.pid, args.proc_id.as_deref()) {
+            (None, None) => None,
+            (pid, proc_id) => Some(identity::resolve(&self.state, pid, proc_id).await?),
+        };
+        let watchlist = self.state.watch.clone();
+        let webhook = args.webhook.clone();
+        let watch = match (pid, args.pattern) {
+            (Some(pid), None) => tokio::task::spawn_blocking(move || watchlist.watch_pid(pid, webhook, Some(ctx))).await,
+            (None, Some(pattern)) => {
+                tokio::task::spawn_blocking(move || watchlist.watch_pattern(&pattern, webhook, Some(ctx))).await
+            }
+            _ => {
+                return Err(McpError::InvalidArguments(
+                    "Pass exactly one of pid, proc_id or pattern".to_string(),
+                ));
+            }
+        }
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to create watch: {e}")))?
+        .map_err(McpError::InvalidArguments)?;
+
+        let target = match (&watch.pid, &watch.pattern) {
+            (Some(pid), _) => format!("PID {pid}"),
+            (None, Some(pattern)) => format!("pattern {pattern:?}"),
+            (None, None) => "nothing".to_string(),
+        };
+        let mut summary = format!(
+            "{} #{}: {target} ({} live)",
+            style.paint(Color::Cyan, "Watching"),
+            watch.id,
+            watch.members.len()
+        );
+        if verbosity != SummaryVerbosity::Quiet {
+            summary.push_str(&format!(
+                "\n{}Exits are streamed here{}",
+                style.icon(ICON_INFO),
+                if watch.webhook.is_some() { " and POSTed to the webhook" } else { "" }
+            ));
+        }
+        if verbosity == SummaryVerbosity::Detailed && !watch.members.is_empty() {
+            summary.push_str(&format!(
+                "\n{} Members: {}",
+                style.bullet(),
+                watch.members.iter().map(u32::to_string).collect::<Vec<_>>().join(", ")
+            ));
+        }
+
+        Ok(ToolResponse::new(summary, ProcessWatchOutput { success: true, watch }))
+    }
+}
@@ -0,0 +1,105 @@
+use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse};
+use kodegen_mcp_schema::McpError;
+use std::sync::Arc;
+
+use crate::config::ProcessConfig;
+use crate::display::{Color, DETAIL_ROWS, human_bytes};
+use crate::schema::{
+    PROCESS_WATCH_EVENTS, ProcessWatchEventsArgs, ProcessWatchEventsOutput, ProcessWatchEventsPrompts, SummaryStyle,
+    SummaryVerbosity,
+};
+use crate::state::ProcessState;
+
+// ============================================================================
+// TOOL STRUCT
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct ProcessWatchEventsTool {
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+}
+
+impl ProcessWatchEventsTool {
+    #[must_use]
+    pub fn new(config: Arc<ProcessConfig>, state: Arc<ProcessState>) -> Self {
+        Self { config, state }
+    }
+}
+
+// ============================================================================
+// TOOL IMPLEMENTATION
+// ============================================================================
+
+impl Tool for ProcessWatchEventsTool {
+    type Args = ProcessWatchEventsArgs;
+    type Prompts = ProcessWatchEventsPrompts;
+
+    fn name() -> &'static str {
+        PROCESS_WATCH_EVENTS
+    }
+
+    fn description() -> &'static str {
+        "List active watchlist entries and recorded exit events (oldest first) with runtime, \
+         exit detection time and last known CPU/memory. Pass the returned last_event_id as \
+         since to receive only new events."
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    fn idempotent() -> bool {
+        false // New events arrive as processes exit
+    }
+
+    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<ProcessWatchEventsOutput>, McpError> {
+        let style = SummaryStyle::resolve(args.style, self.config.style);
+        let verbosity = args.verbosity.unwrap_or(self.config.verbosity);
+
+        let watches = self.state.watch.list();
+        let events = self.state.watch.events(args.since, args.watch_id);
+        let last_event_id = events.last().map_or(args.since, |event| event.id);
+
+        let mut summary = format!(
+            "{}: {} active{}Exits: {}",
+            style.paint(Color::Cyan, "Watchlist"),
+            watches.len(),
+            style.sep(),
+            events.len()
+        );
+        let shown = match verbosity {
+            SummaryVerbosity::Quiet => 0,
+            SummaryVerbosity::Normal | SummaryVerbosity::Detailed => DETAIL_ROWS,
+        };
+        for event in events.iter().rev().take(shown) {
+            summary.push_str(&format!(
+                "\n{} PID {} ({}) exited at {} after {}s",
+                style.bullet(),
+                event.pid,
+                event.name,
+                event.exited_at,
+                event.runtime_secs
+            ));
+            if verbosity == SummaryVerbosity::Detailed {
+                summary.push_str(&format!(
+                    "{}last CPU {:.1}%{}last Mem {}",
+                    style.sep(),
+                    event.last_cpu_percent,
+                    style.sep(),
+                    human_bytes(event.last_rss_bytes)
+                ));
+            }
+        }
+
+        Ok(ToolResponse::new(
+            summary,
+            ProcessWatchEventsOutput {
+                success: true,
+                watches,
+                events,
+                last_event_id,
+            },
+        ))
+    }
+}
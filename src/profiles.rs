@@ -0,0 +1,525 @@
+//! Spawn profiles: pre-approved launches started by name
+//!
+//! A profile fixes everything about a launch except its declared parameters,
+//! whose values replace `{name}` placeholders in the arguments, working
+//! directory and environment values once they match the parameter's pattern.
+//! Profiles come from the server config or, when `spawn_profiles.allow_define`
+//! is set, from `process_profile_define`; configured profiles cannot be
+//! replaced at runtime. A launch with a restart policy is watched by a task
+//! that starts it again after it exits, until `max_restarts` is reached or the
+//! process is killed through the server.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use kodegen_mcp_schema::McpError;
+use regex::Regex;
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::config::{SandboxConfig, SpawnProfilesConfig};
+use crate::events::ProcessEvent;
+use crate::process_contain::check_caps;
+use crate::sandbox;
+use crate::schema::{
+    PROCESS_PROFILE_START, ProfileLaunch, ProfileSource, RestartPolicy, SpawnProfile, SupervisedProcess,
+};
+use crate::spawn::{SpawnSpec, check_cpus, check_io_priority, check_nice, parse_env};
+use crate::state::ProcessState;
+
+/// Values a parameter accepts when its profile sets no pattern: one word that
+/// cannot start with `-`, so a value never turns into an option
+const DEFAULT_PATTERN: &str = r"[\w.,:/@%+=~][\w.,:/@%+=~-]*";
+
+/// How long after an exit a kill through the server still cancels the restart
+/// (the kill tools announce the kill after the process is gone)
+const KILL_GRACE: Duration = Duration::from_millis(200);
+
+/// One process_profile_start call and the restarts it led to
+struct Launch {
+    profile: String,
+    /// Supervisor id of the current process
+    current: u64,
+    pid: u32,
+    restarts: u32,
+    restarting: bool,
+}
+
+pub struct Profiles {
+    allow_define: bool,
+    configured: BTreeMap<String, Arc<SpawnProfile>>,
+    defined: Mutex<BTreeMap<String, Arc<SpawnProfile>>>,
+    /// Keyed by the supervisor id of the first process
+    launches: Mutex<BTreeMap<u64, Launch>>,
+}
+
+impl Profiles {
+    /// Profiles from `config`, skipping invalid ones
+    #[must_use]
+    pub fn new(config: &SpawnProfilesConfig, sandbox: &SandboxConfig) -> Self {
+        let mut configured = BTreeMap::new();
+        for profile in &config.profiles {
+            if let Err(e) = validate(profile, sandbox) {
+                log::warn!("Ignoring invalid spawn profile {:?}: {e}", profile.name);
+                continue;
+            }
+            if configured.insert(profile.name.clone(), Arc::new(profile.clone())).is_some() {
+                log::warn!("Spawn profile {:?} is configured twice; the last one applies", profile.name);
+            }
+        }
+        Self {
+            allow_define: config.allow_define,
+            configured,
+            defined: Mutex::default(),
+            launches: Mutex::default(),
+        }
+    }
+
+    /// Whether profiles may be defined at runtime
+    #[must_use]
+    pub fn allow_define(&self) -> bool {
+        self.allow_define
+    }
+
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<(Arc<SpawnProfile>, ProfileSource)> {
+        if let Some(profile) = self.configured.get(name) {
+            return Some((profile.clone(), ProfileSource::Config));
+        }
+        self.defined().get(name).map(|profile| (profile.clone(), ProfileSource::Defined))
+    }
+
+    /// All profiles, sorted by name
+    #[must_use]
+    pub fn all(&self) -> Vec<(Arc<SpawnProfile>, ProfileSource)> {
+        let mut all: Vec<_> = self
+            .configured
+            .values()
+            .map(|profile| (profile.clone(), ProfileSource::Config))
+            .chain(self.defined().values().map(|profile| (profile.clone(), ProfileSource::Defined)))
+            .collect();
+        all.sort_by(|a, b| a.0.name.cmp(&b.0.name));
+        all
+    }
+
+    /// Create or replace a runtime profile, returning whether one was replaced
+    pub fn define(&self, profile: SpawnProfile, sandbox: &SandboxConfig) -> Result<bool, McpError> {
+        self.check_writable(&profile.name)?;
+        validate(&profile, sandbox).map_err(McpError::InvalidArguments)?;
+        Ok(self.defined().insert(profile.name.clone(), Arc::new(profile)).is_some())
+    }
+
+    /// Delete a runtime profile; its running launches keep running
+    pub fn remove(&self, name: &str) -> Result<(), McpError> {
+        self.check_writable(name)?;
+        match self.defined().remove(name) {
+            Some(_) => Ok(()),
+            None => Err(McpError::ResourceNotFound(format!("Spawn profile {name:?} is not defined"))),
+        }
+    }
+
+    /// Launches of `profile` whose current process is still known to the supervisor, newest first
+    #[must_use]
+    pub fn launches(&self, profile: &str, state: &ProcessState) -> Vec<ProfileLaunch> {
+        let mut launches = self.lock_launches();
+        let mut known = Vec::new();
+        launches.retain(|_, launch| {
+            let current = state.supervisor.list(Some(launch.current), None).into_iter().next();
+            if current.is_none() && !launch.restarting {
+                return false;
+            }
+            if launch.profile == profile {
+                known.push(ProfileLaunch {
+                    supervisor_id: launch.current,
+                    pid: launch.pid,
+                    running: current.is_some_and(|process| process.running),
+                    restarts: launch.restarts,
+                    restarting: launch.restarting,
+                });
+            }
+            true
+        });
+        known.reverse();
+        known
+    }
+
+    fn check_writable(&self, name: &str) -> Result<(), McpError> {
+        if !self.allow_define {
+            return Err(McpError::PermissionDenied(
+                "Runtime spawn profiles are disabled (set spawn_profiles.allow_define in the server config)".to_string(),
+            ));
+        }
+        if self.configured.contains_key(name) {
+            return Err(McpError::PermissionDenied(format!(
+                "Spawn profile {name:?} comes from the server config and cannot be changed at runtime"
+            )));
+        }
+        Ok(())
+    }
+
+    fn update(&self, id: u64, change: impl FnOnce(&mut Launch)) {
+        if let Some(launch) = self.lock_launches().get_mut(&id) {
+            change(launch);
+        }
+    }
+
+    fn defined(&self) -> MutexGuard<'_, BTreeMap<String, Arc<SpawnProfile>>> {
+        self.defined.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn lock_launches(&self) -> MutexGuard<'_, BTreeMap<u64, Launch>> {
+        self.launches.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Refuse a free-form launch through `tool` unless `spawn_profiles.allow_commands` is set
+pub(crate) fn check_free_form(config: &SpawnProfilesConfig, tool: &str) -> Result<(), McpError> {
+    if config.allow_commands {
+        return Ok(());
+    }
+    Err(McpError::PermissionDenied(format!(
+        "{tool} is disabled: this server only starts spawn profiles (see process_profiles and \
+         {PROCESS_PROFILE_START}); the server must set spawn_profiles.allow_commands to run free-form commands"
+    )))
+}
+
+// ============================================================================
+// VALIDATION AND SUBSTITUTION
+// ============================================================================
+
+/// Reject a profile that could never launch or whose placeholders are undeclared
+pub(crate) fn validate(profile: &SpawnProfile, sandbox: &SandboxConfig) -> Result<(), String> {
+    let name = &profile.name;
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')) {
+        return Err(format!("Profile name {name:?} must be letters, digits, '-', '_' or '.'"));
+    }
+    if profile.command.trim().is_empty() {
+        return Err("command must not be empty".to_string());
+    }
+    expand(&profile.command, &mut |_| Err("command cannot contain placeholders".to_string()))?;
+
+    let mut declared = HashMap::new();
+    for param in &profile.params {
+        if param.name.is_empty() || !param.name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(format!("Parameter name {:?} must be letters, digits or '_'", param.name));
+        }
+        if declared.insert(param.name.as_str(), ()).is_some() {
+            return Err(format!("Parameter {:?} is declared twice", param.name));
+        }
+        anchored(param.pattern.as_deref().unwrap_or(DEFAULT_PATTERN))?;
+    }
+    let mut known = |placeholder: &str| {
+        if declared.contains_key(placeholder) {
+            Ok(String::new())
+        } else {
+            Err(format!("Placeholder {{{placeholder}}} is not a declared parameter"))
+        }
+    };
+    for arg in &profile.args {
+        expand(arg, &mut known)?;
+    }
+    if let Some(cwd) = &profile.cwd {
+        expand(cwd, &mut known)?;
+    }
+    for entry in &profile.env {
+        let Some((key, value)) = entry.split_once('=').filter(|(key, _)| !key.is_empty()) else {
+            return Err(format!("env entry {entry:?} is not KEY=VALUE"));
+        };
+        expand(key, &mut |_| Err(format!("env name {key:?} cannot contain placeholders")))?;
+        expand(value, &mut known)?;
+    }
+
+    if let Some(nice) = profile.nice {
+        check_nice(nice)?;
+    }
+    if let Some(priority) = profile.io_priority {
+        check_io_priority(priority)?;
+    }
+    check_cpus(&profile.cpus)?;
+    if let Some(caps) = &profile.limits {
+        check_caps(caps)?;
+    }
+    if let Some(requested) = &profile.sandbox {
+        sandbox::profile(sandbox, Some(requested))?;
+    }
+    Ok(())
+}
+
+/// `template` with every `{name}` replaced by `value(name)` and `{{`, `}}` unescaped
+fn expand(template: &str, value: &mut dyn FnMut(&str) -> Result<String, String>) -> Result<String, String> {
+    let mut expanded = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(at) = rest.find(['{', '}']) {
+        expanded.push_str(&rest[..at]);
+        let tail = &rest[at..];
+        if let Some(after) = tail.strip_prefix("{{") {
+            expanded.push('{');
+            rest = after;
+        } else if let Some(after) = tail.strip_prefix("}}") {
+            expanded.push('}');
+            rest = after;
+        } else if tail.starts_with('}') {
+            return Err(format!("Unmatched '}}' in {template:?} (write '}}}}' for a literal brace)"));
+        } else {
+            let end = tail
+                .find('}')
+                .ok_or_else(|| format!("Unclosed '{{' in {template:?} (write '{{{{' for a literal brace)"))?;
+            expanded.push_str(&value(&tail[1..end])?);
+            rest = &tail[end + 1..];
+        }
+    }
+    expanded.push_str(rest);
+    Ok(expanded)
+}
+
+/// `pattern` compiled to match whole values only
+fn anchored(pattern: &str) -> Result<Regex, String> {
+    Regex::new(&format!("^(?:{pattern})$")).map_err(|e| format!("Invalid pattern {pattern:?}: {e}"))
+}
+
+/// A profile with its parameters filled in
+pub(crate) struct Resolved {
+    pub spec: SpawnSpec,
+    /// Command line before sandboxing
+    pub command: String,
+    pub cwd: Option<String>,
+    pub sandbox: Option<String>,
+}
+
+/// Launch spec of `profile` with the caller's `params`
+pub(crate) fn resolve(profile: &SpawnProfile, params: &BTreeMap<String, String>, sandbox: &SandboxConfig) -> Result<Resolved, String> {
+    if let Some(unknown) = params.keys().find(|name| !profile.params.iter().any(|param| &param.name == *name)) {
+        let declared: Vec<&str> = profile.params.iter().map(|param| param.name.as_str()).collect();
+        return Err(format!(
+            "Profile {:?} has no parameter {unknown:?} (parameters: {})",
+            profile.name,
+            if declared.is_empty() { "none".to_string() } else { declared.join(", ") }
+        ));
+    }
+    let mut values = HashMap::new();
+    for param in &profile.params {
+        let value = match (params.get(&param.name), &param.default) {
+            (Some(value), _) => {
+                let pattern = param.pattern.as_deref().unwrap_or(DEFAULT_PATTERN);
+                if !anchored(pattern)?.is_match(value) {
+                    return Err(format!("Value {value:?} of parameter {:?} does not match {pattern}", param.name));
+                }
+                value.clone()
+            }
+            (None, Some(default)) => default.clone(),
+            (None, None) => return Err(format!("Parameter {:?} is required", param.name)),
+        };
+        values.insert(param.name.as_str(), value);
+    }
+    let mut fill = |name: &str| {
+        values
+            .get(name)
+            .cloned()
+            .ok_or_else(|| format!("Placeholder {{{name}}} is not a declared parameter"))
+    };
+
+    let program = expand(&profile.command, &mut fill)?;
+    let args = profile.args.iter().map(|arg| expand(arg, &mut fill)).collect::<Result<Vec<_>, _>>()?;
+    let cwd = profile.cwd.as_deref().map(|cwd| expand(cwd, &mut fill)).transpose()?;
+    let mut entries = Vec::new();
+    for entry in &profile.env {
+        let (key, value) = entry.split_once('=').unwrap_or((entry, ""));
+        entries.push(format!("{key}={}", expand(value, &mut fill)?));
+    }
+    let env = (!entries.is_empty()).then(|| {
+        let mut env: HashMap<String, String> = std::env::vars().collect();
+        env.extend(parse_env(&entries));
+        env.into_iter().collect()
+    });
+
+    let confinement = sandbox::profile(sandbox, profile.sandbox.as_deref())?;
+    let spec = SpawnSpec {
+        program,
+        args,
+        cwd: cwd.as_ref().map(PathBuf::from),
+        env,
+        io_priority: profile.io_priority,
+        nice: profile.nice,
+        cpus: profile.cpus.clone(),
+    };
+    let command = spec.command_line();
+    let spec = match confinement {
+        Some(confinement) => sandbox::confine(spec, confinement)?,
+        None => spec,
+    };
+    Ok(Resolved {
+        spec,
+        command,
+        cwd,
+        sandbox: confinement.map(|confinement| confinement.name.clone()),
+    })
+}
+
+// ============================================================================
+// LAUNCH AND RESTART
+// ============================================================================
+
+/// Start `spec` for `profile` under the supervisor and watch it per the restart policy
+pub(crate) async fn start(
+    state: &Arc<ProcessState>,
+    profile: Arc<SpawnProfile>,
+    spec: SpawnSpec,
+    client: Option<&str>,
+) -> Result<SupervisedProcess, McpError> {
+    // Subscribe first so an exit right after the launch is not missed
+    let events = state.events.subscribe();
+    let supervised = launch(state, &profile, &spec, client).await?;
+    let restarting = profile.restart != RestartPolicy::Never && profile.max_restarts > 0;
+    state.profiles.lock_launches().insert(
+        supervised.id,
+        Launch {
+            profile: profile.name.clone(),
+            current: supervised.id,
+            pid: supervised.pid,
+            restarts: 0,
+            restarting,
+        },
+    );
+    if restarting {
+        tokio::spawn(supervise(
+            state.clone(),
+            profile,
+            spec,
+            client.map(str::to_string),
+            events,
+            supervised.clone(),
+        ));
+    }
+    Ok(supervised)
+}
+
+/// Launch `spec` and apply the profile's limits, killing it when they cannot be applied
+async fn launch(
+    state: &Arc<ProcessState>,
+    profile: &SpawnProfile,
+    spec: &SpawnSpec,
+    client: Option<&str>,
+) -> Result<SupervisedProcess, McpError> {
+    let supervised = state
+        .supervisor
+        .spawn(spec, PROCESS_PROFILE_START, client)
+        .map_err(|e| McpError::InvalidArguments(format!("Failed to start {}: {e}", spec.command_line())))?;
+    let Some(caps) = profile.limits.filter(|caps| !caps.is_empty()) else {
+        return Ok(supervised);
+    };
+    let pid = supervised.pid;
+    let task_state = state.clone();
+    let contained = tokio::task::spawn_blocking(move || {
+        let process = task_state
+            .provider
+            .lookup(pid, false)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "it exited right away"))?;
+        task_state.containments.contain(pid, &process.name, process.start_time, caps)
+    })
+    .await
+    .map_err(|e| McpError::Other(anyhow::anyhow!("Containment task failed: {e}")))?;
+    if let Err(e) = contained {
+        let _ = state.provider.kill(pid);
+        return Err(McpError::Other(anyhow::anyhow!(
+            "Failed to apply the limits of profile {:?} to process {pid}, which was killed: {e}",
+            profile.name
+        )));
+    }
+    Ok(supervised)
+}
+
+/// How a watched process ended
+enum Ended {
+    /// Exited on its own or by a signal from elsewhere
+    Exited { failed: bool },
+    /// Killed through this server
+    Killed,
+    /// No longer observable
+    Lost,
+}
+
+/// Restart the launch whose first process was `current` until the policy says stop
+async fn supervise(
+    state: Arc<ProcessState>,
+    profile: Arc<SpawnProfile>,
+    spec: SpawnSpec,
+    client: Option<String>,
+    mut events: broadcast::Receiver<ProcessEvent>,
+    mut current: SupervisedProcess,
+) {
+    let id = current.id;
+    let mut restarts = 0;
+    loop {
+        let failed = match ended(&state, &mut events, &current).await {
+            Ended::Exited { failed } => failed,
+            Ended::Killed | Ended::Lost => break,
+        };
+        if !profile.restart.restarts(failed) || restarts >= profile.max_restarts {
+            break;
+        }
+        let delay = Duration::from_secs(profile.restart_delay_secs).max(KILL_GRACE);
+        if killed_during(&mut events, current.pid, delay).await {
+            break;
+        }
+        match launch(&state, &profile, &spec, client.as_deref()).await {
+            Ok(next) => {
+                restarts += 1;
+                log::info!(
+                    "Restarted spawn profile {:?} as PID {} (restart {restarts} of {})",
+                    profile.name,
+                    next.pid,
+                    profile.max_restarts
+                );
+                state.profiles.update(id, |launch| {
+                    launch.current = next.id;
+                    launch.pid = next.pid;
+                    launch.restarts = restarts;
+                });
+                current = next;
+            }
+            Err(e) => {
+                log::warn!("Giving up on spawn profile {:?}: {e}", profile.name);
+                break;
+            }
+        }
+    }
+    state.profiles.update(id, |launch| launch.restarting = false);
+}
+
+/// Wait until `current` exits or is killed through the server
+async fn ended(state: &ProcessState, events: &mut broadcast::Receiver<ProcessEvent>, current: &SupervisedProcess) -> Ended {
+    let failed = |process: &SupervisedProcess| !process.exit.as_ref().is_some_and(|exit| exit.success);
+    loop {
+        match events.recv().await {
+            Ok(ProcessEvent::Finished(process)) if process.id == current.id => {
+                return Ended::Exited { failed: failed(&process) };
+            }
+            Ok(ProcessEvent::Killed(killed)) if killed.pid == current.pid => return Ended::Killed,
+            Ok(_) => {}
+            Err(RecvError::Lagged(_)) => match state.supervisor.list(Some(current.id), None).into_iter().next() {
+                Some(process) if process.running => {}
+                Some(process) => return Ended::Exited { failed: failed(&process) },
+                None => return Ended::Lost,
+            },
+            Err(RecvError::Closed) => return Ended::Lost,
+        }
+    }
+}
+
+/// Wait `delay`, returning early with true when a kill of `pid` is announced
+async fn killed_during(events: &mut broadcast::Receiver<ProcessEvent>, pid: u32, delay: Duration) -> bool {
+    let deadline = tokio::time::sleep(delay);
+    tokio::pin!(deadline);
+    loop {
+        tokio::select! {
+            () = &mut deadline => return false,
+            received = events.recv() => match received {
+                Ok(ProcessEvent::Killed(killed)) if killed.pid == pid => return true,
+                Ok(_) | Err(RecvError::Lagged(_)) => {}
+                Err(RecvError::Closed) => return true,
+            },
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Processes that management tools treat as protected
+//!
+//! Killing any of these either crashes the host, takes down the session, or
+//! terminates this server. The OS may still allow it for privileged callers,
+//! which is exactly why it is checked here instead of left to the kernel.
+
+use crate::ProcessId;
+use crate::confirm;
+use crate::provider::ProcessEntry;
+use crate::schema::DO_NOT_KILL_TAG;
+use crate::state::ProcessState;
+
+/// Process names that are critical to the OS on at least one platform
+const PROTECTED_NAMES: &[&str] = &[
+    // Unix
+    "init",
+    "systemd",
+    "launchd",
+    "kernel_task",
+    "kthreadd",
+    // Windows
+    "System",
+    "Registry",
+    "smss.exe",
+    "csrss.exe",
+    "wininit.exe",
+    "winlogon.exe",
+    "services.exe",
+    "lsass.exe",
+];
+
+/// Return why `pid` is protected, if it is
+#[must_use]
+pub(crate) fn protected_reason(pid: ProcessId, name: &str, parent: Option<ProcessId>) -> Option<String> {
+    if pid <= 1 {
+        return Some(format!("PID {pid} is the system scheduler/init process"));
+    }
+    if pid == std::process::id() {
+        return Some(format!("PID {pid} is this process tools server"));
+    }
+    if cfg!(target_os = "linux") && (pid == 2 || parent == Some(2)) {
+        return Some(format!("PID {pid} is a kernel thread"));
+    }
+    PROTECTED_NAMES
+        .iter()
+        .any(|protected| protected.eq_ignore_ascii_case(name))
+        .then(|| format!("{name} is a critical system process"))
+}
+
+/// Why a kill, signal or terminate of one process must be refused, if it must
+///
+/// Shared by every single-target path so they refuse the same processes:
+/// protected processes (even for a privileged server), the `do-not-kill`
+/// tag, then the confirmation policy unless `confirm` is set.
+pub(crate) fn kill_guard(state: &ProcessState, pid: ProcessId, process: &ProcessEntry, confirm: bool) -> Result<(), String> {
+    if let Some(reason) = protected_reason(pid, &process.name, process.parent) {
+        return Err(format!("protected: {reason}"));
+    }
+    if state.tags.has(pid, process.start_time, DO_NOT_KILL_TAG) {
+        return Err(format!("tagged \"{DO_NOT_KILL_TAG}\"; remove the tag with process_tag first"));
+    }
+    if !confirm && let Some(reason) = state.confirm.reason(&process.name, state.spawned_by(pid, process.start_time).is_some()) {
+        return Err(confirm::ask(&reason));
+    }
+    Ok(())
+}
@@ -0,0 +1,107 @@
+//! Deterministic in-memory [`ProcessProvider`] for tests
+//!
+//! Holds a fixed process table and never touches the host. Terminating
+//! signals (kill, term, int, quit) remove the target from the table; every
+//! delivered signal is logged in order so tests can assert on exactly what
+//! the tools sent.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Mutex, MutexGuard};
+
+use super::{ProcessEntry, ProcessProvider, SignalError};
+use crate::ProcessId;
+use crate::schema::ProcessSignal;
+use crate::signals::terminates;
+
+#[derive(Default)]
+struct Table {
+    processes: BTreeMap<ProcessId, ProcessEntry>,
+    denied: BTreeSet<ProcessId>,
+    delivered: Vec<(ProcessId, ProcessSignal)>,
+}
+
+/// Process table for tests: enumerate returns entries sorted by PID
+#[derive(Default)]
+pub struct FakeProvider {
+    table: Mutex<Table>,
+}
+
+impl FakeProvider {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `entry` to the table (builder form of [`insert`](Self::insert))
+    #[must_use]
+    pub fn with_process(self, entry: ProcessEntry) -> Self {
+        self.insert(entry);
+        self
+    }
+
+    /// Add a process with just a PID, name and start time
+    #[must_use]
+    pub fn with_named(self, pid: ProcessId, name: &str, start_time: u64) -> Self {
+        self.with_process(ProcessEntry {
+            pid,
+            name: name.to_string(),
+            start_time,
+            ..ProcessEntry::default()
+        })
+    }
+
+    /// Add or replace a process
+    pub fn insert(&self, entry: ProcessEntry) {
+        self.lock().processes.insert(entry.pid, entry);
+    }
+
+    /// Drop a process as if it exited on its own
+    pub fn remove(&self, pid: ProcessId) -> Option<ProcessEntry> {
+        self.lock().processes.remove(&pid)
+    }
+
+    /// Make every signal to `pid` fail with [`SignalError::Denied`]
+    pub fn deny(&self, pid: ProcessId) {
+        self.lock().denied.insert(pid);
+    }
+
+    #[must_use]
+    pub fn is_alive(&self, pid: ProcessId) -> bool {
+        self.lock().processes.contains_key(&pid)
+    }
+
+    /// Signals delivered so far, oldest first (refused ones are not logged)
+    #[must_use]
+    pub fn delivered(&self) -> Vec<(ProcessId, ProcessSignal)> {
+        self.lock().delivered.clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Table> {
+        self.table.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl ProcessProvider for FakeProvider {
+    fn enumerate(&self) -> Vec<ProcessEntry> {
+        self.lock().processes.values().cloned().collect()
+    }
+
+    fn lookup(&self, pid: ProcessId, _details: bool) -> Option<ProcessEntry> {
+        self.lock().processes.get(&pid).cloned()
+    }
+
+    fn signal(&self, pid: ProcessId, signal: ProcessSignal) -> Result<(), SignalError> {
+        let mut table = self.lock();
+        if !table.processes.contains_key(&pid) {
+            return Err(SignalError::NotFound);
+        }
+        if table.denied.contains(&pid) {
+            return Err(SignalError::Denied);
+        }
+        table.delivered.push((pid, signal));
+        if terminates(signal) {
+            table.processes.remove(&pid);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+//! Process access behind a trait
+//!
+//! Tools that look up or signal processes go through [`ProcessProvider`]
+//! instead of calling sysinfo directly, so the kill paths can be exercised
+//! against an in-memory table. The server always uses [`SysinfoProvider`];
+//! the `test-util` feature adds [`FakeProvider`] for downstream tests.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::ProcessId;
+use crate::schema::ProcessSignal;
+
+mod system;
+pub use system::SysinfoProvider;
+
+#[cfg(feature = "test-util")]
+mod fake;
+#[cfg(feature = "test-util")]
+pub use fake::FakeProvider;
+
+/// One process as seen by a [`ProcessProvider`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProcessEntry {
+    pub pid: ProcessId,
+    pub parent: Option<ProcessId>,
+    pub name: String,
+    /// Start time in seconds since the Unix epoch (pairs with `pid` to detect reuse)
+    pub start_time: u64,
+    pub cpu_percent: f32,
+    pub rss_bytes: u64,
+    pub virtual_bytes: u64,
+    /// The fields below are only filled by `lookup` with `details`
+    pub cmdline: Vec<String>,
+    pub exe: Option<PathBuf>,
+    pub cwd: Option<PathBuf>,
+    pub env: Vec<String>,
+    /// Owner as `name (uid)` or a bare id
+    pub user: Option<String>,
+}
+
+/// Why a signal was not delivered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignalError {
+    /// No process with that PID exists
+    NotFound,
+    /// The OS refused (permissions, protection, integrity level)
+    Denied,
+    /// The signal does not exist on this platform
+    Unsupported,
+}
+
+impl fmt::Display for SignalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NotFound => "process not found",
+            Self::Denied => "permission denied",
+            Self::Unsupported => "signal not supported on this platform",
+        })
+    }
+}
+
+impl std::error::Error for SignalError {}
+
+pub trait ProcessProvider: Send + Sync {
+    /// Every visible process, without the `details` fields
+    fn enumerate(&self) -> Vec<ProcessEntry>;
+
+    /// A single process; `details` also loads cmdline, exe, cwd, env and user
+    fn lookup(&self, pid: ProcessId, details: bool) -> Option<ProcessEntry>;
+
+    /// The processes of `pids` that exist, in order; one refresh where the backend allows it
+    fn lookup_many(&self, pids: &[ProcessId], details: bool) -> Vec<ProcessEntry> {
+        pids.iter().filter_map(|&pid| self.lookup(pid, details)).collect()
+    }
+
+    /// Deliver `signal` to `pid`
+    fn signal(&self, pid: ProcessId, signal: ProcessSignal) -> Result<(), SignalError>;
+
+    /// Forcefully terminate `pid`
+    fn kill(&self, pid: ProcessId) -> Result<(), SignalError> {
+        self.signal(pid, ProcessSignal::Kill)
+    }
+}
@@ -0,0 +1,89 @@
+//! [`ProcessProvider`] backed by sysinfo
+
+use sysinfo::{Pid, Process, ProcessRefreshKind, ProcessesToUpdate, System, Users};
+
+use super::{ProcessEntry, ProcessProvider, SignalError};
+use crate::ProcessId;
+use crate::platform;
+use crate::schema::ProcessSignal;
+use crate::signals::to_sysinfo;
+use crate::users::user_label;
+
+/// Live processes of this host
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SysinfoProvider;
+
+impl ProcessProvider for SysinfoProvider {
+    fn enumerate(&self) -> Vec<ProcessEntry> {
+        let mut system = System::new();
+        system.refresh_processes(ProcessesToUpdate::All, true);
+        system.processes().values().map(entry).collect()
+    }
+
+    fn lookup(&self, pid: ProcessId, details: bool) -> Option<ProcessEntry> {
+        self.lookup_many(&[pid], details).pop()
+    }
+
+    fn lookup_many(&self, pids: &[ProcessId], details: bool) -> Vec<ProcessEntry> {
+        let kind = if details {
+            // cmdline, cwd and env are not part of the default refresh
+            ProcessRefreshKind::everything()
+        } else {
+            ProcessRefreshKind::nothing().with_memory()
+        };
+        let wanted: Vec<Pid> = pids.iter().map(|&pid| Pid::from_u32(pid)).collect();
+        let mut system = System::new();
+        system.refresh_processes_specifics(ProcessesToUpdate::Some(&wanted), true, kind);
+        let users = details.then(Users::new_with_refreshed_list);
+
+        wanted
+            .iter()
+            .filter_map(|pid| system.process(*pid))
+            .map(|process| {
+                let mut entry = entry(process);
+                if let Some(users) = &users {
+                    let pid = entry.pid;
+                    let lossy = |arg: &std::ffi::OsString| arg.to_string_lossy().to_string();
+                    entry.cmdline = process.cmd().iter().map(lossy).collect();
+                    // Empty when access is denied; the platform read may have a fallback (WMI on Windows)
+                    if entry.cmdline.is_empty() {
+                        entry.cmdline = platform::cmdline(pid).unwrap_or_default();
+                    }
+                    entry.env = process.environ().iter().map(lossy).collect();
+                    entry.exe = process.exe().map(std::path::Path::to_path_buf).or_else(|| platform::exe_path(pid));
+                    entry.cwd = process.cwd().map(std::path::Path::to_path_buf);
+                    entry.user = process.user_id().map(|uid| user_label(users, uid));
+                }
+                entry
+            })
+            .collect()
+    }
+
+    fn signal(&self, pid: ProcessId, signal: ProcessSignal) -> Result<(), SignalError> {
+        let mut system = System::new();
+        system.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[Pid::from_u32(pid)]),
+            true,
+            ProcessRefreshKind::nothing(),
+        );
+        let process = system.process(Pid::from_u32(pid)).ok_or(SignalError::NotFound)?;
+        match process.kill_with(to_sysinfo(signal)) {
+            Some(true) => Ok(()),
+            Some(false) => Err(SignalError::Denied),
+            None => Err(SignalError::Unsupported),
+        }
+    }
+}
+
+fn entry(process: &Process) -> ProcessEntry {
+    ProcessEntry {
+        pid: process.pid().as_u32(),
+        parent: process.parent().map(Pid::as_u32),
+        name: process.name().to_string_lossy().to_string(),
+        start_time: process.start_time(),
+        cpu_percent: process.cpu_usage(),
+        rss_bytes: process.memory(),
+        virtual_bytes: process.virtual_memory(),
+        ..ProcessEntry::default()
+    }
+}
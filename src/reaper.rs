@@ -0,0 +1,139 @@
+//! Reaping of orphans adopted by the server
+//!
+//! In subreaper mode (or when the server runs as PID 1) orphaned descendants
+//! reparent to the server, and nobody waits for them: Tokio only waits for
+//! the children it forked. The reaper collects their exit status whenever
+//! SIGCHLD arrives, plus a periodic sweep, so they never linger as zombies.
+//! Children in the direct set of [`crate::spawn`] are left to Tokio.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+#[cfg(unix)]
+use std::time::Duration;
+
+use crate::platform;
+use crate::schema::{ReapedOrphan, ReaperStats};
+use crate::spawn::with_direct;
+use crate::state::ProcessState;
+
+/// Reaps kept in [`ReaperStats::recent`]
+const RECENT: usize = 16;
+
+/// Sweep for zombies whose SIGCHLD was merged with one Tokio consumed
+#[cfg(unix)]
+const SWEEP_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Default)]
+struct Tally {
+    /// Counters; `recent` stays empty here
+    stats: ReaperStats,
+    recent: VecDeque<ReapedOrphan>,
+}
+
+#[derive(Default)]
+pub struct Reaper {
+    tally: Mutex<Tally>,
+}
+
+impl Reaper {
+    /// Counters so far with the most recent reaps
+    #[must_use]
+    pub fn stats(&self) -> ReaperStats {
+        let tally = self.lock();
+        ReaperStats {
+            recent: tally.recent.iter().cloned().collect(),
+            ..tally.stats.clone()
+        }
+    }
+
+    /// Collect every zombie child the server did not fork itself
+    ///
+    /// Returns how many were reaped.
+    pub fn reap(&self) -> usize {
+        let (reaped, pending) = with_direct(|direct| {
+            let mut reaped = Vec::new();
+            let mut pending = 0;
+            for (pid, name) in platform::zombie_children() {
+                if direct.contains(&pid) {
+                    pending += 1;
+                    continue;
+                }
+                match platform::reap_child(pid) {
+                    Ok(Some(status)) => reaped.push((pid, name, status)),
+                    Ok(None) => pending += 1,
+                    Err(e) => log::debug!("Failed to reap orphan {pid}: {e}"),
+                }
+            }
+            (reaped, pending)
+        });
+
+        let count = reaped.len();
+        let mut tally = self.lock();
+        tally.stats.pending = pending;
+        for (pid, name, status) in reaped {
+            #[cfg(unix)]
+            let signal = std::os::unix::process::ExitStatusExt::signal(&status);
+            #[cfg(not(unix))]
+            let signal: Option<i32> = None;
+
+            tally.stats.reaped += 1;
+            if signal.is_some() {
+                tally.stats.signalled += 1;
+            }
+            if tally.recent.len() == RECENT {
+                tally.recent.pop_back();
+            }
+            tally.recent.push_front(ReapedOrphan {
+                pid,
+                name,
+                code: status.code(),
+                #[cfg(unix)]
+                signal_name: signal.and_then(crate::signals::signal_name).map(str::to_string),
+                #[cfg(not(unix))]
+                signal_name: None,
+                reaped_at: chrono::Utc::now().to_rfc3339(),
+            });
+        }
+        count
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Tally> {
+        self.tally.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Start reaping on SIGCHLD when orphans can reach the server
+///
+/// Must be called from within the Tokio runtime.
+#[cfg(unix)]
+pub(crate) fn start(state: Arc<ProcessState>) {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    if !(platform::is_child_subreaper() || std::process::id() == 1) {
+        return;
+    }
+    let mut children = match signal(SignalKind::child()) {
+        Ok(children) => children,
+        Err(e) => {
+            log::warn!("Not reaping adopted orphans: cannot listen for SIGCHLD: {e}");
+            return;
+        }
+    };
+    state.reaper.lock().stats.active = true;
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = children.recv() => {}
+                () = tokio::time::sleep(SWEEP_INTERVAL) => {}
+            }
+            let state = state.clone();
+            if tokio::task::spawn_blocking(move || state.reaper.reap()).await.is_err() {
+                break;
+            }
+        }
+    });
+}
+
+/// Orphans go to the system rather than the server here
+#[cfg(not(unix))]
+pub(crate) fn start(_state: Arc<ProcessState>) {}
@@ -0,0 +1,151 @@
+//! Masking of secrets in process data returned to clients
+//!
+//! Environments and command lines of other processes routinely carry API
+//! tokens and passwords. Everything the tools expose goes through a [`Redactor`] built
+//! from [`RedactionConfig`] so the server is not a credential channel for the
+//! model; the raw data stays in memory for operations such as respawn.
+
+use kodegen_mcp_schema::McpError;
+use regex::{Regex, RegexBuilder};
+
+use crate::config::RedactionConfig;
+
+/// Replacement for masked values
+pub const REDACTED: &str = "[REDACTED]";
+
+pub struct Redactor {
+    env_keys: Vec<Regex>,
+    cmdline_patterns: Vec<Regex>,
+    allow_reveal: bool,
+}
+
+impl Default for Redactor {
+    fn default() -> Self {
+        Self::new(&RedactionConfig::default())
+    }
+}
+
+impl Redactor {
+    /// Compile the policy; invalid patterns are logged and skipped
+    #[must_use]
+    pub fn new(config: &RedactionConfig) -> Self {
+        Self {
+            env_keys: compile(&config.env_keys),
+            cmdline_patterns: compile(&config.cmdline_patterns),
+            allow_reveal: config.allow_reveal,
+        }
+    }
+
+    /// Apply the per-call `reveal_secrets` flag against the policy
+    ///
+    /// Returns whether redaction is active for this call.
+    pub fn active(&self, reveal_secrets: bool) -> Result<bool, McpError> {
+        if reveal_secrets && !self.allow_reveal {
+            return Err(McpError::PermissionDenied(
+                "reveal_secrets is disabled by the server's redaction policy (set redaction.allow_reveal in process.json)"
+                    .to_string(),
+            ));
+        }
+        Ok(!reveal_secrets)
+    }
+
+    /// Whether the value of environment variable `key` is masked
+    #[must_use]
+    pub fn is_secret_env_key(&self, key: &str) -> bool {
+        self.env_keys.iter().any(|pattern| pattern.is_match(key))
+    }
+
+    /// Mask secret values in `KEY=VALUE` entries, returning how many were masked
+    pub fn env(&self, entries: &mut [String]) -> usize {
+        let mut masked = 0;
+        for entry in entries {
+            if let Some((key, value)) = entry.split_once('=')
+                && !value.is_empty()
+                && self.is_secret_env_key(key)
+            {
+                *entry = format!("{key}={REDACTED}");
+                masked += 1;
+            }
+        }
+        masked
+    }
+
+    /// Mask secrets in argv entries, returning how many arguments changed
+    ///
+    /// Flags named like a secret env key lose their value, whether attached
+    /// (`--api-token=x`) or in the next argument (`--password x`); so do
+    /// `KEY=VALUE` assignments such as `env API_TOKEN=x cmd`. Remaining
+    /// arguments are scanned for `cmdline_patterns`.
+    pub fn cmdline(&self, args: &mut [String]) -> usize {
+        let mut masked = 0;
+        let mut mask_next = false;
+        for arg in args.iter_mut() {
+            if std::mem::take(&mut mask_next) && !arg.starts_with('-') {
+                *arg = REDACTED.to_string();
+                masked += 1;
+                continue;
+            }
+
+            let flag = arg.strip_prefix("--").or_else(|| arg.strip_prefix('-'));
+            let assignment = flag.unwrap_or(arg).split_once('=');
+            match (flag, assignment) {
+                (_, Some((name, value))) if !value.is_empty() && (flag.is_some() || is_identifier(name)) => {
+                    if self.is_secret_env_key(name) {
+                        *arg = format!("{}={REDACTED}", &arg[..arg.len() - value.len() - 1]);
+                        masked += 1;
+                        continue;
+                    }
+                }
+                (Some(name), None) if self.is_secret_env_key(name) => {
+                    mask_next = true;
+                    continue;
+                }
+                _ => {}
+            }
+
+            let mut changed = false;
+            for pattern in &self.cmdline_patterns {
+                if !pattern.is_match(arg) {
+                    continue;
+                }
+                *arg = pattern
+                    .replace_all(arg, |caps: &regex::Captures| {
+                        let whole = &caps[0];
+                        let offset = caps.get(0).map_or(0, |whole| whole.start());
+                        match caps.name("secret") {
+                            Some(secret) => format!(
+                                "{}{REDACTED}{}",
+                                &whole[..secret.start() - offset],
+                                &whole[secret.end() - offset..]
+                            ),
+                            None => REDACTED.to_string(),
+                        }
+                    })
+                    .into_owned();
+                changed = true;
+            }
+            masked += usize::from(changed);
+        }
+        masked
+    }
+}
+
+/// Shell variable name, so `a=b` positional arguments are not mistaken for assignments
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn compile(patterns: &[String]) -> Vec<Regex> {
+    patterns
+        .iter()
+        .filter_map(|pattern| match RegexBuilder::new(pattern).case_insensitive(true).build() {
+            Ok(regex) => Some(regex),
+            Err(e) => {
+                log::warn!("Ignoring invalid redaction pattern {pattern:?}: {e}");
+                None
+            }
+        })
+        .collect()
+}
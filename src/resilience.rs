@@ -0,0 +1,80 @@
+//! Retries and partial results for process reads
+//!
+//! Enumerating processes races with processes exiting: an entry seen in the
+//! directory listing or PID array is gone by the time it is read (`ENOENT`,
+//! `ESRCH`), and some entries deny access (`EPERM`, `EACCES`). Neither should
+//! fail a whole listing. Reads that may succeed on a second try (`EINTR`,
+//! `EAGAIN`) are retried a few times; entries that still fail are skipped and
+//! tallied in [`Skipped`], which the listing reports as `warnings`.
+
+use std::io::{self, ErrorKind};
+use std::time::Duration;
+
+/// Tries per read before giving up
+pub(crate) const ATTEMPTS: usize = 3;
+
+/// Pause between tries
+const BACKOFF: Duration = Duration::from_millis(2);
+
+/// Whether a failed read may succeed if repeated
+fn transient(error: &io::Error) -> bool {
+    matches!(error.kind(), ErrorKind::Interrupted | ErrorKind::WouldBlock | ErrorKind::TimedOut)
+}
+
+/// `read`, repeated while it fails transiently
+pub(crate) fn retry<T>(mut read: impl FnMut() -> io::Result<T>) -> io::Result<T> {
+    let mut attempt = 1;
+    loop {
+        match read() {
+            Err(error) if attempt < ATTEMPTS && transient(&error) => {
+                attempt += 1;
+                std::thread::sleep(BACKOFF);
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Entries of one enumeration that were skipped, by cause
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct Skipped {
+    /// Exited between being listed and being read
+    pub vanished: usize,
+    /// Access denied
+    pub denied: usize,
+    /// Any other failure
+    pub failed: usize,
+}
+
+impl Skipped {
+    /// Count one skipped entry under the cause `error` points to
+    pub(crate) fn record(&mut self, error: &io::Error) {
+        match error.kind() {
+            ErrorKind::NotFound => self.vanished += 1,
+            ErrorKind::PermissionDenied => self.denied += 1,
+            _ if ESRCH.is_some() && error.raw_os_error() == ESRCH => self.vanished += 1,
+            _ => self.failed += 1,
+        }
+    }
+
+    /// One warning per cause that occurred
+    pub(crate) fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if self.vanished > 0 {
+            warnings.push(format!("{} process(es) exited during the scan and were skipped", self.vanished));
+        }
+        if self.denied > 0 {
+            warnings.push(format!("{} process(es) could not be read (access denied) and were skipped", self.denied));
+        }
+        if self.failed > 0 {
+            warnings.push(format!("{} process(es) could not be read and were skipped", self.failed));
+        }
+        warnings
+    }
+}
+
+/// `ESRCH`, which std maps to no specific error kind
+#[cfg(unix)]
+const ESRCH: Option<i32> = Some(libc::ESRCH);
+#[cfg(not(unix))]
+const ESRCH: Option<i32> = None;
@@ -0,0 +1,465 @@
+//! Plain REST routes over the process tools
+//!
+//! For curl, dashboards and scripts that do not speak MCP. Handlers run the
+//! same code as `process_list`, `process_info` and `process_kill`, so the
+//! kill budget, protected processes, do-not-kill tags and redaction apply unchanged;
+//! `?confirm=true` stands in for the user's confirmation, so only callers
+//! holding a `rest.tokens` entry reach it once tokens are set (see
+//! [`crate::auth`]). `GET /events` upgrades to a WebSocket that pushes [`ProcessEvent`]s as
+//! JSON text frames. Sibling kodegen servers register the processes they
+//! spawn with `POST /children` (`{"pid", "note"}`), list them with
+//! `GET /children` and drop them with `DELETE /children/{pid}`; registering
+//! exempts a process from `confirmation.unowned`, so both mutating routes
+//! need a token and the token's name is the owner. The routes are served on their own listener
+//! (`rest.bind`) because the MCP server's router has no extension point.
+
+use std::future::Future;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{ConnectInfo, Path, Query, Request, State};
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{delete, get};
+use axum::{Extension, Json, Router};
+use globset::{GlobBuilder, GlobMatcher};
+use kodegen_mcp_schema::McpError;
+use kodegen_server_http::{Managers, ShutdownHook};
+use serde::Deserialize;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{Notify, broadcast, watch};
+
+use crate::auth::{self, Caller};
+use crate::config::ProcessConfig;
+use crate::events::ProcessEvent;
+use crate::schema::{ProcessColumn, ProcessInfoArgs, ProcessKillArgs, ProcessKind, ProcessListArgs};
+use crate::state::ProcessState;
+use crate::{ProcessInfoTool, ProcessKillTool, ProcessListTool};
+
+#[derive(Clone)]
+struct Api {
+    list: ProcessListTool,
+    info: ProcessInfoTool,
+    kill: ProcessKillTool,
+    state: Arc<ProcessState>,
+    config: Arc<ProcessConfig>,
+    /// Flips to true when the server shuts down, closing open WebSockets
+    closing: watch::Receiver<bool>,
+}
+
+/// Query string of the listing routes; lists are comma-separated
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ListQuery {
+    filter: Option<String>,
+    limit: usize,
+    pids: Option<String>,
+    tag: Option<String>,
+    group: Option<String>,
+    columns: Option<String>,
+    named_filters: Option<String>,
+    kind: Option<ProcessKind>,
+    pod: Option<String>,
+    wsl: bool,
+    compact: bool,
+    delta: bool,
+    since: Option<String>,
+    reveal_secrets: bool,
+}
+
+impl ListQuery {
+    fn into_args(self) -> Result<ProcessListArgs, McpError> {
+        let columns = split(self.columns.as_deref())
+            .map(|name| name.parse())
+            .collect::<Result<Vec<ProcessColumn>, _>>()
+            .map_err(McpError::InvalidArguments)?;
+        let pids = split(self.pids.as_deref())
+            .map(|pid| pid.parse().map_err(|_| McpError::InvalidArguments(format!("Invalid PID {pid:?}"))))
+            .collect::<Result<_, _>>()?;
+        Ok(ProcessListArgs {
+            filter: self.filter,
+            limit: self.limit,
+            pids,
+            tag: self.tag,
+            group: self.group,
+            columns,
+            named_filters: split(self.named_filters.as_deref()).collect(),
+            kind: self.kind,
+            pod: self.pod,
+            wsl: self.wsl,
+            compact: self.compact,
+            delta: self.delta,
+            since: self.since,
+            reveal_secrets: self.reveal_secrets,
+            ..ProcessListArgs::default()
+        })
+    }
+}
+
+/// Query string of `GET /events`; every given filter must match
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct EventQuery {
+    /// Event kinds, e.g. `exited,killed`
+    types: Option<String>,
+    pids: Option<String>,
+    /// Name glob, case-insensitive
+    pattern: Option<String>,
+    reveal_secrets: bool,
+}
+
+/// Server-side filter of one WebSocket subscription
+struct EventFilter {
+    kinds: Vec<String>,
+    pids: Vec<u32>,
+    pattern: Option<GlobMatcher>,
+}
+
+impl EventQuery {
+    fn filter(&self) -> Result<EventFilter, McpError> {
+        let kinds: Vec<String> = split(self.types.as_deref()).collect();
+        if let Some(unknown) = kinds.iter().find(|kind| !ProcessEvent::KINDS.contains(&kind.as_str())) {
+            return Err(McpError::InvalidArguments(format!(
+                "Unknown event type {unknown:?} (expected one of {})",
+                ProcessEvent::KINDS.join(", ")
+            )));
+        }
+        let pids = split(self.pids.as_deref())
+            .map(|pid| pid.parse().map_err(|_| McpError::InvalidArguments(format!("Invalid PID {pid:?}"))))
+            .collect::<Result<_, _>>()?;
+        let pattern = self
+            .pattern
+            .as_deref()
+            .map(|pattern| {
+                GlobBuilder::new(pattern)
+                    .case_insensitive(true)
+                    .build()
+                    .map(|glob| glob.compile_matcher())
+                    .map_err(|e| McpError::InvalidArguments(format!("Invalid pattern {pattern:?}: {e}")))
+            })
+            .transpose()?;
+        Ok(EventFilter { kinds, pids, pattern })
+    }
+}
+
+impl EventFilter {
+    fn matches(&self, event: &ProcessEvent) -> bool {
+        (self.kinds.is_empty() || self.kinds.iter().any(|kind| kind == event.kind()))
+            && (self.pids.is_empty() || self.pids.contains(&event.pid()))
+            && self.pattern.as_ref().is_none_or(|pattern| pattern.is_match(event.name()))
+    }
+}
+
+fn split(list: Option<&str>) -> impl Iterator<Item = String> + '_ {
+    list.into_iter()
+        .flat_map(|list| list.split(','))
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+}
+
+/// Tool errors as HTTP status plus `{"error": "..."}`
+struct ApiError(McpError);
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match &self.0 {
+            McpError::InvalidArguments(_) => StatusCode::BAD_REQUEST,
+            McpError::PermissionDenied(_) | McpError::Authentication(_) => StatusCode::FORBIDDEN,
+            McpError::ResourceNotFound(_) => StatusCode::NOT_FOUND,
+            McpError::RateLimitExceeded => StatusCode::TOO_MANY_REQUESTS,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        (status, Json(serde_json::json!({ "error": self.0.to_string() }))).into_response()
+    }
+}
+
+impl From<McpError> for ApiError {
+    fn from(error: McpError) -> Self {
+        Self(error)
+    }
+}
+
+/// Reject requests without a valid token, or without tokens those for another
+/// host ([`auth::local_host`]), and attach the [`Caller`] for the handlers
+async fn authorize(State(api): State<Api>, mut request: Request, next: Next) -> Response {
+    let authorization = request.headers().get(header::AUTHORIZATION).and_then(|value| value.to_str().ok());
+    match auth::authenticate(&api.config.rest.tokens, authorization) {
+        Ok(Caller::Local)
+            if !request_host(&request)
+                .is_some_and(|host| auth::local_host(host, api.config.rest.bind.as_deref().unwrap_or_default())) =>
+        {
+            (
+                StatusCode::MISDIRECTED_REQUEST,
+                Json(serde_json::json!({
+                    "error": "without rest.tokens only requests for localhost or rest.bind are served"
+                })),
+            )
+                .into_response()
+        }
+        Ok(caller) => {
+            request.extensions_mut().insert(caller);
+            next.run(request).await
+        }
+        Err(reason) => (StatusCode::UNAUTHORIZED, Json(serde_json::json!({ "error": reason }))).into_response(),
+    }
+}
+
+/// Authority the request was sent to: the `Host` header, or the URI's under HTTP/2
+fn request_host(request: &Request) -> Option<&str> {
+    request
+        .headers()
+        .get(header::HOST)
+        .and_then(|value| value.to_str().ok())
+        .or_else(|| request.uri().authority().map(|authority| authority.as_str()))
+}
+
+async fn list(State(api): State<Api>, Query(query): Query<ListQuery>) -> Result<Response, ApiError> {
+    let output = api.list.list(query.into_args()?).await?.metadata;
+    Ok(Json(output).into_response())
+}
+
+/// Query string of `GET /processes/{pid}`
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct InfoQuery {
+    reveal_secrets: bool,
+}
+
+async fn info(State(api): State<Api>, Path(pid): Path<u32>, Query(query): Query<InfoQuery>) -> Result<Response, ApiError> {
+    let args = ProcessInfoArgs {
+        pid: Some(pid),
+        proc_id: None,
+        pids: Vec::new(),
+        reveal_secrets: query.reveal_secrets,
+        style: None,
+        verbosity: None,
+    };
+    let output = api.info.info(args).await?.metadata;
+    Ok(Json(output).into_response())
+}
+
+/// Body of `POST /children`; the owner is the caller's token name
+#[derive(Debug, Deserialize)]
+struct RegisterBody {
+    pid: u32,
+    /// Accepted for older clients, but must then name the caller
+    #[serde(default)]
+    owner: Option<String>,
+    #[serde(default)]
+    note: Option<String>,
+}
+
+/// Token name of a caller allowed to change the child registry
+fn registrant(caller: &Caller) -> Result<&str, McpError> {
+    match caller {
+        Caller::Token(name) => Ok(name),
+        Caller::Local => Err(McpError::PermissionDenied(
+            "The child registry changes kill confirmation, so it needs an authenticated caller: \
+             set rest.tokens and register with the token whose name should own the process"
+                .to_string(),
+        )),
+    }
+}
+
+async fn children(State(api): State<Api>) -> Response {
+    Json(api.state.children.snapshot()).into_response()
+}
+
+async fn register(
+    State(api): State<Api>,
+    Extension(caller): Extension<Caller>,
+    Json(body): Json<RegisterBody>,
+) -> Result<Response, ApiError> {
+    let owner = registrant(&caller)?;
+    if let Some(claimed) = body.owner.as_deref().map(str::trim)
+        && claimed != owner
+    {
+        return Err(McpError::InvalidArguments(format!(
+            "owner {claimed:?} does not match the caller's token name {owner:?}; omit owner"
+        ))
+        .into());
+    }
+    let state = api.state.clone();
+    let pid = body.pid;
+    let process = tokio::task::spawn_blocking(move || state.provider.lookup(pid, false))
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to look up process {pid}: {e}")))?
+        .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} not found")))?;
+    match api.state.children.register(pid, process.start_time, owner, body.note) {
+        Ok(child) => Ok((StatusCode::CREATED, Json(child)).into_response()),
+        Err(child) => Ok((
+            StatusCode::CONFLICT,
+            Json(serde_json::json!({
+                "error": format!("Process {pid} is registered by {:?}, not {owner:?}", child.owner)
+            })),
+        )
+            .into_response()),
+    }
+}
+
+async fn unregister(
+    State(api): State<Api>,
+    Extension(caller): Extension<Caller>,
+    Path(pid): Path<u32>,
+) -> Result<Response, ApiError> {
+    let owner = registrant(&caller)?;
+    let child = api
+        .state
+        .children
+        .unregister_owned(pid, owner)
+        .ok_or_else(|| McpError::ResourceNotFound(format!("Process {pid} is not registered")))?
+        .map_err(|child| {
+            McpError::PermissionDenied(format!("Process {pid} is registered by {:?}, not {owner:?}", child.owner))
+        })?;
+    Ok(Json(child).into_response())
+}
+
+/// Query string of `DELETE /processes/{pid}`
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct KillQuery {
+    confirm: bool,
+    start_time: Option<u64>,
+}
+
+async fn kill(
+    State(api): State<Api>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    Extension(caller): Extension<Caller>,
+    Path(pid): Path<u32>,
+    Query(query): Query<KillQuery>,
+) -> Result<Response, ApiError> {
+    let args = ProcessKillArgs {
+        pid: Some(pid),
+        confirm: query.confirm,
+        start_time: query.start_time,
+        ..ProcessKillArgs::default()
+    };
+    // Budgets are per token name (or caller address), like per-connection budgets over MCP
+    let client = caller.client("rest", Some(peer.ip()));
+    let output = api.kill.kill(args, client.as_deref(), None).await?.metadata;
+    Ok(Json(output).into_response())
+}
+
+async fn events(
+    State(api): State<Api>,
+    Query(query): Query<EventQuery>,
+    headers: HeaderMap,
+    upgrade: WebSocketUpgrade,
+) -> Result<Response, ApiError> {
+    if let Some(origin) = headers.get(header::ORIGIN)
+        && !origin
+            .to_str()
+            .is_ok_and(|origin| auth::trusted_origin(origin, &api.config.rest.allowed_origins))
+    {
+        return Err(McpError::PermissionDenied(format!(
+            "origin {origin:?} may not open the event stream; add it to rest.allowed_origins"
+        ))
+        .into());
+    }
+    let filter = query.filter()?;
+    let redact = api.state.redactor.active(query.reveal_secrets)?;
+    // Subscribe before the upgrade so nothing published during the handshake is missed
+    let events = api.state.events.subscribe();
+    Ok(upgrade.on_upgrade(move |socket| push(socket, events, filter, redact, api)))
+}
+
+/// Forward matching events until the client leaves or the server shuts down
+///
+/// A subscriber that falls behind gets `{"type": "lagged", "missed": n}` in
+/// place of the events it missed.
+async fn push(
+    mut socket: WebSocket,
+    mut events: broadcast::Receiver<ProcessEvent>,
+    filter: EventFilter,
+    redact: bool,
+    mut api: Api,
+) {
+    loop {
+        let received = tokio::select! {
+            _ = api.closing.wait_for(|closing| *closing) => break,
+            message = socket.recv() => match message {
+                Some(Ok(Message::Close(_)) | Err(_)) | None => break,
+                // Pings are answered by axum; anything else the client sends is ignored
+                Some(Ok(_)) => continue,
+            },
+            received = events.recv() => received,
+        };
+        let frame = match received {
+            Ok(mut event) if filter.matches(&event) => {
+                if redact && let ProcessEvent::Killed(process) = &mut event {
+                    api.state.redactor.env(&mut process.env);
+                    api.state.redactor.cmdline(&mut process.cmdline);
+                }
+                serde_json::to_string(&event).unwrap_or_default()
+            }
+            Ok(_) => continue,
+            Err(RecvError::Lagged(missed)) => serde_json::json!({ "type": "lagged", "missed": missed }).to_string(),
+            Err(RecvError::Closed) => break,
+        };
+        if socket.send(Message::Text(frame.into())).await.is_err() {
+            return;
+        }
+    }
+    let _ = socket.send(Message::Close(None)).await;
+}
+
+/// Stops a side listener (REST, gRPC) when the server shuts down
+pub(crate) struct StopOnShutdown(pub Arc<Notify>);
+
+impl ShutdownHook for StopOnShutdown {
+    fn shutdown(&self) -> Pin<Box<dyn Future<Output = anyhow::Result<()>> + Send + '_>> {
+        self.0.notify_one();
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Bind `bind` and serve the REST routes until the server shuts down
+pub(crate) async fn start(
+    bind: &str,
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+    managers: &Managers,
+) -> anyhow::Result<()> {
+    let listener = tokio::net::TcpListener::bind(bind)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to bind REST listener {bind}: {e}"))?;
+    auth::check_bind("rest", listener.local_addr()?, &config.rest.tokens)?;
+    let (close, closing) = watch::channel(false);
+    let api = Api {
+        list: ProcessListTool::new(config.clone(), state.clone()),
+        info: ProcessInfoTool::new(config.clone(), state.clone()),
+        kill: ProcessKillTool::new(config.clone(), state.clone()),
+        state,
+        config,
+        closing,
+    };
+    let router = Router::new()
+        .route("/processes", get(list))
+        .route("/processes/{pid}", get(info).delete(kill))
+        .route("/children", get(children).post(register))
+        .route("/children/{pid}", delete(unregister))
+        .route("/events", get(events))
+        .layer(middleware::from_fn_with_state(api.clone(), authorize))
+        .with_state(api);
+
+    let stop = Arc::new(Notify::new());
+    managers.register(StopOnShutdown(stop.clone())).await;
+    log::info!("REST routes listening on {bind}");
+    tokio::spawn(async move {
+        let service = router.into_make_service_with_connect_info::<SocketAddr>();
+        let shutdown = async move {
+            stop.notified().await;
+            // Graceful shutdown waits for open connections, WebSockets included
+            let _ = close.send(true);
+        };
+        if let Err(e) = axum::serve(listener, service).with_graceful_shutdown(shutdown).await {
+            log::warn!("REST listener stopped: {e}");
+        }
+    });
+    Ok(())
+}
@@ -0,0 +1,106 @@
+//! Runtime-adjustable sampling cadence
+//!
+//! Starts from [`SamplingConfig`] and can be changed while the server runs
+//! through `process_sampling`. Small hosts want fresh data on every call;
+//! build servers with thousands of processes want fewer full scans. Shared by
+//! the watch monitor (its sampling period) and `process_list` (how long one
+//! process table may be reused).
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::config::SamplingConfig;
+use crate::schema::ProcessInfo;
+
+/// Fastest allowed watch sampling period
+pub const MIN_WATCH_INTERVAL_MS: u64 = 100;
+
+/// Slowest allowed watch sampling period
+pub const MAX_WATCH_INTERVAL_MS: u64 = 60_000;
+
+/// Longest allowed reuse of one process table
+pub const MAX_LIST_AGE_MS: u64 = 60_000;
+
+/// Rows of one full listing with their start times, before any filtering
+pub(crate) type TableRows = Arc<Vec<(u64, ProcessInfo)>>;
+
+pub struct Sampling {
+    watch_interval_ms: AtomicU64,
+    list_max_age_ms: AtomicU64,
+    table: Mutex<Option<(Instant, TableRows)>>,
+}
+
+impl Default for Sampling {
+    fn default() -> Self {
+        Self::new(&SamplingConfig::default())
+    }
+}
+
+impl Sampling {
+    /// Cadence from config, clamped to the supported ranges
+    #[must_use]
+    pub fn new(config: &SamplingConfig) -> Self {
+        let sampling = Self {
+            watch_interval_ms: AtomicU64::new(0),
+            list_max_age_ms: AtomicU64::new(0),
+            table: Mutex::new(None),
+        };
+        sampling.set_watch_interval_ms(config.watch_interval_ms);
+        sampling.set_list_max_age_ms(config.list_max_age_ms);
+        sampling
+    }
+
+    /// Sampling period of the watch monitor
+    #[must_use]
+    pub fn watch_interval(&self) -> Duration {
+        Duration::from_millis(self.watch_interval_ms())
+    }
+
+    #[must_use]
+    pub fn watch_interval_ms(&self) -> u64 {
+        self.watch_interval_ms.load(Ordering::Relaxed)
+    }
+
+    #[must_use]
+    pub fn list_max_age_ms(&self) -> u64 {
+        self.list_max_age_ms.load(Ordering::Relaxed)
+    }
+
+    /// Set the watch period, returning the clamped value in effect
+    pub fn set_watch_interval_ms(&self, millis: u64) -> u64 {
+        let millis = millis.clamp(MIN_WATCH_INTERVAL_MS, MAX_WATCH_INTERVAL_MS);
+        self.watch_interval_ms.store(millis, Ordering::Relaxed);
+        millis
+    }
+
+    /// Set how long `process_list` reuses a table (0 = always fresh), returning the value in effect
+    pub fn set_list_max_age_ms(&self, millis: u64) -> u64 {
+        let millis = millis.min(MAX_LIST_AGE_MS);
+        self.list_max_age_ms.store(millis, Ordering::Relaxed);
+        if millis == 0 {
+            *self.lock_table() = None;
+        }
+        millis
+    }
+
+    /// The last stored table if it is younger than the configured max age
+    pub(crate) fn cached_table(&self) -> Option<TableRows> {
+        let max_age = Duration::from_millis(self.list_max_age_ms());
+        self.lock_table()
+            .as_ref()
+            .filter(|(at, _)| at.elapsed() < max_age)
+            .map(|(_, rows)| rows.clone())
+    }
+
+    /// Remember a fresh table for reuse (no-op while reuse is disabled)
+    pub(crate) fn store_table(&self, rows: &[(u64, ProcessInfo)]) {
+        if self.list_max_age_ms() > 0 {
+            *self.lock_table() = Some((Instant::now(), Arc::new(rows.to_vec())));
+        }
+    }
+
+    fn lock_table(&self) -> std::sync::MutexGuard<'_, Option<(Instant, TableRows)>> {
+        self.table.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
@@ -0,0 +1,196 @@
+//! Kills scheduled for a later time
+//!
+//! Each pending kill is a task sleeping until its due time, which then runs
+//! the escalation plan through `process_terminate` with the budget of the
+//! client that scheduled it. The target is pinned to its start time, so a
+//! process that exited meanwhile and had its PID reused is left alone.
+//! Pending kills are dropped when the server shuts down.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+
+use crate::config::ProcessConfig;
+use crate::identity;
+use crate::process_terminate::ProcessTerminateTool;
+use crate::schema::{ProcessTerminateArgs, ScheduleStatus, ScheduledKill};
+use crate::state::ProcessState;
+
+/// Pending kills accepted at once
+pub const MAX_PENDING: usize = 64;
+
+/// Finished, cancelled and skipped kills kept for `process_kill_schedules`
+const RECENT: usize = 16;
+
+struct Pending {
+    info: ScheduledKill,
+    start_time: u64,
+    client: Option<String>,
+    task: Option<JoinHandle<()>>,
+}
+
+#[derive(Default)]
+struct Entries {
+    pending: BTreeMap<u64, Pending>,
+    recent: VecDeque<ScheduledKill>,
+}
+
+#[derive(Default)]
+pub struct Scheduler {
+    next_id: AtomicU64,
+    entries: Mutex<Entries>,
+}
+
+impl Scheduler {
+    /// Pending kills by due time, then finished ones newest first
+    #[must_use]
+    pub fn all(&self) -> (Vec<ScheduledKill>, Vec<ScheduledKill>) {
+        let entries = self.lock();
+        let mut pending: Vec<ScheduledKill> = entries.pending.values().map(|entry| entry.info.clone()).collect();
+        pending.sort_by(|a, b| a.due_at.cmp(&b.due_at));
+        (pending, entries.recent.iter().rev().cloned().collect())
+    }
+
+    /// Cancel a pending kill; `None` if there is no pending kill with that id
+    pub fn cancel(&self, id: u64) -> Option<ScheduledKill> {
+        let mut entries = self.lock();
+        let mut entry = entries.pending.remove(&id)?;
+        if let Some(task) = entry.task.take() {
+            task.abort();
+        }
+        entry.info.status = ScheduleStatus::Cancelled;
+        entry.info.finished_at = Some(chrono::Utc::now().to_rfc3339());
+        push_recent(&mut entries, entry.info.clone());
+        Some(entry.info)
+    }
+
+    /// Drop every pending kill, e.g. at shutdown
+    pub fn cancel_all(&self) {
+        for (_, entry) in std::mem::take(&mut self.lock().pending) {
+            if let Some(task) = entry.task {
+                task.abort();
+            }
+        }
+    }
+
+    fn finish(&self, id: u64, status: ScheduleStatus, outcome: String) {
+        let mut entries = self.lock();
+        let Some(mut entry) = entries.pending.remove(&id) else {
+            return;
+        };
+        entry.info.status = status;
+        entry.info.outcome = Some(outcome);
+        entry.info.finished_at = Some(chrono::Utc::now().to_rfc3339());
+        push_recent(&mut entries, entry.info);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Entries> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Schedule `info` (id and status are filled in) to run after `delay`
+///
+/// `start_time` pins the target process; `client` pays the kill budget when it runs.
+pub(crate) fn schedule(
+    config: Arc<ProcessConfig>,
+    state: Arc<ProcessState>,
+    mut info: ScheduledKill,
+    start_time: u64,
+    client: Option<String>,
+    delay: Duration,
+) -> Result<ScheduledKill, String> {
+    let scheduler = &state.scheduler;
+    let mut entries = scheduler.lock();
+    if entries.pending.len() >= MAX_PENDING {
+        return Err(format!("{MAX_PENDING} kills are already scheduled; cancel one first"));
+    }
+    let id = scheduler.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+    info.id = id;
+    info.status = ScheduleStatus::Pending;
+    // Inserted before the task exists, so a zero delay cannot finish an unknown id
+    entries.pending.insert(
+        id,
+        Pending {
+            info: info.clone(),
+            start_time,
+            client,
+            task: None,
+        },
+    );
+    let task = tokio::spawn({
+        let state = state.clone();
+        async move {
+            tokio::time::sleep(delay).await;
+            run(config, state, id).await;
+        }
+    });
+    if let Some(entry) = entries.pending.get_mut(&id) {
+        entry.task = Some(task);
+    }
+    Ok(info)
+}
+
+/// Carry out a due kill and record what happened
+async fn run(config: Arc<ProcessConfig>, state: Arc<ProcessState>, id: u64) {
+    let Some((info, start_time, client)) = state
+        .scheduler
+        .lock()
+        .pending
+        .get(&id)
+        .map(|entry| (entry.info.clone(), entry.start_time, entry.client.clone()))
+    else {
+        return;
+    };
+    let pid = info.pid;
+
+    let lookup = state.clone();
+    let alive = tokio::task::spawn_blocking(move || {
+        lookup
+            .provider
+            .lookup(pid, false)
+            .is_some_and(|process| process.start_time == start_time)
+    })
+    .await
+    .unwrap_or(false);
+    if !alive {
+        log::info!("Scheduled kill #{id}: process {pid} ({}) already exited", info.name);
+        state
+            .scheduler
+            .finish(id, ScheduleStatus::Gone, "process had already exited".to_string());
+        return;
+    }
+
+    let args = ProcessTerminateArgs {
+        pid: None,
+        proc_id: Some(identity::proc_id(pid, start_time)),
+        container: None,
+        plan: info.plan.clone(),
+        // Confirmation was settled when the kill was scheduled
+        confirm: true,
+        style: None,
+        verbosity: None,
+    };
+    let result = ProcessTerminateTool::new(config, state.clone())
+        .terminate(args, client.as_deref())
+        .await;
+    let (status, outcome) = match result {
+        Ok(response) => match response.metadata.succeeded_signal {
+            Some(signal) => (ScheduleStatus::Done, format!("terminated by {signal}")),
+            None => (ScheduleStatus::Survived, "survived every step of the plan".to_string()),
+        },
+        Err(e) => (ScheduleStatus::Failed, e.to_string()),
+    };
+    log::info!("Scheduled kill #{id} of process {pid} ({}): {outcome}", info.name);
+    state.scheduler.finish(id, status, outcome);
+}
+
+fn push_recent(entries: &mut Entries, info: ScheduledKill) {
+    if entries.recent.len() >= RECENT {
+        entries.recent.pop_front();
+    }
+    entries.recent.push_back(info);
+}
@@ -0,0 +1,313 @@
+//! Schema types for process tools
+//!
+//! Mirrors the layout of `kodegen_mcp_schema::process`: each tool module owns
+//! its Args/Output types and binds them together through `ToolArgs`. Tool name
+//! constants and prompt providers are reused from `kodegen_mcp_schema` where
+//! the upstream package already defines them.
+
+pub mod shared;
+pub use shared::*;
+
+pub mod process_list;
+pub use process_list::{
+    ListDelta, Packaging, ProcessColumn, ProcessInfo, ProcessKind, ProcessListArgs, ProcessListOutput, ResultCap,
+    SeccompMode,
+};
+
+pub mod process_kill;
+pub use process_kill::{ProcessKillArgs, ProcessKillOutput};
+
+pub mod process_can_kill;
+pub use process_can_kill::{PROCESS_CAN_KILL, ProcessCanKillArgs, ProcessCanKillOutput, ProcessCanKillPrompts};
+
+pub mod process_capabilities;
+pub use process_capabilities::{
+    CapabilitySet, PROCESS_CAPABILITIES, ProcessCapabilitiesArgs, ProcessCapabilitiesOutput,
+    ProcessCapabilitiesPrompts,
+};
+
+pub mod process_recently_killed;
+pub use process_recently_killed::{
+    KilledProcess, PROCESS_RECENTLY_KILLED, ProcessRecentlyKilledArgs, ProcessRecentlyKilledOutput,
+    ProcessRecentlyKilledPrompts,
+};
+
+pub mod process_respawn;
+pub use process_respawn::{PROCESS_RESPAWN, ProcessRespawnArgs, ProcessRespawnOutput, ProcessRespawnPrompts};
+
+pub mod process_tag;
+pub use process_tag::{
+    DO_NOT_KILL_TAG, PROCESS_TAG, ProcessTagArgs, ProcessTagOutput, ProcessTagPrompts, TaggedProcess,
+};
+
+pub mod process_tags;
+pub use process_tags::{PROCESS_TAGS, ProcessTagsArgs, ProcessTagsOutput, ProcessTagsPrompts};
+
+pub mod process_group_define;
+pub use process_group_define::{
+    PROCESS_GROUP_DEFINE, ProcessGroupDefineArgs, ProcessGroupDefineOutput, ProcessGroupDefinePrompts,
+};
+
+pub mod process_groups;
+pub use process_groups::{PROCESS_GROUPS, ProcessGroupInfo, ProcessGroupsArgs, ProcessGroupsOutput, ProcessGroupsPrompts};
+
+pub mod process_group_kill;
+pub use process_group_kill::{
+    PROCESS_GROUP_KILL, ProcessGroupKillArgs, ProcessGroupKillOutput, ProcessGroupKillPrompts, SkippedProcess,
+};
+
+pub mod process_watch;
+pub use process_watch::{PROCESS_WATCH, ProcessWatchArgs, ProcessWatchOutput, ProcessWatchPrompts, WatchEvent, WatchInfo};
+
+pub mod process_unwatch;
+pub use process_unwatch::{PROCESS_UNWATCH, ProcessUnwatchArgs, ProcessUnwatchOutput, ProcessUnwatchPrompts};
+
+pub mod process_watch_events;
+pub use process_watch_events::{
+    PROCESS_WATCH_EVENTS, ProcessWatchEventsArgs, ProcessWatchEventsOutput, ProcessWatchEventsPrompts,
+};
+
+pub mod process_status;
+pub use process_status::{
+    ExitInfo, PROCESS_STATUS, ProcessStatusArgs, ProcessStatusOutput, ProcessStatusPrompts, ReapedOrphan, ReaperStats,
+    ResourceUsage, SupervisedProcess,
+};
+
+pub mod process_logs;
+pub use process_logs::{
+    DEFAULT_LOG_LINES, LogSegment, PROCESS_LOGS, ProcessLogsArgs, ProcessLogsOutput, ProcessLogsPrompts,
+};
+
+pub mod process_logs_follow;
+pub use process_logs_follow::{
+    DEFAULT_ERROR_PATTERN, DEFAULT_FOLLOW_TIMEOUT_SECS, FollowEnd, PROCESS_LOGS_FOLLOW, ProcessLogsFollowArgs,
+    ProcessLogsFollowOutput, ProcessLogsFollowPrompts,
+};
+
+pub mod process_run;
+pub use process_run::{
+    DEFAULT_RUN_OUTPUT_BYTES, DEFAULT_RUN_TIMEOUT_SECS, PROCESS_RUN, ProcessRunArgs, ProcessRunOutput,
+    ProcessRunPrompts,
+};
+
+pub mod process_pidfile;
+pub use process_pidfile::{
+    PROCESS_PIDFILE, PidfileStatus, ProcessPidfileArgs, ProcessPidfileOutput, ProcessPidfilePrompts,
+};
+
+pub mod process_pidfile_cleanup;
+pub use process_pidfile_cleanup::{
+    PROCESS_PIDFILE_CLEANUP, ProcessPidfileCleanupArgs, ProcessPidfileCleanupOutput, ProcessPidfileCleanupPrompts,
+};
+
+pub mod process_lock_check;
+pub use process_lock_check::{
+    DEFAULT_LOCK_MIN_AGE_SECS, LockHolder, PROCESS_LOCK_CHECK, ProcessLockCheckArgs, ProcessLockCheckOutput,
+    ProcessLockCheckPrompts,
+};
+
+pub mod process_sampling;
+pub use process_sampling::{PROCESS_SAMPLING, ProcessSamplingArgs, ProcessSamplingOutput, ProcessSamplingPrompts};
+
+pub mod process_terminate;
+pub use process_terminate::{
+    EscalationOutcome, EscalationStep, FINAL_STEP_WAIT_MS, MAX_ESCALATION_STEPS, MAX_STEP_WAIT_MS, PROCESS_TERMINATE,
+    ProcessTerminateArgs, ProcessTerminateOutput, ProcessTerminatePrompts, default_escalation,
+};
+
+pub mod process_renice;
+pub use process_renice::{PROCESS_RENICE, ProcessReniceArgs, ProcessReniceOutput, ProcessRenicePrompts, ReniceResult};
+pub mod process_ionice;
+pub use process_ionice::{PROCESS_IONICE, ProcessIoniceArgs, ProcessIoniceOutput, ProcessIonicePrompts};
+pub mod process_cpu_limit;
+pub use process_cpu_limit::{
+    CpuLimitInfo, CpuLimitMethod, PROCESS_CPU_LIMIT, ProcessCpuLimitArgs, ProcessCpuLimitOutput, ProcessCpuLimitPrompts,
+};
+pub mod process_cpu_unlimit;
+pub use process_cpu_unlimit::{PROCESS_CPU_UNLIMIT, ProcessCpuUnlimitArgs, ProcessCpuUnlimitOutput, ProcessCpuUnlimitPrompts};
+pub mod process_cpu_limits;
+pub use process_cpu_limits::{PROCESS_CPU_LIMITS, ProcessCpuLimitsArgs, ProcessCpuLimitsOutput, ProcessCpuLimitsPrompts};
+pub mod process_contain;
+pub use process_contain::{
+    ContainmentCaps, ContainmentInfo, MIN_MEMORY_MAX_BYTES, PROCESS_CONTAIN, ProcessContainArgs, ProcessContainOutput,
+    ProcessContainPrompts,
+};
+pub mod process_uncontain;
+pub use process_uncontain::{PROCESS_UNCONTAIN, ProcessUncontainArgs, ProcessUncontainOutput, ProcessUncontainPrompts};
+pub mod process_freeze;
+pub use process_freeze::{
+    FreezeInfo, FreezeMethod, PROCESS_FREEZE, ProcessFreezeArgs, ProcessFreezeOutput, ProcessFreezePrompts,
+};
+pub mod process_thaw;
+pub use process_thaw::{PROCESS_THAW, ProcessThawArgs, ProcessThawOutput, ProcessThawPrompts};
+
+pub mod process_stats;
+pub use process_stats::{MetricStats, PROCESS_STATS, ProcessStatsArgs, ProcessStatsOutput, ProcessStatsPrompts};
+
+pub mod process_mark;
+pub use process_mark::{MarkerInfo, MarkerTotals, PROCESS_MARK, ProcessMarkArgs, ProcessMarkOutput, ProcessMarkPrompts};
+
+pub mod process_mark_compare;
+pub use process_mark_compare::{
+    MarkerChange, MarkerComparison, MarkerProcess, PROCESS_MARK_COMPARE, ProcessMarkCompareArgs,
+    ProcessMarkCompareOutput, ProcessMarkComparePrompts,
+};
+
+pub mod process_kill_schedule;
+pub use process_kill_schedule::{
+    MAX_SCHEDULE_DELAY_SECS, PROCESS_KILL_SCHEDULE, ProcessKillScheduleArgs, ProcessKillScheduleOutput,
+    ProcessKillSchedulePrompts, ScheduleStatus, ScheduledKill,
+};
+
+pub mod process_kill_schedules;
+pub use process_kill_schedules::{
+    PROCESS_KILL_SCHEDULES, ProcessKillSchedulesArgs, ProcessKillSchedulesOutput, ProcessKillSchedulesPrompts,
+};
+
+pub mod process_kill_unschedule;
+pub use process_kill_unschedule::{
+    PROCESS_KILL_UNSCHEDULE, ProcessKillUnscheduleArgs, ProcessKillUnscheduleOutput, ProcessKillUnschedulePrompts,
+};
+
+pub mod process_kill_if_idle;
+pub use process_kill_if_idle::{
+    IdleVerdict, MAX_IDLE_WINDOW_SECS, PROCESS_KILL_IF_IDLE, ProcessKillIfIdleArgs, ProcessKillIfIdleOutput,
+    ProcessKillIfIdlePrompts,
+};
+
+pub mod process_oom_guard;
+pub use process_oom_guard::{
+    OomGuardAction, OomGuardRule, OomIntervention, PROCESS_OOM_GUARD, ProcessOomGuardArgs, ProcessOomGuardOutput,
+    ProcessOomGuardPrompts,
+};
+
+pub mod process_leaks;
+pub use process_leaks::{
+    LeakSuspect, PROCESS_LEAKS, ProcessLeaksArgs, ProcessLeaksOutput, ProcessLeaksPrompts,
+};
+
+pub mod process_forensics;
+pub use process_forensics::{
+    ForensicRecord, MemoryMap, NamespaceInfo, OpenFile, PROCESS_FORENSICS, ProcessForensicsArgs, ProcessForensicsOutput,
+    ProcessForensicsPrompts, ResourceLimit, SocketInfo, ThreadInfo,
+};
+
+pub mod process_provenance;
+pub use process_provenance::{
+    LaunchOrigin, PROCESS_PROVENANCE, ProcessAncestor, ProcessProvenanceArgs, ProcessProvenanceOutput,
+    ProcessProvenancePrompts,
+};
+
+pub mod process_audit;
+pub use process_audit::{
+    AuditCheck, AuditFinding, AuditFlag, PROCESS_AUDIT, ProcessAuditArgs, ProcessAuditOutput, ProcessAuditPrompts,
+};
+
+pub mod process_env_diff;
+pub use process_env_diff::{
+    PROCESS_ENV_DIFF, ProcessEnvDiffArgs, ProcessEnvDiffOutput, ProcessEnvDiffPrompts, ValueDifference,
+};
+
+pub mod process_compare;
+pub use process_compare::{
+    ComparedField, PROCESS_COMPARE, ProcessCompareArgs, ProcessCompareOutput, ProcessComparePrompts,
+};
+
+pub mod process_pid_translate;
+pub use process_pid_translate::{
+    PROCESS_PID_TRANSLATE, ProcessPidTranslateArgs, ProcessPidTranslateOutput, ProcessPidTranslatePrompts,
+};
+
+pub mod process_signal_check;
+pub use process_signal_check::{
+    PROCESS_SIGNAL_CHECK, ProcessSignalCheckArgs, ProcessSignalCheckOutput, ProcessSignalCheckPrompts, SignalVerdict,
+};
+
+pub mod process_code_signature;
+pub use process_code_signature::{
+    CodeSignature, PROCESS_CODE_SIGNATURE, ProcessCodeSignatureArgs, ProcessCodeSignatureOutput,
+    ProcessCodeSignaturePrompts, SignatureState,
+};
+
+pub mod process_profile_define;
+pub use process_profile_define::{
+    DEFAULT_MAX_RESTARTS, DEFAULT_RESTART_DELAY_SECS, PROCESS_PROFILE_DEFINE, ProcessProfileDefineArgs,
+    ProcessProfileDefineOutput, ProcessProfileDefinePrompts, ProfileParam, RestartPolicy, SpawnProfile,
+};
+
+pub mod process_profiles;
+pub use process_profiles::{
+    PROCESS_PROFILES, ProcessProfilesArgs, ProcessProfilesOutput, ProcessProfilesPrompts, ProfileInfo, ProfileLaunch,
+    ProfileSource,
+};
+
+pub mod process_profile_start;
+pub use process_profile_start::{
+    PROCESS_PROFILE_START, ProcessProfileStartArgs, ProcessProfileStartOutput, ProcessProfileStartPrompts,
+};
+
+pub mod process_spawn;
+pub use process_spawn::{
+    DEFAULT_SPAWN_OUTPUT_LINES, DEFAULT_SPAWN_WAIT_MS, MAX_SPAWN_WAIT_MS, PROCESS_SPAWN, ProcessSpawnArgs,
+    ProcessSpawnOutput, ProcessSpawnPrompts,
+};
+
+pub mod process_tree;
+pub use process_tree::{PROCESS_TREE, ProcessTreeArgs, ProcessTreeNode, ProcessTreeOutput, ProcessTreePrompts};
+
+pub mod process_info;
+pub use process_info::{PROCESS_INFO, ProcessDetails, ProcessInfoArgs, ProcessInfoOutput, ProcessInfoPrompts, ProcessMemory};
+
+pub mod process_signal;
+pub use process_signal::{PROCESS_SIGNAL, ProcessSignalArgs, ProcessSignalOutput, ProcessSignalPrompts};
+
+pub mod process_suspend;
+pub use process_suspend::{PROCESS_SUSPEND, ProcessSuspendArgs, ProcessSuspendOutput, ProcessSuspendPrompts};
+
+pub mod process_resume;
+pub use process_resume::{PROCESS_RESUME, ProcessResumeArgs, ProcessResumeOutput, ProcessResumePrompts};
+
+pub mod process_wait;
+pub use process_wait::{
+    DEFAULT_WAIT_TIMEOUT_MS, MAX_WAIT_TIMEOUT_MS, PROCESS_WAIT, ProcessWaitArgs, ProcessWaitOutput, ProcessWaitPrompts,
+};
+
+pub mod process_kill_by_name;
+pub use process_kill_by_name::{
+    PROCESS_KILL_BY_NAME, ProcessKillByNameArgs, ProcessKillByNameOutput, ProcessKillByNamePrompts,
+};
+
+pub mod process_kill_tree;
+pub use process_kill_tree::{
+    PROCESS_KILL_TREE, ProcessKillTreeArgs, ProcessKillTreeOutput, ProcessKillTreePrompts, TreeKillOrder,
+};
+
+pub mod process_pgrep;
+pub use process_pgrep::{PROCESS_PGREP, PgrepMatch, ProcessPgrepArgs, ProcessPgrepOutput, ProcessPgrepPrompts};
+
+pub mod process_ancestors;
+pub use process_ancestors::{
+    PROCESS_ANCESTORS, ProcessAncestorsArgs, ProcessAncestorsOutput, ProcessAncestorsPrompts,
+};
+
+pub mod process_env;
+pub use process_env::{PROCESS_ENV, ProcessEnvArgs, ProcessEnvOutput, ProcessEnvPrompts};
+
+pub mod process_open_files;
+pub use process_open_files::{
+    PROCESS_OPEN_FILES, ProcessOpenFilesArgs, ProcessOpenFilesOutput, ProcessOpenFilesPrompts,
+};
+
+pub mod process_connections;
+pub use process_connections::{
+    PROCESS_CONNECTIONS, ProcessConnectionsArgs, ProcessConnectionsOutput, ProcessConnectionsPrompts,
+};
+
+pub mod port_lookup;
+pub use port_lookup::{PORT_LOOKUP, PortLookupArgs, PortLookupOutput, PortLookupPrompts, PortOwner, PortProtocol};
+
+pub mod process_priority;
+pub use process_priority::{PROCESS_PRIORITY, ProcessPriorityArgs, ProcessPriorityOutput, ProcessPriorityPrompts};
+
+pub mod process_affinity;
+pub use process_affinity::{PROCESS_AFFINITY, ProcessAffinityArgs, ProcessAffinityOutput, ProcessAffinityPrompts};
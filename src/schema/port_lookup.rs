@@ -0,0 +1,167 @@
+//! Schema types for port_lookup tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for finding the process that owns a port
+pub const PORT_LOOKUP: &str = "port_lookup";
+
+// ============================================================================
+// PORT LOOKUP TOOL
+// ============================================================================
+
+/// Transport protocol of a looked-up port
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PortProtocol {
+    /// TCP over IPv4 and IPv6
+    #[default]
+    Tcp,
+    /// UDP over IPv4 and IPv6
+    Udp,
+    /// Both TCP and UDP
+    Any,
+}
+
+impl PortProtocol {
+    /// Name as accepted by the `protocol` argument
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Tcp => "tcp",
+            Self::Udp => "udp",
+            Self::Any => "any",
+        }
+    }
+
+    /// Whether a socket protocol such as `tcp6` is covered
+    #[must_use]
+    pub fn matches(self, protocol: &str) -> bool {
+        match self {
+            Self::Tcp => protocol.starts_with("tcp"),
+            Self::Udp => protocol.starts_with("udp"),
+            Self::Any => true,
+        }
+    }
+}
+
+/// Arguments for `port_lookup` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PortLookupArgs {
+    /// Local port number, e.g. 8080
+    pub port: u16,
+
+    /// Optional: "tcp", "udp" or "any" (default: tcp)
+    #[serde(default)]
+    pub protocol: PortProtocol,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// A socket on the looked-up port and the process holding it
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PortOwner {
+    /// Process ID
+    pub pid: u32,
+    /// Reuse-safe handle for process_kill and other tools
+    pub proc_id: String,
+    /// Process name
+    pub name: String,
+    /// Command line with secrets masked
+    pub cmdline: Vec<String>,
+    /// Owning user
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// `tcp`, `tcp6`, `udp` or `udp6`
+    pub protocol: String,
+    /// Local address as `ip:port`
+    pub local: String,
+    /// Peer address as `ip:port`, for connected sockets
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<String>,
+    /// TCP state such as `LISTEN` or `ESTABLISHED`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+/// Output from `port_lookup` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PortLookupOutput {
+    /// Whether the socket tables could be read
+    pub success: bool,
+    /// Port looked up
+    pub port: u16,
+    /// Protocol looked up
+    pub protocol: PortProtocol,
+    /// Whether any socket is bound to the port, owned or not
+    pub in_use: bool,
+    /// One entry per socket and holding process, listeners first
+    pub owners: Vec<PortOwner>,
+    /// Sockets on the port whose process could not be determined (another user's, without privileges)
+    pub unknown_owner: usize,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `port_lookup` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PortLookupPromptArgs {}
+
+/// Prompt provider for `port_lookup` tool
+pub struct PortLookupPrompts;
+
+impl SealedPromptProvider for PortLookupPrompts {}
+
+impl PromptProvider for PortLookupPrompts {
+    type PromptArgs = PortLookupPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("npm start fails with EADDRINUSE: port 8080 is already in use."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "port_lookup({\"port\": 8080})\n\n\
+                     Returns the process listening on 8080 with its command line, often a dev \
+                     server left running from an earlier session. If it is safe to stop, pass \
+                     its proc_id to process_terminate, which cannot hit a process that reused \
+                     the PID meanwhile.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for PortLookupArgs {
+    type Output = PortLookupOutput;
+    type Prompts = PortLookupPrompts;
+
+    const NAME: &'static str = PORT_LOOKUP;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Find the process that owns a TCP or UDP port, with its PID, name and command line";
+}
@@ -0,0 +1,115 @@
+//! Schema types for process_affinity tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for reading or setting the CPU affinity of one process
+pub const PROCESS_AFFINITY: &str = "process_affinity";
+
+// ============================================================================
+// PROCESS AFFINITY TOOL
+// ============================================================================
+
+/// Arguments for `process_affinity` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessAffinityArgs {
+    /// Process ID to read or pin (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: CPU indices to pin the process to, e.g. [2, 3]; omit to only read the mask
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpus: Option<Vec<usize>>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_affinity` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessAffinityOutput {
+    /// Whether the mask was read (and set, when `cpus` was given)
+    pub success: bool,
+    /// Process ID
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// CPUs the process could run on before the call
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<Vec<usize>>,
+    /// CPUs the process may run on now, read back after any change
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpus: Option<Vec<usize>>,
+    /// CPUs configured on the machine
+    pub cpu_count: usize,
+    /// Whether the mask was set by this call
+    pub changed: bool,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_affinity` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessAffinityPromptArgs {}
+
+/// Prompt provider for `process_affinity` tool
+pub struct ProcessAffinityPrompts;
+
+impl SealedPromptProvider for ProcessAffinityPrompts {}
+
+impl PromptProvider for ProcessAffinityPrompts {
+    type PromptArgs = ProcessAffinityPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("I'm benchmarking the API server on CPUs 0-1, but the indexer (PID 4120) keeps stealing cycles from it."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_affinity({\"pid\": 4120, \"cpus\": [2, 3]})\n\n\
+                     Moves every thread of the indexer onto CPUs 2 and 3, away from the \
+                     benchmarked server; threads it starts later inherit the mask. Calling \
+                     process_affinity without cpus afterwards confirms the mask, and passing the \
+                     before list back restores it once the benchmark is done.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessAffinityArgs {
+    type Output = ProcessAffinityOutput;
+    type Prompts = ProcessAffinityPrompts;
+
+    const NAME: &'static str = PROCESS_AFFINITY;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Read or set the CPU affinity mask of one process";
+}
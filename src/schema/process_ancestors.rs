@@ -0,0 +1,109 @@
+//! Schema types for process_ancestors tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{ProcessAncestor, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for the parent chain of a process
+pub const PROCESS_ANCESTORS: &str = "process_ancestors";
+
+// ============================================================================
+// PROCESS ANCESTORS TOOL
+// ============================================================================
+
+/// Arguments for `process_ancestors` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessAncestorsArgs {
+    /// Process ID whose parents to list (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_ancestors` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessAncestorsOutput {
+    /// Whether the process was found
+    pub success: bool,
+    /// Process ID whose parents are listed
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Parent chain, nearest parent first, ending at the root (normally PID 1)
+    pub ancestors: Vec<ProcessAncestor>,
+    /// Parent PID where the chain ends early because that process has exited
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub broken_at: Option<u32>,
+    /// The chain was cut off at 64 ancestors
+    pub truncated: bool,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_ancestors` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessAncestorsPromptArgs {}
+
+/// Prompt provider for `process_ancestors` tool
+pub struct ProcessAncestorsPrompts;
+
+impl SealedPromptProvider for ProcessAncestorsPrompts {}
+
+impl PromptProvider for ProcessAncestorsPrompts {
+    type PromptArgs = ProcessAncestorsPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("What launched PID 8123?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_ancestors({\"pid\": 8123})\n\n\
+                     Lists its parents from the nearest up to PID 1, e.g. \
+                     python3 (8123) ← bash (8001) ← tmux: server (1788) ← systemd (1). broken_at \
+                     names the parent PID where the chain stops early because that process has \
+                     exited. process_provenance adds users, the terminal and the launch through \
+                     this server.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessAncestorsArgs {
+    type Output = ProcessAncestorsOutput;
+    type Prompts = ProcessAncestorsPrompts;
+
+    const NAME: &'static str = PROCESS_ANCESTORS;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "List the parent chain of a process up to PID 1 with names";
+}
@@ -0,0 +1,199 @@
+//! Schema types for process_audit tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for the red-flag scan
+pub const PROCESS_AUDIT: &str = "process_audit";
+
+fn default_min_score() -> u32 {
+    1
+}
+
+fn default_limit() -> usize {
+    25
+}
+
+// ============================================================================
+// PROCESS AUDIT TOOL
+// ============================================================================
+
+/// Arguments for `process_audit` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessAuditArgs {
+    /// Optional: only these PIDs (default: every process)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pids: Vec<u32>,
+
+    /// Score below which a process is not reported (default: 1)
+    #[serde(default = "default_min_score")]
+    pub min_score: u32,
+
+    /// Maximum findings returned, highest score first (default: 25, 0 = all)
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// One heuristic `process_audit` applies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditCheck {
+    /// Runs from an anonymous memory file (`memfd:`), never from disk (Linux)
+    FilelessExe,
+    /// Name or argv[0] imitates a kernel thread such as `[kworker/0:1]`
+    KernelMasquerade,
+    /// The executable was deleted or replaced after the process started
+    DeletedExe,
+    /// The executable lives in a temporary directory
+    TempExe,
+    /// The executable's directory is writable by every user
+    WorldWritableDir,
+    /// The command line is empty: argv was wiped
+    HiddenArgv,
+    /// argv[0] names neither the process nor its executable
+    SpoofedArgv,
+    /// The process name does not match its executable's file name
+    NameMismatch,
+}
+
+impl AuditCheck {
+    /// Points the check adds to a process's score
+    #[must_use]
+    pub fn weight(self) -> u32 {
+        match self {
+            Self::FilelessExe => 60,
+            Self::KernelMasquerade => 50,
+            Self::DeletedExe | Self::TempExe | Self::HiddenArgv => 30,
+            Self::WorldWritableDir => 25,
+            Self::NameMismatch => 15,
+            Self::SpoofedArgv => 10,
+        }
+    }
+
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::FilelessExe => "fileless_exe",
+            Self::KernelMasquerade => "kernel_masquerade",
+            Self::DeletedExe => "deleted_exe",
+            Self::TempExe => "temp_exe",
+            Self::WorldWritableDir => "world_writable_dir",
+            Self::HiddenArgv => "hidden_argv",
+            Self::SpoofedArgv => "spoofed_argv",
+            Self::NameMismatch => "name_mismatch",
+        }
+    }
+}
+
+/// One red flag raised on a process
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AuditFlag {
+    pub check: AuditCheck,
+    /// Points added to the score
+    pub weight: u32,
+    /// What was seen, e.g. the path or the mismatching names
+    pub detail: String,
+}
+
+/// A process with at least one red flag
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AuditFinding {
+    /// Process ID
+    pub pid: u32,
+    /// Reuse-safe handle to pass as `proc_id` to mutating tools
+    pub proc_id: String,
+    /// Process name
+    pub name: String,
+    /// Executable path as the OS reports it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exe: Option<String>,
+    /// Sum of the flags' weights
+    pub score: u32,
+    /// `high` (60 and above), `medium` (30 and above) or `low`
+    pub severity: String,
+    /// Red flags, heaviest first
+    pub flags: Vec<AuditFlag>,
+}
+
+/// Output from `process_audit` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessAuditOutput {
+    /// Whether the scan ran
+    pub success: bool,
+    /// Processes examined (kernel threads excluded)
+    pub scanned: usize,
+    /// Processes whose executable path could not be read, so only name checks applied
+    pub unreadable: usize,
+    /// Processes at or above min_score before the limit
+    pub total_findings: usize,
+    /// Findings, highest score first
+    pub findings: Vec<AuditFinding>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_audit` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessAuditPromptArgs {}
+
+/// Prompt provider for `process_audit` tool
+pub struct ProcessAuditPrompts;
+
+impl SealedPromptProvider for ProcessAuditPrompts {}
+
+impl PromptProvider for ProcessAuditPrompts {
+    type PromptArgs = ProcessAuditPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Does anything running on this box look off?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_audit({\"min_score\": 30})\n\n\
+                     Each finding lists its red flags, such as a deleted executable, a binary \
+                     in /tmp or a process posing as a kernel thread, with a score. These are \
+                     heuristics: a daemon whose package was upgraded also runs a deleted \
+                     executable. High scores are worth process_provenance and \
+                     process_code_signature before anything is killed.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessAuditArgs {
+    type Output = ProcessAuditOutput;
+    type Prompts = ProcessAuditPrompts;
+
+    const NAME: &'static str = PROCESS_AUDIT;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Scan processes for red flags such as deleted or temp-dir executables and spoofed names, with scores";
+}
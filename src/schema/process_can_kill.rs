@@ -0,0 +1,117 @@
+//! Schema types for process_can_kill tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for the kill permission pre-check
+pub const PROCESS_CAN_KILL: &str = "process_can_kill";
+
+// ============================================================================
+// PROCESS CAN KILL TOOL
+// ============================================================================
+
+/// Arguments for `process_can_kill` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCanKillArgs {
+    /// Process ID to check
+    pub pid: u32,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_can_kill` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCanKillOutput {
+    /// Whether the check completed
+    pub success: bool,
+    /// Process ID that was checked
+    pub pid: u32,
+    /// Whether the process exists
+    pub exists: bool,
+    /// Process name (if it exists)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Verdict: the server could plausibly kill this process
+    pub can_kill: bool,
+    /// Process is on the protected list (OS-critical or this server)
+    pub protected: bool,
+    /// Owner of the target process
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_user: Option<String>,
+    /// User the server runs as
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server_user: Option<String>,
+    /// Facts behind the verdict, most decisive first
+    pub reasons: Vec<String>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_can_kill` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCanKillPromptArgs {}
+
+/// Prompt provider for `process_can_kill` tool
+pub struct ProcessCanKillPrompts;
+
+impl SealedPromptProvider for ProcessCanKillPrompts {}
+
+impl PromptProvider for ProcessCanKillPrompts {
+    type PromptArgs = ProcessCanKillPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text(
+                    "How do I check whether a process can be killed before asking the user?",
+                ),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "Call process_can_kill with the PID. No signal is sent.\n\n\
+                     process_can_kill({\"pid\": 12345})\n\n\
+                     The response contains:\n\
+                     - can_kill: whether the server could plausibly terminate it\n\
+                     - protected: OS-critical process or the server itself\n\
+                     - target_user / server_user: ownership comparison\n\
+                     - reasons: the facts behind the verdict\n\n\
+                     Only propose process_kill to the user when can_kill is true, and \
+                     quote the reasons when it is false.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessCanKillArgs {
+    type Output = ProcessCanKillOutput;
+    type Prompts = ProcessCanKillPrompts;
+
+    const NAME: &'static str = PROCESS_CAN_KILL;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Check whether the server could kill a process (ownership, privileges, protected list) without sending any signal";
+}
@@ -0,0 +1,119 @@
+//! Schema types for process_capabilities tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for Linux capability inspection
+pub const PROCESS_CAPABILITIES: &str = "process_capabilities";
+
+// ============================================================================
+// PROCESS CAPABILITIES TOOL
+// ============================================================================
+
+/// Arguments for `process_capabilities` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCapabilitiesArgs {
+    /// Process ID to inspect
+    pub pid: u32,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_capabilities` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCapabilitiesOutput {
+    /// Whether the operation succeeded
+    pub success: bool,
+    /// Process ID that was inspected
+    pub pid: u32,
+    /// Effective set (CapEff): what the process can do right now
+    pub effective: CapabilitySet,
+    /// Permitted set (CapPrm): what it may raise into the effective set
+    pub permitted: CapabilitySet,
+    /// Inheritable set (CapInh)
+    pub inheritable: CapabilitySet,
+    /// Bounding set (CapBnd): upper limit for it and its children
+    pub bounding: CapabilitySet,
+    /// Ambient set (CapAmb), absent on kernels older than 4.3
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ambient: Option<CapabilitySet>,
+    /// Plain-language consequences of notable effective capabilities
+    pub notes: Vec<String>,
+}
+
+/// One decoded capability set
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CapabilitySet {
+    /// Raw hex mask as shown in `/proc/<pid>/status`
+    pub raw: String,
+    /// Capability names, e.g. `CAP_NET_BIND_SERVICE`
+    pub names: Vec<String>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_capabilities` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCapabilitiesPromptArgs {}
+
+/// Prompt provider for `process_capabilities` tool
+pub struct ProcessCapabilitiesPrompts;
+
+impl SealedPromptProvider for ProcessCapabilitiesPrompts {}
+
+impl PromptProvider for ProcessCapabilitiesPrompts {
+    type PromptArgs = ProcessCapabilitiesPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text(
+                    "Why can this non-root process bind port 80?",
+                ),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "Inspect its Linux capabilities:\n\n\
+                     process_capabilities({\"pid\": 12345})\n\n\
+                     Look at the effective set: CAP_NET_BIND_SERVICE allows binding ports \
+                     below 1024, CAP_KILL allows signalling processes of other users, \
+                     CAP_SYS_PTRACE allows attaching debuggers. The bounding set caps what \
+                     the process and its children can ever gain. Linux only.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessCapabilitiesArgs {
+    type Output = ProcessCapabilitiesOutput;
+    type Prompts = ProcessCapabilitiesPrompts;
+
+    const NAME: &'static str = PROCESS_CAPABILITIES;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Decode a process's Linux capability sets (effective, permitted, bounding, ...) into capability names";
+}
@@ -0,0 +1,164 @@
+//! Schema types for process_code_signature tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for code signing checks
+pub const PROCESS_CODE_SIGNATURE: &str = "process_code_signature";
+
+// ============================================================================
+// PROCESS CODE SIGNATURE TOOL
+// ============================================================================
+
+/// Arguments for `process_code_signature` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCodeSignatureArgs {
+    /// Process ID whose executable to check
+    pub pid: u32,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Verdict on an executable's signature
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SignatureState {
+    /// Signed, and the signature verifies against the file
+    Valid,
+    /// Signed, but the signature is broken, untrusted or revoked
+    Invalid,
+    /// Not signed at all
+    Unsigned,
+    /// The OS has no code signing to check (Linux)
+    #[default]
+    Unsupported,
+}
+
+impl SignatureState {
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Valid => "valid",
+            Self::Invalid => "invalid",
+            Self::Unsigned => "unsigned",
+            Self::Unsupported => "unsupported",
+        }
+    }
+}
+
+/// What the OS reports about an executable's signature
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct CodeSignature {
+    /// Overall verdict
+    pub state: SignatureState,
+    /// The OS's own wording, e.g. `HashMismatch` or codesign's verification error
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// Who signed it: the leaf certificate's subject or first codesign authority
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signer: Option<String>,
+    /// Certificate chain from the signer up to the root
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authorities: Vec<String>,
+    /// Apple Developer team ID (macOS)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// Signing identifier such as `com.apple.ls` (macOS)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    /// Signed without a certificate, e.g. by the linker on Apple silicon (macOS)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ad_hoc: Option<bool>,
+    /// Built with the hardened runtime (macOS)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hardened_runtime: Option<bool>,
+    /// Gatekeeper accepts it as notarized (macOS, app bundles only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notarized: Option<bool>,
+    /// Part of the operating system: an Apple platform binary or a Windows OS binary
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub os_binary: Option<bool>,
+    /// Consequences worth knowing, e.g. which operations the OS will refuse
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+}
+
+/// Output from `process_code_signature` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCodeSignatureOutput {
+    /// Whether the check ran
+    pub success: bool,
+    /// Process ID checked
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Executable that was checked
+    pub exe: String,
+    #[serde(flatten)]
+    pub signature: CodeSignature,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_code_signature` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCodeSignaturePromptArgs {}
+
+/// Prompt provider for `process_code_signature` tool
+pub struct ProcessCodeSignaturePrompts;
+
+impl SealedPromptProvider for ProcessCodeSignaturePrompts {}
+
+impl PromptProvider for ProcessCodeSignaturePrompts {
+    type PromptArgs = ProcessCodeSignaturePromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Is the updater.exe running as PID 7720 really from the vendor?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_code_signature({\"pid\": 7720})\n\n\
+                     state tells whether the executable is validly signed, and signer and \
+                     authorities name the certificate it was signed with. An invalid or \
+                     unsigned updater claiming to be from a vendor is worth a closer look, \
+                     e.g. with process_provenance and process_forensics.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessCodeSignatureArgs {
+    type Output = ProcessCodeSignatureOutput;
+    type Prompts = ProcessCodeSignaturePrompts;
+
+    const NAME: &'static str = PROCESS_CODE_SIGNATURE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Report whether a process's executable is code-signed, by whom, and whether it verifies";
+}
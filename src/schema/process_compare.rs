@@ -0,0 +1,133 @@
+//! Schema types for process_compare tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity, ValueDifference};
+
+/// Tool name for side-by-side process comparison
+pub const PROCESS_COMPARE: &str = "process_compare";
+
+// ============================================================================
+// PROCESS COMPARE TOOL
+// ============================================================================
+
+/// Arguments for `process_compare` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCompareArgs {
+    /// Process ID of the first process (e.g. the good instance)
+    pub pid_a: u32,
+
+    /// Process ID of the second process (e.g. the bad instance)
+    pub pid_b: u32,
+
+    /// Optional: return only the fields that differ (default: false)
+    #[serde(default)]
+    pub only_differences: bool,
+
+    /// Optional: show command lines unmasked (only if the server's redaction policy allows it)
+    #[serde(default)]
+    pub reveal_secrets: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// One field of both processes side by side
+///
+/// Values keep their JSON type: numbers stay numbers and the command line is
+/// an array. `null` means the field could not be read for that process.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ComparedField {
+    /// Field name, e.g. `exe` or `rss_bytes`
+    pub field: String,
+    /// Value in process A
+    pub a: serde_json::Value,
+    /// Value in process B
+    pub b: serde_json::Value,
+    /// Both values are equal
+    pub same: bool,
+}
+
+/// Output from `process_compare` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCompareOutput {
+    /// Whether the comparison ran
+    pub success: bool,
+    /// Process ID of A
+    pub pid_a: u32,
+    /// Process ID of B
+    pub pid_b: u32,
+    /// Fields in a fixed order; only differing ones with `only_differences`
+    pub fields: Vec<ComparedField>,
+    /// Resource limits that differ, as `soft / hard` (Linux)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub limits: Vec<ValueDifference>,
+    /// Fields and limits that differ
+    pub differing: usize,
+    /// Command-line arguments masked by the redaction policy
+    pub redacted: usize,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_compare` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessComparePromptArgs {}
+
+/// Prompt provider for `process_compare` tool
+pub struct ProcessComparePrompts;
+
+impl SealedPromptProvider for ProcessComparePrompts {}
+
+impl PromptProvider for ProcessComparePrompts {
+    type PromptArgs = ProcessComparePromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text(
+                    "Worker 4410 is healthy but worker 4522 keeps timing out. What is different about them?",
+                ),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_compare({\"pid_a\": 4410, \"pid_b\": 4522, \"only_differences\": true})\n\n\
+                     The differing fields come back side by side: perhaps another executable or \
+                     flags, a different cgroup, a lower open-files limit or far more open \
+                     handles. Follow up with process_env_diff for the environment.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessCompareArgs {
+    type Output = ProcessCompareOutput;
+    type Prompts = ProcessComparePrompts;
+
+    const NAME: &'static str = PROCESS_COMPARE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Compare two processes side by side: exe, cmdline, user, cgroup, limits, handles and resource usage";
+}
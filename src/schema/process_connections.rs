@@ -0,0 +1,115 @@
+//! Schema types for process_connections tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SocketInfo, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for listing the sockets of a process
+pub const PROCESS_CONNECTIONS: &str = "process_connections";
+
+// ============================================================================
+// PROCESS CONNECTIONS TOOL
+// ============================================================================
+
+/// Arguments for `process_connections` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessConnectionsArgs {
+    /// Process ID to inspect (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: only TCP sockets in this state, e.g. "LISTEN" or "ESTABLISHED" (case-insensitive)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+
+    /// Optional: also list Unix domain sockets (Linux only, default: false)
+    #[serde(default)]
+    pub include_unix: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_connections` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessConnectionsOutput {
+    /// Whether the process was found
+    pub success: bool,
+    /// Process ID inspected
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Sockets matching the filters
+    pub sockets: Vec<SocketInfo>,
+    /// TCP sockets in `sockets` accepting connections
+    pub listening: usize,
+    /// False when the sockets could not be read, normally another user's process without privileges
+    pub readable: bool,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_connections` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessConnectionsPromptArgs {}
+
+/// Prompt provider for `process_connections` tool
+pub struct ProcessConnectionsPrompts;
+
+impl SealedPromptProvider for ProcessConnectionsPrompts {}
+
+impl PromptProvider for ProcessConnectionsPrompts {
+    type PromptArgs = ProcessConnectionsPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("My API server (PID 5120) is up but curl gets connection refused."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_connections({\"pid\": 5120, \"state\": \"LISTEN\"})\n\n\
+                     Shows the addresses the server listens on. A listener on 127.0.0.1 is not \
+                     reachable from other hosts or containers, and one on another port than \
+                     expected points at the configuration. No listener at all means it never got \
+                     to bind. Drop state to see its connections to databases and other services.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessConnectionsArgs {
+    type Output = ProcessConnectionsOutput;
+    type Prompts = ProcessConnectionsPrompts;
+
+    const NAME: &'static str = PROCESS_CONNECTIONS;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "List the TCP and UDP sockets of a process with local and remote addresses and state";
+}
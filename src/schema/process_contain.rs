@@ -0,0 +1,162 @@
+//! Schema types for process_contain tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for capping a running process inside a managed cgroup
+pub const PROCESS_CONTAIN: &str = "process_contain";
+
+/// Smallest accepted `memory_max_bytes` (1 MiB)
+pub const MIN_MEMORY_MAX_BYTES: u64 = 1 << 20;
+
+// ============================================================================
+// PROCESS CONTAIN TOOL
+// ============================================================================
+
+/// Resource caps of a contained process; unset caps are unlimited
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ContainmentCaps {
+    /// `memory.max`: hard memory limit in bytes, enforced by reclaim and then the OOM killer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_max_bytes: Option<u64>,
+    /// `cpu.max`: CPU share in percent of one core (may exceed 100 on multi-core hosts)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_percent: Option<u32>,
+    /// `pids.max`: most processes and threads the cgroup may hold
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pids_max: Option<u64>,
+}
+
+impl ContainmentCaps {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.memory_max_bytes.is_none() && self.cpu_percent.is_none() && self.pids_max.is_none()
+    }
+}
+
+/// Arguments for `process_contain` tool
+///
+/// A new containment needs at least one cap; later calls change only the caps given.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessContainArgs {
+    /// Process ID (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: hard memory limit in bytes (`memory.max`, at least 1 MiB)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_max_bytes: Option<u64>,
+
+    /// Optional: CPU share in percent of one core (`cpu.max`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_percent: Option<u32>,
+
+    /// Optional: most processes and threads the cgroup may hold (`pids.max`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pids_max: Option<u64>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// A process held in a managed cgroup
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ContainmentInfo {
+    /// Process ID
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Cgroup directory holding the process
+    pub cgroup: String,
+    /// Caps in effect
+    pub caps: ContainmentCaps,
+    /// `memory.current` of the cgroup when last read
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_current_bytes: Option<u64>,
+    /// `pids.current` of the cgroup when last read
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pids_current: Option<u64>,
+    /// When the process was contained (RFC 3339)
+    pub since: String,
+}
+
+/// Output from `process_contain` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessContainOutput {
+    /// Whether the caps were applied
+    pub success: bool,
+    /// The containment now in effect
+    pub containment: ContainmentInfo,
+    /// Whether an existing containment was changed rather than a new one created
+    pub updated: bool,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_contain` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessContainPromptArgs {}
+
+/// Prompt provider for `process_contain` tool
+pub struct ProcessContainPrompts;
+
+impl SealedPromptProvider for ProcessContainPrompts {}
+
+impl PromptProvider for ProcessContainPrompts {
+    type PromptArgs = ProcessContainPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("The test runner (PID 9021) keeps eating memory and forking workers."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "Contain it in place rather than killing it:\n\n\
+                     process_contain({\"pid\": 9021, \"memory_max_bytes\": 2147483648, \"pids_max\": 64})\n\n\
+                     It moves into a cgroup of its own; workers it forks from now on stay inside \
+                     and share the caps. Add cpu_percent to cap CPU too, or call again to change \
+                     a cap. Beyond memory_max the kernel reclaims and then OOM-kills inside the \
+                     cgroup. process_uncontain moves it back. Needs Linux with cgroup v2 and root \
+                     or a delegated hierarchy.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessContainArgs {
+    type Output = ProcessContainOutput;
+    type Prompts = ProcessContainPrompts;
+
+    const NAME: &'static str = PROCESS_CONTAIN;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Move a running process into a managed cgroup v2 and cap it with memory.max, cpu.max and pids.max (Linux)";
+}
@@ -0,0 +1,150 @@
+//! Schema types for process_cpu_limit tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for capping the CPU use of a running process
+pub const PROCESS_CPU_LIMIT: &str = "process_cpu_limit";
+
+// ============================================================================
+// PROCESS CPU LIMIT TOOL
+// ============================================================================
+
+/// How a CPU limit is enforced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CpuLimitMethod {
+    /// `cpu.max` of a cgroup v2 created for the process (Linux)
+    Cgroup,
+    /// SIGSTOP/SIGCONT duty cycling over a 100 ms period (Unix)
+    Signals,
+}
+
+impl CpuLimitMethod {
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Cgroup => "cgroup",
+            Self::Signals => "signals",
+        }
+    }
+}
+
+/// Arguments for `process_cpu_limit` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCpuLimitArgs {
+    /// Process ID (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// CPU share to allow, in percent of one core (above 100 only with the cgroup method)
+    pub percent: u32,
+
+    /// Optional: "cgroup" or "signals" (default: cgroup when available, else signals)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<CpuLimitMethod>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// An active CPU limit
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CpuLimitInfo {
+    /// Process ID
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Allowed CPU share in percent of one core
+    pub percent: u32,
+    /// Enforcement method in use
+    pub method: CpuLimitMethod,
+    /// Cgroup directory holding the process (cgroup method)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cgroup: Option<String>,
+    /// When the limit was first applied (RFC 3339)
+    pub since: String,
+}
+
+/// Output from `process_cpu_limit` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCpuLimitOutput {
+    /// Whether the limit was applied
+    pub success: bool,
+    /// The limit now in effect
+    pub limit: CpuLimitInfo,
+    /// Whether an existing limit was changed rather than a new one started
+    pub updated: bool,
+    /// Why the cgroup method was not used, when it was not requested explicitly
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fallback_reason: Option<String>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_cpu_limit` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCpuLimitPromptArgs {}
+
+/// Prompt provider for `process_cpu_limit` tool
+pub struct ProcessCpuLimitPrompts;
+
+impl SealedPromptProvider for ProcessCpuLimitPrompts {}
+
+impl PromptProvider for ProcessCpuLimitPrompts {
+    type PromptArgs = ProcessCpuLimitPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("The indexer (PID 7311) is pegging a core but I need it to finish."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "Slow it down instead of killing it:\n\n\
+                     process_cpu_limit({\"pid\": 7311, \"percent\": 25})\n\n\
+                     The limit holds until process_cpu_unlimit, the process exits or the server \
+                     stops. Calling again with another percent adjusts it; process_cpu_limits \
+                     shows every active limit. On Linux with cgroup v2 it uses cpu.max, otherwise \
+                     it pauses and resumes the process with SIGSTOP/SIGCONT.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessCpuLimitArgs {
+    type Output = ProcessCpuLimitOutput;
+    type Prompts = ProcessCpuLimitPrompts;
+
+    const NAME: &'static str = PROCESS_CPU_LIMIT;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Cap a running process at a percentage of CPU via cgroup cpu.max or SIGSTOP/SIGCONT duty cycling";
+}
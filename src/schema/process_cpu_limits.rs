@@ -0,0 +1,91 @@
+//! Schema types for process_cpu_limits tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{CpuLimitInfo, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for listing active CPU limits
+pub const PROCESS_CPU_LIMITS: &str = "process_cpu_limits";
+
+// ============================================================================
+// PROCESS CPU LIMITS TOOL
+// ============================================================================
+
+/// Arguments for `process_cpu_limits` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCpuLimitsArgs {
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_cpu_limits` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCpuLimitsOutput {
+    /// Whether the listing succeeded
+    pub success: bool,
+    /// Number of active limits
+    pub count: usize,
+    /// Active limits, by PID
+    pub limits: Vec<CpuLimitInfo>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_cpu_limits` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCpuLimitsPromptArgs {}
+
+/// Prompt provider for `process_cpu_limits` tool
+pub struct ProcessCpuLimitsPrompts;
+
+impl SealedPromptProvider for ProcessCpuLimitsPrompts {}
+
+impl PromptProvider for ProcessCpuLimitsPrompts {
+    type PromptArgs = ProcessCpuLimitsPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Which processes are you holding back?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_cpu_limits({})\n\n\
+                     Lists every CPU limit with its percent, method and start time. Limits of \
+                     processes that exited are dropped automatically.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessCpuLimitsArgs {
+    type Output = ProcessCpuLimitsOutput;
+    type Prompts = ProcessCpuLimitsPrompts;
+
+    const NAME: &'static str = PROCESS_CPU_LIMITS;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "List processes whose CPU use is capped by process_cpu_limit";
+}
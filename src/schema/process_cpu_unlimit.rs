@@ -0,0 +1,97 @@
+//! Schema types for process_cpu_unlimit tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{CpuLimitInfo, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for lifting a CPU limit
+pub const PROCESS_CPU_UNLIMIT: &str = "process_cpu_unlimit";
+
+// ============================================================================
+// PROCESS CPU UNLIMIT TOOL
+// ============================================================================
+
+/// Arguments for `process_cpu_unlimit` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCpuUnlimitArgs {
+    /// Process ID given to `process_cpu_limit` (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_cpu_unlimit` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCpuUnlimitOutput {
+    /// Whether the limit was lifted
+    pub success: bool,
+    /// The limit that was in effect
+    pub limit: CpuLimitInfo,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_cpu_unlimit` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessCpuUnlimitPromptArgs {}
+
+/// Prompt provider for `process_cpu_unlimit` tool
+pub struct ProcessCpuUnlimitPrompts;
+
+impl SealedPromptProvider for ProcessCpuUnlimitPrompts {}
+
+impl PromptProvider for ProcessCpuUnlimitPrompts {
+    type PromptArgs = ProcessCpuUnlimitPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Let the indexer run at full speed again."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_cpu_unlimit({\"pid\": 7311})\n\n\
+                     The process is resumed (or moved back to its original cgroup) and keeps \
+                     running unrestricted.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessCpuUnlimitArgs {
+    type Output = ProcessCpuUnlimitOutput;
+    type Prompts = ProcessCpuUnlimitPrompts;
+
+    const NAME: &'static str = PROCESS_CPU_UNLIMIT;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Lift a CPU limit set by process_cpu_limit and let the process run unrestricted";
+}
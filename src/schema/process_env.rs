@@ -0,0 +1,119 @@
+//! Schema types for process_env tool
+
+use std::collections::BTreeMap;
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for reading a process's environment
+pub const PROCESS_ENV: &str = "process_env";
+
+// ============================================================================
+// PROCESS ENV TOOL
+// ============================================================================
+
+/// Arguments for `process_env` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessEnvArgs {
+    /// Process ID to read (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: case-insensitive globs on variable names, e.g. ["PATH", "NODE_*"] (default: all)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keys: Vec<String>,
+
+    /// Optional: show secret values unmasked (only if the server's redaction policy allows it)
+    #[serde(default)]
+    pub reveal_secrets: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_env` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessEnvOutput {
+    /// Whether the process was found
+    pub success: bool,
+    /// Process ID read
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Variables matching `keys`, by name; secret values are masked
+    pub env: BTreeMap<String, String>,
+    /// Variables in the whole environment, before `keys` was applied
+    pub total: usize,
+    /// Values masked by the redaction policy
+    pub redacted: usize,
+    /// False when no environment could be read, normally another user's process without privileges
+    pub readable: bool,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_env` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessEnvPromptArgs {}
+
+/// Prompt provider for `process_env` tool
+pub struct ProcessEnvPrompts;
+
+impl SealedPromptProvider for ProcessEnvPrompts {}
+
+impl PromptProvider for ProcessEnvPrompts {
+    type PromptArgs = ProcessEnvPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("The worker daemon (PID 2210) keeps talking to the staging database. Why?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_env({\"pid\": 2210, \"keys\": [\"DATABASE_*\", \"PG*\", \"*_ENV\", \"CONFIG*\"]})\n\n\
+                     Shows the variables the daemon was started with, which are fixed at launch: \
+                     editing a .env file later does not change them. Secret values such as \
+                     passwords and tokens are masked. To compare with a process that behaves, \
+                     use process_env_diff.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessEnvArgs {
+    type Output = ProcessEnvOutput;
+    type Prompts = ProcessEnvPrompts;
+
+    const NAME: &'static str = PROCESS_ENV;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Read the environment variables of a process, optionally filtered by name, with secrets masked";
+}
@@ -0,0 +1,147 @@
+//! Schema types for process_env_diff tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for environment comparison
+pub const PROCESS_ENV_DIFF: &str = "process_env_diff";
+
+// ============================================================================
+// PROCESS ENV DIFF TOOL
+// ============================================================================
+
+/// Arguments for `process_env_diff` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessEnvDiffArgs {
+    /// Process ID of the first process (e.g. the one that works)
+    pub pid_a: u32,
+
+    /// Process ID of the second process
+    pub pid_b: u32,
+
+    /// Optional: also compare working directories (default: false)
+    #[serde(default)]
+    pub include_cwd: bool,
+
+    /// Optional: also compare resource limits (default: false, Linux only)
+    #[serde(default)]
+    pub include_limits: bool,
+
+    /// Optional: show secret values unmasked (only if the server's redaction policy allows it)
+    #[serde(default)]
+    pub reveal_secrets: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// One value that differs between the two processes
+///
+/// `None` means the process does not have it; secret values are masked but
+/// were compared unmasked.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ValueDifference {
+    /// Variable name or limit resource
+    pub key: String,
+    /// Value in process A
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub a: Option<String>,
+    /// Value in process B
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub b: Option<String>,
+}
+
+/// Output from `process_env_diff` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessEnvDiffOutput {
+    /// Whether the comparison ran
+    pub success: bool,
+    /// Process ID of A
+    pub pid_a: u32,
+    /// Process name of A
+    pub name_a: String,
+    /// Process ID of B
+    pub pid_b: u32,
+    /// Process name of B
+    pub name_b: String,
+    /// Variables with the same value in both
+    pub same: usize,
+    /// Variables set in only one process or set differently, sorted by name
+    pub env: Vec<ValueDifference>,
+    /// Working directories, when compared and different
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<ValueDifference>,
+    /// Resource limits that differ, as `soft / hard` (when compared)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub limits: Vec<ValueDifference>,
+    /// Values masked by the redaction policy
+    pub redacted: usize,
+    /// What could not be read, e.g. the environment of another user's process
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unavailable: Vec<String>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_env_diff` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessEnvDiffPromptArgs {}
+
+/// Prompt provider for `process_env_diff` tool
+pub struct ProcessEnvDiffPrompts;
+
+impl SealedPromptProvider for ProcessEnvDiffPrompts {}
+
+impl PromptProvider for ProcessEnvDiffPrompts {
+    type PromptArgs = ProcessEnvDiffPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text(
+                    "The build works in my terminal (PID 3100) but fails when the agent runs it (PID 3377). Why?",
+                ),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_env_diff({\"pid_a\": 3100, \"pid_b\": 3377, \"include_cwd\": true})\n\n\
+                     Only the variables that differ come back: missing in one process or set \
+                     to another value. PATH, locale and toolchain variables such as \
+                     RUSTUP_TOOLCHAIN are the usual suspects.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessEnvDiffArgs {
+    type Output = ProcessEnvDiffOutput;
+    type Prompts = ProcessEnvDiffPrompts;
+
+    const NAME: &'static str = PROCESS_ENV_DIFF;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Compare the environments of two processes and return only the variables that differ";
+}
@@ -0,0 +1,248 @@
+//! Schema types for process_forensics tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for forensic snapshots
+pub const PROCESS_FORENSICS: &str = "process_forensics";
+
+// ============================================================================
+// PROCESS FORENSICS TOOL
+// ============================================================================
+
+/// Arguments for `process_forensics` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessForensicsArgs {
+    /// Process ID to capture
+    pub pid: u32,
+
+    /// Optional: record environment values and command lines unmasked (only if the server's redaction policy allows it)
+    #[serde(default)]
+    pub reveal_secrets: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// One open file descriptor
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct OpenFile {
+    /// Descriptor number
+    pub fd: u32,
+    /// What it refers to: a path, or on Linux `socket:[inode]`, `pipe:[inode]` or `anon_inode:...`;
+    /// macOS and Windows name non-file descriptors by kind, such as `socket` or `pipe`
+    pub target: String,
+}
+
+/// One socket owned by the process
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SocketInfo {
+    /// Descriptor holding it (Linux, macOS; Windows sockets have none)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fd: Option<u32>,
+    /// `tcp`, `tcp6`, `udp`, `udp6` or `unix` (Linux)
+    pub protocol: String,
+    /// Local address as `ip:port`, or the path of a Unix socket (empty when unnamed)
+    pub local: String,
+    /// Peer address as `ip:port` (inet sockets)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<String>,
+    /// TCP state such as `LISTEN` or `ESTABLISHED`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// Kernel socket inode (Linux)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inode: Option<u64>,
+}
+
+/// One mapping of the address space
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MemoryMap {
+    /// `start-end` in hex
+    pub address: String,
+    /// Permissions such as `r-xp`
+    pub perms: String,
+    /// Length of the mapping
+    pub size_bytes: u64,
+    /// Backing file or pseudo-path such as `[heap]` (anonymous mappings have none)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+/// One thread of the process
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ThreadInfo {
+    /// Thread ID
+    pub tid: u32,
+    /// Thread name
+    pub name: String,
+    /// Scheduler state letter, e.g. `R`, `S` or `D`
+    pub state: String,
+}
+
+/// One namespace the process is in
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NamespaceInfo {
+    /// Namespace type, e.g. `net` or `pid`
+    pub kind: String,
+    /// Namespace identity, e.g. `net:[4026531840]`; equal values mean a shared namespace
+    pub id: String,
+}
+
+/// One resource limit
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ResourceLimit {
+    /// Limit name, e.g. `Max open files`
+    pub resource: String,
+    /// Soft limit (`unlimited` when unset)
+    pub soft: String,
+    /// Hard limit (`unlimited` when unset)
+    pub hard: String,
+    /// Unit such as `files` or `bytes`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub units: Option<String>,
+}
+
+/// Everything captured about one process, as written to the dump file
+///
+/// Sections the OS does not provide or the server may not read are `None` and
+/// named in `unavailable`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ForensicRecord {
+    /// Process ID
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Parent process ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<u32>,
+    /// Owner as `name (uid)` or a bare id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// Start time in seconds since the Unix epoch
+    pub start_time: u64,
+    /// Reuse-safe handle to pass as `proc_id` to mutating tools
+    pub proc_id: String,
+    /// When the snapshot was taken (RFC 3339)
+    pub captured_at: String,
+    /// Executable path
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exe: Option<String>,
+    /// Working directory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    /// Full command line, argv[0] first (secrets masked unless revealed)
+    pub cmdline: Vec<String>,
+    /// Environment as `KEY=VALUE` entries (secret values masked unless revealed)
+    pub env: Vec<String>,
+    /// CPU usage percent
+    pub cpu_percent: f32,
+    /// Resident memory
+    pub rss_bytes: u64,
+    /// Virtual memory
+    pub virtual_bytes: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_files: Option<Vec<OpenFile>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sockets: Option<Vec<SocketInfo>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_maps: Option<Vec<MemoryMap>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threads: Option<Vec<ThreadInfo>>,
+    /// Lines of `/proc/<pid>/cgroup`, e.g. `0::/user.slice/...`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cgroup: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespaces: Option<Vec<NamespaceInfo>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limits: Option<Vec<ResourceLimit>>,
+    /// Sections that could not be captured
+    pub unavailable: Vec<String>,
+    /// Number of masked secrets
+    pub redacted: usize,
+}
+
+/// Output from `process_forensics` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessForensicsOutput {
+    /// Whether the snapshot was written
+    pub success: bool,
+    /// Process ID captured
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// The JSON file holding the full [`ForensicRecord`]
+    pub path: String,
+    /// Size of that file
+    pub file_bytes: u64,
+    /// Entries per captured section, e.g. `{"open_files": 12, "threads": 4}`
+    pub counts: std::collections::BTreeMap<String, usize>,
+    /// Sections that could not be captured
+    pub unavailable: Vec<String>,
+    /// Number of masked secrets
+    pub redacted: usize,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_forensics` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessForensicsPromptArgs {}
+
+/// Prompt provider for `process_forensics` tool
+pub struct ProcessForensicsPrompts;
+
+impl SealedPromptProvider for ProcessForensicsPrompts {}
+
+impl PromptProvider for ProcessForensicsPrompts {
+    type PromptArgs = ProcessForensicsPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("PID 6631 is talking to an address I don't recognise. Kill it, but keep evidence."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_forensics({\"pid\": 6631})\n\n\
+                     The command line, environment (secrets masked), open files, sockets, memory \
+                     maps, threads, cgroup, namespaces and limits are written to a timestamped \
+                     JSON file whose path is returned. Once that file exists, \
+                     process_terminate({\"pid\": 6631}) can follow.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessForensicsArgs {
+    type Output = ProcessForensicsOutput;
+    type Prompts = ProcessForensicsPrompts;
+
+    const NAME: &'static str = PROCESS_FORENSICS;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Capture a full forensic record of one process to a timestamped JSON file";
+}
@@ -0,0 +1,152 @@
+//! Schema types for process_freeze tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for freezing a process tree
+pub const PROCESS_FREEZE: &str = "process_freeze";
+
+// ============================================================================
+// PROCESS FREEZE TOOL
+// ============================================================================
+
+/// How a tree is frozen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FreezeMethod {
+    /// `cgroup.freeze` of a cgroup v2 holding the tree, all members at once (Linux)
+    Cgroup,
+    /// SIGSTOP top-down, re-scanning for children forked meanwhile (Unix)
+    Signals,
+}
+
+impl FreezeMethod {
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Cgroup => "cgroup",
+            Self::Signals => "signals",
+        }
+    }
+}
+
+/// Arguments for `process_freeze` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessFreezeArgs {
+    /// Root of the tree to freeze (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: "cgroup" or "signals" (default: cgroup when available, else signals)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<FreezeMethod>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// A frozen process tree
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FreezeInfo {
+    /// Root PID
+    pub pid: u32,
+    /// Root process name
+    pub name: String,
+    /// Method used for the tree
+    pub method: FreezeMethod,
+    /// Every frozen PID, root included, sorted
+    pub members: Vec<u32>,
+    /// Members stopped with SIGSTOP: all of them with signals, and those living in another
+    /// cgroup with the cgroup method
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub signalled: Vec<u32>,
+    /// Cgroup directory holding the tree (cgroup method)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cgroup: Option<String>,
+    /// Whether the freeze was confirmed: `cgroup.events` reported it within a second and a
+    /// final re-scan found no running descendant
+    pub settled: bool,
+    /// When the tree was frozen (RFC 3339)
+    pub since: String,
+}
+
+/// Output from `process_freeze` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessFreezeOutput {
+    /// Whether the tree was frozen
+    pub success: bool,
+    /// The frozen tree
+    pub freeze: FreezeInfo,
+    /// Why the cgroup method was not used, when it was not requested explicitly
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fallback_reason: Option<String>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_freeze` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessFreezePromptArgs {}
+
+/// Prompt provider for `process_freeze` tool
+pub struct ProcessFreezePrompts;
+
+impl SealedPromptProvider for ProcessFreezePrompts {}
+
+impl PromptProvider for ProcessFreezePrompts {
+    type PromptArgs = ProcessFreezePromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("The build (PID 4410) keeps spawning compilers; hold everything while I look."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "Freeze the whole tree in one step:\n\n\
+                     process_freeze({\"pid\": 4410})\n\n\
+                     With cgroup v2 the tree moves into its own cgroup and cgroup.freeze stops \
+                     every member at once, so nothing can fork in between. Otherwise each process \
+                     gets SIGSTOP from the top down until no running child is left. Nothing is \
+                     lost; process_thaw resumes the tree where it stopped.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessFreezeArgs {
+    type Output = ProcessFreezeOutput;
+    type Prompts = ProcessFreezePrompts;
+
+    const NAME: &'static str = PROCESS_FREEZE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Freeze a whole process tree atomically via the cgroup v2 freezer, falling back to top-down SIGSTOP";
+}
@@ -0,0 +1,122 @@
+//! Schema types for process_group_define tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for defining named process groups
+pub const PROCESS_GROUP_DEFINE: &str = "process_group_define";
+
+// ============================================================================
+// PROCESS GROUP DEFINE TOOL
+// ============================================================================
+
+/// Arguments for `process_group_define` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessGroupDefineArgs {
+    /// Group name, e.g. "frontend"
+    pub name: String,
+
+    /// Optional: case-insensitive globs matched against process names, e.g. ["vite", "node", "esbuild*"]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub patterns: Vec<String>,
+
+    /// Optional: explicit member PIDs (bound to the current process, not reused PIDs)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pids: Vec<u32>,
+
+    /// Optional: explicit members by reuse-safe handle from process_list
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub proc_ids: Vec<String>,
+
+    /// Delete the group instead of defining it
+    #[serde(default)]
+    pub delete: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_group_define` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessGroupDefineOutput {
+    /// Whether the operation succeeded
+    pub success: bool,
+    /// Group name
+    pub name: String,
+    /// Whether the group was deleted
+    pub deleted: bool,
+    /// Name patterns of the group
+    pub patterns: Vec<String>,
+    /// Explicit member PIDs that were found running
+    pub pids: Vec<u32>,
+    /// Live members right now (pattern matches and explicit PIDs)
+    pub members: Vec<u32>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_group_define` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessGroupDefinePromptArgs {}
+
+/// Prompt provider for `process_group_define` tool
+pub struct ProcessGroupDefinePrompts;
+
+impl SealedPromptProvider for ProcessGroupDefinePrompts {}
+
+impl PromptProvider for ProcessGroupDefinePrompts {
+    type PromptArgs = ProcessGroupDefinePromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("How do I clean up all my frontend dev processes in one call?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "Define a named group once:\n\n\
+                     process_group_define({\"name\": \"frontend\", \"patterns\": [\"vite\", \"node\", \"esbuild\"]})\n\n\
+                     Then operate on the name:\n\
+                     - process_list({\"group\": \"frontend\"}) lists members\n\
+                     - process_groups({}) shows member counts, CPU and memory per group\n\
+                     - process_group_kill({\"group\": \"frontend\", \"dry_run\": true}) previews, \
+                     then without dry_run sends the signal to every member\n\n\
+                     Patterns are case-insensitive globs on the process name, so \"node\" does \
+                     not match \"nodemon\" but \"node*\" does.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessGroupDefineArgs {
+    type Output = ProcessGroupDefineOutput;
+    type Prompts = ProcessGroupDefinePrompts;
+
+    const NAME: &'static str = PROCESS_GROUP_DEFINE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Define (or delete) a named process group from name globs and explicit PIDs for use by list/kill/stats tools";
+}
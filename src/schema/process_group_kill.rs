@@ -0,0 +1,132 @@
+//! Schema types for process_group_kill tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{KillBudget, ProcessSignal, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for signalling every member of a named group
+pub const PROCESS_GROUP_KILL: &str = "process_group_kill";
+
+// ============================================================================
+// PROCESS GROUP KILL TOOL
+// ============================================================================
+
+/// Arguments for `process_group_kill` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessGroupKillArgs {
+    /// Group name defined with `process_group_define`
+    pub group: String,
+
+    /// Optional: signal to send (default: "kill")
+    #[serde(default)]
+    pub signal: ProcessSignal,
+
+    /// Only report which processes would be signalled
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// Also signal members that need confirmation; set only after the user agreed
+    #[serde(default)]
+    pub confirm: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// A group member that was not signalled
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SkippedProcess {
+    /// Process ID
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Why it was skipped
+    pub reason: String,
+}
+
+/// Output from `process_group_kill` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessGroupKillOutput {
+    /// Whether the operation completed (some members may still be skipped)
+    pub success: bool,
+    /// Group name
+    pub group: String,
+    /// Signal name, e.g. "SIGKILL"
+    pub signal: String,
+    /// Whether this was a preview only
+    pub dry_run: bool,
+    /// Members that were signalled (or would be, on a dry run)
+    pub signalled: Vec<u32>,
+    /// Members gone by their turn, so there was nothing left to signal
+    #[serde(default)]
+    pub exited: Vec<u32>,
+    /// Members left alone, with reasons
+    pub skipped: Vec<SkippedProcess>,
+    /// Kill budget left after this operation
+    pub budget: KillBudget,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_group_kill` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessGroupKillPromptArgs {}
+
+/// Prompt provider for `process_group_kill` tool
+pub struct ProcessGroupKillPrompts;
+
+impl SealedPromptProvider for ProcessGroupKillPrompts {}
+
+impl PromptProvider for ProcessGroupKillPrompts {
+    type PromptArgs = ProcessGroupKillPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Stop everything in the frontend group."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "Preview first:\n\n\
+                     process_group_kill({\"group\": \"frontend\", \"dry_run\": true})\n\n\
+                     Then ask politely before forcing:\n\n\
+                     process_group_kill({\"group\": \"frontend\", \"signal\": \"term\"})\n\n\
+                     Every signalled member consumes kill budget. Protected processes, the \
+                     server itself and processes tagged do-not-kill are skipped and listed in \
+                     skipped with the reason.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessGroupKillArgs {
+    type Output = ProcessGroupKillOutput;
+    type Prompts = ProcessGroupKillPrompts;
+
+    const NAME: &'static str = PROCESS_GROUP_KILL;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Send a signal (default SIGKILL) to every live member of a named process group, skipping protected processes";
+}
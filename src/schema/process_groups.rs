@@ -0,0 +1,112 @@
+//! Schema types for process_groups tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for listing named process groups with stats
+pub const PROCESS_GROUPS: &str = "process_groups";
+
+// ============================================================================
+// PROCESS GROUPS TOOL
+// ============================================================================
+
+/// Arguments for `process_groups` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessGroupsArgs {
+    /// Optional: only this group
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// A named group with aggregate stats over its live members
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessGroupInfo {
+    /// Group name
+    pub name: String,
+    /// Name patterns
+    pub patterns: Vec<String>,
+    /// Explicit member PIDs
+    pub pids: Vec<u32>,
+    /// Live members right now
+    pub members: Vec<u32>,
+    /// Sum of member CPU usage percentages
+    pub cpu_percent: f32,
+    /// Sum of member resident memory in bytes (shared pages counted per member)
+    pub rss_bytes: u64,
+}
+
+/// Output from `process_groups` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessGroupsOutput {
+    /// Whether the query succeeded
+    pub success: bool,
+    /// Number of groups returned
+    pub count: usize,
+    /// Groups sorted by name
+    pub groups: Vec<ProcessGroupInfo>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_groups` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessGroupsPromptArgs {}
+
+/// Prompt provider for `process_groups` tool
+pub struct ProcessGroupsPrompts;
+
+impl SealedPromptProvider for ProcessGroupsPrompts {}
+
+impl PromptProvider for ProcessGroupsPrompts {
+    type PromptArgs = ProcessGroupsPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("How much CPU and memory is my frontend group using?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_groups({\"name\": \"frontend\"}) returns the group's live members \
+                     with summed cpu_percent and rss_bytes. Without a name every defined group \
+                     is listed.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessGroupsArgs {
+    type Output = ProcessGroupsOutput;
+    type Prompts = ProcessGroupsPrompts;
+
+    const NAME: &'static str = PROCESS_GROUPS;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "List named process groups with live members and aggregate CPU/memory stats";
+}
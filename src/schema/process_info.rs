@@ -0,0 +1,213 @@
+//! Schema types for process_info tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{CpuLimitInfo, SeccompMode, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for detailed single-process inspection
+pub const PROCESS_INFO: &str = "process_info";
+
+// ============================================================================
+// PROCESS INFO TOOL
+// ============================================================================
+
+/// Arguments for `process_info` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessInfoArgs {
+    /// Process ID to inspect (or give `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: inspect these PIDs in one pass instead of `pid` (those without a process come back in `missing`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pids: Vec<u32>,
+
+    /// Optional: return the command line unmasked (only if the server's redaction policy allows it)
+    #[serde(default)]
+    pub reveal_secrets: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Memory of one process
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessMemory {
+    /// Resident set size in bytes
+    pub rss_bytes: u64,
+    /// Virtual address space in bytes
+    pub virtual_bytes: u64,
+    /// Memory no other process shares: USS on Linux, private bytes on Windows, footprint on macOS
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private_bytes: Option<u64>,
+    /// Resident anonymous memory, heap and stacks (Linux)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anon_bytes: Option<u64>,
+    /// Resident file-backed memory, code and mapped files (Linux)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_bytes: Option<u64>,
+    /// Resident shared memory, tmpfs and shm segments (Linux)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shmem_bytes: Option<u64>,
+    /// Swapped out in bytes (Linux)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub swap_bytes: Option<u64>,
+}
+
+/// Details of one process
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessDetails {
+    /// Process ID
+    pub pid: u32,
+    /// Reuse-safe handle for mutating tools
+    pub proc_id: String,
+    /// Process name
+    pub name: String,
+    /// Parent process ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<u32>,
+    /// Scheduler state as the OS reports it, e.g. `Runnable`, `Sleeping`, `Stopped` or `Zombie`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Owner as `name (uid)` or a bare id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// Command line, secrets masked per the server's redaction policy
+    pub cmdline: Vec<String>,
+    /// Executable path
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exe: Option<String>,
+    /// Working directory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    /// Start time in seconds since the Unix epoch
+    pub start_time: u64,
+    /// Start time as RFC 3339
+    pub started_at: String,
+    /// Seconds since the process started
+    pub age_secs: u64,
+    /// User plus system CPU time over the process's lifetime, in seconds
+    pub cpu_time_secs: f64,
+    /// Thread count (Linux)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threads: Option<usize>,
+    /// Memory breakdown
+    pub memory: ProcessMemory,
+    /// Controlling terminal such as `pts/3`, or `none`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tty: Option<String>,
+    /// Cgroup path in the unified or systemd hierarchy (Linux)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cgroup: Option<String>,
+    /// Security context, e.g. `system_u:system_r:httpd_t:s0` or `unconfined` (Linux)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_label: Option<String>,
+    /// Seccomp mode (`disabled`, `strict` or `filter`, Linux)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seccomp: Option<SeccompMode>,
+    /// Mandatory integrity level (`Low`, `Medium`, `High`, `System`, ..., Windows)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrity_level: Option<String>,
+    /// Runs inside an AppContainer sandbox (Windows)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_container: Option<bool>,
+    /// CPU cap set with `process_cpu_limit`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_limit: Option<CpuLimitInfo>,
+}
+
+/// Output from `process_info` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessInfoOutput {
+    /// Whether the process was found (with `pids`: whether the lookup ran)
+    pub success: bool,
+    /// The process given by `pid` or `proc_id`
+    #[serde(flatten)]
+    pub process: Option<ProcessDetails>,
+    /// Processes found for `pids`, in request order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub processes: Vec<ProcessDetails>,
+    /// Requested `pids` that no process has
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing: Vec<u32>,
+    /// Command line arguments masked
+    pub redacted: usize,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_info` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessInfoPromptArgs {}
+
+/// Prompt provider for `process_info` tool
+pub struct ProcessInfoPrompts;
+
+impl SealedPromptProvider for ProcessInfoPrompts {}
+
+impl PromptProvider for ProcessInfoPrompts {
+    type PromptArgs = ProcessInfoPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Why is PID 4242 using so much memory, and what exactly is it?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_info({\"pid\": 4242})\n\n\
+                     Returns its command line, executable, working directory, owner, start time, \
+                     state and thread count, the CPU time it used so far, and memory split into \
+                     resident, virtual, private, anonymous, file-backed, shared and swapped.",
+                ),
+            },
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Show me the details of every process in that tree: 4242, 4250, 4251 and 4263."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_info({\"pids\": [4242, 4250, 4251, 4263]})\n\n\
+                     Reads all four in one pass and returns their details in processes; any that \
+                     already exited are listed in missing.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessInfoArgs {
+    type Output = ProcessInfoOutput;
+    type Prompts = ProcessInfoPrompts;
+
+    const NAME: &'static str = PROCESS_INFO;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Show full details of one process, or of a list of PIDs in one pass: command line, paths, owner, state, threads, CPU time and memory";
+}
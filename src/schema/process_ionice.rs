@@ -0,0 +1,115 @@
+//! Schema types for process_ionice tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{IoClass, IoPriority, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for reading or setting a process's I/O scheduling
+pub const PROCESS_IONICE: &str = "process_ionice";
+
+// ============================================================================
+// PROCESS IONICE TOOL
+// ============================================================================
+
+/// Arguments for `process_ionice` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessIoniceArgs {
+    /// Process ID (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: class to set ("realtime", "best-effort", "idle" or "none"); reads only when omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub class: Option<IoClass>,
+
+    /// Optional: level 0 (highest) to 7 within realtime or best-effort (default: 4)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<u8>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_ionice` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessIoniceOutput {
+    /// Whether the call succeeded
+    pub success: bool,
+    /// Process ID
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// I/O scheduling before the call
+    pub before: IoPriority,
+    /// I/O scheduling now in effect
+    pub io_priority: IoPriority,
+    /// Whether a new value was set
+    pub changed: bool,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_ionice` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessIonicePromptArgs {}
+
+/// Prompt provider for `process_ionice` tool
+pub struct ProcessIonicePrompts;
+
+impl SealedPromptProvider for ProcessIonicePrompts {}
+
+impl PromptProvider for ProcessIonicePrompts {
+    type PromptArgs = ProcessIonicePromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("The backup job (PID 5120) makes everything else slow."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "Move it to the idle I/O class so it only uses the disk when nothing else does:\n\n\
+                     process_ionice({\"pid\": 5120, \"class\": \"idle\"})\n\n\
+                     Call with just the pid to read the current class. best-effort takes a level \
+                     from 0 (highest) to 7; realtime needs CAP_SYS_ADMIN. To start a command this \
+                     way, pass io_priority to process_run. Linux only.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessIoniceArgs {
+    type Output = ProcessIoniceOutput;
+    type Prompts = ProcessIonicePrompts;
+
+    const NAME: &'static str = PROCESS_IONICE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Read or set the I/O scheduling class and level of a process (Linux), to deprioritize disk-heavy jobs instead of killing them";
+}
@@ -0,0 +1,89 @@
+//! Schema types for process_kill tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::process::{PROCESS_KILL, ProcessKillPrompts};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{ContainerDelivery, KillBudget, SummaryStyle, SummaryVerbosity};
+
+// ============================================================================
+// PROCESS KILL TOOL
+// ============================================================================
+
+/// Arguments for `process_kill` tool
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillArgs {
+    /// Process ID to terminate (or use `pidfile`, `proc_id` or `container`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: read the PID from this pidfile instead; refused when the pidfile is stale
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pidfile: Option<String>,
+
+    /// Optional: with `pidfile`, the process name the PID must belong to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_name: Option<String>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: `<container_id>:<pid>` with the PID as the container sees it; the signal is
+    /// delivered through the container runtime (docker, podman, nerdctl) or nsenter (Linux)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+
+    /// Optional: start time from process_list; the kill is refused if the PID now belongs to a newer process
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+
+    /// Set only after the user agreed, when a previous call said the kill needs confirmation
+    #[serde(default)]
+    pub confirm: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_kill` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillOutput {
+    /// Whether the kill operation succeeded
+    pub success: bool,
+    /// Process ID that was targeted
+    pub pid: u32,
+    /// Human-readable result message
+    pub message: String,
+    /// Kill budget left after this operation
+    pub budget: KillBudget,
+    /// Pidfile the PID was read from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pidfile: Option<String>,
+    /// Id of the snapshot stored for `process_recently_killed`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_id: Option<u64>,
+    /// Container the process ran in, for `container` targets
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<ContainerDelivery>,
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessKillArgs {
+    type Output = ProcessKillOutput;
+    type Prompts = ProcessKillPrompts;
+
+    const NAME: &'static str = PROCESS_KILL;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Terminate a process by PID. Sends SIGKILL signal - the process cannot catch or ignore it. Use with caution";
+}
@@ -0,0 +1,122 @@
+//! Schema types for process_kill_by_name tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{KillBudget, ProcessSignal, SignalResult, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for signalling every process whose name matches a pattern
+pub const PROCESS_KILL_BY_NAME: &str = "process_kill_by_name";
+
+// ============================================================================
+// PROCESS KILL BY NAME TOOL
+// ============================================================================
+
+/// Arguments for `process_kill_by_name` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillByNameArgs {
+    /// Process name or case-insensitive glob, e.g. "node" or "esbuild*"
+    pub pattern: String,
+
+    /// Optional: signal to send (default: "kill")
+    #[serde(default)]
+    pub signal: ProcessSignal,
+
+    /// Only match processes launched through this server or descending from such a launch
+    #[serde(default)]
+    pub launched_only: bool,
+
+    /// Only report which processes would be signalled
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// Also signal processes that need confirmation; set only after the user agreed
+    #[serde(default)]
+    pub confirm: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_kill_by_name` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillByNameOutput {
+    /// Whether at least one match was signalled and none was skipped
+    pub success: bool,
+    /// Pattern that was matched
+    pub pattern: String,
+    /// Signal name, e.g. "SIGKILL"
+    pub signal: String,
+    /// Whether this was a preview only
+    pub dry_run: bool,
+    /// Number of processes signalled (or that would be, on a dry run)
+    pub signalled: usize,
+    /// One entry per matching process, sorted by PID
+    pub results: Vec<SignalResult>,
+    /// Kill budget left after this operation
+    pub budget: KillBudget,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_kill_by_name` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillByNamePromptArgs {}
+
+/// Prompt provider for `process_kill_by_name` tool
+pub struct ProcessKillByNamePrompts;
+
+impl SealedPromptProvider for ProcessKillByNamePrompts {}
+
+impl PromptProvider for ProcessKillByNamePrompts {
+    type PromptArgs = ProcessKillByNamePromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Kill all the node processes my test run left behind."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "Preview first:\n\n\
+                     process_kill_by_name({\"pattern\": \"node\", \"launched_only\": true, \"dry_run\": true})\n\n\
+                     Then ask politely:\n\n\
+                     process_kill_by_name({\"pattern\": \"node\", \"launched_only\": true, \"signal\": \"term\"})\n\n\
+                     launched_only keeps node processes the user started elsewhere out of it. \
+                     results lists every match with signalled and, for skipped ones, the reason. \
+                     Each signalled process consumes kill budget.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessKillByNameArgs {
+    type Output = ProcessKillByNameOutput;
+    type Prompts = ProcessKillByNamePrompts;
+
+    const NAME: &'static str = PROCESS_KILL_BY_NAME;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Send a signal (default SIGKILL) to every process whose name matches a glob, with a result per PID";
+}
@@ -0,0 +1,182 @@
+//! Schema types for process_kill_if_idle tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{EscalationStep, ProcessTerminateOutput, SummaryStyle, SummaryVerbosity, default_escalation};
+
+/// Tool name for killing a process only when it is idle
+pub const PROCESS_KILL_IF_IDLE: &str = "process_kill_if_idle";
+
+/// Longest observation window accepted
+pub const MAX_IDLE_WINDOW_SECS: u64 = 600;
+
+fn default_cpu_below() -> f32 {
+    1.0
+}
+
+fn default_window_secs() -> u64 {
+    30
+}
+
+fn default_children() -> bool {
+    true
+}
+
+// ============================================================================
+// PROCESS KILL IF IDLE TOOL
+// ============================================================================
+
+/// Arguments for `process_kill_if_idle` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillIfIdleArgs {
+    /// Process ID to kill if idle (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// CPU percent (100 = one core) that every sample must stay below (default: 1.0)
+    #[serde(default = "default_cpu_below")]
+    pub cpu_below: f32,
+
+    /// Seconds to observe before deciding, sampled once per second (default: 30, max: 600)
+    #[serde(default = "default_window_secs")]
+    pub window_secs: u64,
+
+    /// Count descendants: their CPU adds to the target's, and a child starting or exiting
+    /// counts as activity (default: true)
+    #[serde(default = "default_children")]
+    pub children: bool,
+
+    /// Optional: escalation plan run when idle, as for `process_terminate` (default: term for
+    /// 5 s, then kill)
+    #[serde(default = "default_escalation")]
+    pub plan: Vec<EscalationStep>,
+
+    /// Set only after the user agreed, when a previous call said the kill needs confirmation
+    #[serde(default)]
+    pub confirm: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// What the observation found
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IdleVerdict {
+    /// Stayed below the threshold for the whole window; the plan ran
+    Idle,
+    /// A sample reached the threshold; not killed
+    Active,
+    /// A descendant started or exited; not killed
+    ChildActivity,
+    /// The process exited during the window
+    Exited,
+}
+
+impl IdleVerdict {
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Idle => "idle",
+            Self::Active => "active",
+            Self::ChildActivity => "child activity",
+            Self::Exited => "exited",
+        }
+    }
+}
+
+/// Output from `process_kill_if_idle` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillIfIdleOutput {
+    /// Whether the observation completed (also when the process was found active)
+    pub success: bool,
+    /// Process ID that was observed
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Whether the process was idle and is now gone
+    pub killed: bool,
+    pub verdict: IdleVerdict,
+    /// Why, e.g. "12.5% CPU at 4 s, threshold 1%"
+    pub reason: String,
+    /// Seconds observed before deciding
+    pub observed_secs: f64,
+    /// CPU samples taken
+    pub samples: usize,
+    /// Highest CPU percent seen (target plus descendants with `children`)
+    pub peak_cpu_percent: f32,
+    /// Mean CPU percent over the samples
+    pub mean_cpu_percent: f32,
+    /// Descendants at the start of the window (with `children`)
+    pub descendants: usize,
+    /// The termination that ran, when idle
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminate: Option<ProcessTerminateOutput>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_kill_if_idle` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillIfIdlePromptArgs {}
+
+/// Prompt provider for `process_kill_if_idle` tool
+pub struct ProcessKillIfIdlePrompts;
+
+impl SealedPromptProvider for ProcessKillIfIdlePrompts {}
+
+impl PromptProvider for ProcessKillIfIdlePrompts {
+    type PromptArgs = ProcessKillIfIdlePromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("There's an old dev server on PID 5120. Stop it if nobody is using it."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_kill_if_idle({\"pid\": 5120, \"cpu_below\": 1.0, \"window_secs\": 60})\n\n\
+                     For a minute the server and its children are sampled every second. Only if \
+                     CPU stays below 1% and no child starts or exits is it terminated (SIGTERM, \
+                     then SIGKILL); otherwise the answer is \"still active, not killed\" with the \
+                     sample that showed activity.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessKillIfIdleArgs {
+    type Output = ProcessKillIfIdleOutput;
+    type Prompts = ProcessKillIfIdlePrompts;
+
+    const NAME: &'static str = PROCESS_KILL_IF_IDLE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Terminate a process only if its CPU stays below a threshold for an observation window";
+}
@@ -0,0 +1,182 @@
+//! Schema types for process_kill_schedule tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{EscalationStep, SummaryStyle, SummaryVerbosity, default_escalation};
+
+/// Tool name for scheduling a kill
+pub const PROCESS_KILL_SCHEDULE: &str = "process_kill_schedule";
+
+/// Longest delay accepted for a scheduled kill (seven days)
+pub const MAX_SCHEDULE_DELAY_SECS: u64 = 7 * 24 * 60 * 60;
+
+// ============================================================================
+// PROCESS KILL SCHEDULE TOOL
+// ============================================================================
+
+/// Arguments for `process_kill_schedule` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillScheduleArgs {
+    /// Process ID to kill (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Kill after this many seconds (give this or `at`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delay_secs: Option<u64>,
+
+    /// Kill at this time, RFC 3339 such as "2025-06-01T18:30:00Z" (give this or `delay_secs`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub at: Option<String>,
+
+    /// Optional: escalation plan run when due, as for `process_terminate` (default: term for 5 s,
+    /// then kill)
+    #[serde(default = "default_escalation")]
+    pub plan: Vec<EscalationStep>,
+
+    /// Optional: why the kill was scheduled, shown when listing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+
+    /// Set only after the user agreed, when a previous call said the kill needs confirmation
+    #[serde(default)]
+    pub confirm: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// State of a scheduled kill
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduleStatus {
+    /// Waiting for its due time
+    Pending,
+    /// The process was terminated
+    Done,
+    /// The plan ran but the process is still alive
+    Survived,
+    /// The process exited (or its PID was reused) before the due time
+    Gone,
+    /// Refused when due, e.g. by the kill budget or a do-not-kill tag
+    Failed,
+    /// Cancelled with `process_kill_unschedule`
+    Cancelled,
+}
+
+impl ScheduleStatus {
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Done => "done",
+            Self::Survived => "survived",
+            Self::Gone => "gone",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+}
+
+/// A kill scheduled for later
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ScheduledKill {
+    /// Id for `process_kill_unschedule`
+    pub id: u64,
+    /// Target PID
+    pub pid: u32,
+    /// Target process name
+    pub name: String,
+    /// Escalation plan run when due
+    pub plan: Vec<EscalationStep>,
+    /// Why the kill was scheduled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// When it was scheduled (RFC 3339)
+    pub scheduled_at: String,
+    /// When it is due (RFC 3339)
+    pub due_at: String,
+    pub status: ScheduleStatus,
+    /// What happened when it ran, e.g. "terminated by SIGTERM"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outcome: Option<String>,
+    /// When it ran or was cancelled (RFC 3339)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<String>,
+}
+
+/// Output from `process_kill_schedule` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillScheduleOutput {
+    /// Whether the kill was scheduled
+    pub success: bool,
+    /// The scheduled kill
+    pub kill: ScheduledKill,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_kill_schedule` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillSchedulePromptArgs {}
+
+/// Prompt provider for `process_kill_schedule` tool
+pub struct ProcessKillSchedulePrompts;
+
+impl SealedPromptProvider for ProcessKillSchedulePrompts {}
+
+impl PromptProvider for ProcessKillSchedulePrompts {
+    type PromptArgs = ProcessKillSchedulePromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Let the batch job (PID 1234) finish its current chunk, then stop it in 30 minutes."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_kill_schedule({\"pid\": 1234, \"delay_secs\": 1800, \"note\": \"stop after current chunk\"})\n\n\
+                     In 30 minutes the job gets SIGTERM and, if still running 5 s later, SIGKILL. \
+                     process_kill_schedules shows what is pending, and process_kill_unschedule \
+                     with the returned id calls it off. If the job exits by itself first, nothing \
+                     is sent, even if its PID is reused.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessKillScheduleArgs {
+    type Output = ProcessKillScheduleOutput;
+    type Prompts = ProcessKillSchedulePrompts;
+
+    const NAME: &'static str = PROCESS_KILL_SCHEDULE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Schedule a graceful kill for a later time or after a delay, cancellable until it runs";
+}
@@ -0,0 +1,91 @@
+//! Schema types for process_kill_schedules tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{ScheduledKill, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for listing scheduled kills
+pub const PROCESS_KILL_SCHEDULES: &str = "process_kill_schedules";
+
+// ============================================================================
+// PROCESS KILL SCHEDULES TOOL
+// ============================================================================
+
+/// Arguments for `process_kill_schedules` tool
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillSchedulesArgs {
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_kill_schedules` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillSchedulesOutput {
+    /// Whether the listing succeeded
+    pub success: bool,
+    /// Kills waiting for their due time, soonest first
+    pub pending: Vec<ScheduledKill>,
+    /// Recently run, skipped or cancelled kills, newest first
+    pub recent: Vec<ScheduledKill>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_kill_schedules` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillSchedulesPromptArgs {}
+
+/// Prompt provider for `process_kill_schedules` tool
+pub struct ProcessKillSchedulesPrompts;
+
+impl SealedPromptProvider for ProcessKillSchedulesPrompts {}
+
+impl PromptProvider for ProcessKillSchedulesPrompts {
+    type PromptArgs = ProcessKillSchedulesPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Is anything set to be killed later?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_kill_schedules({})\n\n\
+                     `pending` lists what is still waiting, soonest first; `recent` shows how \
+                     the last ones ended (done, survived, gone, failed or cancelled).",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessKillSchedulesArgs {
+    type Output = ProcessKillSchedulesOutput;
+    type Prompts = ProcessKillSchedulesPrompts;
+
+    const NAME: &'static str = PROCESS_KILL_SCHEDULES;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "List pending and recently finished scheduled kills";
+}
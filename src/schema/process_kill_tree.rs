@@ -0,0 +1,153 @@
+//! Schema types for process_kill_tree tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{KillBudget, ProcessSignal, SignalResult, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for signalling a process and all its descendants
+pub const PROCESS_KILL_TREE: &str = "process_kill_tree";
+
+// ============================================================================
+// PROCESS KILL TREE TOOL
+// ============================================================================
+
+/// Order in which the members of a tree are signalled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TreeKillOrder {
+    /// Deepest descendants first and the root last, so no parent sees its children die
+    #[default]
+    ChildrenFirst,
+    /// The root first, so it cannot start replacements for children as they die
+    ParentFirst,
+}
+
+impl TreeKillOrder {
+    /// Name as accepted by the `order` argument
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::ChildrenFirst => "children_first",
+            Self::ParentFirst => "parent_first",
+        }
+    }
+}
+
+/// Arguments for `process_kill_tree` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillTreeArgs {
+    /// Root of the tree to signal (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: signal to send (default: "kill")
+    #[serde(default)]
+    pub signal: ProcessSignal,
+
+    /// Optional: "children_first" or "parent_first" (default: children_first)
+    #[serde(default)]
+    pub order: TreeKillOrder,
+
+    /// Only report which processes would be signalled
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// Also signal members that need confirmation; set only after the user agreed
+    #[serde(default)]
+    pub confirm: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_kill_tree` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillTreeOutput {
+    /// Whether every member was signalled (or would be, on a dry run) or had exited by its turn
+    pub success: bool,
+    /// Root of the tree
+    pub pid: u32,
+    /// Name of the root
+    pub name: String,
+    /// Signal name, e.g. "SIGKILL"
+    pub signal: String,
+    /// Order the members were signalled in
+    pub order: TreeKillOrder,
+    /// Whether this was a preview only
+    pub dry_run: bool,
+    /// Number of members signalled (or that would be, on a dry run)
+    pub signalled: usize,
+    /// One entry per member, root included, in the order they were signalled
+    pub results: Vec<SignalResult>,
+    /// Kill budget left after this operation
+    pub budget: KillBudget,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_kill_tree` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillTreePromptArgs {}
+
+/// Prompt provider for `process_kill_tree` tool
+pub struct ProcessKillTreePrompts;
+
+impl SealedPromptProvider for ProcessKillTreePrompts {}
+
+impl PromptProvider for ProcessKillTreePrompts {
+    type PromptArgs = ProcessKillTreePromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Kill the dev server at PID 6400 and everything it started."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_kill_tree({\"pid\": 6400, \"dry_run\": true})\n\n\
+                     lists the tree first. Then:\n\n\
+                     process_kill_tree({\"pid\": 6400})\n\n\
+                     kills the deepest children first and PID 6400 last, so no grandchild is left \
+                     behind as an orphan. Each member consumes kill budget; results reports every \
+                     member, with the reason for any that were skipped. Use order \"parent_first\" \
+                     for a supervisor that restarts children it sees exit.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessKillTreeArgs {
+    type Output = ProcessKillTreeOutput;
+    type Prompts = ProcessKillTreePrompts;
+
+    const NAME: &'static str = PROCESS_KILL_TREE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Send a signal (default SIGKILL) to a process and all its descendants, children first by default";
+}
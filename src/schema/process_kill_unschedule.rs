@@ -0,0 +1,91 @@
+//! Schema types for process_kill_unschedule tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{ScheduledKill, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for cancelling a scheduled kill
+pub const PROCESS_KILL_UNSCHEDULE: &str = "process_kill_unschedule";
+
+// ============================================================================
+// PROCESS KILL UNSCHEDULE TOOL
+// ============================================================================
+
+/// Arguments for `process_kill_unschedule` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillUnscheduleArgs {
+    /// Id returned by `process_kill_schedule`
+    pub id: u64,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_kill_unschedule` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillUnscheduleOutput {
+    /// Whether the kill was cancelled
+    pub success: bool,
+    /// The cancelled kill
+    pub kill: ScheduledKill,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_kill_unschedule` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessKillUnschedulePromptArgs {}
+
+/// Prompt provider for `process_kill_unschedule` tool
+pub struct ProcessKillUnschedulePrompts;
+
+impl SealedPromptProvider for ProcessKillUnschedulePrompts {}
+
+impl PromptProvider for ProcessKillUnschedulePrompts {
+    type PromptArgs = ProcessKillUnschedulePromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("The batch job needs longer; don't stop it."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_kill_unschedule({\"id\": 3})\n\n\
+                     The pending kill is called off and nothing is sent to the process.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessKillUnscheduleArgs {
+    type Output = ProcessKillUnscheduleOutput;
+    type Prompts = ProcessKillUnschedulePrompts;
+
+    const NAME: &'static str = PROCESS_KILL_UNSCHEDULE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Cancel a pending scheduled kill";
+}
@@ -0,0 +1,194 @@
+//! Schema types for process_leaks tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for memory leak detection
+pub const PROCESS_LEAKS: &str = "process_leaks";
+
+fn default_window_secs() -> u64 {
+    600
+}
+
+fn default_min_samples() -> usize {
+    6
+}
+
+fn default_min_growth_bytes() -> u64 {
+    1024 * 1024
+}
+
+fn default_tolerance_percent() -> f64 {
+    1.0
+}
+
+fn default_limit() -> usize {
+    10
+}
+
+// ============================================================================
+// PROCESS LEAKS TOOL
+// ============================================================================
+
+/// Arguments for `process_leaks` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessLeaksArgs {
+    /// Optional: filter by name (case-insensitive substring match)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+
+    /// Optional: only these PIDs
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pids: Vec<u32>,
+
+    /// Seconds of history to examine, up to the server's retention (default: 600)
+    #[serde(default = "default_window_secs")]
+    pub window_secs: u64,
+
+    /// Samples a process needs inside the window to be judged (default: 6)
+    #[serde(default = "default_min_samples")]
+    pub min_samples: usize,
+
+    /// Growth from first to last sample below which a process is not flagged (default: 1 MiB)
+    #[serde(default = "default_min_growth_bytes")]
+    pub min_growth_bytes: u64,
+
+    /// Drop between consecutive samples, in percent, still counted as growing (default: 1.0)
+    #[serde(default = "default_tolerance_percent")]
+    pub tolerance_percent: f64,
+
+    /// Maximum suspects returned, fastest growing first (default: 10, 0 = all)
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+impl Default for ProcessLeaksArgs {
+    fn default() -> Self {
+        Self {
+            filter: None,
+            pids: Vec::new(),
+            window_secs: default_window_secs(),
+            min_samples: default_min_samples(),
+            min_growth_bytes: default_min_growth_bytes(),
+            tolerance_percent: default_tolerance_percent(),
+            limit: default_limit(),
+            style: None,
+            verbosity: None,
+        }
+    }
+}
+
+/// A process whose resident memory kept growing over the window
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LeakSuspect {
+    /// Process ID
+    pub pid: u32,
+    /// Reuse-safe handle to pass as `proc_id` to mutating tools
+    pub proc_id: String,
+    /// Process name
+    pub name: String,
+    /// Samples inside the window
+    pub samples: usize,
+    /// Seconds between the first and last sample
+    pub observed_secs: f64,
+    /// Resident memory at the first sample
+    pub first_rss_bytes: u64,
+    /// Resident memory at the last sample
+    pub last_rss_bytes: u64,
+    /// Last minus first
+    pub growth_bytes: u64,
+    /// Least-squares slope of resident memory over time
+    pub slope_bytes_per_sec: f64,
+    /// How well a straight line fits the samples (1.0 = perfectly linear)
+    pub r_squared: f64,
+    /// Seconds until the growth would use up the memory available now, at the fitted slope
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_to_oom_secs: Option<f64>,
+}
+
+/// Output from `process_leaks` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessLeaksOutput {
+    /// Whether the analysis ran
+    pub success: bool,
+    /// Window examined
+    pub window_secs: u64,
+    /// Seconds of history recorded so far (less than the window shortly after startup)
+    pub history_secs: f64,
+    /// Seconds between history samples
+    pub interval_secs: u64,
+    /// Processes with at least `min_samples` samples in the window
+    pub examined: usize,
+    /// System memory available at the last sample
+    pub available_memory_bytes: u64,
+    /// Processes flagged as growing, fastest first
+    pub suspects: Vec<LeakSuspect>,
+    /// Whether `limit` left suspects out
+    pub truncated: bool,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_leaks` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessLeaksPromptArgs {}
+
+/// Prompt provider for `process_leaks` tool
+pub struct ProcessLeaksPrompts;
+
+impl SealedPromptProvider for ProcessLeaksPrompts {}
+
+impl PromptProvider for ProcessLeaksPrompts {
+    type PromptArgs = ProcessLeaksPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Memory keeps going down on this box. Is something leaking?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_leaks({\"window_secs\": 900})\n\n\
+                     Every process whose resident memory grew steadily over the last 15 minutes \
+                     is listed with its growth, slope and R², fastest first. time_to_oom_secs \
+                     projects when that growth alone would use up the memory available now.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessLeaksArgs {
+    type Output = ProcessLeaksOutput;
+    type Prompts = ProcessLeaksPrompts;
+
+    const NAME: &'static str = PROCESS_LEAKS;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Flag processes whose memory grew steadily over a window, with slope and projected time to OOM";
+}
@@ -0,0 +1,420 @@
+//! Schema types for process_list tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::process::{PROCESS_LIST, ProcessListPrompts};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::{PodInfo, SummaryStyle, SummaryVerbosity};
+
+// ============================================================================
+// PROCESS LIST TOOL
+// ============================================================================
+
+/// Arguments for `process_list` tool
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessListArgs {
+    /// Optional: filter by name (case-insensitive substring match)
+    #[serde(default)]
+    pub filter: Option<String>,
+
+    /// Maximum number of processes to return (0 = up to the server's cap)
+    #[serde(default)]
+    pub limit: usize,
+
+    /// Optional: only these PIDs, all read in the same scan (those without a process come back in `missing`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pids: Vec<u32>,
+
+    /// Optional: only members of this group defined via `process_group_define`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+
+    /// Optional: only processes tagged with this tag via `process_tag`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+
+    /// Optional: only processes of this kind (classifies every process: one OS query each)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<ProcessKind>,
+
+    /// Optional: only processes of this Kubernetes pod: `namespace/name`, a name or a pod UID (Linux nodes)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pod: Option<String>,
+
+    /// Optional: also list the processes of running WSL distros (Windows; rows carry `wsl_distro`, PIDs are the distro's own)
+    #[serde(default)]
+    pub wsl: bool,
+
+    /// Optional: server-defined filters that must all match (an unknown name lists the available ones)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub named_filters: Vec<String>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Compute `uss_bytes`/`private_bytes` (slower: one extra OS query per process)
+    #[serde(default)]
+    pub detailed_memory: bool,
+
+    /// Optional: extra per-process columns (each costs one OS query per returned process)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub columns: Vec<ProcessColumn>,
+
+    /// Optional: return `header` plus one array per process in `rows` instead of `processes` objects (about half the size)
+    #[serde(default)]
+    pub compact: bool,
+
+    /// Optional: also return a `snapshot` token; pass it as `since` on the next call to get only changes
+    #[serde(default)]
+    pub delta: bool,
+
+    /// Optional: snapshot token from a previous call; only added, removed and significantly changed processes are returned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+
+    /// Optional: return command lines unmasked (only if the server's redaction policy allows it)
+    #[serde(default)]
+    pub reveal_secrets: bool,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Optional columns for `process_list`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessColumn {
+    /// Linux security context from `/proc/<pid>/attr/current` (SELinux/AppArmor)
+    SecurityLabel,
+    /// Linux seccomp mode from `/proc/<pid>/status`
+    Seccomp,
+    /// Windows integrity level and AppContainer flag from the process token
+    Integrity,
+    /// Full command line, with secrets masked per the server's redaction policy
+    Cmdline,
+    /// Open file descriptors (Linux, macOS) or kernel handles (Windows)
+    Handles,
+    /// Executable path and working directory (working directory not on Windows)
+    Paths,
+    /// Controlling terminal, e.g. `pts/3`, or `none` for daemons (Linux, macOS)
+    Tty,
+    /// Derived classification: daemon, service-managed, interactive, container or kernel
+    Kind,
+    /// Packaging or runtime origin: snap, flatpak, AppImage, container, Homebrew or system
+    Packaging,
+    /// SHA-256 of the executable, read from disk once per path and modification time
+    ExeHash,
+    /// Executable architecture and word size, and whether it runs emulated (Rosetta 2, x64 on Arm)
+    Arch,
+    /// Kubernetes pod namespace, name and container, from the cgroup path and the kubelet (Linux)
+    Pod,
+    /// Cgroup path, e.g. `/system.slice/nginx.service` (Linux)
+    Cgroup,
+}
+
+impl std::str::FromStr for ProcessColumn {
+    type Err = String;
+
+    /// Parse the snake_case name used in `columns`, e.g. `security_label`
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        serde_json::from_value(serde_json::Value::String(name.to_string()))
+            .map_err(|_| format!("Unknown column {name:?}"))
+    }
+}
+
+/// Linux seccomp mode of a process
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SeccompMode {
+    /// No seccomp restrictions
+    Disabled,
+    /// Strict mode: only read/write/exit/sigreturn allowed
+    Strict,
+    /// BPF filter mode (sandboxes, container runtimes, systemd hardening)
+    Filter,
+}
+
+impl SeccompMode {
+    /// The lowercase name used in JSON
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Disabled => "disabled",
+            Self::Strict => "strict",
+            Self::Filter => "filter",
+        }
+    }
+}
+
+/// What sort of process it is, derived from its terminal, session, cgroup and parent
+///
+/// Checked in this order: kernel threads, processes in a container (a cgroup
+/// of a container runtime or a PID namespace other than init's), interactive
+/// ones (a controlling terminal or a login session or desktop app scope), the
+/// ones a service manager runs (a systemd `.service` unit, launchd jobs, or
+/// Windows session 0), and everything else as a daemon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessKind {
+    /// Detached background process not owned by a service manager
+    Daemon,
+    /// Run by systemd, launchd or the Windows service control manager
+    ServiceManaged,
+    /// Attached to a terminal or a user's login session
+    Interactive,
+    /// Inside a container
+    Container,
+    /// Kernel thread or the kernel itself
+    Kernel,
+}
+
+impl ProcessKind {
+    /// The snake_case name used in JSON
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Daemon => "daemon",
+            Self::ServiceManaged => "service_managed",
+            Self::Interactive => "interactive",
+            Self::Container => "container",
+            Self::Kernel => "kernel",
+        }
+    }
+}
+
+impl std::str::FromStr for ProcessKind {
+    type Err = String;
+
+    /// Parse the snake_case name, e.g. `service_managed`
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        serde_json::from_value(serde_json::Value::String(name.to_string()))
+            .map_err(|_| format!("Unknown process kind {name:?}"))
+    }
+}
+
+/// Where a process's executable comes from, guessed from its exe path and cgroup
+///
+/// It decides how the process is best managed: snap services are stopped
+/// through snapd, flatpak apps with `flatpak kill`, containers through their
+/// runtime and Homebrew services with `brew services`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Packaging {
+    /// Under `/snap/` or in a `snap.*` scope
+    Snap,
+    /// In an `app-flatpak-*` scope or running from a sandbox's `/app`
+    Flatpak,
+    /// Running from an AppImage mount
+    #[serde(rename = "appimage")]
+    AppImage,
+    /// Inside a container
+    Container,
+    /// Under a Homebrew prefix
+    Homebrew,
+    /// Under a directory owned by the OS or its package manager (`/usr/bin`, `/System`, `C:\Windows`)
+    System,
+    /// Anywhere else, e.g. `/usr/local/bin`, `/opt` or a home directory
+    Unpackaged,
+}
+
+impl Packaging {
+    /// The name used in JSON
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Snap => "snap",
+            Self::Flatpak => "flatpak",
+            Self::AppImage => "appimage",
+            Self::Container => "container",
+            Self::Homebrew => "homebrew",
+            Self::System => "system",
+            Self::Unpackaged => "unpackaged",
+        }
+    }
+}
+
+/// Output from `process_list` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessListOutput {
+    /// Whether the operation succeeded
+    pub success: bool,
+    /// Number of processes returned
+    pub count: usize,
+    /// List of process information (empty in compact mode; only added and changed rows with `since`)
+    pub processes: Vec<ProcessInfo>,
+    /// Field names of each `rows` entry (compact mode only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub header: Vec<String>,
+    /// One array per process, values in `header` order (compact mode only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rows: Vec<Vec<serde_json::Value>>,
+    /// Command-line arguments masked by the redaction policy
+    pub redacted: usize,
+    /// Token for the next `since` call (delta mode only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<String>,
+    /// What changed relative to `since`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delta: Option<ListDelta>,
+    /// Server-side cap that cut the result short (absent when everything matching was returned)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capped: Option<ResultCap>,
+    /// Problems that left entries out without failing the listing, e.g. processes that exited mid-scan
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    /// Requested `pids` that no process has (exited or never existed)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing: Vec<u32>,
+}
+
+/// Server-side result cap applied to a `process_list` call
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ResultCap {
+    /// Which cap cut the result: `max_rows` or `max_bytes`
+    pub by: String,
+    /// Configured row cap (0 = none)
+    pub max_rows: usize,
+    /// Configured byte budget for process rows (0 = none)
+    pub max_bytes: usize,
+}
+
+/// Changes between two `process_list` snapshots
+///
+/// A process counts as changed when CPU moved by 5 percentage points, resident
+/// memory by 10% (and at least 1 MiB), or its name, tags or cmdline differ.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ListDelta {
+    /// Snapshot token the changes are relative to
+    pub since: String,
+    /// PIDs of new processes (their rows are in `processes`)
+    pub added: Vec<u32>,
+    /// PIDs of significantly changed processes (their rows are in `processes`)
+    pub changed: Vec<u32>,
+    /// PIDs that are gone (a PID reused by a new process is both removed and added)
+    pub removed: Vec<u32>,
+    /// Processes present in both snapshots without significant change
+    pub unchanged: usize,
+}
+
+/// Information about a single process
+///
+/// # Memory semantics
+///
+/// | Field           | Linux                         | macOS                  | Windows                  |
+/// |-----------------|-------------------------------|------------------------|--------------------------|
+/// | `rss_bytes`     | VmRSS (includes shared pages) | resident size          | working set              |
+/// | `virtual_bytes` | VmSize                        | virtual size           | pagefile usage           |
+/// | `uss_bytes`     | Private_Clean + Private_Dirty | -                      | -                        |
+/// | `private_bytes` | -                             | physical footprint     | PrivateUsage (commit)    |
+///
+/// Only `uss_bytes` and `private_bytes` exclude shared memory, and they are
+/// only populated when requested (`detailed_memory`) and supported.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessInfo {
+    /// Process ID
+    pub pid: u32,
+    /// Process name/command
+    pub name: String,
+    /// Start time in seconds since the epoch; with `pid` it identifies the process (0 for WSL rows)
+    pub start_time: u64,
+    /// Reuse-safe handle to pass as `proc_id` to mutating tools (absent for WSL rows)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+    /// CPU usage percentage
+    pub cpu_percent: f32,
+    /// Resident memory in MiB (`rss_bytes` / 1048576, kept for compatibility)
+    pub memory_mb: f64,
+    /// Resident memory in bytes (see type-level docs for per-platform meaning)
+    pub rss_bytes: u64,
+    /// `rss_bytes` for display, e.g. `1.42 GiB`
+    pub rss_human: String,
+    /// Virtual memory in bytes
+    pub virtual_bytes: u64,
+    /// Unique set size in bytes (Linux, `detailed_memory` only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uss_bytes: Option<u64>,
+    /// Private memory in bytes (Windows commit charge / macOS footprint, `detailed_memory` only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private_bytes: Option<u64>,
+    /// Linux security context, e.g. `system_u:system_r:httpd_t:s0` or `unconfined`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_label: Option<String>,
+    /// Linux seccomp mode (`disabled`, `strict` or `filter`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seccomp: Option<SeccompMode>,
+    /// Windows mandatory integrity level (`Low`, `Medium`, `High`, `System`, ...)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrity_level: Option<String>,
+    /// Windows: process runs inside an AppContainer sandbox
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_container: Option<bool>,
+    /// Server-side tags attached with `process_tag`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// kodegen server that started the process: this one, or a sibling that registered it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spawned_by: Option<String>,
+    /// Full command line, argv[0] first (`cmdline` column only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cmdline: Option<Vec<String>>,
+    /// Open file descriptors or kernel handles (`handles` column only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub handle_count: Option<u64>,
+    /// Executable path (`paths` column only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exe: Option<String>,
+    /// Working directory (`paths` column only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    /// Controlling terminal such as `pts/3`, or `none` (`tty` column only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tty: Option<String>,
+    /// Derived classification (`kind` column or `kind` filter)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<ProcessKind>,
+    /// Packaging or runtime origin (`packaging` column only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub packaging: Option<Packaging>,
+    /// Hex SHA-256 of the running executable (`exe_hash` column only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exe_sha256: Option<String>,
+    /// Executable architecture such as `arm64`, `x86_64` or `x86` (`arch` column only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arch: Option<String>,
+    /// 32 or 64 (`arch` column only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arch_bits: Option<u8>,
+    /// Runs translated or emulated rather than natively, e.g. x86_64 under Rosetta 2 (`arch` column only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emulated: Option<bool>,
+    /// Cgroup path in the unified or systemd hierarchy (`cgroup` column only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cgroup: Option<String>,
+    /// Kubernetes pod the process runs in (`pod` column or `pod` filter)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pod: Option<PodInfo>,
+    /// WSL distro the process runs in (`wsl` only); `pid` is then the PID inside that distro
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wsl_distro: Option<String>,
+    /// Fields added by server-side enrichers, keyed by enricher
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessListArgs {
+    type Output = ProcessListOutput;
+    type Prompts = ProcessListPrompts;
+
+    const NAME: &'static str = PROCESS_LIST;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "List all running processes with PID, command name, CPU usage, and memory usage. Supports filtering by process name";
+}
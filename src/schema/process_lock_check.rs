@@ -0,0 +1,155 @@
+//! Schema types for process_lock_check tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for detecting stale lock files
+pub const PROCESS_LOCK_CHECK: &str = "process_lock_check";
+
+/// Default for [`ProcessLockCheckArgs::min_age_secs`]
+pub const DEFAULT_LOCK_MIN_AGE_SECS: u64 = 5;
+
+fn default_min_age_secs() -> u64 {
+    DEFAULT_LOCK_MIN_AGE_SECS
+}
+
+// ============================================================================
+// PROCESS LOCK CHECK TOOL
+// ============================================================================
+
+/// Arguments for `process_lock_check` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessLockCheckArgs {
+    /// Lock file path, e.g. ".git/index.lock" (relative paths resolve against the client's working directory)
+    pub path: String,
+
+    /// Optional: delete the lock file when it is stale
+    #[serde(default)]
+    pub remove: bool,
+
+    /// Optional: files modified more recently than this are kept, since their owner may still be starting (default: 5)
+    #[serde(default = "default_min_age_secs")]
+    pub min_age_secs: u64,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// A process that has the lock file open
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LockHolder {
+    /// Process ID
+    pub pid: u32,
+    /// Reuse-safe handle to pass as `proc_id` to mutating tools (absent once it exited)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+    /// Process name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Holds an advisory lock on the file, not just an open handle (Linux and macOS)
+    pub locked: bool,
+}
+
+/// Output from `process_lock_check` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessLockCheckOutput {
+    /// Whether the check ran (and the removal, when requested, did not fail)
+    pub success: bool,
+    /// Resolved lock file path
+    pub path: String,
+    /// The lock file exists
+    pub exists: bool,
+    /// File size in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytes: Option<u64>,
+    /// Last modification time (RFC 3339)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified: Option<String>,
+    /// Seconds since the last modification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub age_secs: Option<u64>,
+    /// PID written in the file, for tools that record their owner
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid_in_file: Option<u32>,
+    /// Whether the PID written in the file is running
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid_in_file_alive: Option<bool>,
+    /// Processes that have the file open
+    pub holders: Vec<LockHolder>,
+    /// Whether open handles could be enumerated on this platform
+    pub holder_detection: bool,
+    /// No live process owns the lock
+    pub stale: bool,
+    /// Why the lock is (or is not) considered stale
+    pub reason: String,
+    /// Whether the file was deleted
+    pub removed: bool,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_lock_check` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessLockCheckPromptArgs {}
+
+/// Prompt provider for `process_lock_check` tool
+pub struct ProcessLockCheckPrompts;
+
+impl SealedPromptProvider for ProcessLockCheckPrompts {}
+
+impl PromptProvider for ProcessLockCheckPrompts {
+    type PromptArgs = ProcessLockCheckPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text(
+                    "git says \"Unable to create .git/index.lock: File exists\". Is it safe to delete?",
+                ),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_lock_check({\"path\": \".git/index.lock\"}) lists the processes that \
+                     still have the file open (holders) and, when the file contains a PID, whether \
+                     that process is alive. stale=true means nobody owns it any more; then call:\n\n\
+                     process_lock_check({\"path\": \".git/index.lock\", \"remove\": true})\n\n\
+                     A lock with live holders is never removed: wait for that process, or show the \
+                     holders to the user. Where holders cannot be enumerated (holder_detection=false) \
+                     a lock without a PID is left alone.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessLockCheckArgs {
+    type Output = ProcessLockCheckOutput;
+    type Prompts = ProcessLockCheckPrompts;
+
+    const NAME: &'static str = PROCESS_LOCK_CHECK;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Check whether a lock file is still owned by a live process and optionally remove it when stale";
+}
@@ -0,0 +1,138 @@
+//! Schema types for process_logs tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{LogStream, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for fetching captured output of supervised processes
+pub const PROCESS_LOGS: &str = "process_logs";
+
+/// Default for [`ProcessLogsArgs::lines`]
+pub const DEFAULT_LOG_LINES: usize = 100;
+
+fn default_lines() -> usize {
+    DEFAULT_LOG_LINES
+}
+
+// ============================================================================
+// PROCESS LOGS TOOL
+// ============================================================================
+
+/// Arguments for `process_logs` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessLogsArgs {
+    /// Supervisor id from `process_status`
+    pub id: u64,
+
+    /// Optional: "stdout" (default) or "stderr"
+    #[serde(default)]
+    pub stream: LogStream,
+
+    /// Optional: segment to read, 0 = live segment (default), 1 = most recently rotated, ...
+    #[serde(default)]
+    pub segment: u32,
+
+    /// Optional: number of trailing lines to return (default: 100, 0 = whole segment)
+    #[serde(default = "default_lines")]
+    pub lines: usize,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// One file of a rotated output log
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LogSegment {
+    /// 0 for the live segment, higher is older
+    pub index: u32,
+    /// File path
+    pub path: String,
+    /// File size in bytes
+    pub bytes: u64,
+    /// Last write time (RFC 3339)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified: Option<String>,
+}
+
+/// Output from `process_logs` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessLogsOutput {
+    /// Whether the read succeeded
+    pub success: bool,
+    /// Supervisor id
+    pub id: u64,
+    /// Stream that was read
+    pub stream: LogStream,
+    /// Segment that was read
+    pub segment: u32,
+    /// All available segments of the stream, live segment first
+    pub segments: Vec<LogSegment>,
+    /// Requested lines, oldest first
+    pub lines: Vec<String>,
+    /// Earlier lines of the segment were left out
+    pub truncated: bool,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_logs` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessLogsPromptArgs {}
+
+/// Prompt provider for `process_logs` tool
+pub struct ProcessLogsPrompts;
+
+impl SealedPromptProvider for ProcessLogsPrompts {}
+
+impl PromptProvider for ProcessLogsPrompts {
+    type PromptArgs = ProcessLogsPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Why did the dev server I restarted fail?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_logs({\"id\": 1, \"stream\": \"stderr\"}) returns the last 100 lines the \
+                     process wrote to stderr. Output is kept in size-rotated segments:\n\
+                     - segments lists every file, live segment (index 0) first\n\
+                     - segment: 1 reads the most recently rotated file\n\
+                     - lines: 0 returns the whole segment\n\n\
+                     Only processes started by this server (see process_status) have logs.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessLogsArgs {
+    type Output = ProcessLogsOutput;
+    type Prompts = ProcessLogsPrompts;
+
+    const NAME: &'static str = PROCESS_LOGS;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "List and read rotated stdout/stderr segments of a process started by this server";
+}
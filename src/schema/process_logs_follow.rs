@@ -0,0 +1,145 @@
+//! Schema types for process_logs_follow tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{LogStream, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for streaming live output of a supervised process
+pub const PROCESS_LOGS_FOLLOW: &str = "process_logs_follow";
+
+/// Default for [`ProcessLogsFollowArgs::error_pattern`]
+pub const DEFAULT_ERROR_PATTERN: &str = r"(?i)\b(error|fatal|panic(ked)?|exception|failed)\b";
+
+/// Default for [`ProcessLogsFollowArgs::timeout_secs`]
+pub const DEFAULT_FOLLOW_TIMEOUT_SECS: u64 = 300;
+
+fn default_timeout_secs() -> u64 {
+    DEFAULT_FOLLOW_TIMEOUT_SECS
+}
+
+// ============================================================================
+// PROCESS LOGS FOLLOW TOOL
+// ============================================================================
+
+/// Arguments for `process_logs_follow` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessLogsFollowArgs {
+    /// Supervisor id from `process_status`
+    pub id: u64,
+
+    /// Optional: only follow "stdout" or "stderr" (default: both)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stream: Option<LogStream>,
+
+    /// Optional: regex marking error lines (default matches error, fatal, panic, exception, failed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_pattern: Option<String>,
+
+    /// Optional: stop after this many seconds (default: 300, 0 = until cancelled or the output ends)
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+
+    /// Optional: stop after this many lines (default: 0 = no limit)
+    #[serde(default)]
+    pub max_lines: usize,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Why following stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FollowEnd {
+    /// Both output pipes closed (the process exited)
+    Ended,
+    /// The client cancelled the request
+    Cancelled,
+    /// `timeout_secs` elapsed
+    Timeout,
+    /// `max_lines` were streamed
+    MaxLines,
+}
+
+/// Output from `process_logs_follow` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessLogsFollowOutput {
+    /// Whether following completed normally
+    pub success: bool,
+    /// Supervisor id
+    pub id: u64,
+    /// Why following stopped
+    pub ended: FollowEnd,
+    /// Lines streamed to the client
+    pub lines_streamed: usize,
+    /// Lines skipped because the client fell behind
+    pub lines_missed: u64,
+    /// Number of lines matching `error_pattern`
+    pub error_count: usize,
+    /// Most recent matching lines, oldest first
+    pub error_lines: Vec<String>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_logs_follow` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessLogsFollowPromptArgs {}
+
+/// Prompt provider for `process_logs_follow` tool
+pub struct ProcessLogsFollowPrompts;
+
+impl SealedPromptProvider for ProcessLogsFollowPrompts {}
+
+impl PromptProvider for ProcessLogsFollowPrompts {
+    type PromptArgs = ProcessLogsFollowPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Watch the dev server start up and tell me if it errors."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_logs_follow({\"id\": 1, \"timeout_secs\": 30}) streams new output lines as \
+                     notifications while the call is open. Error lines are highlighted and collected:\n\
+                     - error_count / error_lines in the final result\n\
+                     - error_pattern: custom regex, e.g. \"EADDRINUSE|ERR!\"\n\
+                     - ended: ended, cancelled, timeout or max_lines\n\n\
+                     For output written before the call, use process_logs.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessLogsFollowArgs {
+    type Output = ProcessLogsFollowOutput;
+    type Prompts = ProcessLogsFollowPrompts;
+
+    const NAME: &'static str = PROCESS_LOGS_FOLLOW;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Stream new output lines of a process started by this server until cancelled, with error highlighting";
+}
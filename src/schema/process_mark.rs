@@ -0,0 +1,127 @@
+//! Schema types for process_mark tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for recording a named process snapshot
+pub const PROCESS_MARK: &str = "process_mark";
+
+// ============================================================================
+// PROCESS MARK TOOL
+// ============================================================================
+
+/// Arguments for `process_mark` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessMarkArgs {
+    /// Marker name, e.g. "before-deploy" (recording it again replaces the old snapshot)
+    pub name: String,
+
+    /// Optional: only processes whose name contains this (case-insensitive); the comparison
+    /// applies the same filter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Process count and summed resources of one table
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema)]
+pub struct MarkerTotals {
+    /// Number of processes
+    pub count: usize,
+    /// Summed CPU usage in percent of one core
+    pub cpu_percent: f64,
+    /// Summed resident memory in bytes
+    pub rss_bytes: u64,
+}
+
+/// A recorded marker
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MarkerInfo {
+    /// Marker name
+    pub name: String,
+    /// Name filter the snapshot was taken with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// When the snapshot was taken (RFC 3339)
+    pub taken_at: String,
+    /// Totals at that time
+    pub totals: MarkerTotals,
+}
+
+/// Output from `process_mark` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessMarkOutput {
+    /// Whether the marker was recorded
+    pub success: bool,
+    /// The new marker
+    pub marker: MarkerInfo,
+    /// Whether a marker of the same name was replaced
+    pub replaced: bool,
+    /// Names of all markers now recorded
+    pub markers: Vec<String>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_mark` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessMarkPromptArgs {}
+
+/// Prompt provider for `process_mark` tool
+pub struct ProcessMarkPrompts;
+
+impl SealedPromptProvider for ProcessMarkPrompts {}
+
+impl PromptProvider for ProcessMarkPrompts {
+    type PromptArgs = ProcessMarkPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("I'm about to deploy; check afterwards that nothing unexpected is left running."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "Record the current state first:\n\n\
+                     process_mark({\"name\": \"before-deploy\"})\n\n\
+                     After the deploy, process_mark_compare({\"name\": \"before-deploy\"}) lists \
+                     new and vanished processes and those whose CPU or memory moved noticeably.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessMarkArgs {
+    type Output = ProcessMarkOutput;
+    type Prompts = ProcessMarkPrompts;
+
+    const NAME: &'static str = PROCESS_MARK;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Record a named snapshot of the process table for a later process_mark_compare";
+}
@@ -0,0 +1,150 @@
+//! Schema types for process_mark_compare tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{MarkerInfo, MarkerTotals, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for comparing the process table against a marker
+pub const PROCESS_MARK_COMPARE: &str = "process_mark_compare";
+
+// ============================================================================
+// PROCESS MARK COMPARE TOOL
+// ============================================================================
+
+/// Arguments for `process_mark_compare` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessMarkCompareArgs {
+    /// Marker recorded with `process_mark`
+    pub name: String,
+
+    /// Optional: delete the marker after comparing
+    #[serde(default)]
+    pub drop: bool,
+
+    /// Maximum entries returned in each of `added`, `removed` and `changed` (0 = unlimited)
+    #[serde(default)]
+    pub limit: usize,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// A process present on only one side of the comparison
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MarkerProcess {
+    pub pid: u32,
+    pub name: String,
+    /// CPU usage when last seen
+    pub cpu_percent: f32,
+    /// Resident memory when last seen
+    pub rss_bytes: u64,
+}
+
+/// A process on both sides whose CPU, memory, name, tags or cmdline moved noticeably
+/// (the `process_list` delta thresholds)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MarkerChange {
+    pub pid: u32,
+    /// Current name
+    pub name: String,
+    pub cpu_before: f32,
+    pub cpu_after: f32,
+    pub rss_before: u64,
+    pub rss_after: u64,
+    /// `rss_after - rss_before`
+    pub rss_delta_bytes: i64,
+}
+
+/// Differences between a marker and the current table
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MarkerComparison {
+    /// Totals when the marker was recorded
+    pub before: MarkerTotals,
+    /// Totals now
+    pub after: MarkerTotals,
+    /// Processes started since, sorted by PID (a recycled PID appears here and in `removed`)
+    pub added: Vec<MarkerProcess>,
+    /// Processes gone since, sorted by PID
+    pub removed: Vec<MarkerProcess>,
+    /// Noticeably changed processes, largest memory change first
+    pub changed: Vec<MarkerChange>,
+    /// Processes present on both sides without notable change
+    pub unchanged: usize,
+}
+
+/// Output from `process_mark_compare` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessMarkCompareOutput {
+    /// Whether the comparison was made
+    pub success: bool,
+    /// The marker compared against
+    pub marker: MarkerInfo,
+    #[serde(flatten)]
+    pub comparison: MarkerComparison,
+    /// Whether `limit` cut any of the lists (the totals always cover everything)
+    pub truncated: bool,
+    /// Whether the marker was deleted (`drop`)
+    pub dropped: bool,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_mark_compare` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessMarkComparePromptArgs {}
+
+/// Prompt provider for `process_mark_compare` tool
+pub struct ProcessMarkComparePrompts;
+
+impl SealedPromptProvider for ProcessMarkComparePrompts {}
+
+impl PromptProvider for ProcessMarkComparePrompts {
+    type PromptArgs = ProcessMarkComparePromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("The deploy is done. Did anything change that shouldn't have?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_mark_compare({\"name\": \"before-deploy\", \"limit\": 20})\n\n\
+                     `added` and `removed` show what started and stopped (a restarted service \
+                     appears in both with different PIDs), `changed` the processes whose memory \
+                     or CPU moved, largest memory change first, and `before`/`after` the totals.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessMarkCompareArgs {
+    type Output = ProcessMarkCompareOutput;
+    type Prompts = ProcessMarkComparePrompts;
+
+    const NAME: &'static str = PROCESS_MARK_COMPARE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Compare the process table against a process_mark snapshot: new, vanished and changed processes";
+}
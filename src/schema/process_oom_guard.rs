@@ -0,0 +1,157 @@
+//! Schema types for process_oom_guard tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for inspecting the memory watchdog
+pub const PROCESS_OOM_GUARD: &str = "process_oom_guard";
+
+// ============================================================================
+// PROCESS OOM GUARD TOOL
+// ============================================================================
+
+/// Arguments for `process_oom_guard` tool
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessOomGuardArgs {
+    /// Optional: maximum interventions to return, newest first (0 = all)
+    #[serde(default)]
+    pub limit: usize,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// What the watchdog does to a process over its ceiling
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum OomGuardAction {
+    /// Terminate: SIGTERM, 5 s, then SIGKILL
+    #[default]
+    Kill,
+    /// Freeze the process tree, as `process_freeze`, until `process_thaw`
+    Freeze,
+}
+
+impl OomGuardAction {
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Kill => "kill",
+            Self::Freeze => "freeze",
+        }
+    }
+}
+
+/// One memory ceiling of the watchdog (the `oom_guard.rules` config entries)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct OomGuardRule {
+    /// Glob matched case-insensitively against process names
+    pub pattern: String,
+    /// Resident memory above which the action is taken
+    pub memory_max_bytes: u64,
+    /// What to do about it (default: kill)
+    #[serde(default)]
+    pub action: OomGuardAction,
+}
+
+/// One process the watchdog acted on
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct OomIntervention {
+    /// Sequence number (stable for the lifetime of the server)
+    pub id: u64,
+    /// Process ID that went over its ceiling
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Pattern of the rule that matched
+    pub pattern: String,
+    /// Resident memory when the ceiling was found exceeded
+    pub rss_bytes: u64,
+    /// The rule's ceiling
+    pub memory_max_bytes: u64,
+    pub action: OomGuardAction,
+    /// Whether the action took effect (the process is gone or frozen)
+    pub success: bool,
+    /// What happened, e.g. "killed by SIGTERM" or why the action failed
+    pub outcome: String,
+    /// When it happened (RFC 3339)
+    pub at: String,
+}
+
+/// Output from `process_oom_guard` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessOomGuardOutput {
+    /// Whether the query succeeded
+    pub success: bool,
+    /// Whether the watchdog is running (it runs when at least one rule is configured)
+    pub enabled: bool,
+    /// Period between memory checks in milliseconds
+    pub interval_ms: u64,
+    /// Configured ceilings, checked in order; the first matching pattern applies
+    pub rules: Vec<OomGuardRule>,
+    /// Processes acted on, newest first
+    pub interventions: Vec<OomIntervention>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_oom_guard` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessOomGuardPromptArgs {}
+
+/// Prompt provider for `process_oom_guard` tool
+pub struct ProcessOomGuardPrompts;
+
+impl SealedPromptProvider for ProcessOomGuardPrompts {}
+
+impl PromptProvider for ProcessOomGuardPrompts {
+    type PromptArgs = ProcessOomGuardPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("My webpack build vanished. Did something kill it?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_oom_guard({})\n\n\
+                     `interventions` lists every process the memory watchdog killed or froze, \
+                     with the rule's pattern, the ceiling and the resident memory it had at \
+                     the time; `rules` shows the ceilings configured in process.json.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessOomGuardArgs {
+    type Output = ProcessOomGuardOutput;
+    type Prompts = ProcessOomGuardPrompts;
+
+    const NAME: &'static str = PROCESS_OOM_GUARD;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Show the memory watchdog's ceilings and the processes it killed or froze";
+}
@@ -0,0 +1,111 @@
+//! Schema types for process_open_files tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{OpenFile, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for listing the files a process has open
+pub const PROCESS_OPEN_FILES: &str = "process_open_files";
+
+// ============================================================================
+// PROCESS OPEN FILES TOOL
+// ============================================================================
+
+/// Arguments for `process_open_files` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessOpenFilesArgs {
+    /// Process ID to inspect (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: only descriptors whose target contains this text, e.g. ".log" or "socket"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains: Option<String>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_open_files` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessOpenFilesOutput {
+    /// Whether the process was found
+    pub success: bool,
+    /// Process ID inspected
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Descriptors matching `contains`, sorted by number
+    pub files: Vec<OpenFile>,
+    /// Descriptors open in total, before `contains` was applied
+    pub total: usize,
+    /// False when the descriptor table could not be read, normally another user's process without privileges
+    pub readable: bool,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_open_files` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessOpenFilesPromptArgs {}
+
+/// Prompt provider for `process_open_files` tool
+pub struct ProcessOpenFilesPrompts;
+
+impl SealedPromptProvider for ProcessOpenFilesPrompts {}
+
+impl PromptProvider for ProcessOpenFilesPrompts {
+    type PromptArgs = ProcessOpenFilesPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("The disk is full but du finds nothing. PID 4410 rotated its logs earlier."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_open_files({\"pid\": 4410, \"contains\": \"(deleted)\"})\n\n\
+                     On Linux a file that was deleted while still open shows up as \
+                     \"/path (deleted)\": its space is only freed once the process closes it. \
+                     Restarting the process, or signalling it to reopen its logs, releases the \
+                     space. To find which process holds a given file, use process_lock_check.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessOpenFilesArgs {
+    type Output = ProcessOpenFilesOutput;
+    type Prompts = ProcessOpenFilesPrompts;
+
+    const NAME: &'static str = PROCESS_OPEN_FILES;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "List the open file descriptors or handles of a process and what each refers to";
+}
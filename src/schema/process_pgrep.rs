@@ -0,0 +1,112 @@
+//! Schema types for process_pgrep tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for resolving PIDs by regex
+pub const PROCESS_PGREP: &str = "process_pgrep";
+
+// ============================================================================
+// PROCESS PGREP TOOL
+// ============================================================================
+
+/// Arguments for `process_pgrep` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessPgrepArgs {
+    /// Regex searched for in the process name, e.g. "^node$" or "postgres|pg_"
+    pub pattern: String,
+
+    /// Match against the full command line instead of the name, like `pgrep -f`
+    #[serde(default)]
+    pub full: bool,
+
+    /// Match case-insensitively
+    #[serde(default)]
+    pub ignore_case: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// A process matched by `process_pgrep`
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PgrepMatch {
+    /// Process ID
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+}
+
+/// Output from `process_pgrep` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessPgrepOutput {
+    /// Whether any process matched
+    pub success: bool,
+    /// Pattern that was matched
+    pub pattern: String,
+    /// Matching processes, sorted by PID
+    pub matches: Vec<PgrepMatch>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_pgrep` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessPgrepPromptArgs {}
+
+/// Prompt provider for `process_pgrep` tool
+pub struct ProcessPgrepPrompts;
+
+impl SealedPromptProvider for ProcessPgrepPrompts {}
+
+impl PromptProvider for ProcessPgrepPrompts {
+    type PromptArgs = ProcessPgrepPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("What's the PID of the vite dev server?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "vite runs under node, so match the command line:\n\n\
+                     process_pgrep({\"pattern\": \"vite( |$)\", \"full\": true})\n\n\
+                     Returns just pid and name for each match. Use process_info or \
+                     process_list for CPU, memory and the rest.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessPgrepArgs {
+    type Output = ProcessPgrepOutput;
+    type Prompts = ProcessPgrepPrompts;
+
+    const NAME: &'static str = PROCESS_PGREP;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Find PIDs and names of processes whose name or command line matches a regex";
+}
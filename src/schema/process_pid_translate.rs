@@ -0,0 +1,125 @@
+//! Schema types for process_pid_translate tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{PodInfo, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for host and container PID translation
+pub const PROCESS_PID_TRANSLATE: &str = "process_pid_translate";
+
+// ============================================================================
+// PROCESS PID TRANSLATE TOOL
+// ============================================================================
+
+/// Arguments for `process_pid_translate` tool
+///
+/// Pass either `pid` (host to container) or `container` with `container_pid`
+/// (container to host).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessPidTranslateArgs {
+    /// Optional: host PID to translate into the container's view
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: container ID or a unique prefix of it (e.g. the 12 characters `docker ps` shows)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+
+    /// Optional: PID inside `container` to translate to the host PID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container_pid: Option<u32>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_pid_translate` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessPidTranslateOutput {
+    /// Whether the translation succeeded
+    pub success: bool,
+    /// PID as seen by the server (and `kill` on the host)
+    pub host_pid: u32,
+    /// PID inside the innermost PID namespace, as the container's `ps` and logs show it
+    pub container_pid: u32,
+    /// PID in every nested PID namespace, host first (`NSpid`)
+    pub namespace_pids: Vec<u32>,
+    /// Container ID from the cgroup path
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container_id: Option<String>,
+    /// PID namespace, e.g. `pid:[4026532575]`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid_namespace: Option<String>,
+    /// Whether the process runs in its own PID namespace or a container cgroup
+    pub in_container: bool,
+    /// Kubernetes pod the container belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pod: Option<PodInfo>,
+    /// Process name
+    pub name: String,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_pid_translate` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessPidTranslatePromptArgs {}
+
+/// Prompt provider for `process_pid_translate` tool
+pub struct ProcessPidTranslatePrompts;
+
+impl SealedPromptProvider for ProcessPidTranslatePrompts {}
+
+impl PromptProvider for ProcessPidTranslatePrompts {
+    type PromptArgs = ProcessPidTranslatePromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text(
+                    "The logs of container 3f2a9c1b7d4e say worker PID 27 is stuck. Kill it from the host.",
+                ),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_pid_translate({\"container\": \"3f2a9c1b7d4e\", \"container_pid\": 27})\n\n\
+                     The result's host_pid is the PID to pass to process_kill; PID 27 only means \
+                     that worker inside the container's namespace. The other direction, \
+                     process_pid_translate({\"pid\": 48211}), tells which container a host \
+                     process belongs to and what its logs call it.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessPidTranslateArgs {
+    type Output = ProcessPidTranslateOutput;
+    type Prompts = ProcessPidTranslatePrompts;
+
+    const NAME: &'static str = PROCESS_PID_TRANSLATE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Translate between host PIDs and PIDs inside containers (Linux)";
+}
@@ -0,0 +1,128 @@
+//! Schema types for process_pidfile tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for reading and validating a pidfile
+pub const PROCESS_PIDFILE: &str = "process_pidfile";
+
+// ============================================================================
+// PROCESS PIDFILE TOOL
+// ============================================================================
+
+/// Arguments for `process_pidfile` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessPidfileArgs {
+    /// Pidfile path (relative paths resolve against the client's working directory)
+    pub path: String,
+
+    /// Optional: process name the PID must belong to, e.g. "nginx"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_name: Option<String>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// A pidfile checked against the live process table
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PidfileStatus {
+    /// Resolved pidfile path
+    pub path: String,
+    /// The file exists and is readable
+    pub exists: bool,
+    /// PID the file names
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+    /// A process with that PID is running
+    pub alive: bool,
+    /// Name of the running process
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Whether the process matches `expected_name` (absent when none was given)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_matches: Option<bool>,
+    /// Last modification time of the file (RFC 3339)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub written_at: Option<String>,
+    /// The file does not describe a live, matching process
+    pub stale: bool,
+    /// Why the file is stale
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Output from `process_pidfile` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessPidfileOutput {
+    /// Whether the check ran (a stale pidfile still succeeds)
+    pub success: bool,
+    /// Check result
+    pub pidfile: PidfileStatus,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_pidfile` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessPidfilePromptArgs {}
+
+/// Prompt provider for `process_pidfile` tool
+pub struct ProcessPidfilePrompts;
+
+impl SealedPromptProvider for ProcessPidfilePrompts {}
+
+impl PromptProvider for ProcessPidfilePrompts {
+    type PromptArgs = ProcessPidfilePromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Is the postgres from /var/run/postgres.pid still running?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_pidfile({\"path\": \"/var/run/postgres.pid\", \"expected_name\": \"postgres\"}) \
+                     reads the PID and checks it:\n\
+                     - alive: a process with that PID exists\n\
+                     - name_matches: it really is postgres\n\
+                     - stale + reason: dead PID, wrong process, or a PID recycled after the file was written\n\n\
+                     Stale files can be removed with process_pidfile_cleanup; process_kill accepts \
+                     {\"pidfile\": ...} instead of a pid.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessPidfileArgs {
+    type Output = ProcessPidfileOutput;
+    type Prompts = ProcessPidfilePrompts;
+
+    const NAME: &'static str = PROCESS_PIDFILE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Read a pidfile and check that its PID is alive and belongs to the expected process";
+}
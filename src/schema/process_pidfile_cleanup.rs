@@ -0,0 +1,109 @@
+//! Schema types for process_pidfile_cleanup tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{PidfileStatus, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for removing stale pidfiles
+pub const PROCESS_PIDFILE_CLEANUP: &str = "process_pidfile_cleanup";
+
+// ============================================================================
+// PROCESS PIDFILE CLEANUP TOOL
+// ============================================================================
+
+/// Arguments for `process_pidfile_cleanup` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessPidfileCleanupArgs {
+    /// Pidfile paths to check (relative paths resolve against the client's working directory)
+    pub paths: Vec<String>,
+
+    /// Optional: process name live PIDs must belong to; files naming another process are stale
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_name: Option<String>,
+
+    /// Optional: only report what would be removed
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_pidfile_cleanup` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessPidfileCleanupOutput {
+    /// Whether every stale file could be removed
+    pub success: bool,
+    /// Whether this was a dry run
+    pub dry_run: bool,
+    /// Check result for every path
+    pub checked: Vec<PidfileStatus>,
+    /// Stale files removed (or that would be removed on a dry run)
+    pub removed: Vec<String>,
+    /// Stale files that could not be removed, with the error
+    pub failed: Vec<String>,
+    /// Files that do not contain a PID; never removed, since they may not be pidfiles at all
+    #[serde(default)]
+    pub invalid: Vec<String>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_pidfile_cleanup` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessPidfileCleanupPromptArgs {}
+
+/// Prompt provider for `process_pidfile_cleanup` tool
+pub struct ProcessPidfileCleanupPrompts;
+
+impl SealedPromptProvider for ProcessPidfileCleanupPrompts {}
+
+impl PromptProvider for ProcessPidfileCleanupPrompts {
+    type PromptArgs = ProcessPidfileCleanupPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("The service refuses to start because of an old pidfile."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_pidfile_cleanup({\"paths\": [\"tmp/pids/server.pid\"], \"expected_name\": \"ruby\", \
+                     \"dry_run\": true}) shows which files are stale. Files whose PID is alive and matches \
+                     are never removed. Run again without dry_run to delete the stale ones.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessPidfileCleanupArgs {
+    type Output = ProcessPidfileCleanupOutput;
+    type Prompts = ProcessPidfileCleanupPrompts;
+
+    const NAME: &'static str = PROCESS_PIDFILE_CLEANUP;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Remove pidfiles whose PID is dead, recycled or belongs to an unexpected process";
+}
@@ -0,0 +1,117 @@
+//! Schema types for process_priority tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for reading or setting the priority of one process
+pub const PROCESS_PRIORITY: &str = "process_priority";
+
+// ============================================================================
+// PROCESS PRIORITY TOOL
+// ============================================================================
+
+/// Arguments for `process_priority` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessPriorityArgs {
+    /// Process ID to read or change (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: nice value to set, from -20 (highest priority) to 19 (lowest); omit to only read it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nice: Option<i32>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_priority` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessPriorityOutput {
+    /// Whether the priority was read (and set, when `nice` was given)
+    pub success: bool,
+    /// Process ID
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Nice value before the call (Windows reports its priority class as a nice value)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<i32>,
+    /// Nice value now, read back after any change
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nice: Option<i32>,
+    /// Priority class now, such as "below_normal" (Windows)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_class: Option<String>,
+    /// Whether the priority was set by this call
+    pub changed: bool,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_priority` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessPriorityPromptArgs {}
+
+/// Prompt provider for `process_priority` tool
+pub struct ProcessPriorityPrompts;
+
+impl SealedPromptProvider for ProcessPriorityPrompts {}
+
+impl PromptProvider for ProcessPriorityPrompts {
+    type PromptArgs = ProcessPriorityPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("The release build (PID 7300) makes my editor lag. Don't stop it, just make it yield."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_priority({\"pid\": 7300, \"nice\": 19})\n\n\
+                     Gives the build the lowest priority: it still uses idle CPU but yields to \
+                     interactive work. Lowering priority never needs root; raising it again below \
+                     the previous nice value does, and is refused with a clear error otherwise. \
+                     Its children keep their own values; use process_renice with a name filter \
+                     for the compiler processes it spawns.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessPriorityArgs {
+    type Output = ProcessPriorityOutput;
+    type Prompts = ProcessPriorityPrompts;
+
+    const NAME: &'static str = PROCESS_PRIORITY;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Read or set the nice value (priority class on Windows) of one process";
+}
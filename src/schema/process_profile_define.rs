@@ -0,0 +1,247 @@
+//! Schema types for process_profile_define tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{ContainmentCaps, IoPriority, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for defining spawn profiles at runtime
+pub const PROCESS_PROFILE_DEFINE: &str = "process_profile_define";
+
+/// Default for [`SpawnProfile::max_restarts`]
+pub const DEFAULT_MAX_RESTARTS: u32 = 5;
+
+/// Default for [`SpawnProfile::restart_delay_secs`]
+pub const DEFAULT_RESTART_DELAY_SECS: u64 = 1;
+
+// ============================================================================
+// SPAWN PROFILES
+// ============================================================================
+
+/// A pre-approved launch: everything is fixed except the declared `{param}` placeholders
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SpawnProfile {
+    /// Name passed to process_profile_start, e.g. "dev-server"
+    pub name: String,
+
+    /// What the profile is for, shown by process_profiles
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+
+    /// Program path or name resolved through PATH (no placeholders)
+    #[serde(default)]
+    pub command: String,
+
+    /// Arguments; `{param}` is replaced by the parameter's value, `{{` and `}}` are literal braces
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+
+    /// Working directory, may contain placeholders (server's when unset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+
+    /// `KEY=VALUE` entries added to the server's environment; values may contain placeholders
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env: Vec<String>,
+
+    /// Parameters callers may fill in
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<ProfileParam>,
+
+    /// Nice value applied before exec (-20 to 19; nearest priority class on Windows)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nice: Option<i32>,
+
+    /// I/O scheduling applied before exec (Linux only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub io_priority: Option<IoPriority>,
+
+    /// Logical CPUs to pin the process to (Linux and Windows); all when empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cpus: Vec<usize>,
+
+    /// Sandbox profile from the server config (the configured default when unset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox: Option<String>,
+
+    /// cgroup v2 caps applied right after each launch, as with process_contain (Linux only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limits: Option<ContainmentCaps>,
+
+    /// When the process is started again after it exits
+    #[serde(default)]
+    pub restart: RestartPolicy,
+
+    /// Restarts before the server gives up (0 never restarts)
+    #[serde(default = "default_max_restarts")]
+    pub max_restarts: u32,
+
+    /// Seconds between an exit and the restart
+    #[serde(default = "default_restart_delay_secs")]
+    pub restart_delay_secs: u64,
+}
+
+fn default_max_restarts() -> u32 {
+    DEFAULT_MAX_RESTARTS
+}
+
+fn default_restart_delay_secs() -> u64 {
+    DEFAULT_RESTART_DELAY_SECS
+}
+
+/// A placeholder callers fill in
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProfileParam {
+    /// Placeholder name, used as `{name}` (letters, digits and `_`)
+    pub name: String,
+
+    /// What the value means
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+
+    /// Value used when the caller passes none (the parameter is required when unset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+
+    /// Regex the whole value must match (default: one word of letters, digits and `._:/@%+=,~-`,
+    /// not starting with `-`, so a value cannot smuggle in an option)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+}
+
+/// When a profile launch is started again
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RestartPolicy {
+    /// Run once
+    #[default]
+    Never,
+    /// Restart after a non-zero exit or a signal
+    OnFailure,
+    /// Restart after every exit
+    Always,
+}
+
+impl RestartPolicy {
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Never => "never",
+            Self::OnFailure => "on_failure",
+            Self::Always => "always",
+        }
+    }
+
+    /// Whether an exit that `failed` (or not) is followed by a restart
+    #[must_use]
+    pub fn restarts(self, failed: bool) -> bool {
+        match self {
+            Self::Never => false,
+            Self::OnFailure => failed,
+            Self::Always => true,
+        }
+    }
+}
+
+// ============================================================================
+// PROCESS PROFILE DEFINE TOOL
+// ============================================================================
+
+/// Arguments for `process_profile_define` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessProfileDefineArgs {
+    /// The profile; with `delete` only its name is used
+    #[serde(flatten)]
+    pub profile: SpawnProfile,
+
+    /// Delete the runtime profile instead of defining it
+    #[serde(default)]
+    pub delete: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_profile_define` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessProfileDefineOutput {
+    /// Whether the operation succeeded
+    pub success: bool,
+    /// Profile name
+    pub name: String,
+    /// Whether the profile was deleted
+    pub deleted: bool,
+    /// Whether an earlier runtime profile of that name was replaced or deleted
+    pub replaced: bool,
+    /// Command line with its placeholders, e.g. "npm run dev -- --port {port}"
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub command: String,
+    /// Declared parameter names
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<String>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_profile_define` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessProfileDefinePromptArgs {}
+
+/// Prompt provider for `process_profile_define` tool
+pub struct ProcessProfileDefinePrompts;
+
+impl SealedPromptProvider for ProcessProfileDefinePrompts {}
+
+impl PromptProvider for ProcessProfileDefinePrompts {
+    type PromptArgs = ProcessProfileDefinePromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Let the agent start the dev server on a port of its choosing, nothing else."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_profile_define({\"name\": \"dev-server\", \"command\": \"npm\", \
+                     \"args\": [\"run\", \"dev\", \"--\", \"--port\", \"{port}\"], \"cwd\": \"/srv/app\", \
+                     \"params\": [{\"name\": \"port\", \"pattern\": \"[0-9]{4,5}\", \"default\": \"5173\"}], \
+                     \"restart\": \"on_failure\"})\n\n\
+                     The agent then calls process_profile_start({\"profile\": \"dev-server\", \
+                     \"params\": {\"port\": \"3000\"}}); a port that does not match the pattern is \
+                     refused. Runtime definitions only work when the server config sets \
+                     spawn_profiles.allow_define; profiles from the config file cannot be replaced.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessProfileDefineArgs {
+    type Output = ProcessProfileDefineOutput;
+    type Prompts = ProcessProfileDefinePrompts;
+
+    const NAME: &'static str = PROCESS_PROFILE_DEFINE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Define or delete a spawn profile at runtime (when the server allows it)";
+}
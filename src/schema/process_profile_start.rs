@@ -0,0 +1,120 @@
+//! Schema types for process_profile_start tool
+
+use std::collections::BTreeMap;
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{ContainmentCaps, RestartPolicy, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for launching a spawn profile
+pub const PROCESS_PROFILE_START: &str = "process_profile_start";
+
+// ============================================================================
+// PROCESS PROFILE START TOOL
+// ============================================================================
+
+/// Arguments for `process_profile_start` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessProfileStartArgs {
+    /// Profile name from process_profiles
+    pub profile: String,
+
+    /// Optional: parameter values by name, e.g. {"port": "3000"}
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, String>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_profile_start` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessProfileStartOutput {
+    /// Whether the process was started
+    pub success: bool,
+    /// Profile name
+    pub profile: String,
+    /// Supervisor id for process_status, process_logs and process_logs_follow
+    pub supervisor_id: u64,
+    /// PID of the new process
+    pub pid: u32,
+    /// Command line after substitution
+    pub command: String,
+    /// Working directory after substitution
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    /// Directory of the captured stdout and stderr
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_dir: Option<String>,
+    /// Sandbox profile it runs under
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox: Option<String>,
+    /// cgroup caps it runs under
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limits: Option<ContainmentCaps>,
+    /// Restart policy watching it
+    pub restart: RestartPolicy,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_profile_start` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessProfileStartPromptArgs {}
+
+/// Prompt provider for `process_profile_start` tool
+pub struct ProcessProfileStartPrompts;
+
+impl SealedPromptProvider for ProcessProfileStartPrompts {}
+
+impl PromptProvider for ProcessProfileStartPrompts {
+    type PromptArgs = ProcessProfileStartPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Start the dev server on port 3000."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_profile_start({\"profile\": \"dev-server\", \"params\": {\"port\": \"3000\"}})\n\n\
+                     The server runs exactly the command the profile fixes, with the port filled in, \
+                     and supervises it: process_logs_follow with the returned supervisor_id streams its \
+                     output. A restart policy starts it again after it exits until max_restarts is \
+                     reached; killing it with process_kill or process_terminate ends that.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessProfileStartArgs {
+    type Output = ProcessProfileStartOutput;
+    type Prompts = ProcessProfileStartPrompts;
+
+    const NAME: &'static str = PROCESS_PROFILE_START;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Launch a pre-approved spawn profile with parameter values";
+}
@@ -0,0 +1,135 @@
+//! Schema types for process_profiles tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SpawnProfile, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for listing spawn profiles
+pub const PROCESS_PROFILES: &str = "process_profiles";
+
+// ============================================================================
+// PROCESS PROFILES TOOL
+// ============================================================================
+
+/// Arguments for `process_profiles` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessProfilesArgs {
+    /// Optional: only this profile
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Where a profile comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileSource {
+    /// The server config file (cannot be changed at runtime)
+    Config,
+    /// process_profile_define
+    Defined,
+}
+
+/// One process_profile_start launch of a profile
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProfileLaunch {
+    /// Supervisor id of the current (most recent) process, for process_status and process_logs
+    pub supervisor_id: u64,
+    /// PID of the current process
+    pub pid: u32,
+    /// Whether the current process is running
+    pub running: bool,
+    /// Times the process was started again
+    pub restarts: u32,
+    /// Whether the restart policy still watches it (false once given up, or after a kill through the server)
+    pub restarting: bool,
+}
+
+/// A profile with its launches
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProfileInfo {
+    #[serde(flatten)]
+    pub profile: SpawnProfile,
+    /// Where the profile comes from
+    pub source: ProfileSource,
+    /// Launches whose supervisor record has not expired, newest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub launches: Vec<ProfileLaunch>,
+}
+
+/// Output from `process_profiles` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessProfilesOutput {
+    /// Whether the query succeeded
+    pub success: bool,
+    /// Number of profiles returned
+    pub count: usize,
+    /// Whether process_profile_define may add profiles
+    pub allow_define: bool,
+    /// Profiles sorted by name
+    pub profiles: Vec<ProfileInfo>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_profiles` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessProfilesPromptArgs {}
+
+/// Prompt provider for `process_profiles` tool
+pub struct ProcessProfilesPrompts;
+
+impl SealedPromptProvider for ProcessProfilesPrompts {}
+
+impl PromptProvider for ProcessProfilesPrompts {
+    type PromptArgs = ProcessProfilesPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("What am I allowed to start?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_profiles({})\n\n\
+                     Each profile shows its command with {param} placeholders, the params (with \
+                     default and pattern) that process_profile_start accepts, its restart policy and \
+                     limits, and the launches made from it with their current PID and restart count.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessProfilesArgs {
+    type Output = ProcessProfilesOutput;
+    type Prompts = ProcessProfilesPrompts;
+
+    const NAME: &'static str = PROCESS_PROFILES;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "List the spawn profiles process_profile_start can launch, with their launches";
+}
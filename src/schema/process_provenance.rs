@@ -0,0 +1,156 @@
+//! Schema types for process_provenance tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for provenance reports
+pub const PROCESS_PROVENANCE: &str = "process_provenance";
+
+// ============================================================================
+// PROCESS PROVENANCE TOOL
+// ============================================================================
+
+/// Arguments for `process_provenance` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessProvenanceArgs {
+    /// Process ID to explain
+    pub pid: u32,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// One process in the ancestor chain
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessAncestor {
+    /// Process ID
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Owner as `name (uid)` or a bare id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// Start time in seconds since the Unix epoch
+    pub start_time: u64,
+}
+
+/// The launch through this server a process comes from
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LaunchOrigin {
+    /// Supervisor id (see process_status)
+    pub supervisor_id: u64,
+    /// PID that was launched
+    pub pid: u32,
+    /// Whether the process is the launched one itself rather than a descendant of it
+    pub direct: bool,
+    /// Tool or API that launched it, e.g. "process_respawn"
+    pub origin: String,
+    /// Connection ID of the client that requested the launch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client: Option<String>,
+    /// Command line that was launched
+    pub command: String,
+    /// Launch time (RFC 3339)
+    pub started_at: String,
+}
+
+/// Output from `process_provenance` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessProvenanceOutput {
+    /// Whether the process was found
+    pub success: bool,
+    /// Process ID explained
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Owner as `name (uid)` or a bare id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// Executable path
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exe: Option<String>,
+    /// Start time in seconds since the Unix epoch
+    pub start_time: u64,
+    /// Reuse-safe handle to pass as `proc_id` to mutating tools
+    pub proc_id: String,
+    /// Start time (RFC 3339)
+    pub started_at: String,
+    /// Controlling terminal such as `pts/3`, or `none` for daemons (Linux, macOS)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tty: Option<String>,
+    /// systemd unit such as `nginx.service` or `session-3.scope` (Linux)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    /// Parent first, up to the root of the tree
+    pub ancestors: Vec<ProcessAncestor>,
+    /// Parent PID the chain stops at because that process has exited (or its PID was reused)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub broken_at: Option<u32>,
+    /// Launch through this server the process comes from, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub launched_by: Option<LaunchOrigin>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_provenance` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessProvenancePromptArgs {}
+
+/// Prompt provider for `process_provenance` tool
+pub struct ProcessProvenancePrompts;
+
+impl SealedPromptProvider for ProcessProvenancePrompts {}
+
+impl PromptProvider for ProcessProvenancePrompts {
+    type PromptArgs = ProcessProvenancePromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Where did PID 48213 come from?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_provenance({\"pid\": 48213})\n\n\
+                     The report walks the parents up to the root with their names and users, \
+                     and gives the controlling terminal or systemd unit and the start time. If \
+                     the process was started through this server, or descends from something \
+                     that was, launched_by names the tool and client that launched it.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessProvenanceArgs {
+    type Output = ProcessProvenanceOutput;
+    type Prompts = ProcessProvenancePrompts;
+
+    const NAME: &'static str = PROCESS_PROVENANCE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Explain where a process came from: ancestor chain, terminal or unit, start time and launching client";
+}
@@ -0,0 +1,139 @@
+//! Schema types for process_recently_killed tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for the recently-killed registry query
+pub const PROCESS_RECENTLY_KILLED: &str = "process_recently_killed";
+
+// ============================================================================
+// PROCESS RECENTLY KILLED TOOL
+// ============================================================================
+
+/// Arguments for `process_recently_killed` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessRecentlyKilledArgs {
+    /// Optional: only return entries for this PID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: maximum number of entries to return, newest first (0 = all)
+    #[serde(default)]
+    pub limit: usize,
+
+    /// Optional: return environment values and command lines unmasked (only if the server's redaction policy allows it)
+    #[serde(default)]
+    pub reveal_secrets: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Snapshot of a process taken right before it was killed
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct KilledProcess {
+    /// Registry record id (stable for the lifetime of the server)
+    pub id: u64,
+    /// Process ID the victim had
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Full command line, argv[0] first (secrets masked unless revealed)
+    pub cmdline: Vec<String>,
+    /// Executable path
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exe: Option<String>,
+    /// Working directory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    /// Environment as `KEY=VALUE` entries (secret values masked unless revealed)
+    pub env: Vec<String>,
+    /// Owner as "name (uid)"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// Process start time in seconds since the Unix epoch
+    pub start_time: u64,
+    /// When the kill happened (RFC 3339)
+    pub killed_at: String,
+    /// Connection ID of the client that issued the kill
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client: Option<String>,
+}
+
+/// Output from `process_recently_killed` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessRecentlyKilledOutput {
+    /// Whether the query succeeded
+    pub success: bool,
+    /// Number of entries returned
+    pub count: usize,
+    /// Entries, newest first
+    pub entries: Vec<KilledProcess>,
+    /// Environment values and command-line arguments masked by the redaction policy
+    pub redacted: usize,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_recently_killed` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessRecentlyKilledPromptArgs {}
+
+/// Prompt provider for `process_recently_killed` tool
+pub struct ProcessRecentlyKilledPrompts;
+
+impl SealedPromptProvider for ProcessRecentlyKilledPrompts {}
+
+impl PromptProvider for ProcessRecentlyKilledPrompts {
+    type PromptArgs = ProcessRecentlyKilledPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("How do I find out what a process_kill call actually terminated?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "Call process_recently_killed. Every successful process_kill stores a \
+                     snapshot taken right before the signal was sent.\n\n\
+                     process_recently_killed({\"pid\": 12345})\n\n\
+                     Each entry contains the id, cmdline, exe, cwd, env, user, start_time, \
+                     killed_at and the client that issued the kill. The registry is bounded, \
+                     so only the most recent kills are kept.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessRecentlyKilledArgs {
+    type Output = ProcessRecentlyKilledOutput;
+    type Prompts = ProcessRecentlyKilledPrompts;
+
+    const NAME: &'static str = PROCESS_RECENTLY_KILLED;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Show what recent process_kill calls terminated: cmdline, cwd, env and user captured before the kill";
+}
@@ -0,0 +1,145 @@
+//! Schema types for process_renice tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for changing the priority of matching processes
+pub const PROCESS_RENICE: &str = "process_renice";
+
+// ============================================================================
+// PROCESS RENICE TOOL
+// ============================================================================
+
+/// Arguments for `process_renice` tool
+///
+/// Every given selector must match; at least one is required.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessReniceArgs {
+    /// Nice value to set, from -20 (highest priority) to 19 (lowest)
+    pub nice: i32,
+
+    /// Optional: process name filter (case-insensitive substring match, as in `process_list`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+
+    /// Optional: only members of this group (see `process_group_define`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+
+    /// Optional: only these PIDs
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pids: Vec<u32>,
+
+    /// Optional: only these processes, by reuse-safe handle from process_list; reused PIDs never match
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub proc_ids: Vec<String>,
+
+    /// Only report which processes would change
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Outcome for one matching process
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReniceResult {
+    /// Process ID
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Nice value before the call
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<i32>,
+    /// Nice value read back afterwards (Windows reports its priority class as a nice value)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<i32>,
+    /// Whether the priority was set
+    pub changed: bool,
+    /// Why it was not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Output from `process_renice` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessReniceOutput {
+    /// Whether the operation completed (some processes may have failed)
+    pub success: bool,
+    /// Requested nice value
+    pub nice: i32,
+    /// Whether this was a preview only
+    pub dry_run: bool,
+    /// Number of processes set (or that would be, on a dry run)
+    pub changed: usize,
+    /// Number of processes that failed or were skipped
+    pub failed: usize,
+    /// Per-process outcomes, by PID
+    pub results: Vec<ReniceResult>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_renice` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessRenicePromptArgs {}
+
+/// Prompt provider for `process_renice` tool
+pub struct ProcessRenicePrompts;
+
+impl SealedPromptProvider for ProcessRenicePrompts {}
+
+impl PromptProvider for ProcessRenicePrompts {
+    type PromptArgs = ProcessRenicePromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("The build is making my editor laggy."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "Lower the priority of the compiler processes instead of stopping the build:\n\n\
+                     process_renice({\"filter\": \"rustc\", \"nice\": 19})\n\n\
+                     Add \"dry_run\": true to see the matches first. For several names, define a \
+                     group (process_group_define with patterns [\"cargo\", \"rustc\"]) and pass \
+                     \"group\". Raising priority (negative nice) usually needs root. Each result \
+                     shows before and after values; protected processes are left alone.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessReniceArgs {
+    type Output = ProcessReniceOutput;
+    type Prompts = ProcessRenicePrompts;
+
+    const NAME: &'static str = PROCESS_RENICE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Set the scheduling priority (nice value) of every process matching a name filter, group or PID list, with per-process results and dry-run";
+}
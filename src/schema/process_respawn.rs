@@ -0,0 +1,128 @@
+//! Schema types for process_respawn tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{DaemonInfo, DaemonOptions, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for relaunching a recently killed process
+pub const PROCESS_RESPAWN: &str = "process_respawn";
+
+// ============================================================================
+// PROCESS RESPAWN TOOL
+// ============================================================================
+
+/// Arguments for `process_respawn` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessRespawnArgs {
+    /// Record id from `process_recently_killed` (or `record_id` of a kill response)
+    pub id: u64,
+
+    /// Must be true to actually launch; otherwise only the command that would run is returned
+    #[serde(default)]
+    pub confirm: bool,
+
+    /// Optional: launch fully detached so it outlives the server (not tracked by `process_status`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daemon: Option<DaemonOptions>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_respawn` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessRespawnOutput {
+    /// Whether the call succeeded (a preview without `confirm` also succeeds)
+    pub success: bool,
+    /// Record id that was replayed
+    pub id: u64,
+    /// Whether a process was launched
+    pub spawned: bool,
+    /// PID of the new process
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+    /// Id of the new process in `process_status`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supervisor_id: Option<u64>,
+    /// Program that runs (recorded executable, falling back to argv[0])
+    pub program: String,
+    /// Arguments after the program
+    pub args: Vec<String>,
+    /// Working directory the process starts in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    /// Number of environment variables restored
+    pub env_count: usize,
+    /// Output and pidfile locations when launched as a daemon
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daemon: Option<DaemonInfo>,
+    /// Differences from the original run worth knowing about
+    pub notes: Vec<String>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_respawn` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessRespawnPromptArgs {}
+
+/// Prompt provider for `process_respawn` tool
+pub struct ProcessRespawnPrompts;
+
+impl SealedPromptProvider for ProcessRespawnPrompts {}
+
+impl PromptProvider for ProcessRespawnPrompts {
+    type PromptArgs = ProcessRespawnPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("I killed the wrong process. How do I bring it back?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "Find the record with process_recently_killed, then preview the relaunch:\n\n\
+                     process_respawn({\"id\": 3})\n\n\
+                     Without confirm nothing runs; the response shows program, args, cwd and \
+                     env_count. Show that to the user, and only after they agree call:\n\n\
+                     process_respawn({\"id\": 3, \"confirm\": true})\n\n\
+                     The new process gets a new PID and runs as the server's user. In-memory \
+                     state of the original process is not restored. Add \"daemon\": \
+                     {\"pidfile\": \"/tmp/app.pid\"} when it must keep running after the server \
+                     stops (its output then goes to files instead of process_logs).",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessRespawnArgs {
+    type Output = ProcessRespawnOutput;
+    type Prompts = ProcessRespawnPrompts;
+
+    const NAME: &'static str = PROCESS_RESPAWN;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Relaunch a recently killed process with its recorded cmdline, cwd and env. Previews unless confirm is true";
+}
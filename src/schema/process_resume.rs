@@ -0,0 +1,110 @@
+//! Schema types for process_resume tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for continuing one stopped process
+pub const PROCESS_RESUME: &str = "process_resume";
+
+// ============================================================================
+// PROCESS RESUME TOOL
+// ============================================================================
+
+/// Arguments for `process_resume` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessResumeArgs {
+    /// Process ID to continue (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_resume` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessResumeOutput {
+    /// Whether the process is running now (`running`)
+    pub success: bool,
+    /// Process ID that was continued
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Scheduler state before the call, normally `Stopped`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_state: Option<String>,
+    /// Scheduler state after the call
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// It was stopped before the call
+    pub was_stopped: bool,
+    /// It was seen out of the stopped state within a second of the signal
+    pub running: bool,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_resume` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessResumePromptArgs {}
+
+/// Prompt provider for `process_resume` tool
+pub struct ProcessResumePrompts;
+
+impl SealedPromptProvider for ProcessResumePrompts {}
+
+impl PromptProvider for ProcessResumePrompts {
+    type PromptArgs = ProcessResumePromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("I'm done looking at PID 7310, let it run again."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_resume({\"pid\": 7310})\n\n\
+                     Sends SIGCONT and reports the state before (Stopped) and after, with running \
+                     true once the process left the stopped state. A process held by a debugger \
+                     stays stopped; trees frozen with process_freeze need process_thaw.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessResumeArgs {
+    type Output = ProcessResumeOutput;
+    type Prompts = ProcessResumePrompts;
+
+    const NAME: &'static str = PROCESS_RESUME;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Continue a stopped process with SIGCONT and report whether it runs again";
+}
@@ -0,0 +1,175 @@
+//! Schema types for process_run tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{ExitInfo, IoPriority, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for one-shot command execution
+pub const PROCESS_RUN: &str = "process_run";
+
+/// Default for [`ProcessRunArgs::timeout_secs`]
+pub const DEFAULT_RUN_TIMEOUT_SECS: u64 = 60;
+
+/// Default for [`ProcessRunArgs::max_output_bytes`]
+pub const DEFAULT_RUN_OUTPUT_BYTES: usize = 256 * 1024;
+
+fn default_timeout_secs() -> u64 {
+    DEFAULT_RUN_TIMEOUT_SECS
+}
+
+fn default_max_output_bytes() -> usize {
+    DEFAULT_RUN_OUTPUT_BYTES
+}
+
+// ============================================================================
+// PROCESS RUN TOOL
+// ============================================================================
+
+/// Arguments for `process_run` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessRunArgs {
+    /// Program to run, or the full command line when `shell` is true
+    pub command: String,
+
+    /// Optional: arguments after the program (not allowed with `shell`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+
+    /// Optional: run `command` through `sh -c` (`cmd /C` on Windows) for pipes and globs
+    #[serde(default)]
+    pub shell: bool,
+
+    /// Optional: working directory (default: server's)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+
+    /// Optional: extra environment as `KEY=VALUE` entries, on top of the server's environment
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env: Vec<String>,
+
+    /// Optional: text written to the command's stdin (stdin is empty otherwise)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stdin: Option<String>,
+
+    /// Optional: kill the command after this many seconds (default: 60)
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+
+    /// Optional: bytes kept per stream; only the tail is kept beyond it (default: 262144)
+    #[serde(default = "default_max_output_bytes")]
+    pub max_output_bytes: usize,
+
+    /// Optional: I/O scheduling for the command, e.g. `{"class": "idle"}` (Linux only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub io_priority: Option<IoPriority>,
+
+    /// Optional: CPU priority from the start, -20 (highest) to 19 (lowest); the nearest
+    /// priority class on Windows. Negative values usually need root
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nice: Option<i32>,
+
+    /// Optional: logical CPUs the command and its threads are pinned to, e.g. `[2, 3]`
+    /// (Linux and Windows)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cpus: Vec<usize>,
+
+    /// Optional: sandbox profile from the server config, e.g. `"workspace"` (default: the
+    /// configured default profile, if any)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox: Option<String>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_run` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessRunOutput {
+    /// Command exited with code 0 before the timeout
+    pub success: bool,
+    /// Command line that ran
+    pub command: String,
+    /// Process ID it had
+    pub pid: u32,
+    /// Killed because `timeout_secs` elapsed (or the request was cancelled)
+    pub timed_out: bool,
+    /// Exit code, signal and duration
+    pub exit: ExitInfo,
+    /// Captured stdout (lossy UTF-8)
+    pub stdout: String,
+    /// Captured stderr (lossy UTF-8)
+    pub stderr: String,
+    /// Leading stdout was dropped to stay within `max_output_bytes`
+    pub stdout_truncated: bool,
+    /// Leading stderr was dropped to stay within `max_output_bytes`
+    pub stderr_truncated: bool,
+    /// Sandbox profile the command ran under
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox: Option<String>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_run` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessRunPromptArgs {}
+
+/// Prompt provider for `process_run` tool
+pub struct ProcessRunPrompts;
+
+impl SealedPromptProvider for ProcessRunPrompts {}
+
+impl PromptProvider for ProcessRunPrompts {
+    type PromptArgs = ProcessRunPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Run the tests and tell me what failed."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_run({\"command\": \"cargo\", \"args\": [\"test\"], \"timeout_secs\": 300}) \
+                     waits for the command and returns:\n\
+                     - exit.code / exit.signal_name and exit.duration_secs\n\
+                     - stdout and stderr (tail kept beyond max_output_bytes)\n\
+                     - timed_out when it had to be killed\n\n\
+                     Use \"shell\": true with a full command line for pipes, e.g. \
+                     {\"command\": \"ls | wc -l\", \"shell\": true}. The command and anything it \
+                     started are killed at the timeout.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessRunArgs {
+    type Output = ProcessRunOutput;
+    type Prompts = ProcessRunPrompts;
+
+    const NAME: &'static str = PROCESS_RUN;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Run a one-shot command, wait up to a timeout and return its exit code, stdout and stderr";
+}
@@ -0,0 +1,107 @@
+//! Schema types for process_sampling tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for reading and adjusting the sampling cadence
+pub const PROCESS_SAMPLING: &str = "process_sampling";
+
+// ============================================================================
+// PROCESS SAMPLING TOOL
+// ============================================================================
+
+/// Arguments for `process_sampling` tool
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessSamplingArgs {
+    /// Optional: new watch monitor period in milliseconds (clamped to 100..=60000)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub watch_interval_ms: Option<u64>,
+
+    /// Optional: how long `process_list` may reuse one process table in milliseconds (0 = always fresh, at most 60000)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list_max_age_ms: Option<u64>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_sampling` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessSamplingOutput {
+    /// Whether the call succeeded
+    pub success: bool,
+    /// Watch monitor period now in effect
+    pub watch_interval_ms: u64,
+    /// `process_list` table reuse window now in effect
+    pub list_max_age_ms: u64,
+    /// Whether any value differs from before the call
+    pub changed: bool,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_sampling` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessSamplingPromptArgs {}
+
+/// Prompt provider for `process_sampling` tool
+pub struct ProcessSamplingPrompts;
+
+impl SealedPromptProvider for ProcessSamplingPrompts {}
+
+impl PromptProvider for ProcessSamplingPrompts {
+    type PromptArgs = ProcessSamplingPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text(
+                    "The build box has thousands of processes and the server keeps a core busy. Can it sample less?",
+                ),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "Check the current cadence with process_sampling({}), then slow it down:\n\n\
+                     process_sampling({\"watch_interval_ms\": 5000, \"list_max_age_ms\": 2000})\n\n\
+                     Watches are then checked every 5 seconds, so exits and threshold crossings \
+                     are noticed up to 5 seconds late. process_list calls within 2 seconds of a \
+                     full scan reuse that scan (CPU and memory may be that old). Set \
+                     list_max_age_ms back to 0 when fresh numbers matter. Changes last until the \
+                     server restarts; the `sampling` config section sets the startup values.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessSamplingArgs {
+    type Output = ProcessSamplingOutput;
+    type Prompts = ProcessSamplingPrompts;
+
+    const NAME: &'static str = PROCESS_SAMPLING;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Show or adjust the watch sampling period and how long process_list reuses a process table";
+}
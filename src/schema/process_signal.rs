@@ -0,0 +1,118 @@
+//! Schema types for process_signal tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{KillBudget, ProcessSignal, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for delivering a single signal
+pub const PROCESS_SIGNAL: &str = "process_signal";
+
+// ============================================================================
+// PROCESS SIGNAL TOOL
+// ============================================================================
+
+/// Arguments for `process_signal` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessSignalArgs {
+    /// Process ID to signal (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Signal to send by name: kill, term, int, hup, quit, usr1 or usr2 (or "SIGHUP" etc.)
+    pub signal: ProcessSignal,
+
+    /// Set only after the user agreed, when a previous call said the signal needs confirmation
+    #[serde(default)]
+    pub confirm: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_signal` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessSignalOutput {
+    /// Whether the signal was delivered
+    pub success: bool,
+    /// Process ID that was signalled
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Signal delivered, e.g. "SIGHUP"
+    pub signal: String,
+    /// The signal ends the process unless it handles it (kill, term, int, quit), so it used kill budget
+    pub terminating: bool,
+    /// Kill budget left after this operation, for terminating signals
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget: Option<KillBudget>,
+    /// Id of the snapshot stored for `process_recently_killed`, for terminating signals
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_id: Option<u64>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_signal` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessSignalPromptArgs {}
+
+/// Prompt provider for `process_signal` tool
+pub struct ProcessSignalPrompts;
+
+impl SealedPromptProvider for ProcessSignalPrompts {}
+
+impl PromptProvider for ProcessSignalPrompts {
+    type PromptArgs = ProcessSignalPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Make nginx reload its config without restarting it."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_signal({\"pid\": 812, \"signal\": \"hup\"})\n\n\
+                     Sends SIGHUP to the nginx master process, which re-reads its configuration. \
+                     The call returns once the signal is delivered; it does not wait for the \
+                     process to react. hup, usr1 and usr2 leave the kill budget alone; kill, \
+                     term, int and quit use it like process_kill.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessSignalArgs {
+    type Output = ProcessSignalOutput;
+    type Prompts = ProcessSignalPrompts;
+
+    const NAME: &'static str = PROCESS_SIGNAL;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Send a named signal (term, hup, usr1, int, ...) to a process";
+}
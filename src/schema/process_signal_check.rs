@@ -0,0 +1,167 @@
+//! Schema types for process_signal_check tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{ProcessSignal, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for checking how a process would take a signal
+pub const PROCESS_SIGNAL_CHECK: &str = "process_signal_check";
+
+// ============================================================================
+// PROCESS SIGNAL CHECK TOOL
+// ============================================================================
+
+/// Arguments for `process_signal_check` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessSignalCheckArgs {
+    /// Process ID to check (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: signal a graceful shutdown would send (default: term)
+    #[serde(default = "default_signal")]
+    pub signal: ProcessSignal,
+
+    /// Optional: also send SIGURG, which no process dies of, and watch whether a thread wakes
+    /// up to handle it (only when the process handles SIGURG, as Go programs do)
+    #[serde(default)]
+    pub wake_probe: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+fn default_signal() -> ProcessSignal {
+    ProcessSignal::Term
+}
+
+/// What the checked signal would do to the process
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SignalVerdict {
+    /// A handler runs: the process can shut down cleanly
+    Graceful,
+    /// No handler: the kernel's default action ends the process at once, without cleanup
+    DefaultAction,
+    /// The handler exists but every thread blocks the signal; it stays pending until unblocked
+    Blocked,
+    /// Ignored (SIG_IGN, or a namespace init without a handler): the signal has no effect
+    Ignored,
+    /// Stopped or traced: the signal is only acted on after SIGCONT or the tracer lets go
+    Stopped,
+    /// Already exited and waiting for its parent to collect the status
+    Zombie,
+    /// The server may not signal this process
+    NotPermitted,
+    /// Signal dispositions cannot be read on this platform or for this process
+    Unknown,
+}
+
+/// Output from `process_signal_check` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessSignalCheckOutput {
+    /// Whether the check completed
+    pub success: bool,
+    /// Process ID that was checked
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Signal that was assessed, e.g. "SIGTERM"
+    pub signal: String,
+    /// Whether the server may signal it (signal 0 was not refused; absent where there is no signal 0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permitted: Option<bool>,
+    /// Scheduler state, e.g. `sleeping`, `running`, `disk_sleep`, `stopped`, `traced` or `zombie`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// What `signal` would do
+    pub verdict: SignalVerdict,
+    /// Whether sending `signal` should lead to a clean shutdown
+    pub graceful: bool,
+    /// Signals with a handler installed (`SigCgt`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub handled: Vec<String>,
+    /// Signals set to SIG_IGN (`SigIgn`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignored: Vec<String>,
+    /// Signals every thread blocks (`SigBlk` of all threads)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocked: Vec<String>,
+    /// Signals already queued and not yet handled (`SigPnd`, `ShdPnd`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pending: Vec<String>,
+    /// Whether a thread ran after the SIGURG of `wake_probe` (absent when not probed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub woke: Option<bool>,
+    /// Facts behind the verdict, most decisive first
+    pub notes: Vec<String>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_signal_check` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessSignalCheckPromptArgs {}
+
+/// Prompt provider for `process_signal_check` tool
+pub struct ProcessSignalCheckPrompts;
+
+impl SealedPromptProvider for ProcessSignalCheckPrompts {}
+
+impl PromptProvider for ProcessSignalCheckPrompts {
+    type PromptArgs = ProcessSignalCheckPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Stop the worker (PID 3120) without losing its queue."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "First check that it can shut down cleanly at all:\n\n\
+                     process_signal_check({\"pid\": 3120})\n\n\
+                     verdict graceful means a SIGTERM handler is installed, so process_terminate can \
+                     let it drain. default_action means SIGTERM kills it as abruptly as SIGKILL; \
+                     ignored or blocked means SIGTERM will do nothing and the plan should not wait \
+                     on it; stopped means it needs SIGCONT first. No signal that ends the process \
+                     is sent by the check.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessSignalCheckArgs {
+    type Output = ProcessSignalCheckOutput;
+    type Prompts = ProcessSignalCheckPrompts;
+
+    const NAME: &'static str = PROCESS_SIGNAL_CHECK;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Check whether a process would shut down gracefully on a signal, without ending it";
+}
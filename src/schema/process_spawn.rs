@@ -0,0 +1,177 @@
+//! Schema types for process_spawn tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{ExitInfo, IoPriority, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for launching a supervised background process
+pub const PROCESS_SPAWN: &str = "process_spawn";
+
+/// Default for [`ProcessSpawnArgs::wait_ms`]
+pub const DEFAULT_SPAWN_WAIT_MS: u64 = 500;
+
+/// Upper bound of [`ProcessSpawnArgs::wait_ms`]
+pub const MAX_SPAWN_WAIT_MS: u64 = 30_000;
+
+/// Default for [`ProcessSpawnArgs::output_lines`]
+pub const DEFAULT_SPAWN_OUTPUT_LINES: usize = 50;
+
+fn default_wait_ms() -> u64 {
+    DEFAULT_SPAWN_WAIT_MS
+}
+
+fn default_output_lines() -> usize {
+    DEFAULT_SPAWN_OUTPUT_LINES
+}
+
+// ============================================================================
+// PROCESS SPAWN TOOL
+// ============================================================================
+
+/// Arguments for `process_spawn` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessSpawnArgs {
+    /// Program to start (path or name resolved through PATH)
+    pub command: String,
+
+    /// Optional: arguments after the program
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+
+    /// Optional: working directory (default: server's)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+
+    /// Optional: extra environment as `KEY=VALUE` entries, on top of the server's environment
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env: Vec<String>,
+
+    /// Optional: milliseconds to collect early output before returning; returns sooner when
+    /// the process exits (default: 500, at most 30000)
+    #[serde(default = "default_wait_ms")]
+    pub wait_ms: u64,
+
+    /// Optional: early output lines returned per stream, the first ones (default: 50, 0 for all)
+    #[serde(default = "default_output_lines")]
+    pub output_lines: usize,
+
+    /// Optional: I/O scheduling for the process, e.g. `{"class": "idle"}` (Linux only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub io_priority: Option<IoPriority>,
+
+    /// Optional: CPU priority from the start, -20 (highest) to 19 (lowest); the nearest
+    /// priority class on Windows. Negative values usually need root
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nice: Option<i32>,
+
+    /// Optional: logical CPUs the process and its threads are pinned to, e.g. `[2, 3]`
+    /// (Linux and Windows)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cpus: Vec<usize>,
+
+    /// Optional: sandbox profile from the server config, e.g. `"workspace"` (default: the
+    /// configured default profile, if any)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox: Option<String>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_spawn` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessSpawnOutput {
+    /// The process started and had not failed by the time the call returned
+    pub success: bool,
+    /// Process ID of the new process
+    pub pid: u32,
+    /// Supervisor id for process_status, process_logs and process_logs_follow
+    pub supervisor_id: u64,
+    /// Command line that was started
+    pub command: String,
+    /// Working directory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    /// Directory of the captured stdout and stderr
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_dir: Option<String>,
+    /// Still running when the call returned
+    pub running: bool,
+    /// Exit code, signal and duration when it already exited
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit: Option<ExitInfo>,
+    /// Early stdout lines (lossy UTF-8)
+    pub stdout: Vec<String>,
+    /// Early stderr lines (lossy UTF-8)
+    pub stderr: Vec<String>,
+    /// More stdout than `output_lines` arrived; the rest is in process_logs
+    pub stdout_truncated: bool,
+    /// More stderr than `output_lines` arrived; the rest is in process_logs
+    pub stderr_truncated: bool,
+    /// Sandbox profile it runs under
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox: Option<String>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_spawn` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessSpawnPromptArgs {}
+
+/// Prompt provider for `process_spawn` tool
+pub struct ProcessSpawnPrompts;
+
+impl SealedPromptProvider for ProcessSpawnPrompts {}
+
+impl PromptProvider for ProcessSpawnPrompts {
+    type PromptArgs = ProcessSpawnPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Start the API server in the background and tell me if it came up."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_spawn({\"command\": \"cargo\", \"args\": [\"run\", \"--bin\", \"api\"], \
+                     \"cwd\": \"/srv/api\", \"env\": [\"RUST_LOG=info\"], \"wait_ms\": 3000})\n\n\
+                     The call returns the PID and supervisor id with the first lines of stdout and \
+                     stderr; if the server crashed on startup, running is false and exit holds the \
+                     code. Keep watching with process_logs_follow, stop it with process_terminate.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessSpawnArgs {
+    type Output = ProcessSpawnOutput;
+    type Prompts = ProcessSpawnPrompts;
+
+    const NAME: &'static str = PROCESS_SPAWN;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Start a supervised background process and return its PID with early output";
+}
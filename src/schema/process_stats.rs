@@ -0,0 +1,145 @@
+//! Schema types for process_stats tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for aggregate process statistics
+pub const PROCESS_STATS: &str = "process_stats";
+
+// ============================================================================
+// PROCESS STATS TOOL
+// ============================================================================
+
+/// Arguments for `process_stats` tool
+///
+/// Filters combine like in `process_list`: a process counts only if it matches all given.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessStatsArgs {
+    /// Optional: filter by name (case-insensitive substring match)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+
+    /// Optional: only processes whose executable or working directory is below this path
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Optional: only these PIDs
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pids: Vec<u32>,
+
+    /// Optional: only members of this group defined via `process_group_define`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+
+    /// Optional: only processes tagged with this tag via `process_tag`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+
+    /// Optional: server-defined filters that must all match (an unknown name lists the available ones)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub named_filters: Vec<String>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Total and distribution of one metric across the matched processes
+///
+/// Percentiles use the nearest-rank method; all values are 0 when nothing matched.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema)]
+pub struct MetricStats<T> {
+    /// Sum over all matched processes
+    pub total: T,
+    /// Arithmetic mean
+    pub mean: f64,
+    /// Median
+    pub p50: T,
+    /// 90th percentile
+    pub p90: T,
+    /// 99th percentile
+    pub p99: T,
+    /// Largest value
+    pub max: T,
+    /// Process holding the largest value, unless every value is 0
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_pid: Option<u32>,
+}
+
+/// Output from `process_stats` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessStatsOutput {
+    /// Whether the statistics were computed
+    pub success: bool,
+    /// Number of matched processes
+    pub count: usize,
+    /// CPU usage in percent of one core
+    pub cpu_percent: MetricStats<f64>,
+    /// Resident memory in bytes (shared pages are counted once per process, so the total can
+    /// exceed what the group really holds)
+    pub rss_bytes: MetricStats<u64>,
+    /// Distinct process names among the matches
+    pub names: usize,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_stats` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessStatsPromptArgs {}
+
+/// Prompt provider for `process_stats` tool
+pub struct ProcessStatsPrompts;
+
+impl SealedPromptProvider for ProcessStatsPrompts {}
+
+impl PromptProvider for ProcessStatsPrompts {
+    type PromptArgs = ProcessStatsPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("How much memory is everything under /srv/app using?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_stats({\"path\": \"/srv/app\"})\n\n\
+                     One small object comes back: the number of matching processes plus total, \
+                     mean, p50/p90/p99 and max of CPU and resident memory, with the PID holding \
+                     each maximum. No need to fetch the full process_list and add it up.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessStatsArgs {
+    type Output = ProcessStatsOutput;
+    type Prompts = ProcessStatsPrompts;
+
+    const NAME: &'static str = PROCESS_STATS;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Totals and percentiles of CPU and memory across all processes matching a filter";
+}
@@ -0,0 +1,213 @@
+//! Schema types for process_status tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for the supervised-process status query
+pub const PROCESS_STATUS: &str = "process_status";
+
+// ============================================================================
+// PROCESS STATUS TOOL
+// ============================================================================
+
+/// Arguments for `process_status` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessStatusArgs {
+    /// Optional: supervisor id of one process
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    /// Optional: only processes with this PID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// How a supervised process ended
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExitInfo {
+    /// Exit code 0
+    pub success: bool,
+    /// Exit code (absent when terminated by a signal)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<i32>,
+    /// Terminating signal number (Unix)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal: Option<i32>,
+    /// Terminating signal name, e.g. "SIGSEGV" (Unix)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal_name: Option<String>,
+    /// A core dump was written (Unix)
+    pub core_dumped: bool,
+    /// When the exit was observed (RFC 3339)
+    pub exited_at: String,
+    /// Wall-clock runtime in seconds
+    pub duration_secs: f64,
+    /// What the process consumed, including descendants it waited for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<ResourceUsage>,
+}
+
+/// Resource usage collected when a process is reaped (`wait4` on Unix)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ResourceUsage {
+    /// Peak resident set size (peak working set on Windows); Linux counts the forked server
+    /// image before `exec`, so small commands report at least that
+    pub max_rss_bytes: u64,
+    /// CPU time spent in user mode
+    pub user_cpu_secs: f64,
+    /// CPU time spent in the kernel
+    pub system_cpu_secs: f64,
+    /// Page faults, minor and major (Windows counts soft faults too)
+    pub page_faults: u64,
+    /// Page faults that needed I/O (Unix)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub major_faults: Option<u64>,
+}
+
+/// A process started by this server
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SupervisedProcess {
+    /// Supervisor id (stable for the lifetime of the server)
+    pub id: u64,
+    /// Process ID
+    pub pid: u32,
+    /// Command line that was launched
+    pub command: String,
+    /// Working directory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    /// Directory holding captured stdout/stderr segments
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_dir: Option<String>,
+    /// Tool that launched it, e.g. "process_respawn"
+    pub origin: String,
+    /// Connection ID of the client that requested the launch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client: Option<String>,
+    /// Launch time (RFC 3339)
+    pub started_at: String,
+    /// Still running
+    pub running: bool,
+    /// Exit details once it has exited
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit: Option<ExitInfo>,
+    /// Live descendants orphaned by a double fork and adopted by the server (subreaper mode)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub orphans: Vec<u32>,
+}
+
+/// Output from `process_status` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessStatusOutput {
+    /// Whether the query succeeded
+    pub success: bool,
+    /// Number of processes returned
+    pub count: usize,
+    /// Supervised processes, newest first
+    pub processes: Vec<SupervisedProcess>,
+    /// Whether orphaned descendants reparent to the server instead of init (Linux)
+    pub subreaper: bool,
+    /// Orphans reaped so far
+    pub reaper: ReaperStats,
+}
+
+/// What the reaper collected since the server started
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ReaperStats {
+    /// Whether the reaper runs (subreaper mode, or the server is PID 1)
+    pub active: bool,
+    /// Adopted orphans whose exit status was collected
+    pub reaped: u64,
+    /// How many of them were killed by a signal
+    pub signalled: u64,
+    /// Zombie children left at the last pass, normally ones Tokio is about to wait for
+    pub pending: usize,
+    /// Most recent reaps, newest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent: Vec<ReapedOrphan>,
+}
+
+/// One orphan collected by the reaper
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReapedOrphan {
+    /// Process ID
+    pub pid: u32,
+    /// Process name at exit
+    pub name: String,
+    /// Exit code (absent when terminated by a signal)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<i32>,
+    /// Terminating signal name, e.g. "SIGKILL"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal_name: Option<String>,
+    /// When it was reaped (RFC 3339)
+    pub reaped_at: String,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_status` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessStatusPromptArgs {}
+
+/// Prompt provider for `process_status` tool
+pub struct ProcessStatusPrompts;
+
+impl SealedPromptProvider for ProcessStatusPrompts {}
+
+impl PromptProvider for ProcessStatusPrompts {
+    type PromptArgs = ProcessStatusPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("The process I started crashed. What was its exit code?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_status({\"pid\": 12345}) shows processes started by this server. \
+                     Exited ones keep an exit record for a while:\n\
+                     - exit.code: exit status (absent when killed by a signal)\n\
+                     - exit.signal / exit.signal_name: e.g. 11 / SIGSEGV\n\
+                     - exit.core_dumped and exit.duration_secs\n\n\
+                     Processes the server did not start have no exit record.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessStatusArgs {
+    type Output = ProcessStatusOutput;
+    type Prompts = ProcessStatusPrompts;
+
+    const NAME: &'static str = PROCESS_STATUS;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Show processes started by this server, including exit code, signal and duration after they exit";
+}
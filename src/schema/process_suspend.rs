@@ -0,0 +1,111 @@
+//! Schema types for process_suspend tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for pausing one process
+pub const PROCESS_SUSPEND: &str = "process_suspend";
+
+// ============================================================================
+// PROCESS SUSPEND TOOL
+// ============================================================================
+
+/// Arguments for `process_suspend` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessSuspendArgs {
+    /// Process ID to pause (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_suspend` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessSuspendOutput {
+    /// Whether the process is stopped now: seen stopped after SIGSTOP, or already before
+    pub success: bool,
+    /// Process ID that was paused
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Scheduler state before the call, e.g. `Runnable` or `Sleeping`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_state: Option<String>,
+    /// Scheduler state after the call
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// The process was seen stopped within a second of the signal
+    pub stopped: bool,
+    /// It was already stopped before the call
+    pub already_stopped: bool,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_suspend` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessSuspendPromptArgs {}
+
+/// Prompt provider for `process_suspend` tool
+pub struct ProcessSuspendPrompts;
+
+impl SealedPromptProvider for ProcessSuspendPrompts {}
+
+impl PromptProvider for ProcessSuspendPrompts {
+    type PromptArgs = ProcessSuspendPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("PID 7310 is pegging a core; pause it but don't kill it."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_suspend({\"pid\": 7310})\n\n\
+                     Sends SIGSTOP and reports the state it was in before (e.g. Runnable) and \
+                     that it is now Stopped. It keeps its memory and open files; \
+                     process_resume continues it. For a job that forks children, \
+                     process_freeze stops the whole tree.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessSuspendArgs {
+    type Output = ProcessSuspendOutput;
+    type Prompts = ProcessSuspendPrompts;
+
+    const NAME: &'static str = PROCESS_SUSPEND;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Pause one process with SIGSTOP and report the state it was in";
+}
@@ -0,0 +1,143 @@
+//! Schema types for process_tag tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for attaching tags and notes to a PID
+pub const PROCESS_TAG: &str = "process_tag";
+
+/// Tag that makes `process_kill` refuse the process
+pub const DO_NOT_KILL_TAG: &str = "do-not-kill";
+
+// ============================================================================
+// PROCESS TAG TOOL
+// ============================================================================
+
+/// Arguments for `process_tag` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessTagArgs {
+    /// Process ID to tag (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: tags to add, e.g. ["my-dev-server"] or ["do-not-kill"]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
+    /// Optional: tags to remove
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remove: Vec<String>,
+
+    /// Optional: free-form note (empty string clears it)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+
+    /// Remove all tags and the note before applying `tags`/`note`
+    #[serde(default)]
+    pub clear: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Tags and note attached to a running process
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TaggedProcess {
+    /// Process ID
+    pub pid: u32,
+    /// Process name when tagged
+    pub name: String,
+    /// Process start time in seconds since the Unix epoch (guards against PID reuse)
+    pub start_time: u64,
+    /// Reuse-safe handle to pass as `proc_id` to mutating tools
+    pub proc_id: String,
+    /// Tags, sorted
+    pub tags: Vec<String>,
+    /// Free-form note
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// When the entry was created (RFC 3339)
+    pub tagged_at: String,
+}
+
+/// Output from `process_tag` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessTagOutput {
+    /// Whether the update succeeded
+    pub success: bool,
+    /// Process ID that was tagged
+    pub pid: u32,
+    /// Entry after the update (`None` when nothing is attached any more)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entry: Option<TaggedProcess>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_tag` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessTagPromptArgs {}
+
+/// Prompt provider for `process_tag` tool
+pub struct ProcessTagPrompts;
+
+impl SealedPromptProvider for ProcessTagPrompts {}
+
+impl PromptProvider for ProcessTagPrompts {
+    type PromptArgs = ProcessTagPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("How do I keep track of which PID is my dev server across turns?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "Tag it on the server:\n\n\
+                     process_tag({\"pid\": 12345, \"tags\": [\"my-dev-server\", \"do-not-kill\"], \
+                     \"note\": \"vite on :5173\"})\n\n\
+                     Later find it with process_list({\"tag\": \"my-dev-server\"}) or \
+                     process_tags({}). Tags vanish automatically when the process exits. \
+                     The do-not-kill tag makes process_kill refuse the PID until the tag is \
+                     removed with process_tag({\"pid\": 12345, \"remove\": [\"do-not-kill\"]}).",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessTagArgs {
+    type Output = ProcessTagOutput;
+    type Prompts = ProcessTagPrompts;
+
+    const NAME: &'static str = PROCESS_TAG;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Attach server-side tags and a note to a PID; cleared when the process exits. The do-not-kill tag guards process_kill";
+}
@@ -0,0 +1,95 @@
+//! Schema types for process_tags tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity, TaggedProcess};
+
+/// Tool name for listing tagged processes
+pub const PROCESS_TAGS: &str = "process_tags";
+
+// ============================================================================
+// PROCESS TAGS TOOL
+// ============================================================================
+
+/// Arguments for `process_tags` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessTagsArgs {
+    /// Optional: only return processes carrying this tag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_tags` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessTagsOutput {
+    /// Whether the query succeeded
+    pub success: bool,
+    /// Number of tagged processes returned
+    pub count: usize,
+    /// Tagged live processes, sorted by PID
+    pub entries: Vec<TaggedProcess>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_tags` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessTagsPromptArgs {}
+
+/// Prompt provider for `process_tags` tool
+pub struct ProcessTagsPrompts;
+
+impl SealedPromptProvider for ProcessTagsPrompts {}
+
+impl PromptProvider for ProcessTagsPrompts {
+    type PromptArgs = ProcessTagsPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Which processes did I tag earlier?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_tags({}) lists every live tagged process with its tags and note. \
+                     Pass {\"tag\": \"my-dev-server\"} to narrow it down. Processes that exited \
+                     since they were tagged are not listed.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessTagsArgs {
+    type Output = ProcessTagsOutput;
+    type Prompts = ProcessTagsPrompts;
+
+    const NAME: &'static str = PROCESS_TAGS;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "List live processes tagged with process_tag, optionally filtered by tag";
+}
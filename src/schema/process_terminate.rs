@@ -0,0 +1,186 @@
+//! Schema types for process_terminate tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{ContainerDelivery, KillBudget, ProcessSignal, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for graceful termination with escalating signals
+pub const PROCESS_TERMINATE: &str = "process_terminate";
+
+/// Most steps accepted in one escalation plan
+pub const MAX_ESCALATION_STEPS: usize = 8;
+
+/// Longest wait accepted for a single step
+pub const MAX_STEP_WAIT_MS: u64 = 120_000;
+
+/// Shortest wait after the last step, so its effect can be observed
+pub const FINAL_STEP_WAIT_MS: u64 = 500;
+
+// ============================================================================
+// PROCESS TERMINATE TOOL
+// ============================================================================
+
+/// One step of an escalation plan: send `signal`, then wait up to `wait_ms` for the exit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct EscalationStep {
+    /// Signal to send
+    pub signal: ProcessSignal,
+    /// Milliseconds to wait for the process to exit before the next step
+    #[serde(default)]
+    pub wait_ms: u64,
+}
+
+/// Plan used when a call passes none: SIGTERM, five seconds, then SIGKILL
+#[must_use]
+pub fn default_escalation() -> Vec<EscalationStep> {
+    vec![
+        EscalationStep {
+            signal: ProcessSignal::Term,
+            wait_ms: 5000,
+        },
+        EscalationStep {
+            signal: ProcessSignal::Kill,
+            wait_ms: 0,
+        },
+    ]
+}
+
+/// Arguments for `process_terminate` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessTerminateArgs {
+    /// Process ID to terminate (or use `proc_id` or `container`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: `<container_id>:<pid>` with the PID as the container sees it; signals are
+    /// delivered through the container runtime (docker, podman, nerdctl) or nsenter (Linux)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+
+    /// Optional: ordered steps such as `[{"signal": "hup", "wait_ms": 2000}, {"signal": "term",
+    /// "wait_ms": 5000}, {"signal": "kill"}]` (default: term for 5 s, then kill)
+    #[serde(default = "default_escalation")]
+    pub plan: Vec<EscalationStep>,
+
+    /// Set only after the user agreed, when a previous call said the kill needs confirmation
+    #[serde(default)]
+    pub confirm: bool,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// What happened at one step of the plan
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EscalationOutcome {
+    /// Signal name, e.g. "SIGTERM"
+    pub signal: String,
+    /// Whether the signal was delivered
+    pub delivered: bool,
+    /// Why it was not delivered (e.g. unsupported on this platform)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Milliseconds waited after this step
+    pub waited_ms: u64,
+    /// Whether the process had exited by the end of this step
+    pub exited: bool,
+}
+
+/// Output from `process_terminate` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessTerminateOutput {
+    /// Whether the process is gone
+    pub success: bool,
+    /// Process ID that was targeted
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Index of the step after which the process exited
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub succeeded_step: Option<usize>,
+    /// Signal of that step, e.g. "SIGTERM"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub succeeded_signal: Option<String>,
+    /// Steps that ran, in order; later steps are skipped once the process exits
+    pub steps: Vec<EscalationOutcome>,
+    /// Milliseconds from the first signal until the exit was seen, or the plan ended
+    pub elapsed_ms: u64,
+    /// Kill budget left after this operation
+    pub budget: KillBudget,
+    /// Id of the snapshot stored for `process_recently_killed`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_id: Option<u64>,
+    /// Container the process ran in, for `container` targets
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<ContainerDelivery>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_terminate` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessTerminatePromptArgs {}
+
+/// Prompt provider for `process_terminate` tool
+pub struct ProcessTerminatePrompts;
+
+impl SealedPromptProvider for ProcessTerminatePrompts {}
+
+impl PromptProvider for ProcessTerminatePrompts {
+    type PromptArgs = ProcessTerminatePromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Shut down nginx (PID 812) cleanly."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "nginx finishes open requests on SIGQUIT, so try that before forcing:\n\n\
+                     process_terminate({\"pid\": 812, \"plan\": [{\"signal\": \"quit\", \"wait_ms\": 10000}, \
+                     {\"signal\": \"term\", \"wait_ms\": 3000}, {\"signal\": \"kill\"}]})\n\n\
+                     Steps run in order and stop as soon as the process exits; succeeded_step and \
+                     succeeded_signal tell which one did it. Without plan the server sends SIGTERM, \
+                     waits 5 seconds and then sends SIGKILL. The call consumes one unit of kill \
+                     budget. On Windows only kill is delivered; other steps are reported as not \
+                     delivered and skipped.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessTerminateArgs {
+    type Output = ProcessTerminateOutput;
+    type Prompts = ProcessTerminatePrompts;
+
+    const NAME: &'static str = PROCESS_TERMINATE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Terminate a process gracefully by escalating through signals, waiting for the exit after each, and report which step worked";
+}
@@ -0,0 +1,97 @@
+//! Schema types for process_thaw tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{FreezeInfo, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for thawing a frozen process tree
+pub const PROCESS_THAW: &str = "process_thaw";
+
+// ============================================================================
+// PROCESS THAW TOOL
+// ============================================================================
+
+/// Arguments for `process_thaw` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessThawArgs {
+    /// Root PID given to `process_freeze` (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_thaw` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessThawOutput {
+    /// Whether the tree was thawed
+    pub success: bool,
+    /// The freeze that was lifted
+    pub freeze: FreezeInfo,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_thaw` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessThawPromptArgs {}
+
+/// Prompt provider for `process_thaw` tool
+pub struct ProcessThawPrompts;
+
+impl SealedPromptProvider for ProcessThawPrompts {}
+
+impl PromptProvider for ProcessThawPrompts {
+    type PromptArgs = ProcessThawPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Let the build continue."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_thaw({\"pid\": 4410})\n\n\
+                     Every member resumes; with the cgroup method the tree also moves back to \
+                     its original cgroup.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessThawArgs {
+    type Output = ProcessThawOutput;
+    type Prompts = ProcessThawPrompts;
+
+    const NAME: &'static str = PROCESS_THAW;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Resume a process tree frozen by process_freeze";
+}
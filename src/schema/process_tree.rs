@@ -0,0 +1,132 @@
+//! Schema types for process_tree tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for the parent/child hierarchy
+pub const PROCESS_TREE: &str = "process_tree";
+
+// ============================================================================
+// PROCESS TREE TOOL
+// ============================================================================
+
+/// Arguments for `process_tree` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessTreeArgs {
+    /// Optional: root the tree at this process (default: every process without a parent)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: levels below the roots to include (default: all); deeper children are only counted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_depth: Option<usize>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// One process with its children
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessTreeNode {
+    /// Process ID
+    pub pid: u32,
+    /// Reuse-safe handle for mutating tools
+    pub proc_id: String,
+    /// Process name
+    pub name: String,
+    /// CPU usage percent (may exceed 100 on multi-core hosts)
+    pub cpu_percent: f32,
+    /// Resident memory in bytes
+    pub memory_bytes: u64,
+    /// Children sorted by PID
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<ProcessTreeNode>,
+    /// Descendants left out below `max_depth`
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub hidden_descendants: usize,
+}
+
+fn is_zero(count: &usize) -> bool {
+    *count == 0
+}
+
+/// Output from `process_tree` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessTreeOutput {
+    /// Whether the tree was built
+    pub success: bool,
+    /// Requested root PID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root: Option<u32>,
+    /// Processes in `roots` and below, hidden descendants excluded
+    pub count: usize,
+    /// The requested process, or every process without a parent, sorted by PID
+    pub roots: Vec<ProcessTreeNode>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_tree` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessTreePromptArgs {}
+
+/// Prompt provider for `process_tree` tool
+pub struct ProcessTreePrompts;
+
+impl SealedPromptProvider for ProcessTreePrompts {}
+
+impl PromptProvider for ProcessTreePrompts {
+    type PromptArgs = ProcessTreePromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("What did my dev server start?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_tree({\"pid\": 4242})\n\n\
+                     Returns PID 4242 with its children nested under it, each with name, CPU and \
+                     memory and its own children. max_depth keeps large trees short; cut-off \
+                     branches report how many descendants they hide.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessTreeArgs {
+    type Output = ProcessTreeOutput;
+    type Prompts = ProcessTreePrompts;
+
+    const NAME: &'static str = PROCESS_TREE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Show the parent/child process hierarchy as nested JSON";
+}
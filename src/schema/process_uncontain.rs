@@ -0,0 +1,97 @@
+//! Schema types for process_uncontain tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{ContainmentInfo, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for releasing a contained process
+pub const PROCESS_UNCONTAIN: &str = "process_uncontain";
+
+// ============================================================================
+// PROCESS UNCONTAIN TOOL
+// ============================================================================
+
+/// Arguments for `process_uncontain` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessUncontainArgs {
+    /// Process ID given to `process_contain` (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_uncontain` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessUncontainOutput {
+    /// Whether the process was released
+    pub success: bool,
+    /// The containment that was in effect, with final usage figures
+    pub containment: ContainmentInfo,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_uncontain` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessUncontainPromptArgs {}
+
+/// Prompt provider for `process_uncontain` tool
+pub struct ProcessUncontainPrompts;
+
+impl SealedPromptProvider for ProcessUncontainPrompts {}
+
+impl PromptProvider for ProcessUncontainPrompts {
+    type PromptArgs = ProcessUncontainPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("The test runner is behaving now; lift its limits."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_uncontain({\"pid\": 9021})\n\n\
+                     The process, and any workers it forked while contained, go back to the \
+                     cgroup it came from and the managed cgroup is removed.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessUncontainArgs {
+    type Output = ProcessUncontainOutput;
+    type Prompts = ProcessUncontainPrompts;
+
+    const NAME: &'static str = PROCESS_UNCONTAIN;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Release a process held by process_contain back to its original cgroup";
+}
@@ -0,0 +1,92 @@
+//! Schema types for process_unwatch tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for removing a watchlist entry
+pub const PROCESS_UNWATCH: &str = "process_unwatch";
+
+// ============================================================================
+// PROCESS UNWATCH TOOL
+// ============================================================================
+
+/// Arguments for `process_unwatch` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessUnwatchArgs {
+    /// Watch id returned by `process_watch`
+    pub id: u64,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_unwatch` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessUnwatchOutput {
+    /// Whether the watch was removed
+    pub success: bool,
+    /// Watch id
+    pub id: u64,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_unwatch` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessUnwatchPromptArgs {}
+
+/// Prompt provider for `process_unwatch` tool
+pub struct ProcessUnwatchPrompts;
+
+impl SealedPromptProvider for ProcessUnwatchPrompts {}
+
+impl PromptProvider for ProcessUnwatchPrompts {
+    type PromptArgs = ProcessUnwatchPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("How do I stop watching a process?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_unwatch({\"id\": 2}) removes the watch. PID watches end on their \
+                     own when the process exits; pattern watches stay until removed. Already \
+                     logged events remain available through process_watch_events.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessUnwatchArgs {
+    type Output = ProcessUnwatchOutput;
+    type Prompts = ProcessUnwatchPrompts;
+
+    const NAME: &'static str = PROCESS_UNWATCH;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Remove a watchlist entry created by process_watch";
+}
@@ -0,0 +1,126 @@
+//! Schema types for process_wait tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{ExitInfo, SummaryStyle, SummaryVerbosity};
+
+/// Tool name for waiting on a process to exit
+pub const PROCESS_WAIT: &str = "process_wait";
+
+/// Default for [`ProcessWaitArgs::timeout_ms`]
+pub const DEFAULT_WAIT_TIMEOUT_MS: u64 = 30_000;
+
+/// Upper bound of [`ProcessWaitArgs::timeout_ms`]
+pub const MAX_WAIT_TIMEOUT_MS: u64 = 600_000;
+
+fn default_timeout_ms() -> u64 {
+    DEFAULT_WAIT_TIMEOUT_MS
+}
+
+// ============================================================================
+// PROCESS WAIT TOOL
+// ============================================================================
+
+/// Arguments for `process_wait` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessWaitArgs {
+    /// Process ID to wait for (or use `proc_id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; a reused PID counts as exited
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: milliseconds to wait before giving up (default: 30000, at most 600000)
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_wait` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessWaitOutput {
+    /// Whether the process exited before the timeout
+    pub success: bool,
+    /// Process ID waited for
+    pub pid: u32,
+    /// Process name, when it was still running at the start of the call
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The process is gone (a zombie or a reused PID counts as gone)
+    pub exited: bool,
+    /// It was already gone when the call started
+    pub already_exited: bool,
+    /// The wait ended because the client cancelled the call
+    pub cancelled: bool,
+    /// Milliseconds waited
+    pub elapsed_ms: u64,
+    /// Exit code, signal and duration, for processes launched through this server
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit: Option<ExitInfo>,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_wait` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessWaitPromptArgs {}
+
+/// Prompt provider for `process_wait` tool
+pub struct ProcessWaitPrompts;
+
+impl SealedPromptProvider for ProcessWaitPrompts {}
+
+impl PromptProvider for ProcessWaitPrompts {
+    type PromptArgs = ProcessWaitPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Tell me when the release build (PID 5120) is finished."),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_wait({\"pid\": 5120, \"timeout_ms\": 600000})\n\n\
+                     Returns as soon as PID 5120 is gone, with the time waited and, if it was \
+                     launched through this server, its exit code. After timeout_ms it returns \
+                     exited false; call it again to keep waiting.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessWaitArgs {
+    type Output = ProcessWaitOutput;
+    type Prompts = ProcessWaitPrompts;
+
+    const NAME: &'static str = PROCESS_WAIT;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Wait until a process exits or a timeout passes, with the exit code where known";
+}
@@ -0,0 +1,156 @@
+//! Schema types for process_watch tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity};
+
+/// Tool name for adding a watchlist entry
+pub const PROCESS_WATCH: &str = "process_watch";
+
+// ============================================================================
+// PROCESS WATCH TOOL
+// ============================================================================
+
+/// Arguments for `process_watch` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessWatchArgs {
+    /// Optional: watch this PID until it exits (exactly one of `pid`/`proc_id`/`pattern`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+
+    /// Optional: reuse-safe handle from process_list instead of `pid`; refused once the PID was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proc_id: Option<String>,
+
+    /// Optional: watch every process whose name matches this case-insensitive glob
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+
+    /// Optional: URL that receives each exit event as a JSON POST
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<String>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// An active watchlist entry
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WatchInfo {
+    /// Watch id
+    pub id: u64,
+    /// Watched PID (PID watches end when the process exits)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+    /// Watched name glob (pattern watches stay until removed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// Webhook receiving exit events
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<String>,
+    /// Live processes currently tracked
+    pub members: Vec<u32>,
+    /// When the watch was created (RFC 3339)
+    pub created_at: String,
+}
+
+/// A watched process exited
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WatchEvent {
+    /// Event id, increasing (use as `since` cursor)
+    pub id: u64,
+    /// Watch that observed the exit
+    pub watch_id: u64,
+    /// Process ID
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Process start time in seconds since the Unix epoch
+    pub started_at: u64,
+    /// When the exit was detected (RFC 3339, within one sampling interval)
+    pub exited_at: String,
+    /// Wall-clock lifetime in seconds
+    pub runtime_secs: u64,
+    /// CPU usage at the last sample before the exit
+    pub last_cpu_percent: f32,
+    /// Resident memory at the last sample before the exit
+    pub last_rss_bytes: u64,
+    /// When the last sample was taken (RFC 3339)
+    pub last_sampled_at: String,
+}
+
+/// Output from `process_watch` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessWatchOutput {
+    /// Whether the watch was created
+    pub success: bool,
+    /// The new watch
+    pub watch: WatchInfo,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_watch` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessWatchPromptArgs {}
+
+/// Prompt provider for `process_watch` tool
+pub struct ProcessWatchPrompts;
+
+impl SealedPromptProvider for ProcessWatchPrompts {}
+
+impl PromptProvider for ProcessWatchPrompts {
+    type PromptArgs = ProcessWatchPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("How do I find out when a long build finishes without polling process_list?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "Put it on the watchlist:\n\n\
+                     process_watch({\"pid\": 12345})\n\
+                     process_watch({\"pattern\": \"cargo\", \"webhook\": \"http://localhost:9000/hook\"})\n\n\
+                     The server samples watched processes every second. When one exits it \
+                     streams a notification to this client, POSTs the event to the webhook if \
+                     given, and logs it. Fetch the log with \
+                     process_watch_events({\"since\": 0}); each event has runtime_secs and the \
+                     last known CPU and memory. Remove a watch with process_unwatch. Exit codes \
+                     are not available for processes the server did not start.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessWatchArgs {
+    type Output = ProcessWatchOutput;
+    type Prompts = ProcessWatchPrompts;
+
+    const NAME: &'static str = PROCESS_WATCH;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "Watch a PID or name pattern and get notified (MCP notification and optional webhook) when processes exit";
+}
@@ -0,0 +1,101 @@
+//! Schema types for process_watch_events tool
+
+use kodegen_config::CATEGORY_PROCESS;
+use kodegen_mcp_schema::ToolArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, SealedPromptProvider};
+use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{SummaryStyle, SummaryVerbosity, WatchEvent, WatchInfo};
+
+/// Tool name for reading the watchlist and its exit events
+pub const PROCESS_WATCH_EVENTS: &str = "process_watch_events";
+
+// ============================================================================
+// PROCESS WATCH EVENTS TOOL
+// ============================================================================
+
+/// Arguments for `process_watch_events` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessWatchEventsArgs {
+    /// Optional: only events with an id greater than this cursor (default: 0 = all retained)
+    #[serde(default)]
+    pub since: u64,
+
+    /// Optional: only events from this watch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub watch_id: Option<u64>,
+
+    /// Optional: summary rendering style ("plain" or "ansi", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<SummaryStyle>,
+
+    /// Optional: summary detail level ("quiet", "normal" or "detailed", default: server setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<SummaryVerbosity>,
+}
+
+/// Output from `process_watch_events` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessWatchEventsOutput {
+    /// Whether the query succeeded
+    pub success: bool,
+    /// Active watches
+    pub watches: Vec<WatchInfo>,
+    /// Exit events, oldest first
+    pub events: Vec<WatchEvent>,
+    /// Cursor to pass as `since` next time
+    pub last_event_id: u64,
+}
+
+// ============================================================================
+// PROMPTS
+// ============================================================================
+
+/// Prompt arguments for `process_watch_events` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessWatchEventsPromptArgs {}
+
+/// Prompt provider for `process_watch_events` tool
+pub struct ProcessWatchEventsPrompts;
+
+impl SealedPromptProvider for ProcessWatchEventsPrompts {}
+
+impl PromptProvider for ProcessWatchEventsPrompts {
+    type PromptArgs = ProcessWatchEventsPromptArgs;
+
+    fn generate_prompts(_args: &Self::PromptArgs) -> Vec<PromptMessage> {
+        vec![
+            PromptMessage {
+                role: PromptMessageRole::User,
+                content: PromptMessageContent::text("Did any of my watched processes exit?"),
+            },
+            PromptMessage {
+                role: PromptMessageRole::Assistant,
+                content: PromptMessageContent::text(
+                    "process_watch_events({\"since\": 0}) returns active watches and the \
+                     retained exit events. Keep last_event_id and pass it as since on the next \
+                     call to see only new exits.",
+                ),
+            },
+        ]
+    }
+
+    fn prompt_arguments() -> Vec<PromptArgument> {
+        vec![]
+    }
+}
+
+// ============================================================================
+// TOOL ARGS IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+impl ToolArgs for ProcessWatchEventsArgs {
+    type Output = ProcessWatchEventsOutput;
+    type Prompts = ProcessWatchEventsPrompts;
+
+    const NAME: &'static str = PROCESS_WATCH_EVENTS;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
+    const DESCRIPTION: &'static str = "List active watches and exit events with runtime and last known CPU/memory";
+}
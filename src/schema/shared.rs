@@ -0,0 +1,273 @@
+//! Schema types shared by several process tools
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Rendering style for the human-readable summary
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SummaryStyle {
+    /// Plain ASCII text without escape codes or icon glyphs
+    Plain,
+    /// ANSI colors and Nerd Font icons (terminal clients)
+    #[default]
+    Ansi,
+}
+
+/// Amount of detail in the human-readable summary
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SummaryVerbosity {
+    /// Single line
+    Quiet,
+    /// Headline plus key facts
+    #[default]
+    Normal,
+    /// Normal summary plus an embedded table of the top entries
+    Detailed,
+}
+
+/// Remaining kill budget for the current throttle window
+///
+/// `None` means the corresponding cap is disabled.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct KillBudget {
+    /// Window length in seconds
+    pub window_secs: u64,
+    /// Kills this client may still perform in the window
+    pub client_remaining: Option<u32>,
+    /// Kills all clients together may still perform in the window
+    pub global_remaining: Option<u32>,
+}
+
+/// Signal delivered by tools that terminate or notify processes
+///
+/// Only `kill` is available on Windows; the others are Unix signals. The
+/// conventional names (`"SIGHUP"`) are accepted as well as the short ones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ProcessSignal {
+    /// SIGKILL: immediate termination, cannot be caught
+    #[default]
+    #[serde(alias = "SIGKILL")]
+    Kill,
+    /// SIGTERM: polite termination request
+    #[serde(alias = "SIGTERM")]
+    Term,
+    /// SIGINT: interrupt (Ctrl+C)
+    #[serde(alias = "SIGINT")]
+    Int,
+    /// SIGHUP: hangup, often "reload config"
+    #[serde(alias = "SIGHUP")]
+    Hup,
+    /// SIGQUIT: quit with core dump
+    #[serde(alias = "SIGQUIT")]
+    Quit,
+    /// SIGUSR1: application-defined
+    #[serde(alias = "SIGUSR1")]
+    Usr1,
+    /// SIGUSR2: application-defined
+    #[serde(alias = "SIGUSR2")]
+    Usr2,
+}
+
+impl ProcessSignal {
+    /// Conventional signal name, e.g. "SIGTERM"
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Kill => "SIGKILL",
+            Self::Term => "SIGTERM",
+            Self::Int => "SIGINT",
+            Self::Hup => "SIGHUP",
+            Self::Quit => "SIGQUIT",
+            Self::Usr1 => "SIGUSR1",
+            Self::Usr2 => "SIGUSR2",
+        }
+    }
+}
+
+/// What happened to one process of a tool that signals several
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SignalResult {
+    /// Process ID
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// Whether the signal was delivered (or would be, on a dry run)
+    pub signalled: bool,
+    /// It was gone by its turn, so there was nothing left to signal
+    #[serde(default)]
+    pub exited: bool,
+    /// Why it was left alone
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Captured output stream of a supervised process
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum LogStream {
+    /// Standard output
+    #[default]
+    Stdout,
+    /// Standard error
+    Stderr,
+}
+
+impl LogStream {
+    /// Stream name, also the log file stem
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Stdout => "stdout",
+            Self::Stderr => "stderr",
+        }
+    }
+}
+
+/// Request to launch a process as a daemon that outlives the server
+///
+/// Relative paths are resolved against the daemon's working directory.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct DaemonOptions {
+    /// Optional: file the daemon's PID is written to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pidfile: Option<String>,
+
+    /// Optional: file stdout is appended to (default: under the server's log directory)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stdout: Option<String>,
+
+    /// Optional: file stderr is appended to (default: under the server's log directory)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stderr: Option<String>,
+}
+
+/// Where a daemonized process writes its output and PID
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DaemonInfo {
+    /// File receiving stdout
+    pub stdout: String,
+    /// File receiving stderr
+    pub stderr: String,
+    /// Pidfile, when one was requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pidfile: Option<String>,
+}
+
+/// Linux I/O scheduling class (see ioprio_set(2))
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum IoClass {
+    /// No class set; the kernel derives best-effort priority from the nice value
+    #[default]
+    None,
+    /// Served first at every level; needs CAP_SYS_ADMIN
+    Realtime,
+    /// Default class, levels 0 (highest) to 7
+    BestEffort,
+    /// Only served when no other process needs the disk
+    Idle,
+}
+
+/// I/O scheduling class plus level within it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct IoPriority {
+    /// Scheduling class
+    pub class: IoClass,
+    /// Level 0 (highest) to 7 for realtime and best-effort; ignored by the other classes
+    #[serde(default = "default_io_level")]
+    pub level: u8,
+}
+
+/// Highest accepted [`IoPriority::level`]
+pub const MAX_IO_LEVEL: u8 = 7;
+
+fn default_io_level() -> u8 {
+    4
+}
+
+/// A process that jumped from idle to pegged and stayed there
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CpuSpike {
+    /// Process ID
+    pub pid: u32,
+    /// Process name
+    pub name: String,
+    /// CPU percent of the sample before the jump
+    pub before_percent: f32,
+    /// Mean CPU percent since the jump (100 = one core)
+    pub cpu_percent: f32,
+    /// Seconds the process has stayed above the threshold
+    pub sustained_secs: f64,
+    /// When the jump was sampled (RFC 3339)
+    pub started_at: String,
+    /// When the spike was reported (RFC 3339)
+    pub detected_at: String,
+}
+
+/// A process start or exit seen by the kernel tracepoints
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TracedProcess {
+    /// Process ID
+    pub pid: u32,
+    /// Parent PID, when the process was still readable after its exec
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<u32>,
+    /// Kernel task name (at most 15 characters)
+    pub name: String,
+    /// Path passed to `execve`; on exit only when the exec was traced too
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    /// When the tracepoint fired (RFC 3339)
+    pub at: String,
+    /// Seconds from the traced exec to the exit; exit events only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runtime_secs: Option<f64>,
+}
+
+/// How a signal reached a process addressed inside its container
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ContainerDelivery {
+    /// Full container ID
+    pub id: String,
+    /// PID inside the container
+    pub pid: u32,
+    /// What delivered the signal: "docker", "podman", "nerdctl" or "nsenter"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub via: Option<String>,
+}
+
+/// Kubernetes pod a process runs in
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct PodInfo {
+    /// Pod UID from the cgroup path
+    pub uid: String,
+    /// Pod namespace, when the kubelet or the node's pod logs know the UID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// Pod name, as `kubectl get pods` shows it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Container name within the pod
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+    /// Runtime container ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container_id: Option<String>,
+    /// QoS class of the pod's cgroup: `guaranteed`, `burstable` or `besteffort`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub qos_class: Option<String>,
+}
+
+impl PodInfo {
+    /// `namespace/name` as `kubectl` takes it, or the UID while the name is unknown
+    #[must_use]
+    pub fn qualified_name(&self) -> String {
+        match (&self.namespace, &self.name) {
+            (Some(namespace), Some(name)) => format!("{namespace}/{name}"),
+            _ => self.uid.clone(),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! Mapping from schema signals to sysinfo signals
+
+use sysinfo::Signal;
+
+use crate::schema::ProcessSignal;
+
+#[must_use]
+pub(crate) fn to_sysinfo(signal: ProcessSignal) -> Signal {
+    match signal {
+        ProcessSignal::Kill => Signal::Kill,
+        ProcessSignal::Term => Signal::Term,
+        ProcessSignal::Int => Signal::Interrupt,
+        ProcessSignal::Hup => Signal::Hangup,
+        ProcessSignal::Quit => Signal::Quit,
+        ProcessSignal::Usr1 => Signal::User1,
+        ProcessSignal::Usr2 => Signal::User2,
+    }
+}
+
+/// Conventional name of a Unix signal number, e.g. 15 -> "SIGTERM"
+#[cfg(unix)]
+#[must_use]
+pub(crate) fn signal_name(signal: i32) -> Option<&'static str> {
+    Some(match signal {
+        libc::SIGHUP => "SIGHUP",
+        libc::SIGINT => "SIGINT",
+        libc::SIGQUIT => "SIGQUIT",
+        libc::SIGILL => "SIGILL",
+        libc::SIGTRAP => "SIGTRAP",
+        libc::SIGABRT => "SIGABRT",
+        libc::SIGBUS => "SIGBUS",
+        libc::SIGFPE => "SIGFPE",
+        libc::SIGKILL => "SIGKILL",
+        libc::SIGUSR1 => "SIGUSR1",
+        libc::SIGSEGV => "SIGSEGV",
+        libc::SIGUSR2 => "SIGUSR2",
+        libc::SIGPIPE => "SIGPIPE",
+        libc::SIGALRM => "SIGALRM",
+        libc::SIGTERM => "SIGTERM",
+        libc::SIGXCPU => "SIGXCPU",
+        libc::SIGXFSZ => "SIGXFSZ",
+        libc::SIGCHLD => "SIGCHLD",
+        libc::SIGCONT => "SIGCONT",
+        libc::SIGSTOP => "SIGSTOP",
+        libc::SIGTSTP => "SIGTSTP",
+        libc::SIGTTIN => "SIGTTIN",
+        libc::SIGTTOU => "SIGTTOU",
+        libc::SIGURG => "SIGURG",
+        libc::SIGVTALRM => "SIGVTALRM",
+        libc::SIGPROF => "SIGPROF",
+        libc::SIGWINCH => "SIGWINCH",
+        libc::SIGIO => "SIGIO",
+        libc::SIGSYS => "SIGSYS",
+        _ => return None,
+    })
+}
+
+/// Unix signal number of a schema signal; `None` where signals are emulated (Windows)
+#[must_use]
+pub(crate) fn number(signal: ProcessSignal) -> Option<i32> {
+    #[cfg(unix)]
+    return Some(match signal {
+        ProcessSignal::Kill => libc::SIGKILL,
+        ProcessSignal::Term => libc::SIGTERM,
+        ProcessSignal::Int => libc::SIGINT,
+        ProcessSignal::Hup => libc::SIGHUP,
+        ProcessSignal::Quit => libc::SIGQUIT,
+        ProcessSignal::Usr1 => libc::SIGUSR1,
+        ProcessSignal::Usr2 => libc::SIGUSR2,
+    });
+    #[cfg(not(unix))]
+    {
+        let _ = signal;
+        None
+    }
+}
+
+/// Names of the signals in a kernel signal mask (bit `n - 1` is signal `n`)
+#[must_use]
+pub(crate) fn mask_names(mask: u64) -> Vec<String> {
+    (1..=64)
+        .filter(|signal| mask & (1 << (signal - 1)) != 0)
+        .map(|signal| {
+            #[cfg(unix)]
+            if let Some(name) = signal_name(signal) {
+                return name.to_string();
+            }
+            // Real-time signals have no fixed name
+            format!("signal {signal}")
+        })
+        .collect()
+}
+
+/// Signals whose default action ends the process (these consume kill budget)
+#[must_use]
+pub(crate) fn terminates(signal: ProcessSignal) -> bool {
+    matches!(
+        signal,
+        ProcessSignal::Kill | ProcessSignal::Term | ProcessSignal::Int | ProcessSignal::Quit
+    )
+}
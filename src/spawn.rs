@@ -0,0 +1,543 @@
+//! Spawn subsystem shared by tools that launch processes
+//!
+//! Long-lived children are started detached from the server: own process
+//! group on Unix, null stdin, and never killed when the handle is dropped.
+//! Stdout and stderr are piped; [`crate::supervisor::Supervisor`] owns the
+//! child handle, drains the pipes into rotated logs and reaps it. One-shot
+//! commands (`process_run`) share the same [`command`] builder.
+//!
+//! Every launch through [`command`] carries a [`SPAWN_MARKER_ENV`] value of
+//! its own, inherited by everything it forks. With the server as child
+//! subreaper (Linux), descendants orphaned by a double fork reparent to the
+//! server instead of init, and the marker tells which launch they came from
+//! (see [`adopted`]). Only [`crate::reaper`] waits for adopted orphans; every
+//! child forked here goes through [`launch`] so the reaper never takes an
+//! exit status meant for its launcher, which gets resource usage with it.
+//!
+//! Daemons ([`spawn_daemon`]) are detached further so they outlive the server:
+//! new session and double fork on Unix, a detached console process on Windows,
+//! output appended to files and an optional pidfile.
+
+use std::collections::BTreeSet;
+use std::fs::{File, OpenOptions};
+use std::path::{Path, PathBuf};
+use std::process::{ExitStatus, Stdio};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::config::OutputLogConfig;
+use crate::platform::{MAX_NICE, MIN_NICE};
+use crate::provider::{ProcessEntry, ProcessProvider};
+use crate::schema::{ExitInfo, IoPriority, MAX_IO_LEVEL, ResourceUsage};
+
+/// Environment variable tagging a launch and everything it forks
+pub(crate) const SPAWN_MARKER_ENV: &str = "KODEGEN_PROCESS_SPAWN";
+
+/// Children forked by the server itself whose exit status is not collected yet
+static DIRECT: Mutex<BTreeSet<u32>> = Mutex::new(BTreeSet::new());
+
+fn direct() -> std::sync::MutexGuard<'static, BTreeSet<u32>> {
+    DIRECT.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Run `f` with the direct set locked so no child can be forked or reaped meanwhile
+pub(crate) fn with_direct<R>(f: impl FnOnce(&BTreeSet<u32>) -> R) -> R {
+    f(&direct())
+}
+
+/// Exit status and resource usage of a reaped child
+type Waited = std::io::Result<(ExitStatus, ResourceUsage)>;
+
+/// A child forked by the server, with async pipes
+pub(crate) struct Launched {
+    pub pid: u32,
+    pub stdin: Option<tokio::process::ChildStdin>,
+    pub stdout: Option<tokio::process::ChildStdout>,
+    pub stderr: Option<tokio::process::ChildStderr>,
+    pub exit: ChildExit,
+}
+
+/// Collects a child's exit status together with its resource usage
+///
+/// Tokio's `Child::wait` reaps with `waitpid`, which discards the usage
+/// figures, so children are spawned as std processes and reaped here instead.
+pub(crate) struct ChildExit {
+    child: Arc<std::process::Child>,
+    waiter: Option<tokio::task::JoinHandle<Waited>>,
+    reaped: bool,
+    kill_on_drop: bool,
+}
+
+impl ChildExit {
+    /// Wait for the exit; cancel-safe, so it may be raced against a timeout and awaited again
+    pub(crate) async fn wait(&mut self) -> Waited {
+        let child = self.child.clone();
+        let waiter = self.waiter.get_or_insert_with(|| tokio::task::spawn_blocking(move || wait_child(&child)));
+        let waited = waiter.await.map_err(std::io::Error::other)?;
+        self.reaped = true;
+        waited
+    }
+
+    /// Kill the child and, on Unix, every process left in its process group
+    ///
+    /// Does nothing once the child has been reaped, so a recycled PID is never hit.
+    pub(crate) fn kill_group(&self) {
+        let direct = direct();
+        if !direct.contains(&self.child.id()) {
+            return;
+        }
+        #[cfg(unix)]
+        if let Ok(pid) = i32::try_from(self.child.id()) {
+            // SAFETY: kill has no memory preconditions; a negative pid targets the group.
+            unsafe {
+                libc::kill(-pid, libc::SIGKILL);
+                libc::kill(pid, libc::SIGKILL);
+            }
+        }
+        #[cfg(windows)]
+        {
+            use std::os::windows::io::AsRawHandle;
+            // SAFETY: the handle stays open while `child` is alive.
+            unsafe {
+                windows_sys::Win32::System::Threading::TerminateProcess(self.child.as_raw_handle() as _, 1);
+            }
+        }
+    }
+}
+
+impl Drop for ChildExit {
+    fn drop(&mut self) {
+        if self.reaped {
+            return;
+        }
+        if self.kill_on_drop {
+            self.kill_group();
+        }
+        // Nobody else reaps it; left alone it would stay a zombie
+        if self.waiter.is_none() {
+            let child = self.child.clone();
+            let _ = std::thread::Builder::new()
+                .name(format!("reap-{}", child.id()))
+                .spawn(move || wait_child(&child));
+        }
+    }
+}
+
+/// Block until `child` exits, then reap it with the direct set locked
+fn wait_child(child: &std::process::Child) -> Waited {
+    crate::platform::await_exit(child)?;
+    let mut direct = direct();
+    let waited = crate::platform::reap_exited(child);
+    direct.remove(&child.id());
+    waited
+}
+
+/// Spawn `command` and record the child as the server's own
+///
+/// The direct set stays locked across the fork, so a child that exits at
+/// once is recorded before the reaper can see it. With `kill_on_drop` the
+/// child is killed if the handle goes away before its exit was collected.
+pub(crate) fn launch(command: &mut tokio::process::Command, kill_on_drop: bool) -> std::io::Result<Launched> {
+    let mut direct = direct();
+    let mut child = command.as_std_mut().spawn()?;
+    direct.insert(child.id());
+    drop(direct);
+
+    let stdin = child.stdin.take().map(tokio::process::ChildStdin::from_std).transpose();
+    let stdout = child.stdout.take().map(tokio::process::ChildStdout::from_std).transpose();
+    let stderr = child.stderr.take().map(tokio::process::ChildStderr::from_std).transpose();
+    let exit = ChildExit {
+        child: Arc::new(child),
+        waiter: None,
+        reaped: false,
+        kill_on_drop,
+    };
+    Ok(Launched {
+        pid: exit.child.id(),
+        stdin: stdin?,
+        stdout: stdout?,
+        stderr: stderr?,
+        exit,
+    })
+}
+
+/// Spawn a helper `command` the caller waits for itself, recorded like [`launch`] children
+///
+/// Collect its exit with [`try_reap`] or [`reap`] so the reaper leaves it alone.
+pub(crate) fn spawn_helper(command: &mut std::process::Command) -> std::io::Result<std::process::Child> {
+    let mut direct = direct();
+    let child = command.spawn()?;
+    direct.insert(child.id());
+    Ok(child)
+}
+
+/// `try_wait` on a [`spawn_helper`] child, forgetting it once reaped
+pub(crate) fn try_reap(child: &mut std::process::Child) -> std::io::Result<Option<ExitStatus>> {
+    let mut direct = direct();
+    let status = child.try_wait();
+    if !matches!(status, Ok(None)) {
+        direct.remove(&child.id());
+    }
+    status
+}
+
+/// Kill a [`spawn_helper`] child and reap it
+pub(crate) fn reap(child: &mut std::process::Child) {
+    let _ = child.kill();
+    let _ = child.wait();
+    direct().remove(&child.id());
+}
+
+/// What to launch
+#[derive(Debug, Clone)]
+pub(crate) struct SpawnSpec {
+    /// Program path or name resolved through `PATH`
+    pub program: String,
+    /// Arguments after the program
+    pub args: Vec<String>,
+    /// Working directory (server's when `None`)
+    pub cwd: Option<PathBuf>,
+    /// Full environment; the server's environment is inherited when `None`
+    pub env: Option<Vec<(String, String)>>,
+    /// I/O scheduling applied before exec (Linux only)
+    pub io_priority: Option<IoPriority>,
+    /// CPU priority from the start: nice value on Unix, nearest priority class on Windows
+    pub nice: Option<i32>,
+    /// Logical CPUs the child and its threads may run on (all when empty)
+    pub cpus: Vec<usize>,
+}
+
+impl SpawnSpec {
+    /// Shell-like rendering for previews and summaries
+    #[must_use]
+    pub fn command_line(&self) -> String {
+        std::iter::once(&self.program)
+            .chain(&self.args)
+            .map(|part| {
+                if part.is_empty() || part.contains(char::is_whitespace) {
+                    format!("'{part}'")
+                } else {
+                    part.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Where a daemon's output and PID go
+#[derive(Debug, Clone)]
+pub(crate) struct DaemonSpec {
+    /// File stdout is appended to
+    pub stdout: PathBuf,
+    /// File stderr is appended to
+    pub stderr: PathBuf,
+    /// File the daemon's PID is written to
+    pub pidfile: Option<PathBuf>,
+}
+
+impl DaemonSpec {
+    /// Resolve requested paths relative to the daemon's working directory
+    ///
+    /// Output defaults to `<stem>-<millis>.out` / `.err` under the `daemons`
+    /// directory of the output log root.
+    #[must_use]
+    pub fn resolve(
+        spec: &SpawnSpec,
+        config: &OutputLogConfig,
+        stdout: Option<&str>,
+        stderr: Option<&str>,
+        pidfile: Option<&str>,
+    ) -> Self {
+        let base = spec
+            .cwd
+            .clone()
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_default();
+        let stem = Path::new(&spec.program)
+            .file_stem()
+            .map_or_else(|| "daemon".to_string(), |stem| stem.to_string_lossy().to_string());
+        let default = config
+            .root()
+            .join("daemons")
+            .join(format!("{stem}-{}", chrono::Utc::now().timestamp_millis()));
+        let resolve = |path: &str| base.join(path);
+        Self {
+            stdout: stdout.map_or_else(|| default.with_extension("out"), resolve),
+            stderr: stderr.map_or_else(|| default.with_extension("err"), resolve),
+            pidfile: pidfile.map(resolve),
+        }
+    }
+}
+
+/// Reject I/O priorities this platform or the kernel cannot apply
+pub(crate) fn check_io_priority(priority: IoPriority) -> Result<(), String> {
+    if !cfg!(target_os = "linux") {
+        return Err("I/O priority is only supported on Linux".to_string());
+    }
+    if priority.level > MAX_IO_LEVEL {
+        return Err(format!("I/O level must be 0 to {MAX_IO_LEVEL}, got {}", priority.level));
+    }
+    Ok(())
+}
+
+/// Reject nice values outside the Unix range
+pub(crate) fn check_nice(nice: i32) -> Result<(), String> {
+    if !(MIN_NICE..=MAX_NICE).contains(&nice) {
+        return Err(format!("nice must be between {MIN_NICE} and {MAX_NICE}, got {nice}"));
+    }
+    Ok(())
+}
+
+/// Number of CPUs an affinity mask can name
+pub(crate) fn cpu_limit() -> usize {
+    // Configured rather than available CPUs: the server's own mask may exclude valid targets
+    #[cfg(unix)]
+    // SAFETY: sysconf only reads its argument.
+    let count = usize::try_from(unsafe { libc::sysconf(libc::_SC_NPROCESSORS_CONF) }).unwrap_or(1).max(1);
+    #[cfg(not(unix))]
+    let count = std::thread::available_parallelism().map_or(1, usize::from);
+    // Windows affinity masks cover one processor group of up to 64 CPUs
+    if cfg!(windows) { count.min(usize::BITS as usize) } else { count }
+}
+
+/// Reject CPU sets that cannot be applied here
+pub(crate) fn check_cpus(cpus: &[usize]) -> Result<(), String> {
+    if cfg!(target_os = "macos") {
+        return Err("CPU affinity is not supported on macOS".to_string());
+    }
+    let limit = cpu_limit();
+    if let Some(cpu) = cpus.iter().find(|&&cpu| cpu >= limit) {
+        return Err(format!("CPU {cpu} does not exist (CPUs 0 to {} are available)", limit - 1));
+    }
+    Ok(())
+}
+
+/// Apply the CPU set of `spec` to a child that is already running (Windows)
+///
+/// Windows has no hook between creation and the first instruction, so the
+/// mask is set right after the launch; on Linux it is applied before exec.
+#[cfg(windows)]
+pub(crate) fn pin(spec: &SpawnSpec, pid: u32) {
+    if !spec.cpus.is_empty()
+        && let Err(e) = crate::platform::set_affinity(pid, &spec.cpus)
+    {
+        log::warn!("Failed to pin PID {pid} to CPUs {:?}: {e}", spec.cpus);
+    }
+}
+
+/// `spec` as a command without any process group or session setup
+fn base_command(spec: &SpawnSpec) -> tokio::process::Command {
+    let mut command = tokio::process::Command::new(&spec.program);
+    command.args(&spec.args);
+    if let Some(cwd) = &spec.cwd {
+        command.current_dir(cwd);
+    }
+    if let Some(env) = &spec.env {
+        command.env_clear().envs(env.iter().map(|(k, v)| (k, v)));
+    }
+    #[cfg(target_os = "linux")]
+    if let Some(priority) = spec.io_priority {
+        // SAFETY: the hook only issues the ioprio_set syscall.
+        unsafe {
+            command.pre_exec(move || crate::platform::set_io_priority(0, priority));
+        }
+    }
+    #[cfg(target_os = "linux")]
+    if !spec.cpus.is_empty() {
+        let cpus = spec.cpus.clone();
+        // SAFETY: the hook only issues the sched_setaffinity syscall.
+        unsafe {
+            command.pre_exec(move || crate::platform::set_affinity(0, &cpus));
+        }
+    }
+    #[cfg(unix)]
+    if let Some(nice) = spec.nice {
+        // SAFETY: the hook only issues the setpriority syscall.
+        unsafe {
+            command.pre_exec(move || crate::platform::set_nice(0, nice));
+        }
+    }
+    #[cfg(windows)]
+    if let Some(nice) = spec.nice {
+        command.creation_flags(crate::platform::priority_class(nice));
+    }
+    command
+}
+
+/// Fresh [`SPAWN_MARKER_ENV`] value, unique across servers and launches
+pub(crate) fn next_marker() -> String {
+    static NEXT: AtomicU64 = AtomicU64::new(1);
+    format!("{}-{}", std::process::id(), NEXT.fetch_add(1, Ordering::Relaxed))
+}
+
+/// Command for `spec` in its own process group (Unix) tagged with `marker`,
+/// stdio left to the caller
+pub(crate) fn command(spec: &SpawnSpec, marker: &str) -> tokio::process::Command {
+    let mut command = base_command(spec);
+    command.env(SPAWN_MARKER_ENV, marker);
+    #[cfg(unix)]
+    command.process_group(0);
+    command
+}
+
+/// Launch a detached child tagged with `marker`
+pub(crate) fn spawn_detached(spec: &SpawnSpec, marker: &str) -> std::io::Result<Launched> {
+    let launched = launch(
+        command(spec, marker)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped()),
+        false,
+    )?;
+    #[cfg(windows)]
+    pin(spec, launched.pid);
+    Ok(launched)
+}
+
+/// Launch `spec` as a daemon that survives the server and return its PID
+///
+/// On Unix the child calls `setsid` and forks once more; the intermediate
+/// process reports the grandchild's PID through a pipe and exits, so the
+/// daemon is reparented away from the server and can never regain a
+/// controlling terminal.
+#[cfg(unix)]
+pub(crate) async fn spawn_daemon(spec: &SpawnSpec, daemon: &DaemonSpec) -> std::io::Result<u32> {
+    use std::io::Read;
+    use std::os::fd::AsRawFd;
+
+    let (mut reader, writer) = std::io::pipe()?;
+    let fd = writer.as_raw_fd();
+    let mut command = base_command(spec);
+    command
+        .stdin(Stdio::null())
+        .stdout(append(&daemon.stdout)?)
+        .stderr(append(&daemon.stderr)?);
+    // SAFETY: only async-signal-safe calls (setsid, fork, write, _exit) run
+    // between fork and exec, and `fd` stays open until exec (close-on-exec).
+    unsafe {
+        command.pre_exec(move || {
+            if libc::setsid() < 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            match libc::fork() {
+                -1 => Err(std::io::Error::last_os_error()),
+                0 => Ok(()),
+                pid => {
+                    let bytes = pid.to_ne_bytes();
+                    libc::write(fd, bytes.as_ptr().cast(), bytes.len());
+                    libc::_exit(0)
+                }
+            }
+        });
+    }
+    let mut intermediate = launch(&mut command, false)?;
+    drop(command);
+    drop(writer);
+    intermediate.exit.wait().await?;
+
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes)?;
+    let pid = u32::try_from(i32::from_ne_bytes(bytes)).map_err(std::io::Error::other)?;
+    if let Some(pidfile) = &daemon.pidfile {
+        crate::pidfile::write(pidfile, pid)?;
+    }
+    Ok(pid)
+}
+
+/// Launch `spec` as a daemon that survives the server and return its PID
+///
+/// On Windows the child is a detached console process in its own process
+/// group, so console control events sent to the server never reach it.
+#[cfg(windows)]
+pub(crate) async fn spawn_daemon(spec: &SpawnSpec, daemon: &DaemonSpec) -> std::io::Result<u32> {
+    use windows_sys::Win32::System::Threading::{CREATE_NEW_PROCESS_GROUP, DETACHED_PROCESS};
+
+    let mut command = base_command(spec);
+    command
+        .stdin(Stdio::null())
+        .stdout(append(&daemon.stdout)?)
+        .stderr(append(&daemon.stderr)?)
+        .kill_on_drop(false)
+        .creation_flags(DETACHED_PROCESS | CREATE_NEW_PROCESS_GROUP | spec.nice.map_or(0, crate::platform::priority_class));
+    let child = command.spawn()?;
+    let pid = child
+        .id()
+        .ok_or_else(|| std::io::Error::other("daemon exited before its PID could be read"))?;
+    pin(spec, pid);
+    if let Some(pidfile) = &daemon.pidfile {
+        crate::pidfile::write(pidfile, pid)?;
+    }
+    Ok(pid)
+}
+
+/// Open `path` for appending, creating it and its parent directories
+fn append(path: &Path) -> std::io::Result<File> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+/// Orphans of the launch tagged `marker` adopted by the server, with their descendants
+///
+/// Only subreaper mode brings orphans to the server; elsewhere they go to
+/// init and this finds nothing. `exclude` is the launched process itself,
+/// which is the server's child too.
+pub(crate) fn adopted(provider: &dyn ProcessProvider, marker: &str, exclude: u32) -> Vec<ProcessEntry> {
+    let server = std::process::id();
+    let tag = format!("{SPAWN_MARKER_ENV}={marker}");
+    let processes = provider.enumerate();
+    let mut found: Vec<ProcessEntry> = Vec::new();
+    for process in &processes {
+        if process.parent != Some(server) || process.pid == exclude {
+            continue;
+        }
+        if provider
+            .lookup(process.pid, true)
+            .is_some_and(|details| details.env.contains(&tag))
+        {
+            found.push(process.clone());
+            found.extend(crate::tree::descendants(&processes, process.pid).into_iter().cloned());
+        }
+    }
+    found
+}
+
+/// Exit record for a child that ran for `duration`
+#[must_use]
+pub(crate) fn exit_info(status: ExitStatus, duration: Duration, usage: Option<ResourceUsage>) -> ExitInfo {
+    #[cfg(unix)]
+    let (signal, core_dumped) = {
+        use std::os::unix::process::ExitStatusExt;
+        (status.signal(), status.core_dumped())
+    };
+    #[cfg(not(unix))]
+    let (signal, core_dumped) = (None, false);
+
+    ExitInfo {
+        success: status.success(),
+        code: status.code(),
+        signal,
+        #[cfg(unix)]
+        signal_name: signal.and_then(crate::signals::signal_name).map(str::to_string),
+        #[cfg(not(unix))]
+        signal_name: None,
+        core_dumped,
+        exited_at: chrono::Utc::now().to_rfc3339(),
+        duration_secs: duration.as_secs_f64(),
+        usage,
+    }
+}
+
+/// Split `KEY=VALUE` entries, skipping malformed ones
+#[must_use]
+pub(crate) fn parse_env(entries: &[String]) -> Vec<(String, String)> {
+    entries
+        .iter()
+        .filter_map(|entry| entry.split_once('='))
+        .filter(|(key, _)| !key.is_empty())
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
@@ -0,0 +1,112 @@
+//! Detection of sudden sustained CPU spikes
+//!
+//! Fed by the history sampler: every sample advances a small state machine
+//! per process. A jump from below the low threshold straight to above the
+//! high one starts a candidate spike; staying above the high threshold for
+//! the configured time turns it into a [`CpuSpike`] published on the event
+//! bus. The first sample of a process never arms it, since sysinfo reports 0%
+//! for processes it has not seen before.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use sysinfo::System;
+
+use crate::config::CpuSpikeConfig;
+use crate::schema::CpuSpike;
+
+enum Phase {
+    /// Just seen, or last sample between the thresholds
+    Unarmed,
+    /// Last sample below the low threshold; a jump counts from here
+    Armed { cpu: f32 },
+    /// Above the high threshold since the jump
+    Spiking {
+        since: DateTime<Utc>,
+        before: f32,
+        sum: f32,
+        samples: u32,
+    },
+    /// Already reported; waits for the process to calm down
+    Reported,
+}
+
+pub struct CpuSpikes {
+    config: CpuSpikeConfig,
+    phases: Mutex<HashMap<(u32, u64), Phase>>,
+}
+
+impl Default for CpuSpikes {
+    fn default() -> Self {
+        Self::new(&CpuSpikeConfig::default())
+    }
+}
+
+impl CpuSpikes {
+    #[must_use]
+    pub fn new(config: &CpuSpikeConfig) -> Self {
+        Self {
+            config: config.clone(),
+            phases: Mutex::default(),
+        }
+    }
+
+    /// Whether spike events are published
+    #[must_use]
+    pub fn enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    /// Advance every process of a refreshed `system`; returns spikes that just became sustained
+    pub(crate) fn observe(&self, system: &System) -> Vec<CpuSpike> {
+        let now = Utc::now();
+        let (low, high) = (self.config.low_percent, self.config.high_percent);
+        let sustained = self.config.sustained_secs as f64;
+        let mut phases = self.lock();
+        let mut live = HashMap::with_capacity(system.processes().len());
+        let mut spikes = Vec::new();
+        for (pid, process) in system.processes() {
+            let key = (pid.as_u32(), process.start_time());
+            let cpu = process.cpu_usage();
+            let calm = |cpu: f32| if cpu < low { Phase::Armed { cpu } } else { Phase::Unarmed };
+            let next = match phases.remove(&key) {
+                None => Phase::Unarmed,
+                Some(Phase::Armed { cpu: before }) if cpu > high => Phase::Spiking {
+                    since: now,
+                    before,
+                    sum: cpu,
+                    samples: 1,
+                },
+                Some(Phase::Spiking { since, before, sum, samples }) if cpu > high => {
+                    let (sum, samples) = (sum + cpu, samples + 1);
+                    let secs = (now - since).num_milliseconds() as f64 / 1000.0;
+                    if secs >= sustained {
+                        spikes.push(CpuSpike {
+                            pid: key.0,
+                            name: process.name().to_string_lossy().to_string(),
+                            before_percent: before,
+                            cpu_percent: sum / samples as f32,
+                            sustained_secs: secs,
+                            started_at: since.to_rfc3339(),
+                            detected_at: now.to_rfc3339(),
+                        });
+                        Phase::Reported
+                    } else {
+                        Phase::Spiking { since, before, sum, samples }
+                    }
+                }
+                Some(Phase::Reported) if cpu >= low => Phase::Reported,
+                Some(_) => calm(cpu),
+            };
+            live.insert(key, next);
+        }
+        // Exited processes fall out here
+        *phases = live;
+        spikes
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<(u32, u64), Phase>> {
+        self.phases.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
@@ -0,0 +1,189 @@
+//! Server-side state shared by the process tools
+//!
+//! Created once in [`crate::build_router_set`] and handed to every tool that
+//! needs to remember something between calls.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use kodegen_server_http::ShutdownHook;
+
+use crate::children::{ChildRegistry, THIS_SERVER};
+use crate::config::ProcessConfig;
+use crate::confirm::ConfirmPolicy;
+use crate::containment::Containments;
+use crate::cpu_limit::CpuLimiter;
+use crate::cpu_sampler::CpuSampler;
+use crate::events::EventBus;
+use crate::exe_hash::ExeHashes;
+use crate::freeze::Freezes;
+use crate::groups::GroupStore;
+use crate::history::History;
+use crate::killed::KilledRegistry;
+use crate::list_delta::ListSnapshots;
+use crate::markers::Markers;
+use crate::oom_guard::OomGuard;
+use crate::plugins::Plugins;
+use crate::pods::PodIndex;
+use crate::profiles::Profiles;
+use crate::provider::{ProcessProvider, SysinfoProvider};
+use crate::reaper::Reaper;
+use crate::redact::Redactor;
+use crate::sampling::Sampling;
+use crate::scheduler::Scheduler;
+use crate::spikes::CpuSpikes;
+use crate::supervisor::Supervisor;
+use crate::tags::TagStore;
+use crate::throttle::KillThrottle;
+use crate::watch::Watchlist;
+
+pub struct ProcessState {
+    /// Budget for destructive operations
+    pub throttle: KillThrottle,
+    /// Processes recently terminated through this server
+    pub killed: KilledRegistry,
+    /// Tags and notes attached to live PIDs
+    pub tags: TagStore,
+    /// Named process groups
+    pub groups: GroupStore,
+    /// Watched PIDs and patterns with their exit events
+    pub watch: Arc<Watchlist>,
+    /// Processes started by this server and their exit records
+    pub supervisor: Arc<Supervisor>,
+    /// Secret masking applied to everything returned to clients
+    pub redactor: Redactor,
+    /// Recent `process_list` results for delta polling
+    pub list_snapshots: ListSnapshots,
+    /// Named snapshots recorded by process_mark
+    pub markers: Markers,
+    /// Previous CPU times for the native `process_list` backend
+    pub cpu_sampler: CpuSampler,
+    /// Sampling cadence shared by the watch monitor and `process_list`
+    pub sampling: Arc<Sampling>,
+    /// Source of process lookups and signals for the kill tools
+    pub provider: Arc<dyn ProcessProvider>,
+    /// Embedder-supplied `process_list` enrichers and named filters
+    pub plugins: Plugins,
+    /// Lifecycle events from the watchlist, the supervisor and the kill tools
+    pub events: EventBus,
+    /// Which kills wait for `confirm: true`
+    pub confirm: ConfirmPolicy,
+    /// CPU caps on running processes
+    pub cpu_limits: Arc<CpuLimiter>,
+    /// Processes held in managed cgroups
+    pub containments: Containments,
+    /// Process trees held by process_freeze
+    pub freezes: Freezes,
+    /// Exit statuses of adopted orphans
+    pub reaper: Reaper,
+    /// Kills waiting for their due time
+    pub scheduler: Scheduler,
+    /// Memory ceilings and the interventions they caused
+    pub oom_guard: OomGuard,
+    /// Rolling memory and CPU samples of every process
+    pub history: History,
+    /// Spike state of every process, advanced by the history sampler
+    pub cpu_spikes: CpuSpikes,
+    /// Executable digests keyed by path and modification time
+    pub exe_hashes: ExeHashes,
+    /// Processes other kodegen servers registered as theirs
+    pub children: ChildRegistry,
+    /// Kubernetes pod names for the pod UIDs in cgroup paths
+    pub pods: PodIndex,
+    /// Spawn profiles and the launches made from them
+    pub profiles: Profiles,
+}
+
+impl Default for ProcessState {
+    fn default() -> Self {
+        Self::new(&ProcessConfig::default())
+    }
+}
+
+impl ProcessState {
+    #[must_use]
+    pub fn new(config: &ProcessConfig) -> Self {
+        let sampling = Arc::new(Sampling::new(&config.sampling));
+        let events = EventBus::default();
+        Self {
+            throttle: KillThrottle::new(config.kill_throttle.clone()),
+            killed: KilledRegistry::new(config.killed_history),
+            tags: TagStore::default(),
+            groups: GroupStore::default(),
+            watch: Arc::new(Watchlist::new(sampling.clone(), events.clone())),
+            supervisor: Arc::new(Supervisor::new(
+                Duration::from_secs(config.exit_retention_secs),
+                config.output_log.clone(),
+                events.clone(),
+            )),
+            redactor: Redactor::new(&config.redaction),
+            list_snapshots: ListSnapshots::default(),
+            markers: Markers::default(),
+            cpu_sampler: CpuSampler::default(),
+            sampling,
+            provider: Arc::new(SysinfoProvider),
+            plugins: Plugins::default(),
+            events,
+            confirm: ConfirmPolicy::new(&config.confirmation),
+            cpu_limits: Arc::default(),
+            containments: Containments::default(),
+            freezes: Freezes::default(),
+            reaper: Reaper::default(),
+            scheduler: Scheduler::default(),
+            oom_guard: OomGuard::new(&config.oom_guard),
+            history: History::new(&config.history),
+            cpu_spikes: CpuSpikes::new(&config.cpu_spikes),
+            exe_hashes: ExeHashes::default(),
+            children: ChildRegistry::default(),
+            pods: PodIndex::new(&config.kubernetes),
+            profiles: Profiles::new(&config.spawn_profiles, &config.sandbox),
+        }
+    }
+
+    /// kodegen server that started the process, if one did: this server's own
+    /// launches or a PID a sibling registered
+    #[must_use]
+    pub fn spawned_by(&self, pid: u32, start_time: u64) -> Option<String> {
+        if self.supervisor.owns(pid) {
+            return Some(THIS_SERVER.to_string());
+        }
+        self.children.owner(pid, start_time)
+    }
+
+    /// Replace the process provider, e.g. with `FakeProvider` in tests
+    #[must_use]
+    pub fn with_provider(mut self, provider: Arc<dyn ProcessProvider>) -> Self {
+        self.provider = provider;
+        self
+    }
+
+    /// Install `process_list` extensions registered by an embedder
+    #[must_use]
+    pub fn with_plugins(mut self, plugins: Plugins) -> Self {
+        self.plugins = plugins;
+        self
+    }
+}
+
+/// Undoes every throttle, containment and freeze when the server shuts down, so no
+/// process is left stopped or capped behind, and drops pending scheduled kills
+pub(crate) struct ReleaseOnShutdown(pub Arc<ProcessState>);
+
+impl ShutdownHook for ReleaseOnShutdown {
+    fn shutdown(&self) -> Pin<Box<dyn Future<Output = anyhow::Result<()>> + Send + '_>> {
+        let state = self.0.clone();
+        Box::pin(async move {
+            // No scheduled kill may fire while the server winds down
+            state.scheduler.cancel_all();
+            tokio::task::spawn_blocking(move || {
+                state.cpu_limits.release_all();
+                state.containments.release_all();
+                state.freezes.release_all();
+            })
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to release throttled processes: {e}"))
+        })
+    }
+}
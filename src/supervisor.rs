@@ -0,0 +1,280 @@
+//! Supervisor for processes started by this server
+//!
+//! Owns every child launched through the spawn subsystem and awaits its exit,
+//! so exit code, terminating signal and wall-clock duration are recorded even
+//! when nobody is polling. Stdout and stderr are drained into size-rotated
+//! logs (see [`crate::output_log`]) and broadcast line by line to live
+//! followers until both pipes close. Exited entries are retained for the
+//! configured period and then dropped together with their logs.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+use crate::config::OutputLogConfig;
+use crate::events::{EventBus, ProcessEvent};
+use crate::output_log::RotatingLog;
+use crate::schema::{LogStream, ResourceUsage, SupervisedProcess};
+use crate::spawn::{SpawnSpec, exit_info, next_marker, spawn_detached};
+
+/// Lines buffered per follower before it starts missing output
+const FOLLOW_CAPACITY: usize = 1024;
+
+/// One line of captured output, without its trailing newline
+#[derive(Debug, Clone)]
+pub(crate) struct LogLine {
+    pub stream: LogStream,
+    pub text: String,
+}
+
+struct Entry {
+    info: SupervisedProcess,
+    started: Instant,
+    exited: Option<Instant>,
+    log_dir: Option<PathBuf>,
+    /// [`crate::spawn::SPAWN_MARKER_ENV`] value inherited by its descendants
+    marker: String,
+    /// Live output; `None` once both pipes have closed
+    lines: Option<broadcast::Sender<LogLine>>,
+}
+
+#[derive(Default)]
+struct Children {
+    next_id: u64,
+    entries: BTreeMap<u64, Entry>,
+}
+
+#[derive(Default)]
+pub struct Supervisor {
+    retention: Duration,
+    output_log: OutputLogConfig,
+    children: Mutex<Children>,
+    /// Where launches and exits are announced
+    bus: EventBus,
+}
+
+impl Supervisor {
+    /// Supervisor keeping exited entries for `retention` and announcing
+    /// launches and exits on `bus`
+    #[must_use]
+    pub fn new(retention: Duration, output_log: OutputLogConfig, bus: EventBus) -> Self {
+        Self {
+            retention,
+            output_log,
+            children: Mutex::new(Children::default()),
+            bus,
+        }
+    }
+
+    /// Launch `spec` and start awaiting its exit
+    ///
+    /// `origin` names the tool that requested the launch and `client` the
+    /// connection it came from. Must be called from within the Tokio runtime.
+    pub(crate) fn spawn(
+        self: &Arc<Self>,
+        spec: &SpawnSpec,
+        origin: &str,
+        client: Option<&str>,
+    ) -> std::io::Result<SupervisedProcess> {
+        let marker = next_marker();
+        let mut child = spawn_detached(spec, &marker)?;
+        let pid = child.pid;
+
+        let (info, captures) = {
+            let mut children = self.lock();
+            children.next_id += 1;
+            let id = children.next_id;
+            let log_dir = self.log_root().join(id.to_string());
+            let log_dir = match std::fs::create_dir_all(&log_dir) {
+                Ok(()) => Some(log_dir),
+                Err(e) => {
+                    log::warn!("Discarding output of supervised process #{id}: {}: {e}", log_dir.display());
+                    None
+                }
+            };
+            let (lines, _) = broadcast::channel(FOLLOW_CAPACITY);
+            let mut captures = Vec::new();
+            if let Some(stdout) = child.stdout.take() {
+                captures.push(self.capture(stdout, log_dir.as_deref(), LogStream::Stdout, lines.clone()));
+            }
+            if let Some(stderr) = child.stderr.take() {
+                captures.push(self.capture(stderr, log_dir.as_deref(), LogStream::Stderr, lines.clone()));
+            }
+            let info = SupervisedProcess {
+                id,
+                pid,
+                command: spec.command_line(),
+                cwd: spec.cwd.as_ref().map(|cwd| cwd.to_string_lossy().to_string()),
+                log_dir: log_dir.as_ref().map(|dir| dir.to_string_lossy().to_string()),
+                origin: origin.to_string(),
+                client: client.map(str::to_string),
+                started_at: chrono::Utc::now().to_rfc3339(),
+                running: true,
+                exit: None,
+                orphans: Vec::new(),
+            };
+            children.entries.insert(
+                info.id,
+                Entry {
+                    info: info.clone(),
+                    started: Instant::now(),
+                    exited: None,
+                    log_dir,
+                    marker,
+                    lines: Some(lines),
+                },
+            );
+            (info, captures)
+        };
+        self.bus.publish(ProcessEvent::Spawned(info.clone()));
+
+        let supervisor = self.clone();
+        let id = info.id;
+        tokio::spawn(async move {
+            match child.exit.wait().await {
+                Ok((status, usage)) => supervisor.finish(id, status, usage),
+                Err(e) => log::warn!("Failed to wait for supervised process #{id}: {e}"),
+            }
+            // Grandchildren may keep the pipes open past the exit
+            for capture in captures {
+                let _ = capture.await;
+            }
+            if let Some(entry) = supervisor.lock().entries.get_mut(&id) {
+                entry.lines = None;
+            }
+        });
+        Ok(info)
+    }
+
+    /// Supervised processes, newest first, optionally narrowed by id or PID
+    #[must_use]
+    pub fn list(&self, id: Option<u64>, pid: Option<u32>) -> Vec<SupervisedProcess> {
+        let mut children = self.lock();
+        let retention = self.retention;
+        children.entries.retain(|_, entry| {
+            let keep = entry.exited.is_none_or(|exited| exited.elapsed() < retention);
+            if !keep && let Some(dir) = &entry.log_dir {
+                let _ = std::fs::remove_dir_all(dir);
+            }
+            keep
+        });
+        children
+            .entries
+            .values()
+            .rev()
+            .filter(|entry| id.is_none_or(|id| entry.info.id == id) && pid.is_none_or(|pid| entry.info.pid == pid))
+            .map(|entry| entry.info.clone())
+            .collect()
+    }
+
+    /// Whether `pid` is a running process started here
+    #[must_use]
+    pub fn owns(&self, pid: u32) -> bool {
+        self.lock().entries.values().any(|entry| entry.info.running && entry.info.pid == pid)
+    }
+
+    /// Supervised process whose launch carried `marker`, i.e. the one a process
+    /// inheriting that [`crate::spawn::SPAWN_MARKER_ENV`] value descends from
+    #[must_use]
+    pub(crate) fn by_marker(&self, marker: &str) -> Option<SupervisedProcess> {
+        self.lock()
+            .entries
+            .values()
+            .find(|entry| entry.marker == marker)
+            .map(|entry| entry.info.clone())
+    }
+
+    /// Spawn marker of supervised process `id`, for finding its adopted orphans
+    #[must_use]
+    pub(crate) fn marker(&self, id: u64) -> Option<String> {
+        self.lock().entries.get(&id).map(|entry| entry.marker.clone())
+    }
+
+    /// Subscribe to output lines written from now on
+    ///
+    /// The receiver reports `Closed` once both pipes of the process have
+    /// closed. Errors when the id is unknown or its output has already ended.
+    pub(crate) fn follow(&self, id: u64) -> Result<broadcast::Receiver<LogLine>, String> {
+        let children = self.lock();
+        let Some(entry) = children.entries.get(&id) else {
+            return Err(format!("No supervised process #{id} (never started here, or its exit record expired)"));
+        };
+        match &entry.lines {
+            Some(lines) => Ok(lines.subscribe()),
+            None => Err(format!("Output of supervised process #{id} has ended; use process_logs")),
+        }
+    }
+
+    /// Directory for this server's captured output, one subdirectory per process
+    fn log_root(&self) -> PathBuf {
+        self.output_log.root().join(format!("logs-{}", std::process::id()))
+    }
+
+    /// Drain `pipe` line by line into the rotated log for `stream` and to followers
+    ///
+    /// The pipe is always drained, even when the log cannot be written, so a
+    /// chatty child never blocks on a full pipe buffer.
+    fn capture(
+        &self,
+        pipe: impl AsyncRead + Unpin + Send + 'static,
+        dir: Option<&Path>,
+        stream: LogStream,
+        lines: broadcast::Sender<LogLine>,
+    ) -> JoinHandle<()> {
+        let mut log = dir.and_then(|dir| match RotatingLog::create(dir, stream, &self.output_log) {
+            Ok(log) => Some(log),
+            Err(e) => {
+                log::warn!("Discarding {} of {}: {e}", stream.name(), dir.display());
+                None
+            }
+        });
+        tokio::spawn(async move {
+            let mut reader = BufReader::new(pipe);
+            let mut line = Vec::new();
+            loop {
+                line.clear();
+                match reader.read_until(b'\n', &mut line).await {
+                    Ok(0) | Err(_) => break,
+                    Ok(_) => {
+                        if let Some(writer) = &mut log
+                            && let Err(e) = writer.write(&line)
+                        {
+                            log::warn!("Stopped writing {} log: {e}", stream.name());
+                            log = None;
+                        }
+                        if lines.receiver_count() > 0 {
+                            let text = String::from_utf8_lossy(&line);
+                            let text = text.trim_end_matches(['\n', '\r']).to_string();
+                            let _ = lines.send(LogLine { stream, text });
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    fn finish(&self, id: u64, status: ExitStatus, usage: ResourceUsage) {
+        let info = {
+            let mut children = self.lock();
+            let Some(entry) = children.entries.get_mut(&id) else {
+                return;
+            };
+            let now = Instant::now();
+            entry.exited = Some(now);
+            entry.info.running = false;
+            entry.info.exit = Some(exit_info(status, now - entry.started, Some(usage)));
+            entry.info.clone()
+        };
+        self.bus.publish(ProcessEvent::Finished(info));
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Children> {
+        self.children.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
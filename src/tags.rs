@@ -0,0 +1,92 @@
+//! Server-side tags and notes attached to PIDs
+//!
+//! Entries remember the process start time so a recycled PID never inherits
+//! another process's tags. Exited processes are dropped lazily whenever the
+//! store is read against fresh process data.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+
+use crate::schema::TaggedProcess;
+
+#[derive(Default)]
+pub struct TagStore {
+    entries: Mutex<HashMap<u32, TaggedProcess>>,
+}
+
+impl TagStore {
+    /// Drop entries whose process exited or whose PID was reused
+    pub fn prune_with(&self, alive: impl Fn(u32, u64) -> bool) {
+        self.lock().retain(|pid, entry| alive(*pid, entry.start_time));
+    }
+
+    /// Drop entries for exited processes, querying the OS for tagged PIDs only
+    pub fn prune_exited(&self) {
+        let pids: Vec<Pid> = self.lock().keys().map(|pid| Pid::from_u32(*pid)).collect();
+        if pids.is_empty() {
+            return;
+        }
+        let mut system = System::new();
+        system.refresh_processes_specifics(ProcessesToUpdate::Some(&pids), true, ProcessRefreshKind::nothing());
+        self.prune_with(|pid, start_time| {
+            system
+                .process(Pid::from_u32(pid))
+                .is_some_and(|process| process.start_time() == start_time)
+        });
+    }
+
+    /// Tags of `pid` if they belong to the process started at `start_time`
+    #[must_use]
+    pub fn get(&self, pid: u32, start_time: u64) -> Option<TaggedProcess> {
+        self.lock()
+            .get(&pid)
+            .filter(|entry| entry.start_time == start_time)
+            .cloned()
+    }
+
+    /// Whether any live entry carries `tag`
+    #[must_use]
+    pub fn has(&self, pid: u32, start_time: u64, tag: &str) -> bool {
+        self.get(pid, start_time)
+            .is_some_and(|entry| entry.tags.iter().any(|t| t == tag))
+    }
+
+    /// All entries, sorted by PID
+    #[must_use]
+    pub fn snapshot(&self) -> Vec<TaggedProcess> {
+        let mut entries: Vec<TaggedProcess> = self.lock().values().cloned().collect();
+        entries.sort_by_key(|entry| entry.pid);
+        entries
+    }
+
+    /// Edit the entry for a process, creating it if needed
+    ///
+    /// An entry left without tags or note is removed. Returns the stored
+    /// entry after the edit.
+    pub fn update(&self, fresh: TaggedProcess, edit: impl FnOnce(&mut TaggedProcess)) -> Option<TaggedProcess> {
+        let mut entries = self.lock();
+        let entry = entries
+            .entry(fresh.pid)
+            .and_modify(|existing| {
+                if existing.start_time != fresh.start_time {
+                    *existing = fresh.clone();
+                }
+            })
+            .or_insert(fresh);
+        edit(entry);
+        entry.tags.sort();
+        entry.tags.dedup();
+        if entry.tags.is_empty() && entry.note.is_none() {
+            let pid = entry.pid;
+            entries.remove(&pid);
+            return None;
+        }
+        Some(entry.clone())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<u32, TaggedProcess>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
@@ -0,0 +1,169 @@
+//! Kill throttling shared by every destructive tool
+//!
+//! Keeps a sliding window of recent kill timestamps globally and per client
+//! connection. Separate from transport-level rate limiting: reads are never
+//! counted, and the budget is what stops a runaway agent loop.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::config::KillThrottleConfig;
+use crate::schema::KillBudget;
+
+/// Bucket used for callers that do not send a connection ID (direct HTTP)
+const ANONYMOUS_CLIENT: &str = "anonymous";
+
+/// Kill timestamps, each tagged with the reservation that made it
+type Window = VecDeque<(Instant, u64)>;
+
+#[derive(Default)]
+struct Windows {
+    global: Window,
+    clients: HashMap<String, Window>,
+    /// Id of the next reservation
+    next: u64,
+}
+
+/// One kill counted by [`KillThrottle::acquire`]; pass it to [`KillThrottle::release`] to give it back
+#[derive(Debug)]
+pub struct Reservation {
+    id: u64,
+    client: String,
+}
+
+/// Global and per-client kill budget
+#[derive(Default)]
+pub struct KillThrottle {
+    config: KillThrottleConfig,
+    windows: Mutex<Windows>,
+}
+
+impl KillThrottle {
+    #[must_use]
+    pub fn new(config: KillThrottleConfig) -> Self {
+        Self {
+            config,
+            windows: Mutex::new(Windows::default()),
+        }
+    }
+
+    /// Reserve one kill for `client`
+    ///
+    /// Returns the reservation and the budget left after it, or a message
+    /// naming the exhausted cap and when it frees up. Call
+    /// [`KillThrottle::release`] with the reservation if the kill is not
+    /// actually performed.
+    pub fn acquire(&self, client: Option<&str>) -> Result<(Reservation, KillBudget), String> {
+        let client = client.unwrap_or(ANONYMOUS_CLIENT);
+        let window = self.window();
+        let now = Instant::now();
+        let mut windows = self.lock();
+        let Windows { global, clients, next } = &mut *windows;
+        prune(global, now, window);
+        clients.retain(|_, entries| {
+            prune(entries, now, window);
+            !entries.is_empty()
+        });
+        let entries = clients.entry(client.to_string()).or_default();
+
+        let caps = [
+            ("global", self.config.global_limit, &*global),
+            ("per-client", self.config.per_client_limit, &*entries),
+        ];
+        for (scope, limit, used) in caps {
+            if limit > 0 && used.len() >= limit as usize {
+                let retry = used
+                    .front()
+                    .map_or(Duration::ZERO, |(oldest, _)| window.saturating_sub(now - *oldest));
+                return Err(format!(
+                    "{scope} kill limit of {limit} per {}s reached; retry in {}s",
+                    self.config.window_secs,
+                    retry.as_secs().max(1)
+                ));
+            }
+        }
+
+        let id = *next;
+        *next += 1;
+        global.push_back((now, id));
+        entries.push_back((now, id));
+        let reservation = Reservation {
+            id,
+            client: client.to_string(),
+        };
+        Ok((reservation, self.budget_of(global.len(), entries.len())))
+    }
+
+    /// Give back a reservation made by [`KillThrottle::acquire`]
+    ///
+    /// Removes exactly that kill, so concurrent reservations keep counting;
+    /// releasing it again, or after it left the window, changes nothing.
+    pub fn release(&self, reservation: &Reservation) -> KillBudget {
+        let mut windows = self.lock();
+        let Windows { global, clients, .. } = &mut *windows;
+        forget(global, reservation.id);
+        let used = clients.get_mut(&reservation.client).map_or(0, |entries| {
+            forget(entries, reservation.id);
+            entries.len()
+        });
+        self.budget_of(global.len(), used)
+    }
+
+    /// Budget left for `client` without reserving anything
+    pub fn remaining(&self, client: Option<&str>) -> KillBudget {
+        let client = client.unwrap_or(ANONYMOUS_CLIENT);
+        let window = self.window();
+        let now = Instant::now();
+        let mut windows = self.lock();
+        prune(&mut windows.global, now, window);
+        let client_used = windows.clients.get_mut(client).map_or(0, |entries| {
+            prune(entries, now, window);
+            entries.len()
+        });
+        self.budget_of(windows.global.len(), client_used)
+    }
+
+    fn budget_of(&self, global_used: usize, client_used: usize) -> KillBudget {
+        let remaining = |limit: u32, used: usize| {
+            (limit > 0).then(|| limit.saturating_sub(u32::try_from(used).unwrap_or(u32::MAX)))
+        };
+        KillBudget {
+            window_secs: self.config.window_secs,
+            client_remaining: remaining(self.config.per_client_limit, client_used),
+            global_remaining: remaining(self.config.global_limit, global_used),
+        }
+    }
+
+    fn window(&self) -> Duration {
+        Duration::from_secs(self.config.window_secs)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Windows> {
+        // Timestamps stay consistent even if a holder panicked
+        self.windows.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn prune(entries: &mut Window, now: Instant, window: Duration) {
+    while entries.front().is_some_and(|(t, _)| now - *t >= window) {
+        entries.pop_front();
+    }
+}
+
+fn forget(entries: &mut Window, id: u64) {
+    if let Some(index) = entries.iter().position(|(_, entry)| *entry == id) {
+        entries.remove(index);
+    }
+}
+
+/// One-line budget description for summaries and error messages
+pub(crate) fn budget_text(budget: &KillBudget) -> String {
+    let part = |remaining: Option<u32>| remaining.map_or_else(|| "unlimited".to_string(), |n| n.to_string());
+    format!(
+        "{} left for this client, {} left globally per {}s",
+        part(budget.client_remaining),
+        part(budget.global_remaining),
+        budget.window_secs
+    )
+}
@@ -0,0 +1,78 @@
+//! Parent/child relationships over a provider enumeration
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::ProcessId;
+use crate::provider::ProcessEntry;
+
+/// Children of every process that has some, each list sorted by PID
+pub(crate) fn children_of(processes: &[ProcessEntry]) -> HashMap<ProcessId, Vec<&ProcessEntry>> {
+    let mut children: HashMap<ProcessId, Vec<&ProcessEntry>> = HashMap::new();
+    for process in processes {
+        if let Some(parent) = process.parent
+            && parent != process.pid
+        {
+            children.entry(parent).or_default().push(process);
+        }
+    }
+    for siblings in children.values_mut() {
+        siblings.sort_unstable_by_key(|process| process.pid);
+    }
+    children
+}
+
+/// Processes without a parent among `processes` (init, kernel threads, orphans of
+/// an exited parent), sorted by PID
+pub(crate) fn roots(processes: &[ProcessEntry]) -> Vec<&ProcessEntry> {
+    let present: HashSet<ProcessId> = processes.iter().map(|process| process.pid).collect();
+    let mut roots: Vec<&ProcessEntry> = processes
+        .iter()
+        .filter(|process| process.parent.is_none_or(|parent| parent == process.pid || !present.contains(&parent)))
+        .collect();
+    roots.sort_unstable_by_key(|process| process.pid);
+    roots
+}
+
+/// Descendants of `root`, breadth-first so every parent precedes its children
+pub(crate) fn descendants(processes: &[ProcessEntry], root: ProcessId) -> Vec<&ProcessEntry> {
+    let children = children_of(processes);
+    let mut found = Vec::new();
+    let mut seen = HashSet::from([root]);
+    let mut queue = VecDeque::from([root]);
+    while let Some(pid) = queue.pop_front() {
+        for child in children.get(&pid).into_iter().flatten() {
+            if seen.insert(child.pid) {
+                found.push(*child);
+                queue.push_back(child.pid);
+            }
+        }
+    }
+    found
+}
+
+/// Parents of `child`, nearest first, and the parent PID the chain broke at
+///
+/// The walk stops at a process without a parent or whose parent is PID 0. It
+/// also stops when the parent is missing or younger than its child, which
+/// means the parent exited and its PID may have been reused; that PID is
+/// returned. `limit` caps the walk on a corrupt or cyclic chain.
+pub(crate) fn ancestors<'a>(
+    processes: &'a [ProcessEntry],
+    child: &ProcessEntry,
+    limit: usize,
+) -> (Vec<&'a ProcessEntry>, Option<ProcessId>) {
+    let by_pid: HashMap<ProcessId, &ProcessEntry> = processes.iter().map(|process| (process.pid, process)).collect();
+    let mut chain = Vec::new();
+    let (mut parent, mut child_start) = (child.parent.filter(|parent| *parent != child.pid), child.start_time);
+    while let Some(ppid) = parent
+        && chain.len() < limit
+    {
+        let Some(ancestor) = by_pid.get(&ppid).filter(|ancestor| ancestor.start_time <= child_start) else {
+            // PID 0 is the kernel's scheduler on Unix, parent of init and kthreadd
+            return (chain, (ppid != 0).then_some(ppid));
+        };
+        chain.push(*ancestor);
+        (parent, child_start) = (ancestor.parent.filter(|next| *next != ppid), ancestor.start_time);
+    }
+    (chain, None)
+}
@@ -0,0 +1,35 @@
+//! User identity helpers for tools that report process owners
+
+use sysinfo::{Uid, Users};
+
+/// Platform user id as a string (numeric uid on Unix, SID on Windows)
+#[must_use]
+pub(crate) fn uid_string(uid: &Uid) -> String {
+    std::ops::Deref::deref(uid).to_string()
+}
+
+/// "name (uid)" when the user database knows the uid, otherwise the bare uid
+#[must_use]
+pub(crate) fn user_label(users: &Users, uid: &Uid) -> String {
+    match users.get_user_by_id(uid) {
+        Some(user) => format!("{} ({})", user.name(), uid_string(uid)),
+        None => uid_string(uid),
+    }
+}
+
+/// Label for the user the server itself runs as (effective uid preferred)
+#[must_use]
+pub(crate) fn server_user_label() -> Option<String> {
+    use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+
+    let pid = Pid::from_u32(std::process::id());
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        true,
+        ProcessRefreshKind::nothing().with_user(UpdateKind::Always),
+    );
+    let process = system.process(pid)?;
+    let uid = process.effective_user_id().or(process.user_id())?;
+    Some(user_label(&Users::new_with_refreshed_list(), uid))
+}
@@ -0,0 +1,339 @@
+//! Watchlist: monitor PIDs or name patterns and report their exits
+//!
+//! A monitor thread samples watched processes once per interval while at
+//! least one watch exists and stops when the list is empty. Every exit
+//! becomes a [`WatchEvent`] kept in a bounded log, streamed to the client
+//! that created the watch, published on the [`EventBus`] and optionally
+//! POSTed to a webhook.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use globset::{Glob, GlobBuilder, GlobMatcher};
+use kodegen_mcp_schema::ToolExecutionContext;
+use sysinfo::{Pid, Process, ProcessesToUpdate, System};
+
+use crate::events::{EventBus, ProcessEvent};
+use crate::sampling::Sampling;
+use crate::schema::{WatchEvent, WatchInfo};
+
+/// Exit events retained for `process_watch_events`
+const EVENT_CAPACITY: usize = 256;
+
+/// Timeout for a single webhook delivery
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Last known state of a watched process
+#[derive(Clone)]
+struct Sample {
+    name: String,
+    start_time: u64,
+    cpu_percent: f32,
+    rss_bytes: u64,
+    sampled_at: String,
+}
+
+impl Sample {
+    fn of(process: &Process) -> Self {
+        Self {
+            name: process.name().to_string_lossy().to_string(),
+            start_time: process.start_time(),
+            cpu_percent: process.cpu_usage(),
+            rss_bytes: process.memory(),
+            sampled_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+struct Watch {
+    info: WatchInfo,
+    /// Name glob for pattern watches; PID watches only track their one PID
+    matcher: Option<GlobMatcher>,
+    tracked: HashMap<u32, Sample>,
+    /// Context of the creating call, used to stream exit notifications
+    notify: Option<ToolExecutionContext>,
+}
+
+#[derive(Default)]
+struct Watches {
+    next_id: u64,
+    watches: HashMap<u64, Watch>,
+}
+
+#[derive(Default)]
+struct Events {
+    next_id: u64,
+    log: VecDeque<WatchEvent>,
+}
+
+pub struct Watchlist {
+    watches: Mutex<Watches>,
+    events: Mutex<Events>,
+    running: AtomicBool,
+    /// Source of the monitor's sampling period
+    sampling: Arc<Sampling>,
+    /// Where every published event is also broadcast
+    bus: EventBus,
+}
+
+impl Default for Watchlist {
+    fn default() -> Self {
+        Self::new(Arc::default(), EventBus::default())
+    }
+}
+
+impl Watchlist {
+    /// Watchlist whose monitor samples at `sampling`'s current watch interval
+    /// and publishes exits on `bus`
+    #[must_use]
+    pub fn new(sampling: Arc<Sampling>, bus: EventBus) -> Self {
+        Self {
+            watches: Mutex::default(),
+            events: Mutex::default(),
+            running: AtomicBool::new(false),
+            sampling,
+            bus,
+        }
+    }
+
+    /// Watch a single live process until it exits
+    pub fn watch_pid(
+        self: &Arc<Self>,
+        pid: u32,
+        webhook: Option<String>,
+        notify: Option<ToolExecutionContext>,
+    ) -> Result<WatchInfo, String> {
+        let mut system = System::new();
+        system.refresh_processes(ProcessesToUpdate::Some(&[Pid::from_u32(pid)]), true);
+        let process = system
+            .process(Pid::from_u32(pid))
+            .ok_or_else(|| format!("Process {pid} not found"))?;
+        let tracked = HashMap::from([(pid, Sample::of(process))]);
+        Ok(self.insert(Some(pid), None, None, tracked, webhook, notify))
+    }
+
+    /// Watch every process whose name matches a case-insensitive glob
+    pub fn watch_pattern(
+        self: &Arc<Self>,
+        pattern: &str,
+        webhook: Option<String>,
+        notify: Option<ToolExecutionContext>,
+    ) -> Result<WatchInfo, String> {
+        let matcher = GlobBuilder::new(pattern)
+            .case_insensitive(true)
+            .build()
+            .map(|glob: Glob| glob.compile_matcher())
+            .map_err(|e| format!("Invalid pattern {pattern:?}: {e}"))?;
+        let mut system = System::new();
+        system.refresh_processes(ProcessesToUpdate::All, true);
+        let tracked = matching(&system, &matcher);
+        Ok(self.insert(None, Some(pattern.to_string()), Some(matcher), tracked, webhook, notify))
+    }
+
+    /// Remove a watch, returning whether it existed
+    pub fn unwatch(&self, id: u64) -> bool {
+        self.lock_watches().watches.remove(&id).is_some()
+    }
+
+    /// Active watches, sorted by id
+    #[must_use]
+    pub fn list(&self) -> Vec<WatchInfo> {
+        let watches = self.lock_watches();
+        let mut infos: Vec<WatchInfo> = watches.watches.values().map(Watch::current_info).collect();
+        infos.sort_by_key(|info| info.id);
+        infos
+    }
+
+    /// Exit events newer than `since` (event id), oldest first
+    #[must_use]
+    pub fn events(&self, since: u64, watch_id: Option<u64>) -> Vec<WatchEvent> {
+        self.lock_events()
+            .log
+            .iter()
+            .filter(|event| event.id > since && watch_id.is_none_or(|id| event.watch_id == id))
+            .cloned()
+            .collect()
+    }
+
+    fn insert(
+        self: &Arc<Self>,
+        pid: Option<u32>,
+        pattern: Option<String>,
+        matcher: Option<GlobMatcher>,
+        tracked: HashMap<u32, Sample>,
+        webhook: Option<String>,
+        notify: Option<ToolExecutionContext>,
+    ) -> WatchInfo {
+        let info = {
+            let mut watches = self.lock_watches();
+            watches.next_id += 1;
+            let id = watches.next_id;
+            let watch = Watch {
+                info: WatchInfo {
+                    id,
+                    pid,
+                    pattern,
+                    webhook,
+                    members: Vec::new(),
+                    created_at: chrono::Utc::now().to_rfc3339(),
+                },
+                matcher,
+                tracked,
+                notify,
+            };
+            let info = watch.current_info();
+            watches.watches.insert(id, watch);
+            info
+        };
+        self.ensure_monitor();
+        info
+    }
+
+    fn ensure_monitor(self: &Arc<Self>) {
+        if self.running.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let watchlist = self.clone();
+        let runtime = tokio::runtime::Handle::try_current().ok();
+        let spawned = std::thread::Builder::new()
+            .name("process-watch".to_string())
+            .spawn(move || watchlist.monitor(runtime));
+        if let Err(e) = spawned {
+            log::warn!("Failed to start process watch monitor: {e}");
+            self.running.store(false, Ordering::SeqCst);
+        }
+    }
+
+    fn monitor(&self, runtime: Option<tokio::runtime::Handle>) {
+        let mut system = System::new();
+        loop {
+            std::thread::sleep(self.sampling.watch_interval());
+            system.refresh_processes(ProcessesToUpdate::All, true);
+
+            let mut exits = Vec::new();
+            {
+                let mut watches = self.lock_watches();
+                watches.watches.retain(|_, watch| {
+                    exits.extend(watch.update(&system));
+                    watch.matcher.is_some() || !watch.tracked.is_empty()
+                });
+                if watches.watches.is_empty() {
+                    // Cleared under the lock so a concurrent insert restarts the monitor
+                    self.running.store(false, Ordering::SeqCst);
+                    drop(watches);
+                    self.publish(exits, runtime.as_ref());
+                    return;
+                }
+            }
+            self.publish(exits, runtime.as_ref());
+        }
+    }
+
+    fn publish(&self, exits: Vec<(WatchEvent, Option<String>, Option<ToolExecutionContext>)>, runtime: Option<&tokio::runtime::Handle>) {
+        for (mut event, webhook, notify) in exits {
+            {
+                let mut events = self.lock_events();
+                events.next_id += 1;
+                event.id = events.next_id;
+                if events.log.len() >= EVENT_CAPACITY {
+                    events.log.pop_front();
+                }
+                events.log.push_back(event.clone());
+            }
+            self.bus.publish(ProcessEvent::Exited(event.clone()));
+            let Some(runtime) = runtime else {
+                continue;
+            };
+            if let Some(ctx) = notify {
+                let message = format!(
+                    "Watch #{}: PID {} ({}) exited after {}s",
+                    event.watch_id, event.pid, event.name, event.runtime_secs
+                );
+                runtime.spawn(async move {
+                    // The client may have gone away; the event log still has it
+                    let _ = ctx.stream(message).await;
+                });
+            }
+            if let Some(url) = webhook {
+                let event = event.clone();
+                runtime.spawn(async move {
+                    let client = reqwest::Client::new();
+                    if let Err(e) = client.post(&url).timeout(WEBHOOK_TIMEOUT).json(&event).send().await {
+                        log::warn!("Watch webhook {url} failed: {e}");
+                    }
+                });
+            }
+        }
+    }
+
+    fn lock_watches(&self) -> std::sync::MutexGuard<'_, Watches> {
+        self.watches.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn lock_events(&self) -> std::sync::MutexGuard<'_, Events> {
+        self.events.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Watch {
+    fn current_info(&self) -> WatchInfo {
+        let mut members: Vec<u32> = self.tracked.keys().copied().collect();
+        members.sort_unstable();
+        WatchInfo {
+            members,
+            ..self.info.clone()
+        }
+    }
+
+    /// Refresh samples and return exits detected since the last tick
+    fn update(&mut self, system: &System) -> Vec<(WatchEvent, Option<String>, Option<ToolExecutionContext>)> {
+        let mut exits = Vec::new();
+        self.tracked.retain(|pid, sample| match system.process(Pid::from_u32(*pid)) {
+            Some(process) if process.start_time() == sample.start_time => {
+                *sample = Sample::of(process);
+                true
+            }
+            _ => {
+                exits.push(exit_event(self.info.id, *pid, sample));
+                false
+            }
+        });
+        if let Some(matcher) = &self.matcher {
+            for (pid, sample) in matching(system, matcher) {
+                self.tracked.entry(pid).or_insert(sample);
+            }
+        }
+        exits
+            .into_iter()
+            .map(|event| (event, self.info.webhook.clone(), self.notify.clone()))
+            .collect()
+    }
+}
+
+fn matching(system: &System, matcher: &GlobMatcher) -> HashMap<u32, Sample> {
+    system
+        .processes()
+        .iter()
+        .filter(|(_, process)| matcher.is_match(process.name().to_string_lossy().as_ref()))
+        .map(|(pid, process)| (pid.as_u32(), Sample::of(process)))
+        .collect()
+}
+
+fn exit_event(watch_id: u64, pid: u32, last: &Sample) -> WatchEvent {
+    let now = chrono::Utc::now();
+    let exited = u64::try_from(now.timestamp()).unwrap_or(0);
+    WatchEvent {
+        id: 0,
+        watch_id,
+        pid,
+        name: last.name.clone(),
+        started_at: last.start_time,
+        exited_at: now.to_rfc3339(),
+        runtime_secs: exited.saturating_sub(last.start_time),
+        last_cpu_percent: last.cpu_percent,
+        last_rss_bytes: last.rss_bytes,
+        last_sampled_at: last.sampled_at.clone(),
+    }
+}
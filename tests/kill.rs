@@ -0,0 +1,130 @@
+//! `process_kill` policy checks against an in-memory process table
+//!
+//! Every test runs on [`FakeProvider`], so no host process is ever signalled.
+
+use std::sync::Arc;
+
+use kodegen_tools_process::provider::FakeProvider;
+use kodegen_tools_process::{
+    DO_NOT_KILL_TAG, ProcessConfig, ProcessKillArgs, ProcessKillTool, ProcessSignal, ProcessState, TaggedProcess,
+};
+
+/// Above the largest Linux `pid_max`, so it can never be this test process
+const PID: u32 = 5_000_001;
+
+/// Start time of every fake process
+const STARTED: u64 = 1_700_000_000;
+
+fn setup(config: ProcessConfig, provider: &Arc<FakeProvider>) -> (ProcessKillTool, Arc<ProcessState>) {
+    let state = Arc::new(ProcessState::new(&config).with_provider(provider.clone()));
+    (ProcessKillTool::new(Arc::new(config), state.clone()), state)
+}
+
+fn pid(pid: u32) -> ProcessKillArgs {
+    ProcessKillArgs {
+        pid: Some(pid),
+        ..ProcessKillArgs::default()
+    }
+}
+
+#[tokio::test]
+async fn refuses_protected_processes() {
+    let provider = Arc::new(
+        FakeProvider::new()
+            .with_named(1, "init", STARTED)
+            .with_named(PID, "systemd", STARTED),
+    );
+    let (tool, _) = setup(ProcessConfig::default(), &provider);
+
+    for target in [1, PID] {
+        let error = tool.kill(pid(target), None, None).await.unwrap_err().to_string();
+        assert!(error.contains("protected"), "{error}");
+        assert!(provider.is_alive(target));
+    }
+    assert!(provider.delivered().is_empty());
+}
+
+#[tokio::test]
+async fn refuses_do_not_kill_tag() {
+    let provider = Arc::new(FakeProvider::new().with_named(PID, "worker", STARTED));
+    let (tool, state) = setup(ProcessConfig::default(), &provider);
+    let entry = TaggedProcess {
+        pid: PID,
+        name: "worker".to_string(),
+        start_time: STARTED,
+        proc_id: format!("{PID}-{STARTED}"),
+        tags: Vec::new(),
+        note: None,
+        tagged_at: "2026-01-01T00:00:00+00:00".to_string(),
+    };
+    state.tags.update(entry, |entry| entry.tags.push(DO_NOT_KILL_TAG.to_string()));
+
+    let error = tool.kill(pid(PID), None, None).await.unwrap_err().to_string();
+    assert!(error.contains(DO_NOT_KILL_TAG), "{error}");
+    assert!(provider.is_alive(PID));
+}
+
+#[tokio::test]
+async fn sensitive_names_need_confirm() {
+    let provider = Arc::new(FakeProvider::new().with_named(PID, "postgres", STARTED));
+    let mut config = ProcessConfig::default();
+    config.confirmation.sensitive = vec!["postgres*".to_string()];
+    let (tool, _) = setup(config, &provider);
+
+    let error = tool.kill(pid(PID), None, None).await.unwrap_err().to_string();
+    assert!(error.contains("confirm=true"), "{error}");
+    assert!(provider.is_alive(PID));
+
+    let confirmed = ProcessKillArgs {
+        confirm: true,
+        ..pid(PID)
+    };
+    let output = tool.kill(confirmed, None, None).await.unwrap().metadata;
+    assert!(output.success);
+    assert_eq!(provider.delivered(), vec![(PID, ProcessSignal::Kill)]);
+}
+
+#[tokio::test]
+async fn failed_kill_returns_its_budget() {
+    let provider = Arc::new(
+        FakeProvider::new()
+            .with_named(PID, "worker", STARTED)
+            .with_named(PID + 1, "worker", STARTED)
+            .with_named(PID + 2, "worker", STARTED),
+    );
+    provider.deny(PID);
+    let mut config = ProcessConfig::default();
+    config.kill_throttle.per_client_limit = 1;
+    let (tool, state) = setup(config, &provider);
+    let client = Some("agent");
+
+    assert!(tool.kill(pid(PID), client, None).await.is_err());
+    assert_eq!(state.throttle.remaining(client).client_remaining, Some(1));
+
+    // The refused kill left the whole budget for the next one
+    tool.kill(pid(PID + 1), client, None).await.unwrap();
+    assert_eq!(state.throttle.remaining(client).client_remaining, Some(0));
+    assert!(tool.kill(pid(PID + 2), client, None).await.is_err());
+    assert!(provider.is_alive(PID + 2));
+}
+
+#[tokio::test]
+async fn refuses_reused_pid() {
+    let provider = Arc::new(FakeProvider::new().with_named(PID, "worker", STARTED));
+    let (tool, _) = setup(ProcessConfig::default(), &provider);
+
+    let stale = ProcessKillArgs {
+        start_time: Some(STARTED - 1),
+        ..pid(PID)
+    };
+    let error = tool.kill(stale, None, None).await.unwrap_err().to_string();
+    assert!(error.contains("does not match"), "{error}");
+    assert!(provider.is_alive(PID));
+
+    let current = ProcessKillArgs {
+        start_time: Some(STARTED),
+        ..pid(PID)
+    };
+    tool.kill(current, None, None).await.unwrap();
+    assert!(!provider.is_alive(PID));
+}